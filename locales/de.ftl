@@ -0,0 +1,74 @@
+# German unit names for the `i18n` feature.
+#
+# Each message is `unit-` followed by the unit's English name with every run of
+# characters other than ASCII letters and digits replaced by `-`. The value selects
+# on `$count`, the number as written; the optional `.symbol` attribute replaces the
+# unit symbol where German usage differs.
+
+## Length
+
+unit-meter = Meter
+unit-kilometer = Kilometer
+unit-centimeter = Zentimeter
+unit-millimeter = Millimeter
+unit-foot = Fuß
+unit-inch = Zoll
+unit-mile =
+    { $count ->
+        [one] Meile
+       *[other] Meilen
+    }
+unit-nautical-mile =
+    { $count ->
+        [one] Seemeile
+       *[other] Seemeilen
+    }
+    .symbol = sm
+
+## Mass
+
+unit-kilogram = Kilogramm
+unit-gram = Gramm
+unit-milligram = Milligramm
+unit-pound = Pfund
+
+## Temperature
+
+unit-kelvin = Kelvin
+unit-degree-celsius = Grad Celsius
+unit-degree-fahrenheit = Grad Fahrenheit
+
+## Time
+
+unit-second =
+    { $count ->
+        [one] Sekunde
+       *[other] Sekunden
+    }
+unit-minute =
+    { $count ->
+        [one] Minute
+       *[other] Minuten
+    }
+unit-hour =
+    { $count ->
+        [one] Stunde
+       *[other] Stunden
+    }
+    .symbol = Std.
+unit-day =
+    { $count ->
+        [one] Tag
+       *[other] Tage
+    }
+unit-week =
+    { $count ->
+        [one] Woche
+       *[other] Wochen
+    }
+    .symbol = Wo.
+
+## Volume
+
+unit-liter = Liter
+unit-milliliter = Milliliter
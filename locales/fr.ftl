@@ -0,0 +1,141 @@
+# French unit names for the `i18n` feature.
+#
+# Each message is `unit-` followed by the unit's English name with every run of
+# characters other than ASCII letters and digits replaced by `-`. The value selects
+# on `$count`, the number as written; the optional `.symbol` attribute replaces the
+# unit symbol where French usage differs.
+
+## Length
+
+unit-meter =
+    { $count ->
+        [one] mètre
+       *[other] mètres
+    }
+unit-kilometer =
+    { $count ->
+        [one] kilomètre
+       *[other] kilomètres
+    }
+unit-centimeter =
+    { $count ->
+        [one] centimètre
+       *[other] centimètres
+    }
+unit-millimeter =
+    { $count ->
+        [one] millimètre
+       *[other] millimètres
+    }
+unit-foot =
+    { $count ->
+        [one] pied
+       *[other] pieds
+    }
+    .symbol = pi
+unit-inch =
+    { $count ->
+        [one] pouce
+       *[other] pouces
+    }
+    .symbol = po
+unit-mile =
+    { $count ->
+        [one] mille
+       *[other] milles
+    }
+unit-nautical-mile =
+    { $count ->
+        [one] mille marin
+       *[other] milles marins
+    }
+    .symbol = M
+
+## Mass
+
+unit-kilogram =
+    { $count ->
+        [one] kilogramme
+       *[other] kilogrammes
+    }
+unit-gram =
+    { $count ->
+        [one] gramme
+       *[other] grammes
+    }
+unit-milligram =
+    { $count ->
+        [one] milligramme
+       *[other] milligrammes
+    }
+unit-pound =
+    { $count ->
+        [one] livre
+       *[other] livres
+    }
+
+## Temperature
+
+unit-kelvin =
+    { $count ->
+        [one] kelvin
+       *[other] kelvins
+    }
+unit-degree-celsius =
+    { $count ->
+        [one] degré Celsius
+       *[other] degrés Celsius
+    }
+unit-degree-fahrenheit =
+    { $count ->
+        [one] degré Fahrenheit
+       *[other] degrés Fahrenheit
+    }
+
+## Time
+
+unit-second =
+    { $count ->
+        [one] seconde
+       *[other] secondes
+    }
+unit-minute =
+    { $count ->
+        [one] minute
+       *[other] minutes
+    }
+unit-hour =
+    { $count ->
+        [one] heure
+       *[other] heures
+    }
+unit-day =
+    { $count ->
+        [one] jour
+       *[other] jours
+    }
+    .symbol = j
+unit-week =
+    { $count ->
+        [one] semaine
+       *[other] semaines
+    }
+    .symbol = sem.
+unit-fortnight =
+    { $count ->
+        [one] quinzaine
+       *[other] quinzaines
+    }
+
+## Volume
+
+unit-liter =
+    { $count ->
+        [one] litre
+       *[other] litres
+    }
+unit-milliliter =
+    { $count ->
+        [one] millilitre
+       *[other] millilitres
+    }
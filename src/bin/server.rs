@@ -0,0 +1,184 @@
+//! HTTP conversion service.
+//!
+//! Exposes the unit registry over HTTP so that several services can share one source
+//! of truth for conversion factors:
+//!
+//! - `GET /convert?value=5&from=km&to=ft` converts a value between two units
+//! - `GET /units` lists every dimension and its units; `?dimension=length` narrows it
+//!
+//! Run with `cargo run --features server --bin uom-server [ADDRESS]`. The address
+//! defaults to `127.0.0.1:8080`.
+
+use std::collections::HashMap;
+
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde_json::{Value, json};
+use uom::unit_conversion::UnitError;
+use uom::unit_conversion::registry::{self, DimensionInfo};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+type Response = (StatusCode, Json<Value>);
+type Params = Query<HashMap<String, String>>;
+
+#[tokio::main]
+async fn main() {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = match tokio::net::TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("cannot listen on {address}: {err}");
+            std::process::exit(1);
+        }
+    };
+    println!("listening on http://{address}");
+    if let Err(err) = axum::serve(listener, app()).await {
+        eprintln!("server error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/convert",
+            get(|Query(params): Params| async move { convert(&params) }),
+        )
+        .route(
+            "/units",
+            get(|Query(params): Params| async move { units(&params) }),
+        )
+}
+
+/// Handles `/convert`, reporting bad input as a 400 with the error message.
+fn convert(params: &HashMap<String, String>) -> Response {
+    let param = |name: &str| {
+        params.get(name).map(String::as_str).ok_or_else(|| {
+            error(
+                StatusCode::BAD_REQUEST,
+                format!("missing parameter '{name}'"),
+            )
+        })
+    };
+    let result = (|| {
+        let (value, from, to) = (param("value")?, param("from")?, param("to")?);
+        let value: f64 = value
+            .trim()
+            .parse()
+            .map_err(|_| error(StatusCode::BAD_REQUEST, format!("invalid number '{value}'")))?;
+        registry::convert(value, from, to)
+            .map(|conversion| {
+                json!({
+                    "dimension": conversion.dimension,
+                    "value": conversion.value,
+                    "unit": conversion.to.symbol,
+                    "input": { "value": value, "unit": conversion.from.symbol },
+                })
+            })
+            .map_err(|err: UnitError| error(StatusCode::BAD_REQUEST, err.to_string()))
+    })();
+    match result {
+        Ok(body) => (StatusCode::OK, Json(body)),
+        Err(response) => response,
+    }
+}
+
+/// Handles `/units`, optionally limited to one dimension.
+fn units(params: &HashMap<String, String>) -> Response {
+    let dimensions: Vec<&DimensionInfo> = match params.get("dimension") {
+        Some(name) => match registry::dimension(name) {
+            Some(dimension) => vec![dimension],
+            None => {
+                return error(StatusCode::NOT_FOUND, format!("unknown dimension '{name}'"));
+            }
+        },
+        None => registry::DIMENSIONS.iter().collect(),
+    };
+    let body = dimensions
+        .into_iter()
+        .map(|dimension| {
+            let units = dimension
+                .all_units()
+                .map(|unit| {
+                    json!({
+                        "symbol": unit.symbol,
+                        "name": unit.name,
+                        "plural": unit.plural,
+                        "aliases": unit.aliases,
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({ "name": dimension.name, "units": units })
+        })
+        .collect::<Vec<_>>();
+    (StatusCode::OK, Json(json!({ "dimensions": body })))
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    (status, Json(json!({ "error": message })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn converts_between_units() {
+        let (status, Json(body)) = convert(&params(&[("value", "5"), ("from", "km"), ("to", "m")]));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["dimension"], "length");
+        assert_eq!(body["value"], 5000.0);
+        assert_eq!(body["unit"], "m");
+        assert_eq!(body["input"]["unit"], "km");
+    }
+
+    #[test]
+    fn reports_bad_requests() {
+        let (status, Json(body)) = convert(&params(&[("value", "5"), ("from", "km")]));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "missing parameter 'to'");
+
+        let (status, _) = convert(&params(&[("value", "five"), ("from", "km"), ("to", "m")]));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, Json(body)) =
+            convert(&params(&[("value", "5"), ("from", "km"), ("to", "kg")]));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body["error"],
+            "expected a length unit but found a mass unit"
+        );
+    }
+
+    #[test]
+    fn lists_units() {
+        let (status, Json(body)) = units(&HashMap::new());
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["dimensions"].as_array().unwrap().len(),
+            registry::DIMENSIONS.len()
+        );
+
+        let (_, Json(body)) = units(&params(&[("dimension", "temperature")]));
+        assert_eq!(body["dimensions"][0]["units"][0]["symbol"], "K");
+        assert_eq!(
+            body["dimensions"][0]["units"][1]["plural"],
+            "degrees Celsius"
+        );
+
+        let (status, _) = units(&params(&[("dimension", "flavor")]));
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
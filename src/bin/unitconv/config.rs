@@ -0,0 +1,257 @@
+//! The `unitconv.toml` preferences file.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
+use uom::unit_conversion::locale::UnitPreferences;
+use uom::unit_conversion::parse::NumberLocale;
+use uom::unit_conversion::precision::PrecisionPolicy;
+use uom::unit_conversion::registry::{self, Measurement};
+use uom::unit_conversion::system::UnitSystem;
+
+const CONFIG_FILE: &str = "unitconv.toml";
+const DEFAULT_PRECISION: usize = 4;
+const DEFAULT_MAX_RATE_AGE_HOURS: u64 = 24;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    precision: Option<usize>,
+    pub locale: Locale,
+    #[serde(deserialize_with = "unit_system")]
+    system: Option<UnitSystem>,
+    #[serde(deserialize_with = "region")]
+    region: Option<UnitPreferences>,
+    units: HashMap<String, String>,
+    decimals: HashMap<String, usize>,
+    pub rates: RatesConfig,
+    #[serde(skip)]
+    policy: PrecisionPolicy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            locale: Locale::default(),
+            system: None,
+            region: None,
+            units: HashMap::new(),
+            decimals: HashMap::new(),
+            rates: RatesConfig::default(),
+            // Money shows its minor unit, cents for dollars, unless `[decimals]` says
+            policy: PrecisionPolicy::new().with_currency_minor_units(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Plain,
+    English,
+    European,
+}
+
+fn unit_system<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<UnitSystem>, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    name.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+fn region<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<UnitPreferences>, D::Error> {
+    let tag = String::deserialize(deserializer)?;
+    Ok(Some(match tag.as_str() {
+        "auto" => UnitPreferences::from_env(),
+        _ => UnitPreferences::for_locale(&tag),
+    }))
+}
+
+/// The `[rates]` table, configuring currency conversion.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RatesConfig {
+    /// Shell command printing the latest rates as JSON.
+    pub command: Option<String>,
+    /// Currency code that amounts convert to when no target is given.
+    pub currency: Option<String>,
+    max_age_hours: Option<u64>,
+}
+
+impl RatesConfig {
+    /// Returns how old cached rates may get before a warning is shown.
+    pub fn max_age(&self) -> Duration {
+        let hours = self.max_age_hours.unwrap_or(DEFAULT_MAX_RATE_AGE_HOURS);
+        Duration::from_secs(hours * 3600)
+    }
+}
+
+impl Config {
+    /// Parses a configuration file, checking that every preferred unit exists.
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let mut config: Self = toml::from_str(text).map_err(|err| err.message().to_string())?;
+        for (name, unit) in &config.units {
+            let dimension =
+                registry::dimension(name).ok_or_else(|| format!("unknown dimension '{name}'"))?;
+            if dimension.find_unit(unit).is_none() {
+                return Err(format!("'{unit}' is not a {name} unit"));
+            }
+        }
+        let mut policy = config.policy.clone();
+        for (name, &decimals) in &config.decimals {
+            policy = match name.as_str() {
+                "currency" => policy.with_currency_decimals(decimals),
+                _ => policy
+                    .with_dimension_name(name, decimals)
+                    .map_err(|_| format!("unknown dimension '{name}'"))?,
+            };
+        }
+        config.policy = policy;
+        Ok(config)
+    }
+
+    /// Returns the default location of the configuration file.
+    pub fn default_path() -> Option<PathBuf> {
+        Some(user_dir("XDG_CONFIG_HOME", ".config")?.join(CONFIG_FILE))
+    }
+
+    /// Reads the file given with `--config`, or else the one at [`default_path`].
+    ///
+    /// Only a missing file at the default location means "no configuration"; a given
+    /// file that cannot be read, or any other failure to read the default one, is an
+    /// error.
+    ///
+    /// [`default_path`]: Config::default_path
+    pub fn load(path: Option<PathBuf>) -> Result<Self, String> {
+        match path {
+            Some(path) => Self::read(&path, true),
+            None => Self::default_path()
+                .map_or_else(|| Ok(Self::default()), |path| Self::read(&path, false)),
+        }
+    }
+
+    fn read(path: &Path, required: bool) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text),
+            Err(err) if !required && err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.to_string()),
+        }
+        .map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn number_locale(&self) -> NumberLocale {
+        match self.locale {
+            Locale::Plain => NumberLocale::PLAIN,
+            Locale::English => NumberLocale::ENGLISH,
+            Locale::European => NumberLocale::EUROPEAN,
+        }
+    }
+
+    /// Writes `value`, a quantity of the dimension named `dimension`, with the
+    /// configured precision and decimal separator.
+    pub fn format_number(&self, value: f64, dimension: &str) -> String {
+        self.format(value, self.policy.decimals(dimension))
+    }
+
+    /// Writes `value`, an amount of the currency with code `code`, with the configured
+    /// precision, by default the currency's minor unit, and decimal separator.
+    pub fn format_money(&self, value: f64, code: &str) -> String {
+        self.format(value, self.policy.currency_decimals(code))
+    }
+
+    fn format(&self, value: f64, decimals: Option<usize>) -> String {
+        let decimals = decimals.or(self.precision).unwrap_or(DEFAULT_PRECISION);
+        let number = uom::smart!(value, decimals).to_string();
+        match self.locale {
+            Locale::European => number.replace('.', ","),
+            Locale::Plain | Locale::English => number,
+        }
+    }
+
+    /// Picks the output unit for `measurement`.
+    ///
+    /// A unit listed under `[units]` wins, then the `system` default, then the
+    /// `region`'s unit scaled to the value. The input unit is kept when it already
+    /// belongs to the system or region.
+    pub fn preferred_unit<'a>(&'a self, measurement: &Measurement) -> &'a str {
+        let dimension = measurement.dimension.name;
+        let unit = measurement.unit.symbol;
+        if let Some(preferred) = self.units.get(dimension) {
+            return preferred;
+        }
+        match (self.system, &self.region) {
+            (Some(system), _) if !system.contains(dimension, unit) => {
+                system.unit_for(dimension).unwrap_or(unit)
+            }
+            (None, Some(region)) if !region.contains(dimension, unit) => {
+                let base = (measurement.unit.to_base)(measurement.value);
+                region
+                    .autoscale_in(measurement.dimension, base)
+                    .map_or(unit, |preferred| preferred.symbol)
+            }
+            _ => unit,
+        }
+    }
+}
+
+/// Returns the XDG base directory named by `variable`, falling back to `fallback`
+/// under the home directory.
+pub fn user_dir(variable: &str, fallback: impl AsRef<OsStr>) -> Option<PathBuf> {
+    match std::env::var_os(variable) {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => Some(PathBuf::from(std::env::var_os("HOME")?).join(fallback.as_ref())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_bad_configuration() {
+        assert_eq!(
+            Config::from_toml("[units]\nlength = \"kg\"").unwrap_err(),
+            "'kg' is not a length unit"
+        );
+        assert_eq!(
+            Config::from_toml("[units]\nflavor = \"mint\"").unwrap_err(),
+            "unknown dimension 'flavor'"
+        );
+        assert!(Config::from_toml("system = \"martian\"").is_err());
+        assert!(Config::from_toml("colour = true").is_err());
+    }
+
+    #[test]
+    fn reads_rate_settings() {
+        let config = Config::from_toml("[rates]\ncurrency = \"EUR\"\nmax_age_hours = 2").unwrap();
+        assert_eq!(config.rates.currency.as_deref(), Some("EUR"));
+        assert_eq!(config.rates.max_age(), Duration::from_secs(7200));
+        assert_eq!(
+            Config::default().rates.max_age(),
+            Duration::from_secs(86_400)
+        );
+    }
+
+    #[test]
+    fn reports_unreadable_files() {
+        let dir = std::env::temp_dir().join(format!("unitconv-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let missing = dir.join("missing.toml");
+        assert!(Config::read(&missing, false).is_ok());
+        let err = Config::load(Some(missing.clone())).unwrap_err();
+        assert!(err.starts_with(&missing.display().to_string()));
+        // A directory exists but is not a readable file
+        assert!(Config::read(&dir, false).is_err());
+
+        let file = dir.join("unitconv.toml");
+        std::fs::write(&file, "precision = 2").unwrap();
+        assert_eq!(Config::load(Some(file.clone())).unwrap().precision, Some(2));
+        std::fs::write(&file, "precision = \"two\"").unwrap();
+        assert!(Config::read(&file, false).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,355 @@
+//! Command-line unit converter.
+//!
+//! ```text
+//! unitconv 100 km          # converts to the configured unit for lengths
+//! unitconv 100 km to ft    # converts to an explicit unit
+//! unitconv 20 USD to EUR   # converts money with the cached exchange rates
+//! unitconv rates update    # refreshes the exchange-rate cache
+//! unitconv calc "2 * (3 ft + 10 cm) in m"
+//! ```
+//!
+//! `calc` evaluates an expression with [`uom::unit_conversion::expr`]; on an error it
+//! prints the expression with the offending part underlined.
+//!
+//! Preferences are read from `$XDG_CONFIG_HOME/unitconv.toml`, falling back to
+//! `~/.config/unitconv.toml`, or from the file given with `--config PATH`. Without a
+//! file at the default location the defaults apply, but a file that exists and cannot
+//! be read, or a `--config` file that cannot be read at all, is an error. Every key is
+//! optional:
+//!
+//! ```toml
+//! precision = 2          # decimal places shown, default 4
+//! locale = "european"    # "plain", "english" or "european" number format
+//! system = "imperial"    # "metric", "us", "imperial" or "cgs" default units
+//! region = "en-GB"       # locale whose units to prefer, or "auto" for $LANG
+//!
+//! [units]                # preferred output unit per dimension
+//! temperature = "°C"
+//!
+//! [decimals]             # decimal places per dimension, overriding `precision`
+//! temperature = 1
+//! currency = 2           # default: the currency's minor unit, 0 for JPY
+//!
+//! [rates]
+//! command = "curl -s https://open.er-api.com/v6/latest/USD"
+//! currency = "EUR"       # default target for amounts of money
+//! max_age_hours = 24     # warn when the cached rates are older
+//! ```
+//!
+//! Without an explicit target, the output unit is the one listed under `[units]` for
+//! the quantity's dimension, then the `system` default when the input is not already
+//! in that system, then the `region`'s unit in a size that keeps the number readable,
+//! and otherwise the input unit.
+//!
+//! Run with `cargo run --features cli --bin unitconv -- 100 km`.
+
+mod config;
+mod rates;
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use uom::unit_conversion::currency::ExchangeRates;
+use uom::unit_conversion::{expr, registry};
+
+use crate::config::Config;
+
+const USAGE: &str =
+    "usage: unitconv [--config PATH] QUANTITY [to UNIT] | calc EXPRESSION | rates update";
+
+/// The result of a conversion, with a warning to show alongside it.
+#[derive(Debug, PartialEq)]
+struct Reply {
+    output: String,
+    warning: Option<String>,
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let path = match args.iter().position(|arg| arg == "--config") {
+        Some(at) if at + 1 < args.len() => {
+            Some(PathBuf::from(args.drain(at..at + 2).nth(1).unwrap()))
+        }
+        Some(_) => fail("--config needs a path"),
+        None => None,
+    };
+    let config = Config::load(path).unwrap_or_else(|err| fail(&err));
+    if args.first().map(String::as_str) == Some("rates") {
+        let result = match args.get(1).map(String::as_str) {
+            Some("update") => cache_path().and_then(|cache| {
+                rates::provider(&config.rates).and_then(|provider| rates::update(&provider, &cache))
+            }),
+            _ => Err(USAGE.to_string()),
+        };
+        match result {
+            Ok(summary) => println!("{summary}"),
+            Err(err) => fail(&err),
+        }
+        return;
+    }
+
+    if args.first().map(String::as_str) == Some("calc") {
+        match calc(&args[1..], &config) {
+            Ok(output) => println!("{output}"),
+            Err(err) => fail(&err),
+        }
+        return;
+    }
+
+    let cached = || cache_path().and_then(|cache| rates::load(&cache));
+    match run(&args, &config, cached, SystemTime::now()) {
+        Ok(reply) => {
+            if let Some(warning) = reply.warning {
+                eprintln!("unitconv: warning: {warning}");
+            }
+            println!("{}", reply.output);
+        }
+        Err(err) => fail(&err),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("unitconv: {message}");
+    std::process::exit(1);
+}
+
+fn cache_path() -> Result<PathBuf, String> {
+    rates::cache_path().ok_or_else(|| "cannot locate the cache directory".to_string())
+}
+
+/// Converts the quantity in `args`, optionally followed by `to UNIT`.
+///
+/// Amounts of money are converted with the cached exchange rates returned by `rates`,
+/// which is only called for money, so a missing or broken cache cannot get in the way
+/// of other conversions.
+fn run(
+    args: &[String],
+    config: &Config,
+    rates: impl FnOnce() -> Result<Option<ExchangeRates>, String>,
+    now: SystemTime,
+) -> Result<Reply, String> {
+    let (quantity, target) = match args.iter().position(|arg| arg == "to") {
+        Some(at) => (&args[..at], Some(args[at + 1..].join(" "))),
+        None => (args, None),
+    };
+    if quantity.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let quantity = quantity.join(" ");
+
+    let measurement = match registry::parse(&quantity, config.number_locale()) {
+        Ok(measurement) => measurement,
+        Err(err) => {
+            return match split_money(&quantity, config) {
+                Some((amount, code)) => {
+                    convert_money(amount, code, target, config, rates()?.as_ref(), now)
+                }
+                None => Err(err.to_string()),
+            };
+        }
+    };
+    let from = measurement.unit.symbol;
+    let to = match &target {
+        Some(target) => target.as_str(),
+        None => config.preferred_unit(&measurement),
+    };
+    let conversion =
+        registry::convert(measurement.value, from, to).map_err(|err| err.to_string())?;
+    Ok(Reply {
+        output: format!(
+            "{} {}",
+            config.format_number(conversion.value, conversion.dimension),
+            conversion.to.symbol
+        ),
+        warning: None,
+    })
+}
+
+/// Evaluates the expression in `args`, which may be split across several arguments.
+fn calc(args: &[String], config: &Config) -> Result<String, String> {
+    let input = args.join(" ");
+    if input.trim().is_empty() {
+        return Err(USAGE.to_string());
+    }
+    match expr::evaluate(&input) {
+        Ok(value) => Ok(match value.unit {
+            Some((dimension, unit)) => format!(
+                "{} {}",
+                config.format_number(value.value, dimension.name),
+                unit.symbol
+            ),
+            None => config.format_number(value.value, ""),
+        }),
+        Err(err) => {
+            let annotated = err.annotate(&input).replace('\n', "\n  ");
+            Err(format!("{err}\n  {annotated}"))
+        }
+    }
+}
+
+/// Splits text such as `20 USD` into the amount and a three-letter currency code.
+fn split_money<'a>(text: &'a str, config: &Config) -> Option<(f64, &'a str)> {
+    let code_start = text
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .len();
+    let code = &text[code_start..];
+    let amount = config.number_locale().parse_number(&text[..code_start])?;
+    (code.len() == 3).then_some((amount, code))
+}
+
+fn convert_money(
+    amount: f64,
+    from: &str,
+    target: Option<String>,
+    config: &Config,
+    rates: Option<&ExchangeRates>,
+    now: SystemTime,
+) -> Result<Reply, String> {
+    let rates = rates.ok_or("no exchange rates cached; run `unitconv rates update`")?;
+    let to = target
+        .or_else(|| config.rates.currency.clone())
+        .unwrap_or_else(|| from.to_string())
+        .to_ascii_uppercase();
+    let value = rates
+        .convert(amount, from, &to)
+        .map_err(|err| err.to_string())?;
+    Ok(Reply {
+        output: format!("{} {to}", config.format_money(value, &to)),
+        warning: rates::staleness_warning(rates, config.rates.max_age(), now),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn convert(line: &str, config: &str) -> Result<String, String> {
+        let args: Vec<String> = line.split(' ').map(String::from).collect();
+        let reply = run(
+            &args,
+            &Config::from_toml(config).unwrap(),
+            || Ok(None),
+            SystemTime::now(),
+        )?;
+        Ok(reply.output)
+    }
+
+    #[test]
+    fn converts_to_an_explicit_unit() {
+        assert_eq!(convert("100 km to m", "").unwrap(), "100000 m");
+        assert_eq!(convert("212 °F to celsius", "").unwrap(), "100 °C");
+        assert!(convert("100 km to kg", "").is_err());
+    }
+
+    #[test]
+    fn follows_the_unit_system() {
+        let imperial = "system = \"imperial\"\nprecision = 2";
+        assert_eq!(convert("100 km", imperial).unwrap(), "62.14 mi");
+        assert_eq!(convert("3 ft", imperial).unwrap(), "3 ft");
+        assert_eq!(convert("20 °C", imperial).unwrap(), "68 °F");
+        assert_eq!(convert("100 km", "system = \"metric\"").unwrap(), "100 km");
+        assert_eq!(convert("100 km", "").unwrap(), "100 km");
+    }
+
+    #[test]
+    fn preferred_units_override_the_system() {
+        let config = "system = \"imperial\"\n[units]\nlength = \"ft\"";
+        assert_eq!(convert("1 km", config).unwrap(), "3280.8399 ft");
+        assert_eq!(convert("1 km to m", config).unwrap(), "1000 m");
+    }
+
+    #[test]
+    fn follows_the_region() {
+        let uk = "region = \"en_GB.UTF-8\"\nprecision = 2";
+        assert_eq!(convert("10 km", uk).unwrap(), "6.21 mi");
+        assert_eq!(convert("300 m", uk).unwrap(), "984.25 ft");
+        assert_eq!(convert("20 °C", uk).unwrap(), "20 °C");
+        assert_eq!(convert("20 °C", "region = \"US\"").unwrap(), "68 °F");
+        assert_eq!(convert("2 mi", "region = \"de-DE\"").unwrap(), "3.2187 km");
+        let both = "region = \"US\"\nsystem = \"metric\"";
+        assert_eq!(convert("2 mi", both).unwrap(), "3.2187 km");
+    }
+
+    #[test]
+    fn rounds_per_dimension() {
+        let config = "precision = 3\n[decimals]\ntemperature = 1\nlength = 0";
+        assert_eq!(convert("70 °F to °C", config).unwrap(), "21.1 °C");
+        assert_eq!(convert("1 mi to m", config).unwrap(), "1609 m");
+        assert_eq!(convert("1 lb to kg", config).unwrap(), "0.454 kg");
+        assert_eq!(
+            Config::from_toml("[decimals]\nflavor = 1").unwrap_err(),
+            "unknown dimension 'flavor'"
+        );
+    }
+
+    #[test]
+    fn applies_the_locale() {
+        let config = "locale = \"european\"\nsystem = \"imperial\"\nprecision = 1";
+        assert_eq!(convert("1,5 km", config).unwrap(), "0,9 mi");
+    }
+
+    #[test]
+    fn calculates_expressions() {
+        let config = Config::from_toml("precision = 3").unwrap();
+        let calc = |line: &str| calc(&[line.to_string()], &config);
+        assert_eq!(calc("2 * (3 ft + 10 cm) in m").unwrap(), "2.029 m");
+        assert_eq!(calc("1 + 2").unwrap(), "3");
+        assert_eq!(
+            calc("3 ft + 2 furlongs").unwrap_err(),
+            "unknown unit 'furlongs'\n  3 ft + 2 furlongs\n           ^^^^^^^^"
+        );
+        assert!(calc(" ").is_err());
+    }
+
+    #[test]
+    fn converts_money_with_cached_rates() {
+        let day = Duration::from_secs(86_400);
+        let mut rates = ExchangeRates::new("USD", SystemTime::UNIX_EPOCH);
+        rates.set_rate("EUR", 0.8).unwrap();
+        let config = Config::from_toml("precision = 2\n[rates]\ncurrency = \"eur\"").unwrap();
+        let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+
+        let fresh = SystemTime::UNIX_EPOCH + day / 2;
+        let cached = |rates: &ExchangeRates| {
+            let rates = rates.clone();
+            move || Ok(Some(rates))
+        };
+        let reply = run(&args("20 USD"), &config, cached(&rates), fresh).unwrap();
+        assert_eq!(
+            reply,
+            Reply {
+                output: "16 EUR".to_string(),
+                warning: None
+            }
+        );
+
+        let old = SystemTime::UNIX_EPOCH + day * 3;
+        let reply = run(&args("8 eur to usd"), &config, cached(&rates), old).unwrap();
+        assert_eq!(reply.output, "10 USD");
+        assert!(reply.warning.unwrap().contains("3 days old"));
+
+        assert!(run(&args("8 EUR to SEK"), &config, cached(&rates), fresh).is_err());
+        rates.set_rate("JPY", 150.0).unwrap();
+        let reply = run(&args("10.01 usd to jpy"), &config, cached(&rates), fresh).unwrap();
+        assert_eq!(reply.output, "1502 JPY");
+        assert_eq!(
+            run(&args("8 EUR"), &config, || Ok(None), fresh).unwrap_err(),
+            "no exchange rates cached; run `unitconv rates update`"
+        );
+    }
+
+    #[test]
+    fn reads_the_rate_cache_only_for_money() {
+        let config = Config::default();
+        let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+        let broken = || Err("rates.toml: invalid TOML".to_string());
+        let reply = run(&args("100 km to m"), &config, broken, SystemTime::now()).unwrap();
+        assert_eq!(reply.output, "100000 m");
+        assert_eq!(
+            run(&args("20 USD"), &config, broken, SystemTime::now()).unwrap_err(),
+            "rates.toml: invalid TOML"
+        );
+    }
+}
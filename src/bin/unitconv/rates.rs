@@ -0,0 +1,246 @@
+//! Fetching exchange rates and caching them between runs.
+//!
+//! `unitconv rates update` asks a [`RateProvider`] for the latest rates and writes
+//! them to `$XDG_CACHE_HOME/unitconv/rates.toml` (or `~/.cache/unitconv/rates.toml`).
+//! Currency conversions read that cache and never go to the network.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use uom::unit_conversion::currency::ExchangeRates;
+
+use crate::config::{RatesConfig, user_dir};
+
+/// A source of current exchange rates.
+pub trait RateProvider {
+    /// Fetches the latest rates, stamped with `now`.
+    fn fetch(&self, now: SystemTime) -> Result<ExchangeRates, String>;
+}
+
+/// Runs a shell command that prints rates as JSON, such as
+/// `curl -s https://open.er-api.com/v6/latest/USD`.
+///
+/// The output must be an object with a `base` (or `base_code`) currency code and a
+/// `rates` object mapping codes to units per one unit of the base; other fields are
+/// ignored.
+pub struct CommandProvider {
+    pub command: String,
+}
+
+impl RateProvider for CommandProvider {
+    fn fetch(&self, now: SystemTime) -> Result<ExchangeRates, String> {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(&self.command)
+            .output()
+            .map_err(|err| format!("cannot run '{}': {err}", self.command))?;
+        if !output.status.success() {
+            return Err(format!(
+                "'{}' failed: {}",
+                self.command,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        parse_json(&String::from_utf8_lossy(&output.stdout), now)
+    }
+}
+
+/// Returns the provider configured in the `[rates]` table.
+pub fn provider(config: &RatesConfig) -> Result<impl RateProvider, String> {
+    match &config.command {
+        Some(command) => Ok(CommandProvider {
+            command: command.clone(),
+        }),
+        None => Err("no rate provider configured; set `command` under [rates]".to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+struct ProviderResponse {
+    #[serde(alias = "base_code")]
+    base: String,
+    rates: BTreeMap<String, f64>,
+}
+
+fn parse_json(text: &str, now: SystemTime) -> Result<ExchangeRates, String> {
+    let response: ProviderResponse =
+        serde_json::from_str(text).map_err(|err| format!("unexpected rate data: {err}"))?;
+    build(&response.base, &response.rates, now)
+}
+
+fn build(
+    base: &str,
+    rates: &BTreeMap<String, f64>,
+    fetched_at: SystemTime,
+) -> Result<ExchangeRates, String> {
+    let mut table = ExchangeRates::new(base, fetched_at);
+    for (code, &rate) in rates {
+        table
+            .set_rate(code, rate)
+            .map_err(|err| format!("bad rate for {code}: {err}"))?;
+    }
+    Ok(table)
+}
+
+/// The on-disk form of the cache.
+#[derive(Deserialize, Serialize)]
+struct Cache {
+    base: String,
+    /// Seconds since the Unix epoch.
+    fetched_at: u64,
+    rates: BTreeMap<String, f64>,
+}
+
+/// Returns the location of the rate cache.
+pub fn cache_path() -> Option<PathBuf> {
+    Some(user_dir("XDG_CACHE_HOME", ".cache")?.join("unitconv/rates.toml"))
+}
+
+pub fn to_cache(rates: &ExchangeRates) -> String {
+    let cache = Cache {
+        base: rates.base().to_string(),
+        fetched_at: rates
+            .fetched_at()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs()),
+        rates: rates
+            .rates()
+            .map(|(code, rate)| (code.to_string(), rate))
+            .collect(),
+    };
+    toml::to_string(&cache).expect("rates serialize to TOML")
+}
+
+pub fn from_cache(text: &str) -> Result<ExchangeRates, String> {
+    let cache: Cache = toml::from_str(text).map_err(|err| err.message().to_string())?;
+    let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(cache.fetched_at);
+    build(&cache.base, &cache.rates, fetched_at)
+}
+
+/// Reads the cache at `path`, or `None` if there is none yet.
+pub fn load(path: &Path) -> Result<Option<ExchangeRates>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => from_cache(&text)
+            .map(Some)
+            .map_err(|err| format!("{}: {err}", path.display())),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Fetches rates from `provider` and writes them to `path`, returning a summary.
+pub fn update(provider: &impl RateProvider, path: &Path) -> Result<String, String> {
+    let rates = provider.fetch(SystemTime::now())?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    }
+    std::fs::write(path, to_cache(&rates)).map_err(|err| format!("{}: {err}", path.display()))?;
+    Ok(format!(
+        "cached {} rates against {} in {}",
+        rates.rates().count(),
+        rates.base(),
+        path.display()
+    ))
+}
+
+/// Describes how old `rates` are if they are older than `max_age`.
+pub fn staleness_warning(
+    rates: &ExchangeRates,
+    max_age: Duration,
+    now: SystemTime,
+) -> Option<String> {
+    if !rates.is_stale(max_age, now) {
+        return None;
+    }
+    let hours = rates.age(now).as_secs() / 3600;
+    let age = if hours < 48 {
+        format!("{hours} hours")
+    } else {
+        format!("{} days", hours / 24)
+    };
+    Some(format!(
+        "exchange rates are {age} old; run `unitconv rates update`"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixed;
+
+    impl RateProvider for Fixed {
+        fn fetch(&self, now: SystemTime) -> Result<ExchangeRates, String> {
+            parse_json(r#"{"base_code": "USD", "rates": {"EUR": 0.8}}"#, now)
+        }
+    }
+
+    #[test]
+    fn reads_provider_json() {
+        let rates = Fixed.fetch(SystemTime::UNIX_EPOCH).unwrap();
+        assert_eq!(rates.base(), "USD");
+        assert_eq!(rates.rate("EUR", "USD"), Some(1.25));
+        assert!(parse_json(r#"{"rates": {}}"#, SystemTime::UNIX_EPOCH).is_err());
+        assert!(
+            parse_json(
+                r#"{"base": "USD", "rates": {"EUR": -1}}"#,
+                SystemTime::UNIX_EPOCH
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn cache_round_trips() {
+        let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let rates = Fixed.fetch(fetched_at).unwrap();
+        assert_eq!(from_cache(&to_cache(&rates)).unwrap(), rates);
+    }
+
+    #[test]
+    fn updates_the_cache_file() {
+        let path = std::env::temp_dir()
+            .join(format!("unitconv-test-{}", std::process::id()))
+            .join("rates.toml");
+        let summary = update(&Fixed, &path).unwrap();
+        assert!(summary.starts_with("cached 2 rates against USD"));
+        assert_eq!(load(&path).unwrap().unwrap().rate("USD", "EUR"), Some(0.8));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(load(&path).unwrap(), None);
+    }
+
+    #[test]
+    fn runs_a_command() {
+        let provider = CommandProvider {
+            command: r#"echo '{"base": "EUR", "rates": {"GBP": 0.85}}'"#.to_string(),
+        };
+        let rates = provider.fetch(SystemTime::UNIX_EPOCH).unwrap();
+        assert_eq!(rates.rate("EUR", "GBP"), Some(0.85));
+        assert!(
+            CommandProvider {
+                command: "exit 3".to_string()
+            }
+            .fetch(SystemTime::UNIX_EPOCH)
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn warns_about_old_rates() {
+        let rates = Fixed.fetch(SystemTime::UNIX_EPOCH).unwrap();
+        let day = Duration::from_secs(86_400);
+        let at = |days| SystemTime::UNIX_EPOCH + day * days;
+        assert_eq!(staleness_warning(&rates, day, at(1)), None);
+        assert_eq!(
+            staleness_warning(&rates, day / 2, at(1)).unwrap(),
+            "exchange rates are 24 hours old; run `unitconv rates update`"
+        );
+        assert!(
+            staleness_warning(&rates, day, at(3))
+                .unwrap()
+                .starts_with("exchange rates are 3 days old")
+        );
+    }
+}
@@ -1,2 +1,16 @@
+//! Type-safe unit conversions.
+//!
+//! See [`unit_conversion`] for the core design and the individual dimension modules.
+//! Most code only needs the [`prelude`]:
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::prelude::*;
+//!
+//! let temp = Temperature::from_unit::<Celsius>(100.0);
+//! assert_eq!(temp.to_unit::<Fahrenheit>(), 212.0);
+//! ```
+
+pub mod prelude;
 pub mod unit_conversion;
-pub mod util;
\ No newline at end of file
+pub mod util;
@@ -1,8 +1,4 @@
-mod unit_conversion;
-
-use unit_conversion::UnitConversion;
-use unit_conversion::length::{Foot, Kilometer, Length, Meter};
-use unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+use uom::prelude::*;
 use uom::smart;
 
 fn main() {
@@ -46,4 +42,4 @@ fn main() {
         smart!(d_km.to_unit::<Foot>(), 5),
         Foot::SYMBOL
     );
-}
\ No newline at end of file
+}
@@ -0,0 +1,77 @@
+//! Convenient re-exports of the commonly used types.
+//!
+//! Glob-importing the prelude brings [`Quantity`], the core traits, every dimension's
+//! quantity type, and the most common units into scope. Only the dimensions enabled
+//! through cargo features are re-exported.
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::prelude::*;
+//!
+//! let distance = Length::from_unit::<Kilometer>(5.0);
+//! assert_eq!(distance.to_unit::<Meter>(), 5000.0);
+//!
+//! let boiling = Temperature::parse("100 °C").unwrap();
+//! assert_eq!(boiling.to_unit::<Kelvin>(), 373.15);
+//! ```
+
+#[cfg(feature = "acceleration")]
+pub use crate::unit_conversion::acceleration::{Acceleration, MeterPerSecondSquared};
+#[cfg(feature = "amount")]
+pub use crate::unit_conversion::amount::{Amount, Mole};
+#[cfg(feature = "angle")]
+pub use crate::unit_conversion::angle::{Angle, Degree, Radian};
+#[cfg(feature = "area")]
+pub use crate::unit_conversion::area::{Area, SquareFoot, SquareMeter};
+#[cfg(feature = "charge")]
+pub use crate::unit_conversion::charge::{AmpereHour, Charge, Coulomb, MilliampereHour};
+#[cfg(feature = "concentration")]
+pub use crate::unit_conversion::concentration::{
+    Analyte, MassConcentration, MilligramPerDeciliter, MillimolePerLiter, MolarConcentration,
+};
+#[cfg(feature = "currency")]
+pub use crate::unit_conversion::currency::{Currency, Money};
+#[cfg(feature = "current")]
+pub use crate::unit_conversion::current::{Ampere, Current, Milliampere};
+#[cfg(feature = "data_rate")]
+pub use crate::unit_conversion::data_rate::{BitPerSecond, DataRate, MegabitPerSecond};
+#[cfg(feature = "data_size")]
+pub use crate::unit_conversion::data_size::{Bit, Byte, DataSize, Megabyte};
+#[cfg(feature = "energy")]
+pub use crate::unit_conversion::energy::{Energy, Joule, KilowattHour};
+#[cfg(feature = "force")]
+pub use crate::unit_conversion::force::{Force, Newton};
+#[cfg(feature = "frequency")]
+pub use crate::unit_conversion::frequency::{Frequency, Hertz, Kilohertz, Megahertz};
+#[cfg(feature = "length")]
+pub use crate::unit_conversion::length::{
+    Centimeter, Foot, Inch, Kilometer, Length, Meter, Mile, Millimeter, NauticalMile,
+};
+#[cfg(feature = "mass")]
+pub use crate::unit_conversion::mass::{Gram, Kilogram, Mass, Milligram, Pound};
+#[cfg(feature = "mass_rate")]
+pub use crate::unit_conversion::mass_rate::{KilogramPerSecond, MassRate};
+#[cfg(feature = "power")]
+pub use crate::unit_conversion::power::{Kilowatt, Power, Watt};
+#[cfg(feature = "pressure")]
+pub use crate::unit_conversion::pressure::{Bar, GaugePressure, Kilopascal, Pascal, Pressure, Psi};
+#[cfg(feature = "ratio")]
+pub use crate::unit_conversion::ratio::{Percent, Ratio, Unity};
+#[cfg(feature = "resistance")]
+pub use crate::unit_conversion::resistance::{Kiloohm, Ohm, Resistance};
+#[cfg(feature = "speed")]
+pub use crate::unit_conversion::speed::{
+    KilometerPerHour, Knot, MeterPerSecond, MilePerHour, Speed,
+};
+#[cfg(feature = "temperature")]
+pub use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+#[cfg(feature = "time")]
+pub use crate::unit_conversion::time::{Day, Hour, Minute, Second, Time, Week};
+#[cfg(feature = "voltage")]
+pub use crate::unit_conversion::voltage::{Volt, Voltage};
+#[cfg(feature = "volume")]
+pub use crate::unit_conversion::volume::{CubicMeter, Liter, Milliliter, Volume};
+pub use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError};
@@ -27,7 +27,11 @@
 //!
 //! ## Example Usage
 //!
-//! ```
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
 //! use uom::unit_conversion::temperature::{Temperature, Celsius, Fahrenheit};
 //! use uom::unit_conversion::length::{Length, Meter, Kilometer};
 //!
@@ -40,10 +44,149 @@
 //! let meter_value = distance.to_unit::<Meter>(); // 5000.0
 //! ```
 
+#[cfg(feature = "acceleration")]
+pub mod acceleration;
+#[cfg(feature = "adc")]
+pub mod adc;
+#[cfg(feature = "amount")]
+pub mod amount;
+#[cfg(feature = "angle")]
+pub mod angle;
+#[cfg(feature = "area")]
+pub mod area;
+#[cfg(feature = "audio")]
+pub mod audio;
+#[cfg(feature = "battery")]
+pub mod battery;
+#[cfg(feature = "beaufort")]
+pub mod beaufort;
+pub mod binary;
+#[cfg(feature = "calculus")]
+pub mod calculus;
+pub mod calibration;
+#[cfg(feature = "charge")]
+pub mod charge;
+#[cfg(feature = "concentration")]
+pub mod concentration;
+#[cfg(feature = "control")]
+pub mod control;
+#[cfg(feature = "cooking")]
+pub mod cooking;
+pub mod csv_schema;
+#[cfg(feature = "currency")]
+pub mod currency;
+#[cfg(feature = "current")]
+pub mod current;
+#[cfg(feature = "data_rate")]
+pub mod data_rate;
+#[cfg(feature = "data_size")]
+pub mod data_size;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+#[cfg(feature = "dosing")]
+pub mod dosing;
+pub mod dual;
+#[cfg(feature = "electrical")]
+pub mod electrical;
+#[cfg(feature = "electricity")]
+pub mod electricity;
+#[cfg(feature = "emissions")]
+pub mod emissions;
+#[cfg(feature = "energy")]
+pub mod energy;
+pub mod error;
+pub mod expr;
+#[cfg(feature = "force")]
+pub mod force;
+pub mod format;
+#[cfg(feature = "frequency")]
+pub mod frequency;
+#[cfg(feature = "geo")]
+pub mod geo;
+#[cfg(feature = "hvac")]
+pub mod hvac;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+#[cfg(feature = "length")]
 pub mod length;
+#[cfg(feature = "light_travel")]
+pub mod light_travel;
+pub mod locale;
+pub mod logarithmic;
+pub mod lookup;
+#[cfg(feature = "mass")]
+pub mod mass;
+#[cfg(feature = "mass_rate")]
+pub mod mass_rate;
+#[cfg(feature = "mechanics")]
+pub mod mechanics;
+pub mod names;
+#[cfg(feature = "navigation")]
+pub mod navigation;
+#[cfg(feature = "network")]
+pub mod network;
+pub mod ordering;
+#[cfg(feature = "paper")]
+pub mod paper;
+pub mod parse;
+#[cfg(feature = "photography")]
+pub mod photography;
+#[cfg(feature = "power")]
+pub mod power;
+pub mod precision;
+#[cfg(feature = "pressure")]
+pub mod pressure;
+pub mod quantity_vec;
+#[cfg(feature = "ratio")]
+pub mod ratio;
+pub mod registry;
+pub mod regression;
+pub mod report;
+#[cfg(feature = "resistance")]
+pub mod resistance;
+pub mod rolling;
+pub mod rounding;
+#[cfg(feature = "seismology")]
+pub mod seismology;
+#[cfg(feature = "sensors")]
+pub mod sensors;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod sigfig;
+#[cfg(feature = "speed")]
+pub mod speed;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod system;
+pub mod tagged;
+#[cfg(feature = "temperature")]
 pub mod temperature;
+pub mod template;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+#[cfg(feature = "thermo")]
+pub mod thermo;
+#[cfg(feature = "time")]
+pub mod time;
+pub mod tolerance;
+#[cfg(feature = "tracing")]
+pub mod trace;
+#[cfg(feature = "serde")]
+pub mod versioned;
+#[cfg(feature = "voltage")]
+pub mod voltage;
+#[cfg(feature = "volume")]
+pub mod volume;
+#[cfg(feature = "wire")]
+pub mod wire;
+
+pub use error::UnitError;
 
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
 use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 /// Defines how a unit type converts to and from base units within its dimension.
 ///
@@ -94,6 +237,252 @@ pub trait UnitConversion {
 
     /// The symbol used to display this unit (e.g., "°C", "m", "ft").
     const SYMBOL: &'static str;
+
+    /// The singular English name of this unit (e.g., "foot", "degree Celsius").
+    ///
+    /// Defaults to the symbol for units that have no spelled-out name.
+    const NAME: &'static str = Self::SYMBOL;
+
+    /// The plural English name of this unit (e.g., "feet", "degrees Celsius").
+    ///
+    /// Defaults to [`NAME`](Self::NAME), which suits units such as "hertz" whose
+    /// plural is unchanged.
+    const PLURAL: &'static str = Self::NAME;
+
+    /// The physically possible values of quantities stored in this unit, in base units,
+    /// as an inclusive `(min, max)` pair.
+    ///
+    /// With the `strict_physics` feature, debug builds panic when a `Quantity<Self>` is
+    /// created or computed outside this range, other than by the arithmetic operators
+    /// (see [`Quantity`]). Defaults to every value. The base units of bounded dimensions
+    /// declare their range, such as `(0.0, f64::INFINITY)` for `Kelvin`; the dimension
+    /// aliases such as `Temperature` store their base unit, so they are checked.
+    const PHYSICAL_RANGE: (f64, f64) = (f64::NEG_INFINITY, f64::INFINITY);
+}
+
+/// Runtime description of a unit within a dimension.
+///
+/// Each dimension lists its units as a table of `UnitInfo` values (see [`Dimension`]),
+/// which lets text be matched to a unit at runtime while still producing a statically
+/// typed [`Quantity`].
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::UnitInfo;
+/// use uom::unit_conversion::length::Kilometer;
+///
+/// let info = UnitInfo::of::<Kilometer>(&["kilometer", "kilometers"]);
+/// assert_eq!(info.symbol, "km");
+/// assert_eq!(info.plural, "kilometers");
+/// assert!(info.matches("KM"));
+/// assert_eq!((info.to_base)(1.5), 1500.0);
+/// ```
+#[derive(Debug)]
+pub struct UnitInfo {
+    /// The unit's display symbol (the unit's `UnitConversion::SYMBOL`).
+    pub symbol: &'static str,
+    /// The unit's singular English name (the unit's `UnitConversion::NAME`).
+    pub name: &'static str,
+    /// The unit's plural English name (the unit's `UnitConversion::PLURAL`).
+    pub plural: &'static str,
+    /// Alternative spellings accepted when parsing (e.g., "meters", "metre").
+    pub aliases: &'static [&'static str],
+    /// Converts a value in this unit to the dimension's base unit.
+    pub to_base: fn(f64) -> f64,
+    /// Converts a value in the dimension's base unit to this unit.
+    pub from_base: fn(f64) -> f64,
+}
+
+impl UnitInfo {
+    /// Builds the table entry for unit type `U` with the given parsing aliases.
+    pub const fn of<U: UnitConversion>(aliases: &'static [&'static str]) -> Self {
+        Self {
+            symbol: U::SYMBOL,
+            name: U::NAME,
+            plural: U::PLURAL,
+            aliases,
+            to_base: U::convert_to,
+            from_base: U::convert_from,
+        }
+    }
+
+    /// Returns true if `text` is exactly this unit's symbol or one of its aliases.
+    pub fn matches_exactly(&self, text: &str) -> bool {
+        self.symbol == text || self.aliases.contains(&text)
+    }
+
+    /// Returns true if `text` is this unit's symbol or one of its aliases,
+    /// ignoring ASCII case.
+    pub fn matches(&self, text: &str) -> bool {
+        self.symbol.eq_ignore_ascii_case(text)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(text))
+    }
+}
+
+/// Runtime metadata for a dimension marker type.
+///
+/// Implemented by each dimension (e.g., `TemperatureDimension`) to list the units it
+/// supports, which enables parsing quantities from text.
+pub trait Dimension {
+    /// The lowercase dimension name used in messages (e.g., "temperature").
+    const NAME: &'static str;
+
+    /// A stable identifier for the dimension, written into binary encodings (see
+    /// [`binary`]). Each dimension has its own, and an identifier is never reused;
+    /// 0 is reserved.
+    const ID: u8;
+
+    /// Every unit of this dimension, base unit first.
+    const UNITS: &'static [UnitInfo];
+
+    /// Names that could mean several units, such as `gallon` for both the US and the
+    /// imperial gallon, each with the unambiguous names to suggest instead.
+    ///
+    /// These names are deliberately not aliases of any unit, so parsing one fails with
+    /// [`UnitError::UnknownUnit`] rather than guessing.
+    const AMBIGUOUS: &'static [(&'static str, &'static [&'static str])] = &[];
+
+    /// Finds the unit matching `text`.
+    ///
+    /// An exact match on a symbol or alias takes precedence over a case-insensitive
+    /// one, so that units differing only in case stay distinguishable.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::Dimension;
+    /// use uom::unit_conversion::temperature::TemperatureDimension;
+    ///
+    /// assert_eq!(TemperatureDimension::find_unit("celsius").unwrap().symbol, "°C");
+    /// assert!(TemperatureDimension::find_unit("furlong").is_none());
+    /// ```
+    fn find_unit(text: &str) -> Option<&'static UnitInfo> {
+        Self::UNITS
+            .iter()
+            .find(|unit| unit.matches_exactly(text))
+            .or_else(|| Self::UNITS.iter().find(|unit| unit.matches(text)))
+    }
+
+    /// Suggests symbols or aliases that `text` may have been a misspelling of.
+    ///
+    /// Candidates are ranked by case-insensitive edit distance, keeping at most one
+    /// name per unit and at most three suggestions overall. Names that are too far
+    /// from `text` to be a plausible typo are not suggested. A name listed in
+    /// [`AMBIGUOUS`](Dimension::AMBIGUOUS) suggests its unambiguous forms instead.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::Dimension;
+    /// use uom::unit_conversion::temperature::TemperatureDimension;
+    ///
+    /// assert_eq!(TemperatureDimension::suggest("Farenheit"), vec!["fahrenheit"]);
+    /// assert!(TemperatureDimension::suggest("furlong").is_empty());
+    /// ```
+    fn suggest(text: &str) -> Vec<&'static str> {
+        if let Some((_, qualified)) = Self::AMBIGUOUS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(text))
+        {
+            return qualified.to_vec();
+        }
+        let max_distance = (text.chars().count() / 3).max(1);
+        let mut candidates: Vec<(usize, &'static str)> = Self::UNITS
+            .iter()
+            .filter_map(|unit| {
+                std::iter::once(&unit.symbol)
+                    .chain(unit.aliases)
+                    .map(|name| (edit_distance(text, name), *name))
+                    .filter(|(distance, _)| *distance <= max_distance)
+                    .min_by_key(|(distance, _)| *distance)
+            })
+            .collect();
+        candidates.sort_by_key(|(distance, _)| *distance);
+        candidates
+            .into_iter()
+            .take(3)
+            .map(|(_, name)| name)
+            .collect()
+    }
+}
+
+/// Case-insensitive Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().flat_map(char::to_lowercase).enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns true unless `base` lies outside `U::PHYSICAL_RANGE` by more than rounding
+/// slack.
+///
+/// NaN passes: it is not a physically impossible value but a missing one, reported by
+/// the `try_` conversions.
+#[cfg(feature = "strict_physics")]
+const fn is_physical<U: UnitConversion>(base: f64) -> bool {
+    const fn slack(bound: f64) -> f64 {
+        1e-12 * if bound.abs() > 1.0 { bound.abs() } else { 1.0 }
+    }
+    let (min, max) = U::PHYSICAL_RANGE;
+    !(base < min - slack(min) || base > max + slack(max))
+}
+
+/// Panics if `base` lies outside `U::PHYSICAL_RANGE` by more than rounding slack.
+#[cfg(all(feature = "strict_physics", debug_assertions))]
+const fn check_physical<U: UnitConversion>(base: f64) {
+    if !is_physical::<U>(base) {
+        // `from_base` is a `const fn`, which cannot format the value into the message
+        panic!("strict_physics: quantity outside its unit's PHYSICAL_RANGE");
+    }
+}
+
+/// Returns `value` if it is finite, or a [`UnitError::NonFinite`] otherwise.
+fn check_finite(value: f64) -> Result<f64, UnitError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(UnitError::NonFinite { value })
+    }
+}
+
+/// Returns the size of one unit `V` in base units, as a difference, so that offsets
+/// such as the one between °C and K drop out.
+///
+/// Multiplying by it converts a difference in `V` to base units; dividing converts back.
+pub(crate) fn unit_span<V: UnitConversion>() -> f64 {
+    V::convert_to(1.0) - V::convert_to(0.0)
+}
+
+/// Returns `value` if it is positive and finite, or a [`UnitError::OutOfRange`] otherwise.
+///
+/// Used by helpers that take logarithms or reciprocals of their input.
+#[allow(dead_code)] // unused when no helper module is enabled
+pub(crate) fn check_positive(value: f64) -> Result<f64, UnitError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(value)
+    } else {
+        Err(UnitError::OutOfRange {
+            value,
+            min: 0.0,
+            max: f64::INFINITY,
+        })
+    }
 }
 
 /// A quantity with a specific unit type, storing values in base units internally.
@@ -109,7 +498,8 @@ pub trait UnitConversion {
 ///
 /// # Examples
 ///
-/// ```
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
 /// use uom::unit_conversion::temperature::{Temperature, Celsius, Fahrenheit};
 ///
 /// // Create a temperature from Celsius
@@ -119,7 +509,23 @@ pub trait UnitConversion {
 /// let f = temp.to_unit::<Fahrenheit>(); // 212.0
 /// let c = temp.to_unit::<Celsius>();    // 100.0
 /// ```
-#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+///
+/// # Physical invariants
+///
+/// With the `strict_physics` feature enabled and debug assertions on, creating or
+/// computing a quantity outside its unit's
+/// [`PHYSICAL_RANGE`](UnitConversion::PHYSICAL_RANGE) panics, to catch unit bugs in
+/// tests: a negative absolute temperature or mass, or a speed faster than light. A
+/// slack of one part in 10¹² absorbs rounding, so converting -459.67 °F does not trip
+/// it. Release builds, and builds without the feature, never check. The fallible
+/// constructors, such as [`try_from_unit`](Quantity::try_from_unit) and
+/// [`parse`](Quantity::parse), return [`UnitError::OutOfRange`] instead, in any build
+/// with the feature.
+///
+/// The arithmetic operators do not check their results: a `Quantity` also holds
+/// differences, and a temperature that fell by 5 K, or half of that drop, is a negative
+/// kelvin value. Dimensions that cannot go negative offer
+/// [`checked_sub`](Quantity::checked_sub) for taking one absolute value from another.
 pub struct Quantity<U: UnitConversion> {
     /// The value stored in base units (e.g., Kelvin for temperature, meters for length)
     base: f64,
@@ -127,6 +533,50 @@ pub struct Quantity<U: UnitConversion> {
     _u: PhantomData<U>,
 }
 
+// The trait impls below are written by hand rather than derived: `derive` would
+// require the zero-sized unit marker `U` itself to implement each trait. The same
+// holds for every other type generic over a unit, such as `SigFig` or `Toleranced`.
+
+impl<U: UnitConversion> Copy for Quantity<U> {}
+
+impl<U: UnitConversion> Clone for Quantity<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> fmt::Debug for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Quantity")
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+/// Formats the quantity for `defmt` logging as its value in `U` followed by the unit
+/// symbol, such as `293.15 K`.
+///
+/// The number is sent as a raw `f64` and formatted on the host, so firmware does not
+/// link `core::fmt` float formatting.
+#[cfg(feature = "defmt")]
+impl<U: UnitConversion> defmt::Format for Quantity<U> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=f64} {=str}", U::convert_from(self.base), U::SYMBOL)
+    }
+}
+
+impl<U: UnitConversion> PartialEq for Quantity<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<U: UnitConversion> PartialOrd for Quantity<U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.base.partial_cmp(&other.base)
+    }
+}
+
 impl<U: UnitConversion> Quantity<U> {
     /// Creates a new quantity from a value in the specified unit type.
     ///
@@ -144,7 +594,8 @@ impl<U: UnitConversion> Quantity<U> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
     /// use uom::unit_conversion::temperature::{Temperature, Celsius, Fahrenheit};
     ///
     /// // Create temperature from Celsius
@@ -160,10 +611,7 @@ impl<U: UnitConversion> Quantity<U> {
     where
         V: UnitConversion<Dimension = U::Dimension>,
     {
-        Self {
-            base: V::convert_to(value),
-            _u: PhantomData,
-        }
+        Self::from_base(V::convert_to(value))
     }
 
     /// Converts this quantity to the specified unit type.
@@ -181,7 +629,8 @@ impl<U: UnitConversion> Quantity<U> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
     /// use uom::unit_conversion::temperature::{Temperature, Celsius, Fahrenheit, Kelvin};
     ///
     /// let temp = Temperature::from_unit::<Celsius>(100.0);
@@ -190,13 +639,110 @@ impl<U: UnitConversion> Quantity<U> {
     /// assert_eq!(temp.to_unit::<Fahrenheit>(), 212.0);
     /// assert_eq!(temp.to_unit::<Kelvin>(), 373.15);
     /// ```
-    pub fn to_unit<V>(&self) -> f64
+    pub fn to_unit<V>(self) -> f64
     where
         V: UnitConversion<Dimension = U::Dimension>,
     {
         V::convert_from(self.base)
     }
 
+    /// Creates a new quantity from a value in the specified unit type, checking
+    /// that both the input and the converted base value are finite.
+    ///
+    /// This is the checked counterpart of [`from_unit`](Self::from_unit), for
+    /// inputs whose magnitude may overflow during conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if `value` or its base-unit equivalent is
+    /// infinite or NaN. With the `strict_physics` feature, returns
+    /// [`UnitError::OutOfRange`], in base units, for a value outside the unit's
+    /// [`PHYSICAL_RANGE`](UnitConversion::PHYSICAL_RANGE) instead of panicking.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::UnitError;
+    /// use uom::unit_conversion::length::{Length, Kilometer};
+    ///
+    /// assert!(Length::try_from_unit::<Kilometer>(3.2).is_ok());
+    /// assert_eq!(
+    ///     Length::try_from_unit::<Kilometer>(1e306),
+    ///     Err(UnitError::NonFinite { value: f64::INFINITY })
+    /// );
+    /// ```
+    pub fn try_from_unit<V>(value: f64) -> Result<Self, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Self::try_from_base(check_finite(V::convert_to(check_finite(value)?))?)
+    }
+
+    /// Converts this quantity to the specified unit type, checking that the
+    /// result is finite.
+    ///
+    /// This is the checked counterpart of [`to_unit`](Self::to_unit).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if the converted value is infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::UnitError;
+    /// use uom::unit_conversion::length::{Length, Meter, Foot};
+    ///
+    /// let huge = Length::from_unit::<Meter>(1e308);
+    /// assert!(huge.try_to_unit::<Meter>().is_ok());
+    /// assert_eq!(
+    ///     huge.try_to_unit::<Foot>(),
+    ///     Err(UnitError::NonFinite { value: f64::INFINITY })
+    /// );
+    /// ```
+    pub fn try_to_unit<V>(self) -> Result<f64, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        check_finite(self.to_unit::<V>())
+    }
+
+    /// Creates a quantity directly from a value in base units.
+    pub(crate) const fn from_base(base: f64) -> Self {
+        #[cfg(all(feature = "strict_physics", debug_assertions))]
+        check_physical::<U>(base);
+        Self::from_difference(base)
+    }
+
+    /// Creates a quantity from a value in base units for a fallible constructor.
+    ///
+    /// With the `strict_physics` feature, a value outside
+    /// [`PHYSICAL_RANGE`](UnitConversion::PHYSICAL_RANGE) is a
+    /// [`UnitError::OutOfRange`] in base units rather than a panic.
+    pub(crate) fn try_from_base(base: f64) -> Result<Self, UnitError> {
+        #[cfg(feature = "strict_physics")]
+        if !is_physical::<U>(base) {
+            let (min, max) = U::PHYSICAL_RANGE;
+            return Err(UnitError::OutOfRange {
+                value: base,
+                min,
+                max,
+            });
+        }
+        Ok(Self::from_base(base))
+    }
+
+    /// Creates a quantity from a value in base units that may be a difference, so is
+    /// not held to [`PHYSICAL_RANGE`](UnitConversion::PHYSICAL_RANGE).
+    const fn from_difference(base: f64) -> Self {
+        Self {
+            base,
+            _u: PhantomData,
+        }
+    }
+
     /// Returns the raw value in base units.
     ///
     /// This is primarily useful for debugging or when you need to access
@@ -205,14 +751,316 @@ impl<U: UnitConversion> Quantity<U> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
     /// use uom::unit_conversion::temperature::{Temperature, Celsius};
     ///
     /// let temp = Temperature::from_unit::<Celsius>(0.0);
     /// assert_eq!(temp.in_base(), 273.15); // 0°C = 273.15K (base unit)
     /// ```
-    #[allow(dead_code)]
     pub fn in_base(&self) -> f64 {
         self.base
     }
 }
+
+/// Returns a zero quantity, zero in the dimension's base unit.
+///
+/// For temperature this is absolute zero, not 0 °C.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Meter};
+///
+/// let total = Length::default();
+/// assert_eq!(total.to_unit::<Meter>(), 0.0);
+/// ```
+impl<U: UnitConversion> Default for Quantity<U> {
+    fn default() -> Self {
+        Self::from_base(0.0)
+    }
+}
+
+impl<U: UnitConversion> Quantity<U> {
+    /// Returns true if the quantity is zero in base units.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter};
+    ///
+    /// assert!(Length::from_unit::<Meter>(-0.0).is_zero());
+    /// assert!(!Length::from_unit::<Meter>(1e-12).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.base == 0.0
+    }
+
+    /// Returns true if the quantity is greater than zero in base units.
+    ///
+    /// Zero and NaN are neither positive nor negative.
+    pub fn is_positive(&self) -> bool {
+        self.base > 0.0
+    }
+
+    /// Returns true if the quantity is less than zero in base units.
+    ///
+    /// Zero and NaN are neither positive nor negative.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Temperature, Celsius};
+    ///
+    /// // Signs are taken in base units, so -10 °C is still a positive temperature
+    /// let cold = Temperature::from_unit::<Celsius>(-10.0);
+    /// assert!(cold.is_positive() && !cold.is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.base < 0.0
+    }
+
+    /// Returns the sign of the quantity in base units: `1.0`, `-1.0`, or NaN for NaN.
+    ///
+    /// Like [`f64::signum`], positive and negative zero return `1.0` and `-1.0`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter};
+    ///
+    /// assert_eq!(Length::from_unit::<Meter>(-3.0).signum(), -1.0);
+    /// assert_eq!(Length::from_unit::<Meter>(2.0).signum(), 1.0);
+    /// ```
+    pub fn signum(&self) -> f64 {
+        self.base.signum()
+    }
+}
+
+/// A dimension whose quantities cannot be negative, such as mass or absolute
+/// temperature.
+///
+/// Implementing this marker enables [`Quantity::checked_sub`], which refuses to produce
+/// a negative result.
+pub trait NonNegative: Dimension {}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: NonNegative,
+{
+    /// Subtracts `other` from this quantity, returning `None` if the result would be
+    /// negative or NaN.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Temperature, Kelvin};
+    ///
+    /// let t = Temperature::from_unit::<Kelvin>(10.0);
+    /// let cooled = t.checked_sub(Temperature::from_unit::<Kelvin>(4.0)).unwrap();
+    /// assert_eq!(cooled.to_unit::<Kelvin>(), 6.0);
+    /// assert!(t.checked_sub(Temperature::from_unit::<Kelvin>(20.0)).is_none());
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let base = self.base - other.base;
+        (base >= 0.0).then(|| Self::from_base(base))
+    }
+}
+
+// Arithmetic works on the base values, so the operands may be in different units of
+// the same dimension and the result takes the unit of the left-hand side.
+
+/// Adds two quantities of the same dimension.
+///
+/// Values are added in base units. For temperature that is kelvin, so adding two
+/// absolute temperatures adds their distances from absolute zero; to raise a
+/// temperature by a difference, add the difference in kelvin.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Foot, Length, Meter};
+///
+/// let total = Length::from_unit::<Meter>(1.0) + Length::from_unit::<Foot>(1.0);
+/// assert!((total.to_unit::<Meter>() - 1.3048).abs() < 1e-12);
+/// ```
+impl<U, V> Add<Quantity<V>> for Quantity<U>
+where
+    U: UnitConversion,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    type Output = Self;
+
+    fn add(self, other: Quantity<V>) -> Self {
+        Self::from_difference(self.base + other.in_base())
+    }
+}
+
+/// Subtracts a quantity of the same dimension.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Centimeter, Length, Meter};
+///
+/// let offcut = Length::from_unit::<Meter>(2.0) - Length::from_unit::<Centimeter>(35.0);
+/// assert!((offcut.to_unit::<Centimeter>() - 165.0).abs() < 1e-9);
+/// ```
+impl<U, V> Sub<Quantity<V>> for Quantity<U>
+where
+    U: UnitConversion,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Quantity<V>) -> Self {
+        Self::from_difference(self.base - other.in_base())
+    }
+}
+
+impl<U, V> AddAssign<Quantity<V>> for Quantity<U>
+where
+    U: UnitConversion,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    fn add_assign(&mut self, other: Quantity<V>) {
+        *self = *self + other;
+    }
+}
+
+impl<U, V> SubAssign<Quantity<V>> for Quantity<U>
+where
+    U: UnitConversion,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    fn sub_assign(&mut self, other: Quantity<V>) {
+        *self = *self - other;
+    }
+}
+
+/// Sums quantities, starting from the zero of [`Default`].
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Kilometer, Length, Meter};
+///
+/// let legs = [
+///     Length::from_unit::<Kilometer>(1.5),
+///     Length::from_unit::<Meter>(400.0),
+///     Length::from_unit::<Kilometer>(2.1),
+/// ];
+/// let route: Length = legs.iter().sum();
+/// assert!((route.to_unit::<Kilometer>() - 4.0).abs() < 1e-12);
+/// ```
+impl<U: UnitConversion> Sum for Quantity<U> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a, U: UnitConversion> Sum<&'a Quantity<U>> for Quantity<U> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Scales a quantity by a plain number.
+///
+/// The base value is scaled, so for temperature the scale applies in kelvin.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Kilometer, Length};
+///
+/// let lap = Length::from_unit::<Kilometer>(2.5);
+/// assert_eq!((lap * 4.0).to_unit::<Kilometer>(), 10.0);
+/// assert_eq!((4.0 * lap).to_unit::<Kilometer>(), 10.0);
+/// ```
+impl<U: UnitConversion> Mul<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self::from_difference(self.base * factor)
+    }
+}
+
+impl<U: UnitConversion> Mul<Quantity<U>> for f64 {
+    type Output = Quantity<U>;
+
+    fn mul(self, quantity: Quantity<U>) -> Quantity<U> {
+        quantity * self
+    }
+}
+
+/// Divides a quantity by a plain number.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Meter};
+///
+/// let plank = Length::from_unit::<Meter>(3.0);
+/// assert_eq!((plank / 4.0).to_unit::<Meter>(), 0.75);
+/// ```
+impl<U: UnitConversion> Div<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self::from_difference(self.base / divisor)
+    }
+}
+
+/// Divides two quantities of the same dimension, giving their ratio as a plain number.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Foot, Length, Meter};
+///
+/// let ratio = Length::from_unit::<Meter>(3.048) / Length::from_unit::<Foot>(5.0);
+/// assert!((ratio - 2.0).abs() < 1e-12);
+/// ```
+impl<U, V> Div<Quantity<V>> for Quantity<U>
+where
+    U: UnitConversion,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    type Output = f64;
+
+    fn div(self, other: Quantity<V>) -> f64 {
+        self.base / other.in_base()
+    }
+}
+
+/// Negates a quantity in base units.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Meter};
+///
+/// let climb = Length::from_unit::<Meter>(120.0);
+/// assert_eq!((-climb).to_unit::<Meter>(), -120.0);
+/// ```
+impl<U: UnitConversion> Neg for Quantity<U> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_difference(-self.base)
+    }
+}
@@ -0,0 +1,204 @@
+//! # Acceleration Unit Conversions
+//!
+//! This module provides type-safe acceleration conversions between SI, customary and
+//! gravitational units.
+//! All accelerations are internally stored in meters per second squared (the base unit)
+//! and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Meters per second squared (m/s²)**: The base unit
+//! - **Feet per second squared (ft/s²)**: 0.3048 m/s²
+//! - **Standard gravity (g₀)**: 9.806 65 m/s²
+//! - **Gal (Gal)**: 0.01 m/s², used in geophysics
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::acceleration::{Acceleration, MeterPerSecondSquared, StandardGravity};
+//!
+//! let turn = Acceleration::from_unit::<StandardGravity>(4.5);
+//! assert!((turn.to_unit::<MeterPerSecondSquared>() - 44.129925).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for acceleration conversions
+const MPS2_PER_FPS2: f64 = 0.3048;
+const MPS2_PER_STANDARD_GRAVITY: f64 = 9.806_65;
+const MPS2_PER_GAL: f64 = 0.01;
+
+/// Marker type for the acceleration dimension.
+///
+/// This prevents accidental conversions between acceleration and speed.
+pub enum AccelerationDimension {}
+
+impl Dimension for AccelerationDimension {
+    const NAME: &'static str = "acceleration";
+    const ID: u8 = 17;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<MeterPerSecondSquared>(&["m/s^2", "m/s2", "meters per second squared"]),
+        UnitInfo::of::<FootPerSecondSquared>(&["ft/s^2", "ft/s2", "feet per second squared"]),
+        UnitInfo::of::<StandardGravity>(&["g0", "gn", "standard gravity"]),
+        UnitInfo::of::<Gal>(&["galileo", "cm/s²", "cm/s^2"]),
+    ];
+}
+
+/// An acceleration quantity that stores values in meters per second squared internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, FootPerSecondSquared, MeterPerSecondSquared};
+///
+/// let a = Acceleration::from_unit::<MeterPerSecondSquared>(3.048);
+/// let fps2 = a.to_unit::<FootPerSecondSquared>(); // 10.0
+/// ```
+pub type Acceleration = Quantity<MeterPerSecondSquared>;
+
+/// Meters per second squared acceleration unit (SI derived unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, MeterPerSecondSquared, StandardGravity};
+///
+/// let q = Acceleration::from_unit::<MeterPerSecondSquared>(9.80665);
+/// assert!((q.to_unit::<StandardGravity>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct MeterPerSecondSquared;
+
+impl UnitConversion for MeterPerSecondSquared {
+    type Dimension = AccelerationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // MeterPerSecondSquared is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // MeterPerSecondSquared is the base unit
+    }
+
+    const SYMBOL: &'static str = "m/s²";
+    const NAME: &'static str = "meter per second squared";
+    const PLURAL: &'static str = "meters per second squared";
+}
+
+/// Feet per second squared acceleration unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, FootPerSecondSquared, MeterPerSecondSquared};
+///
+/// let q = Acceleration::from_unit::<FootPerSecondSquared>(32.174);
+/// assert!((q.to_unit::<MeterPerSecondSquared>() - 9.8066352).abs() < 1e-9);
+/// ```
+pub struct FootPerSecondSquared;
+
+impl UnitConversion for FootPerSecondSquared {
+    type Dimension = AccelerationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MPS2_PER_FPS2
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MPS2_PER_FPS2
+    }
+
+    const SYMBOL: &'static str = "ft/s²";
+    const NAME: &'static str = "foot per second squared";
+    const PLURAL: &'static str = "feet per second squared";
+}
+
+/// Standard gravity, the conventional acceleration of free fall at the Earth's surface.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, StandardGravity, MeterPerSecondSquared};
+///
+/// let q = Acceleration::from_unit::<StandardGravity>(2.0);
+/// assert!((q.to_unit::<MeterPerSecondSquared>() - 19.6133).abs() < 1e-12);
+/// ```
+pub struct StandardGravity;
+
+impl UnitConversion for StandardGravity {
+    type Dimension = AccelerationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MPS2_PER_STANDARD_GRAVITY
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MPS2_PER_STANDARD_GRAVITY
+    }
+
+    const SYMBOL: &'static str = "g₀";
+    const NAME: &'static str = "standard gravity";
+    const PLURAL: &'static str = "standard gravities";
+}
+
+/// Gal acceleration unit (CGS unit, 1 cm/s²), used for gravity anomalies and ground motion.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, Gal, MeterPerSecondSquared};
+///
+/// let q = Acceleration::from_unit::<Gal>(980.0);
+/// assert!((q.to_unit::<MeterPerSecondSquared>() - 9.8).abs() < 1e-12);
+/// ```
+pub struct Gal;
+
+impl UnitConversion for Gal {
+    type Dimension = AccelerationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MPS2_PER_GAL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MPS2_PER_GAL
+    }
+
+    const SYMBOL: &'static str = "Gal";
+    const NAME: &'static str = "gal";
+    const PLURAL: &'static str = "gals";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn standard_gravity() {
+        let g = Acceleration::from_unit::<StandardGravity>(1.0);
+        assert!(approx(g.to_unit::<FootPerSecondSquared>(), 32.174049, 1e-6));
+        assert!(approx(g.to_unit::<Gal>(), 980.665, 1e-9));
+    }
+
+    #[test]
+    fn parses_ascii_forms() {
+        for input in ["9.80665 m/s^2", "9.80665 m/s2", "1 g0"] {
+            let a = Acceleration::parse(input).unwrap();
+            assert!(
+                approx(a.to_unit::<StandardGravity>(), 1.0, 1e-12),
+                "{input}"
+            );
+        }
+    }
+}
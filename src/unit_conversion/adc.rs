@@ -0,0 +1,236 @@
+//! # ADC Count Conversion
+//!
+//! An analog-to-digital converter reports a voltage as an integer count between zero
+//! and `2^bits - 1`, scaled to its reference voltage. This module turns those counts
+//! into typed [`Voltage`] values with [`Adc`], and into any other quantity through an
+//! [`AdcChannel`] that applies a sensor's transfer function to the voltage.
+//!
+//! Counts follow the usual convention that one step (the LSB) is `reference / 2^bits`,
+//! so the full-scale count reads one LSB below the reference voltage.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::adc::Adc;
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//! use uom::unit_conversion::voltage::{Millivolt, Volt, Voltage};
+//!
+//! let adc = Adc::new(Voltage::from_unit::<Volt>(3.3), 12).unwrap();
+//! assert!((adc.voltage(2048).unwrap().to_unit::<Volt>() - 1.65).abs() < 1e-12);
+//!
+//! // A TMP36 sensor outputs 500 mV at 0 °C and 10 mV per degree
+//! let tmp36 = adc.channel(|v: Voltage| {
+//!     Temperature::from_unit::<Celsius>((v.to_unit::<Millivolt>() - 500.0) / 10.0)
+//! });
+//! let room = tmp36.read(931).unwrap();
+//! assert!((room.to_unit::<Celsius>() - 25.01).abs() < 0.01);
+//! ```
+
+use crate::unit_conversion::voltage::{Volt, Voltage};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// The widest converter supported, in bits.
+const MAX_BITS: u8 = 32;
+
+/// An analog-to-digital converter with a reference voltage and bit depth.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Adc {
+    reference: Voltage,
+    bits: u8,
+}
+
+impl Adc {
+    /// Creates a converter with the given full-scale reference voltage and resolution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `bits` is not between 1 and 32 or the
+    /// reference voltage is not positive and finite.
+    pub fn new(reference: Voltage, bits: u8) -> Result<Self, UnitError> {
+        check_positive(reference.to_unit::<Volt>())?;
+        if !(1..=MAX_BITS).contains(&bits) {
+            return Err(UnitError::OutOfRange {
+                value: bits.into(),
+                min: 1.0,
+                max: MAX_BITS.into(),
+            });
+        }
+        Ok(Self { reference, bits })
+    }
+
+    /// Returns the reference voltage.
+    pub fn reference(&self) -> Voltage {
+        self.reference
+    }
+
+    /// Returns the resolution in bits.
+    pub fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Returns the largest count the converter can report, `2^bits - 1`.
+    pub fn max_count(&self) -> u32 {
+        u32::MAX >> (MAX_BITS - self.bits)
+    }
+
+    /// Returns the voltage of one count step, `reference / 2^bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::adc::Adc;
+    /// use uom::unit_conversion::voltage::{Millivolt, Volt, Voltage};
+    ///
+    /// let adc = Adc::new(Voltage::from_unit::<Volt>(4.096), 12).unwrap();
+    /// assert_eq!(adc.lsb().to_unit::<Millivolt>(), 1.0);
+    /// ```
+    pub fn lsb(&self) -> Voltage {
+        Voltage::from_unit::<Volt>(self.reference.to_unit::<Volt>() / 2f64.powi(self.bits.into()))
+    }
+
+    /// Converts a count to the voltage it represents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `count` is above [`Adc::max_count`].
+    pub fn voltage(&self, count: u32) -> Result<Voltage, UnitError> {
+        if count > self.max_count() {
+            return Err(UnitError::OutOfRange {
+                value: count.into(),
+                min: 0.0,
+                max: self.max_count().into(),
+            });
+        }
+        Ok(Voltage::from_unit::<Volt>(
+            f64::from(count) * self.lsb().to_unit::<Volt>(),
+        ))
+    }
+
+    /// Returns the count the converter would report for a voltage, rounding to the
+    /// nearest step and clamping to the converter's range.
+    ///
+    /// Returns `None` if the voltage is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::adc::Adc;
+    /// use uom::unit_conversion::voltage::{Volt, Voltage};
+    ///
+    /// let adc = Adc::new(Voltage::from_unit::<Volt>(5.0), 10).unwrap();
+    /// assert_eq!(adc.count(Voltage::from_unit::<Volt>(2.5)), Some(512));
+    /// assert_eq!(adc.count(Voltage::from_unit::<Volt>(12.0)), Some(1023));
+    /// ```
+    pub fn count(&self, voltage: Voltage) -> Option<u32> {
+        let steps = voltage.to_unit::<Volt>() / self.lsb().to_unit::<Volt>();
+        if steps.is_nan() {
+            return None;
+        }
+        Some(steps.round().clamp(0.0, self.max_count().into()) as u32)
+    }
+
+    /// Creates a channel that applies `transfer` to each converted voltage.
+    ///
+    /// The transfer function models the sensor wired to the input, turning its output
+    /// voltage into the physical quantity it measures.
+    pub fn channel<F, Q>(self, transfer: F) -> AdcChannel<F>
+    where
+        F: Fn(Voltage) -> Q,
+    {
+        AdcChannel {
+            adc: self,
+            transfer,
+        }
+    }
+}
+
+/// An ADC input with a sensor transfer function, producing typed readings from counts.
+///
+/// Created with [`Adc::channel`].
+#[derive(Clone, Debug)]
+pub struct AdcChannel<F> {
+    adc: Adc,
+    transfer: F,
+}
+
+impl<F> AdcChannel<F> {
+    /// Returns the converter this channel reads from.
+    pub fn adc(&self) -> &Adc {
+        &self.adc
+    }
+
+    /// Converts a count to a voltage and applies the transfer function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `count` is above the converter's maximum.
+    pub fn read<Q>(&self, count: u32) -> Result<Q, UnitError>
+    where
+        F: Fn(Voltage) -> Q,
+    {
+        self.adc.voltage(count).map(&self.transfer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::voltage::Millivolt;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn volts(value: f64) -> Voltage {
+        Voltage::from_unit::<Volt>(value)
+    }
+
+    #[test]
+    fn counts_to_voltages() {
+        let adc = Adc::new(volts(3.3), 12).unwrap();
+        assert_eq!(adc.max_count(), 4095);
+        assert_eq!(adc.voltage(0).unwrap().to_unit::<Volt>(), 0.0);
+        assert!(approx(
+            adc.voltage(4095).unwrap().to_unit::<Volt>(),
+            3.3 - 3.3 / 4096.0,
+            1e-12
+        ));
+        assert!(adc.voltage(4096).is_err());
+    }
+
+    #[test]
+    fn bit_depth_limits() {
+        assert_eq!(Adc::new(volts(1.0), 1).unwrap().max_count(), 1);
+        assert_eq!(Adc::new(volts(1.0), 32).unwrap().max_count(), u32::MAX);
+        assert!(Adc::new(volts(1.0), 0).is_err());
+        assert!(Adc::new(volts(1.0), 33).is_err());
+        assert!(Adc::new(volts(0.0), 12).is_err());
+        assert!(Adc::new(volts(-3.3), 12).is_err());
+    }
+
+    #[test]
+    fn counts_round_trip() {
+        let adc = Adc::new(volts(2.5), 16).unwrap();
+        for count in [0, 1, 12_345, 65_535] {
+            assert_eq!(adc.count(adc.voltage(count).unwrap()), Some(count));
+        }
+        assert_eq!(adc.count(volts(-1.0)), Some(0));
+        assert_eq!(adc.count(volts(f64::NAN)), None);
+    }
+
+    #[test]
+    fn channels_apply_the_transfer_function() {
+        let adc = Adc::new(volts(5.0), 10).unwrap();
+        // A divider that scales 0-50 V down to 0-5 V
+        let divider = adc.channel(|v: Voltage| volts(v.to_unit::<Volt>() * 10.0));
+        let input = divider.read(512).unwrap();
+        assert!(approx(input.to_unit::<Volt>(), 25.0, 1e-12));
+        assert!(approx(
+            divider.adc().lsb().to_unit::<Millivolt>(),
+            4.8828125,
+            1e-12
+        ));
+        assert!(divider.read(1024).is_err());
+    }
+}
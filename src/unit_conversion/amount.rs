@@ -0,0 +1,206 @@
+//! # Amount of Substance Unit Conversions
+//!
+//! This module provides type-safe conversions between the mole and its metric multiples.
+//! All amounts are internally stored in moles (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Mole (mol)**: The base unit, 6.022 140 76 × 10²³ elementary entities
+//! - **Kilomole (kmol)**: 1000 moles
+//! - **Millimole (mmol)**: 10⁻³ moles
+//! - **Micromole (µmol)**: 10⁻⁶ moles
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::amount::{Amount, Millimole, Mole};
+//!
+//! let sample = Amount::from_unit::<Millimole>(250.0);
+//! assert_eq!(sample.to_unit::<Mole>(), 0.25);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for amount conversions
+const MOLES_PER_KILOMOLE: f64 = 1e3;
+const MOLES_PER_MILLIMOLE: f64 = 1e-3;
+const MOLES_PER_MICROMOLE: f64 = 1e-6;
+
+/// Marker type for the amount-of-substance dimension.
+///
+/// This prevents accidental conversions between a number of moles and a mass or
+/// volume of the same substance.
+pub enum AmountDimension {}
+
+impl Dimension for AmountDimension {
+    const NAME: &'static str = "amount";
+    const ID: u8 = 15;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Mole>(&["mole", "moles"]),
+        UnitInfo::of::<Kilomole>(&["kilomole", "kilomoles"]),
+        UnitInfo::of::<Millimole>(&["millimole", "millimoles"]),
+        UnitInfo::of::<Micromole>(&["umol", "micromole", "micromoles"]),
+    ];
+}
+
+/// An amount of substance that stores values in moles internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Kilomole, Mole};
+///
+/// let batch = Amount::from_unit::<Kilomole>(1.2);
+/// let moles = batch.to_unit::<Mole>(); // 1200.0
+/// ```
+pub type Amount = Quantity<Mole>;
+
+/// Mole amount unit (SI base unit of amount of substance).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Mole, Millimole};
+///
+/// let amount = Amount::from_unit::<Mole>(0.5);
+/// assert!((amount.to_unit::<Millimole>() - 500.0).abs() < 1e-12);
+/// ```
+pub struct Mole;
+
+impl UnitConversion for Mole {
+    type Dimension = AmountDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Mole is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Mole is the base unit
+    }
+
+    const SYMBOL: &'static str = "mol";
+    const NAME: &'static str = "mole";
+    const PLURAL: &'static str = "moles";
+}
+
+/// Kilomole amount unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Kilomole, Mole};
+///
+/// let amount = Amount::from_unit::<Kilomole>(0.044);
+/// assert!((amount.to_unit::<Mole>() - 44.0).abs() < 1e-12);
+/// ```
+pub struct Kilomole;
+
+impl UnitConversion for Kilomole {
+    type Dimension = AmountDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MOLES_PER_KILOMOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MOLES_PER_KILOMOLE
+    }
+
+    const SYMBOL: &'static str = "kmol";
+    const NAME: &'static str = "kilomole";
+    const PLURAL: &'static str = "kilomoles";
+}
+
+/// Millimole amount unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Millimole, Mole};
+///
+/// let amount = Amount::from_unit::<Millimole>(5.5);
+/// assert!((amount.to_unit::<Mole>() - 0.0055).abs() < 1e-12);
+/// ```
+pub struct Millimole;
+
+impl UnitConversion for Millimole {
+    type Dimension = AmountDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MOLES_PER_MILLIMOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MOLES_PER_MILLIMOLE
+    }
+
+    const SYMBOL: &'static str = "mmol";
+    const NAME: &'static str = "millimole";
+    const PLURAL: &'static str = "millimoles";
+}
+
+/// Micromole amount unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Micromole, Millimole};
+///
+/// let amount = Amount::from_unit::<Micromole>(750.0);
+/// assert!((amount.to_unit::<Millimole>() - 0.75).abs() < 1e-12);
+/// ```
+pub struct Micromole;
+
+impl UnitConversion for Micromole {
+    type Dimension = AmountDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MOLES_PER_MICROMOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MOLES_PER_MICROMOLE
+    }
+
+    const SYMBOL: &'static str = "µmol";
+    const NAME: &'static str = "micromole";
+    const PLURAL: &'static str = "micromoles";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_prefixes() {
+        let amount = Amount::from_unit::<Kilomole>(1.0);
+        assert!(approx(amount.to_unit::<Mole>(), 1000.0, 1e-12));
+        assert!(approx(amount.to_unit::<Millimole>(), 1e6, 1e-6));
+        assert!(approx(amount.to_unit::<Micromole>(), 1e9, 1e-3));
+    }
+
+    #[test]
+    fn parses_symbols_and_aliases() {
+        for input in ["2 µmol", "2umol", "2 micromoles"] {
+            let amount = Amount::parse(input).unwrap();
+            assert!(approx(amount.to_unit::<Micromole>(), 2.0, 1e-12), "{input}");
+        }
+        assert!(approx(
+            Amount::parse("3 mol").unwrap().to_unit::<Mole>(),
+            3.0,
+            1e-12
+        ));
+    }
+}
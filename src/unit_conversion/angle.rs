@@ -0,0 +1,712 @@
+//! # Angle Unit Conversions
+//!
+//! This module provides type-safe angle conversions between radians, degrees, arcminutes,
+//! arcseconds, gradians, turns, and milliradians, along with trigonometric functions on the
+//! [`Angle`] quantity itself.
+//! All angles are internally stored in radians (the base unit) and converted on demand.
+//!
+//! Because `sin`, `cos`, and friends are methods on `Angle`, geometry code never has to
+//! remember whether a bare `f64` holds degrees or radians.
+//!
+//! ## Supported Units
+//!
+//! - **Radian (rad)**: The base unit, SI derived unit of plane angle
+//! - **Degree (°)**: 1/360 of a full turn
+//! - **Arcminute (′)**: 1/60 of a degree
+//! - **Arcsecond (″)**: 1/60 of an arcminute
+//! - **Gradian (gon)**: 1/400 of a full turn
+//! - **Turn (tr)**: One full revolution, 2π radians
+//! - **Milliradian (mrad)**: 10⁻³ radians, the "mil" of rifle scopes
+//! - **NATO mil (mil)**: 1/6400 of a full turn, used for artillery and compasses
+//!
+//! The two mils differ by about 1.8%, enough to miss a target at long range, so they are
+//! distinct units. [`Angle::display_dms`] writes an angle as degrees, minutes and seconds
+//! for surveying.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::angle::{Angle, Degree, Radian, Turn};
+//!
+//! let right = Angle::from_unit::<Degree>(90.0);
+//! assert_eq!(right.to_unit::<Radian>(), std::f64::consts::FRAC_PI_2);
+//! assert_eq!(right.to_unit::<Turn>(), 0.25);
+//!
+//! // Trigonometry works directly on the typed angle
+//! assert!((right.sin() - 1.0).abs() < 1e-12);
+//! let back = Angle::asin(1.0);
+//! assert!((back.to_unit::<Degree>() - 90.0).abs() < 1e-12);
+//!
+//! // Headings wrap around the circle
+//! let heading = Angle::from_unit::<Degree>(-30.0).normalize_0_to_360();
+//! assert!((heading.to_unit::<Degree>() - 330.0).abs() < 1e-9);
+//! ```
+
+use std::f64::consts::{PI, TAU};
+use std::fmt;
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for angle conversions
+const RADIANS_PER_DEGREE: f64 = PI / 180.0;
+const RADIANS_PER_ARCMINUTE: f64 = RADIANS_PER_DEGREE / 60.0;
+const RADIANS_PER_ARCSECOND: f64 = RADIANS_PER_ARCMINUTE / 60.0;
+const RADIANS_PER_GRADIAN: f64 = PI / 200.0;
+const RADIANS_PER_TURN: f64 = TAU;
+const RADIANS_PER_MILLIRADIAN: f64 = 1e-3;
+const RADIANS_PER_NATO_MIL: f64 = TAU / 6400.0;
+const ARCSECONDS_PER_DEGREE: f64 = 3600.0;
+const ARCSECONDS_PER_ARCMINUTE: f64 = 60.0;
+
+/// Marker type for the angle dimension.
+///
+/// This prevents accidental conversions between angles and other dimensions
+/// like length.
+pub enum AngleDimension {}
+
+impl Dimension for AngleDimension {
+    const NAME: &'static str = "angle";
+    const ID: u8 = 6;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Radian>(&["radian", "radians"]),
+        UnitInfo::of::<Degree>(&["deg", "degree", "degrees"]),
+        UnitInfo::of::<ArcMinute>(&["'", "arcmin", "arcminute", "arcminutes"]),
+        UnitInfo::of::<ArcSecond>(&["\"", "arcsec", "arcsecond", "arcseconds"]),
+        UnitInfo::of::<Gradian>(&["grad", "gradian", "gradians"]),
+        UnitInfo::of::<Turn>(&["turn", "turns", "rev", "revolution", "revolutions"]),
+        UnitInfo::of::<Milliradian>(&["milliradian", "milliradians", "MRAD"]),
+        UnitInfo::of::<NatoMil>(&["mils", "NATO mil", "NATO mils", "angular mil"]),
+    ];
+}
+
+/// A plane angle quantity that stores values in radians internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Degree, Radian};
+///
+/// let heading = Angle::from_unit::<Degree>(180.0);
+/// let radians = heading.to_unit::<Radian>(); // π
+/// ```
+pub type Angle = Quantity<Radian>;
+
+/// Radian angle unit (SI derived base unit).
+///
+/// One radian is the angle subtended by an arc equal in length to the radius.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Radian};
+///
+/// let angle = Angle::from_unit::<Radian>(1.0);
+/// assert_eq!(angle.to_unit::<Radian>(), 1.0);
+/// ```
+pub struct Radian;
+
+impl UnitConversion for Radian {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Radian is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Radian is the base unit
+    }
+
+    const SYMBOL: &'static str = "rad";
+    const NAME: &'static str = "radian";
+    const PLURAL: &'static str = "radians";
+}
+
+/// Degree angle unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Degree, Radian};
+///
+/// let half = Angle::from_unit::<Degree>(180.0);
+/// assert_eq!(half.to_unit::<Radian>(), std::f64::consts::PI);
+/// ```
+pub struct Degree;
+
+impl UnitConversion for Degree {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_DEGREE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_DEGREE
+    }
+
+    const SYMBOL: &'static str = "°";
+    const NAME: &'static str = "degree";
+    const PLURAL: &'static str = "degrees";
+}
+
+/// Arcminute angle unit.
+///
+/// One sixtieth of a degree. Used in astronomy, navigation, and optics.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, ArcMinute, Degree};
+///
+/// let angle = Angle::from_unit::<ArcMinute>(90.0);
+/// assert!((angle.to_unit::<Degree>() - 1.5).abs() < 1e-12);
+/// ```
+pub struct ArcMinute;
+
+impl UnitConversion for ArcMinute {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_ARCMINUTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_ARCMINUTE
+    }
+
+    const SYMBOL: &'static str = "′";
+    const NAME: &'static str = "arcminute";
+    const PLURAL: &'static str = "arcminutes";
+}
+
+/// Arcsecond angle unit.
+///
+/// One sixtieth of an arcminute (1/3600 of a degree).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, ArcSecond, ArcMinute};
+///
+/// let angle = Angle::from_unit::<ArcSecond>(120.0);
+/// assert!((angle.to_unit::<ArcMinute>() - 2.0).abs() < 1e-12);
+/// ```
+pub struct ArcSecond;
+
+impl UnitConversion for ArcSecond {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_ARCSECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_ARCSECOND
+    }
+
+    const SYMBOL: &'static str = "″";
+    const NAME: &'static str = "arcsecond";
+    const PLURAL: &'static str = "arcseconds";
+}
+
+/// Gradian angle unit (also called gon or grad).
+///
+/// A right angle is exactly 100 gradians. Used in surveying.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Gradian, Degree};
+///
+/// let right = Angle::from_unit::<Gradian>(100.0);
+/// assert!((right.to_unit::<Degree>() - 90.0).abs() < 1e-12);
+/// ```
+pub struct Gradian;
+
+impl UnitConversion for Gradian {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_GRADIAN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_GRADIAN
+    }
+
+    const SYMBOL: &'static str = "gon";
+    const NAME: &'static str = "gradian";
+    const PLURAL: &'static str = "gradians";
+}
+
+/// Turn angle unit (one full revolution).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Turn, Degree};
+///
+/// let spin = Angle::from_unit::<Turn>(1.5);
+/// assert!((spin.to_unit::<Degree>() - 540.0).abs() < 1e-9);
+/// ```
+pub struct Turn;
+
+impl UnitConversion for Turn {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_TURN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_TURN
+    }
+
+    const SYMBOL: &'static str = "tr";
+    const NAME: &'static str = "turn";
+    const PLURAL: &'static str = "turns";
+}
+
+/// Milliradian angle unit: exactly 10⁻³ radians.
+///
+/// One milliradian subtends 1 m at 1 km (10 cm at 100 m), which makes it convenient
+/// for range estimation and scope adjustments. There are about 6283.2 in a turn.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Degree, Milliradian};
+///
+/// let click = Angle::from_unit::<Milliradian>(0.1);
+/// assert!((click.to_unit::<Degree>() - 0.0057296).abs() < 1e-7);
+/// ```
+pub struct Milliradian;
+
+impl UnitConversion for Milliradian {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_MILLIRADIAN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_MILLIRADIAN
+    }
+
+    const SYMBOL: &'static str = "mrad";
+    const NAME: &'static str = "milliradian";
+    const PLURAL: &'static str = "milliradians";
+}
+
+/// NATO mil angle unit: exactly 1/6400 of a turn.
+///
+/// A rounded milliradian chosen so that a circle divides evenly; about 0.98 mrad. Used
+/// on military compasses and for artillery.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Degree, NatoMil};
+///
+/// let quarter = Angle::from_unit::<NatoMil>(1600.0);
+/// assert!((quarter.to_unit::<Degree>() - 90.0).abs() < 1e-9);
+/// ```
+pub struct NatoMil;
+
+impl UnitConversion for NatoMil {
+    type Dimension = AngleDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * RADIANS_PER_NATO_MIL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / RADIANS_PER_NATO_MIL
+    }
+
+    const SYMBOL: &'static str = "mil";
+    const NAME: &'static str = "NATO mil";
+    const PLURAL: &'static str = "NATO mils";
+}
+
+impl Quantity<Radian> {
+    /// Returns the sine of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::from_unit::<Degree>(30.0);
+    /// assert!((angle.sin() - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn sin(&self) -> f64 {
+        self.to_unit::<Radian>().sin()
+    }
+
+    /// Returns the cosine of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::from_unit::<Degree>(60.0);
+    /// assert!((angle.cos() - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn cos(&self) -> f64 {
+        self.to_unit::<Radian>().cos()
+    }
+
+    /// Returns the tangent of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::from_unit::<Degree>(45.0);
+    /// assert!((angle.tan() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn tan(&self) -> f64 {
+        self.to_unit::<Radian>().tan()
+    }
+
+    /// Returns the angle whose sine is `value`, in the range [-π/2, π/2].
+    ///
+    /// Returns NaN radians if `value` is outside [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::asin(0.5);
+    /// assert!((angle.to_unit::<Degree>() - 30.0).abs() < 1e-12);
+    /// ```
+    pub fn asin(value: f64) -> Self {
+        Self::from_unit::<Radian>(value.asin())
+    }
+
+    /// Returns the angle whose cosine is `value`, in the range [0, π].
+    ///
+    /// Returns NaN radians if `value` is outside [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::acos(0.5);
+    /// assert!((angle.to_unit::<Degree>() - 60.0).abs() < 1e-12);
+    /// ```
+    pub fn acos(value: f64) -> Self {
+        Self::from_unit::<Radian>(value.acos())
+    }
+
+    /// Returns the angle whose tangent is `value`, in the range [-π/2, π/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::atan(1.0);
+    /// assert!((angle.to_unit::<Degree>() - 45.0).abs() < 1e-12);
+    /// ```
+    pub fn atan(value: f64) -> Self {
+        Self::from_unit::<Radian>(value.atan())
+    }
+
+    /// Returns the four-quadrant angle of the point (`x`, `y`), in the range [-π, π].
+    ///
+    /// The arguments follow the `f64::atan2` convention of `y` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::atan2(1.0, -1.0);
+    /// assert!((angle.to_unit::<Degree>() - 135.0).abs() < 1e-12);
+    /// ```
+    pub fn atan2(y: f64, x: f64) -> Self {
+        Self::from_unit::<Radian>(y.atan2(x))
+    }
+
+    /// Returns this angle wrapped into the range [0°, 360°).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::from_unit::<Degree>(-90.0).normalize_0_to_360();
+    /// assert!((angle.to_unit::<Degree>() - 270.0).abs() < 1e-12);
+    ///
+    /// let angle = Angle::from_unit::<Degree>(725.0).normalize_0_to_360();
+    /// assert!((angle.to_unit::<Degree>() - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn normalize_0_to_360(&self) -> Self {
+        Self::from_unit::<Radian>(wrap_turn(self.to_unit::<Radian>()))
+    }
+
+    /// Returns this angle wrapped into the signed range [-180°, 180°).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let angle = Angle::from_unit::<Degree>(270.0).normalize_signed();
+    /// assert!((angle.to_unit::<Degree>() + 90.0).abs() < 1e-12);
+    /// ```
+    pub fn normalize_signed(&self) -> Self {
+        Self::from_unit::<Radian>(wrap_turn(self.to_unit::<Radian>() + PI) - PI)
+    }
+
+    /// Returns the signed shortest rotation from this angle to `other`, in the
+    /// range [-180°, 180°).
+    ///
+    /// A positive result means `other` is reached by rotating in the positive
+    /// (counter-clockwise) direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let from = Angle::from_unit::<Degree>(350.0);
+    /// let to = Angle::from_unit::<Degree>(10.0);
+    /// assert!((from.shortest_distance_to(to).to_unit::<Degree>() - 20.0).abs() < 1e-9);
+    /// assert!((to.shortest_distance_to(from).to_unit::<Degree>() + 20.0).abs() < 1e-9);
+    /// ```
+    pub fn shortest_distance_to(&self, other: Self) -> Self {
+        Self::from_unit::<Radian>(other.to_unit::<Radian>() - self.to_unit::<Radian>())
+            .normalize_signed()
+    }
+
+    /// Returns the unsigned shortest angular separation between this angle and
+    /// `other`, in the range [0°, 180°].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree};
+    ///
+    /// let a = Angle::from_unit::<Degree>(-170.0);
+    /// let b = Angle::from_unit::<Degree>(170.0);
+    /// assert!((a.angular_distance(b).to_unit::<Degree>() - 20.0).abs() < 1e-9);
+    /// ```
+    pub fn angular_distance(&self, other: Self) -> Self {
+        Self::from_unit::<Radian>(self.shortest_distance_to(other).to_unit::<Radian>().abs())
+    }
+}
+
+/// Wraps a radian value into [0, 2π), guarding against `rem_euclid` rounding
+/// tiny negative values up to exactly 2π.
+fn wrap_turn(radians: f64) -> f64 {
+    let wrapped = radians.rem_euclid(TAU);
+    if wrapped >= TAU { 0.0 } else { wrapped }
+}
+
+/// An angle written as degrees, minutes and seconds, as returned by
+/// [`Angle::display_dms`].
+///
+/// Displays as `12°34′56″`, with a leading minus sign for negative angles. The
+/// formatter's precision, if any, sets the number of decimal places of the seconds,
+/// which are otherwise rounded to whole numbers; rounding carries into the minutes and
+/// degrees, so 59.99″ is never shown as 60″.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Dms {
+    degrees: f64,
+}
+
+impl fmt::Display for Dms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let scale = 10f64.powi(precision as i32);
+        let total = (self.degrees.abs() * ARCSECONDS_PER_DEGREE * scale).round() / scale;
+        let degrees = (total / ARCSECONDS_PER_DEGREE).floor();
+        let minutes =
+            ((total - degrees * ARCSECONDS_PER_DEGREE) / ARCSECONDS_PER_ARCMINUTE).floor();
+        let seconds = total - degrees * ARCSECONDS_PER_DEGREE - minutes * ARCSECONDS_PER_ARCMINUTE;
+        let sign = if self.degrees < 0.0 && total > 0.0 {
+            "-"
+        } else {
+            ""
+        };
+        write!(f, "{sign}{degrees}°{minutes}′{seconds:.precision$}″")
+    }
+}
+
+impl Angle {
+    /// Returns a view of the angle that displays as degrees, minutes and seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::angle::{Angle, Degree, Radian};
+    ///
+    /// let bearing = Angle::from_unit::<Degree>(47.2625);
+    /// assert_eq!(bearing.display_dms().to_string(), "47°15′45″");
+    ///
+    /// let one = Angle::from_unit::<Radian>(1.0);
+    /// assert_eq!(format!("{:.2}", one.display_dms()), "57°17′44.81″");
+    /// ```
+    pub fn display_dms(&self) -> Dms {
+        Dms {
+            degrees: self.to_unit::<Degree>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn degree_to_radian() {
+        let angle = Angle::from_unit::<Degree>(180.0);
+        assert!(approx(angle.to_unit::<Radian>(), PI, 1e-12));
+
+        let angle = Angle::from_unit::<Radian>(PI / 2.0);
+        assert!(approx(angle.to_unit::<Degree>(), 90.0, 1e-12));
+    }
+
+    #[test]
+    fn arcminute_and_arcsecond() {
+        let angle = Angle::from_unit::<Degree>(1.0);
+        assert!(approx(angle.to_unit::<ArcMinute>(), 60.0, 1e-9));
+        assert!(approx(angle.to_unit::<ArcSecond>(), 3600.0, 1e-9));
+    }
+
+    #[test]
+    fn gradian_and_turn() {
+        let angle = Angle::from_unit::<Turn>(1.0);
+        assert!(approx(angle.to_unit::<Degree>(), 360.0, 1e-9));
+        assert!(approx(angle.to_unit::<Gradian>(), 400.0, 1e-9));
+    }
+
+    #[test]
+    fn trigonometric_functions() {
+        let angle = Angle::from_unit::<Degree>(30.0);
+        assert!(approx(angle.sin(), 0.5, 1e-12));
+        assert!(approx(angle.cos(), 3f64.sqrt() / 2.0, 1e-12));
+        assert!(approx(angle.tan(), 1.0 / 3f64.sqrt(), 1e-12));
+    }
+
+    #[test]
+    fn inverse_constructors() {
+        assert!(approx(Angle::asin(1.0).to_unit::<Degree>(), 90.0, 1e-12));
+        assert!(approx(Angle::acos(-1.0).to_unit::<Degree>(), 180.0, 1e-12));
+        assert!(approx(Angle::atan(-1.0).to_unit::<Degree>(), -45.0, 1e-12));
+        assert!(approx(
+            Angle::atan2(-1.0, 0.0).to_unit::<Degree>(),
+            -90.0,
+            1e-12
+        ));
+        assert!(Angle::asin(2.0).to_unit::<Radian>().is_nan());
+    }
+
+    #[test]
+    fn normalization() {
+        let angle = Angle::from_unit::<Degree>(-450.0).normalize_0_to_360();
+        assert!(approx(angle.to_unit::<Degree>(), 270.0, 1e-9));
+
+        let angle = Angle::from_unit::<Degree>(360.0).normalize_0_to_360();
+        assert!(approx(angle.to_unit::<Degree>(), 0.0, 1e-9));
+
+        let angle = Angle::from_unit::<Degree>(180.0).normalize_signed();
+        assert!(approx(angle.to_unit::<Degree>(), -180.0, 1e-9));
+
+        let angle = Angle::from_unit::<Degree>(-1e-18).normalize_0_to_360();
+        assert!(angle.to_unit::<Radian>() < TAU);
+    }
+
+    #[test]
+    fn shortest_distance_wraps_around() {
+        let from = Angle::from_unit::<Degree>(10.0);
+        let to = Angle::from_unit::<Degree>(350.0);
+        assert!(approx(
+            from.shortest_distance_to(to).to_unit::<Degree>(),
+            -20.0,
+            1e-9
+        ));
+        assert!(approx(
+            from.angular_distance(to).to_unit::<Degree>(),
+            20.0,
+            1e-9
+        ));
+
+        let from = Angle::from_unit::<Degree>(0.0);
+        let to = Angle::from_unit::<Degree>(720.0 + 90.0);
+        assert!(approx(
+            from.shortest_distance_to(to).to_unit::<Degree>(),
+            90.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn milliradians_and_mils() {
+        let angle = Angle::from_unit::<Milliradian>(1000.0);
+        assert!(approx(angle.to_unit::<Radian>(), 1.0, 1e-12));
+
+        let turn = Angle::from_unit::<Turn>(1.0);
+        assert!(approx(turn.to_unit::<NatoMil>(), 6400.0, 1e-9));
+        assert!(approx(turn.to_unit::<Milliradian>(), 2000.0 * PI, 1e-9));
+
+        let mil = Angle::from_unit::<NatoMil>(1.0);
+        assert!(approx(mil.to_unit::<Milliradian>(), 0.98175, 1e-5));
+        assert!(approx(mil.to_unit::<ArcMinute>(), 3.375, 1e-12));
+
+        assert_eq!(AngleDimension::find_unit("mils").unwrap().symbol, "mil");
+        assert_eq!(AngleDimension::find_unit("MRAD").unwrap().symbol, "mrad");
+    }
+
+    #[test]
+    fn formats_dms() {
+        let dms = |degrees: f64| Angle::from_unit::<Degree>(degrees).display_dms();
+        assert_eq!(dms(0.0).to_string(), "0°0′0″");
+        assert_eq!(dms(90.5).to_string(), "90°30′0″");
+        assert_eq!(dms(-12.5125).to_string(), "-12°30′45″");
+        assert_eq!(dms(29.999_99).to_string(), "30°0′0″");
+        assert_eq!(format!("{:.1}", dms(29.999_99)), "30°0′0.0″");
+        assert_eq!(format!("{:.2}", dms(29.999_99)), "29°59′59.96″");
+        assert_eq!(format!("{:.3}", dms(1.0 / 3600.0 / 2.0)), "0°0′0.500″");
+        assert_eq!(dms(-1e-9).to_string(), "0°0′0″");
+    }
+
+    #[test]
+    fn angle_symbols() {
+        assert_eq!(Radian::SYMBOL, "rad");
+        assert_eq!(Degree::SYMBOL, "°");
+        assert_eq!(ArcMinute::SYMBOL, "′");
+        assert_eq!(ArcSecond::SYMBOL, "″");
+        assert_eq!(Gradian::SYMBOL, "gon");
+        assert_eq!(Turn::SYMBOL, "tr");
+        assert_eq!(Milliradian::SYMBOL, "mrad");
+        assert_eq!(NatoMil::SYMBOL, "mil");
+    }
+}
@@ -0,0 +1,541 @@
+//! # Area Unit Conversions
+//!
+//! This module provides type-safe area conversions between square meters, metric and
+//! imperial square units, land-area units, and circular mils.
+//! All areas are internally stored in square meters (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Square meter (m²)**: The base unit, SI unit of area
+//! - **Square centimeter (cm²)**: 10⁻⁴ square meters
+//! - **Square millimeter (mm²)**: 10⁻⁶ square meters
+//! - **Square foot (ft²)**: Exactly 0.09290304 square meters
+//! - **Square inch (in²)**: Exactly 0.00064516 square meters
+//! - **Square yard (yd²)**: Exactly 0.83612736 square meters
+//! - **Are (a)**: 100 square meters
+//! - **Hectare (ha)**: 10,000 square meters
+//! - **Acre (ac)**: 4840 square yards, exactly 4046.8564224 square meters
+//! - **Circular mil (cmil)**: The area of a circle one mil (0.001 in) in diameter, used
+//!   for conductor cross-sections
+//!
+//! [`Area::display_land`] writes an area the way land registries do, as whole hectares
+//! followed by the remaining square meters.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::area::{Area, SquareMeter, SquareFoot, SquareInch};
+//!
+//! let floor = Area::from_unit::<SquareMeter>(20.0);
+//! assert!((floor.to_unit::<SquareFoot>() - 215.278208).abs() < 1e-6);
+//!
+//! let one_square_foot = Area::from_unit::<SquareFoot>(1.0);
+//! assert!((one_square_foot.to_unit::<SquareInch>() - 144.0).abs() < 1e-9);
+//!
+//! let parcel = Area::from_unit::<SquareMeter>(20_350.0);
+//! assert_eq!(parcel.display_land().to_string(), "2 ha 350 m²");
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for area conversions
+const SQUARE_METERS_PER_SQUARE_CENTIMETER: f64 = 1e-4;
+const SQUARE_METERS_PER_SQUARE_MILLIMETER: f64 = 1e-6;
+const SQUARE_METERS_PER_SQUARE_FOOT: f64 = 0.09290304; // Exact definition
+const SQUARE_METERS_PER_SQUARE_INCH: f64 = 0.00064516; // Exact definition
+const SQUARE_METERS_PER_SQUARE_YARD: f64 = 0.83612736; // Exact definition
+const SQUARE_METERS_PER_ARE: f64 = 100.0;
+const SQUARE_METERS_PER_HECTARE: f64 = 1e4;
+const SQUARE_METERS_PER_ACRE: f64 = 4046.8564224; // Exact definition
+const SQUARE_METERS_PER_CIRCULAR_MIL: f64 = PI / 4.0 * 25.4e-6 * 25.4e-6;
+
+/// Marker type for the area dimension.
+///
+/// This prevents accidental conversions between area and other dimensions
+/// like length or volume.
+pub enum AreaDimension {}
+
+impl Dimension for AreaDimension {
+    const NAME: &'static str = "area";
+    const ID: u8 = 10;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<SquareMeter>(&["m2", "sq m", "square meter", "square meters"]),
+        UnitInfo::of::<SquareCentimeter>(&[
+            "cm2",
+            "sq cm",
+            "square centimeter",
+            "square centimeters",
+        ]),
+        UnitInfo::of::<SquareMillimeter>(&[
+            "mm2",
+            "sq mm",
+            "square millimeter",
+            "square millimeters",
+        ]),
+        UnitInfo::of::<SquareFoot>(&["ft2", "sq ft", "square foot", "square feet"]),
+        UnitInfo::of::<SquareInch>(&["in2", "sq in", "square inch", "square inches"]),
+        UnitInfo::of::<SquareYard>(&["yd2", "sq yd", "square yard", "square yards"]),
+        UnitInfo::of::<Are>(&["are", "ares"]),
+        UnitInfo::of::<Hectare>(&["hectare", "hectares"]),
+        UnitInfo::of::<Acre>(&["acre", "acres"]),
+        UnitInfo::of::<CircularMil>(&["circular mil", "circular mils"]),
+    ];
+}
+
+/// An area quantity that stores values in square meters internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareMeter, SquareCentimeter};
+///
+/// let tile = Area::from_unit::<SquareCentimeter>(900.0);
+/// let square_meters = tile.to_unit::<SquareMeter>(); // 0.09
+/// ```
+pub type Area = Quantity<SquareMeter>;
+
+/// Square meter area unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareMeter};
+///
+/// let plot = Area::from_unit::<SquareMeter>(450.0);
+/// assert_eq!(plot.to_unit::<SquareMeter>(), 450.0);
+/// ```
+pub struct SquareMeter;
+
+impl UnitConversion for SquareMeter {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // SquareMeter is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // SquareMeter is the base unit
+    }
+
+    const SYMBOL: &'static str = "m²";
+    const NAME: &'static str = "square meter";
+    const PLURAL: &'static str = "square meters";
+}
+
+/// Square centimeter area unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareCentimeter, SquareMeter};
+///
+/// let area = Area::from_unit::<SquareMeter>(1.0);
+/// assert!((area.to_unit::<SquareCentimeter>() - 10_000.0).abs() < 1e-9);
+/// ```
+pub struct SquareCentimeter;
+
+impl UnitConversion for SquareCentimeter {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_SQUARE_CENTIMETER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_SQUARE_CENTIMETER
+    }
+
+    const SYMBOL: &'static str = "cm²";
+    const NAME: &'static str = "square centimeter";
+    const PLURAL: &'static str = "square centimeters";
+}
+
+/// Square millimeter area unit, the usual unit for metric wire cross-sections.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareMillimeter, SquareCentimeter};
+///
+/// let conductor = Area::from_unit::<SquareMillimeter>(2.5);
+/// assert!((conductor.to_unit::<SquareCentimeter>() - 0.025).abs() < 1e-12);
+/// ```
+pub struct SquareMillimeter;
+
+impl UnitConversion for SquareMillimeter {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_SQUARE_MILLIMETER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_SQUARE_MILLIMETER
+    }
+
+    const SYMBOL: &'static str = "mm²";
+    const NAME: &'static str = "square millimeter";
+    const PLURAL: &'static str = "square millimeters";
+}
+
+/// Square foot area unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareFoot, SquareMeter};
+///
+/// let room = Area::from_unit::<SquareFoot>(100.0);
+/// assert!((room.to_unit::<SquareMeter>() - 9.290304).abs() < 1e-12);
+/// ```
+pub struct SquareFoot;
+
+impl UnitConversion for SquareFoot {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_SQUARE_FOOT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_SQUARE_FOOT
+    }
+
+    const SYMBOL: &'static str = "ft²";
+    const NAME: &'static str = "square foot";
+    const PLURAL: &'static str = "square feet";
+}
+
+/// Square inch area unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareInch, SquareMillimeter};
+///
+/// let area = Area::from_unit::<SquareInch>(1.0);
+/// assert!((area.to_unit::<SquareMillimeter>() - 645.16).abs() < 1e-9);
+/// ```
+pub struct SquareInch;
+
+impl UnitConversion for SquareInch {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_SQUARE_INCH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_SQUARE_INCH
+    }
+
+    const SYMBOL: &'static str = "in²";
+    const NAME: &'static str = "square inch";
+    const PLURAL: &'static str = "square inches";
+}
+
+/// Square yard area unit.
+///
+/// Exactly 0.83612736 square meters (nine square feet).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareFoot, SquareYard};
+///
+/// let rug = Area::from_unit::<SquareYard>(2.0);
+/// assert!((rug.to_unit::<SquareFoot>() - 18.0).abs() < 1e-9);
+/// ```
+pub struct SquareYard;
+
+impl UnitConversion for SquareYard {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_SQUARE_YARD
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_SQUARE_YARD
+    }
+
+    const SYMBOL: &'static str = "yd²";
+    const NAME: &'static str = "square yard";
+    const PLURAL: &'static str = "square yards";
+}
+
+/// Are area unit.
+///
+/// 100 square meters, a square ten meters on a side.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Are, Area, SquareMeter};
+///
+/// let garden = Area::from_unit::<Are>(3.0);
+/// assert_eq!(garden.to_unit::<SquareMeter>(), 300.0);
+/// ```
+pub struct Are;
+
+impl UnitConversion for Are {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_ARE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_ARE
+    }
+
+    const SYMBOL: &'static str = "a";
+    const NAME: &'static str = "are";
+    const PLURAL: &'static str = "ares";
+}
+
+/// Hectare area unit.
+///
+/// 100 ares, or 10,000 square meters.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Are, Area, Hectare};
+///
+/// let field = Area::from_unit::<Hectare>(1.5);
+/// assert!((field.to_unit::<Are>() - 150.0).abs() < 1e-9);
+/// ```
+pub struct Hectare;
+
+impl UnitConversion for Hectare {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_HECTARE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_HECTARE
+    }
+
+    const SYMBOL: &'static str = "ha";
+    const NAME: &'static str = "hectare";
+    const PLURAL: &'static str = "hectares";
+}
+
+/// Acre area unit.
+///
+/// The international acre of 4840 square yards, exactly 4046.8564224 square meters.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Acre, Area, SquareYard};
+///
+/// let lot = Area::from_unit::<Acre>(1.0);
+/// assert!((lot.to_unit::<SquareYard>() - 4840.0).abs() < 1e-9);
+/// ```
+pub struct Acre;
+
+impl UnitConversion for Acre {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_ACRE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_ACRE
+    }
+
+    const SYMBOL: &'static str = "ac";
+    const NAME: &'static str = "acre";
+    const PLURAL: &'static str = "acres";
+}
+
+/// Circular mil area unit, the area of a circle one thousandth of an inch in diameter.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, CircularMil, SquareInch};
+///
+/// // A circle 1 inch across has an area of one million circular mils
+/// let area = Area::from_unit::<CircularMil>(1e6);
+/// assert!((area.to_unit::<SquareInch>() - std::f64::consts::PI / 4.0).abs() < 1e-12);
+/// ```
+pub struct CircularMil;
+
+impl UnitConversion for CircularMil {
+    type Dimension = AreaDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SQUARE_METERS_PER_CIRCULAR_MIL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SQUARE_METERS_PER_CIRCULAR_MIL
+    }
+
+    const SYMBOL: &'static str = "cmil";
+    const NAME: &'static str = "circular mil";
+    const PLURAL: &'static str = "circular mils";
+}
+
+/// An area written as whole hectares followed by the remaining square meters, as
+/// returned by [`Area::display_land`].
+///
+/// The formatter's precision, if any, applies to the square meters, which are otherwise
+/// rounded to whole numbers. Either part is left out when it is zero, so 10,000 m² is
+/// written as "1 ha" and 350 m² as "350 m²".
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LandArea {
+    square_meters: f64,
+}
+
+impl fmt::Display for LandArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let scale = 10f64.powi(precision as i32);
+        let magnitude = self.square_meters.abs();
+        let mut hectares = (magnitude / SQUARE_METERS_PER_HECTARE).floor();
+        let mut rest = ((magnitude - hectares * SQUARE_METERS_PER_HECTARE) * scale).round() / scale;
+        if rest >= SQUARE_METERS_PER_HECTARE {
+            hectares += 1.0;
+            rest = 0.0;
+        }
+        if self.square_meters < 0.0 && (hectares > 0.0 || rest > 0.0) {
+            f.write_str("-")?;
+        }
+        if hectares > 0.0 {
+            write!(f, "{hectares} ha")?;
+            if rest > 0.0 {
+                f.write_str(" ")?;
+            }
+        }
+        if rest > 0.0 || hectares == 0.0 {
+            write!(f, "{rest:.precision$} m²")?;
+        }
+        Ok(())
+    }
+}
+
+impl Area {
+    /// Returns a view of the area that displays as whole hectares followed by the
+    /// remaining square meters, such as "2 ha 350 m²".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::area::{Acre, Area};
+    ///
+    /// let farm = Area::from_unit::<Acre>(40.0);
+    /// assert_eq!(farm.display_land().to_string(), "16 ha 1874 m²");
+    /// assert_eq!(format!("{:.1}", farm.display_land()), "16 ha 1874.3 m²");
+    /// ```
+    pub fn display_land(&self) -> LandArea {
+        LandArea {
+            square_meters: self.in_base(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_conversions() {
+        let area = Area::from_unit::<SquareMeter>(1.0);
+        assert!(approx(area.to_unit::<SquareCentimeter>(), 1e4, 1e-9));
+        assert!(approx(area.to_unit::<SquareMillimeter>(), 1e6, 1e-6));
+    }
+
+    #[test]
+    fn imperial_conversions() {
+        let area = Area::from_unit::<SquareFoot>(1.0);
+        assert!(approx(area.to_unit::<SquareInch>(), 144.0, 1e-9));
+        assert!(approx(area.to_unit::<SquareMeter>(), 0.09290304, 1e-15));
+    }
+
+    #[test]
+    fn circular_mil_conversions() {
+        let area = Area::from_unit::<CircularMil>(1.0);
+        assert!(approx(
+            area.to_unit::<SquareMillimeter>(),
+            5.067075e-4,
+            1e-10
+        ));
+
+        let area = Area::from_unit::<SquareMillimeter>(1.0);
+        assert!(approx(area.to_unit::<CircularMil>(), 1973.525, 1e-3));
+    }
+
+    #[test]
+    fn land_conversions() {
+        let acre = Area::from_unit::<Acre>(1.0);
+        assert!(approx(acre.to_unit::<SquareYard>(), 4840.0, 1e-9));
+        assert!(approx(acre.to_unit::<SquareFoot>(), 43_560.0, 1e-8));
+        assert!(approx(acre.to_unit::<Hectare>(), 0.40468564224, 1e-15));
+
+        let hectare = Area::from_unit::<Hectare>(1.0);
+        assert!(approx(hectare.to_unit::<Are>(), 100.0, 1e-12));
+        assert!(approx(hectare.to_unit::<SquareMeter>(), 1e4, 1e-12));
+    }
+
+    #[test]
+    fn formats_land_areas() {
+        let land =
+            |square_meters: f64| Area::from_unit::<SquareMeter>(square_meters).display_land();
+        assert_eq!(land(20_350.0).to_string(), "2 ha 350 m²");
+        assert_eq!(land(30_000.0).to_string(), "3 ha");
+        assert_eq!(land(350.4).to_string(), "350 m²");
+        assert_eq!(land(0.0).to_string(), "0 m²");
+        assert_eq!(land(19_999.7).to_string(), "2 ha");
+        assert_eq!(format!("{:.1}", land(19_999.7)), "1 ha 9999.7 m²");
+        assert_eq!(land(-12_500.0).to_string(), "-1 ha 2500 m²");
+        assert_eq!(land(-0.2).to_string(), "0 m²");
+    }
+
+    #[test]
+    fn area_symbols() {
+        assert_eq!(SquareMeter::SYMBOL, "m²");
+        assert_eq!(SquareCentimeter::SYMBOL, "cm²");
+        assert_eq!(SquareMillimeter::SYMBOL, "mm²");
+        assert_eq!(SquareFoot::SYMBOL, "ft²");
+        assert_eq!(SquareInch::SYMBOL, "in²");
+        assert_eq!(SquareYard::SYMBOL, "yd²");
+        assert_eq!(Are::SYMBOL, "a");
+        assert_eq!(Hectare::SYMBOL, "ha");
+        assert_eq!(Acre::SYMBOL, "ac");
+        assert_eq!(CircularMil::SYMBOL, "cmil");
+    }
+}
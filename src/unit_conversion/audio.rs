@@ -0,0 +1,216 @@
+//! # Musical Pitch Helpers
+//!
+//! Pitch is logarithmic in frequency: an octave doubles the frequency, and equal
+//! temperament divides it into 12 semitones of 100 cents each. This module converts
+//! between typed [`Frequency`] values, MIDI note numbers (60 is middle C, 69 is A4), and
+//! cent offsets, using standard A4 = 440 Hz tuning.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::audio::{midi_note_frequency, nearest_midi_note};
+//! use uom::unit_conversion::frequency::{Frequency, Hertz};
+//!
+//! let middle_c = midi_note_frequency(60.0);
+//! assert!((middle_c.to_unit::<Hertz>() - 261.6256).abs() < 1e-4);
+//!
+//! // A slightly sharp A4
+//! let (note, cents) = nearest_midi_note(Frequency::from_unit::<Hertz>(442.0)).unwrap();
+//! assert_eq!(note, 69);
+//! assert!((cents - 7.85).abs() < 0.01);
+//! ```
+
+use crate::unit_conversion::frequency::{Frequency, Hertz};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// Frequency of the tuning reference A4, in hertz.
+const A4_HERTZ: f64 = 440.0;
+
+/// MIDI note number of A4.
+const A4_NOTE: f64 = 69.0;
+
+const SEMITONES_PER_OCTAVE: f64 = 12.0;
+const CENTS_PER_SEMITONE: f64 = 100.0;
+const CENTS_PER_OCTAVE: f64 = SEMITONES_PER_OCTAVE * CENTS_PER_SEMITONE;
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Returns the equal-tempered frequency of a MIDI note number.
+///
+/// Fractional note numbers are allowed, so `69.5` is A4 raised by 50 cents.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::audio::midi_note_frequency;
+/// use uom::unit_conversion::frequency::Hertz;
+///
+/// assert_eq!(midi_note_frequency(69.0).to_unit::<Hertz>(), 440.0);
+/// assert_eq!(midi_note_frequency(57.0).to_unit::<Hertz>(), 220.0);
+/// ```
+pub fn midi_note_frequency(note: f64) -> Frequency {
+    let octaves = (note - A4_NOTE) / SEMITONES_PER_OCTAVE;
+    Frequency::from_unit::<Hertz>(A4_HERTZ * octaves.exp2())
+}
+
+/// Returns the fractional MIDI note number of a frequency.
+///
+/// Returns [`UnitError::OutOfRange`] if the frequency is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::audio::midi_note;
+/// use uom::unit_conversion::frequency::{Frequency, Hertz};
+///
+/// let note = midi_note(Frequency::from_unit::<Hertz>(880.0)).unwrap();
+/// assert!((note - 81.0).abs() < 1e-12);
+/// ```
+pub fn midi_note(frequency: Frequency) -> Result<f64, UnitError> {
+    let hertz = check_positive(frequency.to_unit::<Hertz>())?;
+    Ok(A4_NOTE + SEMITONES_PER_OCTAVE * (hertz / A4_HERTZ).log2())
+}
+
+/// Returns the nearest MIDI note to a frequency and how far the frequency is from it,
+/// in cents.
+///
+/// The offset lies in `-50.0..=50.0` and is positive when the frequency is sharp of the
+/// note. Returns [`UnitError::OutOfRange`] if the frequency is not positive and finite.
+pub fn nearest_midi_note(frequency: Frequency) -> Result<(i32, f64), UnitError> {
+    let note = midi_note(frequency)?;
+    let nearest = note.round();
+    Ok((nearest as i32, (note - nearest) * CENTS_PER_SEMITONE))
+}
+
+/// Returns the interval from one frequency to another in cents.
+///
+/// The result is positive when `to` is higher than `from`. Returns
+/// [`UnitError::OutOfRange`] if either frequency is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::audio::cents_between;
+/// use uom::unit_conversion::frequency::{Frequency, Hertz};
+///
+/// let a4 = Frequency::from_unit::<Hertz>(440.0);
+/// let e5 = Frequency::from_unit::<Hertz>(660.0);
+/// // A just perfect fifth is about 2 cents wider than the tempered one
+/// assert!((cents_between(a4, e5).unwrap() - 701.955).abs() < 1e-3);
+/// ```
+pub fn cents_between(from: Frequency, to: Frequency) -> Result<f64, UnitError> {
+    let from = check_positive(from.to_unit::<Hertz>())?;
+    let to = check_positive(to.to_unit::<Hertz>())?;
+    Ok(CENTS_PER_OCTAVE * (to / from).log2())
+}
+
+/// Returns the frequency offset from `frequency` by the given number of cents.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::audio::offset_by_cents;
+/// use uom::unit_conversion::frequency::{Frequency, Hertz};
+///
+/// let octave_up = offset_by_cents(Frequency::from_unit::<Hertz>(440.0), 1200.0);
+/// assert!((octave_up.to_unit::<Hertz>() - 880.0).abs() < 1e-9);
+/// ```
+pub fn offset_by_cents(frequency: Frequency, cents: f64) -> Frequency {
+    let factor = (cents / CENTS_PER_OCTAVE).exp2();
+    Frequency::from_unit::<Hertz>(frequency.to_unit::<Hertz>() * factor)
+}
+
+/// Returns the scientific pitch name of a MIDI note number (e.g., "A4" or "C#-1").
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::audio::note_name;
+///
+/// assert_eq!(note_name(60), "C4");
+/// assert_eq!(note_name(70), "A#4");
+/// assert_eq!(note_name(0), "C-1");
+/// ```
+pub fn note_name(note: i32) -> String {
+    let name = NOTE_NAMES[note.rem_euclid(12) as usize];
+    let octave = note.div_euclid(12) - 1;
+    format!("{name}{octave}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn hertz(value: f64) -> Frequency {
+        Frequency::from_unit::<Hertz>(value)
+    }
+
+    #[test]
+    fn note_frequencies() {
+        assert!(approx(
+            midi_note_frequency(21.0).to_unit::<Hertz>(),
+            27.5,
+            1e-12
+        ));
+        assert!(approx(
+            midi_note_frequency(108.0).to_unit::<Hertz>(),
+            4186.009,
+            1e-3
+        ));
+        assert!(approx(
+            midi_note_frequency(69.5).to_unit::<Hertz>(),
+            452.893,
+            1e-3
+        ));
+    }
+
+    #[test]
+    fn notes_round_trip() {
+        for note in 0..128 {
+            let frequency = midi_note_frequency(note.into());
+            assert!(approx(midi_note(frequency).unwrap(), note.into(), 1e-9));
+            let (nearest, cents) = nearest_midi_note(frequency).unwrap();
+            assert_eq!(nearest, note);
+            assert!(approx(cents, 0.0, 1e-7));
+        }
+    }
+
+    #[test]
+    fn cents_offsets() {
+        let (note, cents) = nearest_midi_note(hertz(430.0)).unwrap();
+        assert_eq!(note, 69);
+        assert!(approx(cents, -39.80, 1e-2));
+
+        let shifted = offset_by_cents(hertz(440.0), -39.80);
+        assert!(approx(shifted.to_unit::<Hertz>(), 430.0, 1e-2));
+
+        assert!(approx(
+            cents_between(hertz(440.0), hertz(220.0)).unwrap(),
+            -1200.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn note_names() {
+        assert_eq!(note_name(69), "A4");
+        assert_eq!(note_name(61), "C#4");
+        assert_eq!(note_name(71), "B4");
+        assert_eq!(note_name(72), "C5");
+        assert_eq!(note_name(127), "G9");
+        assert_eq!(note_name(-1), "B-2");
+    }
+
+    #[test]
+    fn rejects_non_positive_frequencies() {
+        assert!(midi_note(hertz(0.0)).is_err());
+        assert!(nearest_midi_note(hertz(-440.0)).is_err());
+        assert!(cents_between(hertz(440.0), hertz(f64::NAN)).is_err());
+    }
+}
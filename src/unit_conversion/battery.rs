@@ -0,0 +1,158 @@
+//! # Battery Capacity Helpers
+//!
+//! Battery cells are rated by the charge they hold, in milliampere-hours or
+//! ampere-hours, while the devices they power are rated in watts and the limits on
+//! carrying them (such as the 100 Wh airline limit) are set in watt-hours. The two are
+//! related by the nominal voltage of the battery: `E = Q × V`. This module converts
+//! between a [`Charge`] capacity and the [`Energy`] it stores, and estimates how long
+//! that energy lasts under a constant load.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::battery::{energy, runtime};
+//! use uom::unit_conversion::charge::{Charge, MilliampereHour};
+//! use uom::unit_conversion::energy::WattHour;
+//! use uom::unit_conversion::power::{Power, Watt};
+//! use uom::unit_conversion::time::Hour;
+//! use uom::unit_conversion::voltage::{Volt, Voltage};
+//!
+//! // A 20 000 mAh power bank built from 3.7 V cells
+//! let stored = energy(
+//!     Charge::from_unit::<MilliampereHour>(20_000.0),
+//!     Voltage::from_unit::<Volt>(3.7),
+//! );
+//! assert!((stored.to_unit::<WattHour>() - 74.0).abs() < 1e-9);
+//!
+//! let hours = runtime(stored, Power::from_unit::<Watt>(18.5)).unwrap();
+//! assert!((hours.to_unit::<Hour>() - 4.0).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::charge::Charge;
+use crate::unit_conversion::energy::Energy;
+use crate::unit_conversion::power::Power;
+use crate::unit_conversion::time::Time;
+use crate::unit_conversion::voltage::Voltage;
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// Returns the energy stored in a battery of charge `capacity` at voltage `nominal`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::battery::energy;
+/// use uom::unit_conversion::charge::{AmpereHour, Charge};
+/// use uom::unit_conversion::energy::KilowattHour;
+/// use uom::unit_conversion::voltage::{Volt, Voltage};
+///
+/// let house = energy(
+///     Charge::from_unit::<AmpereHour>(200.0),
+///     Voltage::from_unit::<Volt>(51.2),
+/// );
+/// assert!((house.to_unit::<KilowattHour>() - 10.24).abs() < 1e-12);
+/// ```
+pub fn energy(capacity: Charge, nominal: Voltage) -> Energy {
+    Energy::from_base(capacity.in_base() * nominal.in_base())
+}
+
+/// Returns the charge capacity of a battery storing `energy` at voltage `nominal`.
+///
+/// Returns [`UnitError::OutOfRange`] if the voltage is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::battery::capacity;
+/// use uom::unit_conversion::charge::MilliampereHour;
+/// use uom::unit_conversion::energy::{Energy, WattHour};
+/// use uom::unit_conversion::voltage::{Volt, Voltage};
+///
+/// // The largest power bank allowed in carry-on luggage without approval
+/// let limit = capacity(
+///     Energy::from_unit::<WattHour>(100.0),
+///     Voltage::from_unit::<Volt>(3.7),
+/// )
+/// .unwrap();
+/// assert!((limit.to_unit::<MilliampereHour>() - 27_027.0).abs() < 1.0);
+/// ```
+pub fn capacity(energy: Energy, nominal: Voltage) -> Result<Charge, UnitError> {
+    let volts = check_positive(nominal.in_base())?;
+    Ok(Charge::from_base(energy.in_base() / volts))
+}
+
+/// Returns how long `capacity` lasts under a constant `load`.
+///
+/// The estimate is ideal: it ignores conversion losses, the cut-off voltage and the
+/// loss of capacity at high discharge rates, so real runtimes are shorter.
+///
+/// Returns [`UnitError::OutOfRange`] if the load is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::battery::runtime;
+/// use uom::unit_conversion::energy::{Energy, WattHour};
+/// use uom::unit_conversion::power::{Power, Watt};
+/// use uom::unit_conversion::time::Hour;
+///
+/// let laptop = runtime(
+///     Energy::from_unit::<WattHour>(60.0),
+///     Power::from_unit::<Watt>(8.0),
+/// )
+/// .unwrap();
+/// assert_eq!(laptop.to_unit::<Hour>(), 7.5);
+/// ```
+pub fn runtime(capacity: Energy, load: Power) -> Result<Time, UnitError> {
+    let watts = check_positive(load.in_base())?;
+    Ok(Time::from_base(capacity.in_base() / watts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::charge::{AmpereHour, MilliampereHour};
+    use crate::unit_conversion::energy::{Joule, WattHour};
+    use crate::unit_conversion::power::Watt;
+    use crate::unit_conversion::time::{Hour, Second};
+    use crate::unit_conversion::voltage::Volt;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn charge_and_energy_round_trip() {
+        let nominal = Voltage::from_unit::<Volt>(3.85);
+        let stored = energy(Charge::from_unit::<MilliampereHour>(5000.0), nominal);
+        assert!(approx(stored.to_unit::<WattHour>(), 19.25, 1e-9));
+        assert!(approx(
+            capacity(stored, nominal)
+                .unwrap()
+                .to_unit::<MilliampereHour>(),
+            5000.0,
+            1e-9
+        ));
+
+        let one = energy(
+            Charge::from_unit::<AmpereHour>(1.0),
+            Voltage::from_unit::<Volt>(1.0),
+        );
+        assert!(approx(one.to_unit::<Joule>(), 3600.0, 1e-9));
+    }
+
+    #[test]
+    fn runtime_under_load() {
+        let stored = Energy::from_unit::<WattHour>(10.0);
+        let time = runtime(stored, Power::from_unit::<Watt>(2.5)).unwrap();
+        assert!(approx(time.to_unit::<Hour>(), 4.0, 1e-12));
+        assert!(approx(time.to_unit::<Second>(), 14_400.0, 1e-9));
+    }
+
+    #[test]
+    fn rejects_non_positive_divisors() {
+        let stored = Energy::from_unit::<WattHour>(10.0);
+        assert!(capacity(stored, Voltage::from_unit::<Volt>(0.0)).is_err());
+        assert!(runtime(stored, Power::from_unit::<Watt>(0.0)).is_err());
+        assert!(runtime(stored, Power::from_unit::<Watt>(-1.0)).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! # Beaufort Wind Force Scale
+//!
+//! The Beaufort scale grades wind from force 0 (calm) to force 12 (hurricane force).
+//! Each force covers a range of wind speeds, and the ranges widen as the wind gets
+//! stronger, so a force number is not a unit and cannot be converted with a scale factor.
+//! This module maps between [`Beaufort`] numbers and typed [`Speed`] ranges using the
+//! World Meteorological Organization table.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::beaufort::Beaufort;
+//! use uom::unit_conversion::speed::{Knot, Speed};
+//!
+//! let force = Beaufort::from_speed(Speed::from_unit::<Knot>(25.0)).unwrap();
+//! assert_eq!(force.number(), 6);
+//! assert_eq!(force.description(), "strong breeze");
+//!
+//! let (low, high) = force.range();
+//! assert!(low.to_unit::<Knot>() < 25.0 && 25.0 < high.unwrap().to_unit::<Knot>());
+//! ```
+
+use crate::unit_conversion::UnitError;
+use crate::unit_conversion::speed::{MeterPerSecond, Speed};
+
+/// Lower bound of each force in meters per second, from the WMO table.
+const LOWER_BOUNDS: [f64; 13] = [
+    0.0, 0.3, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+];
+
+/// Descriptive term for each force.
+const DESCRIPTIONS: [&str; 13] = [
+    "calm",
+    "light air",
+    "light breeze",
+    "gentle breeze",
+    "moderate breeze",
+    "fresh breeze",
+    "strong breeze",
+    "near gale",
+    "gale",
+    "strong gale",
+    "storm",
+    "violent storm",
+    "hurricane force",
+];
+
+/// A force on the Beaufort wind scale, from 0 to 12.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::beaufort::Beaufort;
+/// use uom::unit_conversion::speed::MeterPerSecond;
+///
+/// let gale = Beaufort::new(8).unwrap();
+/// assert!((gale.midpoint().to_unit::<MeterPerSecond>() - 19.0).abs() < 1e-9);
+/// assert!(Beaufort::new(13).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Beaufort(u8);
+
+impl Beaufort {
+    /// The highest force on the scale.
+    pub const MAX: u8 = 12;
+
+    /// Creates a Beaufort force from its number.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `number` is greater than 12.
+    pub fn new(number: u8) -> Result<Self, UnitError> {
+        if number > Self::MAX {
+            return Err(UnitError::OutOfRange {
+                value: number.into(),
+                min: 0.0,
+                max: Self::MAX.into(),
+            });
+        }
+        Ok(Self(number))
+    }
+
+    /// Returns the force whose speed range contains the given wind speed.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if the speed is negative or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::beaufort::Beaufort;
+    /// use uom::unit_conversion::speed::{KilometerPerHour, Speed};
+    ///
+    /// let force = Beaufort::from_speed(Speed::from_unit::<KilometerPerHour>(130.0)).unwrap();
+    /// assert_eq!(force.number(), 12);
+    /// ```
+    pub fn from_speed(speed: Speed) -> Result<Self, UnitError> {
+        let meters_per_second = speed.to_unit::<MeterPerSecond>();
+        if meters_per_second.is_nan() || meters_per_second < 0.0 {
+            return Err(UnitError::OutOfRange {
+                value: meters_per_second,
+                min: 0.0,
+                max: f64::INFINITY,
+            });
+        }
+        let above = LOWER_BOUNDS
+            .iter()
+            .filter(|&&lower| meters_per_second >= lower)
+            .count();
+        Ok(Self((above - 1) as u8))
+    }
+
+    /// Returns the force number.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the WMO descriptive term for this force (e.g., "fresh breeze").
+    pub fn description(self) -> &'static str {
+        DESCRIPTIONS[usize::from(self.0)]
+    }
+
+    /// Returns the range of wind speeds for this force as `(lower, upper)`.
+    ///
+    /// The lower bound is inclusive and the upper bound exclusive. Force 12 has no
+    /// upper bound, so `upper` is `None` for it.
+    pub fn range(self) -> (Speed, Option<Speed>) {
+        let index = usize::from(self.0);
+        let lower = Speed::from_unit::<MeterPerSecond>(LOWER_BOUNDS[index]);
+        let upper = LOWER_BOUNDS
+            .get(index + 1)
+            .map(|&bound| Speed::from_unit::<MeterPerSecond>(bound));
+        (lower, upper)
+    }
+
+    /// Returns a representative wind speed for this force, the middle of its range.
+    ///
+    /// Force 12 is open-ended, so its lower bound is returned instead.
+    pub fn midpoint(self) -> Speed {
+        match self.range() {
+            (lower, Some(upper)) => Speed::from_unit::<MeterPerSecond>(
+                (lower.to_unit::<MeterPerSecond>() + upper.to_unit::<MeterPerSecond>()) / 2.0,
+            ),
+            (lower, None) => lower,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::speed::Knot;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn force_at(meters_per_second: f64) -> u8 {
+        Beaufort::from_speed(Speed::from_unit::<MeterPerSecond>(meters_per_second))
+            .unwrap()
+            .number()
+    }
+
+    #[test]
+    fn speeds_map_to_forces() {
+        assert_eq!(force_at(0.0), 0);
+        assert_eq!(force_at(0.29), 0);
+        assert_eq!(force_at(0.3), 1);
+        assert_eq!(force_at(10.7), 5);
+        assert_eq!(force_at(10.8), 6);
+        assert_eq!(force_at(32.6), 11);
+        assert_eq!(force_at(70.0), 12);
+    }
+
+    #[test]
+    fn knot_speeds() {
+        // The WMO table in knots: force 4 is 11-16 kn, force 8 is 34-40 kn
+        let force = |knots| {
+            Beaufort::from_speed(Speed::from_unit::<Knot>(knots))
+                .unwrap()
+                .number()
+        };
+        assert_eq!(force(11.0), 4);
+        assert_eq!(force(15.0), 4);
+        assert_eq!(force(34.0), 8);
+        assert_eq!(force(40.0), 8);
+    }
+
+    #[test]
+    fn ranges_tile_the_scale() {
+        for number in 0..Beaufort::MAX {
+            let (_, upper) = Beaufort::new(number).unwrap().range();
+            let (next_lower, _) = Beaufort::new(number + 1).unwrap().range();
+            assert_eq!(upper, Some(next_lower));
+        }
+        assert_eq!(Beaufort::new(12).unwrap().range().1, None);
+    }
+
+    #[test]
+    fn midpoints_round_trip() {
+        for number in 0..=Beaufort::MAX {
+            let force = Beaufort::new(number).unwrap();
+            assert_eq!(Beaufort::from_speed(force.midpoint()).unwrap(), force);
+        }
+        let calm = Beaufort::new(0).unwrap().midpoint();
+        assert!(approx(calm.to_unit::<MeterPerSecond>(), 0.15, 1e-12));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(Beaufort::new(13).is_err());
+        assert!(Beaufort::from_speed(Speed::from_unit::<MeterPerSecond>(-1.0)).is_err());
+        assert!(Beaufort::from_speed(Speed::from_unit::<MeterPerSecond>(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn descriptions() {
+        assert_eq!(Beaufort::new(0).unwrap().description(), "calm");
+        assert_eq!(Beaufort::new(7).unwrap().description(), "near gale");
+        assert_eq!(Beaufort::new(12).unwrap().description(), "hurricane force");
+    }
+}
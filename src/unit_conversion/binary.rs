@@ -0,0 +1,205 @@
+//! # Binary Encoding
+//!
+//! A fixed-size binary form of a [`Quantity`] for telemetry frames and other wire
+//! protocols where both ends are built separately and may run different versions of
+//! this crate. Every quantity encodes to exactly [`ENCODED_LEN`] bytes:
+//!
+//! | Offset | Size | Content |
+//! |--------|------|---------|
+//! | 0 | 1 | Format version, currently [`FORMAT_VERSION`] |
+//! | 1 | 1 | Dimension identifier ([`Dimension::ID`]) |
+//! | 2 | 8 | Value in the dimension's base unit, IEEE 754 `f64`, little-endian |
+//!
+//! The layout is independent of the host's endianness and of any serialization
+//! framework. Embedded in a postcard or bincode message as a `[u8; ENCODED_LEN]`, it
+//! is copied through verbatim. A decoder rejects versions it does not know, so a
+//! future layout can be introduced under a new version byte without being misread.
+//!
+//! Dimension identifiers are part of the format and never change:
+//!
+//! | Id | Dimension |
+//! |----|-----------|
+//! | 1 | length |
+//! | 2 | temperature |
+//! | 3 | mass |
+//! | 4 | volume |
+//! | 5 | time |
+//! | 6 | angle |
+//! | 7 | energy |
+//! | 8 | power |
+//! | 9 | pressure |
+//! | 10 | area |
+//! | 11 | speed |
+//! | 12 | voltage |
+//! | 13 | frequency |
+//! | 14 | ratio |
+//! | 15 | amount |
+//! | 16 | force |
+//! | 17 | acceleration |
+//! | 18 | data size |
+//! | 19 | data rate |
+//! | 20 | current |
+//! | 21 | resistance |
+//! | 22 | charge |
+//! | 23 | distance emissions |
+//! | 24 | energy emissions |
+//! | 25 | mass concentration |
+//! | 26 | molar concentration |
+//! | 27 | mass rate |
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::length::{Kilometer, Length, Meter};
+//! use uom::unit_conversion::temperature::Temperature;
+//!
+//! let bytes = Length::from_unit::<Kilometer>(1.5).to_bytes();
+//! assert_eq!(bytes[..2], [1, 1]);
+//! assert_eq!(bytes[2..], 1500.0f64.to_le_bytes());
+//!
+//! let length = Length::from_bytes(&bytes).unwrap();
+//! assert_eq!(length.to_unit::<Meter>(), 1500.0);
+//!
+//! // The dimension travels with the value
+//! assert!(Temperature::from_bytes(&bytes).is_err());
+//! ```
+
+use crate::unit_conversion::registry::DIMENSIONS;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError};
+
+/// The version byte written by [`Quantity::to_bytes`].
+pub const FORMAT_VERSION: u8 = 1;
+
+/// The length in bytes of an encoded quantity.
+pub const ENCODED_LEN: usize = 10;
+
+impl<U: UnitConversion> Quantity<U>
+where
+    U::Dimension: Dimension,
+{
+    /// Encodes the quantity in the fixed-size [binary format](self).
+    pub fn to_bytes(&self) -> [u8; ENCODED_LEN] {
+        let mut bytes = [0; ENCODED_LEN];
+        bytes[0] = FORMAT_VERSION;
+        bytes[1] = <U::Dimension as Dimension>::ID;
+        bytes[2..].copy_from_slice(&self.in_base().to_le_bytes());
+        bytes
+    }
+
+    /// Decodes a quantity written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if `bytes` is not [`ENCODED_LEN`] long, has
+    /// an unknown version, or names an unknown dimension, and
+    /// [`UnitError::DimensionMismatch`] if it holds a quantity of another dimension.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::UnitError;
+    /// use uom::unit_conversion::temperature::{Kelvin, Temperature};
+    ///
+    /// let mut frame = [1, 2, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// frame[2..].copy_from_slice(&300.0f64.to_le_bytes());
+    /// assert_eq!(Temperature::from_bytes(&frame).unwrap().to_unit::<Kelvin>(), 300.0);
+    ///
+    /// frame[0] = 2;
+    /// assert!(matches!(
+    ///     Temperature::from_bytes(&frame),
+    ///     Err(UnitError::ParseError { reason: "unsupported format version", .. })
+    /// ));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, UnitError> {
+        let error = |reason| UnitError::ParseError {
+            input: format!("{bytes:02x?}"),
+            reason,
+        };
+        let bytes: &[u8; ENCODED_LEN] = bytes
+            .try_into()
+            .map_err(|_| error("wrong encoded length"))?;
+        if bytes[0] != FORMAT_VERSION {
+            return Err(error("unsupported format version"));
+        }
+        let expected = <U::Dimension as Dimension>::ID;
+        if bytes[1] != expected {
+            return Err(match DIMENSIONS.iter().find(|info| info.id == bytes[1]) {
+                Some(found) => UnitError::DimensionMismatch {
+                    expected: <U::Dimension as Dimension>::NAME,
+                    found: found.name,
+                },
+                None => error("unknown dimension id"),
+            });
+        }
+        let mut value = [0; 8];
+        value.copy_from_slice(&bytes[2..]);
+        Ok(Self::from_base(f64::from_le_bytes(value)))
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Temperature};
+
+    #[test]
+    fn layout_is_fixed() {
+        // 20 °C is 293.15 K, 0x40725266_66666666 as an f64
+        let bytes = Temperature::from_unit::<Celsius>(20.0).to_bytes();
+        assert_eq!(
+            bytes,
+            [0x01, 0x02, 0x66, 0x66, 0x66, 0x66, 0x66, 0x52, 0x72, 0x40]
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for feet in [0.0, -3.5, 1e300, f64::INFINITY] {
+            let length = Length::from_unit::<Foot>(feet);
+            assert_eq!(Length::from_bytes(&length.to_bytes()).unwrap(), length);
+        }
+        let nan = Length::from_unit::<Meter>(f64::NAN).to_bytes();
+        assert!(Length::from_bytes(&nan).unwrap().in_base().is_nan());
+    }
+
+    #[test]
+    fn copied_verbatim_by_postcard() {
+        let bytes = Length::from_unit::<Meter>(2.0).to_bytes();
+        assert_eq!(postcard::to_allocvec(&bytes).unwrap(), bytes);
+        let decoded: [u8; ENCODED_LEN] = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let bytes = Length::from_unit::<Meter>(2.0).to_bytes();
+        let reason = |bytes: &[u8]| match Length::from_bytes(bytes) {
+            Err(UnitError::ParseError { reason, .. }) => reason,
+            other => panic!("unexpected {other:?}"),
+        };
+        assert_eq!(reason(&bytes[..9]), "wrong encoded length");
+        assert_eq!(reason(&[&bytes[..], &[0]].concat()), "wrong encoded length");
+
+        let mut unknown = bytes;
+        unknown[0] = 0;
+        assert_eq!(reason(&unknown), "unsupported format version");
+
+        let mut unknown = bytes;
+        unknown[1] = 0;
+        assert_eq!(reason(&unknown), "unknown dimension id");
+
+        assert_eq!(
+            Temperature::from_bytes(&bytes),
+            Err(UnitError::DimensionMismatch {
+                expected: "temperature",
+                found: "length",
+            })
+        );
+    }
+}
@@ -0,0 +1,417 @@
+//! # Sampled Integration and Differentiation
+//!
+//! Telemetry arrives as timestamped samples: a meter's power draw every few seconds, a
+//! vehicle's odometer reading at each GPS fix. [`integrate`] turns sampled power into
+//! the energy used, and [`differentiate`] turns sampled position into speed. Their
+//! signatures fix the resulting dimension, so integrating power can only produce an
+//! energy and differentiating a length only a speed.
+//!
+//! Samples are taken in order and may be unevenly spaced. Times should increase from
+//! one sample to the next; see each function for what happens when they do not.
+//!
+//! For live streams, an [`Odometer`] accumulates distance from speed samples as they
+//! arrive, as a wheel encoder, a treadmill or a GPS watch reports them.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::calculus::{differentiate, integrate};
+//! use uom::unit_conversion::energy::WattHour;
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//! use uom::unit_conversion::power::{Power, Watt};
+//! use uom::unit_conversion::speed::KilometerPerHour;
+//! use uom::unit_conversion::time::{Minute, Time};
+//!
+//! // A heater ramping from 1 kW to 2 kW over half an hour
+//! let draw = [(0.0, 1000.0), (30.0, 2000.0)]
+//!     .map(|(min, w)| (Time::from_unit::<Minute>(min), Power::from_unit::<Watt>(w)));
+//! let used = integrate(&draw);
+//! assert!((used.to_unit::<WattHour>() - 750.0).abs() < 1e-9);
+//!
+//! // Odometer readings one minute apart
+//! let trip = [(0.0, 0.0), (1.0, 1.5), (2.0, 3.0)]
+//!     .map(|(min, km)| (Time::from_unit::<Minute>(min), Length::from_unit::<Kilometer>(km)));
+//! let speeds = differentiate(&trip);
+//! assert!(speeds.iter().all(|(_, v)| (v.to_unit::<KilometerPerHour>() - 90.0).abs() < 1e-9));
+//! ```
+
+use crate::unit_conversion::energy::{Energy, Joule};
+use crate::unit_conversion::length::{Length, Meter};
+use crate::unit_conversion::power::{Power, Watt};
+use crate::unit_conversion::speed::{MeterPerSecond, Speed};
+use crate::unit_conversion::time::{Second, Time};
+use crate::unit_conversion::{UnitError, check_finite};
+
+/// Returns the energy delivered by the sampled power, integrated with the trapezoidal
+/// rule.
+///
+/// Each interval contributes the mean of the power at its two ends times its length,
+/// so power is assumed to change linearly between samples. Fewer than two samples give
+/// zero energy. An interval whose end is earlier than its start contributes negatively,
+/// as an integral with reversed bounds does.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::calculus::integrate;
+/// use uom::unit_conversion::energy::KilowattHour;
+/// use uom::unit_conversion::power::{Kilowatt, Power};
+/// use uom::unit_conversion::time::{Hour, Time};
+///
+/// let load = [(0.0, 3.0), (1.0, 3.0), (2.5, 0.0)]
+///     .map(|(h, kw)| (Time::from_unit::<Hour>(h), Power::from_unit::<Kilowatt>(kw)));
+/// assert!((integrate(&load).to_unit::<KilowattHour>() - 5.25).abs() < 1e-9);
+/// ```
+pub fn integrate(samples: &[(Time, Power)]) -> Energy {
+    let joules = samples
+        .windows(2)
+        .map(|pair| {
+            let (t0, p0) = pair[0];
+            let (t1, p1) = pair[1];
+            let seconds = t1.to_unit::<Second>() - t0.to_unit::<Second>();
+            (p0.to_unit::<Watt>() + p1.to_unit::<Watt>()) / 2.0 * seconds
+        })
+        .sum();
+    Energy::from_unit::<Joule>(joules)
+}
+
+/// Returns the speed at each sample time, estimated from the sampled positions.
+///
+/// Interior samples use the central difference between their neighbours, and the
+/// first and last samples the difference to their one neighbour, so the result has
+/// one speed per sample. Fewer than two samples give no speeds. Two samples at the
+/// same time give an infinite or NaN speed.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::calculus::differentiate;
+/// use uom::unit_conversion::length::{Length, Meter};
+/// use uom::unit_conversion::speed::MeterPerSecond;
+/// use uom::unit_conversion::time::{Second, Time};
+///
+/// // A body falling from rest, 4.9 t² meters
+/// let fall = [0.0, 1.0, 2.0, 3.0]
+///     .map(|t: f64| (Time::from_unit::<Second>(t), Length::from_unit::<Meter>(4.9 * t * t)));
+/// let speeds: Vec<f64> = differentiate(&fall)
+///     .iter()
+///     .map(|(_, v)| v.to_unit::<MeterPerSecond>())
+///     .collect();
+/// assert_eq!(speeds.len(), 4);
+/// // Central differences are exact for a parabola at interior samples
+/// assert!((speeds[1] - 9.8).abs() < 1e-9 && (speeds[2] - 19.6).abs() < 1e-9);
+/// ```
+pub fn differentiate(samples: &[(Time, Length)]) -> Vec<(Time, Speed)> {
+    if samples.len() < 2 {
+        return Vec::new();
+    }
+    let last = samples.len() - 1;
+    (0..=last)
+        .map(|i| {
+            let (before, after) = (samples[i.saturating_sub(1)], samples[(i + 1).min(last)]);
+            let meters = after.1.to_unit::<Meter>() - before.1.to_unit::<Meter>();
+            let seconds = after.0.to_unit::<Second>() - before.0.to_unit::<Second>();
+            (
+                samples[i].0,
+                Speed::from_unit::<MeterPerSecond>(meters / seconds),
+            )
+        })
+        .collect()
+}
+
+/// How an [`Odometer`] estimates the distance covered between two speed samples.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Integration {
+    /// The earlier sample's speed is held until the next sample, as a speedometer
+    /// driving an odometer does. Suits speeds that change in steps.
+    Hold,
+    /// The speed changes linearly between samples, the trapezoidal rule. Suits smoothly
+    /// changing speeds.
+    #[default]
+    Trapezoidal,
+}
+
+/// Accumulates the distance covered from timestamped speed samples.
+///
+/// Negative speeds, such as a robot reversing, count backwards.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::calculus::{Integration, Odometer};
+/// use uom::unit_conversion::length::Kilometer;
+/// use uom::unit_conversion::speed::{KilometerPerHour, Speed};
+/// use uom::unit_conversion::time::{Minute, Time};
+///
+/// let mut run = Odometer::new();
+/// let mut held = Odometer::new().with_integration(Integration::Hold);
+/// for (min, kmh) in [(0.0, 10.0), (30.0, 12.0), (60.0, 12.0)] {
+///     let (t, v) = (Time::from_unit::<Minute>(min), Speed::from_unit::<KilometerPerHour>(kmh));
+///     run.record(t, v).unwrap();
+///     held.record(t, v).unwrap();
+/// }
+/// assert!((run.distance().to_unit::<Kilometer>() - 11.5).abs() < 1e-9);
+/// assert!((held.distance().to_unit::<Kilometer>() - 11.0).abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Odometer {
+    integration: Integration,
+    /// The distance covered, in meters.
+    meters: f64,
+    /// The previous sample's time in seconds and speed in meters per second.
+    last: Option<(f64, f64)>,
+}
+
+impl Odometer {
+    /// Creates an odometer at zero distance using the trapezoidal rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the odometer with the given integration scheme.
+    pub fn with_integration(mut self, integration: Integration) -> Self {
+        self.integration = integration;
+        self
+    }
+
+    /// Returns the odometer's integration scheme.
+    pub fn integration(&self) -> Integration {
+        self.integration
+    }
+
+    /// Records the speed at `time` and returns the distance covered so far.
+    ///
+    /// The first sample only sets the starting point. Times are measured from any
+    /// fixed origin, such as the start of the trip.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if `time` or `speed` is infinite or NaN, and
+    /// [`UnitError::OutOfRange`], in seconds, if `time` is earlier than the previous
+    /// sample. The odometer is unchanged on error.
+    pub fn record(&mut self, time: Time, speed: Speed) -> Result<Length, UnitError> {
+        let seconds = check_finite(time.to_unit::<Second>())?;
+        let meters_per_second = check_finite(speed.to_unit::<MeterPerSecond>())?;
+        if let Some((previous_time, previous_speed)) = self.last {
+            if seconds < previous_time {
+                return Err(UnitError::OutOfRange {
+                    value: seconds,
+                    min: previous_time,
+                    max: f64::INFINITY,
+                });
+            }
+            let mean_speed = match self.integration {
+                Integration::Hold => previous_speed,
+                Integration::Trapezoidal => (previous_speed + meters_per_second) / 2.0,
+            };
+            self.meters += mean_speed * (seconds - previous_time);
+        }
+        self.last = Some((seconds, meters_per_second));
+        Ok(self.distance())
+    }
+
+    /// Returns the distance covered so far.
+    pub fn distance(&self) -> Length {
+        Length::from_unit::<Meter>(self.meters)
+    }
+
+    /// Returns the odometer to zero distance and forgets the previous sample.
+    pub fn reset(&mut self) {
+        *self = Self::new().with_integration(self.integration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::energy::{KilowattHour, WattHour};
+    use crate::unit_conversion::length::Kilometer;
+    use crate::unit_conversion::power::Kilowatt;
+    use crate::unit_conversion::speed::KilometerPerHour;
+    use crate::unit_conversion::time::{Hour, Minute};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn integrates_uneven_samples_in_any_units() {
+        let samples = [
+            (
+                Time::from_unit::<Second>(0.0),
+                Power::from_unit::<Watt>(500.0),
+            ),
+            (
+                Time::from_unit::<Minute>(30.0),
+                Power::from_unit::<Kilowatt>(1.5),
+            ),
+            (
+                Time::from_unit::<Hour>(2.0),
+                Power::from_unit::<Watt>(1500.0),
+            ),
+        ];
+        // 0.5 h at a mean of 1 kW, then 1.5 h at 1.5 kW
+        assert!(approx(
+            integrate(&samples).to_unit::<KilowattHour>(),
+            2.75,
+            1e-12
+        ));
+
+        let mut reversed = samples;
+        reversed.reverse();
+        assert!(approx(
+            integrate(&reversed).to_unit::<KilowattHour>(),
+            -2.75,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn integrates_nothing_to_zero() {
+        assert_eq!(integrate(&[]).to_unit::<Joule>(), 0.0);
+        let one = [(
+            Time::from_unit::<Second>(5.0),
+            Power::from_unit::<Watt>(100.0),
+        )];
+        assert_eq!(integrate(&one).to_unit::<WattHour>(), 0.0);
+    }
+
+    #[test]
+    fn differentiates_at_each_sample_time() {
+        // Accelerating along a road, sampled unevenly
+        let samples = [(0.0, 0.0), (60.0, 1.0), (90.0, 2.0), (120.0, 3.5)].map(|(s, km)| {
+            (
+                Time::from_unit::<Second>(s),
+                Length::from_unit::<Kilometer>(km),
+            )
+        });
+        let speeds = differentiate(&samples);
+        assert_eq!(speeds.len(), 4);
+        let expected = [60.0, 80.0, 150.0, 180.0];
+        for ((t, v), ((time, _), kmh)) in speeds.iter().zip(samples.iter().zip(expected)) {
+            assert_eq!(t, time);
+            assert!(approx(v.to_unit::<KilometerPerHour>(), kmh, 1e-9));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict_physics", debug_assertions),
+        ignore = "builds physically impossible quantities"
+    )]
+    fn differentiates_short_and_degenerate_input() {
+        assert!(differentiate(&[]).is_empty());
+        let one = [(
+            Time::from_unit::<Second>(0.0),
+            Length::from_unit::<Meter>(1.0),
+        )];
+        assert!(differentiate(&one).is_empty());
+
+        let stalled = [
+            (
+                Time::from_unit::<Second>(1.0),
+                Length::from_unit::<Meter>(0.0),
+            ),
+            (
+                Time::from_unit::<Second>(1.0),
+                Length::from_unit::<Meter>(5.0),
+            ),
+        ];
+        let speeds = differentiate(&stalled);
+        assert!(
+            speeds
+                .iter()
+                .all(|(_, v)| v.to_unit::<MeterPerSecond>().is_infinite())
+        );
+    }
+
+    #[test]
+    fn odometer_integrates_live_samples() {
+        let mut odometer = Odometer::new();
+        assert_eq!(odometer.integration(), Integration::Trapezoidal);
+        let samples = [(0.0, 0.0), (10.0, 2.0), (20.0, 2.0), (25.0, 0.0)];
+        let mut distances = Vec::new();
+        for (s, mps) in samples {
+            let d = odometer
+                .record(
+                    Time::from_unit::<Second>(s),
+                    Speed::from_unit::<MeterPerSecond>(mps),
+                )
+                .unwrap();
+            distances.push(d.to_unit::<Meter>());
+        }
+        assert_eq!(distances, [0.0, 10.0, 30.0, 35.0]);
+
+        // A reversing robot and a repeated timestamp
+        odometer
+            .record(
+                Time::from_unit::<Second>(25.0),
+                Speed::from_unit::<MeterPerSecond>(-1.0),
+            )
+            .unwrap();
+        let back = odometer
+            .record(
+                Time::from_unit::<Second>(30.0),
+                Speed::from_unit::<MeterPerSecond>(-1.0),
+            )
+            .unwrap();
+        assert!(approx(back.to_unit::<Meter>(), 30.0, 1e-12));
+    }
+
+    #[test]
+    fn odometer_hold_uses_the_earlier_speed() {
+        let mut odometer = Odometer::new().with_integration(Integration::Hold);
+        for (min, kmh) in [(0.0, 60.0), (1.0, 120.0), (2.0, 0.0)] {
+            odometer
+                .record(
+                    Time::from_unit::<Minute>(min),
+                    Speed::from_unit::<KilometerPerHour>(kmh),
+                )
+                .unwrap();
+        }
+        assert!(approx(
+            odometer.distance().to_unit::<Kilometer>(),
+            3.0,
+            1e-9
+        ));
+
+        odometer.reset();
+        assert_eq!(odometer.distance().to_unit::<Meter>(), 0.0);
+        assert_eq!(odometer.integration(), Integration::Hold);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict_physics", debug_assertions),
+        ignore = "builds physically impossible quantities"
+    )]
+    fn odometer_rejects_bad_samples() {
+        let mut odometer = Odometer::new();
+        let speed = Speed::from_unit::<MeterPerSecond>(1.0);
+        odometer
+            .record(Time::from_unit::<Second>(10.0), speed)
+            .unwrap();
+        assert_eq!(
+            odometer.record(Time::from_unit::<Second>(5.0), speed),
+            Err(UnitError::OutOfRange {
+                value: 5.0,
+                min: 10.0,
+                max: f64::INFINITY
+            })
+        );
+        assert!(matches!(
+            odometer.record(Time::from_unit::<Second>(f64::NAN), speed),
+            Err(UnitError::NonFinite { .. })
+        ));
+        let before = odometer;
+        assert!(
+            odometer
+                .record(
+                    Time::from_unit::<Second>(20.0),
+                    Speed::from_unit::<MeterPerSecond>(f64::INFINITY)
+                )
+                .is_err()
+        );
+        assert_eq!(odometer, before);
+    }
+}
@@ -0,0 +1,256 @@
+//! # Sensor Calibration
+//!
+//! Raw sensor readings rarely map exactly onto a unit: an ADC count, a thermistor
+//! bridge voltage or a load-cell output needs a per-device correction found during
+//! field calibration. This module keeps that correction next to the unit conversion. A
+//! [`Calibration`] is a polynomial in the raw reading, and a [`CalibratedChannel`]
+//! applies it to produce a typed quantity in the unit the calibration was made in.
+//!
+//! Calibrations can be built in code or parsed from a configuration string listing the
+//! coefficients, constant term first.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::calibration::{CalibratedChannel, Calibration};
+//! use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+//!
+//! // A probe that reads 2% high with a 0.5 °C offset
+//! let probe = CalibratedChannel::<Celsius>::new(0.98, -0.5);
+//! let t: Temperature = probe.read(25.0);
+//! assert!((t.to_unit::<Celsius>() - 24.0).abs() < 1e-12);
+//!
+//! // A quadratic correction loaded from a config file
+//! let calibration: Calibration = "-0.2, 1.01, 0.0004".parse().unwrap();
+//! let channel = CalibratedChannel::<Fahrenheit>::with_calibration(calibration);
+//! let t: Temperature = channel.read(100.0);
+//! assert!((t.to_unit::<Fahrenheit>() - 104.8).abs() < 1e-9);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, check_finite};
+
+/// A polynomial mapping raw readings to calibrated values,
+/// `c0 + c1·raw + c2·raw² + …`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::calibration::Calibration;
+///
+/// let linear = Calibration::linear(2.0, 1.0);
+/// assert_eq!(linear.apply(3.0), 7.0);
+/// assert_eq!(linear.coefficients(), &[1.0, 2.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Calibration {
+    coefficients: Vec<f64>,
+}
+
+impl Calibration {
+    /// Creates a linear calibration, `gain·raw + offset`.
+    pub fn linear(gain: f64, offset: f64) -> Self {
+        Self {
+            coefficients: vec![offset, gain],
+        }
+    }
+
+    /// Creates a polynomial calibration from its coefficients, constant term first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if any coefficient is infinite or NaN, and
+    /// [`UnitError::OutOfRange`] if there are no coefficients.
+    pub fn polynomial(coefficients: Vec<f64>) -> Result<Self, UnitError> {
+        if coefficients.is_empty() {
+            return Err(UnitError::OutOfRange {
+                value: 0.0,
+                min: 1.0,
+                max: f64::INFINITY,
+            });
+        }
+        for &c in &coefficients {
+            check_finite(c)?;
+        }
+        Ok(Self { coefficients })
+    }
+
+    /// Returns the coefficients, constant term first.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Applies the calibration to a raw reading.
+    pub fn apply(&self, raw: f64) -> f64 {
+        // Horner's method
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &c| acc * raw + c)
+    }
+}
+
+/// The identity calibration, which passes raw readings through unchanged.
+impl Default for Calibration {
+    fn default() -> Self {
+        Self::linear(1.0, 0.0)
+    }
+}
+
+/// Parses coefficients separated by commas or whitespace, constant term first, such as
+/// `"-0.2, 1.01, 0.0004"`.
+impl FromStr for Calibration {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coefficients = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty())
+            .map(|field| {
+                field.parse::<f64>().map_err(|_| UnitError::ParseError {
+                    input: s.to_string(),
+                    reason: "invalid calibration coefficient",
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if coefficients.is_empty() {
+            return Err(UnitError::ParseError {
+                input: s.to_string(),
+                reason: "expected calibration coefficients",
+            });
+        }
+        Self::polynomial(coefficients)
+    }
+}
+
+/// Formats the coefficients in the form accepted by [`FromStr`].
+impl fmt::Display for Calibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.coefficients.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A sensor channel whose calibrated output is in unit `V`.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::calibration::CalibratedChannel;
+/// use uom::unit_conversion::length::{Length, Millimeter};
+///
+/// // A displacement sensor giving 0.05 mm per count
+/// let gauge = CalibratedChannel::<Millimeter>::new(0.05, 0.0);
+/// let travel: Length = gauge.read(1200.0);
+/// assert!((travel.to_unit::<Millimeter>() - 60.0).abs() < 1e-12);
+/// ```
+pub struct CalibratedChannel<V: UnitConversion> {
+    calibration: Calibration,
+    _unit: PhantomData<V>,
+}
+
+impl<V: UnitConversion> CalibratedChannel<V> {
+    /// Creates a channel with a linear calibration, `gain·raw + offset` in unit `V`.
+    pub fn new(gain: f64, offset: f64) -> Self {
+        Self::with_calibration(Calibration::linear(gain, offset))
+    }
+
+    /// Creates a channel with the given calibration, producing values in unit `V`.
+    pub fn with_calibration(calibration: Calibration) -> Self {
+        Self {
+            calibration,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the channel's calibration.
+    pub fn calibration(&self) -> &Calibration {
+        &self.calibration
+    }
+
+    /// Applies the calibration to a raw reading and returns the result as a quantity.
+    ///
+    /// The result can be any quantity type of `V`'s dimension, usually inferred from
+    /// the binding.
+    pub fn read<U>(&self, raw: f64) -> Quantity<U>
+    where
+        U: UnitConversion<Dimension = V::Dimension>,
+    {
+        Quantity::from_unit::<V>(self.calibration.apply(raw))
+    }
+}
+
+impl<V: UnitConversion> Clone for CalibratedChannel<V> {
+    fn clone(&self) -> Self {
+        Self::with_calibration(self.calibration.clone())
+    }
+}
+
+impl<V: UnitConversion> fmt::Debug for CalibratedChannel<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CalibratedChannel")
+            .field("calibration", &self.calibration)
+            .field("unit", &V::SYMBOL)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn polynomial_evaluation() {
+        let cubic = Calibration::polynomial(vec![1.0, -2.0, 0.5, 0.25]).unwrap();
+        assert!(approx(cubic.apply(2.0), 1.0 - 4.0 + 2.0 + 2.0, 1e-12));
+        assert_eq!(Calibration::default().apply(42.0), 42.0);
+    }
+
+    #[test]
+    fn offset_applies_in_the_channel_unit() {
+        // +1 in Celsius and in kelvin are the same step, but the zero differs
+        let channel = CalibratedChannel::<Celsius>::new(1.0, 1.0);
+        let t: Temperature = channel.read(0.0);
+        assert!(approx(t.to_unit::<Kelvin>(), 274.15, 1e-12));
+    }
+
+    #[test]
+    fn parses_and_displays_coefficients() {
+        let calibration: Calibration = " 0.5,1.25  -0.001 ".parse().unwrap();
+        assert_eq!(calibration.coefficients(), &[0.5, 1.25, -0.001]);
+        assert_eq!(calibration.to_string(), "0.5, 1.25, -0.001");
+        assert_eq!(calibration.to_string().parse(), Ok(calibration));
+    }
+
+    #[test]
+    fn rejects_bad_coefficients() {
+        assert!(matches!(
+            "".parse::<Calibration>(),
+            Err(UnitError::ParseError { .. })
+        ));
+        assert!(matches!(
+            "1.0, gain".parse::<Calibration>(),
+            Err(UnitError::ParseError { .. })
+        ));
+        assert!(matches!(
+            "1.0, NaN".parse::<Calibration>(),
+            Err(UnitError::NonFinite { .. })
+        ));
+        assert!(Calibration::polynomial(Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! # Electric Charge Unit Conversions
+//!
+//! This module provides type-safe electric charge conversions between the coulomb and the
+//! ampere-hours used to rate batteries. All charges are internally stored in coulombs (the
+//! base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Coulomb (C)**: The base unit, SI unit of electric charge
+//! - **Milliampere-hour (mAh)**: 3.6 coulombs
+//! - **Ampere-hour (Ah)**: 3600 coulombs
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::charge::{AmpereHour, Charge, MilliampereHour};
+//!
+//! let phone = Charge::from_unit::<MilliampereHour>(4500.0);
+//! assert!((phone.to_unit::<AmpereHour>() - 4.5).abs() < 1e-12);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for charge conversions
+const COULOMBS_PER_MILLIAMPERE_HOUR: f64 = 3.6;
+const COULOMBS_PER_AMPERE_HOUR: f64 = 3600.0;
+
+/// Marker type for the electric charge dimension.
+///
+/// This prevents accidental conversions between a battery's charge capacity and the
+/// energy it stores, which differ by the battery voltage.
+pub enum ChargeDimension {}
+
+impl Dimension for ChargeDimension {
+    const NAME: &'static str = "charge";
+    const ID: u8 = 22;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Coulomb>(&["coulomb", "coulombs"]),
+        UnitInfo::of::<MilliampereHour>(&[
+            "mA·h",
+            "mA⋅h",
+            "milliamp hour",
+            "milliamp hours",
+            "milliampere-hour",
+            "milliampere-hours",
+        ]),
+        UnitInfo::of::<AmpereHour>(&[
+            "A·h",
+            "A⋅h",
+            "amp hour",
+            "amp hours",
+            "ampere-hour",
+            "ampere-hours",
+        ]),
+    ];
+}
+
+/// An electric charge that stores values in coulombs internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::charge::{AmpereHour, Charge, Coulomb};
+///
+/// let pack = Charge::from_unit::<AmpereHour>(2.0);
+/// let coulombs = pack.to_unit::<Coulomb>(); // 7200.0
+/// ```
+pub type Charge = Quantity<Coulomb>;
+
+/// Coulomb charge unit (SI derived unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::charge::{Charge, Coulomb};
+///
+/// let capacitor = Charge::from_unit::<Coulomb>(0.01);
+/// assert_eq!(capacitor.to_unit::<Coulomb>(), 0.01);
+/// ```
+pub struct Coulomb;
+
+impl UnitConversion for Coulomb {
+    type Dimension = ChargeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Coulomb is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Coulomb is the base unit
+    }
+
+    const SYMBOL: &'static str = "C";
+    const NAME: &'static str = "coulomb";
+    const PLURAL: &'static str = "coulombs";
+}
+
+/// Milliampere-hour charge unit.
+///
+/// The charge moved by a current of one milliampere over one hour, 3.6 coulombs. Small
+/// batteries are rated in milliampere-hours.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::charge::{Charge, Coulomb, MilliampereHour};
+///
+/// let cell = Charge::from_unit::<MilliampereHour>(3000.0);
+/// assert!((cell.to_unit::<Coulomb>() - 10_800.0).abs() < 1e-9);
+/// ```
+pub struct MilliampereHour;
+
+impl UnitConversion for MilliampereHour {
+    type Dimension = ChargeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * COULOMBS_PER_MILLIAMPERE_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / COULOMBS_PER_MILLIAMPERE_HOUR
+    }
+
+    const SYMBOL: &'static str = "mAh";
+    const NAME: &'static str = "milliampere-hour";
+    const PLURAL: &'static str = "milliampere-hours";
+}
+
+/// Ampere-hour charge unit.
+///
+/// The charge moved by a current of one ampere over one hour, 3600 coulombs.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::charge::{AmpereHour, Charge, MilliampereHour};
+///
+/// let car_battery = Charge::from_unit::<AmpereHour>(60.0);
+/// assert_eq!(car_battery.to_unit::<MilliampereHour>(), 60_000.0);
+/// ```
+pub struct AmpereHour;
+
+impl UnitConversion for AmpereHour {
+    type Dimension = ChargeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * COULOMBS_PER_AMPERE_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / COULOMBS_PER_AMPERE_HOUR
+    }
+
+    const SYMBOL: &'static str = "Ah";
+    const NAME: &'static str = "ampere-hour";
+    const PLURAL: &'static str = "ampere-hours";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn ampere_hours() {
+        let charge = Charge::from_unit::<AmpereHour>(1.0);
+        assert!(approx(charge.to_unit::<Coulomb>(), 3600.0, 1e-9));
+        assert!(approx(charge.to_unit::<MilliampereHour>(), 1000.0, 1e-9));
+
+        for (alias, symbol) in [("mAh", "mAh"), ("amp hours", "Ah"), ("mA·h", "mAh")] {
+            assert_eq!(ChargeDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn charge_symbols() {
+        assert_eq!(Coulomb::SYMBOL, "C");
+        assert_eq!(MilliampereHour::SYMBOL, "mAh");
+        assert_eq!(AmpereHour::SYMBOL, "Ah");
+    }
+}
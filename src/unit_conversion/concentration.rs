@@ -0,0 +1,500 @@
+//! # Concentration Unit Conversions
+//!
+//! This module provides type-safe conversions for the concentration of a substance in a
+//! solution, as reported in laboratory results. A concentration is either a mass per
+//! volume ([`MassConcentration`], stored in grams per liter) or an amount per volume
+//! ([`MolarConcentration`], stored in millimoles per liter). The two are different
+//! dimensions: converting between them takes the molar mass of the substance, which an
+//! [`Analyte`] supplies.
+//!
+//! ## Supported Units
+//!
+//! - **Gram per liter (g/L)**: Base unit for mass concentration, equal to kg/m³
+//! - **Milligram per deciliter (mg/dL)**: 0.01 g/L, used for US laboratory results
+//! - **Milligram per liter (mg/L)**: 0.001 g/L
+//! - **Millimole per liter (mmol/L)**: Base unit for molar concentration, equal to mol/m³
+//! - **Mole per liter (mol/L)**: 1000 mmol/L, the molar (M)
+//! - **Micromole per liter (µmol/L)**: 0.001 mmol/L
+//! - **pH (pH)**: The negative decimal logarithm of the concentration in mol/L
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::concentration::{
+//!     Analyte, MassConcentration, MilligramPerDeciliter, MillimolePerLiter,
+//! };
+//!
+//! // A fasting blood glucose of 99 mg/dL
+//! let reading = MassConcentration::from_unit::<MilligramPerDeciliter>(99.0);
+//! let molar = Analyte::GLUCOSE.to_molar(reading);
+//! assert!((molar.to_unit::<MillimolePerLiter>() - 5.495).abs() < 1e-3);
+//! ```
+
+use crate::unit_conversion::logarithmic::LogarithmicUnit;
+use crate::unit_conversion::{
+    Dimension, NonNegative, Quantity, UnitConversion, UnitError, UnitInfo, check_positive,
+};
+
+/// Constants for concentration conversions
+const GRAMS_PER_LITER_PER_MILLIGRAM_PER_DECILITER: f64 = 0.01;
+const GRAMS_PER_MILLIGRAM: f64 = 1e-3;
+const MILLIMOLES_PER_MOLE: f64 = 1e3;
+const MILLIMOLES_PER_MICROMOLE: f64 = 1e-3;
+
+/// Marker type for the mass concentration dimension.
+///
+/// This prevents accidental conversions between a mass concentration and a molar
+/// concentration, which differ by the molar mass of the substance.
+pub enum MassConcentrationDimension {}
+
+impl Dimension for MassConcentrationDimension {
+    const NAME: &'static str = "mass concentration";
+    const ID: u8 = 25;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<GramPerLiter>(&["kg/m³", "kg/m3", "gram per liter", "grams per liter"]),
+        UnitInfo::of::<MilligramPerDeciliter>(&[
+            "milligram per deciliter",
+            "milligrams per deciliter",
+        ]),
+        UnitInfo::of::<MilligramPerLiter>(&["milligram per liter", "milligrams per liter"]),
+    ];
+}
+
+impl NonNegative for MassConcentrationDimension {}
+
+/// Marker type for the molar concentration dimension.
+///
+/// This prevents accidental conversions between a molar concentration and a mass
+/// concentration, which differ by the molar mass of the substance.
+pub enum MolarConcentrationDimension {}
+
+impl Dimension for MolarConcentrationDimension {
+    const NAME: &'static str = "molar concentration";
+    const ID: u8 = 26;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<MillimolePerLiter>(&[
+            "mM",
+            "mol/m³",
+            "millimole per liter",
+            "millimoles per liter",
+        ]),
+        UnitInfo::of::<MolePerLiter>(&["M", "mole per liter", "moles per liter"]),
+        UnitInfo::of::<MicromolePerLiter>(&[
+            "umol/L",
+            "μmol/L",
+            "µM",
+            "micromole per liter",
+            "micromoles per liter",
+        ]),
+        UnitInfo::of::<Ph>(&[]),
+    ];
+}
+
+impl NonNegative for MolarConcentrationDimension {}
+
+/// A mass concentration that stores values in grams per liter internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{GramPerLiter, MassConcentration, MilligramPerDeciliter};
+///
+/// let cholesterol = MassConcentration::from_unit::<MilligramPerDeciliter>(190.0);
+/// let grams = cholesterol.to_unit::<GramPerLiter>(); // 1.9
+/// ```
+pub type MassConcentration = Quantity<GramPerLiter>;
+
+/// A molar concentration that stores values in millimoles per liter internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{MicromolePerLiter, MolarConcentration, MolePerLiter};
+///
+/// let buffer = MolarConcentration::from_unit::<MolePerLiter>(0.05);
+/// let micromolar = buffer.to_unit::<MicromolePerLiter>(); // 50 000.0
+/// ```
+pub type MolarConcentration = Quantity<MillimolePerLiter>;
+
+/// Gram per liter mass concentration unit (base unit, equal to kg/m³).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{GramPerLiter, MassConcentration};
+///
+/// let plasma_protein = MassConcentration::from_unit::<GramPerLiter>(70.0);
+/// assert_eq!(plasma_protein.to_unit::<GramPerLiter>(), 70.0);
+/// ```
+pub struct GramPerLiter;
+
+impl UnitConversion for GramPerLiter {
+    type Dimension = MassConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // GramPerLiter is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // GramPerLiter is the base unit
+    }
+
+    const SYMBOL: &'static str = "g/L";
+    const NAME: &'static str = "gram per liter";
+    const PLURAL: &'static str = "grams per liter";
+    const PHYSICAL_RANGE: (f64, f64) = (0.0, f64::INFINITY);
+}
+
+/// Milligram per deciliter mass concentration unit, used for US laboratory results.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{GramPerLiter, MassConcentration, MilligramPerDeciliter};
+///
+/// let glucose = MassConcentration::from_unit::<MilligramPerDeciliter>(100.0);
+/// assert!((glucose.to_unit::<GramPerLiter>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct MilligramPerDeciliter;
+
+impl UnitConversion for MilligramPerDeciliter {
+    type Dimension = MassConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * GRAMS_PER_LITER_PER_MILLIGRAM_PER_DECILITER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / GRAMS_PER_LITER_PER_MILLIGRAM_PER_DECILITER
+    }
+
+    const SYMBOL: &'static str = "mg/dL";
+    const NAME: &'static str = "milligram per deciliter";
+    const PLURAL: &'static str = "milligrams per deciliter";
+}
+
+/// Milligram per liter mass concentration unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{MassConcentration, MilligramPerDeciliter, MilligramPerLiter};
+///
+/// let level = MassConcentration::from_unit::<MilligramPerLiter>(50.0);
+/// assert!((level.to_unit::<MilligramPerDeciliter>() - 5.0).abs() < 1e-12);
+/// ```
+pub struct MilligramPerLiter;
+
+impl UnitConversion for MilligramPerLiter {
+    type Dimension = MassConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * GRAMS_PER_MILLIGRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / GRAMS_PER_MILLIGRAM
+    }
+
+    const SYMBOL: &'static str = "mg/L";
+    const NAME: &'static str = "milligram per liter";
+    const PLURAL: &'static str = "milligrams per liter";
+}
+
+/// Millimole per liter molar concentration unit (base unit, equal to mol/m³).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{MillimolePerLiter, MolarConcentration};
+///
+/// let glucose = MolarConcentration::from_unit::<MillimolePerLiter>(5.5);
+/// assert_eq!(glucose.to_unit::<MillimolePerLiter>(), 5.5);
+/// ```
+pub struct MillimolePerLiter;
+
+impl UnitConversion for MillimolePerLiter {
+    type Dimension = MolarConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // MillimolePerLiter is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // MillimolePerLiter is the base unit
+    }
+
+    const SYMBOL: &'static str = "mmol/L";
+    const NAME: &'static str = "millimole per liter";
+    const PLURAL: &'static str = "millimoles per liter";
+    const PHYSICAL_RANGE: (f64, f64) = (0.0, f64::INFINITY);
+}
+
+/// Mole per liter molar concentration unit, the molar (M) of chemistry.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{MillimolePerLiter, MolarConcentration, MolePerLiter};
+///
+/// let saline = MolarConcentration::from_unit::<MolePerLiter>(0.154);
+/// assert!((saline.to_unit::<MillimolePerLiter>() - 154.0).abs() < 1e-9);
+/// ```
+pub struct MolePerLiter;
+
+impl UnitConversion for MolePerLiter {
+    type Dimension = MolarConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MILLIMOLES_PER_MOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MILLIMOLES_PER_MOLE
+    }
+
+    const SYMBOL: &'static str = "mol/L";
+    const NAME: &'static str = "mole per liter";
+    const PLURAL: &'static str = "moles per liter";
+}
+
+/// Micromole per liter molar concentration unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::concentration::{MicromolePerLiter, MillimolePerLiter, MolarConcentration};
+///
+/// let creatinine = MolarConcentration::from_unit::<MicromolePerLiter>(80.0);
+/// assert!((creatinine.to_unit::<MillimolePerLiter>() - 0.08).abs() < 1e-15);
+/// ```
+pub struct MicromolePerLiter;
+
+impl UnitConversion for MicromolePerLiter {
+    type Dimension = MolarConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * MILLIMOLES_PER_MICROMOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / MILLIMOLES_PER_MICROMOLE
+    }
+
+    const SYMBOL: &'static str = "µmol/L";
+    const NAME: &'static str = "micromole per liter";
+    const PLURAL: &'static str = "micromoles per liter";
+}
+
+/// pH, the negative decimal logarithm of a concentration in moles per liter.
+///
+/// Strictly pH measures the activity of hydrogen ions; treating it as their
+/// concentration is exact enough for dilute solutions. A pH of 7 is 10⁻⁷ mol/L.
+///
+/// # Examples
+///
+/// ```
+/// use uom::smart;
+/// use uom::unit_conversion::concentration::{MolarConcentration, MolePerLiter, Ph};
+///
+/// let vinegar = MolarConcentration::from_unit::<Ph>(2.4);
+/// assert!((vinegar.to_unit::<MolePerLiter>() - 0.00398).abs() < 1e-5);
+/// assert_eq!(smart!(7.0, 2).with_linear::<Ph>().to_string(), "7 pH (1.00e-7 mol/L)");
+/// ```
+pub struct Ph;
+
+impl UnitConversion for Ph {
+    type Dimension = MolarConcentrationDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        Self::to_linear(value) * MILLIMOLES_PER_MOLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        Self::from_linear(value / MILLIMOLES_PER_MOLE)
+    }
+
+    const SYMBOL: &'static str = "pH";
+    const NAME: &'static str = "pH";
+    const PLURAL: &'static str = "pH";
+}
+
+impl LogarithmicUnit for Ph {
+    const LOG_FACTOR: f64 = -1.0;
+    const REFERENCE: f64 = 1.0;
+    const LINEAR_SYMBOL: &'static str = "mol/L";
+}
+
+/// A dissolved substance, identified by its molar mass, that bridges mass and molar
+/// concentrations.
+///
+/// Clinical laboratories convert with fixed factors derived from the molar mass: for
+/// glucose, 1 mmol/L is 18.016 mg/dL. The constants here use the molar masses behind
+/// those factors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Analyte {
+    /// The molar mass, in grams per mole.
+    molar_mass: f64,
+}
+
+impl Analyte {
+    /// Glucose, C₆H₁₂O₆, 180.156 g/mol.
+    pub const GLUCOSE: Analyte = Analyte {
+        molar_mass: 180.156,
+    };
+    /// Cholesterol, C₂₇H₄₆O, 386.65 g/mol.
+    pub const CHOLESTEROL: Analyte = Analyte { molar_mass: 386.65 };
+    /// Triglycerides, measured as triolein, 885.7 g/mol.
+    pub const TRIGLYCERIDES: Analyte = Analyte { molar_mass: 885.7 };
+    /// Creatinine, C₄H₇N₃O, 113.12 g/mol.
+    pub const CREATININE: Analyte = Analyte { molar_mass: 113.12 };
+    /// Urea, CH₄N₂O, 60.06 g/mol.
+    pub const UREA: Analyte = Analyte { molar_mass: 60.06 };
+
+    /// Creates an analyte with molar mass `molar_mass` grams per mole.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] unless `molar_mass` is positive and finite.
+    pub fn new(molar_mass: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            molar_mass: check_positive(molar_mass)?,
+        })
+    }
+
+    /// Returns the molar mass, in grams per mole.
+    pub fn molar_mass(&self) -> f64 {
+        self.molar_mass
+    }
+
+    /// Converts a mass concentration of this analyte to a molar concentration.
+    pub fn to_molar(&self, concentration: MassConcentration) -> MolarConcentration {
+        // g/L divided by g/mol is mol/L
+        MolarConcentration::from_unit::<MolePerLiter>(concentration.in_base() / self.molar_mass)
+    }
+
+    /// Converts a molar concentration of this analyte to a mass concentration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::concentration::{
+    ///     Analyte, MilligramPerDeciliter, MillimolePerLiter, MolarConcentration,
+    /// };
+    ///
+    /// let hypo = MolarConcentration::from_unit::<MillimolePerLiter>(3.9);
+    /// let us = Analyte::GLUCOSE.to_mass(hypo);
+    /// assert!((us.to_unit::<MilligramPerDeciliter>() - 70.26).abs() < 0.01);
+    /// ```
+    pub fn to_mass(&self, concentration: MolarConcentration) -> MassConcentration {
+        MassConcentration::from_base(concentration.to_unit::<MolePerLiter>() * self.molar_mass)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn mass_units() {
+        let c = MassConcentration::from_unit::<MilligramPerDeciliter>(250.0);
+        assert!(approx(c.to_unit::<GramPerLiter>(), 2.5, 1e-12));
+        assert!(approx(c.to_unit::<MilligramPerLiter>(), 2500.0, 1e-9));
+    }
+
+    #[test]
+    fn molar_units() {
+        let c = MolarConcentration::from_unit::<MicromolePerLiter>(1500.0);
+        assert!(approx(c.to_unit::<MillimolePerLiter>(), 1.5, 1e-12));
+        assert!(approx(c.to_unit::<MolePerLiter>(), 0.0015, 1e-15));
+        assert_eq!(
+            MolarConcentrationDimension::find_unit("M").unwrap().symbol,
+            "mol/L"
+        );
+        assert_eq!(
+            MolarConcentrationDimension::find_unit("mM").unwrap().symbol,
+            "mmol/L"
+        );
+    }
+
+    #[test]
+    fn glucose_factor() {
+        let one = MolarConcentration::from_unit::<MillimolePerLiter>(1.0);
+        let factor = Analyte::GLUCOSE
+            .to_mass(one)
+            .to_unit::<MilligramPerDeciliter>();
+        assert!(approx(factor, 18.0156, 1e-9));
+
+        let reading = MassConcentration::from_unit::<MilligramPerDeciliter>(126.0);
+        let molar = Analyte::GLUCOSE.to_molar(reading);
+        assert!(approx(molar.to_unit::<MillimolePerLiter>(), 6.994, 1e-3));
+        assert!(approx(
+            Analyte::GLUCOSE
+                .to_mass(molar)
+                .to_unit::<MilligramPerDeciliter>(),
+            126.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn other_analytes() {
+        // Creatinine: 1 mg/dL is 88.4 µmol/L
+        let creatinine = MassConcentration::from_unit::<MilligramPerDeciliter>(1.0);
+        let molar = Analyte::CREATININE.to_molar(creatinine);
+        assert!(approx(molar.to_unit::<MicromolePerLiter>(), 88.4, 0.01));
+
+        // Cholesterol: 1 mmol/L is 38.67 mg/dL
+        let one = MolarConcentration::from_unit::<MillimolePerLiter>(1.0);
+        let mass = Analyte::CHOLESTEROL.to_mass(one);
+        assert!(approx(
+            mass.to_unit::<MilligramPerDeciliter>(),
+            38.665,
+            1e-9
+        ));
+
+        let custom = Analyte::new(58.44).unwrap();
+        assert_eq!(custom.molar_mass(), 58.44);
+        assert!(Analyte::new(0.0).is_err());
+        assert!(Analyte::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn concentration_symbols() {
+        assert_eq!(GramPerLiter::SYMBOL, "g/L");
+        assert_eq!(MilligramPerDeciliter::SYMBOL, "mg/dL");
+        assert_eq!(MillimolePerLiter::SYMBOL, "mmol/L");
+        assert_eq!(MicromolePerLiter::SYMBOL, "µmol/L");
+        assert_eq!(Ph::SYMBOL, "pH");
+    }
+
+    #[test]
+    fn ph_scale() {
+        let neutral = MolarConcentration::from_unit::<Ph>(7.0);
+        assert!(approx(neutral.to_unit::<MicromolePerLiter>(), 0.1, 1e-12));
+        let acid = MolarConcentration::from_unit::<MolePerLiter>(0.01);
+        assert!(approx(acid.to_unit::<Ph>(), 2.0, 1e-12));
+        assert_eq!(
+            crate::smart!(2.0, 1).with_linear::<Ph>().to_string(),
+            "2 pH (0.0100 mol/L)"
+        );
+    }
+}
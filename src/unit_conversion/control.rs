@@ -0,0 +1,732 @@
+//! # Control Helpers
+//!
+//! Building blocks for control loops that work on typed quantities. A
+//! [`RateLimiter`] constrains how fast a signal may change per unit time, as when a
+//! furnace setpoint may ramp by at most 2 °C/min or a motor speed command must not
+//! step: each update moves the output toward the requested target by no more than the
+//! allowed rate times the elapsed [`Time`].
+//!
+//! Rates are differences per time, so a limit of 2 °C/min is also 3.6 °F/min and
+//! 2 K/min; the limiter works in the base unit and any unit of the dimension can be
+//! used on either side.
+//!
+//! A [`Hysteresis`] comparator switches on and off at two different thresholds, so a
+//! thermostat or a low-level alarm does not chatter while its input hovers around a
+//! single set point.
+//!
+//! A [`Pid`] controller drives an output, typically a heater or valve in percent, from
+//! a measured process variable. Its gains are given in the units they carry, such as
+//! 8 % per °C of error, so they read the way they are written on a tuning sheet and
+//! stay correct whatever units the measurements arrive in.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::control::RateLimiter;
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//! use uom::unit_conversion::time::{Minute, Second, Time};
+//!
+//! // A furnace setpoint may ramp by at most 2 °C per minute
+//! let mut ramp = RateLimiter::<Celsius>::new::<Celsius>(2.0, Time::from_unit::<Minute>(1.0))
+//!     .unwrap()
+//!     .starting_at(Temperature::from_unit::<Celsius>(20.0));
+//!
+//! let target = Temperature::from_unit::<Celsius>(200.0);
+//! let setpoint = ramp.update(target, Time::from_unit::<Second>(30.0)).unwrap();
+//! assert!((setpoint.to_unit::<Celsius>() - 21.0).abs() < 1e-9);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::unit_conversion::time::{Second, Time};
+use crate::unit_conversion::{
+    Quantity, UnitConversion, UnitError, check_finite, check_positive, unit_span,
+};
+
+/// Limits how fast a quantity may change per unit time.
+///
+/// The first update with no starting value passes its target through unchanged;
+/// later ones move from the previous output toward the target by at most the allowed
+/// change over the elapsed time.
+pub struct RateLimiter<U: UnitConversion> {
+    /// The largest change per second, in base units.
+    max_rate: f64,
+    /// The last output, in base units.
+    output: Option<f64>,
+    _u: PhantomData<U>,
+}
+
+impl<U: UnitConversion> Clone for RateLimiter<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> Copy for RateLimiter<U> {}
+
+impl<U: UnitConversion> std::fmt::Debug for RateLimiter<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("max_rate", &self.max_rate)
+            .field("output", &self.output)
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> RateLimiter<U> {
+    /// Creates a limiter allowing a change of at most `max_change`, a difference in unit
+    /// `V`, over each `per`.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `max_change` or `per` is not positive and
+    /// finite.
+    pub fn new<V>(max_change: f64, per: Time) -> Result<Self, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let change = check_positive(max_change)?;
+        let seconds = check_positive(per.to_unit::<Second>())?;
+        let base_change = (change * unit_span::<V>()).abs();
+        Ok(Self {
+            max_rate: base_change / seconds,
+            output: None,
+            _u: PhantomData,
+        })
+    }
+
+    /// Starts the output at `value`, so the first update is already rate limited.
+    pub fn starting_at<V>(mut self, value: Quantity<V>) -> Self
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        self.output = Some(value.in_base());
+        self
+    }
+
+    /// Moves the output toward `target` by at most the allowed change over `elapsed`,
+    /// and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if `target` is NaN or infinite, and
+    /// [`UnitError::OutOfRange`], in seconds, if `elapsed` is negative or not finite.
+    /// The output is unchanged on error.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::control::RateLimiter;
+    /// use uom::unit_conversion::length::{Length, Millimeter};
+    /// use uom::unit_conversion::time::{Second, Time};
+    ///
+    /// // An actuator travels at most 5 mm/s
+    /// let mut stroke = RateLimiter::<Millimeter>::new::<Millimeter>(5.0, Time::from_unit::<Second>(1.0))
+    ///     .unwrap()
+    ///     .starting_at(Length::from_unit::<Millimeter>(0.0));
+    /// let tick = Time::from_unit::<Second>(1.0);
+    /// let target = Length::from_unit::<Millimeter>(12.0);
+    /// let path: Vec<f64> = (0..4)
+    ///     .map(|_| stroke.update(target, tick).unwrap().to_unit::<Millimeter>())
+    ///     .collect();
+    /// assert_eq!(path, [5.0, 10.0, 12.0, 12.0]);
+    /// ```
+    pub fn update<V>(
+        &mut self,
+        target: Quantity<V>,
+        elapsed: Time,
+    ) -> Result<Quantity<U>, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let target = check_finite(target.in_base())?;
+        let seconds = elapsed.to_unit::<Second>();
+        if !(seconds.is_finite() && seconds >= 0.0) {
+            return Err(UnitError::OutOfRange {
+                value: seconds,
+                min: 0.0,
+                max: f64::INFINITY,
+            });
+        }
+        let output = match self.output {
+            Some(previous) => {
+                let step = self.max_rate * seconds;
+                target.clamp(previous - step, previous + step)
+            }
+            None => target,
+        };
+        self.output = Some(output);
+        Ok(Quantity::from_base(output))
+    }
+
+    /// Returns the last output, or the starting value before any update.
+    pub fn output(&self) -> Option<Quantity<U>> {
+        self.output.map(Quantity::from_base)
+    }
+
+    /// Forgets the output, so the next update passes its target through.
+    pub fn reset(&mut self) {
+        self.output = None;
+    }
+
+    /// Returns the largest change allowed over `over`, as a difference in unit `V`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::control::RateLimiter;
+    /// use uom::unit_conversion::temperature::{Celsius, Fahrenheit};
+    /// use uom::unit_conversion::time::{Hour, Minute, Time};
+    ///
+    /// let ramp = RateLimiter::<Celsius>::new::<Celsius>(2.0, Time::from_unit::<Minute>(1.0)).unwrap();
+    /// let per_hour = ramp.max_change_in::<Fahrenheit>(Time::from_unit::<Hour>(1.0));
+    /// assert!((per_hour - 216.0).abs() < 1e-9);
+    /// ```
+    pub fn max_change_in<V>(&self, over: Time) -> f64
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let change = self.max_rate * over.to_unit::<Second>();
+        change / unit_span::<V>()
+    }
+}
+
+/// A two-threshold comparator: it switches on when its input reaches the on threshold
+/// and off when the input passes the off threshold.
+///
+/// The order of the thresholds sets the direction. With the on threshold above the off
+/// threshold the comparator turns on for high inputs, like a cooling fan; with it below,
+/// it turns on for low inputs, like a heater. Between the thresholds the state is held.
+pub struct Hysteresis<U: UnitConversion> {
+    /// The on threshold, in base units.
+    on: f64,
+    /// The off threshold, in base units.
+    off: f64,
+    active: bool,
+    _u: PhantomData<U>,
+}
+
+impl<U: UnitConversion> Clone for Hysteresis<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> Copy for Hysteresis<U> {}
+
+impl<U: UnitConversion> std::fmt::Debug for Hysteresis<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hysteresis")
+            .field("on", &self.on)
+            .field("off", &self.off)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> Hysteresis<U> {
+    /// Creates a comparator, initially off, that switches on when its input reaches `on`
+    /// and off when the input passes `off`.
+    ///
+    /// Equal thresholds give a plain comparator that does not chatter at the threshold
+    /// itself.
+    ///
+    /// Returns [`UnitError::NonFinite`] if either threshold is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::control::Hysteresis;
+    /// use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+    ///
+    /// // A heater comes on at 19 °C and goes off above 21 °C
+    /// let mut heater = Hysteresis::<Celsius>::new(
+    ///     Temperature::from_unit::<Celsius>(19.0),
+    ///     Temperature::from_unit::<Celsius>(21.0),
+    /// )
+    /// .unwrap();
+    /// let readings = [68.0, 66.0, 68.0, 69.0, 70.0, 68.0];
+    /// let states: Vec<bool> = readings
+    ///     .iter()
+    ///     .map(|&f| heater.update(Temperature::from_unit::<Fahrenheit>(f)))
+    ///     .collect();
+    /// assert_eq!(states, [false, true, true, true, false, false]);
+    /// ```
+    pub fn new<V, W>(on: Quantity<V>, off: Quantity<W>) -> Result<Self, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+        W: UnitConversion<Dimension = U::Dimension>,
+    {
+        Ok(Self {
+            on: check_finite(on.in_base())?,
+            off: check_finite(off.in_base())?,
+            active: false,
+            _u: PhantomData,
+        })
+    }
+
+    /// Starts the comparator in the given state instead of off.
+    pub fn starting(mut self, on: bool) -> Self {
+        self.active = on;
+        self
+    }
+
+    /// Feeds in an input and returns whether the comparator is now on.
+    ///
+    /// A NaN input leaves the state unchanged.
+    pub fn update<V>(&mut self, input: Quantity<V>) -> bool
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let input = input.in_base();
+        if input.is_nan() {
+            return self.active;
+        }
+        // Once on, the comparator stays on until the input passes the off threshold
+        self.active = match (self.active, self.on >= self.off) {
+            (false, true) => input >= self.on,
+            (false, false) => input <= self.on,
+            (true, true) => input >= self.off,
+            (true, false) => input <= self.off,
+        };
+        self.active
+    }
+
+    /// Returns whether the comparator is on.
+    pub fn is_on(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the threshold at which the comparator switches on.
+    pub fn on_threshold(&self) -> Quantity<U> {
+        Quantity::from_base(self.on)
+    }
+
+    /// Returns the threshold past which the comparator switches off.
+    pub fn off_threshold(&self) -> Quantity<U> {
+        Quantity::from_base(self.off)
+    }
+}
+
+/// Returns the seconds in `per`, for the time constant of a gain.
+///
+/// # Panics
+///
+/// Panics if `per` is not positive and finite.
+fn gain_period(per: Time) -> f64 {
+    let seconds = per.to_unit::<Second>();
+    assert!(
+        seconds.is_finite() && seconds > 0.0,
+        "gain period must be positive and finite, got {seconds} s"
+    );
+    seconds
+}
+
+/// A PID controller driving an output in unit `O` toward a setpoint for a process
+/// variable in unit `P`.
+///
+/// The output is the sum of three terms, each with a typed gain:
+///
+/// - proportional: output per unit of error, as in 8 % per °C;
+/// - integral: output per unit of error held for a period, as in 0.5 % per °C per
+///   minute;
+/// - derivative: output per unit of error change over a period, as in 2 % per °C/min.
+///
+/// The error is the setpoint minus the measurement, so positive gains suit a
+/// direct-acting loop such as a heater; use negative gains for a cooler. The
+/// derivative term is taken on the measurement, so changing the setpoint does not
+/// kick the output. With [`with_output_limits`](Self::with_output_limits) the output
+/// is clamped and the integral stops winding up while it is saturated.
+pub struct Pid<P: UnitConversion, O: UnitConversion> {
+    /// The setpoint, in base units of `P`.
+    setpoint: f64,
+    /// Base output per base process unit.
+    kp: f64,
+    /// Base output per base process unit-second.
+    ki: f64,
+    /// Base output-seconds per base process unit.
+    kd: f64,
+    /// The output range, in base units of `O`.
+    limits: Option<(f64, f64)>,
+    /// The integral of the error, in base process unit-seconds.
+    integral: f64,
+    /// The previous measurement, in base units of `P`.
+    previous: Option<f64>,
+    _u: PhantomData<(P, O)>,
+}
+
+impl<P: UnitConversion, O: UnitConversion> Clone for Pid<P, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: UnitConversion, O: UnitConversion> Copy for Pid<P, O> {}
+
+impl<P: UnitConversion, O: UnitConversion> std::fmt::Debug for Pid<P, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pid")
+            .field("setpoint", &self.setpoint)
+            .field("kp", &self.kp)
+            .field("ki", &self.ki)
+            .field("kd", &self.kd)
+            .field("limits", &self.limits)
+            .field("integral", &self.integral)
+            .finish()
+    }
+}
+
+impl<P: UnitConversion, O: UnitConversion> Pid<P, O> {
+    /// Creates a controller for `setpoint` with all gains zero.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::control::Pid;
+    /// use uom::unit_conversion::ratio::Percent;
+    /// use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+    /// use uom::unit_conversion::time::{Minute, Second, Time};
+    ///
+    /// let mut heater = Pid::<Celsius, Percent>::new(Temperature::from_unit::<Celsius>(21.0))
+    ///     .with_proportional::<Celsius, Percent>(8.0)
+    ///     .with_integral::<Celsius, Percent>(0.5, Time::from_unit::<Minute>(1.0))
+    ///     .with_output_limits::<Percent>(0.0, 100.0);
+    ///
+    /// // 2 °C below the setpoint for a minute: 16 % proportional and 1 % integral
+    /// let room = Temperature::from_unit::<Fahrenheit>(66.2);
+    /// let duty = heater.update(room, Time::from_unit::<Second>(60.0)).unwrap();
+    /// assert!((duty.to_unit::<Percent>() - 17.0).abs() < 1e-9);
+    /// ```
+    pub fn new<V>(setpoint: Quantity<V>) -> Self
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+    {
+        Self {
+            setpoint: setpoint.in_base(),
+            kp: 0.0,
+            ki: 0.0,
+            kd: 0.0,
+            limits: None,
+            integral: 0.0,
+            previous: None,
+            _u: PhantomData,
+        }
+    }
+
+    /// Sets the proportional gain to `gain` units `W` of output for each unit `V` of
+    /// error.
+    pub fn with_proportional<V, W>(mut self, gain: f64) -> Self
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+        W: UnitConversion<Dimension = O::Dimension>,
+    {
+        self.kp = gain * unit_span::<W>() / unit_span::<V>();
+        self
+    }
+
+    /// Sets the integral gain to `gain` units `W` of output for each unit `V` of error
+    /// held for `per`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per` is not positive and finite.
+    pub fn with_integral<V, W>(mut self, gain: f64, per: Time) -> Self
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+        W: UnitConversion<Dimension = O::Dimension>,
+    {
+        self.ki = gain * unit_span::<W>() / (unit_span::<V>() * gain_period(per));
+        self
+    }
+
+    /// Sets the derivative gain to `gain` units `W` of output for an error changing by
+    /// one unit `V` over each `per`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per` is not positive and finite.
+    pub fn with_derivative<V, W>(mut self, gain: f64, per: Time) -> Self
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+        W: UnitConversion<Dimension = O::Dimension>,
+    {
+        self.kd = gain * unit_span::<W>() * gain_period(per) / unit_span::<V>();
+        self
+    }
+
+    /// Clamps the output to `min..=max`, given in unit `W`, and stops the integral
+    /// from winding up while the output is held at a limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max` or either is NaN.
+    pub fn with_output_limits<W>(mut self, min: f64, max: f64) -> Self
+    where
+        W: UnitConversion<Dimension = O::Dimension>,
+    {
+        let (min, max) = (W::convert_to(min), W::convert_to(max));
+        assert!(min <= max, "output limits must satisfy min <= max");
+        self.limits = Some((min, max));
+        self
+    }
+
+    /// Returns the setpoint.
+    pub fn setpoint(&self) -> Quantity<P> {
+        Quantity::from_base(self.setpoint)
+    }
+
+    /// Changes the setpoint, keeping the integral so the output does not jump.
+    pub fn set_setpoint<V>(&mut self, setpoint: Quantity<V>)
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+    {
+        self.setpoint = setpoint.in_base();
+    }
+
+    /// Clears the integral and the previous measurement, as after a manual override.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.previous = None;
+    }
+
+    /// Feeds in a measurement taken `elapsed` after the previous one and returns the
+    /// new output.
+    ///
+    /// The derivative term is zero on the first update after creation or
+    /// [`reset`](Self::reset).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if `measurement` is NaN or infinite, and
+    /// [`UnitError::OutOfRange`], in seconds, if `elapsed` is not positive and finite.
+    /// The controller is unchanged on error.
+    pub fn update<V>(
+        &mut self,
+        measurement: Quantity<V>,
+        elapsed: Time,
+    ) -> Result<Quantity<O>, UnitError>
+    where
+        V: UnitConversion<Dimension = P::Dimension>,
+    {
+        let measurement = check_finite(measurement.in_base())?;
+        let dt = check_positive(elapsed.to_unit::<Second>())?;
+        let error = self.setpoint - measurement;
+        let integral = self.integral + error * dt;
+        let derivative = self
+            .previous
+            .map_or(0.0, |previous| (previous - measurement) / dt);
+        let unclamped = self.kp * error + self.ki * integral + self.kd * derivative;
+        let output = match self.limits {
+            Some((min, max)) => unclamped.clamp(min, max),
+            None => unclamped,
+        };
+        // While saturated, integrate only errors that pull the output back into range
+        if output == unclamped || (unclamped > output) != (self.ki * error > 0.0) {
+            self.integral = integral;
+        }
+        self.previous = Some(measurement);
+        Ok(Quantity::from_base(output))
+    }
+}
+
+#[cfg(all(test, feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::ratio::{Percent, Unity};
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+    use crate::unit_conversion::time::Minute;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::from_unit::<Celsius>(value)
+    }
+
+    fn seconds(value: f64) -> Time {
+        Time::from_unit::<Second>(value)
+    }
+
+    #[test]
+    fn ramps_toward_the_target_in_both_directions() {
+        let mut ramp =
+            RateLimiter::<Kelvin>::new::<Fahrenheit>(3.6, Time::from_unit::<Minute>(1.0))
+                .unwrap()
+                .starting_at(celsius(100.0));
+        // 3.6 °F/min is 2 °C/min, so a minute and a half allows 3 °C
+        let down = ramp.update(celsius(0.0), seconds(90.0)).unwrap();
+        assert!(approx(down.to_unit::<Celsius>(), 97.0, 1e-9));
+        let up = ramp.update(celsius(150.0), seconds(60.0)).unwrap();
+        assert!(approx(up.to_unit::<Celsius>(), 99.0, 1e-9));
+
+        // A target within reach is met exactly and then held
+        let held = ramp.update(celsius(99.5), seconds(60.0)).unwrap();
+        assert!(approx(held.to_unit::<Celsius>(), 99.5, 1e-9));
+        let still = ramp.update(celsius(99.5), seconds(0.0)).unwrap();
+        assert_eq!(still, held);
+    }
+
+    #[test]
+    fn first_update_passes_through_until_reset() {
+        let mut ramp = RateLimiter::<Celsius>::new::<Celsius>(1.0, seconds(1.0)).unwrap();
+        assert!(ramp.output().is_none());
+        let first = ramp.update(celsius(50.0), seconds(1.0)).unwrap();
+        assert!(approx(first.to_unit::<Celsius>(), 50.0, 1e-9));
+        let next = ramp.update(celsius(0.0), seconds(1.0)).unwrap();
+        assert!(approx(next.to_unit::<Celsius>(), 49.0, 1e-9));
+
+        ramp.reset();
+        let restarted = ramp.update(celsius(0.0), seconds(1.0)).unwrap();
+        assert!(approx(restarted.to_unit::<Celsius>(), 0.0, 1e-9));
+        assert!(approx(
+            ramp.max_change_in::<Kelvin>(seconds(30.0)),
+            30.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_limits_and_inputs() {
+        assert!(RateLimiter::<Celsius>::new::<Celsius>(0.0, seconds(1.0)).is_err());
+        assert!(RateLimiter::<Celsius>::new::<Celsius>(-1.0, seconds(1.0)).is_err());
+        assert!(RateLimiter::<Celsius>::new::<Celsius>(1.0, seconds(0.0)).is_err());
+
+        let mut ramp = RateLimiter::<Celsius>::new::<Celsius>(1.0, seconds(1.0))
+            .unwrap()
+            .starting_at(celsius(20.0));
+        assert!(matches!(
+            ramp.update(celsius(f64::NAN), seconds(1.0)),
+            Err(UnitError::NonFinite { .. })
+        ));
+        assert!(matches!(
+            ramp.update(celsius(30.0), seconds(-1.0)),
+            Err(UnitError::OutOfRange { value, .. }) if value == -1.0
+        ));
+        assert!(approx(
+            ramp.output().unwrap().to_unit::<Celsius>(),
+            20.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn hysteresis_holds_state_between_thresholds() {
+        // A cooling fan on at 26 °C, off below 24 °C
+        let mut fan = Hysteresis::<Kelvin>::new(celsius(26.0), celsius(24.0)).unwrap();
+        let readings = [25.0, 26.0, 25.0, 24.0, 23.9, 25.0, 27.0];
+        let states: Vec<bool> = readings.iter().map(|&c| fan.update(celsius(c))).collect();
+        assert_eq!(states, [false, true, true, true, false, false, true]);
+
+        assert!(fan.update(celsius(f64::NAN)));
+        assert!(approx(fan.on_threshold().to_unit::<Celsius>(), 26.0, 1e-9));
+        assert!(approx(fan.off_threshold().to_unit::<Celsius>(), 24.0, 1e-9));
+    }
+
+    #[test]
+    fn hysteresis_with_equal_thresholds_does_not_chatter() {
+        let mut alarm = Hysteresis::<Celsius>::new(celsius(5.0), celsius(5.0))
+            .unwrap()
+            .starting(true);
+        assert!(alarm.is_on());
+        for _ in 0..3 {
+            assert!(alarm.update(celsius(5.0)));
+        }
+        assert!(!alarm.update(celsius(4.9)));
+        assert!(!alarm.update(celsius(4.99)));
+        assert!(alarm.update(celsius(5.0)));
+
+        assert!(Hysteresis::<Celsius>::new(celsius(f64::INFINITY), celsius(0.0)).is_err());
+    }
+
+    #[test]
+    fn pid_terms_carry_their_units() {
+        let setpoint = celsius(50.0);
+        let minute = Time::from_unit::<Minute>(1.0);
+
+        let mut p =
+            Pid::<Celsius, Percent>::new(setpoint).with_proportional::<Fahrenheit, Percent>(1.0);
+        // 1 % per °F is 1.8 % per °C
+        let out = p.update(celsius(45.0), seconds(1.0)).unwrap();
+        assert!(approx(out.to_unit::<Percent>(), 9.0, 1e-9));
+
+        let mut i =
+            Pid::<Kelvin, Percent>::new(setpoint).with_integral::<Celsius, Percent>(0.5, minute);
+        i.update(celsius(48.0), seconds(30.0)).unwrap();
+        let out = i.update(celsius(48.0), seconds(90.0)).unwrap();
+        // 2 °C held for two minutes
+        assert!(approx(out.to_unit::<Percent>(), 2.0, 1e-9));
+
+        let mut d =
+            Pid::<Celsius, Unity>::new(setpoint).with_derivative::<Celsius, Percent>(2.0, minute);
+        assert_eq!(
+            d.update(celsius(40.0), seconds(30.0))
+                .unwrap()
+                .to_unit::<Unity>(),
+            0.0
+        );
+        // Rising 1 °C in 30 s is 2 °C/min toward the setpoint, so the term brakes
+        let out = d.update(celsius(41.0), seconds(30.0)).unwrap();
+        assert!(approx(out.to_unit::<Percent>(), -4.0, 1e-9));
+
+        // Moving the setpoint does not kick the derivative term
+        d.set_setpoint(celsius(80.0));
+        let out = d.update(celsius(41.0), seconds(30.0)).unwrap();
+        assert_eq!(out.to_unit::<Percent>(), 0.0);
+        assert!(approx(d.setpoint().to_unit::<Celsius>(), 80.0, 1e-9));
+    }
+
+    #[test]
+    fn pid_settles_a_simulated_oven() {
+        // A first-order oven: full power holds 50 °C above a 20 °C room, time constant 100 s
+        let mut pid = Pid::<Celsius, Percent>::new(celsius(60.0))
+            .with_proportional::<Celsius, Percent>(5.0)
+            .with_integral::<Celsius, Percent>(0.1, seconds(1.0))
+            .with_output_limits::<Percent>(0.0, 100.0);
+        let mut temperature = 20.0;
+        let tick = seconds(1.0);
+        let mut duty = 0.0;
+        for _ in 0..2000 {
+            duty = pid
+                .update(celsius(temperature), tick)
+                .unwrap()
+                .to_unit::<Percent>();
+            assert!((0.0..=100.0).contains(&duty));
+            temperature += (duty / 100.0 * 50.0 - (temperature - 20.0)) / 100.0;
+        }
+        assert!(approx(temperature, 60.0, 0.01));
+        assert!(approx(duty, 80.0, 0.1));
+    }
+
+    #[test]
+    fn pid_integral_does_not_wind_up_at_the_limits() {
+        let mut pid = Pid::<Celsius, Percent>::new(celsius(100.0))
+            .with_integral::<Celsius, Percent>(1.0, seconds(1.0))
+            .with_output_limits::<Percent>(0.0, 100.0);
+        // A long, large error saturates the output
+        for _ in 0..1000 {
+            let out = pid.update(celsius(0.0), seconds(1.0)).unwrap();
+            assert!(out.to_unit::<Percent>() <= 100.0);
+        }
+        // Without anti-windup this would stay pinned for a long time
+        let out = pid.update(celsius(110.0), seconds(1.0)).unwrap();
+        assert!(out.to_unit::<Percent>() < 100.0);
+
+        pid.reset();
+        let out = pid.update(celsius(100.0), seconds(1.0)).unwrap();
+        assert_eq!(out.to_unit::<Percent>(), 0.0);
+
+        assert!(pid.update(celsius(f64::NAN), seconds(1.0)).is_err());
+        assert!(pid.update(celsius(20.0), seconds(0.0)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "gain period")]
+    fn pid_rejects_zero_gain_periods() {
+        let _ = Pid::<Celsius, Percent>::new(celsius(20.0))
+            .with_integral::<Celsius, Percent>(1.0, seconds(0.0));
+    }
+}
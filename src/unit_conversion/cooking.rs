@@ -0,0 +1,234 @@
+//! # Cooking Conversions
+//!
+//! This module provides an ingredient-density table for bridging volume and mass. The
+//! kitchen measures themselves (cups, tablespoons, teaspoons and fluid ounces) are units
+//! of the [`volume`](crate::unit_conversion::volume) module; the US customary ones are
+//! re-exported here for convenience.
+//!
+//! Recipes are written in volumes, but kitchen scales measure mass. Converting between the
+//! two requires knowing the density of the ingredient, which is what the [`INGREDIENTS`]
+//! table provides.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::cooking::{cups_to_grams, Cup, Tablespoon};
+//! use uom::unit_conversion::volume::Volume;
+//!
+//! // Volume conversions work like any other unit
+//! let butter = Volume::from_unit::<Cup>(0.5);
+//! assert!((butter.to_unit::<Tablespoon>() - 8.0).abs() < 1e-9);
+//!
+//! // Ingredient-aware volume to mass conversion
+//! let flour = cups_to_grams("flour", 2.0).unwrap();
+//! assert!((flour - 250.0).abs() < 1.0);
+//! ```
+
+use crate::unit_conversion::mass::{Gram, Mass};
+use crate::unit_conversion::volume::{Milliliter, Volume};
+
+pub use crate::unit_conversion::volume::{Cup, Tablespoon, Teaspoon};
+
+/// A common ingredient and its typical density.
+pub struct Ingredient {
+    /// The lowercase name used for lookups (e.g., "flour").
+    pub name: &'static str,
+    /// The typical density in grams per milliliter, as measured by the
+    /// spoon-and-level method for dry ingredients.
+    pub grams_per_milliliter: f64,
+}
+
+/// Typical densities of common baking and cooking ingredients.
+///
+/// Dry ingredient densities vary with how they are packed into the measure; the values
+/// here follow the common spoon-and-level convention used by most recipe publishers.
+pub const INGREDIENTS: &[Ingredient] = &[
+    Ingredient {
+        name: "water",
+        grams_per_milliliter: 1.0,
+    },
+    Ingredient {
+        name: "milk",
+        grams_per_milliliter: 1.03,
+    },
+    Ingredient {
+        name: "flour",
+        grams_per_milliliter: 0.528,
+    },
+    Ingredient {
+        name: "sugar",
+        grams_per_milliliter: 0.845,
+    },
+    Ingredient {
+        name: "brown sugar",
+        grams_per_milliliter: 0.93,
+    },
+    Ingredient {
+        name: "powdered sugar",
+        grams_per_milliliter: 0.507,
+    },
+    Ingredient {
+        name: "butter",
+        grams_per_milliliter: 0.959,
+    },
+    Ingredient {
+        name: "honey",
+        grams_per_milliliter: 1.42,
+    },
+    Ingredient {
+        name: "oil",
+        grams_per_milliliter: 0.92,
+    },
+    Ingredient {
+        name: "salt",
+        grams_per_milliliter: 1.217,
+    },
+    Ingredient {
+        name: "rice",
+        grams_per_milliliter: 0.782,
+    },
+    Ingredient {
+        name: "oats",
+        grams_per_milliliter: 0.38,
+    },
+    Ingredient {
+        name: "cocoa",
+        grams_per_milliliter: 0.359,
+    },
+];
+
+/// Looks up an ingredient by name, ignoring case and surrounding whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::cooking::ingredient;
+///
+/// assert_eq!(ingredient("Butter").unwrap().name, "butter");
+/// assert!(ingredient("unobtainium").is_none());
+/// ```
+pub fn ingredient(name: &str) -> Option<&'static Ingredient> {
+    let name = name.trim();
+    INGREDIENTS
+        .iter()
+        .find(|ingredient| ingredient.name.eq_ignore_ascii_case(name))
+}
+
+/// Converts a volume of an ingredient to its mass.
+///
+/// Returns `None` if the ingredient is not in the [`INGREDIENTS`] table.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::cooking::{volume_to_mass, Cup};
+/// use uom::unit_conversion::mass::Gram;
+/// use uom::unit_conversion::volume::Volume;
+///
+/// let sugar = volume_to_mass("sugar", Volume::from_unit::<Cup>(1.0)).unwrap();
+/// assert!((sugar.to_unit::<Gram>() - 200.0).abs() < 1.0);
+/// ```
+pub fn volume_to_mass(name: &str, volume: Volume) -> Option<Mass> {
+    let ingredient = ingredient(name)?;
+    let grams = volume.to_unit::<Milliliter>() * ingredient.grams_per_milliliter;
+    Some(Mass::from_unit::<Gram>(grams))
+}
+
+/// Converts a mass of an ingredient to its volume.
+///
+/// Returns `None` if the ingredient is not in the [`INGREDIENTS`] table.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::cooking::{mass_to_volume, Cup};
+/// use uom::unit_conversion::mass::{Gram, Mass};
+///
+/// let butter = mass_to_volume("butter", Mass::from_unit::<Gram>(227.0)).unwrap();
+/// assert!((butter.to_unit::<Cup>() - 1.0).abs() < 0.01);
+/// ```
+pub fn mass_to_volume(name: &str, mass: Mass) -> Option<Volume> {
+    let ingredient = ingredient(name)?;
+    let milliliters = mass.to_unit::<Gram>() / ingredient.grams_per_milliliter;
+    Some(Volume::from_unit::<Milliliter>(milliliters))
+}
+
+/// Converts a number of US cups of an ingredient to grams.
+///
+/// Returns `None` if the ingredient is not in the [`INGREDIENTS`] table.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::cooking::cups_to_grams;
+///
+/// let flour = cups_to_grams("flour", 2.0).unwrap();
+/// assert!((flour - 250.0).abs() < 1.0);
+/// ```
+pub fn cups_to_grams(name: &str, cups: f64) -> Option<f64> {
+    volume_to_mass(name, Volume::from_unit::<Cup>(cups)).map(|mass| mass.to_unit::<Gram>())
+}
+
+/// Converts grams of an ingredient to a number of US cups.
+///
+/// Returns `None` if the ingredient is not in the [`INGREDIENTS`] table.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::cooking::grams_to_cups;
+///
+/// let sugar = grams_to_cups("sugar", 100.0).unwrap();
+/// assert!((sugar - 0.5).abs() < 0.01);
+/// ```
+pub fn grams_to_cups(name: &str, grams: f64) -> Option<f64> {
+    mass_to_volume(name, Mass::from_unit::<Gram>(grams)).map(|volume| volume.to_unit::<Cup>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::UnitConversion;
+    use crate::unit_conversion::volume::UsFluidOunce;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn kitchen_measures() {
+        let volume = Volume::from_unit::<Cup>(1.0);
+        assert!(approx(volume.to_unit::<Tablespoon>(), 16.0, 1e-12));
+        assert!(approx(volume.to_unit::<Teaspoon>(), 48.0, 1e-12));
+        assert!(approx(volume.to_unit::<UsFluidOunce>(), 8.0, 1e-12));
+        assert!(approx(volume.to_unit::<Milliliter>(), 236.5882365, 1e-9));
+    }
+
+    #[test]
+    fn cups_to_grams_known_ingredients() {
+        assert!(approx(cups_to_grams("water", 1.0).unwrap(), 236.588, 1e-3));
+        assert!(approx(cups_to_grams("flour", 1.0).unwrap(), 125.0, 0.5));
+        assert!(approx(cups_to_grams("sugar", 1.0).unwrap(), 200.0, 0.5));
+        assert!(approx(cups_to_grams("butter", 1.0).unwrap(), 227.0, 0.5));
+    }
+
+    #[test]
+    fn ingredient_lookup_is_case_insensitive() {
+        assert!(cups_to_grams(" FLOUR ", 1.0).is_some());
+        assert!(cups_to_grams("Brown Sugar", 1.0).is_some());
+        assert!(cups_to_grams("unobtainium", 1.0).is_none());
+    }
+
+    #[test]
+    fn grams_to_cups_round_trip() {
+        let grams = cups_to_grams("honey", 0.75).unwrap();
+        assert!(approx(grams_to_cups("honey", grams).unwrap(), 0.75, 1e-12));
+    }
+
+    #[test]
+    fn cooking_symbols() {
+        assert_eq!(Cup::SYMBOL, "cup");
+        assert_eq!(Tablespoon::SYMBOL, "tbsp");
+        assert_eq!(Teaspoon::SYMBOL, "tsp");
+    }
+}
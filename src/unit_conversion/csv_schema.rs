@@ -0,0 +1,479 @@
+//! # CSV Schema Inference
+//!
+//! Third-party data feeds usually say which unit a column holds only in its header:
+//! `temp_C`, `speed (mph)`, `depth [ft]`. [`CsvSchema::infer`] reads the header and a
+//! sample of rows, recognizes those annotations, and returns a typed mapping of every
+//! column to its dimension and unit. [`CsvSchema::convert`] then rewrites the whole
+//! file with chosen columns in other units, so normalizing a feed takes one line per
+//! column instead of a hand-kept table of factors.
+//!
+//! A header is annotated when it ends in a unit in parentheses or brackets, or in a
+//! unit after the last `_` or space. Units are matched as by [`registry::find_unit`];
+//! when a symbol exists in several dimensions, an exact match beats one that ignores
+//! case, and the column name breaks the remaining ties, so `temp_C` is in degrees
+//! Celsius while `charge_C` is in coulombs. An annotated column is only mapped to a
+//! unit if its sampled values are numbers, which keeps `created_at` or `user_id` from
+//! being read as quantities.
+//!
+//! As with [`LookupTable::from_csv`](super::lookup::LookupTable::from_csv), fields may be
+//! separated by commas, semicolons or tabs, lines starting with `#` are comments, and
+//! quoted fields are not supported.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::csv_schema::{ColumnKind, CsvSchema};
+//!
+//! let feed = "station,temp_C,depth (ft)\nA,12.5,30\nB,14,10\n";
+//! let schema = CsvSchema::infer(feed).unwrap();
+//! assert_eq!(schema.columns()[0].kind, ColumnKind::Text);
+//!
+//! let depth = schema.column("depth").unwrap();
+//! assert_eq!(depth.dimension().unwrap().name, "length");
+//! assert_eq!(depth.unit().unwrap().symbol, "ft");
+//!
+//! let metric = schema.convert(feed, &[("temp", "K"), ("depth", "m")]).unwrap();
+//! assert_eq!(metric, "station,temp_K,depth (m)\nA,285.65,9.144\nB,287.15,3.048\n");
+//! ```
+
+use crate::unit_conversion::registry::{DIMENSIONS, DimensionInfo};
+use crate::unit_conversion::{UnitError, UnitInfo};
+
+/// The most data rows [`CsvSchema::infer`] samples.
+pub const SAMPLE_ROWS: usize = 100;
+
+/// Field delimiters, in order of preference when a header contains several.
+const DELIMITERS: [char; 3] = [',', ';', '\t'];
+
+/// Column names that suggest a dimension whose name they do not contain.
+const HINTS: &[(&str, &str)] = &[
+    ("alt", "length"),
+    ("depth", "length"),
+    ("dist", "length"),
+    ("height", "length"),
+    ("duration", "time"),
+    ("elapsed", "time"),
+    ("temp", "temperature"),
+    ("weight", "mass"),
+    ("velocity", "speed"),
+];
+
+/// What a column holds, as inferred from its header and sampled values.
+#[derive(Copy, Clone, Debug)]
+pub enum ColumnKind {
+    /// Numbers in `unit`, a unit of `dimension`.
+    Quantity {
+        /// The dimension of the values.
+        dimension: &'static DimensionInfo,
+        /// The unit named in the header.
+        unit: &'static UnitInfo,
+    },
+    /// Numbers without a recognized unit.
+    Numeric,
+    /// Anything else, including empty columns.
+    Text,
+}
+
+impl PartialEq for ColumnKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Quantity { dimension, unit },
+                Self::Quantity {
+                    dimension: other_dimension,
+                    unit: other_unit,
+                },
+            ) => dimension.id == other_dimension.id && unit.symbol == other_unit.symbol,
+            (Self::Numeric, Self::Numeric) | (Self::Text, Self::Text) => true,
+            _ => false,
+        }
+    }
+}
+
+/// How a header names its unit, kept so converted files read like the original.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Annotation {
+    /// `name (unit)` or `name [unit]`, with the separator and brackets used.
+    Enclosed {
+        separator: &'static str,
+        open: char,
+        close: char,
+    },
+    /// `name_unit` or `name unit`.
+    Suffix(char),
+}
+
+/// One column of a [`CsvSchema`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column {
+    /// The position of the column, from 0.
+    pub index: usize,
+    /// The header as written.
+    pub header: String,
+    /// The header without its unit annotation.
+    pub name: String,
+    /// What the column holds.
+    pub kind: ColumnKind,
+    annotation: Option<Annotation>,
+}
+
+impl Column {
+    /// Returns the dimension of a quantity column.
+    pub fn dimension(&self) -> Option<&'static DimensionInfo> {
+        match self.kind {
+            ColumnKind::Quantity { dimension, .. } => Some(dimension),
+            _ => None,
+        }
+    }
+
+    /// Returns the unit of a quantity column.
+    pub fn unit(&self) -> Option<&'static UnitInfo> {
+        match self.kind {
+            ColumnKind::Quantity { unit, .. } => Some(unit),
+            _ => None,
+        }
+    }
+
+    /// Returns the header annotated with `symbol` in place of the current unit.
+    fn header_in(&self, symbol: &str) -> String {
+        match self.annotation {
+            Some(Annotation::Enclosed {
+                separator,
+                open,
+                close,
+            }) => format!("{}{separator}{open}{symbol}{close}", self.name),
+            Some(Annotation::Suffix(separator)) => format!("{}{separator}{symbol}", self.name),
+            None => self.header.clone(),
+        }
+    }
+}
+
+/// The columns of a CSV file, with the dimension and unit of each annotated column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvSchema {
+    delimiter: char,
+    columns: Vec<Column>,
+}
+
+impl CsvSchema {
+    /// Infers the schema of CSV `text` from its header and up to [`SAMPLE_ROWS`] data
+    /// rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if the text has no header line.
+    pub fn infer(text: &str) -> Result<Self, UnitError> {
+        let mut lines = records(text);
+        let header = lines.next().ok_or_else(|| UnitError::ParseError {
+            input: text.to_string(),
+            reason: "expected a header line",
+        })?;
+        let delimiter = DELIMITERS
+            .into_iter()
+            .find(|&delimiter| header.contains(delimiter))
+            .unwrap_or(DELIMITERS[0]);
+        let samples: Vec<Vec<&str>> = lines
+            .take(SAMPLE_ROWS)
+            .map(|line| split(line, delimiter))
+            .collect();
+        let columns = split(header, delimiter)
+            .into_iter()
+            .enumerate()
+            .map(|(index, header)| {
+                let values = samples
+                    .iter()
+                    .filter_map(|row| row.get(index).copied())
+                    .filter(|value| !value.is_empty());
+                infer_column(index, header, values)
+            })
+            .collect();
+        Ok(Self { delimiter, columns })
+    }
+
+    /// Returns the field delimiter found in the header.
+    pub fn delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    /// Returns every column, in file order.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the column whose name or full header is `name`.
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns
+            .iter()
+            .find(|column| column.name == name || column.header == name)
+    }
+
+    /// Returns the columns holding quantities.
+    pub fn quantities(&self) -> impl Iterator<Item = &Column> {
+        self.columns
+            .iter()
+            .filter(|column| matches!(column.kind, ColumnKind::Quantity { .. }))
+    }
+
+    /// Returns CSV `text`, which must have this schema, with each column named in
+    /// `targets` converted to the paired unit and its header annotated accordingly.
+    ///
+    /// Other columns, comments and empty fields are copied unchanged. Lines are ended
+    /// with `\n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if a target names no quantity column or an
+    /// unknown unit, or if a field to convert is not a number, and
+    /// [`UnitError::DimensionMismatch`] if a target unit is of another dimension than
+    /// its column.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::csv_schema::CsvSchema;
+    ///
+    /// let feed = "time;height [in]\n0;70\n# sensor reset\n1;\n";
+    /// let schema = CsvSchema::infer(feed).unwrap();
+    /// let cm = schema.convert(feed, &[("height", "cm")]).unwrap();
+    /// assert_eq!(cm, "time;height [cm]\n0;177.8\n# sensor reset\n1;\n");
+    /// ```
+    pub fn convert(&self, text: &str, targets: &[(&str, &str)]) -> Result<String, UnitError> {
+        let conversions = targets
+            .iter()
+            .map(|&(name, to)| self.conversion(name, to))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut out = String::with_capacity(text.len());
+        let mut header_seen = false;
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                out.push_str(line);
+                out.push('\n');
+                continue;
+            }
+            let mut fields: Vec<String> = split(trimmed, self.delimiter)
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            for &(column, from, to) in &conversions {
+                let Some(field) = fields.get_mut(column.index) else {
+                    continue;
+                };
+                if !header_seen {
+                    *field = column.header_in(to.symbol);
+                } else if !field.is_empty() {
+                    let value = field.parse::<f64>().map_err(|_| UnitError::ParseError {
+                        input: field.clone(),
+                        reason: "expected a number",
+                    })?;
+                    *field = (to.from_base)((from.to_base)(value)).to_string();
+                }
+            }
+            header_seen = true;
+            out.push_str(&fields.join(&self.delimiter.to_string()));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Resolves one target of [`convert`](Self::convert).
+    fn conversion(
+        &self,
+        name: &str,
+        to: &str,
+    ) -> Result<(&Column, &'static UnitInfo, &'static UnitInfo), UnitError> {
+        let (column, dimension, from) = self
+            .column(name)
+            .and_then(|column| match column.kind {
+                ColumnKind::Quantity { dimension, unit } => Some((column, dimension, unit)),
+                _ => None,
+            })
+            .ok_or_else(|| UnitError::ParseError {
+                input: name.to_string(),
+                reason: "no quantity column with this name",
+            })?;
+        if let Some(unit) = dimension.find_unit(to) {
+            return Ok((column, from, unit));
+        }
+        match DIMENSIONS
+            .iter()
+            .find(|other| other.find_unit(to).is_some())
+        {
+            Some(other) => Err(UnitError::DimensionMismatch {
+                expected: dimension.name,
+                found: other.name,
+            }),
+            None => Err(UnitError::ParseError {
+                input: to.to_string(),
+                reason: "unknown unit",
+            }),
+        }
+    }
+}
+
+/// Returns the non-blank, non-comment lines of `text`, trimmed.
+fn records(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn split(line: &str, delimiter: char) -> Vec<&str> {
+    line.split(delimiter).map(str::trim).collect()
+}
+
+fn infer_column<'a>(index: usize, header: &str, values: impl Iterator<Item = &'a str>) -> Column {
+    let mut values = values.peekable();
+    let numeric = values.peek().is_some() && values.all(|value| value.parse::<f64>().is_ok());
+    let annotated = numeric
+        .then(|| split_annotation(header))
+        .flatten()
+        .and_then(|(name, unit, annotation)| {
+            lookup(name, unit).map(|(dimension, unit)| (name, dimension, unit, annotation))
+        });
+    let (name, kind, annotation) = match annotated {
+        Some((name, dimension, unit, annotation)) => (
+            name,
+            ColumnKind::Quantity { dimension, unit },
+            Some(annotation),
+        ),
+        None if numeric => (header, ColumnKind::Numeric, None),
+        None => (header, ColumnKind::Text, None),
+    };
+    Column {
+        index,
+        header: header.to_string(),
+        name: name.to_string(),
+        kind,
+        annotation,
+    }
+}
+
+/// Splits a header into its name, the text of its unit annotation, and the form of
+/// the annotation.
+fn split_annotation(header: &str) -> Option<(&str, &str, Annotation)> {
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        if let Some(inner) = header.strip_suffix(close)
+            && let Some(start) = inner.rfind(open)
+        {
+            let name = &inner[..start];
+            let trimmed = name.trim_end();
+            let separator = if trimmed.len() < name.len() { " " } else { "" };
+            let annotation = Annotation::Enclosed {
+                separator,
+                open,
+                close,
+            };
+            return Some((trimmed, inner[start + 1..].trim(), annotation));
+        }
+    }
+    let start = header.rfind(['_', ' '])?;
+    let separator = header[start..].chars().next()?;
+    let (name, unit) = (header[..start].trim_end(), &header[start + 1..]);
+    (!name.is_empty() && !unit.is_empty()).then_some((name, unit, Annotation::Suffix(separator)))
+}
+
+/// Finds the unit named `text` for a column called `name`.
+fn lookup(name: &str, text: &str) -> Option<(&'static DimensionInfo, &'static UnitInfo)> {
+    let name = name.to_ascii_lowercase();
+    let hinted = |dimension: &DimensionInfo| {
+        name.contains(dimension.name)
+            || HINTS
+                .iter()
+                .any(|&(hint, hinted)| hinted == dimension.name && name.contains(hint))
+    };
+    DIMENSIONS
+        .iter()
+        .filter_map(|dimension| dimension.find_unit(text).map(|unit| (dimension, unit)))
+        .min_by_key(|&(dimension, unit)| (!unit.matches_exactly(text), !hinted(dimension)))
+}
+
+#[cfg(all(
+    test,
+    feature = "length",
+    feature = "temperature",
+    feature = "speed",
+    feature = "charge"
+))]
+mod tests {
+    use super::*;
+
+    fn kind(schema: &CsvSchema, name: &str) -> (&'static str, &'static str) {
+        let column = schema.column(name).unwrap();
+        (
+            column.dimension().unwrap().name,
+            column.unit().unwrap().symbol,
+        )
+    }
+
+    #[test]
+    fn recognizes_annotation_styles() {
+        let schema = CsvSchema::infer(
+            "temp_C,speed (mph),depth [ft],dist km,charge_C,id,notes\n\
+             20,55,3,1.5,0.2,17,ok\n",
+        )
+        .unwrap();
+        assert_eq!(kind(&schema, "temp"), ("temperature", "°C"));
+        assert_eq!(kind(&schema, "speed"), ("speed", "mph"));
+        assert_eq!(kind(&schema, "depth"), ("length", "ft"));
+        assert_eq!(kind(&schema, "dist"), ("length", "km"));
+        assert_eq!(kind(&schema, "charge"), ("charge", "C"));
+        assert_eq!(schema.column("id").unwrap().kind, ColumnKind::Numeric);
+        assert_eq!(schema.column("notes").unwrap().kind, ColumnKind::Text);
+        assert_eq!(schema.quantities().count(), 5);
+    }
+
+    #[test]
+    fn samples_guard_against_false_annotations() {
+        let text = "created_at;speed_kn;user_id\n2026-01-05;12;\n2026-01-06;x;\n";
+        let schema = CsvSchema::infer(text).unwrap();
+        assert_eq!(schema.delimiter(), ';');
+        assert_eq!(schema.column("created_at").unwrap().kind, ColumnKind::Text);
+        assert_eq!(schema.column("speed_kn").unwrap().kind, ColumnKind::Text);
+        assert_eq!(schema.column("user_id").unwrap().kind, ColumnKind::Text);
+        assert!(CsvSchema::infer("# only a comment\n\n").is_err());
+    }
+
+    #[test]
+    fn converts_feeds() {
+        let feed = "# buoy 7\nspeed_kn\twater temp (F)\n10\t50\n\t\n";
+        let schema = CsvSchema::infer(feed).unwrap();
+        let out = schema
+            .convert(feed, &[("speed", "km/h"), ("water temp", "°C")])
+            .unwrap();
+        assert_eq!(
+            out,
+            "# buoy 7\nspeed_km/h\twater temp (°C)\n18.52\t10\n\t\n"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_targets() {
+        let feed = "site,temp_C\nA,20\nB,warm\n";
+        let schema = CsvSchema::infer("site,temp_C\nA,20\n").unwrap();
+        assert!(matches!(
+            schema.convert(feed, &[("site", "K")]),
+            Err(UnitError::ParseError { .. })
+        ));
+        assert_eq!(
+            schema.convert(feed, &[("temp", "mph")]),
+            Err(UnitError::DimensionMismatch {
+                expected: "temperature",
+                found: "speed",
+            })
+        );
+        assert!(schema.convert(feed, &[("temp", "parsecs")]).is_err());
+        assert!(matches!(
+            schema.convert(feed, &[("temp", "K")]),
+            Err(UnitError::ParseError {
+                reason: "expected a number",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,332 @@
+//! # Currency
+//!
+//! This module provides a [`Money`] type that pairs an amount with its ISO 4217 currency.
+//!
+//! Unlike the physical dimensions in this crate, exchange rates between currencies are not
+//! fixed constants, so currencies cannot implement [`UnitConversion`](crate::unit_conversion::UnitConversion).
+//! Instead each amount carries its [`Currency`] at runtime, which keeps a price in dollars
+//! from ever being mistaken for a price in euros.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::currency::{Currency, Money};
+//!
+//! let price = Money::new(0.15, Currency::USD);
+//! assert_eq!(price.amount(), 0.15);
+//! assert_eq!(price.currency().code(), "USD");
+//! assert_eq!(price.currency().symbol(), "$");
+//! ```
+//!
+//! ## Exchange Rates
+//!
+//! Conversions go through an explicit [`ExchangeRates`] table, which records when its
+//! rates were fetched so that callers can warn about or refuse stale data. Fetching the
+//! rates is left to the application.
+//!
+//! ```
+//! use std::time::{Duration, SystemTime};
+//! use uom::unit_conversion::currency::{Currency, ExchangeRates, Money};
+//!
+//! let mut rates = ExchangeRates::new("USD", SystemTime::now());
+//! rates.set_rate("EUR", 0.9).unwrap();
+//! rates.set_rate("GBP", 0.75).unwrap();
+//!
+//! let price = Money::new(30.0, Currency::EUR).convert(Currency::GBP, &rates).unwrap();
+//! assert!((price.amount() - 25.0).abs() < 1e-9);
+//! assert!(!rates.is_stale(Duration::from_secs(3600), SystemTime::now()));
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// A currency identified by its ISO 4217 code.
+///
+/// Constants are provided for widely used currencies; others can be created with
+/// [`Currency::new`].
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::currency::Currency;
+///
+/// let krona = Currency::new("SEK", "kr");
+/// assert_eq!(krona.code(), "SEK");
+/// assert_ne!(krona, Currency::EUR);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Currency {
+    /// The three-letter ISO 4217 code (e.g., "USD")
+    code: &'static str,
+    /// The symbol used for display (e.g., "$")
+    symbol: &'static str,
+}
+
+impl Currency {
+    /// United States dollar.
+    pub const USD: Currency = Currency::new("USD", "$");
+    /// Euro.
+    pub const EUR: Currency = Currency::new("EUR", "€");
+    /// Pound sterling.
+    pub const GBP: Currency = Currency::new("GBP", "£");
+    /// Japanese yen.
+    pub const JPY: Currency = Currency::new("JPY", "¥");
+    /// Swiss franc.
+    pub const CHF: Currency = Currency::new("CHF", "CHF");
+    /// Canadian dollar.
+    pub const CAD: Currency = Currency::new("CAD", "CA$");
+    /// Australian dollar.
+    pub const AUD: Currency = Currency::new("AUD", "A$");
+
+    /// Creates a currency from its ISO 4217 code and display symbol.
+    pub const fn new(code: &'static str, symbol: &'static str) -> Self {
+        Self { code, symbol }
+    }
+
+    /// Returns the ISO 4217 code of this currency.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the display symbol of this currency.
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the number of decimal places of the currency's minor unit, such as 2
+    /// for cents or 0 for the yen, as listed in ISO 4217.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::currency::Currency;
+    ///
+    /// assert_eq!(Currency::USD.minor_units(), 2);
+    /// assert_eq!(Currency::JPY.minor_units(), 0);
+    /// assert_eq!(Currency::new("KWD", "KD").minor_units(), 3);
+    /// ```
+    pub fn minor_units(&self) -> usize {
+        minor_units(self.code)
+    }
+}
+
+/// Returns the decimal places of the minor unit of the currency with ISO 4217 code
+/// `code`, assuming the usual 2 for codes not listed with another exponent.
+pub(crate) fn minor_units(code: &str) -> usize {
+    match code.to_ascii_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// An amount of money in a specific currency.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::currency::{Currency, Money};
+///
+/// let bill = Money::new(42.50, Currency::EUR);
+/// assert_eq!(bill.amount(), 42.50);
+/// assert_eq!(bill.currency(), Currency::EUR);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Money {
+    /// The numeric amount in units of `currency`
+    amount: f64,
+    /// The currency the amount is denominated in
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of money in the given currency.
+    pub fn new(amount: f64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Returns the numeric amount.
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    /// Returns the currency the amount is denominated in.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Converts this amount to another currency using `rates`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`ExchangeRates::convert`] if either currency has no rate.
+    pub fn convert(self, to: Currency, rates: &ExchangeRates) -> Result<Money, UnitError> {
+        let amount = rates.convert(self.amount, self.currency.code, to.code)?;
+        Ok(Money::new(amount, to))
+    }
+}
+
+/// A table of exchange rates against one base currency, stamped with the time the
+/// rates were fetched.
+///
+/// Currency codes are matched ignoring ASCII case. The base currency always has a rate
+/// of 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExchangeRates {
+    /// The uppercase code every rate is quoted against
+    base: String,
+    /// Units of each currency per one unit of the base, keyed by uppercase code
+    rates: BTreeMap<String, f64>,
+    /// When the rates were fetched from their source
+    fetched_at: SystemTime,
+}
+
+impl ExchangeRates {
+    /// Creates a table quoted against `base`, holding only the base's own rate.
+    pub fn new(base: &str, fetched_at: SystemTime) -> Self {
+        let base = base.to_ascii_uppercase();
+        Self {
+            rates: BTreeMap::from([(base.clone(), 1.0)]),
+            base,
+            fetched_at,
+        }
+    }
+
+    /// Sets how many units of `code` one unit of the base currency buys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `per_base` is not positive and finite.
+    pub fn set_rate(&mut self, code: &str, per_base: f64) -> Result<(), UnitError> {
+        self.rates
+            .insert(code.to_ascii_uppercase(), check_positive(per_base)?);
+        Ok(())
+    }
+
+    /// Returns the code of the base currency.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Returns when the rates were fetched.
+    pub fn fetched_at(&self) -> SystemTime {
+        self.fetched_at
+    }
+
+    /// Returns each currency code with its rate against the base, in code order.
+    pub fn rates(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.rates.iter().map(|(code, &rate)| (code.as_str(), rate))
+    }
+
+    /// Returns how many units of `to` one unit of `from` buys, if both have a rate.
+    pub fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        let from = self.rates.get(&from.to_ascii_uppercase())?;
+        let to = self.rates.get(&to.to_ascii_uppercase())?;
+        Some(to / from)
+    }
+
+    /// Converts `amount` from currency `from` to currency `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] naming the first currency code that has no
+    /// rate in this table.
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Result<f64, UnitError> {
+        for code in [from, to] {
+            if !self.rates.contains_key(&code.to_ascii_uppercase()) {
+                return Err(UnitError::ParseError {
+                    input: code.to_string(),
+                    reason: "no exchange rate for this currency",
+                });
+            }
+        }
+        Ok(amount * self.rate(from, to).unwrap_or(1.0))
+    }
+
+    /// Returns how long before `now` the rates were fetched, or zero if they are dated
+    /// in the future.
+    pub fn age(&self, now: SystemTime) -> Duration {
+        now.duration_since(self.fetched_at).unwrap_or_default()
+    }
+
+    /// Returns true if the rates were fetched more than `max_age` before `now`.
+    pub fn is_stale(&self, max_age: Duration, now: SystemTime) -> bool {
+        self.age(now) > max_age
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_keeps_its_currency() {
+        let money = Money::new(10.0, Currency::GBP);
+        assert_eq!(money.amount(), 10.0);
+        assert_eq!(money.currency(), Currency::GBP);
+        assert_ne!(money, Money::new(10.0, Currency::USD));
+    }
+
+    #[test]
+    fn currency_metadata() {
+        assert_eq!(Currency::USD.code(), "USD");
+        assert_eq!(Currency::EUR.symbol(), "€");
+        assert_eq!(Currency::new("SEK", "kr").symbol(), "kr");
+    }
+
+    fn rates() -> ExchangeRates {
+        let mut rates = ExchangeRates::new("usd", SystemTime::UNIX_EPOCH);
+        rates.set_rate("EUR", 0.8).unwrap();
+        rates.set_rate("jpy", 150.0).unwrap();
+        rates
+    }
+
+    #[test]
+    fn converts_through_the_base() {
+        let rates = rates();
+        assert_eq!(rates.base(), "USD");
+        assert_eq!(rates.rate("USD", "usd"), Some(1.0));
+        assert!((rates.convert(8.0, "EUR", "JPY").unwrap() - 1500.0).abs() < 1e-9);
+
+        let yen = Money::new(300.0, Currency::JPY).convert(Currency::USD, &rates);
+        assert_eq!(yen.unwrap(), Money::new(2.0, Currency::USD));
+    }
+
+    #[test]
+    fn rejects_missing_and_invalid_rates() {
+        let mut rates = rates();
+        assert_eq!(
+            rates.convert(1.0, "EUR", "SEK").unwrap_err(),
+            UnitError::ParseError {
+                input: "SEK".to_string(),
+                reason: "no exchange rate for this currency",
+            }
+        );
+        assert!(
+            Money::new(1.0, Currency::GBP)
+                .convert(Currency::USD, &rates)
+                .is_err()
+        );
+        assert!(rates.set_rate("SEK", 0.0).is_err());
+        assert!(rates.set_rate("SEK", f64::NAN).is_err());
+    }
+
+    #[test]
+    fn tracks_age() {
+        let rates = rates();
+        let day = Duration::from_secs(86_400);
+        let now = SystemTime::UNIX_EPOCH + day * 2;
+        assert_eq!(rates.age(now), day * 2);
+        assert!(rates.is_stale(day, now));
+        assert!(!rates.is_stale(day * 3, now));
+        assert_eq!(rates.age(SystemTime::UNIX_EPOCH - day), Duration::ZERO);
+        assert_eq!(
+            rates.rates().map(|(code, _)| code).collect::<Vec<_>>(),
+            ["EUR", "JPY", "USD"]
+        );
+    }
+}
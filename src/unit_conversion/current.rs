@@ -0,0 +1,210 @@
+//! # Electric Current Unit Conversions
+//!
+//! This module provides type-safe electric current conversions between the ampere and its
+//! metric multiples. All currents are internally stored in amperes (the base unit) and
+//! converted on demand.
+//!
+//! Currents are signed: a negative value flows against the chosen reference direction,
+//! such as a battery that is charging rather than discharging.
+//!
+//! ## Supported Units
+//!
+//! - **Ampere (A)**: The base unit, SI unit of electric current
+//! - **Milliampere (mA)**: 10⁻³ amperes
+//! - **Microampere (µA)**: 10⁻⁶ amperes
+//! - **Kiloampere (kA)**: 1000 amperes
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::current::{Ampere, Current, Milliampere};
+//!
+//! let draw = Current::from_unit::<Milliampere>(350.0);
+//! assert!((draw.to_unit::<Ampere>() - 0.35).abs() < 1e-15);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for current conversions
+const AMPERES_PER_MILLIAMPERE: f64 = 1e-3;
+const AMPERES_PER_MICROAMPERE: f64 = 1e-6;
+const AMPERES_PER_KILOAMPERE: f64 = 1e3;
+
+/// Marker type for the electric current dimension.
+///
+/// This prevents accidental conversions between a current and the voltage or
+/// resistance of the same circuit.
+pub enum CurrentDimension {}
+
+impl Dimension for CurrentDimension {
+    const NAME: &'static str = "current";
+    const ID: u8 = 20;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Ampere>(&["amp", "amps", "ampere", "amperes"]),
+        UnitInfo::of::<Milliampere>(&["milliamp", "milliamps", "milliampere", "milliamperes"]),
+        UnitInfo::of::<Microampere>(&["uA", "μA", "microampere", "microamperes"]),
+        UnitInfo::of::<Kiloampere>(&["kiloampere", "kiloamperes"]),
+    ];
+}
+
+/// An electric current that stores values in amperes internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current, Milliampere};
+///
+/// let charger = Current::from_unit::<Ampere>(2.4);
+/// let milliamps = charger.to_unit::<Milliampere>(); // 2400.0
+/// ```
+pub type Current = Quantity<Ampere>;
+
+/// Ampere current unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current};
+///
+/// let kettle = Current::from_unit::<Ampere>(13.0);
+/// assert_eq!(kettle.to_unit::<Ampere>(), 13.0);
+/// ```
+pub struct Ampere;
+
+impl UnitConversion for Ampere {
+    type Dimension = CurrentDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Ampere is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Ampere is the base unit
+    }
+
+    const SYMBOL: &'static str = "A";
+    const NAME: &'static str = "ampere";
+    const PLURAL: &'static str = "amperes";
+}
+
+/// Milliampere current unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current, Milliampere};
+///
+/// let led = Current::from_unit::<Milliampere>(20.0);
+/// assert!((led.to_unit::<Ampere>() - 0.02).abs() < 1e-15);
+/// ```
+pub struct Milliampere;
+
+impl UnitConversion for Milliampere {
+    type Dimension = CurrentDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * AMPERES_PER_MILLIAMPERE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / AMPERES_PER_MILLIAMPERE
+    }
+
+    const SYMBOL: &'static str = "mA";
+    const NAME: &'static str = "milliampere";
+    const PLURAL: &'static str = "milliamperes";
+}
+
+/// Microampere current unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Current, Microampere, Milliampere};
+///
+/// let sleep = Current::from_unit::<Microampere>(150.0);
+/// assert!((sleep.to_unit::<Milliampere>() - 0.15).abs() < 1e-12);
+/// ```
+pub struct Microampere;
+
+impl UnitConversion for Microampere {
+    type Dimension = CurrentDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * AMPERES_PER_MICROAMPERE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / AMPERES_PER_MICROAMPERE
+    }
+
+    const SYMBOL: &'static str = "µA";
+    const NAME: &'static str = "microampere";
+    const PLURAL: &'static str = "microamperes";
+}
+
+/// Kiloampere current unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current, Kiloampere};
+///
+/// let strike = Current::from_unit::<Kiloampere>(30.0);
+/// assert_eq!(strike.to_unit::<Ampere>(), 30_000.0);
+/// ```
+pub struct Kiloampere;
+
+impl UnitConversion for Kiloampere {
+    type Dimension = CurrentDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * AMPERES_PER_KILOAMPERE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / AMPERES_PER_KILOAMPERE
+    }
+
+    const SYMBOL: &'static str = "kA";
+    const NAME: &'static str = "kiloampere";
+    const PLURAL: &'static str = "kiloamperes";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_prefixes() {
+        let current = Current::from_unit::<Ampere>(1.5);
+        assert!(approx(current.to_unit::<Milliampere>(), 1500.0, 1e-9));
+        assert!(approx(current.to_unit::<Microampere>(), 1.5e6, 1e-6));
+        assert!(approx(current.to_unit::<Kiloampere>(), 0.0015, 1e-15));
+
+        let charging = Current::from_unit::<Milliampere>(-500.0);
+        assert!(approx(charging.to_unit::<Ampere>(), -0.5, 1e-15));
+    }
+
+    #[test]
+    fn current_symbols() {
+        assert_eq!(Ampere::SYMBOL, "A");
+        assert_eq!(Milliampere::SYMBOL, "mA");
+        assert_eq!(Microampere::SYMBOL, "µA");
+        assert_eq!(Kiloampere::SYMBOL, "kA");
+        assert_eq!(CurrentDimension::find_unit("amps").unwrap().symbol, "A");
+        assert_eq!(CurrentDimension::find_unit("uA").unwrap().symbol, "µA");
+    }
+}
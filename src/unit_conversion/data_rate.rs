@@ -0,0 +1,360 @@
+//! # Data Rate Unit Conversions
+//!
+//! This module provides type-safe conversions between data rates in bits and bytes per
+//! second and their decimal multiples.
+//! All rates are internally stored in bits per second (the base unit) and converted on
+//! demand.
+//!
+//! ## Supported Units
+//!
+//! - **Bit per second (bit/s)**: The base unit
+//! - **Kilobit, megabit and gigabit per second (kbit/s, Mbit/s, Gbit/s)**: 10³, 10⁶ and
+//!   10⁹ bits per second
+//! - **Byte per second (B/s)**: 8 bits per second
+//! - **Kilobyte, megabyte and gigabyte per second (kB/s, MB/s, GB/s)**: 10³, 10⁶ and 10⁹
+//!   bytes per second
+//!
+//! Network links are rated in bits per second while file transfers are usually shown in
+//! bytes per second, a factor of eight apart. The common `Mbps`-style abbreviations are
+//! accepted as aliases, and `Mb/s` and `MB/s` are told apart by case.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::data_rate::{DataRate, MegabitPerSecond, MegabytePerSecond};
+//!
+//! let fiber = DataRate::from_unit::<MegabitPerSecond>(500.0);
+//! assert_eq!(fiber.to_unit::<MegabytePerSecond>(), 62.5);
+//! ```
+
+use crate::unit_conversion::{Dimension, NonNegative, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for data rate conversions
+const BITS_PER_SECOND_PER_KILOBIT_PER_SECOND: f64 = 1e3;
+const BITS_PER_SECOND_PER_MEGABIT_PER_SECOND: f64 = 1e6;
+const BITS_PER_SECOND_PER_GIGABIT_PER_SECOND: f64 = 1e9;
+const BITS_PER_SECOND_PER_BYTE_PER_SECOND: f64 = 8.0;
+const BITS_PER_SECOND_PER_KILOBYTE_PER_SECOND: f64 = 8e3;
+const BITS_PER_SECOND_PER_MEGABYTE_PER_SECOND: f64 = 8e6;
+const BITS_PER_SECOND_PER_GIGABYTE_PER_SECOND: f64 = 8e9;
+
+/// Marker type for the data rate dimension.
+///
+/// This prevents accidental conversions between a data rate and an amount of data or
+/// a frequency.
+pub enum DataRateDimension {}
+
+impl Dimension for DataRateDimension {
+    const NAME: &'static str = "data rate";
+    const ID: u8 = 19;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<BitPerSecond>(&["bps", "b/s", "bit per second", "bits per second"]),
+        UnitInfo::of::<KilobitPerSecond>(&["kbps", "kb/s", "kilobits per second"]),
+        UnitInfo::of::<MegabitPerSecond>(&["Mbps", "Mb/s", "megabits per second"]),
+        UnitInfo::of::<GigabitPerSecond>(&["Gbps", "Gb/s", "gigabits per second"]),
+        UnitInfo::of::<BytePerSecond>(&["Bps", "bytes per second"]),
+        UnitInfo::of::<KilobytePerSecond>(&["kBps", "KB/s", "kilobytes per second"]),
+        UnitInfo::of::<MegabytePerSecond>(&["MBps", "megabytes per second"]),
+        UnitInfo::of::<GigabytePerSecond>(&["GBps", "gigabytes per second"]),
+    ];
+}
+
+impl NonNegative for DataRateDimension {}
+
+/// A data rate that stores values in bits per second internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, GigabitPerSecond, MegabytePerSecond};
+///
+/// let uplink = DataRate::from_unit::<GigabitPerSecond>(1.0);
+/// let bytes = uplink.to_unit::<MegabytePerSecond>(); // 125.0
+/// ```
+pub type DataRate = Quantity<BitPerSecond>;
+
+/// Bit per second data-rate unit (the base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{BitPerSecond, DataRate};
+///
+/// let modem = DataRate::from_unit::<BitPerSecond>(56_000.0);
+/// assert_eq!(modem.to_unit::<BitPerSecond>(), 56_000.0);
+/// ```
+pub struct BitPerSecond;
+
+impl UnitConversion for BitPerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // BitPerSecond is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // BitPerSecond is the base unit
+    }
+
+    const SYMBOL: &'static str = "bit/s";
+    const NAME: &'static str = "bit per second";
+    const PLURAL: &'static str = "bits per second";
+}
+
+/// Kilobit per second data-rate unit: 1000 bits per second.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{BitPerSecond, DataRate, KilobitPerSecond};
+///
+/// let audio = DataRate::from_unit::<KilobitPerSecond>(128.0);
+/// assert_eq!(audio.to_unit::<BitPerSecond>(), 128_000.0);
+/// ```
+pub struct KilobitPerSecond;
+
+impl UnitConversion for KilobitPerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_KILOBIT_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_KILOBIT_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "kbit/s";
+    const NAME: &'static str = "kilobit per second";
+    const PLURAL: &'static str = "kilobits per second";
+}
+
+/// Megabit per second data-rate unit: 10⁶ bits per second.
+///
+/// The unit internet connections are usually advertised in.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, MegabitPerSecond, MegabytePerSecond};
+///
+/// let plan = DataRate::from_unit::<MegabitPerSecond>(100.0);
+/// assert_eq!(plan.to_unit::<MegabytePerSecond>(), 12.5);
+/// ```
+pub struct MegabitPerSecond;
+
+impl UnitConversion for MegabitPerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_MEGABIT_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_MEGABIT_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "Mbit/s";
+    const NAME: &'static str = "megabit per second";
+    const PLURAL: &'static str = "megabits per second";
+}
+
+/// Gigabit per second data-rate unit: 10⁹ bits per second.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, GigabitPerSecond, MegabitPerSecond};
+///
+/// let link = DataRate::from_unit::<GigabitPerSecond>(10.0);
+/// assert_eq!(link.to_unit::<MegabitPerSecond>(), 10_000.0);
+/// ```
+pub struct GigabitPerSecond;
+
+impl UnitConversion for GigabitPerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_GIGABIT_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_GIGABIT_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "Gbit/s";
+    const NAME: &'static str = "gigabit per second";
+    const PLURAL: &'static str = "gigabits per second";
+}
+
+/// Byte per second data-rate unit: 8 bits per second.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{BitPerSecond, BytePerSecond, DataRate};
+///
+/// let trickle = DataRate::from_unit::<BytePerSecond>(100.0);
+/// assert_eq!(trickle.to_unit::<BitPerSecond>(), 800.0);
+/// ```
+pub struct BytePerSecond;
+
+impl UnitConversion for BytePerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_BYTE_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_BYTE_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "B/s";
+    const NAME: &'static str = "byte per second";
+    const PLURAL: &'static str = "bytes per second";
+}
+
+/// Kilobyte per second data-rate unit: 1000 bytes per second.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, KilobitPerSecond, KilobytePerSecond};
+///
+/// let sync = DataRate::from_unit::<KilobytePerSecond>(250.0);
+/// assert_eq!(sync.to_unit::<KilobitPerSecond>(), 2000.0);
+/// ```
+pub struct KilobytePerSecond;
+
+impl UnitConversion for KilobytePerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_KILOBYTE_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_KILOBYTE_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "kB/s";
+    const NAME: &'static str = "kilobyte per second";
+    const PLURAL: &'static str = "kilobytes per second";
+}
+
+/// Megabyte per second data-rate unit: 10⁶ bytes per second.
+///
+/// The unit download managers and disks usually report.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, MegabitPerSecond, MegabytePerSecond};
+///
+/// let disk = DataRate::from_unit::<MegabytePerSecond>(550.0);
+/// assert_eq!(disk.to_unit::<MegabitPerSecond>(), 4400.0);
+/// ```
+pub struct MegabytePerSecond;
+
+impl UnitConversion for MegabytePerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_MEGABYTE_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_MEGABYTE_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "MB/s";
+    const NAME: &'static str = "megabyte per second";
+    const PLURAL: &'static str = "megabytes per second";
+}
+
+/// Gigabyte per second data-rate unit: 10⁹ bytes per second.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, GigabitPerSecond, GigabytePerSecond};
+///
+/// let bus = DataRate::from_unit::<GigabytePerSecond>(4.0);
+/// assert_eq!(bus.to_unit::<GigabitPerSecond>(), 32.0);
+/// ```
+pub struct GigabytePerSecond;
+
+impl UnitConversion for GigabytePerSecond {
+    type Dimension = DataRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_SECOND_PER_GIGABYTE_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_SECOND_PER_GIGABYTE_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "GB/s";
+    const NAME: &'static str = "gigabyte per second";
+    const PLURAL: &'static str = "gigabytes per second";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn bits_and_bytes() {
+        let rate = DataRate::from_unit::<MegabitPerSecond>(8.0);
+        assert!(approx(rate.to_unit::<MegabytePerSecond>(), 1.0, 1e-12));
+        assert!(approx(rate.to_unit::<KilobytePerSecond>(), 1000.0, 1e-9));
+        assert!(approx(rate.to_unit::<BytePerSecond>(), 1e6, 1e-6));
+
+        let rate = DataRate::from_unit::<GigabytePerSecond>(1.0);
+        assert!(approx(rate.to_unit::<GigabitPerSecond>(), 8.0, 1e-12));
+    }
+
+    #[test]
+    fn case_distinguishes_bits_from_bytes() {
+        for (alias, symbol) in [
+            ("Mbps", "Mbit/s"),
+            ("Mb/s", "Mbit/s"),
+            ("MB/s", "MB/s"),
+            ("MBps", "MB/s"),
+            ("bps", "bit/s"),
+            ("Bps", "B/s"),
+        ] {
+            assert_eq!(DataRateDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn data_rate_symbols() {
+        assert_eq!(BitPerSecond::SYMBOL, "bit/s");
+        assert_eq!(MegabitPerSecond::SYMBOL, "Mbit/s");
+        assert_eq!(BytePerSecond::SYMBOL, "B/s");
+        assert_eq!(MegabytePerSecond::SYMBOL, "MB/s");
+    }
+}
@@ -0,0 +1,520 @@
+//! # Data Size Unit Conversions
+//!
+//! This module provides type-safe conversions between bits, bytes, and their decimal
+//! (SI) and binary (IEC) multiples.
+//! All sizes are internally stored in bits (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Bit (bit)**: The base unit
+//! - **Byte (B)**: 8 bits
+//! - **Kilobit (kbit)**, **Megabit (Mbit)**, **Gigabit (Gbit)**: 10³, 10⁶ and 10⁹ bits
+//! - **Kilobyte (kB)**, **Megabyte (MB)**, **Gigabyte (GB)**, **Terabyte (TB)**: 10³, 10⁶,
+//!   10⁹ and 10¹² bytes
+//! - **Kibibyte (KiB)**, **Mebibyte (MiB)**, **Gibibyte (GiB)**, **Tebibyte (TiB)**: 2¹⁰,
+//!   2²⁰, 2³⁰ and 2⁴⁰ bytes
+//!
+//! Decimal and binary prefixes are distinct units: a "16 GB" memory module holds 16 GiB,
+//! about 7% more than 16 GB.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::data_size::{DataSize, Gibibyte, Gigabyte, Megabit};
+//!
+//! let download = DataSize::from_unit::<Gigabyte>(1.0);
+//! assert_eq!(download.to_unit::<Megabit>(), 8000.0);
+//! assert!((download.to_unit::<Gibibyte>() - 0.931323).abs() < 1e-6);
+//! ```
+
+use crate::unit_conversion::{Dimension, NonNegative, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for data size conversions
+const BITS_PER_BYTE: f64 = 8.0;
+const BITS_PER_KILOBIT: f64 = 1e3;
+const BITS_PER_MEGABIT: f64 = 1e6;
+const BITS_PER_GIGABIT: f64 = 1e9;
+const BITS_PER_KILOBYTE: f64 = 1e3 * BITS_PER_BYTE;
+const BITS_PER_MEGABYTE: f64 = 1e6 * BITS_PER_BYTE;
+const BITS_PER_GIGABYTE: f64 = 1e9 * BITS_PER_BYTE;
+const BITS_PER_TERABYTE: f64 = 1e12 * BITS_PER_BYTE;
+const BITS_PER_KIBIBYTE: f64 = 1024.0 * BITS_PER_BYTE;
+const BITS_PER_MEBIBYTE: f64 = 1024.0 * BITS_PER_KIBIBYTE;
+const BITS_PER_GIBIBYTE: f64 = 1024.0 * BITS_PER_MEBIBYTE;
+const BITS_PER_TEBIBYTE: f64 = 1024.0 * BITS_PER_GIBIBYTE;
+
+/// Marker type for the data size dimension.
+///
+/// This prevents accidental conversions between an amount of data and a data rate.
+pub enum DataSizeDimension {}
+
+impl Dimension for DataSizeDimension {
+    const NAME: &'static str = "data size";
+    const ID: u8 = 18;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Bit>(&["b", "bits"]),
+        UnitInfo::of::<Byte>(&["byte", "bytes", "octet", "octets"]),
+        UnitInfo::of::<Kilobit>(&["kilobit", "kilobits"]),
+        UnitInfo::of::<Megabit>(&["megabit", "megabits"]),
+        UnitInfo::of::<Gigabit>(&["gigabit", "gigabits"]),
+        UnitInfo::of::<Kilobyte>(&["KB", "kilobyte", "kilobytes"]),
+        UnitInfo::of::<Megabyte>(&["megabyte", "megabytes"]),
+        UnitInfo::of::<Gigabyte>(&["gigabyte", "gigabytes"]),
+        UnitInfo::of::<Terabyte>(&["terabyte", "terabytes"]),
+        UnitInfo::of::<Kibibyte>(&["kibibyte", "kibibytes"]),
+        UnitInfo::of::<Mebibyte>(&["mebibyte", "mebibytes"]),
+        UnitInfo::of::<Gibibyte>(&["gibibyte", "gibibytes"]),
+        UnitInfo::of::<Tebibyte>(&["tebibyte", "tebibytes"]),
+    ];
+}
+
+impl NonNegative for DataSizeDimension {}
+
+/// An amount of data that stores values in bits internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Byte, DataSize, Kibibyte};
+///
+/// let buffer = DataSize::from_unit::<Kibibyte>(64.0);
+/// let bytes = buffer.to_unit::<Byte>(); // 65536.0
+/// ```
+pub type DataSize = Quantity<Bit>;
+
+/// Bit data-size unit (the base unit).
+///
+/// A single binary digit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Bit, Byte, DataSize};
+///
+/// let flags = DataSize::from_unit::<Bit>(16.0);
+/// assert_eq!(flags.to_unit::<Byte>(), 2.0);
+/// ```
+pub struct Bit;
+
+impl UnitConversion for Bit {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Bit is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Bit is the base unit
+    }
+
+    const SYMBOL: &'static str = "bit";
+    const NAME: &'static str = "bit";
+    const PLURAL: &'static str = "bits";
+}
+
+/// Byte data-size unit.
+///
+/// Eight bits (an octet).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Bit, Byte, DataSize};
+///
+/// let word = DataSize::from_unit::<Byte>(4.0);
+/// assert_eq!(word.to_unit::<Bit>(), 32.0);
+/// ```
+pub struct Byte;
+
+impl UnitConversion for Byte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_BYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_BYTE
+    }
+
+    const SYMBOL: &'static str = "B";
+    const NAME: &'static str = "byte";
+    const PLURAL: &'static str = "bytes";
+}
+
+/// Kilobit data-size unit: 1000 bits.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Bit, DataSize, Kilobit};
+///
+/// let packet = DataSize::from_unit::<Kilobit>(12.0);
+/// assert_eq!(packet.to_unit::<Bit>(), 12_000.0);
+/// ```
+pub struct Kilobit;
+
+impl UnitConversion for Kilobit {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_KILOBIT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_KILOBIT
+    }
+
+    const SYMBOL: &'static str = "kbit";
+    const NAME: &'static str = "kilobit";
+    const PLURAL: &'static str = "kilobits";
+}
+
+/// Megabit data-size unit: 10⁶ bits.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Kilobit, Megabit};
+///
+/// let chunk = DataSize::from_unit::<Megabit>(1.5);
+/// assert_eq!(chunk.to_unit::<Kilobit>(), 1500.0);
+/// ```
+pub struct Megabit;
+
+impl UnitConversion for Megabit {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_MEGABIT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_MEGABIT
+    }
+
+    const SYMBOL: &'static str = "Mbit";
+    const NAME: &'static str = "megabit";
+    const PLURAL: &'static str = "megabits";
+}
+
+/// Gigabit data-size unit: 10⁹ bits.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Gigabit, Megabyte};
+///
+/// let transfer = DataSize::from_unit::<Gigabit>(8.0);
+/// assert_eq!(transfer.to_unit::<Megabyte>(), 1000.0);
+/// ```
+pub struct Gigabit;
+
+impl UnitConversion for Gigabit {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_GIGABIT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_GIGABIT
+    }
+
+    const SYMBOL: &'static str = "Gbit";
+    const NAME: &'static str = "gigabit";
+    const PLURAL: &'static str = "gigabits";
+}
+
+/// Kilobyte data-size unit: 1000 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Byte, DataSize, Kilobyte};
+///
+/// let page = DataSize::from_unit::<Kilobyte>(4.096);
+/// assert!((page.to_unit::<Byte>() - 4096.0).abs() < 1e-9);
+/// ```
+pub struct Kilobyte;
+
+impl UnitConversion for Kilobyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_KILOBYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_KILOBYTE
+    }
+
+    const SYMBOL: &'static str = "kB";
+    const NAME: &'static str = "kilobyte";
+    const PLURAL: &'static str = "kilobytes";
+}
+
+/// Megabyte data-size unit: 10⁶ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Kilobyte, Megabyte};
+///
+/// let photo = DataSize::from_unit::<Megabyte>(3.2);
+/// assert!((photo.to_unit::<Kilobyte>() - 3200.0).abs() < 1e-9);
+/// ```
+pub struct Megabyte;
+
+impl UnitConversion for Megabyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_MEGABYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_MEGABYTE
+    }
+
+    const SYMBOL: &'static str = "MB";
+    const NAME: &'static str = "megabyte";
+    const PLURAL: &'static str = "megabytes";
+}
+
+/// Gigabyte data-size unit: 10⁹ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Gigabyte, Megabyte};
+///
+/// let video = DataSize::from_unit::<Gigabyte>(1.2);
+/// assert!((video.to_unit::<Megabyte>() - 1200.0).abs() < 1e-9);
+/// ```
+pub struct Gigabyte;
+
+impl UnitConversion for Gigabyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_GIGABYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_GIGABYTE
+    }
+
+    const SYMBOL: &'static str = "GB";
+    const NAME: &'static str = "gigabyte";
+    const PLURAL: &'static str = "gigabytes";
+}
+
+/// Terabyte data-size unit: 10¹² bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Gigabyte, Terabyte};
+///
+/// let disk = DataSize::from_unit::<Terabyte>(2.0);
+/// assert_eq!(disk.to_unit::<Gigabyte>(), 2000.0);
+/// ```
+pub struct Terabyte;
+
+impl UnitConversion for Terabyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_TERABYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_TERABYTE
+    }
+
+    const SYMBOL: &'static str = "TB";
+    const NAME: &'static str = "terabyte";
+    const PLURAL: &'static str = "terabytes";
+}
+
+/// Kibibyte data-size unit: 1024 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{Byte, DataSize, Kibibyte};
+///
+/// let block = DataSize::from_unit::<Kibibyte>(4.0);
+/// assert_eq!(block.to_unit::<Byte>(), 4096.0);
+/// ```
+pub struct Kibibyte;
+
+impl UnitConversion for Kibibyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_KIBIBYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_KIBIBYTE
+    }
+
+    const SYMBOL: &'static str = "KiB";
+    const NAME: &'static str = "kibibyte";
+    const PLURAL: &'static str = "kibibytes";
+}
+
+/// Mebibyte data-size unit: 2²⁰ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Kibibyte, Mebibyte};
+///
+/// let cache = DataSize::from_unit::<Mebibyte>(2.0);
+/// assert_eq!(cache.to_unit::<Kibibyte>(), 2048.0);
+/// ```
+pub struct Mebibyte;
+
+impl UnitConversion for Mebibyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_MEBIBYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_MEBIBYTE
+    }
+
+    const SYMBOL: &'static str = "MiB";
+    const NAME: &'static str = "mebibyte";
+    const PLURAL: &'static str = "mebibytes";
+}
+
+/// Gibibyte data-size unit: 2³⁰ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Gibibyte, Gigabyte};
+///
+/// let memory = DataSize::from_unit::<Gibibyte>(16.0);
+/// assert!((memory.to_unit::<Gigabyte>() - 17.179869184).abs() < 1e-9);
+/// ```
+pub struct Gibibyte;
+
+impl UnitConversion for Gibibyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_GIBIBYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_GIBIBYTE
+    }
+
+    const SYMBOL: &'static str = "GiB";
+    const NAME: &'static str = "gibibyte";
+    const PLURAL: &'static str = "gibibytes";
+}
+
+/// Tebibyte data-size unit: 2⁴⁰ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_size::{DataSize, Gibibyte, Tebibyte};
+///
+/// let array = DataSize::from_unit::<Tebibyte>(1.0);
+/// assert_eq!(array.to_unit::<Gibibyte>(), 1024.0);
+/// ```
+pub struct Tebibyte;
+
+impl UnitConversion for Tebibyte {
+    type Dimension = DataSizeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * BITS_PER_TEBIBYTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / BITS_PER_TEBIBYTE
+    }
+
+    const SYMBOL: &'static str = "TiB";
+    const NAME: &'static str = "tebibyte";
+    const PLURAL: &'static str = "tebibytes";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn decimal_multiples() {
+        let size = DataSize::from_unit::<Megabyte>(1.0);
+        assert!(approx(size.to_unit::<Kilobyte>(), 1000.0, 1e-9));
+        assert!(approx(size.to_unit::<Megabit>(), 8.0, 1e-12));
+        assert!(approx(size.to_unit::<Byte>(), 1e6, 1e-6));
+
+        let size = DataSize::from_unit::<Gigabit>(1.0);
+        assert!(approx(size.to_unit::<Megabyte>(), 125.0, 1e-9));
+    }
+
+    #[test]
+    fn binary_multiples() {
+        let size = DataSize::from_unit::<Gibibyte>(1.0);
+        assert!(approx(size.to_unit::<Byte>(), 1_073_741_824.0, 1e-3));
+        assert!(approx(size.to_unit::<Mebibyte>(), 1024.0, 1e-9));
+        assert!(approx(size.to_unit::<Gigabyte>(), 1.073741824, 1e-12));
+
+        let size = DataSize::from_unit::<Terabyte>(1.0);
+        assert!(approx(size.to_unit::<Tebibyte>(), 0.9094947, 1e-7));
+    }
+
+    #[test]
+    fn data_size_symbols() {
+        assert_eq!(Bit::SYMBOL, "bit");
+        assert_eq!(Byte::SYMBOL, "B");
+        assert_eq!(Megabit::SYMBOL, "Mbit");
+        assert_eq!(Megabyte::SYMBOL, "MB");
+        assert_eq!(Mebibyte::SYMBOL, "MiB");
+        assert_eq!(DataSizeDimension::find_unit("b").unwrap().symbol, "bit");
+        assert_eq!(DataSizeDimension::find_unit("B").unwrap().symbol, "B");
+        assert_eq!(DataSizeDimension::find_unit("KB").unwrap().symbol, "kB");
+    }
+}
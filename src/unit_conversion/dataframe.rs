@@ -0,0 +1,298 @@
+//! # Polars Integration
+//!
+//! Dataframe pipelines often receive measurements in whatever unit each source used:
+//! one file in miles, another in kilometers, a third with a `unit` column next to the
+//! values. The functions here convert polars columns with this crate's conversion
+//! factors, so the normalization happens inside the pipeline instead of in a hand-kept
+//! table of multipliers:
+//!
+//! - [`convert_series`] and [`convert_series_typed`] convert a whole `Series` between
+//!   two units, named at runtime or given as types.
+//! - [`normalize_series`] converts a `Series` of values whose units are given row by
+//!   row in a second, string `Series`.
+//! - [`convert`] and [`normalize`] build the same conversions as lazy expressions.
+//!
+//! Unit names are matched as by [`registry::convert`]. Values are cast to `Float64`,
+//! and nulls, including rows whose unit is null, stay null. Conversion failures are
+//! reported as `PolarsError::ComputeError` carrying the [`UnitError`] message, so they
+//! propagate through `?` in pipeline code.
+//!
+//! ## Examples
+//!
+//! ```
+//! use polars::prelude::*;
+//! use uom::unit_conversion::dataframe::{convert, normalize};
+//!
+//! let df = df!(
+//!     "distance" => [5.0, 10.0, 2.0],
+//!     "unit" => ["mi", "km", "mi"],
+//! )
+//! .unwrap();
+//! let km = df
+//!     .lazy()
+//!     .select([normalize(col("distance"), col("unit"), "km").unwrap()])
+//!     .collect()
+//!     .unwrap();
+//! let km: Vec<f64> = km["distance"].f64().unwrap().into_no_null_iter().collect();
+//! assert!((km[0] - 8.04672).abs() < 1e-9);
+//! assert_eq!(km[1], 10.0);
+//!
+//! let feet = convert(col("distance"), "m", "ft").unwrap();
+//! # let _ = feet;
+//! ```
+
+use polars::prelude::*;
+
+use crate::unit_conversion::registry::{self, resolve};
+use crate::unit_conversion::{UnitConversion, UnitError, UnitInfo};
+
+impl From<UnitError> for PolarsError {
+    fn from(error: UnitError) -> Self {
+        PolarsError::ComputeError(error.to_string().into())
+    }
+}
+
+/// Returns `series` converted from the unit named `from` to the unit named `to`, as a
+/// `Float64` series with the same name.
+///
+/// # Errors
+///
+/// Returns a `ComputeError` for the unit errors of [`registry::convert`], and the
+/// cast error if the series is not numeric.
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use uom::unit_conversion::dataframe::convert_series;
+///
+/// let celsius = Series::new("t".into(), [0.0, 100.0]);
+/// let fahrenheit = convert_series(&celsius, "°C", "°F").unwrap();
+/// assert_eq!(fahrenheit.f64().unwrap().get(1), Some(212.0));
+/// ```
+pub fn convert_series(series: &Series, from: &str, to: &str) -> PolarsResult<Series> {
+    let (_, from, to) = resolve(from, to)?;
+    convert_between(series, from, to)
+}
+
+/// Returns `series` converted from unit `V` to unit `W` of the same dimension.
+///
+/// # Errors
+///
+/// Returns the cast error if the series is not numeric.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use polars::prelude::*;
+/// use uom::unit_conversion::dataframe::convert_series_typed;
+/// use uom::unit_conversion::length::{Foot, Meter};
+///
+/// let heights = Series::new("height".into(), [6, 5]);
+/// let meters = convert_series_typed::<Foot, Meter>(&heights).unwrap();
+/// let first = meters.f64().unwrap().get(0).unwrap();
+/// assert!((first - 1.8288).abs() < 1e-12);
+/// ```
+pub fn convert_series_typed<V, W>(series: &Series) -> PolarsResult<Series>
+where
+    V: UnitConversion,
+    W: UnitConversion<Dimension = V::Dimension>,
+{
+    Ok(series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .apply_values(|value| W::convert_from(V::convert_to(value)))
+        .into_series())
+}
+
+/// Returns `values` converted to the unit named `to`, reading each row's unit from the
+/// string series `units`.
+///
+/// # Errors
+///
+/// Returns a `ComputeError` if a unit is unknown or of another dimension than `to`,
+/// and a shape error if the series differ in length.
+///
+/// # Examples
+///
+/// ```
+/// use polars::prelude::*;
+/// use uom::unit_conversion::dataframe::normalize_series;
+///
+/// let mass = Series::new("mass".into(), [Some(1.0), Some(500.0), None]);
+/// let unit = Series::new("unit".into(), ["kg", "g", "lb"]);
+/// let kg = normalize_series(&mass, &unit, "kg").unwrap();
+/// let kg: Vec<Option<f64>> = kg.f64().unwrap().into_iter().collect();
+/// assert_eq!(kg, [Some(1.0), Some(0.5), None]);
+/// ```
+pub fn normalize_series(values: &Series, units: &Series, to: &str) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == units.len(),
+        ShapeMismatch: "{} values but {} units", values.len(), units.len()
+    );
+    let values = values.cast(&DataType::Float64)?;
+    let mut resolved: Vec<(&str, &'static UnitInfo, &'static UnitInfo)> = Vec::new();
+    let mut converted = Vec::with_capacity(values.len());
+    for (value, unit) in values.f64()?.iter().zip(units.str()?.iter()) {
+        let (Some(value), Some(unit)) = (value, unit) else {
+            converted.push(None);
+            continue;
+        };
+        let (from, to) = match resolved.iter().find(|(name, ..)| *name == unit) {
+            Some(&(_, from, to)) => (from, to),
+            None => {
+                let (_, from, to) = resolve(unit, to)?;
+                resolved.push((unit, from, to));
+                (from, to)
+            }
+        };
+        converted.push(Some((to.from_base)((from.to_base)(value))));
+    }
+    Ok(
+        Float64Chunked::from_iter_options(values.name().clone(), converted.into_iter())
+            .into_series(),
+    )
+}
+
+/// Returns an expression converting `expr` from the unit named `from` to the unit
+/// named `to`.
+///
+/// # Errors
+///
+/// Returns the unit errors of [`registry::convert`] when the expression is built.
+pub fn convert(expr: Expr, from: &str, to: &str) -> Result<Expr, UnitError> {
+    let (_, from, to) = resolve(from, to)?;
+    Ok(expr.map(
+        move |column| Ok(convert_between(column.as_materialized_series(), from, to)?.into_column()),
+        float64,
+    ))
+}
+
+/// Returns an expression converting `values` to the unit named `to`, reading each
+/// row's unit from the string expression `units`, as [`normalize_series`] does.
+///
+/// # Errors
+///
+/// Returns an error if `to` is not a known unit; the units of the rows are checked when
+/// the expression is evaluated.
+pub fn normalize(values: Expr, units: Expr, to: &str) -> Result<Expr, UnitError> {
+    let (_, target) = registry::find_unit(to).ok_or_else(|| UnitError::ParseError {
+        input: to.to_string(),
+        reason: "unknown unit",
+    })?;
+    let to = target.symbol;
+    Ok(values.map_many(
+        move |columns| {
+            let [values, units] = columns else {
+                unreachable!("normalize takes two columns")
+            };
+            Ok(normalize_series(
+                values.as_materialized_series(),
+                units.as_materialized_series(),
+                to,
+            )?
+            .into_column())
+        },
+        &[units],
+        |schema, fields| float64(schema, &fields[0]),
+    ))
+}
+
+fn convert_between(
+    series: &Series,
+    from: &'static UnitInfo,
+    to: &'static UnitInfo,
+) -> PolarsResult<Series> {
+    Ok(series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .apply_values(|value| (to.from_base)((from.to_base)(value)))
+        .into_series())
+}
+
+/// The output field of a conversion: the input's name with type `Float64`.
+fn float64(_: &Schema, field: &Field) -> PolarsResult<Field> {
+    Ok(Field::new(field.name().clone(), DataType::Float64))
+}
+
+#[cfg(all(test, feature = "length", feature = "mass"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Kilometer, Mile};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn values(series: &Series) -> Vec<Option<f64>> {
+        series.f64().unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn converts_named_and_typed_units() {
+        let miles = Series::new("d".into(), [Some(1.0), None, Some(26.2)]);
+        let named = convert_series(&miles, "miles", "km").unwrap();
+        let typed = convert_series_typed::<Mile, Kilometer>(&miles).unwrap();
+        assert_eq!(named.name().as_str(), "d");
+        assert_eq!(values(&named), values(&typed));
+        assert!(approx(values(&named)[0].unwrap(), 1.609344, 1e-12));
+        assert_eq!(values(&named)[1], None);
+    }
+
+    #[test]
+    fn normalizes_mixed_units() {
+        let mass = Series::new("m".into(), [2.0, 1500.0, 1.0, 3.0]);
+        let unit = Series::new("u".into(), [Some("kg"), Some("g"), Some("lb"), None]);
+        let kg = values(&normalize_series(&mass, &unit, "kg").unwrap());
+        assert_eq!(kg[..2], [Some(2.0), Some(1.5)]);
+        assert!(approx(kg[2].unwrap(), 0.45359237, 1e-12));
+        assert_eq!(kg[3], None);
+    }
+
+    #[test]
+    fn lazy_expressions_match_series_functions() {
+        let df = df!(
+            "value" => [1.0, 2.0, 500.0],
+            "unit" => ["km", "mi", "m"],
+        )
+        .unwrap();
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                normalize(col("value"), col("unit"), "m").unwrap(),
+                convert(col("value"), "km", "m").unwrap().alias("as_km"),
+            ])
+            .collect()
+            .unwrap();
+        let expected = normalize_series(
+            df["value"].as_materialized_series(),
+            df["unit"].as_materialized_series(),
+            "m",
+        )
+        .unwrap();
+        assert_eq!(
+            values(out["value"].as_materialized_series()),
+            values(&expected)
+        );
+        assert_eq!(
+            values(out["as_km"].as_materialized_series()),
+            [Some(1000.0), Some(2000.0), Some(500_000.0)]
+        );
+    }
+
+    #[test]
+    fn reports_unit_errors() {
+        let s = Series::new("x".into(), [1.0]);
+        assert!(convert_series(&s, "kg", "km").is_err());
+        assert!(convert(col("x"), "furlongs", "m").is_err());
+        assert!(normalize(col("x"), col("u"), "parsecs").is_err());
+
+        let unit = Series::new("u".into(), ["kg"]);
+        let err = normalize_series(&s, &unit, "m").unwrap_err();
+        assert!(err.to_string().contains("mass"));
+        let short = Series::new("u".into(), Vec::<&str>::new());
+        assert!(normalize_series(&s, &short, "m").is_err());
+    }
+}
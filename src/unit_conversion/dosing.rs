@@ -0,0 +1,287 @@
+//! # Weight-Based Dosing Helpers
+//!
+//! Many drugs are prescribed per kilogram of body mass: a bolus in mg/kg, or a
+//! continuous infusion in µg/kg/min. Multiplying by the wrong patient mass, or by a mass
+//! in pounds, is a classic medication error. [`dose`] and [`infusion_rate`] take the
+//! patient's typed [`Mass`], so pounds and kilograms both work, and return a typed
+//! [`Mass`] or [`MassRate`].
+//!
+//! The per-kilogram amount is passed as the quantity given for each kilogram of
+//! patient: 15 mg/kg is `Mass::from_unit::<Milligram>(15.0)`, and 0.1 µg/kg/min is
+//! `MassRate::from_unit::<MicrogramPerMinute>(0.1)`.
+//!
+//! [`DoseLimits`] is the validation hook: it holds the permitted range for a result and
+//! rejects, or caps, anything outside it.
+//!
+//! These helpers do the arithmetic only; they are not a substitute for clinical
+//! judgement or a checked drug reference.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::dosing::{DoseLimits, dose};
+//! use uom::unit_conversion::mass::{Gram, Mass, Milligram, Pound};
+//!
+//! // Paracetamol at 15 mg/kg for a 44 lb child, capped at 1 g
+//! let per_kg = Mass::from_unit::<Milligram>(15.0);
+//! let amount = dose(per_kg, Mass::from_unit::<Pound>(44.0)).unwrap();
+//! assert!((amount.to_unit::<Milligram>() - 299.37).abs() < 0.01);
+//!
+//! let limits = DoseLimits::up_to(Mass::from_unit::<Gram>(1.0));
+//! assert!(limits.check(amount).is_ok());
+//! ```
+
+use crate::unit_conversion::mass::{Kilogram, Mass};
+use crate::unit_conversion::mass_rate::MassRate;
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, check_positive};
+
+/// Returns the dose for a patient of mass `patient` at `per_kilogram` of drug for each
+/// kilogram of body mass.
+///
+/// Returns [`UnitError::OutOfRange`], in kilograms, if the patient mass is not positive
+/// and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::dosing::dose;
+/// use uom::unit_conversion::mass::{Kilogram, Mass, Milligram};
+///
+/// let amount = dose(
+///     Mass::from_unit::<Milligram>(0.1),
+///     Mass::from_unit::<Kilogram>(70.0),
+/// )
+/// .unwrap();
+/// assert!((amount.to_unit::<Milligram>() - 7.0).abs() < 1e-12);
+/// ```
+pub fn dose(per_kilogram: Mass, patient: Mass) -> Result<Mass, UnitError> {
+    Ok(Mass::from_base(
+        per_kilogram.in_base() * kilograms(patient)?,
+    ))
+}
+
+/// Returns the infusion rate for a patient of mass `patient` at `per_kilogram` for
+/// each kilogram of body mass.
+///
+/// Returns [`UnitError::OutOfRange`], in kilograms, if the patient mass is not positive
+/// and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::dosing::infusion_rate;
+/// use uom::unit_conversion::mass::{Kilogram, Mass};
+/// use uom::unit_conversion::mass_rate::{MassRate, MicrogramPerMinute, MilligramPerHour};
+///
+/// // Noradrenaline at 0.05 µg/kg/min for an 80 kg patient
+/// let rate = infusion_rate(
+///     MassRate::from_unit::<MicrogramPerMinute>(0.05),
+///     Mass::from_unit::<Kilogram>(80.0),
+/// )
+/// .unwrap();
+/// assert!((rate.to_unit::<MicrogramPerMinute>() - 4.0).abs() < 1e-12);
+/// assert!((rate.to_unit::<MilligramPerHour>() - 0.24).abs() < 1e-12);
+/// ```
+pub fn infusion_rate(per_kilogram: MassRate, patient: Mass) -> Result<MassRate, UnitError> {
+    Ok(MassRate::from_base(
+        per_kilogram.in_base() * kilograms(patient)?,
+    ))
+}
+
+fn kilograms(patient: Mass) -> Result<f64, UnitError> {
+    check_positive(patient.to_unit::<Kilogram>())
+}
+
+/// The permitted range for a computed dose, rate or patient mass.
+///
+/// Both bounds are inclusive. Errors report the value and bounds in the dimension's
+/// base unit, kilograms for a mass and kilograms per second for a rate.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::dosing::{DoseLimits, infusion_rate};
+/// use uom::unit_conversion::mass::{Kilogram, Mass};
+/// use uom::unit_conversion::mass_rate::{MassRate, MicrogramPerMinute};
+///
+/// let limits = DoseLimits::new(
+///     MassRate::from_unit::<MicrogramPerMinute>(1.0),
+///     MassRate::from_unit::<MicrogramPerMinute>(30.0),
+/// )
+/// .unwrap();
+/// let rate = infusion_rate(
+///     MassRate::from_unit::<MicrogramPerMinute>(0.5),
+///     Mass::from_unit::<Kilogram>(90.0),
+/// )
+/// .unwrap();
+/// assert!(limits.check(rate).is_err());
+/// assert_eq!(limits.clamp(rate).to_unit::<MicrogramPerMinute>(), 30.0);
+/// ```
+pub struct DoseLimits<U: UnitConversion> {
+    min: Quantity<U>,
+    max: Quantity<U>,
+}
+
+impl<U: UnitConversion> Clone for DoseLimits<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> Copy for DoseLimits<U> {}
+
+impl<U: UnitConversion> std::fmt::Debug for DoseLimits<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoseLimits")
+            .field("min", &self.min.in_base())
+            .field("max", &self.max.in_base())
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> DoseLimits<U> {
+    /// Creates limits from `min` to `max`, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `min` is greater than `max` or either bound
+    /// is NaN.
+    pub fn new(min: Quantity<U>, max: Quantity<U>) -> Result<Self, UnitError> {
+        if min.in_base() <= max.in_base() {
+            Ok(Self { min, max })
+        } else {
+            Err(UnitError::OutOfRange {
+                value: min.in_base(),
+                min: f64::NEG_INFINITY,
+                max: max.in_base(),
+            })
+        }
+    }
+
+    /// Creates limits from zero to `max`, inclusive.
+    pub fn up_to(max: Quantity<U>) -> Self {
+        Self {
+            min: Quantity::from_base(0.0),
+            max,
+        }
+    }
+
+    /// Returns the lower bound.
+    pub fn min(&self) -> Quantity<U> {
+        self.min
+    }
+
+    /// Returns the upper bound.
+    pub fn max(&self) -> Quantity<U> {
+        self.max
+    }
+
+    /// Returns `value` if it lies within the limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`], in base units, if `value` is outside the
+    /// limits or NaN.
+    pub fn check(&self, value: Quantity<U>) -> Result<Quantity<U>, UnitError> {
+        let (min, max) = (self.min.in_base(), self.max.in_base());
+        if (min..=max).contains(&value.in_base()) {
+            Ok(value)
+        } else {
+            Err(UnitError::OutOfRange {
+                value: value.in_base(),
+                min,
+                max,
+            })
+        }
+    }
+
+    /// Returns `value` limited to the range, so a dose above the maximum is capped at
+    /// the maximum.
+    pub fn clamp(&self, value: Quantity<U>) -> Quantity<U> {
+        Quantity::from_base(
+            value
+                .in_base()
+                .clamp(self.min.in_base(), self.max.in_base()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::mass::{Microgram, Milligram, Pound};
+    use crate::unit_conversion::mass_rate::{MicrogramPerMinute, MilligramPerHour};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn doses_scale_with_patient_mass() {
+        let per_kg = Mass::from_unit::<Milligram>(10.0);
+        let adult = dose(per_kg, Mass::from_unit::<Kilogram>(75.0)).unwrap();
+        assert!(approx(adult.to_unit::<Milligram>(), 750.0, 1e-9));
+
+        let pounds = dose(per_kg, Mass::from_unit::<Pound>(165.347)).unwrap();
+        assert!(approx(pounds.to_unit::<Milligram>(), 750.0, 0.01));
+
+        let micro = dose(
+            Mass::from_unit::<Microgram>(5.0),
+            Mass::from_unit::<Kilogram>(4.0),
+        )
+        .unwrap();
+        assert!(approx(micro.to_unit::<Microgram>(), 20.0, 1e-9));
+    }
+
+    #[test]
+    fn infusion_rates() {
+        let rate = infusion_rate(
+            MassRate::from_unit::<MicrogramPerMinute>(5.0),
+            Mass::from_unit::<Kilogram>(60.0),
+        )
+        .unwrap();
+        assert!(approx(rate.to_unit::<MicrogramPerMinute>(), 300.0, 1e-9));
+        assert!(approx(rate.to_unit::<MilligramPerHour>(), 18.0, 1e-9));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict_physics", debug_assertions),
+        ignore = "builds physically impossible quantities"
+    )]
+    fn rejects_invalid_patients() {
+        let per_kg = Mass::from_unit::<Milligram>(1.0);
+        assert!(dose(per_kg, Mass::from_unit::<Kilogram>(0.0)).is_err());
+        assert!(dose(per_kg, Mass::from_unit::<Kilogram>(f64::NAN)).is_err());
+        let rate = MassRate::from_unit::<MicrogramPerMinute>(1.0);
+        assert!(infusion_rate(rate, Mass::from_unit::<Kilogram>(-1.0)).is_err());
+    }
+
+    #[test]
+    fn limits_check_and_clamp() {
+        let limits = DoseLimits::new(
+            Mass::from_unit::<Milligram>(100.0),
+            Mass::from_unit::<Milligram>(500.0),
+        )
+        .unwrap();
+        let inside = Mass::from_unit::<Milligram>(250.0);
+        assert_eq!(limits.check(inside).unwrap(), inside);
+        assert!(limits.check(Mass::from_unit::<Milligram>(501.0)).is_err());
+        assert!(
+            limits
+                .check(Mass::from_unit::<Milligram>(f64::NAN))
+                .is_err()
+        );
+
+        let high = limits.clamp(Mass::from_unit::<Milligram>(900.0));
+        assert!(approx(high.to_unit::<Milligram>(), 500.0, 1e-9));
+        let low = limits.clamp(Mass::from_unit::<Milligram>(10.0));
+        assert!(approx(low.to_unit::<Milligram>(), 100.0, 1e-9));
+
+        assert!(DoseLimits::new(limits.max(), limits.min()).is_err());
+        assert_eq!(
+            DoseLimits::up_to(limits.max()).min().to_unit::<Milligram>(),
+            0.0
+        );
+    }
+}
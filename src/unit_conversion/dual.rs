@@ -0,0 +1,343 @@
+//! # Dual-Unit Display
+//!
+//! Shops, clinics and fitness apps serve metric and customary readers on the same page,
+//! so a height reads "178 cm / 5′10″" and a weight "70 kg / 154 lb". A
+//! [`DualFormatter`] holds the two halves, each a [`Part`] with its own rounding step,
+//! and [`Quantity::format_dual`] applies it to a quantity.
+//!
+//! Each half is rounded on its own, so the two may disagree in the last digit, as they
+//! do on any printed size chart. Lengths can use [`Part::feet_and_inches`], which
+//! rounds the total in inches before splitting it, so 5′11.8″ rounds to 6′0″ rather
+//! than 5′12″.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::dual::{DualFormatter, Part};
+//! use uom::unit_conversion::length::{Centimeter, Length};
+//! use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+//!
+//! let height = Length::from_unit::<Centimeter>(178.0);
+//! let sizes = DualFormatter::new(Part::unit::<Centimeter>(1.0), Part::feet_and_inches(1.0));
+//! assert_eq!(height.format_dual(&sizes).to_string(), "178 cm / 5′10″");
+//!
+//! let fever = Temperature::from_unit::<Celsius>(38.4);
+//! let clinic = DualFormatter::new(Part::unit::<Celsius>(0.1), Part::unit::<Fahrenheit>(0.1))
+//!     .with_separator(" (")
+//!     .with_suffix(")");
+//! assert_eq!(fever.format_dual(&clinic).to_string(), "38.4 °C (101.1 °F)");
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+#[cfg(feature = "length")]
+use crate::unit_conversion::length::{Inch, LengthDimension};
+use crate::unit_conversion::rounding::RoundingMode;
+use crate::unit_conversion::{Quantity, UnitConversion};
+
+/// Inches in a foot.
+#[cfg(feature = "length")]
+const INCHES_PER_FOOT: f64 = 12.0;
+
+/// The most decimal places a rounding step is written with.
+const MAX_DECIMALS: usize = 9;
+
+/// How one half of a dual display writes its value.
+#[derive(Copy, Clone, Debug)]
+enum Style {
+    /// The number followed by the unit symbol.
+    Unit {
+        symbol: &'static str,
+        from_base: fn(f64) -> f64,
+    },
+    /// Whole feet then inches, as in 5′10″.
+    #[cfg(feature = "length")]
+    FeetInches,
+}
+
+/// One half of a dual display: a unit and the step its value is rounded to.
+///
+/// The step also sets the decimal places written, so a step of `0.5` shows one
+/// decimal place and a step of `5.0` none.
+pub struct Part<D> {
+    style: Style,
+    step: f64,
+    mode: RoundingMode,
+    dimension: PhantomData<fn() -> D>,
+}
+
+impl<D> Clone for Part<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Part<D> {}
+
+impl<D> fmt::Debug for Part<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Part")
+            .field("style", &self.style)
+            .field("step", &self.step)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<D> Part<D> {
+    /// Writes the value in unit `V`, rounded to the nearest multiple of `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive and finite.
+    pub fn unit<V>(step: f64) -> Self
+    where
+        V: UnitConversion<Dimension = D>,
+    {
+        Self::new(
+            Style::Unit {
+                symbol: V::SYMBOL,
+                from_base: V::convert_from,
+            },
+            step,
+        )
+    }
+
+    /// Rounds with `mode` instead of to the nearest step, halves away from zero.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::dual::{DualFormatter, Part};
+    /// use uom::unit_conversion::length::{Centimeter, Length, Millimeter};
+    /// use uom::unit_conversion::rounding::RoundingMode;
+    ///
+    /// // Sizes are rounded down so the garment is never smaller than the chart says
+    /// let down = Part::unit::<Centimeter>(1.0).with_rounding(RoundingMode::Floor);
+    /// let chart = DualFormatter::new(down, Part::unit::<Millimeter>(1.0));
+    /// let waist = Length::from_unit::<Millimeter>(819.0);
+    /// assert_eq!(waist.format_dual(&chart).to_string(), "81 cm / 819 mm");
+    /// ```
+    pub fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn new(style: Style, step: f64) -> Self {
+        assert!(
+            step.is_finite() && step > 0.0,
+            "rounding step must be positive and finite, got {step}"
+        );
+        Self {
+            style,
+            step,
+            mode: RoundingMode::HalfAwayFromZero,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Rounds `value` to a multiple of the step, without a negative zero.
+    fn round(&self, value: f64) -> f64 {
+        self.mode.round(value / self.step) * self.step + 0.0
+    }
+
+    /// Returns the decimal places needed to write multiples of the step.
+    fn decimals(&self) -> usize {
+        (0..MAX_DECIMALS)
+            .find(|&decimals| {
+                let scaled = self.step * 10f64.powi(decimals as i32);
+                (scaled - scaled.round()).abs() < 1e-9 * scaled.max(1.0)
+            })
+            .unwrap_or(MAX_DECIMALS)
+    }
+
+    fn write(&self, base: f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = self.decimals();
+        match self.style {
+            Style::Unit { symbol, from_base } => {
+                write!(f, "{:.decimals$} {symbol}", self.round(from_base(base)))
+            }
+            #[cfg(feature = "length")]
+            Style::FeetInches => {
+                let inches = self.round(Inch::convert_from(base));
+                let sign = if inches < 0.0 { "-" } else { "" };
+                let feet = (inches.abs() / INCHES_PER_FOOT).floor();
+                // Clean up the remainder so a step of 0.1 does not print 9.999999″
+                let rest = self.round(inches.abs() - feet * INCHES_PER_FOOT);
+                write!(f, "{sign}{feet}′{rest:.decimals$}″")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "length")]
+impl Part<LengthDimension> {
+    /// Writes a length as whole feet and inches, as in 5′10″, with the inches rounded
+    /// to the nearest multiple of `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::dual::{DualFormatter, Part};
+    /// use uom::unit_conversion::length::{Length, Meter};
+    ///
+    /// let halves = DualFormatter::new(Part::unit::<Meter>(0.01), Part::feet_and_inches(0.5));
+    /// let q = Length::from_unit::<Meter>(1.83);
+    /// assert_eq!(q.format_dual(&halves).to_string(), "1.83 m / 6′0.0″");
+    /// ```
+    pub fn feet_and_inches(step: f64) -> Self {
+        Self::new(Style::FeetInches, step)
+    }
+}
+
+/// Writes a quantity in two units side by side, as in "178 cm / 5′10″".
+pub struct DualFormatter<D> {
+    primary: Part<D>,
+    secondary: Part<D>,
+    separator: &'static str,
+    suffix: &'static str,
+}
+
+impl<D> Clone for DualFormatter<D> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<D> fmt::Debug for DualFormatter<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DualFormatter")
+            .field("primary", &self.primary)
+            .field("secondary", &self.secondary)
+            .field("separator", &self.separator)
+            .field("suffix", &self.suffix)
+            .finish()
+    }
+}
+
+impl<D> DualFormatter<D> {
+    /// Creates a formatter writing `primary`, then `" / "`, then `secondary`.
+    pub fn new(primary: Part<D>, secondary: Part<D>) -> Self {
+        Self {
+            primary,
+            secondary,
+            separator: " / ",
+            suffix: "",
+        }
+    }
+
+    /// Writes `separator` between the two halves instead of `" / "`.
+    pub fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Writes `suffix` after the second half, to close a bracket opened by the
+    /// separator.
+    pub fn with_suffix(mut self, suffix: &'static str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+}
+
+/// A quantity with a [`DualFormatter`], as returned by [`Quantity::format_dual`].
+pub struct Dual<'a, D> {
+    base: f64,
+    formatter: &'a DualFormatter<D>,
+}
+
+impl<D> fmt::Debug for Dual<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dual")
+            .field("base", &self.base)
+            .field("formatter", self.formatter)
+            .finish()
+    }
+}
+
+/// Writes both halves with their own rounding; a precision in the format string is
+/// ignored.
+impl<D> fmt::Display for Dual<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.formatter.primary.write(self.base, f)?;
+        f.write_str(self.formatter.separator)?;
+        self.formatter.secondary.write(self.base, f)?;
+        f.write_str(self.formatter.suffix)
+    }
+}
+
+impl<U: UnitConversion> Quantity<U> {
+    /// Returns a displayable form of this quantity in the two units of `formatter`.
+    pub fn format_dual<'a>(
+        &self,
+        formatter: &'a DualFormatter<U::Dimension>,
+    ) -> Dual<'a, U::Dimension> {
+        Dual {
+            base: self.in_base(),
+            formatter,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "mass"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Centimeter, Length, Meter, Millimeter};
+    use crate::unit_conversion::mass::{Kilogram, Mass, Pound};
+
+    fn height(formatter: &DualFormatter<LengthDimension>, centimeters: f64) -> String {
+        Length::from_unit::<Centimeter>(centimeters)
+            .format_dual(formatter)
+            .to_string()
+    }
+
+    #[test]
+    fn body_measurements() {
+        let sizes = DualFormatter::new(Part::unit::<Centimeter>(1.0), Part::feet_and_inches(1.0));
+        assert_eq!(height(&sizes, 152.4), "152 cm / 5′0″");
+        assert_eq!(height(&sizes, 182.6), "183 cm / 6′0″");
+        assert_eq!(height(&sizes, 30.0), "30 cm / 1′0″");
+
+        let weight = DualFormatter::new(Part::unit::<Kilogram>(0.5), Part::unit::<Pound>(1.0));
+        let w = Mass::from_unit::<Kilogram>(70.2);
+        assert_eq!(w.format_dual(&weight).to_string(), "70.0 kg / 155 lb");
+    }
+
+    #[test]
+    fn steps_set_rounding_and_decimals() {
+        let fine = Part::<LengthDimension>::unit::<Millimeter>(0.25);
+        assert_eq!(fine.decimals(), 2);
+        assert_eq!(fine.round(3.1), 3.0);
+        assert_eq!(fine.round(3.13), 3.25);
+        assert_eq!(Part::<LengthDimension>::unit::<Meter>(5.0).decimals(), 0);
+
+        let tenths = DualFormatter::new(Part::unit::<Centimeter>(1.0), Part::feet_and_inches(0.1));
+        assert_eq!(height(&tenths, 177.8), "178 cm / 5′10.0″");
+        assert_eq!(height(&tenths, 179.0), "179 cm / 5′10.5″");
+    }
+
+    #[test]
+    fn negative_and_near_zero_values() {
+        let sizes = DualFormatter::new(Part::unit::<Centimeter>(1.0), Part::feet_and_inches(1.0))
+            .with_separator(", ");
+        assert_eq!(height(&sizes, -0.2), "0 cm, 0′0″");
+        assert_eq!(height(&sizes, -40.0), "-40 cm, -1′4″");
+    }
+
+    #[test]
+    #[should_panic(expected = "rounding step")]
+    fn rejects_zero_steps() {
+        let _ = Part::<LengthDimension>::unit::<Meter>(0.0);
+    }
+}
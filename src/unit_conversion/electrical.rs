@@ -0,0 +1,185 @@
+//! # Ohm's Law Helpers
+//!
+//! Ohm's law, `V = I × R`, and the power law, `P = V × I`, are simple enough to write
+//! inline, which is how a current ends up divided by a voltage instead of the other way
+//! round. The functions here take the typed [`Voltage`], [`Current`] and [`Resistance`]
+//! quantities, so transposed operands fail to compile:
+//!
+//! - [`voltage`] gives the voltage across a resistance carrying a current.
+//! - [`current`] gives the current a voltage drives through a resistance.
+//! - [`resistance`] gives the resistance that draws a current at a voltage.
+//! - [`power`] gives the power delivered at a voltage and current.
+//!
+//! Currents and voltages are signed, so a negative current gives a negative voltage and
+//! power; the resistance is never negative.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::current::{Current, Milliampere};
+//! use uom::unit_conversion::electrical::{power, resistance};
+//! use uom::unit_conversion::power::Watt;
+//! use uom::unit_conversion::resistance::Ohm;
+//! use uom::unit_conversion::voltage::{Volt, Voltage};
+//!
+//! // An LED dropping 3.3 V - 2.0 V across its series resistor at 20 mA
+//! let drop = Voltage::from_unit::<Volt>(1.3);
+//! let led = Current::from_unit::<Milliampere>(20.0);
+//! assert!((resistance(drop, led).unwrap().to_unit::<Ohm>() - 65.0).abs() < 1e-9);
+//! assert!((power(drop, led).to_unit::<Watt>() - 0.026).abs() < 1e-12);
+//! ```
+
+use crate::unit_conversion::current::Current;
+use crate::unit_conversion::power::Power;
+use crate::unit_conversion::resistance::Resistance;
+use crate::unit_conversion::voltage::Voltage;
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// Returns the voltage across `resistance` when it carries `current`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Current, Milliampere};
+/// use uom::unit_conversion::electrical::voltage;
+/// use uom::unit_conversion::resistance::{Kiloohm, Resistance};
+/// use uom::unit_conversion::voltage::Volt;
+///
+/// let drop = voltage(
+///     Current::from_unit::<Milliampere>(2.0),
+///     Resistance::from_unit::<Kiloohm>(4.7),
+/// );
+/// assert!((drop.to_unit::<Volt>() - 9.4).abs() < 1e-12);
+/// ```
+pub fn voltage(current: Current, resistance: Resistance) -> Voltage {
+    Voltage::from_base(current.in_base() * resistance.in_base())
+}
+
+/// Returns the current that `voltage` drives through `resistance`.
+///
+/// Returns [`UnitError::OutOfRange`] if the resistance is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::Milliampere;
+/// use uom::unit_conversion::electrical::current;
+/// use uom::unit_conversion::resistance::{Ohm, Resistance};
+/// use uom::unit_conversion::voltage::{Volt, Voltage};
+///
+/// let draw = current(
+///     Voltage::from_unit::<Volt>(5.0),
+///     Resistance::from_unit::<Ohm>(220.0),
+/// )
+/// .unwrap();
+/// assert!((draw.to_unit::<Milliampere>() - 22.727).abs() < 1e-3);
+/// ```
+pub fn current(voltage: Voltage, resistance: Resistance) -> Result<Current, UnitError> {
+    let ohms = check_positive(resistance.in_base())?;
+    Ok(Current::from_base(voltage.in_base() / ohms))
+}
+
+/// Returns the resistance that draws `current` at `voltage`.
+///
+/// Returns [`UnitError::OutOfRange`] if the result is not a finite, non-negative
+/// resistance: the current is zero, or it flows against the voltage.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current};
+/// use uom::unit_conversion::electrical::resistance;
+/// use uom::unit_conversion::resistance::Ohm;
+/// use uom::unit_conversion::voltage::{Volt, Voltage};
+///
+/// let heater = resistance(
+///     Voltage::from_unit::<Volt>(230.0),
+///     Current::from_unit::<Ampere>(10.0),
+/// )
+/// .unwrap();
+/// assert_eq!(heater.to_unit::<Ohm>(), 23.0);
+/// ```
+pub fn resistance(voltage: Voltage, current: Current) -> Result<Resistance, UnitError> {
+    let ohms = voltage.in_base() / current.in_base();
+    if ohms.is_finite() && ohms >= 0.0 {
+        // abs() turns the -0.0 of a zero voltage over a negative current into 0.0
+        Ok(Resistance::from_base(ohms.abs()))
+    } else {
+        Err(UnitError::OutOfRange {
+            value: ohms,
+            min: 0.0,
+            max: f64::INFINITY,
+        })
+    }
+}
+
+/// Returns the power delivered at `voltage` and `current`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::current::{Ampere, Current};
+/// use uom::unit_conversion::electrical::power;
+/// use uom::unit_conversion::power::Watt;
+/// use uom::unit_conversion::voltage::{Volt, Voltage};
+///
+/// let charger = power(
+///     Voltage::from_unit::<Volt>(20.0),
+///     Current::from_unit::<Ampere>(3.25),
+/// );
+/// assert_eq!(charger.to_unit::<Watt>(), 65.0);
+/// ```
+pub fn power(voltage: Voltage, current: Current) -> Power {
+    Power::from_base(voltage.in_base() * current.in_base())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::current::{Ampere, Milliampere};
+    use crate::unit_conversion::power::Watt;
+    use crate::unit_conversion::resistance::{Kiloohm, Ohm};
+    use crate::unit_conversion::voltage::{Millivolt, Volt};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn ohms_law_round_trips() {
+        let i = Current::from_unit::<Milliampere>(3.0);
+        let r = Resistance::from_unit::<Kiloohm>(1.5);
+        let v = voltage(i, r);
+        assert!(approx(v.to_unit::<Volt>(), 4.5, 1e-12));
+        assert!(approx(
+            current(v, r).unwrap().to_unit::<Milliampere>(),
+            3.0,
+            1e-12
+        ));
+        assert!(approx(
+            resistance(v, i).unwrap().to_unit::<Kiloohm>(),
+            1.5,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn signs_follow_the_current() {
+        let i = Current::from_unit::<Ampere>(-2.0);
+        let v = voltage(i, Resistance::from_unit::<Ohm>(6.0));
+        assert_eq!(v.to_unit::<Volt>(), -12.0);
+        assert_eq!(power(v, i).to_unit::<Watt>(), 24.0);
+        assert_eq!(resistance(v, i).unwrap().to_unit::<Ohm>(), 6.0);
+
+        let short = resistance(Voltage::from_unit::<Millivolt>(0.0), i).unwrap();
+        assert_eq!(short.to_unit::<Ohm>(), 0.0);
+    }
+
+    #[test]
+    fn rejects_degenerate_circuits() {
+        let v = Voltage::from_unit::<Volt>(5.0);
+        assert!(current(v, Resistance::from_unit::<Ohm>(0.0)).is_err());
+        assert!(resistance(v, Current::from_unit::<Ampere>(0.0)).is_err());
+        assert!(resistance(v, Current::from_unit::<Ampere>(-1.0)).is_err());
+    }
+}
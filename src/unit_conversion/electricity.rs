@@ -0,0 +1,100 @@
+//! # Electricity Cost Helpers
+//!
+//! Helpers for home-energy and metering calculations that combine the power, time,
+//! energy, and currency types, so the whole chain from an appliance rating to a bill
+//! stays in typed quantities.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::currency::{Currency, Money};
+//! use uom::unit_conversion::electricity::{energy_cost, energy_used};
+//! use uom::unit_conversion::power::{Power, Watt};
+//! use uom::unit_conversion::time::{Hour, Time};
+//!
+//! // A 1500 W space heater running for 4 hours at $0.20/kWh
+//! let energy = energy_used(Power::from_unit::<Watt>(1500.0), Time::from_unit::<Hour>(4.0));
+//! let cost = energy_cost(energy, Money::new(0.20, Currency::USD));
+//! assert!((cost.amount() - 1.20).abs() < 1e-12);
+//! assert_eq!(cost.currency(), Currency::USD);
+//! ```
+
+use crate::unit_conversion::currency::Money;
+use crate::unit_conversion::energy::{Energy, Joule, KilowattHour};
+use crate::unit_conversion::power::{Power, Watt};
+use crate::unit_conversion::time::{Second, Time};
+
+/// Returns the energy consumed by a constant power draw over a duration.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::electricity::energy_used;
+/// use uom::unit_conversion::energy::KilowattHour;
+/// use uom::unit_conversion::power::{Power, Kilowatt};
+/// use uom::unit_conversion::time::{Time, Minute};
+///
+/// let energy = energy_used(Power::from_unit::<Kilowatt>(2.0), Time::from_unit::<Minute>(30.0));
+/// assert!((energy.to_unit::<KilowattHour>() - 1.0).abs() < 1e-12);
+/// ```
+pub fn energy_used(power: Power, duration: Time) -> Energy {
+    Energy::from_unit::<Joule>(power.to_unit::<Watt>() * duration.to_unit::<Second>())
+}
+
+/// Returns the cost of an amount of energy at a price per kilowatt-hour.
+///
+/// The result is denominated in the same currency as `price_per_kwh`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::currency::{Currency, Money};
+/// use uom::unit_conversion::electricity::energy_cost;
+/// use uom::unit_conversion::energy::{Energy, KilowattHour};
+///
+/// let monthly = Energy::from_unit::<KilowattHour>(350.0);
+/// let bill = energy_cost(monthly, Money::new(0.30, Currency::EUR));
+/// assert!((bill.amount() - 105.0).abs() < 1e-9);
+/// ```
+pub fn energy_cost(energy: Energy, price_per_kwh: Money) -> Money {
+    Money::new(
+        energy.to_unit::<KilowattHour>() * price_per_kwh.amount(),
+        price_per_kwh.currency(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::currency::Currency;
+    use crate::unit_conversion::time::Hour;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn one_kilowatt_for_one_hour() {
+        let energy = energy_used(
+            Power::from_unit::<Watt>(1000.0),
+            Time::from_unit::<Hour>(1.0),
+        );
+        assert!(approx(energy.to_unit::<KilowattHour>(), 1.0, 1e-12));
+        assert!(approx(energy.to_unit::<Joule>(), 3.6e6, 1e-6));
+    }
+
+    #[test]
+    fn cost_uses_price_currency() {
+        let energy = Energy::from_unit::<KilowattHour>(10.0);
+        let cost = energy_cost(energy, Money::new(0.25, Currency::GBP));
+        assert!(approx(cost.amount(), 2.5, 1e-12));
+        assert_eq!(cost.currency(), Currency::GBP);
+    }
+
+    #[test]
+    fn zero_usage_costs_nothing() {
+        let energy = energy_used(Power::from_unit::<Watt>(0.0), Time::from_unit::<Hour>(5.0));
+        let cost = energy_cost(energy, Money::new(0.25, Currency::USD));
+        assert_eq!(cost.amount(), 0.0);
+    }
+}
@@ -0,0 +1,420 @@
+//! # CO₂ Emission Intensity Conversions
+//!
+//! Sustainability reports state emissions as an intensity: grams of CO₂ per kilometer
+//! driven for a vehicle, kilograms of CO₂ per kilowatt-hour for an electricity supply.
+//! The two are different dimensions, kept apart by [`DistanceEmissions`] and
+//! [`EnergyEmissions`]; the mass is always the mass of CO₂ (or CO₂-equivalent).
+//! Distance intensities are stored in grams per kilometer and energy intensities in
+//! kilograms per kilowatt-hour.
+//!
+//! An energy intensity becomes a distance intensity through the efficiency of the
+//! vehicle, the energy it uses over a distance: [`per_distance`] and [`per_energy`]
+//! convert in each direction.
+//!
+//! ## Supported Units
+//!
+//! - **Gram per kilometer (g/km)**: Base unit for distance intensity
+//! - **Gram per mile (g/mi)**, **Kilogram per kilometer (kg/km)**
+//! - **Kilogram per kilowatt-hour (kg/kWh)**: Base unit for energy intensity
+//! - **Gram per kilowatt-hour (g/kWh)**, **Pound per megawatt-hour (lb/MWh)**
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::emissions::{
+//!     EnergyEmissions, GramPerKilometer, GramPerKilowattHour, per_distance,
+//! };
+//! use uom::unit_conversion::energy::{Energy, KilowattHour};
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//!
+//! // An electric car using 17 kWh/100 km on a 250 g/kWh grid
+//! let grid = EnergyEmissions::from_unit::<GramPerKilowattHour>(250.0);
+//! let car = per_distance(
+//!     grid,
+//!     Energy::from_unit::<KilowattHour>(17.0),
+//!     Length::from_unit::<Kilometer>(100.0),
+//! )
+//! .unwrap();
+//! assert!((car.to_unit::<GramPerKilometer>() - 42.5).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::energy::{Energy, KilowattHour};
+use crate::unit_conversion::length::{Kilometer, Length};
+use crate::unit_conversion::{
+    Dimension, Quantity, UnitConversion, UnitError, UnitInfo, check_positive,
+};
+
+/// Constants for emission intensity conversions
+const KILOMETERS_PER_MILE: f64 = 1.0 / 1.609344; // 1 g/mi in g/km
+const GRAMS_PER_KILOGRAM: f64 = 1000.0;
+const KILOGRAMS_PER_GRAM: f64 = 1e-3;
+const KG_PER_KWH_PER_LB_PER_MWH: f64 = 0.45359237e-3; // 1 lb/MWh in kg/kWh
+
+/// Marker type for the per-distance emission intensity dimension.
+///
+/// This prevents accidental conversions between a vehicle's emissions per distance and
+/// an electricity supply's emissions per unit of energy.
+pub enum DistanceEmissionsDimension {}
+
+impl Dimension for DistanceEmissionsDimension {
+    const NAME: &'static str = "distance emissions";
+    const ID: u8 = 23;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<GramPerKilometer>(&["gCO2/km", "g CO2/km", "g CO₂/km"]),
+        UnitInfo::of::<GramPerMile>(&["gCO2/mi", "g CO2/mi", "g CO₂/mi", "g/mile"]),
+        UnitInfo::of::<KilogramPerKilometer>(&["kgCO2/km", "kg CO2/km", "kg CO₂/km"]),
+    ];
+}
+
+/// Marker type for the per-energy emission intensity dimension.
+///
+/// This prevents accidental conversions between an electricity supply's emissions per
+/// unit of energy and a vehicle's emissions per distance.
+pub enum EnergyEmissionsDimension {}
+
+impl Dimension for EnergyEmissionsDimension {
+    const NAME: &'static str = "energy emissions";
+    const ID: u8 = 24;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<KilogramPerKilowattHour>(&["kgCO2/kWh", "kg CO2/kWh", "kg CO₂/kWh"]),
+        UnitInfo::of::<GramPerKilowattHour>(&["gCO2/kWh", "g CO2/kWh", "g CO₂/kWh"]),
+        UnitInfo::of::<PoundPerMegawattHour>(&["lbCO2/MWh", "lb CO2/MWh", "lb CO₂/MWh"]),
+    ];
+}
+
+/// A CO₂ emission intensity per distance travelled, stored in grams per kilometer.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{DistanceEmissions, GramPerKilometer, GramPerMile};
+///
+/// let rating = DistanceEmissions::from_unit::<GramPerKilometer>(95.0);
+/// let us_rating = rating.to_unit::<GramPerMile>(); // about 152.9
+/// ```
+pub type DistanceEmissions = Quantity<GramPerKilometer>;
+
+/// A CO₂ emission intensity per unit of energy, stored in kilograms per kilowatt-hour.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{EnergyEmissions, GramPerKilowattHour};
+///
+/// let grid = EnergyEmissions::from_unit::<GramPerKilowattHour>(400.0);
+/// let base = grid.in_base(); // 0.4 kg/kWh
+/// ```
+pub type EnergyEmissions = Quantity<KilogramPerKilowattHour>;
+
+/// Grams of CO₂ per kilometer, the unit of EU and UK vehicle ratings.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{DistanceEmissions, GramPerKilometer};
+///
+/// let car = DistanceEmissions::from_unit::<GramPerKilometer>(120.0);
+/// assert_eq!(car.to_unit::<GramPerKilometer>(), 120.0);
+/// ```
+pub struct GramPerKilometer;
+
+impl UnitConversion for GramPerKilometer {
+    type Dimension = DistanceEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // GramPerKilometer is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // GramPerKilometer is the base unit
+    }
+
+    const SYMBOL: &'static str = "g/km";
+    const NAME: &'static str = "gram per kilometer";
+    const PLURAL: &'static str = "grams per kilometer";
+}
+
+/// Grams of CO₂ per mile, the unit of US EPA vehicle ratings.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{DistanceEmissions, GramPerKilometer, GramPerMile};
+///
+/// let car = DistanceEmissions::from_unit::<GramPerMile>(320.0);
+/// assert!((car.to_unit::<GramPerKilometer>() - 198.84).abs() < 0.01);
+/// ```
+pub struct GramPerMile;
+
+impl UnitConversion for GramPerMile {
+    type Dimension = DistanceEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOMETERS_PER_MILE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOMETERS_PER_MILE
+    }
+
+    const SYMBOL: &'static str = "g/mi";
+    const NAME: &'static str = "gram per mile";
+    const PLURAL: &'static str = "grams per mile";
+}
+
+/// Kilograms of CO₂ per kilometer, for heavy vehicles and freight.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{DistanceEmissions, GramPerKilometer, KilogramPerKilometer};
+///
+/// let truck = DistanceEmissions::from_unit::<KilogramPerKilometer>(0.9);
+/// assert_eq!(truck.to_unit::<GramPerKilometer>(), 900.0);
+/// ```
+pub struct KilogramPerKilometer;
+
+impl UnitConversion for KilogramPerKilometer {
+    type Dimension = DistanceEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * GRAMS_PER_KILOGRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / GRAMS_PER_KILOGRAM
+    }
+
+    const SYMBOL: &'static str = "kg/km";
+    const NAME: &'static str = "kilogram per kilometer";
+    const PLURAL: &'static str = "kilograms per kilometer";
+}
+
+/// Kilograms of CO₂ per kilowatt-hour.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{EnergyEmissions, KilogramPerKilowattHour};
+///
+/// let coal = EnergyEmissions::from_unit::<KilogramPerKilowattHour>(0.95);
+/// assert_eq!(coal.to_unit::<KilogramPerKilowattHour>(), 0.95);
+/// ```
+pub struct KilogramPerKilowattHour;
+
+impl UnitConversion for KilogramPerKilowattHour {
+    type Dimension = EnergyEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // KilogramPerKilowattHour is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // KilogramPerKilowattHour is the base unit
+    }
+
+    const SYMBOL: &'static str = "kg/kWh";
+    const NAME: &'static str = "kilogram per kilowatt-hour";
+    const PLURAL: &'static str = "kilograms per kilowatt-hour";
+}
+
+/// Grams of CO₂ per kilowatt-hour, the usual unit of grid carbon intensity.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{EnergyEmissions, GramPerKilowattHour, KilogramPerKilowattHour};
+///
+/// let grid = EnergyEmissions::from_unit::<GramPerKilowattHour>(233.0);
+/// assert!((grid.to_unit::<KilogramPerKilowattHour>() - 0.233).abs() < 1e-12);
+/// ```
+pub struct GramPerKilowattHour;
+
+impl UnitConversion for GramPerKilowattHour {
+    type Dimension = EnergyEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_GRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_GRAM
+    }
+
+    const SYMBOL: &'static str = "g/kWh";
+    const NAME: &'static str = "gram per kilowatt-hour";
+    const PLURAL: &'static str = "grams per kilowatt-hour";
+}
+
+/// Pounds of CO₂ per megawatt-hour, the unit of US eGRID emission factors.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{EnergyEmissions, GramPerKilowattHour, PoundPerMegawattHour};
+///
+/// let subregion = EnergyEmissions::from_unit::<PoundPerMegawattHour>(1000.0);
+/// assert!((subregion.to_unit::<GramPerKilowattHour>() - 453.59237).abs() < 1e-9);
+/// ```
+pub struct PoundPerMegawattHour;
+
+impl UnitConversion for PoundPerMegawattHour {
+    type Dimension = EnergyEmissionsDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_KWH_PER_LB_PER_MWH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_KWH_PER_LB_PER_MWH
+    }
+
+    const SYMBOL: &'static str = "lb/MWh";
+    const NAME: &'static str = "pound per megawatt-hour";
+    const PLURAL: &'static str = "pounds per megawatt-hour";
+}
+
+/// Returns the distance intensity of a vehicle that uses `energy` over `distance` from a
+/// supply of intensity `intensity`.
+///
+/// Returns [`UnitError::OutOfRange`] if the distance is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{
+///     EnergyEmissions, GramPerMile, KilogramPerKilowattHour, per_distance,
+/// };
+/// use uom::unit_conversion::energy::{Energy, KilowattHour};
+/// use uom::unit_conversion::length::{Length, Mile};
+///
+/// // 300 Wh/mi on a 0.4 kg/kWh grid
+/// let car = per_distance(
+///     EnergyEmissions::from_unit::<KilogramPerKilowattHour>(0.4),
+///     Energy::from_unit::<KilowattHour>(0.3),
+///     Length::from_unit::<Mile>(1.0),
+/// )
+/// .unwrap();
+/// assert!((car.to_unit::<GramPerMile>() - 120.0).abs() < 1e-9);
+/// ```
+pub fn per_distance(
+    intensity: EnergyEmissions,
+    energy: Energy,
+    distance: Length,
+) -> Result<DistanceEmissions, UnitError> {
+    let kilometers = check_positive(distance.to_unit::<Kilometer>())?;
+    let kilograms =
+        intensity.to_unit::<KilogramPerKilowattHour>() * energy.to_unit::<KilowattHour>();
+    Ok(DistanceEmissions::from_unit::<KilogramPerKilometer>(
+        kilograms / kilometers,
+    ))
+}
+
+/// Returns the energy intensity implied by a vehicle of intensity `intensity` that uses
+/// `energy` over `distance`.
+///
+/// Returns [`UnitError::OutOfRange`] if the energy is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::emissions::{
+///     DistanceEmissions, GramPerKilometer, GramPerKilowattHour, per_energy,
+/// };
+/// use uom::unit_conversion::energy::{Energy, KilowattHour};
+/// use uom::unit_conversion::length::{Kilometer, Length};
+///
+/// // A car rated 120 g/km that burns fuel worth 50 kWh per 100 km
+/// let fuel = per_energy(
+///     DistanceEmissions::from_unit::<GramPerKilometer>(120.0),
+///     Energy::from_unit::<KilowattHour>(50.0),
+///     Length::from_unit::<Kilometer>(100.0),
+/// )
+/// .unwrap();
+/// assert!((fuel.to_unit::<GramPerKilowattHour>() - 240.0).abs() < 1e-9);
+/// ```
+pub fn per_energy(
+    intensity: DistanceEmissions,
+    energy: Energy,
+    distance: Length,
+) -> Result<EnergyEmissions, UnitError> {
+    let kilowatt_hours = check_positive(energy.to_unit::<KilowattHour>())?;
+    let kilograms = intensity.to_unit::<KilogramPerKilometer>() * distance.to_unit::<Kilometer>();
+    Ok(EnergyEmissions::from_unit::<KilogramPerKilowattHour>(
+        kilograms / kilowatt_hours,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::energy::Joule;
+    use crate::unit_conversion::length::{Meter, Mile};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn distance_units() {
+        let rating = DistanceEmissions::from_unit::<GramPerMile>(1.609344);
+        assert!(approx(rating.to_unit::<GramPerKilometer>(), 1.0, 1e-12));
+        let truck = DistanceEmissions::from_unit::<GramPerKilometer>(750.0);
+        assert!(approx(truck.to_unit::<KilogramPerKilometer>(), 0.75, 1e-12));
+    }
+
+    #[test]
+    fn energy_units() {
+        let grid = EnergyEmissions::from_unit::<PoundPerMegawattHour>(2204.62262);
+        assert!(approx(grid.to_unit::<KilogramPerKilowattHour>(), 1.0, 1e-8));
+        assert!(approx(grid.to_unit::<GramPerKilowattHour>(), 1000.0, 1e-5));
+    }
+
+    #[test]
+    fn intensities_round_trip() {
+        let grid = EnergyEmissions::from_unit::<GramPerKilowattHour>(300.0);
+        let energy = Energy::from_unit::<Joule>(7.2e7); // 20 kWh
+        let distance = Length::from_unit::<Mile>(62.137119);
+        let car = per_distance(grid, energy, distance).unwrap();
+        assert!(approx(car.to_unit::<GramPerKilometer>(), 60.0, 1e-6));
+        let back = per_energy(car, energy, distance).unwrap();
+        assert!(approx(back.to_unit::<GramPerKilowattHour>(), 300.0, 1e-9));
+    }
+
+    #[test]
+    fn rejects_zero_divisors() {
+        let grid = EnergyEmissions::from_unit::<GramPerKilowattHour>(300.0);
+        let energy = Energy::from_unit::<KilowattHour>(1.0);
+        let none = Length::from_unit::<Meter>(0.0);
+        assert!(per_distance(grid, energy, none).is_err());
+
+        let car = DistanceEmissions::from_unit::<GramPerKilometer>(100.0);
+        let idle = Energy::from_unit::<KilowattHour>(0.0);
+        assert!(per_energy(car, idle, Length::from_unit::<Meter>(1.0)).is_err());
+    }
+
+    #[test]
+    fn emissions_symbols() {
+        assert_eq!(GramPerKilometer::SYMBOL, "g/km");
+        assert_eq!(GramPerMile::SYMBOL, "g/mi");
+        assert_eq!(KilogramPerKilowattHour::SYMBOL, "kg/kWh");
+        assert_eq!(
+            EnergyEmissionsDimension::find_unit("gCO2/kWh")
+                .unwrap()
+                .symbol,
+            "g/kWh"
+        );
+    }
+}
@@ -0,0 +1,546 @@
+//! # Energy Unit Conversions
+//!
+//! This module provides type-safe energy conversions between joules, kilojoules,
+//! watt-hours and kilowatt-hours, the calories used in chemistry and nutrition, the British thermal
+//! units used in heating and fuel billing, and TNT equivalents for explosive yields.
+//! All energies are internally stored in joules (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Joule (J)**: The base unit, SI unit of energy
+//! - **Kilojoule (kJ)**: 1000 joules
+//! - **Watt-hour (Wh)**: Exactly 3600 joules, the unit of battery energy ratings
+//! - **Kilowatt-hour (kWh)**: Exactly 3.6 megajoules, the unit of electricity billing
+//! - **British thermal unit (BTU)**: International Table BTU, exactly 1055.05585262 joules
+//! - **Therm (thm)**: 100,000 BTU, the unit of natural-gas billing
+//! - **Calorie (cal)**: The small (thermochemical) calorie, exactly 4.184 joules
+//! - **Kilocalorie (kcal)**: The large or food Calorie, exactly 4184 joules
+//! - **Gram of TNT (g TNT)**: Conventionally 1000 calories, exactly 4184 joules
+//! - **Kilogram of TNT (kg TNT)**: Exactly 4.184 megajoules
+//! - **Ton of TNT (t TNT)**: Exactly 4.184 gigajoules
+//!
+//! Nutrition labels write the kilocalorie as "Calorie" with a capital C, so the aliases
+//! are case-sensitive: `"Cal"` and `"Calorie"` parse as [`Kilocalorie`], while `"cal"` and
+//! `"calorie"` parse as [`Calorie`]. Food energy is labelled in kilojoules in much of the
+//! world; [`Kilojoule`] covers that.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::energy::{Energy, Joule, KilowattHour, Btu};
+//!
+//! let usage = Energy::from_unit::<KilowattHour>(1.0);
+//! assert_eq!(usage.to_unit::<Joule>(), 3.6e6);
+//! assert!((usage.to_unit::<Btu>() - 3412.14163).abs() < 1e-5);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for energy conversions
+const JOULES_PER_KILOJOULE: f64 = 1000.0;
+const JOULES_PER_WATT_HOUR: f64 = 3600.0; // Exact definition
+const JOULES_PER_KILOWATT_HOUR: f64 = 3.6e6; // Exact definition
+const JOULES_PER_BTU: f64 = 1055.05585262; // International Table BTU, exact
+const JOULES_PER_THERM: f64 = 100_000.0 * JOULES_PER_BTU;
+const JOULES_PER_CALORIE: f64 = 4.184; // Thermochemical calorie, exact
+const JOULES_PER_KILOCALORIE: f64 = 1000.0 * JOULES_PER_CALORIE;
+// By convention a gram of TNT releases 4.184 kJ, whatever the real explosive yields
+const JOULES_PER_GRAM_OF_TNT: f64 = 4184.0;
+const JOULES_PER_KILOGRAM_OF_TNT: f64 = 1e3 * JOULES_PER_GRAM_OF_TNT;
+const JOULES_PER_TON_OF_TNT: f64 = 1e6 * JOULES_PER_GRAM_OF_TNT;
+
+/// Marker type for the energy dimension.
+///
+/// This prevents accidental conversions between energy and other dimensions
+/// like power or temperature.
+pub enum EnergyDimension {}
+
+impl Dimension for EnergyDimension {
+    const NAME: &'static str = "energy";
+    const ID: u8 = 7;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Joule>(&["joule", "joules"]),
+        UnitInfo::of::<Kilojoule>(&["kilojoule", "kilojoules"]),
+        UnitInfo::of::<WattHour>(&["watt hour", "watt hours", "watt-hour", "watt-hours"]),
+        UnitInfo::of::<KilowattHour>(&[
+            "kilowatt hour",
+            "kilowatt hours",
+            "kilowatt-hour",
+            "kilowatt-hours",
+        ]),
+        UnitInfo::of::<Btu>(&["btus", "british thermal unit", "british thermal units"]),
+        UnitInfo::of::<Therm>(&["therm", "therms"]),
+        UnitInfo::of::<Calorie>(&[
+            "calorie",
+            "calories",
+            "small calorie",
+            "small calories",
+            "gram calorie",
+            "gram calories",
+        ]),
+        UnitInfo::of::<Kilocalorie>(&[
+            "Cal",
+            "Calorie",
+            "Calories",
+            "kilocalorie",
+            "kilocalories",
+            "large calorie",
+            "large calories",
+            "food calorie",
+            "food calories",
+        ]),
+        UnitInfo::of::<GramOfTnt>(&["gTNT", "gram of TNT", "grams of TNT"]),
+        UnitInfo::of::<KilogramOfTnt>(&["kgTNT", "kilogram of TNT", "kilograms of TNT"]),
+        UnitInfo::of::<TonOfTnt>(&["tTNT", "ton of TNT", "tons of TNT", "tonne of TNT"]),
+    ];
+}
+
+/// An energy quantity that stores values in joules internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, KilowattHour, Kilojoule};
+///
+/// let battery = Energy::from_unit::<KilowattHour>(13.5);
+/// let kilojoules = battery.to_unit::<Kilojoule>(); // 48600.0
+/// ```
+pub type Energy = Quantity<Joule>;
+
+/// Joule energy unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Joule};
+///
+/// let work = Energy::from_unit::<Joule>(500.0);
+/// assert_eq!(work.to_unit::<Joule>(), 500.0);
+/// ```
+pub struct Joule;
+
+impl UnitConversion for Joule {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Joule is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Joule is the base unit
+    }
+
+    const SYMBOL: &'static str = "J";
+    const NAME: &'static str = "joule";
+    const PLURAL: &'static str = "joules";
+}
+
+/// Kilojoule energy unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Kilojoule, Joule};
+///
+/// let snack = Energy::from_unit::<Kilojoule>(2.5);
+/// assert_eq!(snack.to_unit::<Joule>(), 2500.0);
+/// ```
+pub struct Kilojoule;
+
+impl UnitConversion for Kilojoule {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_KILOJOULE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_KILOJOULE
+    }
+
+    const SYMBOL: &'static str = "kJ";
+    const NAME: &'static str = "kilojoule";
+    const PLURAL: &'static str = "kilojoules";
+}
+
+/// Watt-hour energy unit.
+///
+/// The energy delivered by one watt of power over one hour, exactly 3600 J. Battery
+/// packs and power banks are rated in watt-hours.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Joule, WattHour};
+///
+/// let power_bank = Energy::from_unit::<WattHour>(99.0);
+/// assert_eq!(power_bank.to_unit::<Joule>(), 356_400.0);
+/// ```
+pub struct WattHour;
+
+impl UnitConversion for WattHour {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_WATT_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_WATT_HOUR
+    }
+
+    const SYMBOL: &'static str = "Wh";
+    const NAME: &'static str = "watt-hour";
+    const PLURAL: &'static str = "watt-hours";
+}
+
+/// Kilowatt-hour energy unit.
+///
+/// The energy delivered by one kilowatt of power over one hour, exactly 3.6 MJ.
+/// Used for electricity metering and billing.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, KilowattHour, Kilojoule};
+///
+/// let usage = Energy::from_unit::<KilowattHour>(1.0);
+/// assert_eq!(usage.to_unit::<Kilojoule>(), 3600.0);
+/// ```
+pub struct KilowattHour;
+
+impl UnitConversion for KilowattHour {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_KILOWATT_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_KILOWATT_HOUR
+    }
+
+    const SYMBOL: &'static str = "kWh";
+    const NAME: &'static str = "kilowatt-hour";
+    const PLURAL: &'static str = "kilowatt-hours";
+}
+
+/// British thermal unit (International Table).
+///
+/// Approximately the energy needed to heat one pound of water by one degree
+/// Fahrenheit; defined as exactly 1055.05585262 joules.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Btu, Joule};
+///
+/// let heat = Energy::from_unit::<Btu>(1.0);
+/// assert_eq!(heat.to_unit::<Joule>(), 1055.05585262);
+/// ```
+pub struct Btu;
+
+impl UnitConversion for Btu {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_BTU
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_BTU
+    }
+
+    const SYMBOL: &'static str = "BTU";
+    const NAME: &'static str = "British thermal unit";
+    const PLURAL: &'static str = "British thermal units";
+}
+
+/// Therm energy unit.
+///
+/// Exactly 100,000 International Table BTU. Used for natural-gas billing.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Therm, KilowattHour};
+///
+/// let gas = Energy::from_unit::<Therm>(1.0);
+/// assert!((gas.to_unit::<KilowattHour>() - 29.3071).abs() < 1e-4);
+/// ```
+pub struct Therm;
+
+impl UnitConversion for Therm {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_THERM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_THERM
+    }
+
+    const SYMBOL: &'static str = "thm";
+    const NAME: &'static str = "therm";
+    const PLURAL: &'static str = "therms";
+}
+
+/// Small calorie energy unit.
+///
+/// The thermochemical calorie, exactly 4.184 joules: roughly the energy that warms one
+/// gram of water by one kelvin. Not to be confused with the food [`Kilocalorie`].
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Calorie, Energy, Joule};
+///
+/// let heat = Energy::from_unit::<Calorie>(10.0);
+/// assert!((heat.to_unit::<Joule>() - 41.84).abs() < 1e-12);
+/// ```
+pub struct Calorie;
+
+impl UnitConversion for Calorie {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_CALORIE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_CALORIE
+    }
+
+    const SYMBOL: &'static str = "cal";
+    const NAME: &'static str = "calorie";
+    const PLURAL: &'static str = "calories";
+}
+
+/// Kilocalorie (large or food Calorie) energy unit.
+///
+/// Exactly 4184 joules. This is the "Calorie" on nutrition labels.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Kilocalorie, Kilojoule};
+///
+/// let snack = Energy::from_unit::<Kilocalorie>(250.0);
+/// assert!((snack.to_unit::<Kilojoule>() - 1046.0).abs() < 1e-9);
+/// ```
+pub struct Kilocalorie;
+
+impl UnitConversion for Kilocalorie {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_KILOCALORIE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_KILOCALORIE
+    }
+
+    const SYMBOL: &'static str = "kcal";
+    const NAME: &'static str = "kilocalorie";
+    const PLURAL: &'static str = "kilocalories";
+}
+
+/// Gram of TNT energy unit.
+///
+/// Defined by convention as exactly 4.184 kilojoules (1000 thermochemical calories),
+/// close to the measured detonation energy of TNT. Used to express explosive yields.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, GramOfTnt, Kilocalorie};
+///
+/// let charge = Energy::from_unit::<GramOfTnt>(1.0);
+/// assert!((charge.to_unit::<Kilocalorie>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct GramOfTnt;
+
+impl UnitConversion for GramOfTnt {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_GRAM_OF_TNT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_GRAM_OF_TNT
+    }
+
+    const SYMBOL: &'static str = "g TNT";
+    const NAME: &'static str = "gram of TNT";
+    const PLURAL: &'static str = "grams of TNT";
+}
+
+/// Kilogram of TNT energy unit.
+///
+/// 1000 grams of TNT, exactly 4.184 megajoules.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Joule, KilogramOfTnt};
+///
+/// let blast = Energy::from_unit::<KilogramOfTnt>(2.0);
+/// assert!((blast.to_unit::<Joule>() - 8.368e6).abs() < 1e-6);
+/// ```
+pub struct KilogramOfTnt;
+
+impl UnitConversion for KilogramOfTnt {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_KILOGRAM_OF_TNT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_KILOGRAM_OF_TNT
+    }
+
+    const SYMBOL: &'static str = "kg TNT";
+    const NAME: &'static str = "kilogram of TNT";
+    const PLURAL: &'static str = "kilograms of TNT";
+}
+
+/// Ton of TNT energy unit.
+///
+/// A metric ton (10⁶ grams) of TNT, exactly 4.184 gigajoules. Nuclear yields are quoted
+/// in kilotons and megatons of this unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, KilowattHour, TonOfTnt};
+///
+/// let yield_ = Energy::from_unit::<TonOfTnt>(1.0);
+/// assert!((yield_.to_unit::<KilowattHour>() - 1162.222).abs() < 1e-3);
+/// ```
+pub struct TonOfTnt;
+
+impl UnitConversion for TonOfTnt {
+    type Dimension = EnergyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * JOULES_PER_TON_OF_TNT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / JOULES_PER_TON_OF_TNT
+    }
+
+    const SYMBOL: &'static str = "t TNT";
+    const NAME: &'static str = "ton of TNT";
+    const PLURAL: &'static str = "tons of TNT";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn kilowatt_hour_to_joule() {
+        let energy = Energy::from_unit::<KilowattHour>(1.0);
+        assert!(approx(energy.to_unit::<Joule>(), 3.6e6, 1e-6));
+        assert!(approx(energy.to_unit::<Kilojoule>(), 3600.0, 1e-9));
+        assert!(approx(energy.to_unit::<WattHour>(), 1000.0, 1e-9));
+    }
+
+    #[test]
+    fn btu_conversions() {
+        let energy = Energy::from_unit::<Btu>(1.0);
+        assert!(approx(energy.to_unit::<Joule>(), 1055.05585262, 1e-9));
+
+        let energy = Energy::from_unit::<KilowattHour>(1.0);
+        assert!(approx(energy.to_unit::<Btu>(), 3412.141633, 1e-6));
+    }
+
+    #[test]
+    fn therm_conversions() {
+        let energy = Energy::from_unit::<Therm>(1.0);
+        assert!(approx(energy.to_unit::<Btu>(), 100_000.0, 1e-6));
+        assert!(approx(energy.to_unit::<KilowattHour>(), 29.307107, 1e-6));
+    }
+
+    #[test]
+    fn food_energy() {
+        let meal = Energy::from_unit::<Kilocalorie>(1.0);
+        assert!(approx(meal.to_unit::<Calorie>(), 1000.0, 1e-9));
+        assert!(approx(meal.to_unit::<Kilojoule>(), 4.184, 1e-12));
+
+        let label = Energy::from_unit::<Kilojoule>(2000.0);
+        assert!(approx(label.to_unit::<Kilocalorie>(), 478.011472, 1e-6));
+
+        for (alias, symbol) in [
+            ("Cal", "kcal"),
+            ("Calories", "kcal"),
+            ("food calorie", "kcal"),
+            ("cal", "cal"),
+            ("calories", "cal"),
+            ("small calorie", "cal"),
+        ] {
+            assert_eq!(EnergyDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn tnt_equivalents() {
+        let ton = Energy::from_unit::<TonOfTnt>(1.0);
+        assert!(approx(ton.to_unit::<Joule>(), 4.184e9, 1e-3));
+        assert!(approx(ton.to_unit::<KilogramOfTnt>(), 1000.0, 1e-9));
+        assert!(approx(ton.to_unit::<GramOfTnt>(), 1e6, 1e-6));
+
+        // A 15 kt yield
+        let yield_ = Energy::from_unit::<TonOfTnt>(15_000.0);
+        assert!(approx(yield_.to_unit::<Joule>(), 6.276e13, 1e2));
+
+        for (alias, symbol) in [("tons of TNT", "t TNT"), ("kgTNT", "kg TNT")] {
+            assert_eq!(EnergyDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn energy_symbols() {
+        assert_eq!(Joule::SYMBOL, "J");
+        assert_eq!(Kilojoule::SYMBOL, "kJ");
+        assert_eq!(WattHour::SYMBOL, "Wh");
+        assert_eq!(KilowattHour::SYMBOL, "kWh");
+        assert_eq!(Btu::SYMBOL, "BTU");
+        assert_eq!(Therm::SYMBOL, "thm");
+        assert_eq!(Calorie::SYMBOL, "cal");
+        assert_eq!(Kilocalorie::SYMBOL, "kcal");
+        assert_eq!(GramOfTnt::SYMBOL, "g TNT");
+        assert_eq!(TonOfTnt::SYMBOL, "t TNT");
+    }
+}
@@ -0,0 +1,152 @@
+//! # Errors
+//!
+//! This module defines [`UnitError`], the error type shared by every fallible operation
+//! in the crate, so downstream code can match on the cause of a failure regardless of
+//! which API produced it.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::UnitError;
+//! use uom::unit_conversion::temperature::Temperature;
+//!
+//! match Temperature::parse("25 km") {
+//!     Err(UnitError::DimensionMismatch { expected, found }) => {
+//!         assert_eq!(expected, "temperature");
+//!         assert_eq!(found, "length");
+//!     }
+//!     other => panic!("unexpected result: {other:?}"),
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+/// The error type for parsing, lookup, and fallible conversion failures.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnitError {
+    /// The input text is not a well-formed quantity.
+    ParseError {
+        /// The text that could not be parsed.
+        input: String,
+        /// What was wrong with it.
+        reason: &'static str,
+    },
+    /// The unit is not a known unit of the target dimension.
+    UnknownUnit {
+        /// The unit text as it appeared in the input.
+        unit: String,
+        /// The name of the dimension that was being looked up.
+        dimension: &'static str,
+        /// The symbols of the units that would have been accepted.
+        expected: Vec<&'static str>,
+        /// Close matches for a likely misspelling, best first.
+        suggestions: Vec<&'static str>,
+    },
+    /// A unit or quantity of one dimension was used where another was expected.
+    DimensionMismatch {
+        /// The name of the dimension that was expected.
+        expected: &'static str,
+        /// The name of the dimension that was found.
+        found: &'static str,
+    },
+    /// A value lies outside the range accepted by the operation.
+    OutOfRange {
+        /// The offending value.
+        value: f64,
+        /// The smallest accepted value.
+        min: f64,
+        /// The largest accepted value.
+        max: f64,
+    },
+    /// A value or conversion result is infinite or NaN.
+    NonFinite {
+        /// The offending value.
+        value: f64,
+    },
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseError { input, reason } => write!(f, "cannot parse '{input}': {reason}"),
+            Self::UnknownUnit {
+                unit,
+                dimension,
+                suggestions,
+                ..
+            } if !suggestions.is_empty() => write!(
+                f,
+                "unknown {dimension} unit '{unit}' (did you mean '{}'?)",
+                suggestions.join("', '")
+            ),
+            Self::UnknownUnit {
+                unit,
+                dimension,
+                expected,
+                ..
+            } => write!(
+                f,
+                "unknown {dimension} unit '{unit}' (expected one of: {})",
+                expected.join(", ")
+            ),
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "expected a {expected} unit but found a {found} unit")
+            }
+            Self::OutOfRange { value, min, max } => {
+                write!(f, "value {value} is outside the valid range [{min}, {max}]")
+            }
+            Self::NonFinite { value } => write!(f, "value {value} is not finite"),
+        }
+    }
+}
+
+impl Error for UnitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_messages() {
+        let err = UnitError::ParseError {
+            input: "km".to_string(),
+            reason: "expected a number",
+        };
+        assert_eq!(err.to_string(), "cannot parse 'km': expected a number");
+
+        let err = UnitError::DimensionMismatch {
+            expected: "temperature",
+            found: "length",
+        };
+        assert_eq!(
+            err.to_string(),
+            "expected a temperature unit but found a length unit"
+        );
+
+        let err = UnitError::OutOfRange {
+            value: -1.0,
+            min: 0.0,
+            max: f64::INFINITY,
+        };
+        assert_eq!(
+            err.to_string(),
+            "value -1 is outside the valid range [0, inf]"
+        );
+
+        let err = UnitError::NonFinite { value: f64::NAN };
+        assert_eq!(err.to_string(), "value NaN is not finite");
+    }
+
+    #[test]
+    fn is_std_error() {
+        let err: Box<dyn Error> = Box::new(UnitError::NonFinite {
+            value: f64::INFINITY,
+        });
+        assert_eq!(err.to_string(), "value inf is not finite");
+    }
+}
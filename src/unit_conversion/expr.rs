@@ -0,0 +1,566 @@
+//! # Unit-Aware Expressions
+//!
+//! [`evaluate`] computes arithmetic over quantities written with any unit of an enabled
+//! dimension, such as `2 * (3 ft + 10 cm) in m`. It is the engine behind calculator
+//! front-ends, where the units are only known once the user has typed them.
+//!
+//! ## Rules
+//!
+//! - A number followed by a unit is a quantity; a bare number is a plain scalar.
+//! - `+` and `-` combine quantities of the same dimension. The right-hand side is
+//!   converted to the left-hand unit as a difference, so `20 °C + 9 °F` is `25 °C`.
+//! - `*` scales a quantity by a scalar. `/` divides a quantity by a scalar, or two
+//!   quantities of the same dimension to give their ratio.
+//! - A trailing `in UNIT` or `to UNIT` converts the result. Without one, the result
+//!   stays in the unit of its leftmost quantity.
+//!
+//! Units containing `/` such as `m/s` are read as a single word, so put spaces around
+//! a division that follows a unit (`6 ft / 2`, not `6 ft/2`).
+//!
+//! Errors carry the byte range of the offending text, and [`ExprError::annotate`]
+//! draws it under the input.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::expr::evaluate;
+//!
+//! let result = evaluate("2 * (3 ft + 10 cm) in m").unwrap();
+//! assert!((result.value - 2.0288).abs() < 1e-12);
+//! assert_eq!(result.unit.unwrap().1.symbol, "m");
+//!
+//! let err = evaluate("3 ft + 2 °C").unwrap_err();
+//! assert_eq!(err.to_string(), "cannot add a temperature to a length");
+//! assert_eq!(err.annotate("3 ft + 2 °C"), "3 ft + 2 °C\n       ^^^^");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::unit_conversion::UnitInfo;
+use crate::unit_conversion::registry::{self, DimensionInfo};
+
+/// The result of an expression: a number, with the unit it is expressed in if it is a
+/// quantity rather than a plain scalar.
+#[derive(Copy, Clone, Debug)]
+pub struct Value {
+    /// The number, in `unit` if there is one.
+    pub value: f64,
+    /// The dimension and unit of a quantity, or `None` for a plain number.
+    pub unit: Option<(&'static DimensionInfo, &'static UnitInfo)>,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        match self.unit {
+            Some((_, unit)) => write!(f, " {}", unit.symbol),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Why an expression could not be evaluated, and where.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExprError {
+    /// The byte range of the input the error refers to.
+    pub span: Range<usize>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl ExprError {
+    fn new(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Returns `input` with a line of carets underneath the span of the error.
+    pub fn annotate(&self, input: &str) -> String {
+        let start = input[..self.span.start].chars().count();
+        let width = input[self.span.clone()].chars().count().max(1);
+        format!("{input}\n{}{}", " ".repeat(start), "^".repeat(width))
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ExprError {}
+
+/// Evaluates a unit-aware arithmetic expression.
+///
+/// See the [module documentation](self) for the syntax.
+///
+/// # Errors
+///
+/// Returns an [`ExprError`] pointing at malformed syntax, an unknown unit, or an
+/// operation that mixes dimensions.
+pub fn evaluate(input: &str) -> Result<Value, ExprError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+        end: input.len(),
+    };
+    let (mut value, span) = parser.sum(None)?;
+    if parser.is_conversion_keyword() {
+        parser.pos += 1;
+        let (dimension, unit, target) = parser.unit(value.unit.map(|(d, _)| d))?;
+        value = match value.unit {
+            Some((from_dimension, from)) if from_dimension.id == dimension.id => Value {
+                value: (unit.from_base)((from.to_base)(value.value)),
+                unit: Some((dimension, unit)),
+            },
+            Some((from_dimension, _)) => {
+                return Err(ExprError::new(
+                    target,
+                    format!(
+                        "cannot convert a {} to {} ({})",
+                        from_dimension.name, unit.symbol, dimension.name
+                    ),
+                ));
+            }
+            None => {
+                return Err(ExprError::new(
+                    span,
+                    format!("cannot convert a plain number to {}", unit.symbol),
+                ));
+            }
+        };
+    }
+    if let Some(token) = parser.tokens.get(parser.pos) {
+        return Err(ExprError::new(
+            token.span.clone(),
+            format!("unexpected '{}'", &input[token.span.clone()]),
+        ));
+    }
+    if !value.value.is_finite() {
+        return Err(ExprError::new(0..input.len(), "result is not finite"));
+    }
+    Ok(value)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind<'a> {
+    Number(f64),
+    Word(&'a str),
+    Op(char),
+}
+
+#[derive(Clone, Debug)]
+struct Token<'a> {
+    kind: Kind<'a>,
+    span: Range<usize>,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token<'_>>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if matches!(c, '+' | '-' | '*' | '/' | '(' | ')') {
+            chars.next();
+            tokens.push(Token {
+                kind: Kind::Op(c),
+                span: start..start + 1,
+            });
+            continue;
+        }
+        let number = c.is_ascii_digit() || c == '.';
+        let mut end = start;
+        let mut prev = None;
+        while let Some(&(i, c)) = chars.peek() {
+            let next = input[i + c.len_utf8()..].chars().next();
+            let keep = if number {
+                c.is_ascii_digit()
+                    || c == '.'
+                    || (matches!(c, 'e' | 'E')
+                        && next.is_some_and(|n| n.is_ascii_digit() || n == '-' || n == '+'))
+                    || (matches!(c, '-' | '+') && matches!(prev, Some('e' | 'E')))
+            } else {
+                match c {
+                    '+' | '*' | '(' | ')' => false,
+                    '-' => {
+                        prev.is_some_and(char::is_alphabetic)
+                            && next.is_some_and(char::is_alphabetic)
+                    }
+                    '/' => prev.is_some() && next.is_some_and(|n| !n.is_whitespace()),
+                    c => !c.is_whitespace(),
+                }
+            };
+            if !keep {
+                break;
+            }
+            prev = Some(c);
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        let text = &input[start..end];
+        let kind = if number {
+            Kind::Number(
+                text.parse()
+                    .map_err(|_| ExprError::new(start..end, format!("invalid number '{text}'")))?,
+            )
+        } else {
+            Kind::Word(text)
+        };
+        tokens.push(Token {
+            kind,
+            span: start..end,
+        });
+    }
+    Ok(tokens)
+}
+
+/// A recursive-descent evaluator over the token stream.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    end: usize,
+}
+
+type Spanned = (Value, Range<usize>);
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Kind<'a>> {
+        self.tokens.get(self.pos).map(|token| token.kind)
+    }
+
+    fn here(&self) -> Range<usize> {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.end..self.end, |token| token.span.clone())
+    }
+
+    /// Returns true if the next word is `in` or `to` and a unit follows it.
+    ///
+    /// In `12 in to ft` the first `in` is a unit, because the conversion keyword
+    /// after it takes the unit that follows.
+    fn is_conversion_keyword(&self) -> bool {
+        self.is_conversion_keyword_at(self.pos)
+    }
+
+    fn is_conversion_keyword_at(&self, pos: usize) -> bool {
+        let word = |pos: usize| match self.tokens.get(pos).map(|token| token.kind) {
+            Some(Kind::Word(word)) => Some(word),
+            _ => None,
+        };
+        matches!(word(pos), Some("in" | "to"))
+            && word(pos + 1).is_some()
+            && !self.is_conversion_keyword_at(pos + 1)
+    }
+
+    /// sum := product (("+" | "-") product)*
+    fn sum(&mut self, hint: Option<&'static DimensionInfo>) -> Result<Spanned, ExprError> {
+        let (mut left, mut span) = self.product(hint)?;
+        while let Some(Kind::Op(op @ ('+' | '-'))) = self.peek() {
+            self.pos += 1;
+            let (right, right_span) = self.product(left.unit.map(|(d, _)| d).or(hint))?;
+            let right = match (left.unit, right.unit) {
+                (None, None) => right.value,
+                (Some((dimension, unit)), Some((right_dimension, right_unit)))
+                    if dimension.id == right_dimension.id =>
+                {
+                    let difference = (right_unit.to_base)(right.value) - (right_unit.to_base)(0.0);
+                    (unit.from_base)((unit.to_base)(0.0) + difference)
+                }
+                (left_unit, right_unit) => {
+                    let verb = if op == '+' { "add" } else { "subtract" };
+                    let preposition = if op == '+' { "to" } else { "from" };
+                    return Err(ExprError::new(
+                        right_span,
+                        format!(
+                            "cannot {verb} {} {preposition} {}",
+                            describe(right_unit),
+                            describe(left_unit)
+                        ),
+                    ));
+                }
+            };
+            left.value = if op == '+' {
+                left.value + right
+            } else {
+                left.value - right
+            };
+            span = span.start..right_span.end;
+            if !left.value.is_finite() {
+                return Err(ExprError::new(span, "result overflows"));
+            }
+        }
+        Ok((left, span))
+    }
+
+    /// product := unary (("*" | "/") unary)*
+    fn product(&mut self, hint: Option<&'static DimensionInfo>) -> Result<Spanned, ExprError> {
+        let (mut left, mut span) = self.unary(hint)?;
+        while let Some(Kind::Op(op @ ('*' | '/'))) = self.peek() {
+            self.pos += 1;
+            let (right, right_span) = self.unary(left.unit.map(|(d, _)| d).or(hint))?;
+            let whole = span.start..right_span.end;
+            left = match (op, left.unit, right.unit) {
+                ('*', Some(_), Some(_)) => {
+                    return Err(ExprError::new(whole, "cannot multiply two quantities"));
+                }
+                ('*', unit, None) | ('*', None, unit) => Value {
+                    value: left.value * right.value,
+                    unit,
+                },
+                (_, unit, None) => Value {
+                    value: left.value / nonzero(right.value, &right_span)?,
+                    unit,
+                },
+                (_, Some((dimension, unit)), Some((right_dimension, right_unit)))
+                    if dimension.id == right_dimension.id =>
+                {
+                    let divisor = nonzero((right_unit.to_base)(right.value), &right_span)?;
+                    Value {
+                        value: (unit.to_base)(left.value) / divisor,
+                        unit: None,
+                    }
+                }
+                (_, left_unit, right_unit) => {
+                    return Err(ExprError::new(
+                        whole,
+                        format!(
+                            "cannot divide {} by {}",
+                            describe(left_unit),
+                            describe(right_unit)
+                        ),
+                    ));
+                }
+            };
+            if !left.value.is_finite() {
+                return Err(ExprError::new(whole, "result overflows"));
+            }
+            span = whole;
+        }
+        Ok((left, span))
+    }
+
+    /// unary := ("-" | "+") unary | primary
+    fn unary(&mut self, hint: Option<&'static DimensionInfo>) -> Result<Spanned, ExprError> {
+        if let Some(Kind::Op(sign @ ('-' | '+'))) = self.peek() {
+            let start = self.here().start;
+            self.pos += 1;
+            let (mut value, span) = self.unary(hint)?;
+            if sign == '-' {
+                value.value = -value.value;
+            }
+            return Ok((value, start..span.end));
+        }
+        self.primary(hint)
+    }
+
+    /// primary := number unit? | "(" sum ")"
+    fn primary(&mut self, hint: Option<&'static DimensionInfo>) -> Result<Spanned, ExprError> {
+        let span = self.here();
+        match self.peek() {
+            Some(Kind::Number(value)) => {
+                self.pos += 1;
+                if !matches!(self.peek(), Some(Kind::Word(_))) || self.is_conversion_keyword() {
+                    return Ok((Value { value, unit: None }, span));
+                }
+                let (dimension, unit, unit_span) = self.unit(hint)?;
+                let value = Value {
+                    value,
+                    unit: Some((dimension, unit)),
+                };
+                Ok((value, span.start..unit_span.end))
+            }
+            Some(Kind::Op('(')) => {
+                self.pos += 1;
+                let (value, _) = self.sum(hint)?;
+                if self.peek() != Some(Kind::Op(')')) {
+                    return Err(ExprError::new(self.here(), "expected ')'"));
+                }
+                let end = self.here().end;
+                self.pos += 1;
+                Ok((value, span.start..end))
+            }
+            _ => Err(ExprError::new(span, "expected a number")),
+        }
+    }
+
+    /// Reads a unit of one or more words, preferring the longest that is known and a
+    /// unit of `hint` over other dimensions.
+    fn unit(
+        &mut self,
+        hint: Option<&'static DimensionInfo>,
+    ) -> Result<(&'static DimensionInfo, &'static UnitInfo, Range<usize>), ExprError> {
+        let words: Vec<(&str, Range<usize>)> = self.tokens[self.pos..]
+            .iter()
+            .map_while(|token| match token.kind {
+                Kind::Word(word) => Some((word, token.span.clone())),
+                _ => None,
+            })
+            .take(3)
+            .collect();
+        for count in (1..=words.len()).rev() {
+            let text = words[..count]
+                .iter()
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>()
+                .join(" ");
+            // An exact match anywhere beats a case-insensitive one in the hinted dimension.
+            let found = hint
+                .and_then(|d| d.find_unit_exactly(&text).map(|unit| (d, unit)))
+                .or_else(|| {
+                    registry::find_unit(&text).filter(|(_, unit)| unit.matches_exactly(&text))
+                })
+                .or_else(|| hint.and_then(|d| d.find_unit(&text).map(|unit| (d, unit))))
+                .or_else(|| registry::find_unit(&text));
+            if let Some((dimension, unit)) = found {
+                self.pos += count;
+                return Ok((dimension, unit, words[0].1.start..words[count - 1].1.end));
+            }
+        }
+        match words.first() {
+            Some((word, span)) => Err(ExprError::new(
+                span.clone(),
+                format!("unknown unit '{word}'"),
+            )),
+            None => Err(ExprError::new(self.here(), "expected a unit")),
+        }
+    }
+}
+
+/// Names what a value is for error messages, such as "a length" or "a plain number".
+fn describe(unit: Option<(&'static DimensionInfo, &'static UnitInfo)>) -> String {
+    match unit {
+        Some((dimension, _)) => format!("a {}", dimension.name),
+        None => "a plain number".to_string(),
+    }
+}
+
+/// Returns `divisor`, or an error pointing at `span` if it is zero.
+fn nonzero(divisor: f64, span: &Range<usize>) -> Result<f64, ExprError> {
+    if divisor == 0.0 {
+        return Err(ExprError::new(span.clone(), "division by zero"));
+    }
+    Ok(divisor)
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn eval(input: &str) -> (f64, Option<&'static str>) {
+        let value = evaluate(input).unwrap();
+        (value.value, value.unit.map(|(_, unit)| unit.symbol))
+    }
+
+    fn error(input: &str) -> (String, &str) {
+        let err = evaluate(input).unwrap_err();
+        (err.message, &input[err.span])
+    }
+
+    #[test]
+    fn evaluates_plain_arithmetic() {
+        assert_eq!(eval("1 + 2 * 3"), (7.0, None));
+        assert_eq!(eval("(1 + 2) * 3"), (9.0, None));
+        assert_eq!(eval("-2 - -3"), (1.0, None));
+        assert_eq!(eval("1.5e3 / 3"), (500.0, None));
+    }
+
+    #[test]
+    fn combines_quantities() {
+        let (value, unit) = eval("2 * (3 ft + 10 cm) in m");
+        assert!(approx(value, 2.0288, 1e-12));
+        assert_eq!(unit, Some("m"));
+
+        let (value, unit) = eval("1 km - 100 m");
+        assert!(approx(value, 0.9, 1e-12));
+        assert_eq!(unit, Some("km"));
+
+        assert_eq!(eval("6 ft / 2"), (3.0, Some("ft")));
+        assert_eq!(eval("3 in"), (3.0, Some("in")));
+        let (value, unit) = eval("12 in to ft");
+        assert!(approx(value, 1.0, 1e-12));
+        assert_eq!(unit, Some("ft"));
+        assert_eq!(eval("3 ft in in").1, Some("in"));
+        assert!(approx(eval("1 km / 500 m").0, 2.0, 1e-12));
+    }
+
+    #[cfg(all(feature = "current", feature = "speed"))]
+    #[test]
+    fn prefers_exact_unit_matches() {
+        assert_eq!(eval("20 mA"), (20.0, Some("mA")));
+        assert_eq!(eval("2 Ma"), (2.0, Some("Ma")));
+        let (value, unit) = eval("1 A + 500 mA");
+        assert!(approx(value, 1.5, 1e-12));
+        assert_eq!(unit, Some("A"));
+    }
+
+    #[test]
+    fn adds_temperature_differences() {
+        let (value, unit) = eval("20 °C + 9 °F");
+        assert!(approx(value, 25.0, 1e-9));
+        assert_eq!(unit, Some("°C"));
+        assert!(approx(eval("(20 °C + 5 °C) in °F").0, 77.0, 1e-9));
+    }
+
+    #[test]
+    fn points_at_the_problem() {
+        assert_eq!(
+            error("2 * (3 ft + 10 cubits) in m"),
+            ("unknown unit 'cubits'".to_string(), "cubits")
+        );
+        assert_eq!(
+            error("3 ft + 2"),
+            ("cannot add a plain number to a length".to_string(), "2")
+        );
+        assert_eq!(
+            error("3 ft * 2 ft"),
+            ("cannot multiply two quantities".to_string(), "3 ft * 2 ft")
+        );
+        assert_eq!(
+            error("3 ft in °C"),
+            (
+                "cannot convert a length to °C (temperature)".to_string(),
+                "°C"
+            )
+        );
+        assert_eq!(error("(1 + 2"), ("expected ')'".to_string(), ""));
+        assert_eq!(error("1 + * 2"), ("expected a number".to_string(), "*"));
+        assert_eq!(error("1 / 0"), ("division by zero".to_string(), "0"));
+        assert_eq!(error("0 / 0"), ("division by zero".to_string(), "0"));
+        assert_eq!(error("1 km / 0 m"), ("division by zero".to_string(), "0 m"));
+        assert_eq!(
+            error("1e308 * 10 m"),
+            ("result overflows".to_string(), "1e308 * 10 m")
+        );
+        assert_eq!(
+            error("1e308 / 1e-10"),
+            ("result overflows".to_string(), "1e308 / 1e-10")
+        );
+        assert_eq!(
+            error("1 m + 1e308 m + 1e308 m"),
+            ("result overflows".to_string(), "1 m + 1e308 m + 1e308 m")
+        );
+        assert_eq!(error("1 2"), ("unexpected '2'".to_string(), "2"));
+    }
+
+    #[test]
+    fn annotates_with_carets() {
+        let input = "5 °C + 1 mile";
+        let err = evaluate(input).unwrap_err();
+        assert_eq!(err.annotate(input), "5 °C + 1 mile\n       ^^^^^^");
+    }
+}
@@ -0,0 +1,234 @@
+//! # Force Unit Conversions
+//!
+//! This module provides type-safe force conversions between SI, gravitational and CGS
+//! units.
+//! All forces are internally stored in newtons (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Newton (N)**: The base unit, the force that accelerates 1 kg at 1 m/s²
+//! - **Kilonewton (kN)**: 1000 newtons
+//! - **Pound-force (lbf)**: 4.448 221 615 260 5 newtons
+//! - **Kilogram-force (kgf)**: 9.806 65 newtons, the weight of 1 kg in standard gravity
+//! - **Dyne (dyn)**: 10⁻⁵ newtons
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::force::{Force, Newton, PoundForce};
+//!
+//! let thrust = Force::from_unit::<PoundForce>(100.0);
+//! assert!((thrust.to_unit::<Newton>() - 444.822).abs() < 1e-3);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for force conversions
+const NEWTONS_PER_KILONEWTON: f64 = 1e3;
+const NEWTONS_PER_POUND_FORCE: f64 = 4.448_221_615_260_5;
+const NEWTONS_PER_KILOGRAM_FORCE: f64 = 9.806_65;
+const NEWTONS_PER_DYNE: f64 = 1e-5;
+
+/// Marker type for the force dimension.
+///
+/// This prevents accidental conversions between a force and a mass, which customary
+/// units both call "pounds".
+pub enum ForceDimension {}
+
+impl Dimension for ForceDimension {
+    const NAME: &'static str = "force";
+    const ID: u8 = 16;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Newton>(&["newton", "newtons"]),
+        UnitInfo::of::<Kilonewton>(&["kilonewton", "kilonewtons"]),
+        UnitInfo::of::<PoundForce>(&["pound-force", "pounds-force", "pound force"]),
+        UnitInfo::of::<KilogramForce>(&["kilogram-force", "kilopond", "kp"]),
+        UnitInfo::of::<Dyne>(&["dyne", "dynes"]),
+    ];
+}
+
+/// A force quantity that stores values in newtons internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, Kilonewton, Newton};
+///
+/// let load = Force::from_unit::<Kilonewton>(2.5);
+/// let newtons = load.to_unit::<Newton>(); // 2500.0
+/// ```
+pub type Force = Quantity<Newton>;
+
+/// Newton force unit (SI derived unit, 1 kg·m/s²).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, Newton, Kilonewton};
+///
+/// let q = Force::from_unit::<Newton>(1500.0);
+/// assert!((q.to_unit::<Kilonewton>() - 1.5).abs() < 1e-9);
+/// ```
+pub struct Newton;
+
+impl UnitConversion for Newton {
+    type Dimension = ForceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Newton is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Newton is the base unit
+    }
+
+    const SYMBOL: &'static str = "N";
+    const NAME: &'static str = "newton";
+    const PLURAL: &'static str = "newtons";
+}
+
+/// Kilonewton force unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, Kilonewton, Newton};
+///
+/// let q = Force::from_unit::<Kilonewton>(0.2);
+/// assert!((q.to_unit::<Newton>() - 200.0).abs() < 1e-9);
+/// ```
+pub struct Kilonewton;
+
+impl UnitConversion for Kilonewton {
+    type Dimension = ForceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * NEWTONS_PER_KILONEWTON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / NEWTONS_PER_KILONEWTON
+    }
+
+    const SYMBOL: &'static str = "kN";
+    const NAME: &'static str = "kilonewton";
+    const PLURAL: &'static str = "kilonewtons";
+}
+
+/// Pound-force unit, the weight of one avoirdupois pound in standard gravity.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, PoundForce, Newton};
+///
+/// let q = Force::from_unit::<PoundForce>(1.0);
+/// assert!((q.to_unit::<Newton>() - 4.4482216152605).abs() < 1e-12);
+/// ```
+pub struct PoundForce;
+
+impl UnitConversion for PoundForce {
+    type Dimension = ForceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * NEWTONS_PER_POUND_FORCE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / NEWTONS_PER_POUND_FORCE
+    }
+
+    const SYMBOL: &'static str = "lbf";
+    const NAME: &'static str = "pound-force";
+    const PLURAL: &'static str = "pounds-force";
+}
+
+/// Kilogram-force unit, the weight of one kilogram in standard gravity.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, KilogramForce, Newton};
+///
+/// let q = Force::from_unit::<KilogramForce>(2.0);
+/// assert!((q.to_unit::<Newton>() - 19.6133).abs() < 1e-12);
+/// ```
+pub struct KilogramForce;
+
+impl UnitConversion for KilogramForce {
+    type Dimension = ForceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    const SYMBOL: &'static str = "kgf";
+    const NAME: &'static str = "kilogram-force";
+    const PLURAL: &'static str = "kilograms-force";
+}
+
+/// Dyne force unit (CGS unit, 1 g·cm/s²).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::force::{Force, Dyne, Newton};
+///
+/// let q = Force::from_unit::<Dyne>(1e5);
+/// assert!((q.to_unit::<Newton>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct Dyne;
+
+impl UnitConversion for Dyne {
+    type Dimension = ForceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * NEWTONS_PER_DYNE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / NEWTONS_PER_DYNE
+    }
+
+    const SYMBOL: &'static str = "dyn";
+    const NAME: &'static str = "dyne";
+    const PLURAL: &'static str = "dynes";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn gravitational_units() {
+        let force = Force::from_unit::<KilogramForce>(1.0);
+        assert!(approx(force.to_unit::<PoundForce>(), 2.204623, 1e-6));
+        assert!(approx(force.to_unit::<Dyne>(), 980_665.0, 1e-6));
+    }
+
+    #[test]
+    fn parses_units() {
+        let force = Force::parse("12 kN").unwrap();
+        assert!(approx(force.to_unit::<Newton>(), 12_000.0, 1e-9));
+        let force = Force::parse("3 pounds-force").unwrap();
+        assert!(approx(force.to_unit::<PoundForce>(), 3.0, 1e-12));
+    }
+}
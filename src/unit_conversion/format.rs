@@ -0,0 +1,542 @@
+//! # Unit Symbol Formatting
+//!
+//! Style guides disagree on how a number and its unit symbol are written: the SI
+//! brochure asks for a space before every symbol ("100 %"), most house styles close up
+//! the percent sign ("100%"), typesetters prefer a narrow no-break space that keeps the
+//! two on one line, and money is written "$5" in one report and "5 USD" in the next. A
+//! [`UnitFormatter`] holds those choices:
+//!
+//! - the [`Placement`] of the symbol, before or after the number;
+//! - the [`Separator`] between them;
+//! - symbol overrides, which replace a unit's symbol (or a currency code) with the text
+//!   to print.
+//!
+//! [`Quantity::format_with`] applies a formatter to a quantity and returns a
+//! [`Formatted`] value whose `Display` output follows it; the formatter's precision, if
+//! any, applies to the number. A negative number keeps its sign in front, so a prefix
+//! symbol reads "-$5".
+//!
+//! Quantities also implement `Display` directly, in their own unit with the default
+//! formatter, and [`Quantity::display_in`] does the same in any unit of the dimension.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::format::{Separator, UnitFormatter};
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//!
+//! let t = Temperature::from_unit::<Celsius>(37.0);
+//! let si = UnitFormatter::new();
+//! assert_eq!(t.format_with::<Celsius>(&si).to_string(), "37 °C");
+//!
+//! let house = UnitFormatter::new().with_separator(Separator::None);
+//! assert_eq!(t.format_with::<Celsius>(&house).to_string(), "37°C");
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+
+/// Where a unit symbol is written relative to the number.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// Before the number, as in "$5".
+    Prefix,
+    /// After the number, as in "5 kg".
+    #[default]
+    Suffix,
+}
+
+/// What is written between the number and its unit symbol.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// An ordinary space, U+0020.
+    #[default]
+    Space,
+    /// A narrow no-break space, U+202F, as recommended for typeset SI values.
+    NarrowNoBreakSpace,
+    /// A no-break space, U+00A0.
+    NoBreakSpace,
+    /// Nothing: the symbol is closed up to the number.
+    None,
+}
+
+impl Separator {
+    /// Returns the separator text.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::NarrowNoBreakSpace => "\u{202F}",
+            Self::NoBreakSpace => "\u{A0}",
+            Self::None => "",
+        }
+    }
+}
+
+/// Configuration for writing a number with a unit symbol.
+///
+/// The default writes the symbol after the number, separated by a space, which is how
+/// the rest of the crate formats quantities.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::format::{Placement, Separator, UnitFormatter};
+/// use uom::unit_conversion::length::{Kilometer, Length};
+///
+/// let typeset = UnitFormatter::new().with_separator(Separator::NarrowNoBreakSpace);
+/// let run = Length::from_unit::<Kilometer>(42.195);
+/// assert_eq!(
+///     format!("{:.1}", run.format_with::<Kilometer>(&typeset)),
+///     "42.2\u{202F}km"
+/// );
+///
+/// let signage = UnitFormatter::new().with_symbol("km", "KM");
+/// assert_eq!(run.format_with::<Kilometer>(&signage).to_string(), "42.195 KM");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitFormatter {
+    placement: Placement,
+    separator: Separator,
+    overrides: Vec<(String, String)>,
+}
+
+impl UnitFormatter {
+    /// Creates a formatter that writes the symbol after the number, separated by a
+    /// space, with no symbol overrides.
+    pub const fn new() -> Self {
+        Self {
+            placement: Placement::Suffix,
+            separator: Separator::Space,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Returns the formatter with the given symbol placement.
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Returns the formatter with the given separator.
+    pub fn with_separator(mut self, separator: Separator) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Returns the formatter with `symbol` written as `replacement`.
+    ///
+    /// `symbol` is matched exactly against a unit's [`SYMBOL`](UnitConversion::SYMBOL)
+    /// or a currency's ISO 4217 code. A later override of the same symbol replaces an
+    /// earlier one.
+    pub fn with_symbol(
+        mut self,
+        symbol: impl Into<String>,
+        replacement: impl Into<String>,
+    ) -> Self {
+        let (symbol, replacement) = (symbol.into(), replacement.into());
+        match self.overrides.iter_mut().find(|(from, _)| *from == symbol) {
+            Some(entry) => entry.1 = replacement,
+            None => self.overrides.push((symbol, replacement)),
+        }
+        self
+    }
+
+    /// Returns the symbol placement.
+    pub fn placement(&self) -> Placement {
+        self.placement
+    }
+
+    /// Returns the separator.
+    pub fn separator(&self) -> Separator {
+        self.separator
+    }
+
+    /// Returns the text written for `symbol`: its override if there is one, or
+    /// `symbol` itself.
+    pub fn symbol<'a>(&'a self, symbol: &'a str) -> &'a str {
+        self.overrides
+            .iter()
+            .find(|(from, _)| from == symbol)
+            .map_or(symbol, |(_, to)| to)
+    }
+
+    /// Writes `number`, already formatted, with `symbol` according to this formatter.
+    ///
+    /// A symbol that is empty, after overrides, is written without a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::format::{Placement, Separator, UnitFormatter};
+    ///
+    /// let dollars = UnitFormatter::new()
+    ///     .with_placement(Placement::Prefix)
+    ///     .with_separator(Separator::None)
+    ///     .with_symbol("USD", "$");
+    /// assert_eq!(dollars.write("-5", "USD"), "-$5");
+    /// assert_eq!(UnitFormatter::new().write("5", "USD"), "5 USD");
+    /// ```
+    pub fn write(&self, number: &str, symbol: &str) -> String {
+        let symbol = self.symbol(symbol);
+        if symbol.is_empty() {
+            return number.to_string();
+        }
+        let separator = self.separator.as_str();
+        match self.placement {
+            Placement::Suffix => format!("{number}{separator}{symbol}"),
+            Placement::Prefix => match number.strip_prefix('-') {
+                Some(magnitude) => format!("-{symbol}{separator}{magnitude}"),
+                None => format!("{symbol}{separator}{number}"),
+            },
+        }
+    }
+}
+
+/// The formatter behind `Display` for quantities and [`Quantity::display_in`].
+static DEFAULT: UnitFormatter = UnitFormatter::new();
+
+/// A value displayed with its unit symbol according to a [`UnitFormatter`].
+///
+/// Created by [`Quantity::format_with`]. The formatter's precision, or else the
+/// installed [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies to the
+/// number.
+#[derive(Clone, Copy, Debug)]
+pub struct Formatted<'a> {
+    value: f64,
+    symbol: &'static str,
+    subject: Subject,
+    formatter: &'a UnitFormatter,
+}
+
+impl Formatted<'_> {
+    /// Returns the numeric value in the display unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Pads the output to the format string's width, with its fill and alignment.
+///
+/// Written out rather than left to `Formatter::pad`, which would also cut the text
+/// to the precision that already applied to the number.
+impl fmt::Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = format_number(self.value, f.precision(), self.subject);
+        let text = self.formatter.write(&number, self.symbol);
+        let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&text)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Returns a displayable form of this quantity in unit `V`, written according to
+    /// `formatter`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::format::{Placement, UnitFormatter};
+    /// use uom::unit_conversion::length::{Foot, Length, Meter};
+    ///
+    /// let style = UnitFormatter::new().with_placement(Placement::Prefix);
+    /// let q = Length::from_unit::<Meter>(3.048);
+    /// assert_eq!(format!("{:.0}", q.format_with::<Foot>(&style)), "ft 10");
+    /// ```
+    pub fn format_with<'a, V>(&self, formatter: &'a UnitFormatter) -> Formatted<'a>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Formatted {
+            value: self.to_unit::<V>(),
+            symbol: V::SYMBOL,
+            subject: Subject::Dimension(<U::Dimension as Dimension>::NAME),
+            formatter,
+        }
+    }
+
+    /// Returns a displayable form of this quantity in unit `V`, written as the number
+    /// followed by a space and the unit symbol.
+    ///
+    /// A precision in the format string, or else the installed
+    /// [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies to the number.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Kilometer, Length, Meter};
+    ///
+    /// let len = Length::from_unit::<Meter>(3200.0);
+    /// assert_eq!(format!("{:.2}", len.display_in::<Kilometer>()), "3.20 km");
+    /// assert_eq!(len.display_in::<Meter>().to_string(), "3200 m");
+    /// ```
+    pub fn display_in<V>(&self) -> Formatted<'static>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        self.format_with::<V>(&DEFAULT)
+    }
+}
+
+/// Writes the quantity in its own unit `U` followed by the unit symbol, as
+/// [`display_in::<U>()`](Quantity::display_in) does.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
+/// use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+///
+/// let t = Temperature::from_unit::<Celsius>(37.0);
+/// assert_eq!(t.to_string(), "310.15 K");
+/// assert_eq!(format!("{:.1}", t.display_in::<Fahrenheit>()), "98.6 °F");
+/// ```
+impl<U> fmt::Display for Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_in::<U>(), f)
+    }
+}
+
+#[cfg(feature = "currency")]
+impl crate::unit_conversion::currency::Money {
+    /// Returns a displayable form of this amount, written with its ISO 4217 code as the
+    /// symbol according to `formatter`.
+    ///
+    /// Override the code to print a currency sign instead.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "currency"), doc = "```ignore")]
+    #[cfg_attr(feature = "currency", doc = "```")]
+    /// use uom::unit_conversion::currency::{Currency, Money};
+    /// use uom::unit_conversion::format::{Placement, Separator, UnitFormatter};
+    ///
+    /// let price = Money::new(5.0, Currency::USD);
+    /// assert_eq!(price.format_with(&UnitFormatter::new()).to_string(), "5 USD");
+    ///
+    /// let retail = UnitFormatter::new()
+    ///     .with_placement(Placement::Prefix)
+    ///     .with_separator(Separator::None)
+    ///     .with_symbol("USD", "$");
+    /// assert_eq!(format!("{:.2}", price.format_with(&retail)), "$5.00");
+    /// ```
+    pub fn format_with<'a>(&self, formatter: &'a UnitFormatter) -> Formatted<'a> {
+        Formatted {
+            value: self.amount(),
+            symbol: self.currency().code(),
+            subject: Subject::Currency(self.currency().code()),
+            formatter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::UnitInfo;
+    #[cfg(feature = "currency")]
+    use crate::unit_conversion::currency::{Currency, Money};
+    #[cfg(feature = "length")]
+    use crate::unit_conversion::length::{Length, Meter};
+    use crate::unit_conversion::precision::PrecisionPolicy;
+    #[cfg(feature = "ratio")]
+    use crate::unit_conversion::ratio::{Percent, Ratio, Unity};
+
+    // The installed policy is shared by every test in the process, so the `Display`
+    // tests use dimensions of their own that no other test displays, and only the
+    // policy test installs one, for sprockets.
+    struct GadgetDimension;
+    struct Gadget;
+    struct Kilogadget;
+
+    impl UnitConversion for Gadget {
+        type Dimension = GadgetDimension;
+        const SYMBOL: &'static str = "gdg";
+        const NAME: &'static str = "gadget";
+
+        fn convert_to(value: f64) -> f64 {
+            value
+        }
+
+        fn convert_from(value: f64) -> f64 {
+            value
+        }
+    }
+
+    impl UnitConversion for Kilogadget {
+        type Dimension = GadgetDimension;
+        const SYMBOL: &'static str = "kgdg";
+        const NAME: &'static str = "kilogadget";
+
+        fn convert_to(value: f64) -> f64 {
+            value * 1000.0
+        }
+
+        fn convert_from(value: f64) -> f64 {
+            value / 1000.0
+        }
+    }
+
+    impl Dimension for GadgetDimension {
+        const NAME: &'static str = "gadget count";
+        const ID: u8 = u8::MAX;
+        const UNITS: &'static [UnitInfo] =
+            &[UnitInfo::of::<Gadget>(&[]), UnitInfo::of::<Kilogadget>(&[])];
+    }
+
+    struct SprocketDimension;
+    struct Sprocket;
+
+    impl UnitConversion for Sprocket {
+        type Dimension = SprocketDimension;
+        const SYMBOL: &'static str = "spr";
+        const NAME: &'static str = "sprocket";
+
+        fn convert_to(value: f64) -> f64 {
+            value
+        }
+
+        fn convert_from(value: f64) -> f64 {
+            value
+        }
+    }
+
+    impl Dimension for SprocketDimension {
+        const NAME: &'static str = "sprocket count";
+        const ID: u8 = u8::MAX;
+        const UNITS: &'static [UnitInfo] = &[UnitInfo::of::<Sprocket>(&[])];
+    }
+
+    #[test]
+    fn quantities_display_in_their_own_unit() {
+        let q = Quantity::<Gadget>::from_unit::<Kilogadget>(-1.25);
+        assert_eq!(q.to_string(), "-1250 gdg");
+        assert_eq!(format!("{:.1}", q), "-1250.0 gdg");
+        assert_eq!(format!("{:>12}", q), "   -1250 gdg");
+        assert_eq!(format!("{:*<12.1}", q), "-1250.0 gdg*");
+        assert_eq!(format!("{:^11}", q), " -1250 gdg ");
+        assert_eq!(format!("{:3}", q), "-1250 gdg");
+    }
+
+    #[test]
+    fn display_in_converts_first() {
+        let q = Quantity::<Gadget>::from_unit::<Gadget>(3200.0);
+        assert_eq!(q.display_in::<Kilogadget>().to_string(), "3.2 kgdg");
+        assert_eq!(format!("{:.2}", q.display_in::<Kilogadget>()), "3.20 kgdg");
+        assert_eq!(
+            format!("{:<10}|", q.display_in::<Kilogadget>()),
+            "3.2 kgdg  |"
+        );
+        assert_eq!(q.display_in::<Kilogadget>().value(), 3.2);
+        let debt = -q;
+        assert_eq!(
+            format!("{:>10.1}", debt.display_in::<Kilogadget>()),
+            " -3.2 kgdg"
+        );
+    }
+
+    #[test]
+    fn display_follows_the_installed_policy() {
+        let q = Quantity::<Sprocket>::from_unit::<Sprocket>(-2.0 / 3.0);
+        PrecisionPolicy::installed()
+            .with_dimension::<SprocketDimension>(2)
+            .install();
+        assert_eq!(q.to_string(), "-0.67 spr");
+        assert_eq!(q.display_in::<Sprocket>().to_string(), "-0.67 spr");
+        // An explicit precision wins over the policy
+        assert_eq!(format!("{:.4}", q), "-0.6667 spr");
+        assert_eq!(format!("{:>10}", q), " -0.67 spr");
+    }
+
+    #[cfg(feature = "ratio")]
+    #[test]
+    fn separators() {
+        let share = Ratio::from_unit::<Percent>(12.5);
+        let shown = |separator| {
+            let style = UnitFormatter::new().with_separator(separator);
+            share.format_with::<Percent>(&style).to_string()
+        };
+        assert_eq!(shown(Separator::Space), "12.5 %");
+        assert_eq!(shown(Separator::NarrowNoBreakSpace), "12.5\u{202F}%");
+        assert_eq!(shown(Separator::NoBreakSpace), "12.5\u{A0}%");
+        assert_eq!(shown(Separator::None), "12.5%");
+    }
+
+    #[cfg(feature = "currency")]
+    #[test]
+    fn prefix_symbols_follow_the_sign() {
+        let dollars = UnitFormatter::new()
+            .with_placement(Placement::Prefix)
+            .with_separator(Separator::None)
+            .with_symbol("USD", "$");
+        let refund = Money::new(-12.5, Currency::USD);
+        assert_eq!(format!("{:.2}", refund.format_with(&dollars)), "-$12.50");
+        assert_eq!(
+            Money::new(3.0, Currency::EUR)
+                .format_with(&dollars)
+                .to_string(),
+            "EUR3"
+        );
+
+        let francs = UnitFormatter::new().with_placement(Placement::Prefix);
+        let fee = Money::new(20.0, Currency::CHF);
+        assert_eq!(fee.format_with(&francs).to_string(), "CHF 20");
+    }
+
+    #[cfg(feature = "ratio")]
+    #[test]
+    fn overrides() {
+        let style = UnitFormatter::new()
+            .with_symbol("m", "metres")
+            .with_symbol("m", "meters");
+        assert_eq!(style.symbol("m"), "meters");
+        assert_eq!(style.symbol("km"), "km");
+
+        let hide = UnitFormatter::new().with_symbol("1", "");
+        let r = Ratio::from_unit::<Unity>(0.5);
+        assert_eq!(r.format_with::<Unity>(&hide).to_string(), "0.5");
+        assert_eq!(r.format_with::<Unity>(&hide).value(), 0.5);
+    }
+
+    #[cfg(feature = "length")]
+    #[test]
+    fn overrides_apply_to_quantities() {
+        let style = UnitFormatter::new().with_symbol("m", "meters");
+        let q = Length::from_unit::<Meter>(3.0);
+        assert_eq!(q.format_with::<Meter>(&style).to_string(), "3 meters");
+    }
+
+    #[test]
+    fn defaults() {
+        let style = UnitFormatter::default();
+        assert_eq!(style, UnitFormatter::new());
+        assert_eq!(style.placement(), Placement::Suffix);
+        assert_eq!(style.separator(), Separator::Space);
+    }
+}
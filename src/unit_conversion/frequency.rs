@@ -0,0 +1,413 @@
+//! # Frequency Unit Conversions
+//!
+//! This module provides type-safe frequency conversions between hertz, its metric
+//! multiples, and the reciprocal period units that give the duration of one cycle.
+//! All frequencies are internally stored in hertz (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Hertz (Hz)**: The base unit, one cycle per second
+//! - **Kilohertz (kHz)**: 1000 hertz
+//! - **Megahertz (MHz)**: 10⁶ hertz
+//! - **Gigahertz (GHz)**: 10⁹ hertz
+//! - **Second per cycle (s/cycle)**, **Millisecond per cycle (ms/cycle)**, **Microsecond per
+//!   cycle (µs/cycle)**: The period of one cycle; reciprocal units, so 0 Hz has no finite
+//!   period and a period of 0 is an infinite frequency
+//!
+//! With the `time` feature, [`Frequency::period`] and [`Time::frequency`] convert between a
+//! frequency and a typed [`Time`] period.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::frequency::{Frequency, Hertz, Megahertz};
+//!
+//! let station = Frequency::from_unit::<Megahertz>(101.1);
+//! assert!((station.to_unit::<Hertz>() - 101_100_000.0).abs() < 1e-3);
+//! ```
+
+#[cfg(feature = "network")]
+use crate::unit_conversion::network::PacketPerSecond;
+#[cfg(feature = "time")]
+use crate::unit_conversion::time::Time;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for frequency conversions
+const HERTZ_PER_KILOHERTZ: f64 = 1e3;
+const HERTZ_PER_MEGAHERTZ: f64 = 1e6;
+const HERTZ_PER_GIGAHERTZ: f64 = 1e9;
+const SECONDS_PER_MILLISECOND: f64 = 1e-3;
+const SECONDS_PER_MICROSECOND: f64 = 1e-6;
+
+/// Marker type for the frequency dimension.
+///
+/// This prevents accidental conversions between frequency and other dimensions
+/// like time or speed.
+pub enum FrequencyDimension {}
+
+impl Dimension for FrequencyDimension {
+    const NAME: &'static str = "frequency";
+    const ID: u8 = 13;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Hertz>(&["hertz", "cps"]),
+        UnitInfo::of::<Kilohertz>(&["kilohertz"]),
+        UnitInfo::of::<Megahertz>(&["megahertz"]),
+        UnitInfo::of::<Gigahertz>(&["gigahertz"]),
+        UnitInfo::of::<SecondPerCycle>(&["s/cyc", "second per cycle", "seconds per cycle"]),
+        UnitInfo::of::<MillisecondPerCycle>(&[
+            "ms/cyc",
+            "millisecond per cycle",
+            "milliseconds per cycle",
+        ]),
+        UnitInfo::of::<MicrosecondPerCycle>(&[
+            "us/cycle",
+            "µs/cyc",
+            "microsecond per cycle",
+            "microseconds per cycle",
+        ]),
+        #[cfg(feature = "network")]
+        UnitInfo::of::<PacketPerSecond>(&["packet per second", "packets per second"]),
+    ];
+}
+
+/// A frequency quantity that stores values in hertz internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Gigahertz, Megahertz};
+///
+/// let clock = Frequency::from_unit::<Gigahertz>(3.2);
+/// let mhz = clock.to_unit::<Megahertz>(); // 3200.0
+/// ```
+pub type Frequency = Quantity<Hertz>;
+
+/// Hertz frequency unit (SI derived unit, one cycle per second).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Hertz};
+///
+/// let mains = Frequency::from_unit::<Hertz>(50.0);
+/// assert_eq!(mains.to_unit::<Hertz>(), 50.0);
+/// ```
+pub struct Hertz;
+
+impl UnitConversion for Hertz {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Hertz is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Hertz is the base unit
+    }
+
+    const SYMBOL: &'static str = "Hz";
+    const NAME: &'static str = "hertz";
+    const PLURAL: &'static str = "hertz";
+}
+
+/// Kilohertz frequency unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Kilohertz, Hertz};
+///
+/// let sample_rate = Frequency::from_unit::<Kilohertz>(44.1);
+/// assert!((sample_rate.to_unit::<Hertz>() - 44_100.0).abs() < 1e-9);
+/// ```
+pub struct Kilohertz;
+
+impl UnitConversion for Kilohertz {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * HERTZ_PER_KILOHERTZ
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / HERTZ_PER_KILOHERTZ
+    }
+
+    const SYMBOL: &'static str = "kHz";
+    const NAME: &'static str = "kilohertz";
+    const PLURAL: &'static str = "kilohertz";
+}
+
+/// Megahertz frequency unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Megahertz, Kilohertz};
+///
+/// let crystal = Frequency::from_unit::<Megahertz>(16.0);
+/// assert_eq!(crystal.to_unit::<Kilohertz>(), 16_000.0);
+/// ```
+pub struct Megahertz;
+
+impl UnitConversion for Megahertz {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * HERTZ_PER_MEGAHERTZ
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / HERTZ_PER_MEGAHERTZ
+    }
+
+    const SYMBOL: &'static str = "MHz";
+    const NAME: &'static str = "megahertz";
+    const PLURAL: &'static str = "megahertz";
+}
+
+/// Gigahertz frequency unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Gigahertz, Megahertz};
+///
+/// let wifi = Frequency::from_unit::<Gigahertz>(2.4);
+/// assert!((wifi.to_unit::<Megahertz>() - 2400.0).abs() < 1e-9);
+/// ```
+pub struct Gigahertz;
+
+impl UnitConversion for Gigahertz {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * HERTZ_PER_GIGAHERTZ
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / HERTZ_PER_GIGAHERTZ
+    }
+
+    const SYMBOL: &'static str = "GHz";
+    const NAME: &'static str = "gigahertz";
+    const PLURAL: &'static str = "gigahertz";
+}
+
+/// Period unit: seconds per cycle.
+///
+/// A frequency of `f` hertz has a period of `1 / f` seconds per cycle.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Hertz, SecondPerCycle};
+///
+/// let pendulum = Frequency::from_unit::<SecondPerCycle>(2.0);
+/// assert_eq!(pendulum.to_unit::<Hertz>(), 0.5);
+/// ```
+pub struct SecondPerCycle;
+
+impl UnitConversion for SecondPerCycle {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        1.0 / (value)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        1.0 / (value)
+    }
+
+    const SYMBOL: &'static str = "s/cycle";
+    const NAME: &'static str = "second per cycle";
+    const PLURAL: &'static str = "seconds per cycle";
+}
+
+/// Period unit: milliseconds per cycle.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Hertz, MillisecondPerCycle};
+///
+/// let mains = Frequency::from_unit::<Hertz>(50.0);
+/// assert!((mains.to_unit::<MillisecondPerCycle>() - 20.0).abs() < 1e-12);
+/// ```
+pub struct MillisecondPerCycle;
+
+impl UnitConversion for MillisecondPerCycle {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        1.0 / (value * SECONDS_PER_MILLISECOND)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        1.0 / (value * SECONDS_PER_MILLISECOND)
+    }
+
+    const SYMBOL: &'static str = "ms/cycle";
+    const NAME: &'static str = "millisecond per cycle";
+    const PLURAL: &'static str = "milliseconds per cycle";
+}
+
+/// Period unit: microseconds per cycle.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Kilohertz, MicrosecondPerCycle};
+///
+/// let tone = Frequency::from_unit::<MicrosecondPerCycle>(250.0);
+/// assert!((tone.to_unit::<Kilohertz>() - 4.0).abs() < 1e-12);
+/// ```
+pub struct MicrosecondPerCycle;
+
+impl UnitConversion for MicrosecondPerCycle {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        1.0 / (value * SECONDS_PER_MICROSECOND)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        1.0 / (value * SECONDS_PER_MICROSECOND)
+    }
+
+    const SYMBOL: &'static str = "µs/cycle";
+    const NAME: &'static str = "microsecond per cycle";
+    const PLURAL: &'static str = "microseconds per cycle";
+}
+
+#[cfg(feature = "time")]
+impl Frequency {
+    /// Returns the period of one cycle at this frequency.
+    ///
+    /// A frequency of zero has an infinite period.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "time"), doc = "```ignore")]
+    #[cfg_attr(feature = "time", doc = "```")]
+    /// use uom::unit_conversion::frequency::{Frequency, Kilohertz};
+    /// use uom::unit_conversion::time::Microsecond;
+    ///
+    /// let clock = Frequency::from_unit::<Kilohertz>(32.768);
+    /// assert!((clock.period().to_unit::<Microsecond>() - 30.517578125).abs() < 1e-9);
+    /// ```
+    pub fn period(&self) -> Time {
+        Time::from_base(1.0 / self.in_base())
+    }
+}
+
+#[cfg(feature = "time")]
+impl Time {
+    /// Returns the frequency of a cycle that repeats with this period.
+    ///
+    /// A period of zero gives an infinite frequency.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "time"), doc = "```ignore")]
+    #[cfg_attr(feature = "time", doc = "```")]
+    /// use uom::unit_conversion::frequency::Hertz;
+    /// use uom::unit_conversion::time::{Millisecond, Time};
+    ///
+    /// let frame = Time::from_unit::<Millisecond>(16.0);
+    /// assert!((frame.frequency().to_unit::<Hertz>() - 62.5).abs() < 1e-9);
+    /// ```
+    pub fn frequency(&self) -> Frequency {
+        Frequency::from_base(1.0 / self.in_base())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_prefixes() {
+        let frequency = Frequency::from_unit::<Gigahertz>(1.0);
+        assert!(approx(frequency.to_unit::<Megahertz>(), 1000.0, 1e-9));
+        assert!(approx(frequency.to_unit::<Kilohertz>(), 1e6, 1e-6));
+        assert!(approx(frequency.to_unit::<Hertz>(), 1e9, 1e-3));
+    }
+
+    #[test]
+    fn period_units() {
+        let frequency = Frequency::from_unit::<Kilohertz>(1.0);
+        assert!(approx(
+            frequency.to_unit::<MillisecondPerCycle>(),
+            1.0,
+            1e-12
+        ));
+        assert!(approx(
+            frequency.to_unit::<MicrosecondPerCycle>(),
+            1000.0,
+            1e-9
+        ));
+        assert!(approx(frequency.to_unit::<SecondPerCycle>(), 0.001, 1e-15));
+
+        let frequency = Frequency::from_unit::<MillisecondPerCycle>(20.0);
+        assert!(approx(frequency.to_unit::<Hertz>(), 50.0, 1e-12));
+
+        assert_eq!(
+            Frequency::from_unit::<Hertz>(0.0).to_unit::<SecondPerCycle>(),
+            f64::INFINITY
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn period_and_frequency_are_reciprocal() {
+        use crate::unit_conversion::time::{Microsecond, Second};
+
+        let frequency = Frequency::from_unit::<Megahertz>(2.0);
+        assert!(approx(
+            frequency.period().to_unit::<Microsecond>(),
+            0.5,
+            1e-12
+        ));
+        assert!(approx(
+            frequency.period().frequency().to_unit::<Megahertz>(),
+            2.0,
+            1e-12
+        ));
+
+        let period = Time::from_unit::<Second>(0.25);
+        assert!(approx(period.frequency().to_unit::<Hertz>(), 4.0, 1e-12));
+        assert_eq!(
+            Time::from_unit::<Second>(0.0)
+                .frequency()
+                .to_unit::<Hertz>(),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn frequency_symbols() {
+        assert_eq!(Hertz::SYMBOL, "Hz");
+        assert_eq!(Kilohertz::SYMBOL, "kHz");
+        assert_eq!(Megahertz::SYMBOL, "MHz");
+        assert_eq!(Gigahertz::SYMBOL, "GHz");
+    }
+}
@@ -0,0 +1,344 @@
+//! # Geographic Coordinates
+//!
+//! This module provides [`Latitude`] and [`Longitude`] types built on the [`Angle`]
+//! quantity. Each checks its range on construction, formats as degrees, minutes and
+//! seconds with a hemisphere letter, and parses the notations found on maps and in GPS
+//! exports.
+//!
+//! Accepted input forms include:
+//!
+//! - Degrees, minutes and seconds: `48°51'24"N`, `48°51′24″ N`, `N 48 51 24`
+//! - Degrees and decimal minutes: `2°21.05'E`
+//! - Decimal degrees with a hemisphere letter or a sign: `48.8566N`, `-2.3508`
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::geo::{Latitude, Longitude};
+//!
+//! let lat: Latitude = "48°51'24\"N".parse().unwrap();
+//! let lon: Longitude = "2°21'03\"E".parse().unwrap();
+//! assert!((lat.degrees() - 48.856667).abs() < 1e-6);
+//! assert!((lon.degrees() - 2.350833).abs() < 1e-6);
+//!
+//! let south = Latitude::from_degrees(-33.8568).unwrap();
+//! assert_eq!(south.to_string(), "33°51'24\"S");
+//! assert_eq!(format!("{south:.1}"), "33°51'24.5\"S");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::unit_conversion::UnitError;
+use crate::unit_conversion::angle::{Angle, Degree};
+
+const MINUTES_PER_DEGREE: f64 = 60.0;
+const SECONDS_PER_DEGREE: f64 = 3600.0;
+
+/// A latitude between 90° south and 90° north; positive values are north.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::{Angle, Degree};
+/// use uom::unit_conversion::geo::Latitude;
+///
+/// let lat = Latitude::new(Angle::from_unit::<Degree>(51.4779)).unwrap();
+/// assert_eq!(lat.to_string(), "51°28'40\"N");
+/// assert!(Latitude::from_degrees(91.0).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Latitude(Angle);
+
+/// A longitude between 180° west and 180° east; positive values are east.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::geo::Longitude;
+///
+/// let lon = Longitude::from_degrees(-0.0015).unwrap();
+/// assert_eq!(lon.to_string(), "0°00'05\"W");
+/// assert!(Longitude::from_degrees(180.5).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Longitude(Angle);
+
+/// Range and hemisphere letters of one coordinate axis.
+struct Axis {
+    limit: f64,
+    positive: char,
+    negative: char,
+}
+
+const LATITUDE: Axis = Axis {
+    limit: 90.0,
+    positive: 'N',
+    negative: 'S',
+};
+
+const LONGITUDE: Axis = Axis {
+    limit: 180.0,
+    positive: 'E',
+    negative: 'W',
+};
+
+macro_rules! coordinate_impl {
+    ($name:ident, $axis:expr) => {
+        impl $name {
+            /// Creates a coordinate from an angle.
+            ///
+            /// Returns [`UnitError::OutOfRange`] if the angle is outside the valid range
+            /// or not finite.
+            pub fn new(angle: Angle) -> Result<Self, UnitError> {
+                $axis.check(angle.to_unit::<Degree>())?;
+                Ok(Self(angle))
+            }
+
+            /// Creates a coordinate from signed decimal degrees.
+            ///
+            /// Returns [`UnitError::OutOfRange`] if the value is outside the valid range
+            /// or not finite.
+            pub fn from_degrees(degrees: f64) -> Result<Self, UnitError> {
+                Self::new(Angle::from_unit::<Degree>(degrees))
+            }
+
+            /// Returns the coordinate as an angle.
+            pub fn angle(self) -> Angle {
+                self.0
+            }
+
+            /// Returns the coordinate in signed decimal degrees.
+            pub fn degrees(self) -> f64 {
+                self.0.to_unit::<Degree>()
+            }
+
+            /// Parses a coordinate written in degrees, minutes and seconds or in decimal
+            /// degrees, with an optional hemisphere letter.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`UnitError::ParseError`] if the text is not a coordinate, and
+            /// [`UnitError::OutOfRange`] if it lies outside the valid range.
+            pub fn parse(input: &str) -> Result<Self, UnitError> {
+                Self::from_degrees($axis.parse(input)?)
+            }
+        }
+
+        /// Formats the coordinate as degrees, minutes and seconds with a hemisphere
+        /// letter. The precision, if given, sets the number of decimal places of the
+        /// seconds.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                $axis.format(self.degrees(), f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = UnitError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+    };
+}
+
+coordinate_impl!(Latitude, LATITUDE);
+coordinate_impl!(Longitude, LONGITUDE);
+
+impl Axis {
+    fn check(&self, degrees: f64) -> Result<(), UnitError> {
+        if degrees.is_finite() && degrees.abs() <= self.limit {
+            Ok(())
+        } else {
+            Err(UnitError::OutOfRange {
+                value: degrees,
+                min: -self.limit,
+                max: self.limit,
+            })
+        }
+    }
+
+    /// Returns the hemisphere sign of `letter`, if it is one of this axis's letters.
+    fn hemisphere(&self, letter: char) -> Option<f64> {
+        match letter.to_ascii_uppercase() {
+            c if c == self.positive => Some(1.0),
+            c if c == self.negative => Some(-1.0),
+            _ => None,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<f64, UnitError> {
+        let error = |reason| UnitError::ParseError {
+            input: input.to_string(),
+            reason,
+        };
+
+        let mut text = input.trim();
+        let mut hemisphere = None;
+        if let Some(first) = text.chars().next()
+            && let Some(sign) = self.hemisphere(first)
+        {
+            hemisphere = Some(sign);
+            text = &text[first.len_utf8()..];
+        } else if let Some(last) = text.chars().next_back()
+            && let Some(sign) = self.hemisphere(last)
+        {
+            hemisphere = Some(sign);
+            text = &text[..text.len() - last.len_utf8()];
+        }
+
+        let mut text = text.trim();
+        let mut sign = 1.0;
+        if let Some(rest) = text.strip_prefix('-') {
+            sign = -1.0;
+            text = rest;
+        } else if let Some(rest) = text.strip_prefix('+') {
+            text = rest;
+        }
+        if let Some(hemisphere) = hemisphere {
+            if sign < 0.0 {
+                return Err(error("a sign and a hemisphere letter cannot be combined"));
+            }
+            sign = hemisphere;
+        }
+
+        let fields: Vec<&str> = text
+            .split(|c: char| c.is_whitespace() || "°'\"′″".contains(c))
+            .filter(|field| !field.is_empty())
+            .collect();
+        if fields.is_empty() || fields.len() > 3 {
+            return Err(error("expected degrees, minutes and seconds"));
+        }
+
+        let mut degrees = 0.0;
+        for (i, field) in fields.iter().enumerate() {
+            let value: f64 = field.parse().map_err(|_| error("invalid number"))?;
+            let is_last = i + 1 == fields.len();
+            if !value.is_finite() || value < 0.0 || (!is_last && value.fract() != 0.0) {
+                return Err(error("invalid number"));
+            }
+            if i > 0 && value >= 60.0 {
+                return Err(error("minutes and seconds must be less than 60"));
+            }
+            degrees += value / [1.0, MINUTES_PER_DEGREE, SECONDS_PER_DEGREE][i];
+        }
+        Ok(sign * degrees)
+    }
+
+    fn format(&self, degrees: f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let scale = 10f64.powi(precision as i32);
+        // Round once on the total so that 59.99" carries into the minutes
+        let total = (degrees.abs() * SECONDS_PER_DEGREE * scale).round() / scale;
+        let whole_degrees = (total / SECONDS_PER_DEGREE).floor();
+        let minutes = ((total - whole_degrees * SECONDS_PER_DEGREE) / MINUTES_PER_DEGREE).floor();
+        let seconds = total - whole_degrees * SECONDS_PER_DEGREE - minutes * MINUTES_PER_DEGREE;
+        let letter = if degrees < 0.0 && total > 0.0 {
+            self.negative
+        } else {
+            self.positive
+        };
+        let width = if precision == 0 { 2 } else { precision + 3 };
+        write!(
+            f,
+            "{whole_degrees}°{minutes:02}'{seconds:0width$.precision$}\"{letter}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn parses_dms_forms() {
+        for input in [
+            "48°51'24\"N",
+            "48°51′24″N",
+            "48° 51' 24\" N",
+            "N 48 51 24",
+            "n48°51'24\"",
+        ] {
+            let lat = Latitude::parse(input).unwrap();
+            assert!(approx(lat.degrees(), 48.856667, 1e-6), "{input}");
+        }
+        let lon = Longitude::parse("122°25'09.8\"W").unwrap();
+        assert!(approx(lon.degrees(), -122.419389, 1e-6));
+    }
+
+    #[test]
+    fn parses_decimal_forms() {
+        let lat = Latitude::parse("-33.8568").unwrap();
+        assert!(approx(lat.degrees(), -33.8568, 1e-12));
+
+        let lon = Longitude::parse("151.2153E").unwrap();
+        assert!(approx(lon.degrees(), 151.2153, 1e-12));
+
+        let lon = Longitude::parse("2°21.05'E").unwrap();
+        assert!(approx(lon.degrees(), 2.350833, 1e-6));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        for input in [
+            "",
+            "N",
+            "48°61'00\"N",
+            "48°51'24\"E",
+            "-48°51'24\"S",
+            "48.5°30'N",
+            "1 2 3 4",
+            "abc",
+        ] {
+            assert!(
+                matches!(Latitude::parse(input), Err(UnitError::ParseError { .. })),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            Latitude::parse("91N"),
+            Err(UnitError::OutOfRange { .. })
+        ));
+        assert!(Longitude::parse("181°W").is_err());
+    }
+
+    #[test]
+    fn formats_dms() {
+        let lon = Longitude::from_degrees(2.350833).unwrap();
+        assert_eq!(lon.to_string(), "2°21'03\"E");
+        assert_eq!(format!("{lon:.2}"), "2°21'03.00\"E");
+
+        let lat = Latitude::from_degrees(0.0).unwrap();
+        assert_eq!(lat.to_string(), "0°00'00\"N");
+    }
+
+    #[test]
+    fn formatting_carries_rounded_seconds() {
+        let lat = Latitude::from_degrees(10.0 - 0.1 / 3600.0).unwrap();
+        assert_eq!(lat.to_string(), "10°00'00\"N");
+        assert_eq!(format!("{lat:.1}"), "9°59'59.9\"N");
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        for degrees in [-89.999, -45.5, 0.25, 12.3456, 90.0] {
+            let lat = Latitude::from_degrees(degrees).unwrap();
+            let parsed = Latitude::parse(&format!("{lat:.3}")).unwrap();
+            assert!(approx(parsed.degrees(), degrees, 1e-6), "{degrees}");
+        }
+    }
+
+    #[test]
+    fn range_checks() {
+        assert!(Latitude::from_degrees(90.0).is_ok());
+        assert!(Latitude::from_degrees(-90.1).is_err());
+        assert!(Longitude::from_degrees(-180.0).is_ok());
+        assert!(Longitude::from_degrees(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! # HVAC Capacity Helpers
+//!
+//! Heating and cooling equipment is specified in a mix of units: capacities in BTU/h or
+//! tons of refrigeration, electrical input in watts, and efficiency as either a
+//! dimensionless coefficient of performance (COP) or an energy efficiency ratio (EER)
+//! expressed in BTU/h per watt. This module converts between those specifications using
+//! the typed [`Power`] quantity.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::hvac::{cop_from_eer, electrical_input};
+//! use uom::unit_conversion::power::{Power, TonOfRefrigeration, Watt};
+//!
+//! // A 3-ton air conditioner rated at EER 12
+//! let capacity = Power::from_unit::<TonOfRefrigeration>(3.0);
+//! let input = electrical_input(capacity, cop_from_eer(12.0));
+//! assert!((input.to_unit::<Watt>() - 3000.0).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::UnitConversion;
+use crate::unit_conversion::power::{BtuPerHour, Power, Watt};
+
+/// Converts an energy efficiency ratio (BTU/h of capacity per watt of input) to a
+/// coefficient of performance (watts of capacity per watt of input).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::hvac::cop_from_eer;
+///
+/// assert!((cop_from_eer(12.0) - 3.5169).abs() < 1e-4);
+/// ```
+pub fn cop_from_eer(eer: f64) -> f64 {
+    BtuPerHour::convert_to(eer)
+}
+
+/// Converts a coefficient of performance to an energy efficiency ratio in
+/// BTU/h per watt.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::hvac::eer_from_cop;
+///
+/// assert!((eer_from_cop(3.0) - 10.2364).abs() < 1e-4);
+/// ```
+pub fn eer_from_cop(cop: f64) -> f64 {
+    BtuPerHour::convert_from(cop)
+}
+
+/// Returns the electrical input power needed to deliver the given heating or
+/// cooling capacity at the given coefficient of performance.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::hvac::electrical_input;
+/// use uom::unit_conversion::power::{Power, Kilowatt};
+///
+/// let heat_pump = Power::from_unit::<Kilowatt>(9.0);
+/// let input = electrical_input(heat_pump, 3.0);
+/// assert!((input.to_unit::<Kilowatt>() - 3.0).abs() < 1e-12);
+/// ```
+pub fn electrical_input(capacity: Power, cop: f64) -> Power {
+    Power::from_unit::<Watt>(capacity.to_unit::<Watt>() / cop)
+}
+
+/// Returns the heating or cooling capacity delivered by the given electrical
+/// input power at the given coefficient of performance.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::hvac::capacity_from_input;
+/// use uom::unit_conversion::power::{Power, Kilowatt, BtuPerHour};
+///
+/// let capacity = capacity_from_input(Power::from_unit::<Kilowatt>(1.0), 4.0);
+/// assert!((capacity.to_unit::<BtuPerHour>() - 13_648.57).abs() < 0.01);
+/// ```
+pub fn capacity_from_input(input: Power, cop: f64) -> Power {
+    Power::from_unit::<Watt>(input.to_unit::<Watt>() * cop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::power::TonOfRefrigeration;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn eer_cop_round_trip() {
+        assert!(approx(eer_from_cop(cop_from_eer(11.5)), 11.5, 1e-12));
+        assert!(approx(cop_from_eer(3.412141633), 1.0, 1e-9));
+    }
+
+    #[test]
+    fn input_and_capacity_are_inverse() {
+        let capacity = Power::from_unit::<TonOfRefrigeration>(2.0);
+        let input = electrical_input(capacity, 3.2);
+        let recovered = capacity_from_input(input, 3.2);
+        assert!(approx(
+            recovered.to_unit::<TonOfRefrigeration>(),
+            2.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn one_ton_at_eer_twelve_draws_one_kilowatt() {
+        let capacity = Power::from_unit::<TonOfRefrigeration>(1.0);
+        let input = electrical_input(capacity, cop_from_eer(12.0));
+        assert!(approx(input.to_unit::<Watt>(), 1000.0, 1e-9));
+    }
+}
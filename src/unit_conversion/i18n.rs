@@ -0,0 +1,421 @@
+//! # Localized Unit Names
+//!
+//! [`NAME`](UnitConversion::NAME) and [`PLURAL`](UnitConversion::PLURAL) are English,
+//! and English plural rules do not carry over: French treats 0 as singular ("0
+//! kilomètre"), German leaves most unit names unchanged ("5 Kilometer"), and other
+//! languages have more than two forms. A [`UnitCatalog`] holds translated unit names,
+//! and optionally locale-specific symbols, loaded from [Fluent](https://projectfluent.org)
+//! resources, and lets Fluent choose the plural form from the locale's CLDR rules.
+//!
+//! Each unit is a message whose identifier is `unit-` followed by the unit's English
+//! name, lowercased, with every run of other characters than ASCII letters and digits
+//! replaced by `-` (see [`message_id`]). The message value is the name and may select
+//! on `$count`, the number as it will be written; a `.symbol` attribute replaces the
+//! unit symbol:
+//!
+//! ```ftl
+//! unit-kilometer =
+//!     { $count ->
+//!         [one] kilomètre
+//!        *[other] kilomètres
+//!     }
+//! unit-foot =
+//!     { $count ->
+//!         [one] pied
+//!        *[other] pieds
+//!     }
+//!     .symbol = pi
+//! ```
+//!
+//! Units missing from a catalog fall back to their English name and usual symbol.
+//! French and German catalogs for common length, mass, temperature, time and volume
+//! units ship with the crate and are available from [`UnitCatalog::bundled`].
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::i18n::UnitCatalog;
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//!
+//! let run = Length::from_unit::<Kilometer>(10.0);
+//! let fr = UnitCatalog::bundled("fr-FR").unwrap();
+//! assert_eq!(run.localized::<Kilometer>(&fr).to_string(), "10 kilomètres");
+//!
+//! let de = UnitCatalog::bundled("de").unwrap();
+//! assert_eq!(run.localized::<Kilometer>(&de).to_string(), "10 Kilometer");
+//! ```
+
+use std::fmt;
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::unit_conversion::format::UnitFormatter;
+use crate::unit_conversion::names::{choose, unit_name};
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError, registry};
+
+/// Bundled catalogs by language subtag.
+const BUNDLED: &[(&str, &str)] = &[
+    ("de", include_str!("../../locales/de.ftl")),
+    ("fr", include_str!("../../locales/fr.ftl")),
+];
+
+/// Returns the Fluent message identifier for the unit whose English name is `name`.
+///
+/// Returns `None` for a name with no ASCII letters or digits, such as the `%` of a
+/// unit without a spelled-out name.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::i18n::message_id;
+///
+/// assert_eq!(message_id("kilometer").as_deref(), Some("unit-kilometer"));
+/// assert_eq!(message_id("degree Celsius").as_deref(), Some("unit-degree-celsius"));
+/// assert_eq!(message_id("%"), None);
+/// ```
+pub fn message_id(name: &str) -> Option<String> {
+    let mut id = String::from("unit");
+    for word in name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        id.push('-');
+        id.push_str(&word.to_ascii_lowercase());
+    }
+    (id.len() > "unit".len()).then_some(id)
+}
+
+/// Translated unit names and symbols for one locale.
+///
+/// Build a catalog from the locale and Fluent sources with [`from_ftl`](Self::from_ftl)
+/// or [`add_ftl`](Self::add_ftl), or start from a bundled one.
+pub struct UnitCatalog {
+    bundle: FluentBundle<FluentResource>,
+}
+
+impl fmt::Debug for UnitCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnitCatalog")
+            .field("locale", &self.locale())
+            .finish_non_exhaustive()
+    }
+}
+
+impl UnitCatalog {
+    /// Creates an empty catalog for `locale`, a BCP 47 language tag such as `"fr-CA"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if `locale` is not a valid language tag.
+    pub fn new(locale: &str) -> Result<Self, UnitError> {
+        let langid: LanguageIdentifier = locale.parse().map_err(|_| UnitError::ParseError {
+            input: locale.to_string(),
+            reason: "invalid language tag",
+        })?;
+        let mut bundle = FluentBundle::new(vec![langid]);
+        bundle.set_use_isolating(false);
+        Ok(Self { bundle })
+    }
+
+    /// Creates a catalog for `locale` from the Fluent resource `source`, such as the
+    /// contents of a `.ftl` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if `locale` is not a valid language tag or
+    /// `source` is not valid Fluent syntax.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::i18n::UnitCatalog;
+    /// use uom::unit_conversion::length::{Length, Meter};
+    ///
+    /// let es = UnitCatalog::from_ftl(
+    ///     "es",
+    ///     "unit-meter = { $count ->\n [one] metro\n *[other] metros\n}",
+    /// )
+    /// .unwrap();
+    /// let q = Length::from_unit::<Meter>(1.0);
+    /// assert_eq!(q.localized::<Meter>(&es).to_string(), "1 metro");
+    /// ```
+    pub fn from_ftl(locale: &str, source: &str) -> Result<Self, UnitError> {
+        let mut catalog = Self::new(locale)?;
+        catalog.add_ftl(source)?;
+        Ok(catalog)
+    }
+
+    /// Returns the catalog shipped with the crate for the language of `locale`, or
+    /// `None` if there is none.
+    ///
+    /// Only the language subtag is used, so `"fr-CA"` and `"fr_FR.UTF-8"` both select
+    /// the French catalog.
+    pub fn bundled(locale: &str) -> Option<Self> {
+        let language = locale.split(['-', '_', '.']).next()?.to_ascii_lowercase();
+        let (_, source) = BUNDLED.iter().find(|(code, _)| *code == language)?;
+        let mut catalog = Self::new(&language).ok()?;
+        catalog.add_ftl(source).ok()?;
+        Some(catalog)
+    }
+
+    /// Adds the messages of the Fluent resource `source` to the catalog.
+    ///
+    /// A message already in the catalog is replaced, so a bundled catalog can be
+    /// corrected or extended with an application's own file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if `source` is not valid Fluent syntax; the
+    /// catalog is left unchanged.
+    pub fn add_ftl(&mut self, source: &str) -> Result<(), UnitError> {
+        let resource =
+            FluentResource::try_new(source.to_string()).map_err(|_| UnitError::ParseError {
+                input: source.to_string(),
+                reason: "invalid Fluent resource",
+            })?;
+        self.bundle.add_resource_overriding(resource);
+        Ok(())
+    }
+
+    /// Returns the catalog's locale as a language tag.
+    pub fn locale(&self) -> String {
+        self.bundle
+            .locales
+            .first()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    /// Returns the localized name of the unit whose English name is `name`, in the form
+    /// that agrees with `number`, or `None` if the catalog does not translate it.
+    pub fn translate(&self, name: &str, number: &str) -> Option<String> {
+        let message = self.bundle.get_message(&message_id(name)?)?;
+        let mut args = FluentArgs::new();
+        args.set("count", FluentValue::try_number(number));
+        let mut errors = Vec::new();
+        let text = self
+            .bundle
+            .format_pattern(message.value()?, Some(&args), &mut errors);
+        errors.is_empty().then(|| text.into_owned())
+    }
+
+    /// Returns the localized symbol of the unit whose English name is `name`, or `None`
+    /// if the catalog keeps the usual symbol.
+    pub fn translate_symbol(&self, name: &str) -> Option<String> {
+        let message = self.bundle.get_message(&message_id(name)?)?;
+        let mut errors = Vec::new();
+        let text =
+            self.bundle
+                .format_pattern(message.get_attribute("symbol")?.value(), None, &mut errors);
+        errors.is_empty().then(|| text.into_owned())
+    }
+
+    /// Returns the name of unit `U` that agrees with `number`, the number as it will
+    /// be written, falling back to the English name.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::i18n::UnitCatalog;
+    /// use uom::unit_conversion::length::{Kilometer, Meter};
+    ///
+    /// let fr = UnitCatalog::bundled("fr").unwrap();
+    /// assert_eq!(fr.unit_name::<Kilometer>("0"), "kilomètre");
+    /// assert_eq!(fr.unit_name::<Kilometer>("2"), "kilomètres");
+    /// ```
+    pub fn unit_name<U: UnitConversion>(&self, number: &str) -> String {
+        self.translate(U::NAME, number)
+            .unwrap_or_else(|| unit_name::<U>(number).to_string())
+    }
+
+    /// Returns the symbol of unit `U` in this locale, falling back to the usual symbol.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::i18n::UnitCatalog;
+    /// use uom::unit_conversion::length::{Foot, Meter};
+    ///
+    /// let fr = UnitCatalog::bundled("fr-CA").unwrap();
+    /// assert_eq!(fr.unit_symbol::<Foot>(), "pi");
+    /// assert_eq!(fr.unit_symbol::<Meter>(), "m");
+    /// ```
+    pub fn unit_symbol<U: UnitConversion>(&self) -> String {
+        self.translate_symbol(U::NAME)
+            .unwrap_or_else(|| U::SYMBOL.to_string())
+    }
+
+    /// Returns `formatter` with a symbol override for every unit of the enabled
+    /// dimensions that this catalog writes with a different symbol.
+    ///
+    /// Overrides already in `formatter` for those symbols are replaced.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::format::UnitFormatter;
+    /// use uom::unit_conversion::i18n::UnitCatalog;
+    /// use uom::unit_conversion::length::{Foot, Length};
+    ///
+    /// let fr = UnitCatalog::bundled("fr-CA").unwrap();
+    /// let style = fr.formatter(UnitFormatter::new());
+    /// let q = Length::from_unit::<Foot>(6.0);
+    /// assert_eq!(q.format_with::<Foot>(&style).to_string(), "6 pi");
+    /// ```
+    pub fn formatter(&self, formatter: UnitFormatter) -> UnitFormatter {
+        registry::DIMENSIONS
+            .iter()
+            .flat_map(|dimension| dimension.units)
+            .filter_map(|unit| Some((unit.symbol, self.translate_symbol(unit.name)?)))
+            .fold(formatter, |formatter, (symbol, local)| {
+                formatter.with_symbol(symbol, local)
+            })
+    }
+}
+
+/// A quantity displayed as a number followed by its unit name in a catalog's language.
+///
+/// Created by [`Quantity::localized`]. The formatter's precision, or else the installed
+/// [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies to the number, and
+/// the name is chosen after rounding.
+#[derive(Clone, Copy, Debug)]
+pub struct Localized<'a> {
+    value: f64,
+    name: &'static str,
+    plural: &'static str,
+    dimension: &'static str,
+    catalog: &'a UnitCatalog,
+}
+
+impl Localized<'_> {
+    /// Returns the numeric value in the display unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl fmt::Display for Localized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = format_number(
+            self.value,
+            f.precision(),
+            Subject::Dimension(self.dimension),
+        );
+        let name = self
+            .catalog
+            .translate(self.name, &number)
+            .unwrap_or_else(|| choose(&number, self.name, self.plural).to_string());
+        if name.is_empty() {
+            f.write_str(&number)
+        } else {
+            write!(f, "{number} {name}")
+        }
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Returns a displayable form of this quantity in unit `V` with the unit's name in
+    /// the language of `catalog`, agreeing with the number.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::i18n::UnitCatalog;
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let fr = UnitCatalog::bundled("fr").unwrap();
+    /// let t = Temperature::from_unit::<Celsius>(1.5);
+    /// assert_eq!(t.localized::<Celsius>(&fr).to_string(), "1.5 degré Celsius");
+    /// assert_eq!(format!("{:.0}", t.localized::<Celsius>(&fr)), "2 degrés Celsius");
+    /// ```
+    pub fn localized<'a, V>(&self, catalog: &'a UnitCatalog) -> Localized<'a>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Localized {
+            value: self.to_unit::<V>(),
+            name: V::NAME,
+            plural: V::PLURAL,
+            dimension: <U::Dimension as Dimension>::NAME,
+            catalog,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Kilometer, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+
+    #[test]
+    fn message_ids() {
+        assert_eq!(message_id("meter").unwrap(), "unit-meter");
+        assert_eq!(message_id("US fluid ounce").unwrap(), "unit-us-fluid-ounce");
+        assert_eq!(message_id("30-day month").unwrap(), "unit-30-day-month");
+        assert_eq!(message_id("degree Rømer").unwrap(), "unit-degree-r-mer");
+        assert_eq!(message_id(""), None);
+    }
+
+    #[test]
+    fn plural_rules_follow_the_locale() {
+        let fr = UnitCatalog::bundled("fr").unwrap();
+        let de = UnitCatalog::bundled("de").unwrap();
+        let zero = Length::from_unit::<Meter>(0.0);
+        assert_eq!(zero.localized::<Meter>(&fr).to_string(), "0 mètre");
+        assert_eq!(zero.localized::<Meter>(&de).to_string(), "0 Meter");
+        assert_eq!(zero.named::<Meter>().to_string(), "0 meters");
+
+        let one = Length::from_unit::<Kilometer>(1.0);
+        assert_eq!(one.localized::<Kilometer>(&fr).to_string(), "1 kilomètre");
+        assert_eq!(
+            format!("{:.1}", one.localized::<Kilometer>(&fr)),
+            "1.0 kilomètre"
+        );
+        assert_eq!(fr.unit_name::<Kilometer>("2.5"), "kilomètres");
+    }
+
+    #[test]
+    fn falls_back_to_english() {
+        let empty = UnitCatalog::new("it").unwrap();
+        let q = Temperature::from_unit::<Kelvin>(2.0);
+        assert_eq!(q.localized::<Kelvin>(&empty).to_string(), "2 kelvins");
+        assert_eq!(empty.unit_symbol::<Celsius>(), "°C");
+        assert!(UnitCatalog::bundled("it").is_none());
+    }
+
+    #[test]
+    fn later_sources_override_earlier_ones() {
+        let mut fr = UnitCatalog::bundled("fr-CA").unwrap();
+        assert_eq!(fr.locale(), "fr");
+        fr.add_ftl("unit-foot = pied\n    .symbol = ′").unwrap();
+        assert_eq!(fr.unit_name::<Foot>("3"), "pied");
+        assert_eq!(fr.unit_symbol::<Foot>(), "′");
+        assert_eq!(fr.unit_name::<Meter>("3"), "mètres");
+
+        let style = fr.formatter(UnitFormatter::new().with_symbol("ft", "feet"));
+        assert_eq!(style.symbol("ft"), "′");
+        assert_eq!(style.symbol("m"), "m");
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(UnitCatalog::new("not a locale!").is_err());
+        assert!(UnitCatalog::from_ftl("fr", "unit-meter = {").is_err());
+        let mut de = UnitCatalog::bundled("de").unwrap();
+        assert!(de.add_ftl("= broken").is_err());
+        assert_eq!(de.unit_name::<Meter>("2"), "Meter");
+    }
+}
@@ -1,13 +1,21 @@
 //! # Length Unit Conversions
 //!
-//! This module provides type-safe length conversions between meters, kilometers, and feet.
+//! This module provides type-safe length conversions between meters, kilometers, centimeters,
+//! millimeters, feet, inches, miles, and nautical miles.
 //! All lengths are internally stored in meters (the base unit) and converted on demand.
 //!
 //! ## Supported Units
 //!
 //! - **Meter (m)**: The base unit, SI unit of length
 //! - **Kilometer (km)**: 1000 meters
+//! - **Centimeter (cm)**: 0.01 meters
+//! - **Millimeter (mm)**: 0.001 meters
 //! - **Foot (ft)**: Imperial unit, exactly 0.3048 meters
+//! - **Inch (in)**: Imperial unit, exactly 0.0254 meters
+//! - **Mile (mi)**: Imperial unit, exactly 1609.344 meters
+//! - **Nautical mile (nmi)**: International nautical mile, exactly 1852 meters
+//!
+//! [`ScreenContext`] converts lengths to and from pixels for a given display density.
 //!
 //! ## Examples
 //!
@@ -37,7 +45,6 @@
 // Meter (m) – SI base unit of length.
 // Decimeter (dm) – 0.1 meters.
 // Centimeter (cm) – 0.01 meters.
-// Millimeter (mm) – 0.001 meters.
 // Micrometer (µm) – 10⁻⁶ meters.
 // Nanometer (nm) – 10⁻⁹ meters.
 // Picometer (pm) – 10⁻¹² meters.
@@ -45,11 +52,18 @@
 // Planck length (ℓₚ) – ~1.616 × 10⁻³⁵ meters
 // */
 
-use crate::unit_conversion::{Quantity, UnitConversion};
+use crate::unit_conversion::{
+    Dimension, Quantity, UnitConversion, UnitError, UnitInfo, check_positive,
+};
 
 /// Constants for length conversions
 const METERS_PER_KILOMETER: f64 = 1000.0;
+const METERS_PER_CENTIMETER: f64 = 0.01;
+const METERS_PER_MILLIMETER: f64 = 0.001;
 const METERS_PER_FOOT: f64 = 0.3048; // Exact definition
+const METERS_PER_INCH: f64 = 0.0254; // Exact definition
+const METERS_PER_MILE: f64 = 1609.344; // Exact definition
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0; // Exact definition
 
 /// Marker type for the length dimension.
 ///
@@ -57,10 +71,25 @@ const METERS_PER_FOOT: f64 = 0.3048; // Exact definition
 /// like temperature or mass.
 pub enum LengthDimension {}
 
+impl Dimension for LengthDimension {
+    const NAME: &'static str = "length";
+    const ID: u8 = 1;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Meter>(&["meter", "meters", "metre", "metres"]),
+        UnitInfo::of::<Kilometer>(&["kilometer", "kilometers", "kilometre", "kilometres"]),
+        UnitInfo::of::<Centimeter>(&["centimeter", "centimeters", "centimetre", "centimetres"]),
+        UnitInfo::of::<Millimeter>(&["millimeter", "millimeters", "millimetre", "millimetres"]),
+        UnitInfo::of::<Foot>(&["foot", "feet", "'", "′"]),
+        UnitInfo::of::<Inch>(&["inch", "inches", "\"", "″"]),
+        UnitInfo::of::<Mile>(&["mile", "miles"]),
+        UnitInfo::of::<NauticalMile>(&["NM", "nautical mile", "nautical miles"]),
+    ];
+}
+
 /// A length quantity that stores values in meters internally.
 ///
 /// This is the main type for working with lengths. It provides type-safe
-/// conversions between meters, kilometers, and feet.
+/// conversions between meters, kilometers, feet, and inches.
 ///
 /// # Examples
 ///
@@ -72,6 +101,57 @@ pub enum LengthDimension {}
 /// ```
 pub type Length = Quantity<Meter>;
 
+/// The pixel density of a display, used to move lengths between physical and pixel
+/// space.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Inch, Length, Millimeter, ScreenContext};
+///
+/// let retina = ScreenContext { dpi: 220.0 };
+/// let margin = Length::from_unit::<Millimeter>(5.0);
+/// assert!((margin.to_pixels(&retina) - 43.307).abs() < 1e-3);
+///
+/// let icon = Length::from_pixels(48.0, &ScreenContext::CSS);
+/// assert_eq!(icon.to_unit::<Inch>(), 0.5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScreenContext {
+    /// Pixels per inch; must be positive.
+    pub dpi: f64,
+}
+
+impl ScreenContext {
+    /// The CSS reference density of 96 pixels per inch.
+    pub const CSS: ScreenContext = ScreenContext { dpi: 96.0 };
+
+    /// Creates a context for a display with `dpi` pixels per inch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `dpi` is not positive and finite.
+    pub fn new(dpi: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            dpi: check_positive(dpi)?,
+        })
+    }
+}
+
+impl Length {
+    /// Creates the physical length covered by `px` pixels on a display described by
+    /// `ctx`.
+    pub fn from_pixels(px: f64, ctx: &ScreenContext) -> Self {
+        Self::from_unit::<Inch>(px / ctx.dpi)
+    }
+
+    /// Returns the number of pixels this length covers on a display described by
+    /// `ctx`. The result is not rounded.
+    pub fn to_pixels(&self, ctx: &ScreenContext) -> f64 {
+        self.to_unit::<Inch>() * ctx.dpi
+    }
+}
+
 /// Meter length unit (SI base unit).
 ///
 /// The meter is the base unit for length in this system and in the International
@@ -101,6 +181,8 @@ impl UnitConversion for Meter {
     }
 
     const SYMBOL: &'static str = "m";
+    const NAME: &'static str = "meter";
+    const PLURAL: &'static str = "meters";
 }
 
 /// Kilometer length unit.
@@ -132,6 +214,73 @@ impl UnitConversion for Kilometer {
     }
 
     const SYMBOL: &'static str = "km";
+    const NAME: &'static str = "kilometer";
+    const PLURAL: &'static str = "kilometers";
+}
+
+/// Centimeter length unit.
+///
+/// A centimeter is exactly one hundredth of a meter.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, Centimeter, Inch};
+///
+/// let inch = Length::from_unit::<Inch>(1.0);
+/// assert!((inch.to_unit::<Centimeter>() - 2.54).abs() < 1e-12);
+/// ```
+pub struct Centimeter;
+
+impl UnitConversion for Centimeter {
+    type Dimension = LengthDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_CENTIMETER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_CENTIMETER
+    }
+
+    const SYMBOL: &'static str = "cm";
+    const NAME: &'static str = "centimeter";
+    const PLURAL: &'static str = "centimeters";
+}
+
+/// Millimeter length unit.
+///
+/// A millimeter is exactly one thousandth of a meter, the usual unit for
+/// engineering drawings and paper sizes.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, Millimeter, Meter};
+///
+/// let sheet_width = Length::from_unit::<Millimeter>(210.0);
+/// assert!((sheet_width.to_unit::<Meter>() - 0.21).abs() < 1e-12);
+/// ```
+pub struct Millimeter;
+
+impl UnitConversion for Millimeter {
+    type Dimension = LengthDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_MILLIMETER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_MILLIMETER
+    }
+
+    const SYMBOL: &'static str = "mm";
+    const NAME: &'static str = "millimeter";
+    const PLURAL: &'static str = "millimeters";
 }
 
 /// Foot length unit (Imperial).
@@ -163,6 +312,106 @@ impl UnitConversion for Foot {
     }
 
     const SYMBOL: &'static str = "ft";
+    const NAME: &'static str = "foot";
+    const PLURAL: &'static str = "feet";
+}
+
+/// Inch length unit (Imperial).
+///
+/// The international inch is defined as exactly 0.0254 meters, one twelfth of
+/// a foot.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, Inch, Foot};
+///
+/// let ruler = Length::from_unit::<Inch>(12.0);
+/// assert!((ruler.to_unit::<Foot>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct Inch;
+
+impl UnitConversion for Inch {
+    type Dimension = LengthDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_INCH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_INCH
+    }
+
+    const SYMBOL: &'static str = "in";
+    const NAME: &'static str = "inch";
+    const PLURAL: &'static str = "inches";
+}
+
+/// Mile length unit (Imperial).
+///
+/// The international mile is defined as exactly 1609.344 meters, or 5280 feet.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, Mile, Foot};
+///
+/// let mile = Length::from_unit::<Mile>(1.0);
+/// assert!((mile.to_unit::<Foot>() - 5280.0).abs() < 1e-9);
+/// ```
+pub struct Mile;
+
+impl UnitConversion for Mile {
+    type Dimension = LengthDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_MILE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_MILE
+    }
+
+    const SYMBOL: &'static str = "mi";
+    const NAME: &'static str = "mile";
+    const PLURAL: &'static str = "miles";
+}
+
+/// Nautical mile length unit, used at sea and in aviation.
+///
+/// The international nautical mile is defined as exactly 1852 meters, close to one
+/// minute of arc of latitude.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Kilometer, Length, NauticalMile};
+///
+/// let leg = Length::from_unit::<NauticalMile>(10.0);
+/// assert!((leg.to_unit::<Kilometer>() - 18.52).abs() < 1e-12);
+/// ```
+pub struct NauticalMile;
+
+impl UnitConversion for NauticalMile {
+    type Dimension = LengthDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_NAUTICAL_MILE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_NAUTICAL_MILE
+    }
+
+    const SYMBOL: &'static str = "nmi";
+    const NAME: &'static str = "nautical mile";
+    const PLURAL: &'static str = "nautical miles";
 }
 
 #[cfg(test)]
@@ -245,10 +494,69 @@ mod tests {
         assert!(approx(length.to_unit::<Kilometer>(), 2.0, 1e-8));
     }
 
+    #[test]
+    fn pixel_conversions() {
+        let ctx = ScreenContext::new(300.0).unwrap();
+        let length = Length::from_pixels(300.0, &ctx);
+        assert!(approx(length.to_unit::<Meter>(), 0.0254, 1e-12));
+        assert!(approx(
+            Length::from_unit::<Centimeter>(2.54).to_pixels(&ctx),
+            300.0,
+            1e-9
+        ));
+        assert!(approx(
+            Length::from_pixels(96.0, &ScreenContext::CSS).to_unit::<Inch>(),
+            1.0,
+            1e-12
+        ));
+        assert!(ScreenContext::new(0.0).is_err());
+        assert!(ScreenContext::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn inch_conversions() {
+        let length = Length::from_unit::<Inch>(1.0);
+        assert!(approx(length.to_unit::<Meter>(), 0.0254, 1e-12));
+
+        let length = Length::from_unit::<Foot>(1.0);
+        assert!(approx(length.to_unit::<Inch>(), 12.0, 1e-12));
+    }
+
+    #[test]
+    fn centimeter_conversions() {
+        let length = Length::from_unit::<Centimeter>(100.0);
+        assert!(approx(length.to_unit::<Meter>(), 1.0, 1e-12));
+
+        let length = Length::from_unit::<Foot>(1.0);
+        assert!(approx(length.to_unit::<Centimeter>(), 30.48, 1e-12));
+    }
+
+    #[test]
+    fn millimeter_conversions() {
+        let length = Length::from_unit::<Millimeter>(1000.0);
+        assert!(approx(length.to_unit::<Meter>(), 1.0, 1e-12));
+
+        let length = Length::from_unit::<Inch>(1.0);
+        assert!(approx(length.to_unit::<Millimeter>(), 25.4, 1e-12));
+    }
+
+    #[test]
+    fn mile_conversions() {
+        let length = Length::from_unit::<Mile>(1.0);
+        assert!(approx(length.to_unit::<Meter>(), 1609.344, 1e-12));
+
+        let length = Length::from_unit::<Kilometer>(100.0);
+        assert!(approx(length.to_unit::<Mile>(), 62.137119224, 1e-9));
+    }
+
     #[test]
     fn length_symbols() {
         assert_eq!(Meter::SYMBOL, "m");
         assert_eq!(Kilometer::SYMBOL, "km");
+        assert_eq!(Centimeter::SYMBOL, "cm");
+        assert_eq!(Millimeter::SYMBOL, "mm");
         assert_eq!(Foot::SYMBOL, "ft");
+        assert_eq!(Inch::SYMBOL, "in");
+        assert_eq!(Mile::SYMBOL, "mi");
     }
 }
@@ -0,0 +1,107 @@
+//! # Light-Travel Helpers
+//!
+//! Light covers a fixed distance in a given time, so distances and durations can stand
+//! in for each other: astronomers quote distances in light-seconds, and network
+//! engineers bound latency by how long a signal takes to cross a link. This module
+//! converts between typed [`Length`] and [`Time`] values through
+//! [`SPEED_OF_LIGHT`] in vacuum.
+//!
+//! Signals in optical fiber or copper travel slower than this, typically at about two
+//! thirds of the vacuum speed, so these helpers give a lower bound on latency.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//! use uom::unit_conversion::time::{Minute, Second, Time};
+//!
+//! // Sunlight is about 8.3 minutes old
+//! let sun = Length::from_unit::<Kilometer>(149_597_870.7);
+//! assert!((sun.light_travel_time().to_unit::<Minute>() - 8.317).abs() < 1e-3);
+//!
+//! // A 10 ms round trip can reach at most about 1500 km away
+//! let one_way = Time::from_unit::<Second>(0.005);
+//! assert!((one_way.light_travel_distance().to_unit::<Kilometer>() - 1498.96).abs() < 0.01);
+//! ```
+
+use crate::unit_conversion::Quantity;
+use crate::unit_conversion::length::{Length, Meter};
+use crate::unit_conversion::speed::{MeterPerSecond, SPEED_OF_LIGHT};
+use crate::unit_conversion::time::{Second, Time};
+
+impl Quantity<Meter> {
+    /// Returns the time light takes to travel this distance in vacuum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::length::{Length, Meter};
+    /// use uom::unit_conversion::time::Second;
+    ///
+    /// let one_light_second = Length::from_unit::<Meter>(299_792_458.0);
+    /// assert_eq!(one_light_second.light_travel_time().to_unit::<Second>(), 1.0);
+    /// ```
+    pub fn light_travel_time(self) -> Time {
+        Time::from_unit::<Second>(
+            self.to_unit::<Meter>() / SPEED_OF_LIGHT.to_unit::<MeterPerSecond>(),
+        )
+    }
+}
+
+impl Quantity<Second> {
+    /// Returns the distance light travels in vacuum in this time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::length::Kilometer;
+    /// use uom::unit_conversion::time::{Second, Time};
+    ///
+    /// let nanosecond = Time::from_unit::<Second>(1e-9);
+    /// // Light moves about 30 cm per nanosecond
+    /// assert!((nanosecond.light_travel_distance().to_unit::<Kilometer>() - 2.998e-4).abs() < 1e-7);
+    /// ```
+    pub fn light_travel_distance(self) -> Length {
+        Length::from_unit::<Meter>(
+            self.to_unit::<Second>() * SPEED_OF_LIGHT.to_unit::<MeterPerSecond>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::Kilometer;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn moon_distance() {
+        let moon = Length::from_unit::<Kilometer>(384_400.0);
+        assert!(approx(
+            moon.light_travel_time().to_unit::<Second>(),
+            1.2822,
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn round_trips() {
+        for meters in [0.0, 1.0, 3.0e8, 1.5e11] {
+            let distance = Length::from_unit::<Meter>(meters);
+            let back = distance.light_travel_time().light_travel_distance();
+            assert!(approx(back.to_unit::<Meter>(), meters, meters * 1e-15));
+        }
+    }
+
+    #[test]
+    fn negative_values_keep_their_sign() {
+        let time = Time::from_unit::<Second>(-1.0);
+        assert_eq!(
+            time.light_travel_distance().to_unit::<Meter>(),
+            -299_792_458.0
+        );
+    }
+}
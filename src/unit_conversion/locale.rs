@@ -0,0 +1,270 @@
+//! # Locale Unit Preferences
+//!
+//! Which unit reads naturally depends on where the reader is: a distance is shown in
+//! miles in the United States and the United Kingdom and in kilometers almost
+//! everywhere else, while a temperature is shown in degrees Fahrenheit only in the
+//! United States. [`UnitPreferences::for_locale`] maps a locale or region to the units
+//! used there, starting from a [`UnitSystem`] and overriding individual dimensions:
+//!
+//! | Region | Distance | Temperature | Pressure | Volume |
+//! |--------|----------|-------------|----------|--------|
+//! | US, Liberia, Myanmar | mi | °F | psi | US gal |
+//! | United Kingdom | mi | °C | kPa | L |
+//! | Everywhere else | km | °C | kPa | L |
+//!
+//! [`Quantity::autoscale`] then picks among a dimension's preferred units the one that
+//! keeps the number readable, so 0.3 km is written as 300 m.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::locale::UnitPreferences;
+//!
+//! let walk = Length::from_unit::<Meter>(2500.0);
+//! let us = UnitPreferences::for_locale("en-US");
+//! assert_eq!(format!("{:.2}", walk.autoscale(&us).unwrap()), "1.55 mi");
+//!
+//! let de = UnitPreferences::for_locale("de_DE.UTF-8");
+//! assert_eq!(format!("{}", walk.autoscale(&de).unwrap()), "2.5 km");
+//! assert_eq!(de.unit_for("temperature"), Some("°C"));
+//! ```
+
+use crate::unit_conversion::registry::DimensionInfo;
+use crate::unit_conversion::system::{SystemValue, UnitSystem};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Per-dimension overrides on top of a system, the preferred unit first.
+type Overrides = &'static [(&'static str, &'static [&'static str])];
+
+/// The United Kingdom: metric, except for road distances and speeds.
+const UNITED_KINGDOM: Overrides = &[("length", &["mi", "ft", "in"]), ("speed", &["mph"])];
+
+/// Regions that use US customary units.
+const US_CUSTOMARY_REGIONS: &[&str] = &["US", "LR", "MM"];
+
+/// The units preferred for each dimension in some locale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitPreferences {
+    system: UnitSystem,
+    overrides: Overrides,
+}
+
+impl UnitPreferences {
+    /// Returns the preferences of the locale `tag`, given as a BCP 47 tag (`"en-GB"`),
+    /// a POSIX locale (`"en_GB.UTF-8"`) or a bare region code (`"GB"`).
+    ///
+    /// Only the region matters; a tag without one, or with an unrecognized one, gets
+    /// the metric preferences used in most of the world. `"UK"` is accepted for the
+    /// United Kingdom.
+    pub fn for_locale(tag: &str) -> Self {
+        match region(tag).as_deref() {
+            Some(code) if US_CUSTOMARY_REGIONS.contains(&code) => UnitSystem::UsCustomary.into(),
+            Some("GB" | "UK") => Self {
+                system: UnitSystem::Si,
+                overrides: UNITED_KINGDOM,
+            },
+            _ => UnitSystem::Si.into(),
+        }
+    }
+
+    /// Returns the preferences of the locale in the environment, read from `LC_ALL`,
+    /// `LC_MEASUREMENT` and `LANG` in that order.
+    pub fn from_env() -> Self {
+        let tag = ["LC_ALL", "LC_MEASUREMENT", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        Self::for_locale(&tag)
+    }
+
+    /// Returns the system the preferences start from.
+    pub fn system(&self) -> UnitSystem {
+        self.system
+    }
+
+    /// Returns the symbols of the preferred units for `dimension`, the default first,
+    /// or an empty slice if there are none.
+    pub fn units(&self, dimension: &str) -> &'static [&'static str] {
+        match self.overrides.iter().find(|(name, _)| *name == dimension) {
+            Some((_, units)) => units,
+            None => self.system.units(dimension),
+        }
+    }
+
+    /// Returns the symbol of the default unit for `dimension`.
+    pub fn unit_for(&self, dimension: &str) -> Option<&'static str> {
+        self.units(dimension).first().copied()
+    }
+
+    /// Returns true if the unit `symbol` of `dimension` is one of the preferred ones.
+    pub fn contains(&self, dimension: &str, symbol: &str) -> bool {
+        self.units(dimension).contains(&symbol)
+    }
+
+    /// Picks the preferred unit of dimension `D` for `base`, a value in base units.
+    ///
+    /// Starting from the default unit, a value whose magnitude is below 1 moves to the
+    /// next smaller preferred unit until it is at least 1. Units with an offset, such
+    /// as degrees Celsius, never change.
+    pub fn autoscale_unit<D: Dimension>(&self, base: f64) -> Option<&'static UnitInfo> {
+        self.pick(D::NAME, D::find_unit, base)
+    }
+
+    /// Picks the preferred unit of a dimension known only at runtime, as
+    /// [`autoscale_unit`](Self::autoscale_unit) does.
+    pub fn autoscale_in(&self, dimension: &DimensionInfo, base: f64) -> Option<&'static UnitInfo> {
+        self.pick(dimension.name, |symbol| dimension.find_unit(symbol), base)
+    }
+
+    fn pick(
+        &self,
+        dimension: &str,
+        find: impl Fn(&str) -> Option<&'static UnitInfo>,
+        base: f64,
+    ) -> Option<&'static UnitInfo> {
+        let units: Vec<&'static UnitInfo> = self
+            .units(dimension)
+            .iter()
+            .filter_map(|symbol| find(symbol))
+            .collect();
+        let mut chosen = *units.first()?;
+        if (chosen.to_base)(0.0) != 0.0 {
+            return Some(chosen);
+        }
+        let mut smaller: Vec<&'static UnitInfo> = units
+            .into_iter()
+            .filter(|unit| (unit.to_base)(0.0) == 0.0 && size(unit) < size(chosen))
+            .collect();
+        smaller.sort_by(|a, b| size(b).total_cmp(&size(a)));
+        for unit in smaller {
+            if (chosen.from_base)(base).abs() >= 1.0 || base == 0.0 {
+                break;
+            }
+            chosen = unit;
+        }
+        Some(chosen)
+    }
+}
+
+impl From<UnitSystem> for UnitPreferences {
+    fn from(system: UnitSystem) -> Self {
+        Self {
+            system,
+            overrides: &[],
+        }
+    }
+}
+
+/// The size of one `unit` in base units.
+fn size(unit: &UnitInfo) -> f64 {
+    (unit.to_base)(1.0)
+}
+
+/// Extracts the uppercase region code from a locale tag.
+fn region(tag: &str) -> Option<String> {
+    let tag = tag.split(['.', '@']).next()?.trim();
+    let mut parts = tag.split(['-', '_']).filter(|part| !part.is_empty());
+    let first = parts.next()?;
+    let rest: Vec<&str> = parts.collect();
+    let code = if rest.is_empty() {
+        first
+    } else {
+        rest.into_iter()
+            .find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()))?
+    };
+    (code.len() == 2).then(|| code.to_ascii_uppercase())
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Converts the quantity to the preferred unit that keeps its number readable (see
+    /// [`UnitPreferences::autoscale_unit`]).
+    ///
+    /// Returns `None` if there is no preferred unit for the dimension.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Kilometer, Length};
+    /// use uom::unit_conversion::locale::UnitPreferences;
+    ///
+    /// let gap = Length::from_unit::<Kilometer>(0.3);
+    /// let metric = UnitPreferences::for_locale("fr-FR");
+    /// assert_eq!(gap.autoscale(&metric).unwrap().to_string(), "300 m");
+    /// ```
+    pub fn autoscale(&self, preferences: &UnitPreferences) -> Option<SystemValue> {
+        let unit = preferences.autoscale_unit::<U::Dimension>(self.in_base())?;
+        Some(SystemValue {
+            value: (unit.from_base)(self.in_base()),
+            unit,
+            dimension: <U::Dimension as Dimension>::NAME,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Length, Meter, Mile};
+    use crate::unit_conversion::temperature::{Celsius, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn maps_regions_to_preferences() {
+        let us = UnitPreferences::for_locale("en_US.UTF-8");
+        assert_eq!(us.system(), UnitSystem::UsCustomary);
+        assert_eq!(us.unit_for("temperature"), Some("°F"));
+        assert_eq!(us.unit_for("pressure"), Some("psi"));
+
+        let uk = UnitPreferences::for_locale("en-GB");
+        assert_eq!(uk.unit_for("length"), Some("mi"));
+        assert_eq!(uk.unit_for("speed"), Some("mph"));
+        assert_eq!(uk.unit_for("temperature"), Some("°C"));
+        assert_eq!(UnitPreferences::for_locale("UK"), uk);
+
+        for tag in ["de-DE", "ja_JP", "fr", "", "C", "POSIX", "zh-Hant-TW"] {
+            let prefs = UnitPreferences::for_locale(tag);
+            assert_eq!(prefs.unit_for("length"), Some("km"), "{tag}");
+            assert_eq!(prefs.unit_for("temperature"), Some("°C"), "{tag}");
+        }
+    }
+
+    #[test]
+    fn autoscales_to_a_readable_unit() {
+        let metric = UnitPreferences::from(UnitSystem::Si);
+        let scaled = |meters: f64, prefs: &UnitPreferences| {
+            let shown = Length::from_unit::<Meter>(meters).autoscale(prefs).unwrap();
+            (shown.value, shown.unit.symbol)
+        };
+        assert_eq!(scaled(12_000.0, &metric), (12.0, "km"));
+        assert_eq!(scaled(300.0, &metric), (300.0, "m"));
+        assert_eq!(scaled(-0.05, &metric), (-5.0, "cm"));
+        assert_eq!(scaled(0.0001, &metric).1, "mm");
+        assert_eq!(scaled(0.0, &metric), (0.0, "km"));
+
+        let us = UnitPreferences::for_locale("US");
+        let (value, symbol) = scaled(Length::from_unit::<Mile>(0.1).in_base(), &us);
+        assert_eq!(symbol, "ft");
+        assert!(approx(value, 528.0, 1e-9));
+    }
+
+    #[test]
+    fn keeps_offset_units() {
+        let metric = UnitPreferences::for_locale("de");
+        let t = Temperature::from_unit::<Celsius>(-0.5)
+            .autoscale(&metric)
+            .unwrap();
+        assert_eq!(t.unit.symbol, "°C");
+        assert!(approx(t.value, -0.5, 1e-12));
+    }
+}
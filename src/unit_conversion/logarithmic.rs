@@ -0,0 +1,42 @@
+//! # Logarithmic Units
+//!
+//! Decibels and p-scales write a linear quantity as a scaled decimal logarithm of its
+//! ratio to a reference: `10 log10(P / 1 mW)` dBm, `20 log10(V / 1 V)` dBV,
+//! `−log10([H⁺] / 1 mol/L)` pH. [`LogarithmicUnit`] describes that mapping for a unit,
+//! so code can move between a level and its linear equivalent without repeating the
+//! formula, and reports can show both: with [`SmartF64::with_linear`] a level prints
+//! as "-3 dB (0.501×)" or "7 pH (1.00e-7 mol/L)".
+//!
+//! Levels of zero or negative linear values do not exist; [`LogarithmicUnit::from_linear`]
+//! returns −∞ and NaN for them, as the unit conversions do.
+//!
+//! The trait is implemented by the decibel units of the `ratio`, `power` and `voltage`
+//! features and by pH in the `concentration` feature; their documentation has examples.
+//!
+//! [`SmartF64::with_linear`]: crate::util::smart::SmartF64::with_linear
+
+use crate::unit_conversion::UnitConversion;
+
+/// A unit whose values are scaled decimal logarithms of a linear quantity.
+///
+/// A level `L` stands for the linear value `REFERENCE × 10^(L / LOG_FACTOR)`, written
+/// in [`LINEAR_SYMBOL`](Self::LINEAR_SYMBOL).
+pub trait LogarithmicUnit: UnitConversion {
+    /// The multiplier of the decimal logarithm: 10 for power quantities, 20 for
+    /// root-power quantities such as voltage, −1 for p-scales.
+    const LOG_FACTOR: f64;
+    /// The linear value at level zero, in the linear unit.
+    const REFERENCE: f64;
+    /// The symbol of the linear unit, `×` for a plain ratio.
+    const LINEAR_SYMBOL: &'static str;
+
+    /// Returns the linear value of `level`, in the linear unit.
+    fn to_linear(level: f64) -> f64 {
+        Self::REFERENCE * 10f64.powf(level / Self::LOG_FACTOR)
+    }
+
+    /// Returns the level of the linear value `linear`, given in the linear unit.
+    fn from_linear(linear: f64) -> f64 {
+        Self::LOG_FACTOR * (linear / Self::REFERENCE).log10()
+    }
+}
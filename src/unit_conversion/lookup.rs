@@ -0,0 +1,437 @@
+//! # Interpolation Tables
+//!
+//! Many physical relationships are published as tables rather than formulas: the
+//! output of a thermocouple at each temperature, the head a pump delivers at each flow
+//! rate, the temperature at each depth of a borehole. A [`LookupTable`] holds such a
+//! table with a unit on each axis, interpolates between its rows, and finds the input
+//! that produces a given output.
+//!
+//! Interpolation is [`Linear`](Interpolation::Linear) between neighbouring rows, or
+//! [`Cubic`](Interpolation::Cubic), a monotone piecewise cubic (Fritsch–Carlson) that
+//! follows curved data more closely without overshooting between rows. Both work on
+//! the base values of each axis, which gives the same result as working in any unit
+//! related to the base by a scale and an offset.
+//!
+//! Tables can be built in code or loaded from CSV text with the input in the first
+//! column and the output in the second, both in the table's units.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::lookup::LookupTable;
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//!
+//! let csv = "depth_m,temp_c\n0,12\n100,15\n200,21\n";
+//! let borehole = LookupTable::<Meter, Celsius>::from_csv(csv).unwrap();
+//!
+//! let t: Temperature = borehole.lookup(Length::from_unit::<Meter>(150.0)).unwrap();
+//! assert!((t.to_unit::<Celsius>() - 18.0).abs() < 1e-9);
+//!
+//! let depth: Length = borehole.inverse(Temperature::from_unit::<Celsius>(13.5)).unwrap();
+//! assert!((depth.to_unit::<Meter>() - 50.0).abs() < 1e-9);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, check_finite};
+
+/// How a [`LookupTable`] fills in values between its rows.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// A straight line between neighbouring rows.
+    #[default]
+    Linear,
+    /// A monotone cubic through the rows, smooth at each row and never overshooting
+    /// the values on either side.
+    Cubic,
+}
+
+/// A table mapping quantities in unit `X` to quantities in unit `Y`.
+///
+/// The table's rows are written in `X` and `Y`, but lookups take and return any
+/// quantity type of the same dimensions, usually inferred from the binding. The inputs
+/// must be strictly increasing. Lookups outside the table's input range fail rather
+/// than extrapolate.
+pub struct LookupTable<X: UnitConversion, Y: UnitConversion> {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// Slope at each row for cubic interpolation, in base units of `Y` per `X`.
+    slopes: Vec<f64>,
+    interpolation: Interpolation,
+    _units: PhantomData<(X, Y)>,
+}
+
+impl<X: UnitConversion, Y: UnitConversion> Clone for LookupTable<X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            xs: self.xs.clone(),
+            ys: self.ys.clone(),
+            slopes: self.slopes.clone(),
+            interpolation: self.interpolation,
+            _units: PhantomData,
+        }
+    }
+}
+
+impl<X: UnitConversion, Y: UnitConversion> std::fmt::Debug for LookupTable<X, Y> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LookupTable")
+            .field("xs", &self.xs)
+            .field("ys", &self.ys)
+            .field("interpolation", &self.interpolation)
+            .finish()
+    }
+}
+
+impl<X: UnitConversion, Y: UnitConversion> LookupTable<X, Y> {
+    /// Creates a linearly interpolated table from `(input, output)` rows.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnitError::OutOfRange`] if there are fewer than two rows, or an input is not
+    ///   greater than the one before it
+    /// - [`UnitError::NonFinite`] if any value is infinite or NaN
+    pub fn new<A, B>(rows: &[(Quantity<A>, Quantity<B>)]) -> Result<Self, UnitError>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        let (xs, ys) = rows.iter().map(|(x, y)| (x.in_base(), y.in_base())).unzip();
+        Self::from_base(xs, ys)
+    }
+
+    /// Creates a linearly interpolated table from rows written in units `X` and `Y`.
+    ///
+    /// # Errors
+    ///
+    /// As for [`new`](Self::new).
+    pub fn from_rows(rows: &[(f64, f64)]) -> Result<Self, UnitError> {
+        let (xs, ys) = rows
+            .iter()
+            .map(|&(x, y)| (X::convert_to(x), Y::convert_to(y)))
+            .unzip();
+        Self::from_base(xs, ys)
+    }
+
+    /// Reads a table from CSV text with the input, in `X`, in the first column and the
+    /// output, in `Y`, in the second.
+    ///
+    /// Columns may be separated by commas, semicolons or tabs. Blank lines and lines
+    /// starting with `#` are skipped, as is a first line that is not numeric, such as
+    /// a header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] for a line without exactly two numbers, and
+    /// otherwise as for [`new`](Self::new).
+    pub fn from_csv(text: &str) -> Result<Self, UnitError> {
+        let mut rows = Vec::new();
+        let lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for (i, line) in lines.enumerate() {
+            let fields: Vec<&str> = line.split([',', ';', '\t']).map(str::trim).collect();
+            let row = match fields[..] {
+                [x, y] => x.parse::<f64>().ok().zip(y.parse::<f64>().ok()),
+                _ => None,
+            };
+            match row {
+                Some(row) => rows.push(row),
+                None if i == 0 => continue,
+                None => {
+                    return Err(UnitError::ParseError {
+                        input: line.to_string(),
+                        reason: "expected two numeric columns",
+                    });
+                }
+            }
+        }
+        Self::from_rows(&rows)
+    }
+
+    fn from_base(xs: Vec<f64>, ys: Vec<f64>) -> Result<Self, UnitError> {
+        if xs.len() < 2 {
+            return Err(UnitError::OutOfRange {
+                value: xs.len() as f64,
+                min: 2.0,
+                max: f64::INFINITY,
+            });
+        }
+        for (&x, &y) in xs.iter().zip(&ys) {
+            check_finite(X::convert_from(x))?;
+            check_finite(Y::convert_from(y))?;
+        }
+        if let Some(pair) = xs.windows(2).find(|pair| pair[1] <= pair[0]) {
+            return Err(UnitError::OutOfRange {
+                value: X::convert_from(pair[1]),
+                min: X::convert_from(pair[0]),
+                max: f64::INFINITY,
+            });
+        }
+        let slopes = monotone_slopes(&xs, &ys);
+        Ok(Self {
+            xs,
+            ys,
+            slopes,
+            interpolation: Interpolation::Linear,
+            _units: PhantomData,
+        })
+    }
+
+    /// Returns the table with the given interpolation.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the table's interpolation.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Iterates over the table's rows, in units `X` and `Y`.
+    pub fn rows(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(&x, &y)| (X::convert_from(x), Y::convert_from(y)))
+    }
+
+    /// Returns the output at `x`, interpolated between the surrounding rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `x` lies outside the table's inputs.
+    pub fn lookup<A, B>(&self, x: Quantity<A>) -> Result<Quantity<B>, UnitError>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        let base = x.in_base();
+        let (first, last) = (self.xs[0], self.xs[self.xs.len() - 1]);
+        if !(first..=last).contains(&base) {
+            return Err(UnitError::OutOfRange {
+                value: x.to_unit::<X>(),
+                min: X::convert_from(first),
+                max: X::convert_from(last),
+            });
+        }
+        let segment = self
+            .xs
+            .partition_point(|&v| v <= base)
+            .clamp(1, self.xs.len() - 1)
+            - 1;
+        Ok(Quantity::from_base(self.eval(segment, base)))
+    }
+
+    /// Returns the input at which the table's output is `y`.
+    ///
+    /// Rows are searched from the smallest input, so if several inputs produce `y` the
+    /// smallest is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if no input produces `y`.
+    pub fn inverse<A, B>(&self, y: Quantity<B>) -> Result<Quantity<A>, UnitError>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        let target = y.in_base();
+        for segment in 0..self.xs.len() - 1 {
+            let (y0, y1) = (self.ys[segment], self.ys[segment + 1]);
+            if !(y0.min(y1)..=y0.max(y1)).contains(&target) {
+                continue;
+            }
+            if target == y0 {
+                return Ok(Quantity::from_base(self.xs[segment]));
+            }
+            if target == y1 {
+                return Ok(Quantity::from_base(self.xs[segment + 1]));
+            }
+            // Each segment is monotone, so bisection on it converges to the crossing.
+            let (mut lo, mut hi) = (self.xs[segment], self.xs[segment + 1]);
+            let rising = y1 > y0;
+            for _ in 0..100 {
+                let mid = 0.5 * (lo + hi);
+                if (self.eval(segment, mid) < target) == rising {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            return Ok(Quantity::from_base(0.5 * (lo + hi)));
+        }
+        let lowest = self.ys.iter().copied().fold(f64::INFINITY, f64::min);
+        let highest = self.ys.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Err(UnitError::OutOfRange {
+            value: y.to_unit::<Y>(),
+            min: Y::convert_from(lowest),
+            max: Y::convert_from(highest),
+        })
+    }
+
+    /// Evaluates the interpolant on `segment`, between rows `segment` and `segment + 1`.
+    fn eval(&self, segment: usize, x: f64) -> f64 {
+        let (x0, x1) = (self.xs[segment], self.xs[segment + 1]);
+        let (y0, y1) = (self.ys[segment], self.ys[segment + 1]);
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        match self.interpolation {
+            Interpolation::Linear => y0 + t * (y1 - y0),
+            Interpolation::Cubic => {
+                let (m0, m1) = (self.slopes[segment], self.slopes[segment + 1]);
+                let t2 = t * t;
+                let t3 = t2 * t;
+                (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+                    + (t3 - 2.0 * t2 + t) * h * m0
+                    + (-2.0 * t3 + 3.0 * t2) * y1
+                    + (t3 - t2) * h * m1
+            }
+        }
+    }
+}
+
+/// Fritsch–Carlson slopes: zero at local extrema, otherwise a weighted harmonic mean of
+/// the neighbouring secants, which keeps every segment monotone.
+fn monotone_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let secants: Vec<f64> = (0..n - 1).map(|k| (ys[k + 1] - ys[k]) / h[k]).collect();
+    let mut slopes = vec![0.0; n];
+    slopes[0] = secants[0];
+    slopes[n - 1] = secants[n - 2];
+    for k in 1..n - 1 {
+        let (before, after) = (secants[k - 1], secants[k]);
+        if before * after > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            slopes[k] = (w1 + w2) / (w1 / before + w2 / after);
+        }
+    }
+    slopes
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn table() -> LookupTable<Meter, Celsius> {
+        LookupTable::from_rows(&[(0.0, 10.0), (1.0, 20.0), (2.0, 40.0), (4.0, 40.0)]).unwrap()
+    }
+
+    fn at(table: &LookupTable<Meter, Celsius>, meters: f64) -> f64 {
+        let t: Temperature = table.lookup(Length::from_unit::<Meter>(meters)).unwrap();
+        t.to_unit::<Celsius>()
+    }
+
+    #[test]
+    fn interpolates_linearly() {
+        let table = table();
+        assert!(approx(at(&table, 0.5), 15.0, 1e-9));
+        assert!(approx(at(&table, 1.5), 30.0, 1e-9));
+        assert!(approx(at(&table, 4.0), 40.0, 1e-9));
+        let t: Temperature = table.lookup(Length::from_unit::<Foot>(5.0)).unwrap();
+        assert!(approx(t.to_unit::<Fahrenheit>(), 30.48 * 1.8 + 32.0, 1e-9));
+        assert_eq!(
+            table
+                .lookup::<_, Kelvin>(Length::from_unit::<Meter>(-1.0))
+                .unwrap_err(),
+            UnitError::OutOfRange {
+                value: -1.0,
+                min: 0.0,
+                max: 4.0
+            }
+        );
+    }
+
+    #[test]
+    fn interpolates_cubically_without_overshoot() {
+        let table = table().with_interpolation(Interpolation::Cubic);
+        for (x, y) in table.rows() {
+            assert!(approx(at(&table, x), y, 1e-9));
+        }
+        // Curved between rows, but flat where the data is flat
+        assert!(at(&table, 1.5) != 30.0);
+        for i in 0..=40 {
+            let t = at(&table, 2.0 + i as f64 * 0.05);
+            assert!(approx(t, 40.0, 1e-9));
+        }
+        let mut previous = f64::NEG_INFINITY;
+        for i in 0..=200 {
+            let t = at(&table, i as f64 * 0.01);
+            assert!(t >= previous - 1e-12 && t <= 40.0 + 1e-12);
+            previous = t;
+        }
+    }
+
+    #[test]
+    fn inverts_lookups() {
+        for interpolation in [Interpolation::Linear, Interpolation::Cubic] {
+            let table = table().with_interpolation(interpolation);
+            for meters in [0.0, 0.3, 1.0, 1.7] {
+                let t: Temperature = table.lookup(Length::from_unit::<Meter>(meters)).unwrap();
+                let back: Length = table.inverse(t).unwrap();
+                assert!(approx(back.to_unit::<Meter>(), meters, 1e-9));
+            }
+            // The flat section resolves to its first row
+            let flat: Length = table
+                .inverse(Temperature::from_unit::<Celsius>(40.0))
+                .unwrap();
+            assert!(approx(flat.to_unit::<Meter>(), 2.0, 1e-9));
+            assert!(
+                table
+                    .inverse::<Meter, _>(Temperature::from_unit::<Fahrenheit>(0.0))
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn validates_rows() {
+        assert!(LookupTable::<Meter, Celsius>::from_rows(&[(0.0, 1.0)]).is_err());
+        assert!(LookupTable::<Meter, Celsius>::from_rows(&[(1.0, 1.0), (1.0, 2.0)]).is_err());
+        assert!(LookupTable::<Meter, Celsius>::from_rows(&[(0.0, f64::NAN), (1.0, 2.0)]).is_err());
+        let rows = [
+            (
+                Length::from_unit::<Meter>(0.0),
+                Temperature::from_unit::<Celsius>(0.0),
+            ),
+            (
+                Length::from_unit::<Meter>(1.0),
+                Temperature::from_unit::<Celsius>(1.0),
+            ),
+        ];
+        assert!(LookupTable::<Foot, Fahrenheit>::new(&rows).is_ok());
+    }
+
+    #[test]
+    fn reads_csv() {
+        let csv = "# borehole log\ndepth;temp\n0;10\n\n1 ; 20\n2\t40\n4,40\n";
+        let table = LookupTable::<Meter, Celsius>::from_csv(csv).unwrap();
+        assert_eq!(table.rows().count(), 4);
+        assert!(approx(at(&table, 3.0), 40.0, 1e-9));
+
+        let err = LookupTable::<Meter, Celsius>::from_csv("0,1\n1,x\n").unwrap_err();
+        assert_eq!(
+            err,
+            UnitError::ParseError {
+                input: "1,x".to_string(),
+                reason: "expected two numeric columns"
+            }
+        );
+        assert!(LookupTable::<Meter, Celsius>::from_csv("0,1,2\n1,2,3\n").is_err());
+    }
+}
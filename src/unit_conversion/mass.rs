@@ -0,0 +1,618 @@
+//! # Mass Unit Conversions
+//!
+//! This module provides type-safe mass conversions between kilograms, grams and their
+//! submultiples, pounds, the
+//! UK customary units built on the pound, and the troy and jeweler's units used for
+//! precious metals and gemstones.
+//! All masses are internally stored in kilograms (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Kilogram (kg)**: The base unit, SI unit of mass
+//! - **Gram (g)**: 0.001 kilograms
+//! - **Milligram (mg)**: 10⁻⁶ kilograms
+//! - **Microgram (µg)**: 10⁻⁹ kilograms; written `mcg` on prescriptions
+//! - **Pound (lb)**: Avoirdupois pound, exactly 0.45359237 kilograms
+//! - **Stone (st)**: 14 pounds
+//! - **Long hundredweight (cwt)**: 112 pounds
+//! - **Short hundredweight (sh cwt)**: 100 pounds
+//! - **Long ton (long tn)**: 2240 pounds
+//! - **Troy ounce (oz t)**: 480 grains, exactly 31.1034768 grams
+//! - **Pennyweight (dwt)**: 24 grains, exactly 1.55517384 grams
+//! - **Grain (gr)**: Exactly 64.79891 milligrams
+//! - **Carat (ct)**: Metric carat, exactly 200 milligrams
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::mass::{Mass, Kilogram, Gram, Pound};
+//!
+//! let bag = Mass::from_unit::<Kilogram>(1.0);
+//! assert_eq!(bag.to_unit::<Gram>(), 1000.0);
+//! assert!((bag.to_unit::<Pound>() - 2.20462262).abs() < 1e-8);
+//! ```
+
+use crate::unit_conversion::{Dimension, NonNegative, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for mass conversions
+const KILOGRAMS_PER_GRAM: f64 = 1e-3;
+const KILOGRAMS_PER_MILLIGRAM: f64 = 1e-6;
+const KILOGRAMS_PER_MICROGRAM: f64 = 1e-9;
+const KILOGRAMS_PER_POUND: f64 = 0.45359237; // Exact definition
+const KILOGRAMS_PER_STONE: f64 = 14.0 * KILOGRAMS_PER_POUND;
+const KILOGRAMS_PER_LONG_HUNDREDWEIGHT: f64 = 112.0 * KILOGRAMS_PER_POUND;
+const KILOGRAMS_PER_SHORT_HUNDREDWEIGHT: f64 = 100.0 * KILOGRAMS_PER_POUND;
+const KILOGRAMS_PER_LONG_TON: f64 = 2240.0 * KILOGRAMS_PER_POUND;
+const KILOGRAMS_PER_GRAIN: f64 = 64.79891e-6; // Exact definition
+const KILOGRAMS_PER_PENNYWEIGHT: f64 = 24.0 * KILOGRAMS_PER_GRAIN;
+const KILOGRAMS_PER_TROY_OUNCE: f64 = 480.0 * KILOGRAMS_PER_GRAIN;
+const KILOGRAMS_PER_CARAT: f64 = 200e-6; // Exact definition
+
+/// Marker type for the mass dimension.
+///
+/// This prevents accidental conversions between mass and other dimensions
+/// like volume or length.
+pub enum MassDimension {}
+
+impl Dimension for MassDimension {
+    const NAME: &'static str = "mass";
+    const ID: u8 = 3;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Kilogram>(&["kilogram", "kilograms", "kilo", "kilos"]),
+        UnitInfo::of::<Gram>(&["gram", "grams"]),
+        UnitInfo::of::<Milligram>(&["milligram", "milligrams"]),
+        UnitInfo::of::<Microgram>(&["ug", "μg", "mcg", "microgram", "micrograms"]),
+        UnitInfo::of::<Pound>(&["lbs", "pound", "pounds"]),
+        UnitInfo::of::<Stone>(&["stone", "stones"]),
+        UnitInfo::of::<LongHundredweight>(&[
+            "long cwt",
+            "hundredweight",
+            "hundredweights",
+            "long hundredweight",
+            "long hundredweights",
+        ]),
+        UnitInfo::of::<ShortHundredweight>(&[
+            "short cwt",
+            "short hundredweight",
+            "short hundredweights",
+        ]),
+        UnitInfo::of::<LongTon>(&[
+            "LT",
+            "long ton",
+            "long tons",
+            "imperial ton",
+            "imperial tons",
+        ]),
+        UnitInfo::of::<TroyOunce>(&["ozt", "troy ounce", "troy ounces"]),
+        UnitInfo::of::<Pennyweight>(&["pennyweight", "pennyweights"]),
+        UnitInfo::of::<Grain>(&["grain", "grains"]),
+        UnitInfo::of::<Carat>(&["carat", "carats"]),
+    ];
+}
+
+impl NonNegative for MassDimension {}
+
+/// A mass quantity that stores values in kilograms internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Gram, Kilogram};
+///
+/// let flour = Mass::from_unit::<Gram>(500.0);
+/// let kilograms = flour.to_unit::<Kilogram>(); // 0.5
+/// ```
+pub type Mass = Quantity<Kilogram>;
+
+/// Kilogram mass unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Kilogram};
+///
+/// let mass = Mass::from_unit::<Kilogram>(75.0);
+/// assert_eq!(mass.to_unit::<Kilogram>(), 75.0);
+/// ```
+pub struct Kilogram;
+
+impl UnitConversion for Kilogram {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Kilogram is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Kilogram is the base unit
+    }
+
+    const SYMBOL: &'static str = "kg";
+    const NAME: &'static str = "kilogram";
+    const PLURAL: &'static str = "kilograms";
+    const PHYSICAL_RANGE: (f64, f64) = (0.0, f64::INFINITY);
+}
+
+/// Gram mass unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Gram, Kilogram};
+///
+/// let sugar = Mass::from_unit::<Gram>(250.0);
+/// assert_eq!(sugar.to_unit::<Kilogram>(), 0.25);
+/// ```
+pub struct Gram;
+
+impl UnitConversion for Gram {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_GRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_GRAM
+    }
+
+    const SYMBOL: &'static str = "g";
+    const NAME: &'static str = "gram";
+    const PLURAL: &'static str = "grams";
+}
+
+/// Milligram mass unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Gram, Mass, Milligram};
+///
+/// let tablet = Mass::from_unit::<Milligram>(500.0);
+/// assert!((tablet.to_unit::<Gram>() - 0.5).abs() < 1e-12);
+/// ```
+pub struct Milligram;
+
+impl UnitConversion for Milligram {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_MILLIGRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_MILLIGRAM
+    }
+
+    const SYMBOL: &'static str = "mg";
+    const NAME: &'static str = "milligram";
+    const PLURAL: &'static str = "milligrams";
+}
+
+/// Microgram mass unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Microgram, Milligram};
+///
+/// let dose = Mass::from_unit::<Microgram>(250.0);
+/// assert!((dose.to_unit::<Milligram>() - 0.25).abs() < 1e-12);
+/// ```
+pub struct Microgram;
+
+impl UnitConversion for Microgram {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_MICROGRAM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_MICROGRAM
+    }
+
+    const SYMBOL: &'static str = "µg";
+    const NAME: &'static str = "microgram";
+    const PLURAL: &'static str = "micrograms";
+}
+
+/// Pound mass unit (avoirdupois).
+///
+/// The international avoirdupois pound is defined as exactly 0.45359237 kilograms.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Pound, Kilogram};
+///
+/// let weight = Mass::from_unit::<Pound>(1.0);
+/// assert_eq!(weight.to_unit::<Kilogram>(), 0.45359237);
+/// ```
+pub struct Pound;
+
+impl UnitConversion for Pound {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_POUND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_POUND
+    }
+
+    const SYMBOL: &'static str = "lb";
+    const NAME: &'static str = "pound";
+    const PLURAL: &'static str = "pounds";
+}
+
+/// Stone mass unit.
+///
+/// Fourteen pounds; body weight in the UK is usually given in stones and pounds.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Stone, Pound};
+///
+/// let weight = Mass::from_unit::<Stone>(11.0);
+/// assert!((weight.to_unit::<Pound>() - 154.0).abs() < 1e-9);
+/// ```
+pub struct Stone;
+
+impl UnitConversion for Stone {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_STONE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_STONE
+    }
+
+    const SYMBOL: &'static str = "st";
+    const NAME: &'static str = "stone";
+    const PLURAL: &'static str = "stones";
+}
+
+/// Long (imperial) hundredweight mass unit.
+///
+/// Eight stones, or 112 pounds. This is the hundredweight meant by a bare `cwt`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, LongHundredweight, Stone};
+///
+/// let sack = Mass::from_unit::<LongHundredweight>(1.0);
+/// assert!((sack.to_unit::<Stone>() - 8.0).abs() < 1e-12);
+/// ```
+pub struct LongHundredweight;
+
+impl UnitConversion for LongHundredweight {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_LONG_HUNDREDWEIGHT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_LONG_HUNDREDWEIGHT
+    }
+
+    const SYMBOL: &'static str = "cwt";
+    const NAME: &'static str = "long hundredweight";
+    const PLURAL: &'static str = "long hundredweights";
+}
+
+/// Short (US) hundredweight mass unit.
+///
+/// Exactly 100 pounds.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, ShortHundredweight, Pound};
+///
+/// let load = Mass::from_unit::<ShortHundredweight>(2.0);
+/// assert!((load.to_unit::<Pound>() - 200.0).abs() < 1e-9);
+/// ```
+pub struct ShortHundredweight;
+
+impl UnitConversion for ShortHundredweight {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_SHORT_HUNDREDWEIGHT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_SHORT_HUNDREDWEIGHT
+    }
+
+    const SYMBOL: &'static str = "sh cwt";
+    const NAME: &'static str = "short hundredweight";
+    const PLURAL: &'static str = "short hundredweights";
+}
+
+/// Long (imperial) ton mass unit.
+///
+/// Twenty long hundredweights, or 2240 pounds, exactly 1016.0469088 kilograms. Still
+/// used for ship displacement.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, LongTon, Kilogram};
+///
+/// let cargo = Mass::from_unit::<LongTon>(1.0);
+/// assert!((cargo.to_unit::<Kilogram>() - 1016.0469088).abs() < 1e-9);
+/// ```
+pub struct LongTon;
+
+impl UnitConversion for LongTon {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_LONG_TON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_LONG_TON
+    }
+
+    const SYMBOL: &'static str = "long tn";
+    const NAME: &'static str = "long ton";
+    const PLURAL: &'static str = "long tons";
+}
+
+/// Troy ounce mass unit.
+///
+/// Used for precious metals; 480 grains, or exactly 31.1034768 grams.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, TroyOunce, Gram};
+///
+/// let bar = Mass::from_unit::<TroyOunce>(1.0);
+/// assert!((bar.to_unit::<Gram>() - 31.1034768).abs() < 1e-12);
+/// ```
+pub struct TroyOunce;
+
+impl UnitConversion for TroyOunce {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_TROY_OUNCE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_TROY_OUNCE
+    }
+
+    const SYMBOL: &'static str = "oz t";
+    const NAME: &'static str = "troy ounce";
+    const PLURAL: &'static str = "troy ounces";
+}
+
+/// Pennyweight mass unit.
+///
+/// One twentieth of a troy ounce (24 grains), used in the jewelry trade.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Pennyweight, TroyOunce};
+///
+/// let scrap = Mass::from_unit::<Pennyweight>(20.0);
+/// assert!((scrap.to_unit::<TroyOunce>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct Pennyweight;
+
+impl UnitConversion for Pennyweight {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_PENNYWEIGHT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_PENNYWEIGHT
+    }
+
+    const SYMBOL: &'static str = "dwt";
+    const NAME: &'static str = "pennyweight";
+    const PLURAL: &'static str = "pennyweights";
+}
+
+/// Grain mass unit.
+///
+/// Defined as exactly 64.79891 milligrams; 7000 grains make an avoirdupois pound.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Grain, Pound};
+///
+/// let powder = Mass::from_unit::<Grain>(7000.0);
+/// assert!((powder.to_unit::<Pound>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct Grain;
+
+impl UnitConversion for Grain {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_GRAIN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_GRAIN
+    }
+
+    const SYMBOL: &'static str = "gr";
+    const NAME: &'static str = "grain";
+    const PLURAL: &'static str = "grains";
+}
+
+/// Metric carat mass unit.
+///
+/// Defined as exactly 200 milligrams, used for gemstones.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass::{Mass, Carat, Gram};
+///
+/// let stone = Mass::from_unit::<Carat>(1.5);
+/// assert!((stone.to_unit::<Gram>() - 0.3).abs() < 1e-12);
+/// ```
+pub struct Carat;
+
+impl UnitConversion for Carat {
+    type Dimension = MassDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KILOGRAMS_PER_CARAT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KILOGRAMS_PER_CARAT
+    }
+
+    const SYMBOL: &'static str = "ct";
+    const NAME: &'static str = "carat";
+    const PLURAL: &'static str = "carats";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn gram_to_kilogram() {
+        let mass = Mass::from_unit::<Gram>(1000.0);
+        assert!(approx(mass.to_unit::<Kilogram>(), 1.0, 1e-12));
+
+        let mass = Mass::from_unit::<Gram>(1.0);
+        assert!(approx(mass.to_unit::<Kilogram>(), 0.001, 1e-12));
+        assert!(approx(mass.to_unit::<Milligram>(), 1000.0, 1e-9));
+        assert!(approx(mass.to_unit::<Microgram>(), 1e6, 1e-6));
+        assert_eq!(MassDimension::find_unit("mcg").unwrap().symbol, "µg");
+    }
+
+    #[test]
+    fn pound_to_kilogram() {
+        let mass = Mass::from_unit::<Pound>(1.0);
+        assert!(approx(mass.to_unit::<Kilogram>(), 0.45359237, 1e-12));
+
+        let mass = Mass::from_unit::<Kilogram>(1.0);
+        assert!(approx(mass.to_unit::<Pound>(), 2.2046226218, 1e-9));
+    }
+
+    #[test]
+    fn uk_customary_units() {
+        let ton = Mass::from_unit::<LongTon>(1.0);
+        assert!(approx(ton.to_unit::<Pound>(), 2240.0, 1e-9));
+        assert!(approx(ton.to_unit::<LongHundredweight>(), 20.0, 1e-12));
+        assert!(approx(ton.to_unit::<Stone>(), 160.0, 1e-9));
+        assert!(approx(ton.to_unit::<Kilogram>(), 1016.0469088, 1e-9));
+
+        let short = Mass::from_unit::<ShortHundredweight>(1.0);
+        assert!(approx(short.to_unit::<Kilogram>(), 45.359237, 1e-12));
+
+        for (alias, symbol) in [
+            ("st", "st"),
+            ("stones", "st"),
+            ("cwt", "cwt"),
+            ("hundredweight", "cwt"),
+            ("short cwt", "sh cwt"),
+            ("long ton", "long tn"),
+        ] {
+            assert_eq!(MassDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn troy_and_jewelers_units() {
+        let ounce = Mass::from_unit::<TroyOunce>(1.0);
+        assert!(approx(ounce.to_unit::<Gram>(), 31.1034768, 1e-12));
+        assert!(approx(ounce.to_unit::<Pennyweight>(), 20.0, 1e-12));
+        assert!(approx(ounce.to_unit::<Grain>(), 480.0, 1e-9));
+
+        let pound = Mass::from_unit::<Pound>(1.0);
+        assert!(approx(pound.to_unit::<Grain>(), 7000.0, 1e-9));
+
+        let stone = Mass::from_unit::<Carat>(5.0);
+        assert!(approx(stone.to_unit::<Gram>(), 1.0, 1e-12));
+        assert_eq!(MassDimension::find_unit("ozt").unwrap().symbol, "oz t");
+    }
+
+    #[test]
+    fn mass_symbols() {
+        assert_eq!(Kilogram::SYMBOL, "kg");
+        assert_eq!(Gram::SYMBOL, "g");
+        assert_eq!(Pound::SYMBOL, "lb");
+        assert_eq!(Stone::SYMBOL, "st");
+        assert_eq!(LongHundredweight::SYMBOL, "cwt");
+        assert_eq!(LongTon::SYMBOL, "long tn");
+        assert_eq!(TroyOunce::SYMBOL, "oz t");
+        assert_eq!(Pennyweight::SYMBOL, "dwt");
+        assert_eq!(Grain::SYMBOL, "gr");
+        assert_eq!(Carat::SYMBOL, "ct");
+    }
+
+    #[test]
+    fn checked_sub_rejects_negative_mass() {
+        let sack = Mass::from_unit::<Kilogram>(25.0);
+        let scoop = Mass::from_unit::<Gram>(500.0);
+        let rest = sack.checked_sub(scoop).unwrap();
+        assert!(approx(rest.to_unit::<Kilogram>(), 24.5, 1e-12));
+        assert!(scoop.checked_sub(sack).is_none());
+        assert!(sack.checked_sub(sack).unwrap().is_zero());
+        assert!(
+            sack.checked_sub(Mass::from_unit::<Kilogram>(f64::NAN))
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict_physics", debug_assertions))]
+    #[should_panic(expected = "PHYSICAL_RANGE")]
+    fn strict_physics_rejects_negative_mass() {
+        let _ = Mass::from_unit::<Pound>(-0.5);
+    }
+}
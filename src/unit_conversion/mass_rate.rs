@@ -0,0 +1,278 @@
+//! # Mass Rate Unit Conversions
+//!
+//! This module provides type-safe conversions for mass flow rates, from industrial feeds
+//! in kilograms per hour down to the micrograms per minute of a drug infusion.
+//! All rates are internally stored in kilograms per second (the base unit) and converted
+//! on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Kilogram per second (kg/s)**: The base unit
+//! - **Kilogram per hour (kg/h)**, **Gram per hour (g/h)**
+//! - **Milligram per hour (mg/h)**, **Milligram per minute (mg/min)**
+//! - **Microgram per minute (µg/min)**
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::mass_rate::{MassRate, MicrogramPerMinute, MilligramPerHour};
+//!
+//! let drip = MassRate::from_unit::<MilligramPerHour>(3.0);
+//! assert!((drip.to_unit::<MicrogramPerMinute>() - 50.0).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for mass rate conversions
+const KG_PER_S_PER_KG_PER_H: f64 = 1.0 / 3600.0;
+const KG_PER_S_PER_G_PER_H: f64 = 1e-3 / 3600.0;
+const KG_PER_S_PER_MG_PER_H: f64 = 1e-6 / 3600.0;
+const KG_PER_S_PER_MG_PER_MIN: f64 = 1e-6 / 60.0;
+const KG_PER_S_PER_UG_PER_MIN: f64 = 1e-9 / 60.0;
+
+/// Marker type for the mass rate dimension.
+///
+/// This prevents accidental conversions between a rate of flow and the mass delivered.
+pub enum MassRateDimension {}
+
+impl Dimension for MassRateDimension {
+    const NAME: &'static str = "mass rate";
+    const ID: u8 = 27;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<KilogramPerSecond>(&["kilogram per second", "kilograms per second"]),
+        UnitInfo::of::<KilogramPerHour>(&["kilogram per hour", "kilograms per hour"]),
+        UnitInfo::of::<GramPerHour>(&["gram per hour", "grams per hour"]),
+        UnitInfo::of::<MilligramPerHour>(&["milligram per hour", "milligrams per hour"]),
+        UnitInfo::of::<MilligramPerMinute>(&["milligram per minute", "milligrams per minute"]),
+        UnitInfo::of::<MicrogramPerMinute>(&[
+            "ug/min",
+            "μg/min",
+            "mcg/min",
+            "microgram per minute",
+            "micrograms per minute",
+        ]),
+    ];
+}
+
+/// A mass rate that stores values in kilograms per second internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{GramPerHour, KilogramPerHour, MassRate};
+///
+/// let hopper = MassRate::from_unit::<KilogramPerHour>(1.2);
+/// let grams = hopper.to_unit::<GramPerHour>(); // 1200.0
+/// ```
+pub type MassRate = Quantity<KilogramPerSecond>;
+
+/// Kilogram per second mass rate unit (SI unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{KilogramPerSecond, MassRate};
+///
+/// let pump = MassRate::from_unit::<KilogramPerSecond>(2.5);
+/// assert_eq!(pump.to_unit::<KilogramPerSecond>(), 2.5);
+/// ```
+pub struct KilogramPerSecond;
+
+impl UnitConversion for KilogramPerSecond {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // KilogramPerSecond is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // KilogramPerSecond is the base unit
+    }
+
+    const SYMBOL: &'static str = "kg/s";
+    const NAME: &'static str = "kilogram per second";
+    const PLURAL: &'static str = "kilograms per second";
+}
+
+/// Kilogram per hour mass rate unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{KilogramPerHour, KilogramPerSecond, MassRate};
+///
+/// let feeder = MassRate::from_unit::<KilogramPerHour>(360.0);
+/// assert!((feeder.to_unit::<KilogramPerSecond>() - 0.1).abs() < 1e-15);
+/// ```
+pub struct KilogramPerHour;
+
+impl UnitConversion for KilogramPerHour {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_S_PER_KG_PER_H
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_S_PER_KG_PER_H
+    }
+
+    const SYMBOL: &'static str = "kg/h";
+    const NAME: &'static str = "kilogram per hour";
+    const PLURAL: &'static str = "kilograms per hour";
+}
+
+/// Gram per hour mass rate unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{GramPerHour, KilogramPerHour, MassRate};
+///
+/// let burner = MassRate::from_unit::<GramPerHour>(150.0);
+/// assert!((burner.to_unit::<KilogramPerHour>() - 0.15).abs() < 1e-15);
+/// ```
+pub struct GramPerHour;
+
+impl UnitConversion for GramPerHour {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_S_PER_G_PER_H
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_S_PER_G_PER_H
+    }
+
+    const SYMBOL: &'static str = "g/h";
+    const NAME: &'static str = "gram per hour";
+    const PLURAL: &'static str = "grams per hour";
+}
+
+/// Milligram per hour mass rate unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{MassRate, MilligramPerHour, MilligramPerMinute};
+///
+/// let infusion = MassRate::from_unit::<MilligramPerHour>(120.0);
+/// assert!((infusion.to_unit::<MilligramPerMinute>() - 2.0).abs() < 1e-12);
+/// ```
+pub struct MilligramPerHour;
+
+impl UnitConversion for MilligramPerHour {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_S_PER_MG_PER_H
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_S_PER_MG_PER_H
+    }
+
+    const SYMBOL: &'static str = "mg/h";
+    const NAME: &'static str = "milligram per hour";
+    const PLURAL: &'static str = "milligrams per hour";
+}
+
+/// Milligram per minute mass rate unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{MassRate, MicrogramPerMinute, MilligramPerMinute};
+///
+/// let infusion = MassRate::from_unit::<MilligramPerMinute>(0.5);
+/// assert!((infusion.to_unit::<MicrogramPerMinute>() - 500.0).abs() < 1e-9);
+/// ```
+pub struct MilligramPerMinute;
+
+impl UnitConversion for MilligramPerMinute {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_S_PER_MG_PER_MIN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_S_PER_MG_PER_MIN
+    }
+
+    const SYMBOL: &'static str = "mg/min";
+    const NAME: &'static str = "milligram per minute";
+    const PLURAL: &'static str = "milligrams per minute";
+}
+
+/// Microgram per minute mass rate unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::mass_rate::{MassRate, MicrogramPerMinute, MilligramPerHour};
+///
+/// let infusion = MassRate::from_unit::<MicrogramPerMinute>(10.0);
+/// assert!((infusion.to_unit::<MilligramPerHour>() - 0.6).abs() < 1e-12);
+/// ```
+pub struct MicrogramPerMinute;
+
+impl UnitConversion for MicrogramPerMinute {
+    type Dimension = MassRateDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * KG_PER_S_PER_UG_PER_MIN
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / KG_PER_S_PER_UG_PER_MIN
+    }
+
+    const SYMBOL: &'static str = "µg/min";
+    const NAME: &'static str = "microgram per minute";
+    const PLURAL: &'static str = "micrograms per minute";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn hourly_and_per_minute_rates() {
+        let rate = MassRate::from_unit::<KilogramPerHour>(3.6);
+        assert!(approx(rate.to_unit::<KilogramPerSecond>(), 0.001, 1e-15));
+        assert!(approx(rate.to_unit::<GramPerHour>(), 3600.0, 1e-9));
+        assert!(approx(rate.to_unit::<MilligramPerMinute>(), 60_000.0, 1e-6));
+
+        let infusion = MassRate::from_unit::<MicrogramPerMinute>(100.0);
+        assert!(approx(infusion.to_unit::<MilligramPerHour>(), 6.0, 1e-12));
+    }
+
+    #[test]
+    fn mass_rate_symbols() {
+        assert_eq!(KilogramPerSecond::SYMBOL, "kg/s");
+        assert_eq!(MilligramPerHour::SYMBOL, "mg/h");
+        assert_eq!(MicrogramPerMinute::SYMBOL, "µg/min");
+        assert_eq!(
+            MassRateDimension::find_unit("mcg/min").unwrap().symbol,
+            "µg/min"
+        );
+    }
+}
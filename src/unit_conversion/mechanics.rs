@@ -0,0 +1,196 @@
+//! # Kinematics and Dynamics Helpers
+//!
+//! Typed versions of the basic formulas of mechanics. Each signature spells out the
+//! dimensional relationship it encodes, such as `force(Mass, Acceleration) -> Force`,
+//! so formula code reads like the physics and passing a speed where an acceleration is
+//! expected does not compile. Inputs may be in any unit of their dimension.
+//!
+//! Formulas that divide by a duration return [`UnitError::OutOfRange`] unless it is
+//! positive and finite.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//! use uom::unit_conversion::mass::{Kilogram, Mass};
+//! use uom::unit_conversion::mechanics::{kinetic_energy, speed};
+//! use uom::unit_conversion::energy::Kilojoule;
+//! use uom::unit_conversion::speed::KilometerPerHour;
+//! use uom::unit_conversion::time::{Minute, Time};
+//!
+//! let v = speed(Length::from_unit::<Kilometer>(10.0), Time::from_unit::<Minute>(6.0)).unwrap();
+//! assert!((v.to_unit::<KilometerPerHour>() - 100.0).abs() < 1e-9);
+//!
+//! let energy = kinetic_energy(Mass::from_unit::<Kilogram>(1500.0), v);
+//! assert!((energy.to_unit::<Kilojoule>() - 578.7).abs() < 0.1);
+//! ```
+
+use crate::unit_conversion::acceleration::{Acceleration, MeterPerSecondSquared};
+use crate::unit_conversion::energy::{Energy, Joule};
+use crate::unit_conversion::force::{Force, Newton};
+use crate::unit_conversion::length::{Length, Meter};
+use crate::unit_conversion::mass::{Kilogram, Mass};
+use crate::unit_conversion::speed::{MeterPerSecond, Speed};
+use crate::unit_conversion::time::{Second, Time};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// Returns the average speed over `distance` covered in `time`, `v = d / t`.
+///
+/// Returns [`UnitError::OutOfRange`] if the time is not positive and finite.
+pub fn speed(distance: Length, time: Time) -> Result<Speed, UnitError> {
+    let seconds = check_positive(time.to_unit::<Second>())?;
+    Ok(Speed::from_unit::<MeterPerSecond>(
+        distance.to_unit::<Meter>() / seconds,
+    ))
+}
+
+/// Returns the distance covered at `speed` for `time`, `d = v t`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::Kilometer;
+/// use uom::unit_conversion::mechanics::distance;
+/// use uom::unit_conversion::speed::{KilometerPerHour, Speed};
+/// use uom::unit_conversion::time::{Minute, Time};
+///
+/// let d = distance(Speed::from_unit::<KilometerPerHour>(80.0), Time::from_unit::<Minute>(45.0));
+/// assert!((d.to_unit::<Kilometer>() - 60.0).abs() < 1e-9);
+/// ```
+pub fn distance(speed: Speed, time: Time) -> Length {
+    Length::from_unit::<Meter>(speed.to_unit::<MeterPerSecond>() * time.to_unit::<Second>())
+}
+
+/// Returns the average acceleration of a change in speed over `time`, `a = Δv / t`.
+///
+/// Returns [`UnitError::OutOfRange`] if the time is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::StandardGravity;
+/// use uom::unit_conversion::mechanics::acceleration;
+/// use uom::unit_conversion::speed::{KilometerPerHour, Speed};
+/// use uom::unit_conversion::time::{Second, Time};
+///
+/// // 0-100 km/h in 2.8 s is about 1 g
+/// let a = acceleration(
+///     Speed::from_unit::<KilometerPerHour>(100.0),
+///     Time::from_unit::<Second>(2.8),
+/// )
+/// .unwrap();
+/// assert!((a.to_unit::<StandardGravity>() - 1.01).abs() < 0.01);
+/// ```
+pub fn acceleration(speed_change: Speed, time: Time) -> Result<Acceleration, UnitError> {
+    let seconds = check_positive(time.to_unit::<Second>())?;
+    Ok(Acceleration::from_unit::<MeterPerSecondSquared>(
+        speed_change.to_unit::<MeterPerSecond>() / seconds,
+    ))
+}
+
+/// Returns the force that gives `mass` the `acceleration`, `F = m a`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::acceleration::{Acceleration, StandardGravity};
+/// use uom::unit_conversion::force::KilogramForce;
+/// use uom::unit_conversion::mass::{Kilogram, Mass};
+/// use uom::unit_conversion::mechanics::force;
+///
+/// // The weight of 70 kg is 70 kgf
+/// let weight = force(Mass::from_unit::<Kilogram>(70.0), Acceleration::from_unit::<StandardGravity>(1.0));
+/// assert!((weight.to_unit::<KilogramForce>() - 70.0).abs() < 1e-9);
+/// ```
+pub fn force(mass: Mass, acceleration: Acceleration) -> Force {
+    Force::from_unit::<Newton>(
+        mass.to_unit::<Kilogram>() * acceleration.to_unit::<MeterPerSecondSquared>(),
+    )
+}
+
+/// Returns the kinetic energy of `mass` moving at `speed`, `E = ½ m v²`.
+pub fn kinetic_energy(mass: Mass, speed: Speed) -> Energy {
+    let meters_per_second = speed.to_unit::<MeterPerSecond>();
+    Energy::from_unit::<Joule>(
+        0.5 * mass.to_unit::<Kilogram>() * meters_per_second * meters_per_second,
+    )
+}
+
+/// Returns the work done by a constant `force` acting along `distance`, `W = F d`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::Joule;
+/// use uom::unit_conversion::force::{Force, Newton};
+/// use uom::unit_conversion::length::{Length, Meter};
+/// use uom::unit_conversion::mechanics::work;
+///
+/// let w = work(Force::from_unit::<Newton>(50.0), Length::from_unit::<Meter>(3.0));
+/// assert_eq!(w.to_unit::<Joule>(), 150.0);
+/// ```
+pub fn work(force: Force, distance: Length) -> Energy {
+    Energy::from_unit::<Joule>(force.to_unit::<Newton>() * distance.to_unit::<Meter>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::acceleration::FootPerSecondSquared;
+    use crate::unit_conversion::force::PoundForce;
+    use crate::unit_conversion::length::Foot;
+    use crate::unit_conversion::mass::Pound;
+    use crate::unit_conversion::speed::FootPerSecond;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn kinematics_round_trip() {
+        let t = Time::from_unit::<Second>(8.0);
+        let v = speed(Length::from_unit::<Foot>(400.0), t).unwrap();
+        assert!(approx(v.to_unit::<FootPerSecond>(), 50.0, 1e-9));
+        assert!(approx(distance(v, t).to_unit::<Foot>(), 400.0, 1e-9));
+
+        let a = acceleration(v, t).unwrap();
+        assert!(approx(a.to_unit::<FootPerSecondSquared>(), 6.25, 1e-9));
+    }
+
+    #[test]
+    fn customary_units_combine() {
+        // One pound of mass in standard gravity weighs one pound-force
+        let g = Acceleration::from_unit::<FootPerSecondSquared>(32.174_048_556);
+        let weight = force(Mass::from_unit::<Pound>(1.0), g);
+        assert!(approx(weight.to_unit::<PoundForce>(), 1.0, 1e-9));
+    }
+
+    #[test]
+    fn energy_formulas() {
+        let mass = Mass::from_unit::<Kilogram>(2.0);
+        let v = Speed::from_unit::<MeterPerSecond>(3.0);
+        assert!(approx(
+            kinetic_energy(mass, v).to_unit::<Joule>(),
+            9.0,
+            1e-12
+        ));
+        assert!(approx(
+            kinetic_energy(mass, Speed::from_unit::<MeterPerSecond>(-3.0)).to_unit::<Joule>(),
+            9.0,
+            1e-12
+        ));
+
+        let lift = force(mass, Acceleration::from_unit::<MeterPerSecondSquared>(9.8));
+        let w = work(lift, Length::from_unit::<Meter>(10.0));
+        assert!(approx(w.to_unit::<Joule>(), 196.0, 1e-9));
+    }
+
+    #[test]
+    fn rejects_non_positive_durations() {
+        let d = Length::from_unit::<Meter>(1.0);
+        let v = Speed::from_unit::<MeterPerSecond>(1.0);
+        assert!(speed(d, Time::from_unit::<Second>(0.0)).is_err());
+        assert!(speed(d, Time::from_unit::<Second>(-1.0)).is_err());
+        assert!(acceleration(v, Time::from_unit::<Second>(f64::NAN)).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! # Spelled-Out Unit Names
+//!
+//! Natural-language output reads better with unit names than with symbols, but the name
+//! has to agree with the number: "1 foot", "2 feet", "1.5 meters". [`Quantity::named`]
+//! formats a quantity that way using each unit's [`NAME`](UnitConversion::NAME) and
+//! [`PLURAL`](UnitConversion::PLURAL).
+//!
+//! The singular is used only when the formatted number is exactly `1` or `-1`; every
+//! other value, including `0`, fractions and `1.0` written with a precision, takes the
+//! plural. The choice is made on the formatted text, so `0.9996` shown with two decimal
+//! places reads "1.00 meters" and with no decimal places reads "1 meter".
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Foot, Length, Meter};
+//!
+//! let one_foot = Length::from_unit::<Foot>(1.0);
+//! assert_eq!(one_foot.named::<Foot>().to_string(), "1 foot");
+//!
+//! let two_feet = Length::from_unit::<Foot>(2.0);
+//! assert_eq!(two_feet.named::<Foot>().to_string(), "2 feet");
+//!
+//! let run = Length::from_unit::<Meter>(1.5);
+//! assert_eq!(run.named::<Meter>().to_string(), "1.5 meters");
+//! assert_eq!(format!("{:.2}", run.named::<Meter>()), "1.50 meters");
+//! ```
+
+use std::fmt;
+
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+
+/// Returns the name of unit `U` that agrees with `number`, the number as it will be
+/// written: the singular for `"1"` and `"-1"`, the plural otherwise.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::Inch;
+/// use uom::unit_conversion::names::unit_name;
+///
+/// assert_eq!(unit_name::<Inch>("1"), "inch");
+/// assert_eq!(unit_name::<Inch>("1.0"), "inches");
+/// assert_eq!(unit_name::<Inch>("0"), "inches");
+/// ```
+pub fn unit_name<U: UnitConversion>(number: &str) -> &'static str {
+    choose(number, U::NAME, U::PLURAL)
+}
+
+pub(crate) fn choose(number: &str, singular: &'static str, plural: &'static str) -> &'static str {
+    if matches!(number, "1" | "-1") {
+        singular
+    } else {
+        plural
+    }
+}
+
+/// A quantity displayed as a number followed by its spelled-out unit name.
+///
+/// Created by [`Quantity::named`]. The formatter's precision, or else the installed
+/// [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies to the number, and
+/// the name is chosen after rounding. Units without a name (such as the
+/// ratio `Unity`) print the bare number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Named {
+    value: f64,
+    singular: &'static str,
+    plural: &'static str,
+    dimension: &'static str,
+}
+
+impl Named {
+    /// Returns the numeric value in the display unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl fmt::Display for Named {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = format_number(
+            self.value,
+            f.precision(),
+            Subject::Dimension(self.dimension),
+        );
+        let name = choose(&number, self.singular, self.plural);
+        if name.is_empty() {
+            f.write_str(&number)
+        } else {
+            write!(f, "{number} {name}")
+        }
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Returns a displayable form of this quantity in unit `V` with the unit's name
+    /// written out in the singular or plural to match the number.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let t = Temperature::from_unit::<Celsius>(-1.0);
+    /// assert_eq!(t.named::<Celsius>().to_string(), "-1 degree Celsius");
+    /// ```
+    pub fn named<V>(&self) -> Named
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Named {
+            value: self.to_unit::<V>(),
+            singular: V::NAME,
+            plural: V::PLURAL,
+            dimension: <U::Dimension as Dimension>::NAME,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "ratio"))]
+mod tests {
+    use crate::unit_conversion::length::{Foot, Inch, Length, Meter};
+    use crate::unit_conversion::ratio::{Percent, Ratio, Unity};
+
+    fn feet(value: f64) -> String {
+        Length::from_unit::<Foot>(value).named::<Foot>().to_string()
+    }
+
+    #[test]
+    fn singular_only_for_exactly_one() {
+        assert_eq!(feet(1.0), "1 foot");
+        assert_eq!(feet(-1.0), "-1 foot");
+        assert_eq!(feet(0.0), "0 feet");
+        assert_eq!(feet(-2.0), "-2 feet");
+        assert_eq!(feet(0.5), "0.5 feet");
+    }
+
+    #[test]
+    fn name_follows_rounding() {
+        let nearly_one = Length::from_unit::<Meter>(0.9996);
+        assert_eq!(format!("{:.0}", nearly_one.named::<Meter>()), "1 meter");
+        assert_eq!(format!("{:.2}", nearly_one.named::<Meter>()), "1.00 meters");
+        assert_eq!(
+            format!("{:.3}", nearly_one.named::<Meter>()),
+            "1.000 meters"
+        );
+    }
+
+    #[test]
+    fn converts_before_naming() {
+        let q = Length::from_unit::<Inch>(12.0);
+        assert_eq!(format!("{:.0}", q.named::<Foot>()), "1 foot");
+        assert!((q.named::<Foot>().value() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn unnamed_units_print_the_bare_number() {
+        let r = Ratio::from_unit::<Unity>(0.25);
+        assert_eq!(r.named::<Unity>().to_string(), "0.25");
+        assert_eq!(r.named::<Percent>().to_string(), "25 percent");
+    }
+}
@@ -0,0 +1,281 @@
+//! # Navigation Helpers
+//!
+//! Passage planning on a sphere: the great-circle distance and initial bearing between
+//! two [`Position`]s, and the speed-time-distance triangle every watch officer works
+//! in knots and nautical miles. Results are typed, so a distance comes back as a
+//! [`Length`] that reads equally well in nautical miles or kilometers, and a bearing as
+//! an [`Angle`].
+//!
+//! Distances use a spherical Earth of radius [`EARTH_RADIUS`] (the IUGG mean radius)
+//! and the haversine formula, which stays accurate for short legs. The sphere differs
+//! from the WGS 84 ellipsoid by up to about 0.5%, plenty for planning but not for
+//! surveying.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::length::NauticalMile;
+//! use uom::unit_conversion::navigation::{self, Position};
+//! use uom::unit_conversion::speed::{Knot, Speed};
+//! use uom::unit_conversion::time::Hour;
+//!
+//! let dover = Position::from_degrees(51.1279, 1.3134).unwrap();
+//! let calais = Position::from_degrees(50.9513, 1.8587).unwrap();
+//! let leg = navigation::great_circle_distance(dover, calais);
+//! assert!((leg.to_unit::<NauticalMile>() - 23.2).abs() < 0.1);
+//!
+//! let passage = navigation::time(leg, Speed::from_unit::<Knot>(18.0)).unwrap();
+//! assert!((passage.to_unit::<Hour>() - 1.29).abs() < 0.01);
+//! ```
+
+use crate::unit_conversion::angle::{Angle, Radian};
+use crate::unit_conversion::geo::{Latitude, Longitude};
+use crate::unit_conversion::length::{Length, Meter};
+use crate::unit_conversion::speed::{MeterPerSecond, Speed};
+use crate::unit_conversion::time::{Second, Time};
+use crate::unit_conversion::{Quantity, UnitError, check_positive};
+
+/// The mean radius of the Earth, 6371.0088 km, used for great-circle distances.
+pub const EARTH_RADIUS: Length = Quantity::from_base(6_371_008.8);
+
+/// A point on the Earth's surface.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Position {
+    /// The latitude, positive north.
+    pub latitude: Latitude,
+    /// The longitude, positive east.
+    pub longitude: Longitude,
+}
+
+impl Position {
+    /// Creates a position from its coordinates.
+    pub fn new(latitude: Latitude, longitude: Longitude) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Creates a position from signed decimal degrees.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if either coordinate is outside its valid
+    /// range or not finite.
+    pub fn from_degrees(latitude: f64, longitude: f64) -> Result<Self, UnitError> {
+        Ok(Self::new(
+            Latitude::from_degrees(latitude)?,
+            Longitude::from_degrees(longitude)?,
+        ))
+    }
+
+    fn radians(self) -> (f64, f64) {
+        (
+            self.latitude.angle().to_unit::<Radian>(),
+            self.longitude.angle().to_unit::<Radian>(),
+        )
+    }
+}
+
+/// Returns the distance from `from` to `to` along the great circle through them.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::Kilometer;
+/// use uom::unit_conversion::navigation::{Position, great_circle_distance};
+///
+/// let london = Position::from_degrees(51.5074, -0.1278).unwrap();
+/// let paris = Position::from_degrees(48.8566, 2.3522).unwrap();
+/// let d = great_circle_distance(london, paris);
+/// assert!((d.to_unit::<Kilometer>() - 343.6).abs() < 0.1);
+/// ```
+pub fn great_circle_distance(from: Position, to: Position) -> Length {
+    let (lat1, lon1) = from.radians();
+    let (lat2, lon2) = to.radians();
+    let half_lat = ((lat2 - lat1) / 2.0).sin();
+    let half_lon = ((lon2 - lon1) / 2.0).sin();
+    // Rounding can push h just past 1 for antipodal points
+    let h = (half_lat * half_lat + lat1.cos() * lat2.cos() * half_lon * half_lon).min(1.0);
+    let central_angle = 2.0 * h.sqrt().atan2((1.0 - h).sqrt());
+    Length::from_unit::<Meter>(EARTH_RADIUS.to_unit::<Meter>() * central_angle)
+}
+
+/// Returns the initial bearing, clockwise from true north in `[0°, 360°)`, of the
+/// great circle from `from` to `to`.
+///
+/// Along a great circle the bearing changes as the route progresses, except on a
+/// meridian or the equator; this is the course to steer on departure. The bearing from
+/// a position to itself is 0°.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::angle::Degree;
+/// use uom::unit_conversion::navigation::{Position, initial_bearing};
+///
+/// let london = Position::from_degrees(51.5074, -0.1278).unwrap();
+/// let paris = Position::from_degrees(48.8566, 2.3522).unwrap();
+/// let course = initial_bearing(london, paris);
+/// assert!((course.to_unit::<Degree>() - 148.1).abs() < 0.1);
+/// ```
+pub fn initial_bearing(from: Position, to: Position) -> Angle {
+    let (lat1, lon1) = from.radians();
+    let (lat2, lon2) = to.radians();
+    let delta_lon = lon2 - lon1;
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+    Angle::atan2(y, x).normalize_0_to_360()
+}
+
+/// Returns the speed made good over `distance` run in `time`.
+///
+/// Returns [`UnitError::OutOfRange`] if the time is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, NauticalMile};
+/// use uom::unit_conversion::navigation::speed;
+/// use uom::unit_conversion::speed::Knot;
+/// use uom::unit_conversion::time::{Hour, Time};
+///
+/// let v = speed(Length::from_unit::<NauticalMile>(45.0), Time::from_unit::<Hour>(6.0)).unwrap();
+/// assert!((v.to_unit::<Knot>() - 7.5).abs() < 1e-9);
+/// ```
+pub fn speed(distance: Length, time: Time) -> Result<Speed, UnitError> {
+    let seconds = check_positive(time.to_unit::<Second>())?;
+    Ok(Speed::from_unit::<MeterPerSecond>(
+        distance.to_unit::<Meter>() / seconds,
+    ))
+}
+
+/// Returns the distance run at `speed` for `time`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::NauticalMile;
+/// use uom::unit_conversion::navigation::distance;
+/// use uom::unit_conversion::speed::{Knot, Speed};
+/// use uom::unit_conversion::time::{Minute, Time};
+///
+/// let run = distance(Speed::from_unit::<Knot>(12.0), Time::from_unit::<Minute>(20.0));
+/// assert!((run.to_unit::<NauticalMile>() - 4.0).abs() < 1e-9);
+/// ```
+pub fn distance(speed: Speed, time: Time) -> Length {
+    Length::from_unit::<Meter>(speed.to_unit::<MeterPerSecond>() * time.to_unit::<Second>())
+}
+
+/// Returns the time to run `distance` at `speed`.
+///
+/// Returns [`UnitError::OutOfRange`] if the speed is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, NauticalMile};
+/// use uom::unit_conversion::navigation::time;
+/// use uom::unit_conversion::speed::{Knot, Speed};
+/// use uom::unit_conversion::time::{Hour, Time};
+///
+/// let eta = time(Length::from_unit::<NauticalMile>(120.0), Speed::from_unit::<Knot>(15.0)).unwrap();
+/// assert!((eta.to_unit::<Hour>() - 8.0).abs() < 1e-9);
+/// ```
+pub fn time(distance: Length, speed: Speed) -> Result<Time, UnitError> {
+    let meters_per_second = check_positive(speed.to_unit::<MeterPerSecond>())?;
+    Ok(Time::from_unit::<Second>(
+        distance.to_unit::<Meter>() / meters_per_second,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::angle::Degree;
+    use crate::unit_conversion::length::{Kilometer, NauticalMile};
+    use crate::unit_conversion::speed::Knot;
+    use crate::unit_conversion::time::Hour;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn position(latitude: f64, longitude: f64) -> Position {
+        Position::from_degrees(latitude, longitude).unwrap()
+    }
+
+    #[test]
+    fn minute_of_latitude_is_about_a_nautical_mile() {
+        let d = great_circle_distance(position(45.0, -30.0), position(45.0 + 1.0 / 60.0, -30.0));
+        assert!(approx(d.to_unit::<NauticalMile>(), 1.0, 1e-3));
+
+        let quarter = great_circle_distance(position(0.0, 0.0), position(90.0, 0.0));
+        let expected = EARTH_RADIUS.to_unit::<Kilometer>() * std::f64::consts::FRAC_PI_2;
+        assert!(approx(quarter.to_unit::<Kilometer>(), expected, 1e-9));
+
+        let antipode = great_circle_distance(position(10.0, 20.0), position(-10.0, -160.0));
+        let expected = EARTH_RADIUS.to_unit::<Kilometer>() * std::f64::consts::PI;
+        assert!(approx(antipode.to_unit::<Kilometer>(), expected, 1e-3));
+    }
+
+    #[test]
+    fn distances_match_known_routes() {
+        // Land's End to John o' Groats, and New York JFK to London Heathrow
+        let lands_end = position(50.0664, -5.7147);
+        let john_o_groats = position(58.6439, -3.07);
+        let d = great_circle_distance(lands_end, john_o_groats);
+        assert!(approx(d.to_unit::<Kilometer>(), 968.85, 0.01));
+        assert!(approx(
+            initial_bearing(lands_end, john_o_groats).to_unit::<Degree>(),
+            9.12,
+            0.01
+        ));
+
+        let jfk = position(40.6413, -73.7781);
+        let lhr = position(51.47, -0.4543);
+        let d = great_circle_distance(jfk, lhr);
+        assert!(approx(d.to_unit::<NauticalMile>(), 2991.37, 0.01));
+        assert_eq!(d, great_circle_distance(lhr, jfk));
+        assert!(approx(
+            initial_bearing(jfk, lhr).to_unit::<Degree>(),
+            51.35,
+            0.01
+        ));
+    }
+
+    #[test]
+    fn bearings_cover_the_compass() {
+        let origin = position(0.0, 0.0);
+        let cases = [
+            (position(1.0, 0.0), 0.0),
+            (position(0.0, 1.0), 90.0),
+            (position(-1.0, 0.0), 180.0),
+            (position(0.0, -1.0), 270.0),
+        ];
+        for (to, expected) in cases {
+            assert!(approx(
+                initial_bearing(origin, to).to_unit::<Degree>(),
+                expected,
+                1e-9
+            ));
+        }
+        assert_eq!(initial_bearing(origin, origin).to_unit::<Degree>(), 0.0);
+    }
+
+    #[test]
+    fn speed_time_distance_triangle() {
+        let run = Length::from_unit::<NauticalMile>(36.0);
+        let elapsed = Time::from_unit::<Hour>(4.0);
+        let v = speed(run, elapsed).unwrap();
+        assert!(approx(v.to_unit::<Knot>(), 9.0, 1e-9));
+        assert!(approx(
+            distance(v, elapsed).to_unit::<NauticalMile>(),
+            36.0,
+            1e-9
+        ));
+        assert!(approx(time(run, v).unwrap().to_unit::<Hour>(), 4.0, 1e-9));
+
+        assert!(speed(run, Time::from_unit::<Hour>(0.0)).is_err());
+        assert!(time(run, Speed::from_unit::<Knot>(0.0)).is_err());
+        assert!(time(run, Speed::from_unit::<Knot>(-5.0)).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! # Network Throughput Helpers
+//!
+//! Network engineering mixes three dimensions: links are rated as a [`DataRate`], queues
+//! and windows hold a [`DataSize`], and latency is a [`Time`]. This module relates them:
+//!
+//! - [`bdp`] gives the bandwidth-delay product, the data in flight on a link, which is
+//!   the smallest TCP window or buffer that keeps the link busy.
+//! - [`packet_rate`] and [`throughput`] convert between a data rate and a rate in
+//!   [`PacketPerSecond`] for packets of a given size.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::data_rate::{DataRate, MegabitPerSecond};
+//! use uom::unit_conversion::data_size::Megabyte;
+//! use uom::unit_conversion::network::bdp;
+//! use uom::unit_conversion::time::{Millisecond, Time};
+//!
+//! let link = DataRate::from_unit::<MegabitPerSecond>(100.0);
+//! let rtt = Time::from_unit::<Millisecond>(80.0);
+//! assert!((bdp(link, rtt).to_unit::<Megabyte>() - 1.0).abs() < 1e-12);
+//! ```
+
+use crate::unit_conversion::data_rate::DataRate;
+use crate::unit_conversion::data_size::DataSize;
+use crate::unit_conversion::frequency::{Frequency, FrequencyDimension};
+use crate::unit_conversion::time::Time;
+use crate::unit_conversion::{UnitConversion, UnitError, check_positive};
+
+/// Packets per second rate unit.
+///
+/// A count of packets per second, measured in the frequency dimension: one packet per
+/// second is one hertz.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::frequency::{Frequency, Kilohertz};
+/// use uom::unit_conversion::network::PacketPerSecond;
+///
+/// let load = Frequency::from_unit::<PacketPerSecond>(14_880.0);
+/// assert!((load.to_unit::<Kilohertz>() - 14.88).abs() < 1e-12);
+/// ```
+pub struct PacketPerSecond;
+
+impl UnitConversion for PacketPerSecond {
+    type Dimension = FrequencyDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value
+    }
+
+    const SYMBOL: &'static str = "pps";
+    const NAME: &'static str = "packet per second";
+    const PLURAL: &'static str = "packets per second";
+}
+
+/// Returns the bandwidth-delay product of a link: the amount of data sent during one
+/// round trip `rtt` at `rate`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, GigabitPerSecond};
+/// use uom::unit_conversion::data_size::Mebibyte;
+/// use uom::unit_conversion::network::bdp;
+/// use uom::unit_conversion::time::{Millisecond, Time};
+///
+/// // A transatlantic 10 Gbit/s path needs a window of about 83 MiB
+/// let window = bdp(
+///     DataRate::from_unit::<GigabitPerSecond>(10.0),
+///     Time::from_unit::<Millisecond>(70.0),
+/// );
+/// assert!((window.to_unit::<Mebibyte>() - 83.45).abs() < 0.01);
+/// ```
+pub fn bdp(rate: DataRate, rtt: Time) -> DataSize {
+    DataSize::from_base(rate.in_base() * rtt.in_base())
+}
+
+/// Returns the packet rate that carries `rate` in packets of size `packet`.
+///
+/// Returns [`UnitError::OutOfRange`] if the packet size is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::{DataRate, GigabitPerSecond};
+/// use uom::unit_conversion::data_size::{Byte, DataSize};
+/// use uom::unit_conversion::network::{PacketPerSecond, packet_rate};
+///
+/// // Minimum-size Ethernet frames, with preamble and inter-frame gap, on a 1 Gbit/s link
+/// let wire = DataSize::from_unit::<Byte>(84.0);
+/// let rate = packet_rate(DataRate::from_unit::<GigabitPerSecond>(1.0), wire).unwrap();
+/// assert!((rate.to_unit::<PacketPerSecond>() - 1_488_095.2).abs() < 0.1);
+/// ```
+pub fn packet_rate(rate: DataRate, packet: DataSize) -> Result<Frequency, UnitError> {
+    let bits = check_positive(packet.in_base())?;
+    Ok(Frequency::from_base(rate.in_base() / bits))
+}
+
+/// Returns the data rate of `packets` packets per second, each of size `packet`.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::data_rate::MegabitPerSecond;
+/// use uom::unit_conversion::data_size::{Byte, DataSize};
+/// use uom::unit_conversion::frequency::Frequency;
+/// use uom::unit_conversion::network::{PacketPerSecond, throughput};
+///
+/// let packets = Frequency::from_unit::<PacketPerSecond>(10_000.0);
+/// let rate = throughput(packets, DataSize::from_unit::<Byte>(1500.0));
+/// assert!((rate.to_unit::<MegabitPerSecond>() - 120.0).abs() < 1e-9);
+/// ```
+pub fn throughput(packets: Frequency, packet: DataSize) -> DataRate {
+    DataRate::from_base(packets.in_base() * packet.in_base())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::Dimension;
+    use crate::unit_conversion::data_rate::{KilobitPerSecond, MegabitPerSecond};
+    use crate::unit_conversion::data_size::{Byte, Kilobyte};
+    use crate::unit_conversion::time::{Millisecond, Second};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn bandwidth_delay_product() {
+        let rate = DataRate::from_unit::<MegabitPerSecond>(10.0);
+        let window = bdp(rate, Time::from_unit::<Millisecond>(100.0));
+        assert!(approx(window.to_unit::<Kilobyte>(), 125.0, 1e-9));
+
+        let idle = bdp(rate, Time::from_unit::<Second>(0.0));
+        assert_eq!(idle.to_unit::<Byte>(), 0.0);
+    }
+
+    #[test]
+    fn packets_and_throughput() {
+        let packet = DataSize::from_unit::<Byte>(1250.0);
+        let rate = DataRate::from_unit::<KilobitPerSecond>(1000.0);
+        let packets = packet_rate(rate, packet).unwrap();
+        assert!(approx(packets.to_unit::<PacketPerSecond>(), 100.0, 1e-9));
+        assert!(approx(
+            throughput(packets, packet).to_unit::<KilobitPerSecond>(),
+            1000.0,
+            1e-9
+        ));
+
+        assert!(packet_rate(rate, DataSize::from_unit::<Byte>(0.0)).is_err());
+    }
+
+    #[test]
+    fn packets_per_second_is_a_frequency_unit() {
+        assert_eq!(FrequencyDimension::find_unit("pps").unwrap().symbol, "pps");
+        assert_eq!(PacketPerSecond::SYMBOL, "pps");
+    }
+}
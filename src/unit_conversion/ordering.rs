@@ -0,0 +1,232 @@
+//! # Sorting and Extrema
+//!
+//! Quantities are only [`PartialOrd`], because a NaN value compares as neither less
+//! than nor greater than anything. The helpers in this module sort slices of quantities
+//! and find their minimum and maximum under an explicit [`NanPolicy`], so callers decide
+//! once how NaN should behave instead of unwrapping `partial_cmp` at every call site.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::ordering::{NanPolicy, max_of, sort_quantities};
+//!
+//! let mut runs: Vec<Length> = [5000.0, f64::NAN, 1200.0]
+//!     .into_iter()
+//!     .map(Length::from_unit::<Meter>)
+//!     .collect();
+//!
+//! sort_quantities(&mut runs, NanPolicy::Largest).unwrap();
+//! assert_eq!(runs[0].to_unit::<Meter>(), 1200.0);
+//! assert!(runs[2].to_unit::<Meter>().is_nan());
+//!
+//! // Treating NaN as smallest keeps it out of the maximum
+//! let longest = max_of(runs, NanPolicy::Smallest).unwrap().unwrap();
+//! assert_eq!(longest.to_unit::<Meter>(), 5000.0);
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError};
+
+/// How NaN quantities are ordered relative to other values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaN sorts before every other value, so it wins [`min_of`] and is ignored by
+    /// [`max_of`] unless every value is NaN.
+    Smallest,
+    /// NaN sorts after every other value, so it wins [`max_of`] and is ignored by
+    /// [`min_of`] unless every value is NaN.
+    Largest,
+    /// Any NaN makes the operation fail with [`UnitError::NonFinite`].
+    Reject,
+}
+
+impl NanPolicy {
+    /// Compares two quantities, placing NaN according to this policy.
+    ///
+    /// Two NaN values compare equal. Returns [`UnitError::NonFinite`] under
+    /// [`NanPolicy::Reject`] if either quantity is NaN.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use std::cmp::Ordering;
+    /// use uom::unit_conversion::length::{Length, Meter};
+    /// use uom::unit_conversion::ordering::NanPolicy;
+    ///
+    /// let nan = Length::from_unit::<Meter>(f64::NAN);
+    /// let one = Length::from_unit::<Meter>(1.0);
+    /// assert_eq!(NanPolicy::Smallest.compare(nan, one), Ok(Ordering::Less));
+    /// assert!(NanPolicy::Reject.compare(nan, one).is_err());
+    /// ```
+    pub fn compare<U: UnitConversion>(
+        self,
+        a: Quantity<U>,
+        b: Quantity<U>,
+    ) -> Result<Ordering, UnitError> {
+        let (a, b) = (a.in_base(), b.in_base());
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => Ok(a.partial_cmp(&b).unwrap_or(Ordering::Equal)),
+            _ if self == NanPolicy::Reject => Err(UnitError::NonFinite { value: f64::NAN }),
+            (true, true) => Ok(Ordering::Equal),
+            (true, false) if self == NanPolicy::Smallest => Ok(Ordering::Less),
+            (true, false) => Ok(Ordering::Greater),
+            (false, true) if self == NanPolicy::Smallest => Ok(Ordering::Greater),
+            (false, true) => Ok(Ordering::Less),
+        }
+    }
+}
+
+/// Sorts quantities in ascending order, placing NaN according to `nan`.
+///
+/// The sort is stable. Under [`NanPolicy::Reject`] the slice is left unchanged and
+/// [`UnitError::NonFinite`] is returned if it contains NaN.
+pub fn sort_quantities<U: UnitConversion>(
+    items: &mut [Quantity<U>],
+    nan: NanPolicy,
+) -> Result<(), UnitError> {
+    if nan == NanPolicy::Reject && items.iter().any(|q| q.in_base().is_nan()) {
+        return Err(UnitError::NonFinite { value: f64::NAN });
+    }
+    items.sort_by(|&a, &b| nan.compare(a, b).unwrap_or(Ordering::Equal));
+    Ok(())
+}
+
+/// Returns the smallest quantity, or `None` if there are none.
+///
+/// NaN is placed according to `nan`. When several quantities compare equal the first
+/// is returned.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Kilometer, Length, Meter};
+/// use uom::unit_conversion::ordering::{NanPolicy, min_of};
+///
+/// let routes = [Length::from_unit::<Kilometer>(1.2), Length::from_unit::<Meter>(850.0)];
+/// let shortest = min_of(routes, NanPolicy::Reject).unwrap().unwrap();
+/// assert_eq!(shortest.to_unit::<Meter>(), 850.0);
+/// ```
+pub fn min_of<U, I>(items: I, nan: NanPolicy) -> Result<Option<Quantity<U>>, UnitError>
+where
+    U: UnitConversion,
+    I: IntoIterator<Item = Quantity<U>>,
+{
+    extreme_of(items, nan, Ordering::Less)
+}
+
+/// Returns the largest quantity, or `None` if there are none.
+///
+/// NaN is placed according to `nan`. When several quantities compare equal the first
+/// is returned.
+pub fn max_of<U, I>(items: I, nan: NanPolicy) -> Result<Option<Quantity<U>>, UnitError>
+where
+    U: UnitConversion,
+    I: IntoIterator<Item = Quantity<U>>,
+{
+    extreme_of(items, nan, Ordering::Greater)
+}
+
+/// Returns the first item that no later item beats in the direction of `wanted`.
+fn extreme_of<U, I>(
+    items: I,
+    nan: NanPolicy,
+    wanted: Ordering,
+) -> Result<Option<Quantity<U>>, UnitError>
+where
+    U: UnitConversion,
+    I: IntoIterator<Item = Quantity<U>>,
+{
+    let mut best: Option<Quantity<U>> = None;
+    for item in items {
+        best = match best {
+            Some(current) if nan.compare(item, current)? != wanted => Some(current),
+            _ => Some(item),
+        };
+    }
+    Ok(best)
+}
+
+#[cfg(all(test, feature = "length"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Length, Meter};
+
+    fn meters(values: &[f64]) -> Vec<Length> {
+        values
+            .iter()
+            .map(|&v| Length::from_unit::<Meter>(v))
+            .collect()
+    }
+
+    fn values(items: &[Length]) -> Vec<f64> {
+        items.iter().map(|q| q.to_unit::<Meter>()).collect()
+    }
+
+    #[test]
+    fn sorts_with_nan_at_either_end() {
+        let mut items = meters(&[3.0, f64::NAN, -1.0, 2.0]);
+        sort_quantities(&mut items, NanPolicy::Largest).unwrap();
+        let sorted = values(&items);
+        assert_eq!(&sorted[..3], &[-1.0, 2.0, 3.0]);
+        assert!(sorted[3].is_nan());
+
+        sort_quantities(&mut items, NanPolicy::Smallest).unwrap();
+        let sorted = values(&items);
+        assert!(sorted[0].is_nan());
+        assert_eq!(&sorted[1..], &[-1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn reject_leaves_slice_untouched() {
+        let mut items = meters(&[3.0, f64::NAN, 1.0]);
+        assert!(matches!(
+            sort_quantities(&mut items, NanPolicy::Reject),
+            Err(UnitError::NonFinite { .. })
+        ));
+        assert_eq!(items[0].to_unit::<Meter>(), 3.0);
+
+        let mut items = meters(&[3.0, 1.0]);
+        sort_quantities(&mut items, NanPolicy::Reject).unwrap();
+        assert_eq!(values(&items), [1.0, 3.0]);
+    }
+
+    #[test]
+    fn extrema_follow_policy() {
+        let items = meters(&[f64::NAN, 4.0, -2.0]);
+        let min = |nan| min_of(items.clone(), nan).map(|q| q.map(|q| q.to_unit::<Meter>()));
+        let max = |nan| max_of(items.clone(), nan).map(|q| q.map(|q| q.to_unit::<Meter>()));
+
+        assert_eq!(min(NanPolicy::Largest), Ok(Some(-2.0)));
+        assert_eq!(max(NanPolicy::Smallest), Ok(Some(4.0)));
+        assert!(min(NanPolicy::Smallest).unwrap().unwrap().is_nan());
+        assert!(max(NanPolicy::Largest).unwrap().unwrap().is_nan());
+        assert!(min(NanPolicy::Reject).is_err());
+    }
+
+    #[test]
+    fn empty_and_all_nan_inputs() {
+        assert_eq!(min_of(Vec::<Length>::new(), NanPolicy::Reject), Ok(None));
+        let nans = meters(&[f64::NAN, f64::NAN]);
+        assert!(
+            max_of(nans, NanPolicy::Smallest)
+                .unwrap()
+                .unwrap()
+                .to_unit::<Meter>()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn ties_keep_the_first() {
+        let items = meters(&[0.0, -0.0]);
+        let min = min_of(items.clone(), NanPolicy::Reject).unwrap().unwrap();
+        let max = max_of(items, NanPolicy::Reject).unwrap().unwrap();
+        assert!(min.to_unit::<Meter>().is_sign_positive());
+        assert!(max.to_unit::<Meter>().is_sign_positive());
+    }
+}
@@ -0,0 +1,269 @@
+//! # Paper Sizes
+//!
+//! This module provides the ISO 216 A and B series and the common North American paper
+//! sizes as typed [`Length`] pairs, and identifies a sheet from its measured dimensions.
+//!
+//! Sizes are given in portrait orientation, width first. ISO sizes use the millimeter
+//! values from the standard, which are rounded from the exact √2 aspect ratio.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::length::{Inch, Length, Millimeter};
+//! use uom::unit_conversion::paper::PaperSize;
+//!
+//! let (width, height) = PaperSize::A4.dimensions();
+//! assert_eq!(width.to_unit::<Millimeter>(), 210.0);
+//! assert_eq!(height.to_unit::<Millimeter>(), 297.0);
+//!
+//! // A landscape US Letter page
+//! let found = PaperSize::from_dimensions(
+//!     Length::from_unit::<Inch>(11.0),
+//!     Length::from_unit::<Inch>(8.5),
+//! );
+//! assert_eq!(found, Some(PaperSize::Letter));
+//! ```
+
+use crate::unit_conversion::length::{Length, Millimeter};
+
+/// How far a measured side may be from the nominal size and still match, in millimeters.
+///
+/// This is the ISO 216 tolerance for sides between 150 and 600 mm.
+const MATCH_TOLERANCE_MM: f64 = 2.0;
+
+/// A standard paper size.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::Inch;
+/// use uom::unit_conversion::paper::PaperSize;
+///
+/// let (width, height) = PaperSize::Legal.dimensions();
+/// assert!((width.to_unit::<Inch>() - 8.5).abs() < 1e-12);
+/// assert!((height.to_unit::<Inch>() - 14.0).abs() < 1e-12);
+/// assert_eq!(PaperSize::Legal.name(), "Legal");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaperSize {
+    A0,
+    A1,
+    A2,
+    A3,
+    A4,
+    A5,
+    A6,
+    A7,
+    A8,
+    A9,
+    A10,
+    B0,
+    B1,
+    B2,
+    B3,
+    B4,
+    B5,
+    B6,
+    B7,
+    B8,
+    B9,
+    B10,
+    /// US Letter, 8.5 × 11 in
+    Letter,
+    /// US Legal, 8.5 × 14 in
+    Legal,
+    /// Tabloid, 11 × 17 in (Ledger in landscape)
+    Tabloid,
+    /// Executive, 7.25 × 10.5 in
+    Executive,
+}
+
+/// Nominal portrait dimensions of every size, in millimeters.
+const SIZES: &[(PaperSize, &str, f64, f64)] = &[
+    (PaperSize::A0, "A0", 841.0, 1189.0),
+    (PaperSize::A1, "A1", 594.0, 841.0),
+    (PaperSize::A2, "A2", 420.0, 594.0),
+    (PaperSize::A3, "A3", 297.0, 420.0),
+    (PaperSize::A4, "A4", 210.0, 297.0),
+    (PaperSize::A5, "A5", 148.0, 210.0),
+    (PaperSize::A6, "A6", 105.0, 148.0),
+    (PaperSize::A7, "A7", 74.0, 105.0),
+    (PaperSize::A8, "A8", 52.0, 74.0),
+    (PaperSize::A9, "A9", 37.0, 52.0),
+    (PaperSize::A10, "A10", 26.0, 37.0),
+    (PaperSize::B0, "B0", 1000.0, 1414.0),
+    (PaperSize::B1, "B1", 707.0, 1000.0),
+    (PaperSize::B2, "B2", 500.0, 707.0),
+    (PaperSize::B3, "B3", 353.0, 500.0),
+    (PaperSize::B4, "B4", 250.0, 353.0),
+    (PaperSize::B5, "B5", 176.0, 250.0),
+    (PaperSize::B6, "B6", 125.0, 176.0),
+    (PaperSize::B7, "B7", 88.0, 125.0),
+    (PaperSize::B8, "B8", 62.0, 88.0),
+    (PaperSize::B9, "B9", 44.0, 62.0),
+    (PaperSize::B10, "B10", 31.0, 44.0),
+    (PaperSize::Letter, "Letter", 215.9, 279.4),
+    (PaperSize::Legal, "Legal", 215.9, 355.6),
+    (PaperSize::Tabloid, "Tabloid", 279.4, 431.8),
+    (PaperSize::Executive, "Executive", 184.15, 266.7),
+];
+
+impl PaperSize {
+    /// Every supported paper size, A series first, then B series, then US sizes.
+    pub const ALL: [PaperSize; 26] = [
+        PaperSize::A0,
+        PaperSize::A1,
+        PaperSize::A2,
+        PaperSize::A3,
+        PaperSize::A4,
+        PaperSize::A5,
+        PaperSize::A6,
+        PaperSize::A7,
+        PaperSize::A8,
+        PaperSize::A9,
+        PaperSize::A10,
+        PaperSize::B0,
+        PaperSize::B1,
+        PaperSize::B2,
+        PaperSize::B3,
+        PaperSize::B4,
+        PaperSize::B5,
+        PaperSize::B6,
+        PaperSize::B7,
+        PaperSize::B8,
+        PaperSize::B9,
+        PaperSize::B10,
+        PaperSize::Letter,
+        PaperSize::Legal,
+        PaperSize::Tabloid,
+        PaperSize::Executive,
+    ];
+
+    /// Returns the conventional name of this size (e.g., "A4" or "Letter").
+    pub fn name(self) -> &'static str {
+        self.entry().1
+    }
+
+    /// Returns the `(width, height)` of this size in portrait orientation.
+    pub fn dimensions(self) -> (Length, Length) {
+        let (_, _, width, height) = *self.entry();
+        (
+            Length::from_unit::<Millimeter>(width),
+            Length::from_unit::<Millimeter>(height),
+        )
+    }
+
+    /// Returns the paper size whose sides match the given dimensions.
+    ///
+    /// The dimensions may be given in either orientation, and each side may differ from
+    /// the nominal size by up to 2 mm to allow for measurement and cutting error.
+    /// Returns `None` if no standard size matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::length::{Length, Millimeter};
+    /// use uom::unit_conversion::paper::PaperSize;
+    ///
+    /// let measured = |mm| Length::from_unit::<Millimeter>(mm);
+    /// assert_eq!(
+    ///     PaperSize::from_dimensions(measured(148.5), measured(209.0)),
+    ///     Some(PaperSize::A5)
+    /// );
+    /// assert_eq!(PaperSize::from_dimensions(measured(100.0), measured(100.0)), None);
+    /// ```
+    pub fn from_dimensions(width: Length, height: Length) -> Option<PaperSize> {
+        let (a, b) = (
+            width.to_unit::<Millimeter>(),
+            height.to_unit::<Millimeter>(),
+        );
+        let (short, long) = if a <= b { (a, b) } else { (b, a) };
+        SIZES
+            .iter()
+            .find(|(_, _, w, h)| {
+                (short - w).abs() <= MATCH_TOLERANCE_MM && (long - h).abs() <= MATCH_TOLERANCE_MM
+            })
+            .map(|(size, ..)| *size)
+    }
+
+    fn entry(self) -> &'static (PaperSize, &'static str, f64, f64) {
+        SIZES
+            .iter()
+            .find(|(size, ..)| *size == self)
+            .expect("every paper size has a table entry")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::Inch;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn iso_series_halve_in_area() {
+        for series in [&PaperSize::ALL[..11], &PaperSize::ALL[11..22]] {
+            for pair in series.windows(2) {
+                let (w0, h0) = pair[0].dimensions();
+                let (w1, h1) = pair[1].dimensions();
+                // Each size is the previous one folded in half, give or take rounding
+                assert!(approx(
+                    h1.to_unit::<Millimeter>(),
+                    w0.to_unit::<Millimeter>(),
+                    0.0
+                ));
+                assert!(approx(
+                    w1.to_unit::<Millimeter>(),
+                    h0.to_unit::<Millimeter>() / 2.0,
+                    1.0
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn us_sizes_are_exact_inches() {
+        let (width, height) = PaperSize::Letter.dimensions();
+        assert!(approx(width.to_unit::<Inch>(), 8.5, 1e-12));
+        assert!(approx(height.to_unit::<Inch>(), 11.0, 1e-12));
+
+        let (width, height) = PaperSize::Executive.dimensions();
+        assert!(approx(width.to_unit::<Inch>(), 7.25, 1e-12));
+        assert!(approx(height.to_unit::<Inch>(), 10.5, 1e-12));
+    }
+
+    #[test]
+    fn every_size_round_trips() {
+        for size in PaperSize::ALL {
+            let (width, height) = size.dimensions();
+            assert_eq!(PaperSize::from_dimensions(width, height), Some(size));
+            assert_eq!(PaperSize::from_dimensions(height, width), Some(size));
+        }
+    }
+
+    #[test]
+    fn lookup_respects_tolerance() {
+        let mm = |value| Length::from_unit::<Millimeter>(value);
+        assert_eq!(
+            PaperSize::from_dimensions(mm(212.0), mm(295.0)),
+            Some(PaperSize::A4)
+        );
+        assert_eq!(PaperSize::from_dimensions(mm(213.0), mm(297.0)), None);
+        assert_eq!(
+            PaperSize::from_dimensions(mm(216.0), mm(279.0)),
+            Some(PaperSize::Letter)
+        );
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for (i, size) in PaperSize::ALL.iter().enumerate() {
+            for other in &PaperSize::ALL[i + 1..] {
+                assert_ne!(size.name(), other.name());
+            }
+        }
+    }
+}
@@ -0,0 +1,792 @@
+//! # Quantity Parsing
+//!
+//! This module parses strings such as `"25 °C"` or `"3.2km"` into typed quantities.
+//!
+//! The unit is detected from the symbols and aliases listed by the target dimension
+//! (see [`Dimension`]) and the units registered for it at runtime with
+//! [`register_unit`](super::registry::register_unit), so `Length::parse` only ever
+//! accepts length units. Whitespace
+//! between the number and the unit is optional, and symbols are matched
+//! case-insensitively when there is no exact match. Compound customary forms such as
+//! `5'11"`, `6 ft 2 in`, `1h23m45s`, and `12°34'56"` are summed segment by segment.
+//! Numbers use Rust float syntax by default; [`NumberLocale`] enables comma decimal
+//! separators and digit grouping for pasted input such as `"1 234,5 km"`. Plain parsing
+//! never allocates unless it fails, whether or not units have been registered.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::temperature::{Temperature, Fahrenheit};
+//!
+//! let distance = Length::parse("3.2km").unwrap();
+//! assert_eq!(distance.to_unit::<Meter>(), 3200.0);
+//!
+//! let temp: Temperature = "25 celsius".parse().unwrap();
+//! assert!((temp.to_unit::<Fahrenheit>() - 77.0).abs() < 1e-9);
+//!
+//! // Compound customary input
+//! use uom::unit_conversion::length::Inch;
+//! let height = Length::parse("5'11\"").unwrap();
+//! assert!((height.to_unit::<Inch>() - 71.0).abs() < 1e-9);
+//!
+//! // Units from another dimension are rejected
+//! use uom::unit_conversion::UnitError;
+//! assert_eq!(
+//!     Temperature::parse("3.2 km"),
+//!     Err(UnitError::DimensionMismatch { expected: "temperature", found: "length" })
+//! );
+//! ```
+
+use std::str::FromStr;
+
+use crate::unit_conversion::registry::{self, find_unit_of};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError, check_finite};
+
+/// How numbers are written in the text being parsed.
+///
+/// The plain format used by [`Quantity::parse`] accepts Rust float syntax only. Other
+/// locales change the decimal separator and allow digit grouping, so that pasted values
+/// such as `"1 234,5 km"` or `"1,234.5 km"` are understood.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Meter};
+/// use uom::unit_conversion::parse::NumberLocale;
+///
+/// let european = Length::parse_with("1 234,5 m", NumberLocale::EUROPEAN).unwrap();
+/// assert_eq!(european.to_unit::<Meter>(), 1234.5);
+///
+/// let english = Length::parse_with("1,234.5 m", NumberLocale::ENGLISH).unwrap();
+/// assert_eq!(english.to_unit::<Meter>(), 1234.5);
+///
+/// let custom = NumberLocale::new(',', &['\'']);
+/// let swiss = Length::parse_with("1'234,5 m", custom).unwrap();
+/// assert_eq!(swiss.to_unit::<Meter>(), 1234.5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// The character separating the integer and fractional parts.
+    pub decimal_separator: char,
+    /// Characters that may group digits and are ignored when parsing.
+    ///
+    /// Grouping is only accepted in the integer part, with groups of exactly three
+    /// digits after the first, so `"1,5"` is never read as fifteen.
+    pub group_separators: &'static [char],
+}
+
+impl NumberLocale {
+    /// Rust float syntax: `.` as the decimal separator and no digit grouping.
+    pub const PLAIN: NumberLocale = NumberLocale::new('.', &[]);
+
+    /// `.` as the decimal separator, with commas, underscores, and thin spaces
+    /// accepted as digit grouping (`1,234.5`).
+    pub const ENGLISH: NumberLocale = NumberLocale::new('.', &[',', '_', '\u{2009}', '\u{202F}']);
+
+    /// `,` as the decimal separator, with periods, underscores, and regular, thin, or
+    /// no-break spaces accepted as digit grouping (`1 234,5` or `1.234,5`).
+    pub const EUROPEAN: NumberLocale =
+        NumberLocale::new(',', &['.', '_', ' ', '\u{A0}', '\u{2009}', '\u{202F}']);
+
+    /// Creates a number format from a decimal separator and grouping characters.
+    pub const fn new(decimal_separator: char, group_separators: &'static [char]) -> Self {
+        Self {
+            decimal_separator,
+            group_separators,
+        }
+    }
+
+    /// Parses `text`, surrounding whitespace aside, as a single number in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::parse::NumberLocale;
+    ///
+    /// assert_eq!(NumberLocale::EUROPEAN.parse_number(" 1.234,5 "), Some(1234.5));
+    /// assert_eq!(NumberLocale::PLAIN.parse_number("1,5"), None);
+    /// assert_eq!(NumberLocale::ENGLISH.parse_number("0,5"), None);
+    /// ```
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        match split_number(text.trim(), *self).ok()? {
+            (value, "") => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `c` can be part of a number in this format.
+    fn is_number_char(&self, c: char) -> bool {
+        matches!(c, '0'..='9' | '+' | '-' | 'e' | 'E')
+            || c == self.decimal_separator
+            || self.group_separators.contains(&c)
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+/// Splits `input` into its leading number and the remaining text.
+///
+/// The longest prefix made of number characters that parses as an `f64` is taken,
+/// so `"2e3m"` yields `2000` while `"5em"` yields `5` followed by `"em"`. Only
+/// non-plain locales allocate, to rewrite the number into Rust float syntax.
+///
+/// A group separator that is not followed by a digit ends the number, so the space
+/// in `"1 234 km"` is left with the unit. One that is followed by a digit must sit in
+/// the integer part, between groups of three digits after a first group of one to
+/// three; anything else is rejected rather than silently dropped.
+///
+/// Returns the reason for a [`UnitError::ParseError`] if there is no number.
+pub(crate) fn split_number(input: &str, locale: NumberLocale) -> Result<(f64, &str), &'static str> {
+    const NO_NUMBER: &str = "expected a number";
+    const MISPLACED: &str = "misplaced digit group separator";
+
+    let candidate = input
+        .find(|c: char| !locale.is_number_char(c))
+        .unwrap_or(input.len());
+
+    if locale == NumberLocale::PLAIN {
+        return (1..=candidate)
+            .rev()
+            .find_map(|end| {
+                input[..end]
+                    .parse()
+                    .ok()
+                    .map(|value| (value, &input[end..]))
+            })
+            .ok_or(NO_NUMBER);
+    }
+
+    // Rewrite into Rust float syntax, remembering where each kept char ends in `input`.
+    let mut normalized = String::with_capacity(candidate);
+    let mut ends = Vec::with_capacity(candidate);
+    // Digits since the last separator, and whether the integer part is grouped at all.
+    let mut group = 0;
+    let mut grouped = false;
+    let mut in_integer = true;
+    for (i, c) in input[..candidate].char_indices() {
+        if locale.group_separators.contains(&c) {
+            let next = input[i + c.len_utf8()..].chars().next();
+            if !next.is_some_and(|next| next.is_ascii_digit()) {
+                break;
+            }
+            if !in_integer || group == 0 || group > 3 || (grouped && group != 3) {
+                return Err(MISPLACED);
+            }
+            grouped = true;
+            group = 0;
+            continue;
+        }
+        if c.is_ascii_digit() {
+            group += 1;
+        } else if in_integer && (c == locale.decimal_separator || matches!(c, 'e' | 'E')) {
+            if grouped && group != 3 {
+                return Err(MISPLACED);
+            }
+            in_integer = false;
+        }
+        normalized.push(if c == locale.decimal_separator {
+            '.'
+        } else {
+            c
+        });
+        ends.push(i + c.len_utf8());
+    }
+    if in_integer && grouped && group != 3 {
+        return Err(MISPLACED);
+    }
+    (1..=normalized.len())
+        .rev()
+        .find_map(|len| {
+            normalized[..len]
+                .parse()
+                .ok()
+                .map(|value| (value, &input[ends[len - 1]..]))
+        })
+        .ok_or(NO_NUMBER)
+}
+
+impl<U: UnitConversion> Quantity<U>
+where
+    U::Dimension: Dimension,
+{
+    /// Parses a quantity from a number followed by a unit of this dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] describing why the input was rejected:
+    /// [`UnitError::ParseError`] for malformed text, [`UnitError::DimensionMismatch`]
+    /// for a unit of another dimension, [`UnitError::UnknownUnit`] (with close matches
+    /// for a likely typo) for an unrecognized unit, and [`UnitError::NonFinite`] if the
+    /// value overflows. With the `strict_physics` feature, a physically impossible value
+    /// such as "-5 K" is a [`UnitError::OutOfRange`] in base units.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Temperature, Kelvin};
+    ///
+    /// let temp = Temperature::parse(" -40 °F ").unwrap();
+    /// assert!((temp.to_unit::<Kelvin>() - 233.15).abs() < 1e-9);
+    ///
+    /// let err = Temperature::parse("20 parsecs").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "unknown temperature unit 'parsecs' (expected one of: K, °C, °F, °De, °N, °Rø)"
+    /// );
+    ///
+    /// let err = Temperature::parse("20 Farenheit").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "unknown temperature unit 'Farenheit' (did you mean 'fahrenheit'?)"
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, UnitError> {
+        Self::parse_with(input, NumberLocale::PLAIN)
+    }
+
+    /// Parses a quantity whose number is written in the given [`NumberLocale`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] as described for [`Quantity::parse`].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Kilometer};
+    /// use uom::unit_conversion::parse::NumberLocale;
+    ///
+    /// let distance = Length::parse_with("1\u{2009}234,5 km", NumberLocale::EUROPEAN).unwrap();
+    /// assert_eq!(distance.to_unit::<Kilometer>(), 1234.5);
+    /// ```
+    pub fn parse_with(input: &str, locale: NumberLocale) -> Result<Self, UnitError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(parse_error(input, "expected a quantity"));
+        }
+
+        let (value, rest) =
+            split_number(input, locale).map_err(|reason| parse_error(input, reason))?;
+        let unit = rest.trim();
+        if unit.is_empty() {
+            return Err(parse_error(input, "expected a unit after the number"));
+        }
+
+        let base = match find_unit_of::<U::Dimension>(unit) {
+            Some(info) => (info.to_base)(value),
+            None if unit.contains(|c: char| c.is_ascii_digit()) => {
+                Self::parse_compound(input, locale)?
+            }
+            None => return Err(unknown_unit::<U::Dimension>(unit)),
+        };
+        Self::try_from_base(check_finite(base)?)
+    }
+
+    /// Parses a compound quantity such as `5'11"`, `6 ft 2 in`, or `1h23m45s`.
+    ///
+    /// Each number-unit segment is converted and the results are summed. A leading
+    /// sign applies to the whole quantity, so `-5'6"` is negative five and a half feet.
+    /// Compound input is meant for linear units; for affine units such as degrees
+    /// Celsius only the first segment's offset is applied.
+    ///
+    /// Returns the sum in base units, for the caller to check.
+    fn parse_compound(input: &str, locale: NumberLocale) -> Result<f64, UnitError> {
+        let (negative, mut rest) = match input.as_bytes()[0] {
+            b'-' => (true, &input[1..]),
+            b'+' => (false, &input[1..]),
+            _ => (false, input),
+        };
+
+        let mut total: Option<f64> = None;
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            if !rest.starts_with(|c: char| c.is_ascii_digit() || c == locale.decimal_separator) {
+                return Err(parse_error(rest, "expected a number"));
+            }
+
+            let (value, after) =
+                split_number(rest, locale).map_err(|reason| parse_error(rest, reason))?;
+            let unit_end = after
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(after.len());
+            let unit = after[..unit_end].trim();
+            if unit.is_empty() {
+                return Err(parse_error(rest, "expected a unit after the number"));
+            }
+
+            let info = find_unit_of::<U::Dimension>(unit)
+                .ok_or_else(|| unknown_unit::<U::Dimension>(unit))?;
+            total = Some(match total {
+                None => (info.to_base)(value),
+                Some(sum) => sum + (info.to_base)(value) - (info.to_base)(0.0),
+            });
+            rest = &after[unit_end..];
+        }
+
+        let total = total.ok_or_else(|| parse_error(input, "expected a quantity"))?;
+        Ok(if negative { -total } else { total })
+    }
+}
+
+/// Builds the error for malformed input.
+fn parse_error(input: &str, reason: &'static str) -> UnitError {
+    UnitError::ParseError {
+        input: input.to_string(),
+        reason,
+    }
+}
+
+/// Builds the error for a unit that is not part of dimension `D`.
+///
+/// If the unit belongs to another enabled dimension the error reports the
+/// mismatch; otherwise it lists the units of `D` and close matches within it.
+pub(crate) fn unknown_unit<D: Dimension>(unit: &str) -> UnitError {
+    // A name `D` rejects as ambiguous is one of its own, whatever other dimensions call it
+    let ambiguous = D::AMBIGUOUS
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(unit));
+    match dimension_of::<D>(unit).filter(|_| !ambiguous) {
+        Some(found) => UnitError::DimensionMismatch {
+            expected: D::NAME,
+            found,
+        },
+        None => UnitError::UnknownUnit {
+            unit: unit.to_string(),
+            dimension: D::NAME,
+            expected: match registry::dimension(D::NAME) {
+                Some(info) => info.all_units().map(|info| info.symbol).collect(),
+                None => D::UNITS.iter().map(|info| info.symbol).collect(),
+            },
+            suggestions: D::suggest(unit),
+        },
+    }
+}
+
+/// Returns the name of the enabled dimension other than `D` that has a unit matching
+/// `unit`, preferring an exact match.
+fn dimension_of<D: Dimension>(unit: &str) -> Option<&'static str> {
+    let others = || registry::DIMENSIONS.iter().filter(|d| d.name != D::NAME);
+    others()
+        .find(|d| d.find_unit_exactly(unit).is_some())
+        .or_else(|| others().find(|d| d.find_unit(unit).is_some()))
+        .map(|d| d.name)
+}
+
+impl<U: UnitConversion> FromStr for Quantity<U>
+where
+    U::Dimension: Dimension,
+{
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "angle")]
+    use crate::unit_conversion::angle::{Angle, Degree};
+    use crate::unit_conversion::length::{Foot, Inch, Kilometer, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+    #[cfg(feature = "time")]
+    use crate::unit_conversion::time::{Hour, Second, Time};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn parses_with_and_without_whitespace() {
+        let length = Length::parse("3.2km").unwrap();
+        assert!(approx(length.to_unit::<Kilometer>(), 3.2, 1e-12));
+
+        let length = Length::parse("  3.2   km ").unwrap();
+        assert!(approx(length.to_unit::<Kilometer>(), 3.2, 1e-12));
+    }
+
+    #[test]
+    fn parses_aliases_and_ignores_case() {
+        let temp = Temperature::parse("25 °C").unwrap();
+        assert!(approx(temp.to_unit::<Celsius>(), 25.0, 1e-12));
+
+        let temp = Temperature::parse("25 degC").unwrap();
+        assert!(approx(temp.to_unit::<Celsius>(), 25.0, 1e-12));
+
+        let temp = Temperature::parse("300 KELVIN").unwrap();
+        assert!(approx(temp.to_unit::<Kelvin>(), 300.0, 1e-12));
+
+        let length = Length::parse("6 Feet").unwrap();
+        assert!(approx(length.to_unit::<Foot>(), 6.0, 1e-12));
+    }
+
+    #[test]
+    fn parses_signs_and_exponents() {
+        let length = Length::parse("-1.5e3 m").unwrap();
+        assert!(approx(length.to_unit::<Meter>(), -1500.0, 1e-9));
+
+        let length = Length::parse("+2E-3km").unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 2.0, 1e-12));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(matches!(
+            Length::parse("   "),
+            Err(UnitError::ParseError {
+                reason: "expected a quantity",
+                ..
+            })
+        ));
+        assert_eq!(
+            Length::parse("12"),
+            Err(UnitError::ParseError {
+                input: "12".to_string(),
+                reason: "expected a unit after the number",
+            })
+        );
+        assert_eq!(
+            Length::parse("km"),
+            Err(UnitError::ParseError {
+                input: "km".to_string(),
+                reason: "expected a number",
+            })
+        );
+        assert!(matches!(
+            Temperature::parse("12 furlongs"),
+            Err(UnitError::UnknownUnit {
+                dimension: "temperature",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn reports_units_of_other_dimensions() {
+        assert_eq!(
+            Temperature::parse("12 km"),
+            Err(UnitError::DimensionMismatch {
+                expected: "temperature",
+                found: "length",
+            })
+        );
+        #[cfg(feature = "energy")]
+        assert_eq!(
+            Length::parse("3 kWh"),
+            Err(UnitError::DimensionMismatch {
+                expected: "length",
+                found: "energy",
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_values() {
+        assert!(matches!(
+            Length::parse("1e999 m"),
+            Err(UnitError::NonFinite { .. })
+        ));
+        assert!(matches!(
+            Length::parse("1e308 km"),
+            Err(UnitError::NonFinite { .. })
+        ));
+    }
+
+    #[test]
+    fn from_str_matches_parse() {
+        let length: Length = "100 m".parse().unwrap();
+        assert_eq!(length, Length::parse("100 m").unwrap());
+    }
+
+    #[test]
+    fn parses_compound_lengths() {
+        let length = Length::parse("5'11\"").unwrap();
+        assert!(approx(length.to_unit::<Inch>(), 71.0, 1e-9));
+
+        let length = Length::parse("6 ft 2 in").unwrap();
+        assert!(approx(length.to_unit::<Inch>(), 74.0, 1e-9));
+
+        let length = Length::parse("-5′6″").unwrap();
+        assert!(approx(length.to_unit::<Foot>(), -5.5, 1e-9));
+    }
+
+    #[test]
+    #[cfg(all(feature = "time", feature = "angle"))]
+    fn parses_compound_durations_and_angles() {
+        let time = Time::parse("1h23m45s").unwrap();
+        assert!(approx(time.to_unit::<Second>(), 5025.0, 1e-9));
+
+        let time = Time::parse("2 hours 30 minutes").unwrap();
+        assert!(approx(time.to_unit::<Hour>(), 2.5, 1e-12));
+
+        let angle = Angle::parse("12°34'56\"").unwrap();
+        assert!(approx(angle.to_unit::<Degree>(), 12.582222222, 1e-9));
+    }
+
+    #[test]
+    fn rejects_bad_compound_input() {
+        assert!(matches!(
+            Length::parse("5 ft 11"),
+            Err(UnitError::ParseError {
+                reason: "expected a unit after the number",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Length::parse("5 ft -2 in"),
+            Err(UnitError::UnknownUnit { .. })
+        ));
+        assert!(matches!(
+            Length::parse("5 ft 2 parsecs"),
+            Err(UnitError::UnknownUnit { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_units_carry_suggestions() {
+        match Temperature::parse("20 Farenheit") {
+            Err(UnitError::UnknownUnit { suggestions, .. }) => {
+                assert_eq!(suggestions, vec!["fahrenheit"]);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        match Length::parse("3 kilometrs") {
+            Err(UnitError::UnknownUnit { suggestions, .. }) => {
+                assert_eq!(suggestions, vec!["kilometers"]);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        match Length::parse("3 parsecs") {
+            Err(UnitError::UnknownUnit { suggestions, .. }) => {
+                assert!(suggestions.is_empty());
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_registered_units_of_the_target_dimension() {
+        registry::register_unit(
+            "length",
+            crate::unit_conversion::UnitInfo {
+                symbol: "parsepace",
+                name: "test pace",
+                plural: "test paces",
+                aliases: &["parsepaces"],
+                to_base: |paces| paces * 0.75,
+                from_base: |meters| meters / 0.75,
+            },
+        )
+        .unwrap();
+
+        let length = Length::parse("4 parsepaces").unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 3.0, 1e-12));
+        assert!(approx(
+            Length::parse("1 m 4 parsepace").unwrap().to_unit::<Meter>(),
+            4.0,
+            1e-12
+        ));
+        assert_eq!(
+            Temperature::parse("4 parsepaces"),
+            Err(UnitError::DimensionMismatch {
+                expected: "temperature",
+                found: "length",
+            })
+        );
+        match Length::parse("4 parsecs") {
+            Err(UnitError::UnknownUnit { expected, .. }) => {
+                assert!(expected.contains(&"parsepace"));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn mismatches_never_name_the_target_dimension() {
+        use crate::unit_conversion::temperature::TemperatureDimension;
+
+        assert_eq!(dimension_of::<TemperatureDimension>("kelvin"), None);
+        assert_eq!(dimension_of::<TemperatureDimension>("KM"), Some("length"));
+        assert!(matches!(
+            unknown_unit::<TemperatureDimension>("K"),
+            UnitError::UnknownUnit { .. }
+        ));
+    }
+
+    fn assert_unique_names<D: Dimension>() {
+        let names: Vec<&str> = D::UNITS
+            .iter()
+            .flat_map(|info| std::iter::once(&info.symbol).chain(info.aliases))
+            .copied()
+            .collect();
+        for (i, name) in names.iter().enumerate() {
+            assert!(
+                !names[i + 1..].contains(name),
+                "'{name}' is listed twice for {}",
+                D::NAME
+            );
+        }
+    }
+
+    #[test]
+    fn unit_tables_have_unique_names() {
+        #[cfg(feature = "angle")]
+        assert_unique_names::<crate::unit_conversion::angle::AngleDimension>();
+        #[cfg(feature = "energy")]
+        assert_unique_names::<crate::unit_conversion::energy::EnergyDimension>();
+        assert_unique_names::<crate::unit_conversion::length::LengthDimension>();
+        #[cfg(feature = "mass")]
+        assert_unique_names::<crate::unit_conversion::mass::MassDimension>();
+        #[cfg(feature = "power")]
+        assert_unique_names::<crate::unit_conversion::power::PowerDimension>();
+        assert_unique_names::<crate::unit_conversion::temperature::TemperatureDimension>();
+        #[cfg(feature = "time")]
+        assert_unique_names::<crate::unit_conversion::time::TimeDimension>();
+        #[cfg(feature = "volume")]
+        assert_unique_names::<crate::unit_conversion::volume::VolumeDimension>();
+        #[cfg(feature = "pressure")]
+        assert_unique_names::<crate::unit_conversion::pressure::PressureDimension>();
+        #[cfg(feature = "area")]
+        assert_unique_names::<crate::unit_conversion::area::AreaDimension>();
+        #[cfg(feature = "speed")]
+        assert_unique_names::<crate::unit_conversion::speed::SpeedDimension>();
+        #[cfg(feature = "voltage")]
+        assert_unique_names::<crate::unit_conversion::voltage::VoltageDimension>();
+        #[cfg(feature = "frequency")]
+        assert_unique_names::<crate::unit_conversion::frequency::FrequencyDimension>();
+        #[cfg(feature = "ratio")]
+        assert_unique_names::<crate::unit_conversion::ratio::RatioDimension>();
+        #[cfg(feature = "amount")]
+        assert_unique_names::<crate::unit_conversion::amount::AmountDimension>();
+        #[cfg(feature = "force")]
+        assert_unique_names::<crate::unit_conversion::force::ForceDimension>();
+        #[cfg(feature = "acceleration")]
+        assert_unique_names::<crate::unit_conversion::acceleration::AccelerationDimension>();
+        #[cfg(feature = "data_size")]
+        assert_unique_names::<crate::unit_conversion::data_size::DataSizeDimension>();
+        #[cfg(feature = "data_rate")]
+        assert_unique_names::<crate::unit_conversion::data_rate::DataRateDimension>();
+        #[cfg(feature = "current")]
+        assert_unique_names::<crate::unit_conversion::current::CurrentDimension>();
+        #[cfg(feature = "resistance")]
+        assert_unique_names::<crate::unit_conversion::resistance::ResistanceDimension>();
+        #[cfg(feature = "charge")]
+        assert_unique_names::<crate::unit_conversion::charge::ChargeDimension>();
+        #[cfg(feature = "emissions")]
+        assert_unique_names::<crate::unit_conversion::emissions::DistanceEmissionsDimension>();
+        #[cfg(feature = "emissions")]
+        assert_unique_names::<crate::unit_conversion::emissions::EnergyEmissionsDimension>();
+        #[cfg(feature = "concentration")]
+        assert_unique_names::<crate::unit_conversion::concentration::MassConcentrationDimension>();
+        #[cfg(feature = "concentration")]
+        assert_unique_names::<crate::unit_conversion::concentration::MolarConcentrationDimension>();
+        #[cfg(feature = "mass_rate")]
+        assert_unique_names::<crate::unit_conversion::mass_rate::MassRateDimension>();
+    }
+
+    #[test]
+    fn parses_european_numbers() {
+        let length = Length::parse_with("1 234,5 km", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Kilometer>(), 1234.5, 1e-9));
+
+        let length = Length::parse_with("1.234,5km", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Kilometer>(), 1234.5, 1e-9));
+
+        let length = Length::parse_with("1\u{202F}000 m", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 1000.0, 1e-9));
+
+        let temp = Temperature::parse_with("-3,5 °C", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(temp.to_unit::<Celsius>(), -3.5, 1e-12));
+    }
+
+    #[test]
+    fn parses_grouped_english_numbers() {
+        let length = Length::parse_with("1,234.5 m", NumberLocale::ENGLISH).unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 1234.5, 1e-9));
+
+        let length = Length::parse_with("1_000_000 m", NumberLocale::ENGLISH).unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 1e6, 1e-9));
+    }
+
+    #[test]
+    fn locales_apply_to_compound_input() {
+        let length = Length::parse_with("5 ft 1,5 in", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Inch>(), 61.5, 1e-9));
+    }
+
+    #[test]
+    fn plain_parse_rejects_grouping() {
+        assert!(Length::parse("1,5 km").is_err());
+        assert!(Length::parse("1_000 m").is_err());
+    }
+
+    #[test]
+    fn split_number_backtracks_over_exponent_marker() {
+        assert_eq!(split_number("5em", NumberLocale::PLAIN), Ok((5.0, "em")));
+        assert_eq!(split_number("2e3m", NumberLocale::PLAIN), Ok((2000.0, "m")));
+        assert_eq!(
+            split_number("abc", NumberLocale::PLAIN),
+            Err("expected a number")
+        );
+        assert_eq!(
+            split_number("1,5e3m", NumberLocale::EUROPEAN),
+            Ok((1500.0, "m"))
+        );
+        assert_eq!(
+            split_number("1.234e3m", NumberLocale::EUROPEAN),
+            Ok((1234e3, "m"))
+        );
+        assert_eq!(
+            split_number("5 em", NumberLocale::EUROPEAN),
+            Ok((5.0, " em"))
+        );
+    }
+
+    #[test]
+    fn rejects_misplaced_group_separators() {
+        let misplaced = [
+            ("0,5 m", NumberLocale::ENGLISH),
+            ("1,23 m", NumberLocale::ENGLISH),
+            ("1234,567 m", NumberLocale::ENGLISH),
+            ("1.5,25 m", NumberLocale::ENGLISH),
+            ("1.5 km", NumberLocale::EUROPEAN),
+            ("1.5e3 m", NumberLocale::EUROPEAN),
+            ("2 3 m", NumberLocale::EUROPEAN),
+            ("1.234.56 m", NumberLocale::EUROPEAN),
+            ("1,5 000 m", NumberLocale::EUROPEAN),
+        ];
+        for (input, locale) in misplaced {
+            assert_eq!(
+                Length::parse_with(input, locale),
+                Err(UnitError::ParseError {
+                    input: input.to_string(),
+                    reason: "misplaced digit group separator",
+                }),
+                "{input}"
+            );
+        }
+
+        let length = Length::parse_with("12.345.678 m", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Meter>(), 12_345_678.0, 1e-9));
+        let length = Length::parse_with("1 234 km", NumberLocale::EUROPEAN).unwrap();
+        assert!(approx(length.to_unit::<Kilometer>(), 1234.0, 1e-9));
+    }
+}
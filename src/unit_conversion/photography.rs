@@ -0,0 +1,243 @@
+//! # Photographic Exposure Helpers
+//!
+//! Exposure value (EV) condenses an aperture and shutter time into a single number of
+//! stops: `EV = log2(N² / t)` for f-number `N` and shutter time `t` in seconds. Each
+//! stop halves or doubles the light reaching the sensor. This module computes EV from
+//! an [`FNumber`] and a typed shutter [`Time`], solves for the missing setting given a
+//! metered EV, and accounts for sensor sensitivity (ISO) by normalizing to ISO 100.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::photography::{FNumber, exposure_value, shutter_time};
+//! use uom::unit_conversion::time::{Second, Time};
+//!
+//! // f/16 at 1/100 s is the "sunny 16" exposure at ISO 100
+//! let f16 = FNumber::new(16.0).unwrap();
+//! let ev = exposure_value(f16, Time::from_unit::<Second>(0.01)).unwrap();
+//! assert!((ev - 14.64).abs() < 0.01);
+//!
+//! // Opening up to f/8 at ISO 400 needs a faster shutter for the same scene
+//! let t = shutter_time(ev, FNumber::new(8.0).unwrap(), 400.0).unwrap();
+//! assert!((t.to_unit::<Second>() - 1.0 / 1600.0).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::time::{Second, Time};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// The ISO sensitivity that exposure values are normalized to.
+const REFERENCE_ISO: f64 = 100.0;
+
+/// A lens aperture expressed as an f-number, the ratio of focal length to the diameter
+/// of the entrance pupil.
+///
+/// F-numbers are dimensionless. Each full stop multiplies the f-number by √2, so f/2.8
+/// lets in half the light of f/2.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::photography::FNumber;
+///
+/// let wide = FNumber::new(2.0).unwrap();
+/// let narrow = FNumber::new(8.0).unwrap();
+/// assert!((narrow.stops_from(wide) - 4.0).abs() < 1e-12);
+/// assert!(FNumber::new(0.0).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct FNumber(f64);
+
+impl FNumber {
+    /// Creates an f-number.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `value` is not positive and finite.
+    pub fn new(value: f64) -> Result<Self, UnitError> {
+        check_positive(value).map(Self)
+    }
+
+    /// Returns the f-number as a plain ratio (e.g., `5.6` for f/5.6).
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Returns how many stops less light this aperture admits than `other`.
+    ///
+    /// The result is negative when this aperture is wider than `other`.
+    pub fn stops_from(self, other: FNumber) -> f64 {
+        2.0 * (self.0 / other.0).log2()
+    }
+}
+
+/// Returns the exposure value of an aperture and shutter time, `log2(N² / t)`.
+///
+/// This is independent of ISO. Returns [`UnitError::OutOfRange`] if the shutter time is
+/// not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::photography::{FNumber, exposure_value};
+/// use uom::unit_conversion::time::{Second, Time};
+///
+/// let ev = exposure_value(FNumber::new(1.0).unwrap(), Time::from_unit::<Second>(1.0));
+/// assert_eq!(ev, Ok(0.0));
+/// ```
+pub fn exposure_value(aperture: FNumber, shutter: Time) -> Result<f64, UnitError> {
+    let seconds = check_positive(shutter.to_unit::<Second>())?;
+    Ok((aperture.0 * aperture.0 / seconds).log2())
+}
+
+/// Returns the exposure value normalized to ISO 100 (EV100) for settings used at the
+/// given ISO.
+///
+/// A scene metered at EV100 14 is correctly exposed at EV 14 on an ISO 100 sensor and
+/// at EV 16 on an ISO 400 sensor. Returns [`UnitError::OutOfRange`] if the shutter time
+/// or ISO is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::photography::{FNumber, exposure_value_100};
+/// use uom::unit_conversion::time::{Second, Time};
+///
+/// let f4 = FNumber::new(4.0).unwrap();
+/// let ev100 = exposure_value_100(f4, Time::from_unit::<Second>(1.0 / 60.0), 800.0).unwrap();
+/// assert!((ev100 - 6.907).abs() < 1e-3);
+/// ```
+pub fn exposure_value_100(aperture: FNumber, shutter: Time, iso: f64) -> Result<f64, UnitError> {
+    Ok(exposure_value(aperture, shutter)? - iso_stops(iso)?)
+}
+
+/// Returns the shutter time that correctly exposes a scene metered at `ev100` with the
+/// given aperture and ISO.
+///
+/// Returns [`UnitError::OutOfRange`] if the ISO is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::photography::{FNumber, shutter_time};
+/// use uom::unit_conversion::time::Second;
+///
+/// let t = shutter_time(12.0, FNumber::new(5.6).unwrap(), 100.0).unwrap();
+/// assert!((1.0 / t.to_unit::<Second>() - 130.6).abs() < 0.1);
+/// ```
+pub fn shutter_time(ev100: f64, aperture: FNumber, iso: f64) -> Result<Time, UnitError> {
+    let ev = ev100 + iso_stops(iso)?;
+    Ok(Time::from_unit::<Second>(
+        aperture.0 * aperture.0 / ev.exp2(),
+    ))
+}
+
+/// Returns the aperture that correctly exposes a scene metered at `ev100` with the
+/// given shutter time and ISO.
+///
+/// Returns [`UnitError::OutOfRange`] if the shutter time or ISO is not positive and
+/// finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::photography::aperture;
+/// use uom::unit_conversion::time::{Second, Time};
+///
+/// let n = aperture(15.0, Time::from_unit::<Second>(1.0 / 125.0), 100.0).unwrap();
+/// assert!((n.value() - 16.19).abs() < 0.01); // about f/16
+/// ```
+pub fn aperture(ev100: f64, shutter: Time, iso: f64) -> Result<FNumber, UnitError> {
+    let seconds = check_positive(shutter.to_unit::<Second>())?;
+    let ev = ev100 + iso_stops(iso)?;
+    FNumber::new((ev.exp2() * seconds).sqrt())
+}
+
+/// Returns how many stops more sensitive `iso` is than ISO 100.
+fn iso_stops(iso: f64) -> Result<f64, UnitError> {
+    Ok((check_positive(iso)? / REFERENCE_ISO).log2())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn seconds(value: f64) -> Time {
+        Time::from_unit::<Second>(value)
+    }
+
+    #[test]
+    fn reference_exposures() {
+        let f1 = FNumber::new(1.0).unwrap();
+        assert!(approx(
+            exposure_value(f1, seconds(1.0)).unwrap(),
+            0.0,
+            1e-12
+        ));
+
+        let f2 = FNumber::new(2.0).unwrap();
+        assert!(approx(
+            exposure_value(f2, seconds(1.0)).unwrap(),
+            2.0,
+            1e-12
+        ));
+        assert!(approx(
+            exposure_value(f2, seconds(0.5)).unwrap(),
+            3.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn iso_normalization() {
+        let f8 = FNumber::new(8.0).unwrap();
+        let ev = exposure_value(f8, seconds(1.0 / 250.0)).unwrap();
+        assert!(approx(
+            exposure_value_100(f8, seconds(1.0 / 250.0), 100.0).unwrap(),
+            ev,
+            1e-12
+        ));
+        assert!(approx(
+            exposure_value_100(f8, seconds(1.0 / 250.0), 400.0).unwrap(),
+            ev - 2.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn solving_round_trips() {
+        let f = FNumber::new(5.6).unwrap();
+        let t = seconds(1.0 / 30.0);
+        let ev100 = exposure_value_100(f, t, 1600.0).unwrap();
+
+        let solved_t = shutter_time(ev100, f, 1600.0).unwrap();
+        assert!(approx(solved_t.to_unit::<Second>(), 1.0 / 30.0, 1e-12));
+
+        let solved_f = aperture(ev100, t, 1600.0).unwrap();
+        assert!(approx(solved_f.value(), 5.6, 1e-12));
+    }
+
+    #[test]
+    fn full_stops() {
+        let stops = [1.0, 1.4, 2.0, 2.8, 4.0, 5.6, 8.0, 11.0, 16.0];
+        for pair in stops.windows(2) {
+            let wider = FNumber::new(pair[0]).unwrap();
+            let narrower = FNumber::new(pair[1]).unwrap();
+            // Marked f-numbers are rounded, so allow a tenth of a stop
+            assert!(approx(narrower.stops_from(wider), 1.0, 0.1));
+            assert!(approx(wider.stops_from(narrower), -1.0, 0.1));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let f = FNumber::new(4.0).unwrap();
+        assert!(FNumber::new(-2.0).is_err());
+        assert!(FNumber::new(f64::NAN).is_err());
+        assert!(exposure_value(f, seconds(0.0)).is_err());
+        assert!(exposure_value_100(f, seconds(1.0), 0.0).is_err());
+        assert!(shutter_time(10.0, f, -100.0).is_err());
+        assert!(aperture(10.0, seconds(-1.0), 100.0).is_err());
+    }
+}
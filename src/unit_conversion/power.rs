@@ -0,0 +1,509 @@
+//! # Power Unit Conversions
+//!
+//! This module provides type-safe power conversions between watts, kilowatts, the
+//! BTU-based units used to rate heating and cooling equipment, the horsepower variants
+//! used for engines and motors, and the decibel-referenced units used in RF and audio
+//! work.
+//! All powers are internally stored in watts (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Watt (W)**: The base unit, SI unit of power
+//! - **Kilowatt (kW)**: 1000 watts
+//! - **BTU per hour (BTU/h)**: One International Table BTU per hour, about 0.293 watts
+//! - **Ton of refrigeration (TR)**: 12,000 BTU/h, the rate of heat absorbed by melting
+//!   one short ton of ice per day
+//! - **Mechanical horsepower (hp)**: 550 foot-pounds-force per second, about 745.7 watts
+//! - **Metric horsepower (PS)**: 75 kilogram-force meters per second, exactly 735.49875
+//!   watts
+//! - **Electrical horsepower (hp(E))**: Exactly 746 watts
+//! - **Decibel-milliwatt (dBm)**: Power level relative to 1 mW, where 0 dBm is 1 mW and
+//!   every 10 dB is a factor of ten
+//! - **Decibel-watt (dBW)**: Power level relative to 1 W; 0 dBW is 30 dBm
+//!
+//! The three horsepowers differ by up to 1.4%, so a "150 hp" rating means different
+//! things on a US and a German spec sheet; each is a distinct unit here.
+//!
+//! The decibel units are logarithmic, so a power of zero is −∞ dB and negative powers
+//! have no decibel value (converting one yields NaN). Use
+//! [`Quantity::try_to_unit`](crate::unit_conversion::Quantity::try_to_unit) to catch
+//! those cases.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::power::{Power, Kilowatt, BtuPerHour, TonOfRefrigeration};
+//!
+//! let chiller = Power::from_unit::<TonOfRefrigeration>(1.0);
+//! assert!((chiller.to_unit::<BtuPerHour>() - 12_000.0).abs() < 1e-9);
+//! assert!((chiller.to_unit::<Kilowatt>() - 3.516853).abs() < 1e-6);
+//! ```
+
+use crate::unit_conversion::logarithmic::LogarithmicUnit;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for power conversions
+const WATTS_PER_KILOWATT: f64 = 1000.0;
+const WATTS_PER_BTU_PER_HOUR: f64 = 1055.05585262 / 3600.0; // International Table BTU
+const WATTS_PER_TON_OF_REFRIGERATION: f64 = 12_000.0 * WATTS_PER_BTU_PER_HOUR;
+// 550 ft·lbf/s, with 0.3048 m per foot and 9.80665 N per lbf per kg
+const WATTS_PER_MECHANICAL_HORSEPOWER: f64 = 550.0 * 0.3048 * 0.45359237 * 9.80665;
+const WATTS_PER_METRIC_HORSEPOWER: f64 = 735.49875; // 75 kgf·m/s, exact
+const WATTS_PER_ELECTRICAL_HORSEPOWER: f64 = 746.0; // Exact definition
+const DBM_REFERENCE_WATTS: f64 = 0.001;
+const DBW_REFERENCE_WATTS: f64 = 1.0;
+
+/// Marker type for the power dimension.
+///
+/// This prevents accidental conversions between power and other dimensions
+/// like energy.
+pub enum PowerDimension {}
+
+impl Dimension for PowerDimension {
+    const NAME: &'static str = "power";
+    const ID: u8 = 8;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Watt>(&["watt", "watts"]),
+        UnitInfo::of::<Kilowatt>(&["kilowatt", "kilowatts"]),
+        UnitInfo::of::<BtuPerHour>(&["btuh", "btu/hr", "btu per hour"]),
+        UnitInfo::of::<TonOfRefrigeration>(&["ton of refrigeration", "tons of refrigeration"]),
+        UnitInfo::of::<MechanicalHorsepower>(&[
+            "bhp",
+            "hp(I)",
+            "horsepower",
+            "mechanical horsepower",
+            "imperial horsepower",
+        ]),
+        UnitInfo::of::<MetricHorsepower>(&[
+            "hp(M)",
+            "CV",
+            "cv",
+            "pk",
+            "metric horsepower",
+            "Pferdestärke",
+        ]),
+        UnitInfo::of::<ElectricalHorsepower>(&["hpE", "electrical horsepower"]),
+        UnitInfo::of::<Dbm>(&["dB(mW)", "dBmW"]),
+        UnitInfo::of::<Dbw>(&["dB(W)"]),
+    ];
+    // A bare ton is more often a mass than a cooling capacity
+    const AMBIGUOUS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("ton", &["ton of refrigeration"]),
+        ("tons", &["tons of refrigeration"]),
+    ];
+}
+
+/// A power quantity that stores values in watts internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, Kilowatt, Watt};
+///
+/// let heater = Power::from_unit::<Kilowatt>(2.0);
+/// let watts = heater.to_unit::<Watt>(); // 2000.0
+/// ```
+pub type Power = Quantity<Watt>;
+
+/// Watt power unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, Watt};
+///
+/// let bulb = Power::from_unit::<Watt>(60.0);
+/// assert_eq!(bulb.to_unit::<Watt>(), 60.0);
+/// ```
+pub struct Watt;
+
+impl UnitConversion for Watt {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Watt is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Watt is the base unit
+    }
+
+    const SYMBOL: &'static str = "W";
+    const NAME: &'static str = "watt";
+    const PLURAL: &'static str = "watts";
+}
+
+/// Kilowatt power unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, Kilowatt, Watt};
+///
+/// let charger = Power::from_unit::<Kilowatt>(7.4);
+/// assert_eq!(charger.to_unit::<Watt>(), 7400.0);
+/// ```
+pub struct Kilowatt;
+
+impl UnitConversion for Kilowatt {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_KILOWATT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_KILOWATT
+    }
+
+    const SYMBOL: &'static str = "kW";
+    const NAME: &'static str = "kilowatt";
+    const PLURAL: &'static str = "kilowatts";
+}
+
+/// BTU per hour power unit.
+///
+/// The customary North American unit for rating furnaces, boilers, and air
+/// conditioners.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, BtuPerHour, Watt};
+///
+/// let furnace = Power::from_unit::<BtuPerHour>(60_000.0);
+/// assert!((furnace.to_unit::<Watt>() - 17_584.26).abs() < 0.01);
+/// ```
+pub struct BtuPerHour;
+
+impl UnitConversion for BtuPerHour {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_BTU_PER_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_BTU_PER_HOUR
+    }
+
+    const SYMBOL: &'static str = "BTU/h";
+    const NAME: &'static str = "British thermal unit per hour";
+    const PLURAL: &'static str = "British thermal units per hour";
+}
+
+/// Ton of refrigeration power unit.
+///
+/// Exactly 12,000 BTU/h. Used to rate the cooling capacity of air conditioners
+/// and chillers.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, TonOfRefrigeration, Kilowatt};
+///
+/// let unit = Power::from_unit::<TonOfRefrigeration>(3.0);
+/// assert!((unit.to_unit::<Kilowatt>() - 10.55056).abs() < 1e-5);
+/// ```
+pub struct TonOfRefrigeration;
+
+impl UnitConversion for TonOfRefrigeration {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_TON_OF_REFRIGERATION
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_TON_OF_REFRIGERATION
+    }
+
+    const SYMBOL: &'static str = "TR";
+    const NAME: &'static str = "ton of refrigeration";
+    const PLURAL: &'static str = "tons of refrigeration";
+}
+
+/// Mechanical (imperial) horsepower power unit.
+///
+/// Exactly 550 foot-pounds-force per second, about 745.7 watts. This is the horsepower
+/// of US and UK engine ratings, and the one meant by a bare "hp".
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Kilowatt, MechanicalHorsepower, Power};
+///
+/// let engine = Power::from_unit::<MechanicalHorsepower>(300.0);
+/// assert!((engine.to_unit::<Kilowatt>() - 223.71).abs() < 1e-2);
+/// ```
+pub struct MechanicalHorsepower;
+
+impl UnitConversion for MechanicalHorsepower {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_MECHANICAL_HORSEPOWER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_MECHANICAL_HORSEPOWER
+    }
+
+    const SYMBOL: &'static str = "hp";
+    const NAME: &'static str = "mechanical horsepower";
+    const PLURAL: &'static str = "mechanical horsepower";
+}
+
+/// Metric horsepower power unit (Pferdestärke).
+///
+/// Exactly 75 kilogram-force meters per second, or 735.49875 watts, about 1.4% less than
+/// a mechanical horsepower. Used for engine ratings in continental Europe and Japan
+/// (PS, CV, ch, pk).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{MechanicalHorsepower, MetricHorsepower, Power};
+///
+/// let engine = Power::from_unit::<MetricHorsepower>(150.0);
+/// assert!((engine.to_unit::<MechanicalHorsepower>() - 147.95).abs() < 1e-2);
+/// ```
+pub struct MetricHorsepower;
+
+impl UnitConversion for MetricHorsepower {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_METRIC_HORSEPOWER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_METRIC_HORSEPOWER
+    }
+
+    const SYMBOL: &'static str = "PS";
+    const NAME: &'static str = "metric horsepower";
+    const PLURAL: &'static str = "metric horsepower";
+}
+
+/// Electrical horsepower power unit.
+///
+/// Exactly 746 watts, used to rate electric motors.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{ElectricalHorsepower, Power, Watt};
+///
+/// let motor = Power::from_unit::<ElectricalHorsepower>(2.0);
+/// assert_eq!(motor.to_unit::<Watt>(), 1492.0);
+/// ```
+pub struct ElectricalHorsepower;
+
+impl UnitConversion for ElectricalHorsepower {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * WATTS_PER_ELECTRICAL_HORSEPOWER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / WATTS_PER_ELECTRICAL_HORSEPOWER
+    }
+
+    const SYMBOL: &'static str = "hp(E)";
+    const NAME: &'static str = "electrical horsepower";
+    const PLURAL: &'static str = "electrical horsepower";
+}
+
+/// Decibel-milliwatt power level unit.
+///
+/// A power of `P` watts is `10 log10(P / 1 mW)` dBm. Common in RF link budgets, fiber
+/// optics, and receiver sensitivity specifications.
+///
+/// # Examples
+///
+/// ```
+/// use uom::smart;
+/// use uom::unit_conversion::power::{Power, Dbm, Watt};
+///
+/// let transmitter = Power::from_unit::<Dbm>(30.0);
+/// assert!((transmitter.to_unit::<Watt>() - 1.0).abs() < 1e-12);
+///
+/// let signal = Power::from_unit::<Watt>(1e-9);
+/// assert!((signal.to_unit::<Dbm>() + 60.0).abs() < 1e-9);
+///
+/// // A level with its linear equivalent, for link budget reports
+/// let rx = smart!(signal.to_unit::<Dbm>(), 1).with_linear::<Dbm>();
+/// assert_eq!(rx.to_string(), "-60 dBm (1.00e-6 mW)");
+/// ```
+pub struct Dbm;
+
+impl UnitConversion for Dbm {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        DBM_REFERENCE_WATTS * 10f64.powf(value / 10.0)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        10.0 * (value / DBM_REFERENCE_WATTS).log10()
+    }
+
+    const SYMBOL: &'static str = "dBm";
+    const NAME: &'static str = "decibel-milliwatt";
+    const PLURAL: &'static str = "decibel-milliwatts";
+}
+
+impl LogarithmicUnit for Dbm {
+    const LOG_FACTOR: f64 = 10.0;
+    const REFERENCE: f64 = 1.0;
+    const LINEAR_SYMBOL: &'static str = "mW";
+}
+
+/// Decibel-watt power level unit.
+///
+/// A power of `P` watts is `10 log10(P / 1 W)` dBW, so 0 dBW equals 30 dBm.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::power::{Power, Dbw, Kilowatt};
+///
+/// let broadcast = Power::from_unit::<Dbw>(50.0);
+/// assert!((broadcast.to_unit::<Kilowatt>() - 100.0).abs() < 1e-9);
+/// ```
+pub struct Dbw;
+
+impl UnitConversion for Dbw {
+    type Dimension = PowerDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        DBW_REFERENCE_WATTS * 10f64.powf(value / 10.0)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        10.0 * (value / DBW_REFERENCE_WATTS).log10()
+    }
+
+    const SYMBOL: &'static str = "dBW";
+    const NAME: &'static str = "decibel-watt";
+    const PLURAL: &'static str = "decibel-watts";
+}
+
+impl LogarithmicUnit for Dbw {
+    const LOG_FACTOR: f64 = 10.0;
+    const REFERENCE: f64 = DBW_REFERENCE_WATTS;
+    const LINEAR_SYMBOL: &'static str = "W";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn kilowatt_to_watt() {
+        let power = Power::from_unit::<Kilowatt>(1.5);
+        assert!(approx(power.to_unit::<Watt>(), 1500.0, 1e-9));
+    }
+
+    #[test]
+    fn btu_per_hour_conversions() {
+        let power = Power::from_unit::<BtuPerHour>(1.0);
+        assert!(approx(power.to_unit::<Watt>(), 0.29307107, 1e-8));
+
+        let power = Power::from_unit::<Kilowatt>(1.0);
+        assert!(approx(power.to_unit::<BtuPerHour>(), 3412.141633, 1e-6));
+    }
+
+    #[test]
+    fn ton_of_refrigeration_conversions() {
+        let power = Power::from_unit::<TonOfRefrigeration>(1.0);
+        assert!(approx(power.to_unit::<BtuPerHour>(), 12_000.0, 1e-9));
+        assert!(approx(power.to_unit::<Watt>(), 3516.852842, 1e-6));
+    }
+
+    #[test]
+    fn horsepower_variants() {
+        let mechanical = Power::from_unit::<MechanicalHorsepower>(1.0);
+        assert!(approx(mechanical.to_unit::<Watt>(), 745.699871582, 1e-9));
+
+        let metric = Power::from_unit::<MetricHorsepower>(1.0);
+        assert!(approx(metric.to_unit::<Watt>(), 735.49875, 1e-12));
+        assert!(approx(
+            metric.to_unit::<MechanicalHorsepower>(),
+            0.98632,
+            1e-5
+        ));
+
+        let electrical = Power::from_unit::<ElectricalHorsepower>(1.0);
+        assert_eq!(electrical.to_unit::<Watt>(), 746.0);
+
+        for (alias, symbol) in [("horsepower", "hp"), ("CV", "PS"), ("hpE", "hp(E)")] {
+            assert_eq!(PowerDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+        assert!(PowerDimension::find_unit("ton").is_none());
+        assert_eq!(
+            PowerDimension::suggest("tons"),
+            vec!["tons of refrigeration"]
+        );
+    }
+
+    #[test]
+    fn decibel_conversions() {
+        let power = Power::from_unit::<Dbm>(0.0);
+        assert!(approx(power.to_unit::<Watt>(), 0.001, 1e-15));
+        assert!(approx(power.to_unit::<Dbw>(), -30.0, 1e-12));
+
+        let power = Power::from_unit::<Watt>(2.0);
+        assert!(approx(power.to_unit::<Dbm>(), 33.0103, 1e-4));
+
+        let power = Power::from_unit::<Dbw>(-3.0);
+        assert!(approx(power.to_unit::<Watt>(), 0.501187, 1e-6));
+    }
+
+    #[test]
+    fn decibels_of_zero_and_negative_power() {
+        let power = Power::from_unit::<Watt>(0.0);
+        assert_eq!(power.to_unit::<Dbm>(), f64::NEG_INFINITY);
+        assert!(power.try_to_unit::<Dbm>().is_err());
+
+        let power = Power::from_unit::<Watt>(-1.0);
+        assert!(power.to_unit::<Dbw>().is_nan());
+    }
+
+    #[test]
+    fn power_symbols() {
+        assert_eq!(Watt::SYMBOL, "W");
+        assert_eq!(Kilowatt::SYMBOL, "kW");
+        assert_eq!(BtuPerHour::SYMBOL, "BTU/h");
+        assert_eq!(TonOfRefrigeration::SYMBOL, "TR");
+        assert_eq!(MechanicalHorsepower::SYMBOL, "hp");
+        assert_eq!(MetricHorsepower::SYMBOL, "PS");
+        assert_eq!(ElectricalHorsepower::SYMBOL, "hp(E)");
+        assert_eq!(Dbm::SYMBOL, "dBm");
+        assert_eq!(Dbw::SYMBOL, "dBW");
+    }
+}
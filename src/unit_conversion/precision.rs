@@ -0,0 +1,284 @@
+//! # Default Precision
+//!
+//! The display views of a quantity ([`Named`](super::names::Named),
+//! [`SystemValue`](super::system::SystemValue), [`Formatted`](super::format::Formatted)
+//! and the others) print the shortest representation of the value unless the format
+//! string gives a precision. Rather than writing `{:.1}` at every place a temperature
+//! is shown, an application can install a [`PrecisionPolicy`] once at startup naming
+//! the number of decimal places per dimension. Views without an explicit precision
+//! consult it when they are displayed; a precision in the format string still wins, so
+//! any single call can override the policy.
+//!
+//! No policy is installed by default, so output is unchanged until an application
+//! opts in.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::length::{Foot, Length, LengthDimension, Meter};
+//! use uom::unit_conversion::precision::PrecisionPolicy;
+//! use uom::unit_conversion::temperature::TemperatureDimension;
+//!
+//! PrecisionPolicy::new()
+//!     .with_dimension::<TemperatureDimension>(1)
+//!     .with_dimension::<LengthDimension>(2)
+//!     .install();
+//!
+//! let span = Length::from_unit::<Meter>(3.0);
+//! assert_eq!(span.named::<Foot>().to_string(), "9.84 feet");
+//! assert_eq!(format!("{:.0}", span.named::<Foot>()), "10 feet");
+//! ```
+
+use std::sync::RwLock;
+
+use crate::unit_conversion::Dimension;
+#[cfg(feature = "currency")]
+use crate::unit_conversion::currency::minor_units;
+use crate::unit_conversion::{UnitError, registry};
+
+/// The policy consulted by display views, replaced by [`PrecisionPolicy::install`].
+static INSTALLED: RwLock<PrecisionPolicy> = RwLock::new(PrecisionPolicy::new());
+
+/// How amounts of money are rounded.
+#[cfg(feature = "currency")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CurrencyRule {
+    Decimals(usize),
+    MinorUnits,
+}
+
+/// The number of decimal places to display per dimension, when a format string does
+/// not say.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PrecisionPolicy {
+    dimensions: Vec<(&'static str, usize)>,
+    fallback: Option<usize>,
+    #[cfg(feature = "currency")]
+    currency: Option<CurrencyRule>,
+}
+
+impl PrecisionPolicy {
+    /// Creates a policy that leaves every value at full precision.
+    pub const fn new() -> Self {
+        Self {
+            dimensions: Vec::new(),
+            fallback: None,
+            #[cfg(feature = "currency")]
+            currency: None,
+        }
+    }
+
+    /// Shows quantities of dimension `D` with `decimals` decimal places.
+    pub fn with_dimension<D: Dimension>(mut self, decimals: usize) -> Self {
+        self.dimensions.retain(|&(name, _)| name != D::NAME);
+        self.dimensions.push((D::NAME, decimals));
+        self
+    }
+
+    /// Shows quantities of the dimension named `dimension` with `decimals` decimal
+    /// places, for policies read from configuration files.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if no enabled dimension has that name.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::precision::PrecisionPolicy;
+    ///
+    /// let policy = PrecisionPolicy::new().with_dimension_name("temperature", 1).unwrap();
+    /// assert_eq!(policy.decimals("temperature"), Some(1));
+    /// assert!(PrecisionPolicy::new().with_dimension_name("flavor", 1).is_err());
+    /// ```
+    pub fn with_dimension_name(
+        mut self,
+        dimension: &str,
+        decimals: usize,
+    ) -> Result<Self, UnitError> {
+        let info = registry::dimension(dimension).ok_or_else(|| UnitError::ParseError {
+            input: dimension.to_string(),
+            reason: "unknown dimension",
+        })?;
+        self.dimensions.retain(|&(name, _)| name != info.name);
+        self.dimensions.push((info.name, decimals));
+        Ok(self)
+    }
+
+    /// Shows quantities of dimensions without their own setting with `decimals`
+    /// decimal places.
+    pub fn with_fallback(mut self, decimals: usize) -> Self {
+        self.fallback = Some(decimals);
+        self
+    }
+
+    /// Shows amounts of money with `decimals` decimal places, whatever the currency.
+    #[cfg(feature = "currency")]
+    pub fn with_currency_decimals(mut self, decimals: usize) -> Self {
+        self.currency = Some(CurrencyRule::Decimals(decimals));
+        self
+    }
+
+    /// Shows amounts of money with the decimal places of their currency's minor unit
+    /// (see [`Currency::minor_units`](super::currency::Currency::minor_units)), so dollars show cents and yen show none.
+    #[cfg(feature = "currency")]
+    pub fn with_currency_minor_units(mut self) -> Self {
+        self.currency = Some(CurrencyRule::MinorUnits);
+        self
+    }
+
+    /// Returns the decimal places for quantities of the dimension named `dimension`,
+    /// or `None` for full precision.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::LengthDimension;
+    /// use uom::unit_conversion::precision::PrecisionPolicy;
+    ///
+    /// let policy = PrecisionPolicy::new()
+    ///     .with_dimension::<LengthDimension>(2)
+    ///     .with_fallback(4);
+    /// assert_eq!(policy.decimals("length"), Some(2));
+    /// assert_eq!(policy.decimals("temperature"), Some(4));
+    /// assert_eq!(PrecisionPolicy::new().decimals("length"), None);
+    /// ```
+    pub fn decimals(&self, dimension: &str) -> Option<usize> {
+        self.dimensions
+            .iter()
+            .find(|&&(name, _)| name == dimension)
+            .map(|&(_, decimals)| decimals)
+            .or(self.fallback)
+    }
+
+    /// Returns the decimal places for amounts of the currency with ISO 4217 code
+    /// `code`, or `None` for full precision.
+    #[cfg(feature = "currency")]
+    pub fn currency_decimals(&self, code: &str) -> Option<usize> {
+        match self.currency {
+            Some(CurrencyRule::Decimals(decimals)) => Some(decimals),
+            Some(CurrencyRule::MinorUnits) => Some(minor_units(code)),
+            None => self.fallback,
+        }
+    }
+
+    /// Makes this the policy consulted by every display view, replacing the one
+    /// installed before.
+    pub fn install(self) {
+        *INSTALLED
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = self;
+    }
+
+    /// Returns a copy of the installed policy.
+    pub fn installed() -> Self {
+        INSTALLED
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// What a displayed value measures, which selects its entry in the policy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Subject {
+    /// A quantity of the dimension with this name.
+    Dimension(&'static str),
+    /// An amount of money in the currency with this ISO 4217 code.
+    #[cfg(feature = "currency")]
+    Currency(&'static str),
+}
+
+/// Writes `value` with the given precision, or else with the installed policy's
+/// precision for `subject`, or else at full precision.
+pub(crate) fn format_number(value: f64, precision: Option<usize>, subject: Subject) -> String {
+    let precision = precision.or_else(|| {
+        let policy = INSTALLED
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match subject {
+            Subject::Dimension(name) => policy.decimals(name),
+            #[cfg(feature = "currency")]
+            Subject::Currency(code) => policy.currency_decimals(code),
+        }
+    });
+    match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::{Quantity, UnitConversion, UnitInfo};
+
+    // The installed policy is shared by every test in the process, so these tests
+    // configure a dimension of their own that no other test displays.
+    struct WidgetDimension;
+    struct Widget;
+
+    impl UnitConversion for Widget {
+        type Dimension = WidgetDimension;
+        const SYMBOL: &'static str = "wdg";
+        const NAME: &'static str = "widget";
+        const PLURAL: &'static str = "widgets";
+
+        fn convert_to(value: f64) -> f64 {
+            value
+        }
+
+        fn convert_from(value: f64) -> f64 {
+            value
+        }
+    }
+
+    impl Dimension for WidgetDimension {
+        const NAME: &'static str = "widget count";
+        const ID: u8 = u8::MAX;
+        const UNITS: &'static [UnitInfo] = &[UnitInfo::of::<Widget>(&[])];
+    }
+
+    #[test]
+    fn later_settings_replace_earlier_ones() {
+        let policy = PrecisionPolicy::new()
+            .with_dimension::<WidgetDimension>(1)
+            .with_dimension::<WidgetDimension>(3);
+        assert_eq!(policy.decimals("widget count"), Some(3));
+        assert_eq!(policy.decimals("length"), None);
+        assert_eq!(policy.with_fallback(0).decimals("length"), Some(0));
+    }
+
+    #[test]
+    fn views_consult_the_installed_policy() {
+        let subject = Subject::Dimension(WidgetDimension::NAME);
+        let q = Quantity::<Widget>::from_unit::<Widget>(2.0 / 3.0);
+        PrecisionPolicy::installed()
+            .with_dimension::<WidgetDimension>(2)
+            .install();
+        assert_eq!(format_number(2.0 / 3.0, None, subject), "0.67");
+        assert_eq!(format_number(2.0 / 3.0, Some(4), subject), "0.6667");
+        assert_eq!(q.named::<Widget>().to_string(), "0.67 widgets");
+        assert_eq!(format!("{:.0}", q.named::<Widget>()), "1 widget");
+        let installed = PrecisionPolicy::installed();
+        assert_eq!(installed.decimals(WidgetDimension::NAME), Some(2));
+    }
+
+    #[cfg(feature = "currency")]
+    #[test]
+    fn currencies_follow_their_minor_units() {
+        let policy = PrecisionPolicy::new().with_currency_minor_units();
+        assert_eq!(policy.currency_decimals("EUR"), Some(2));
+        assert_eq!(policy.currency_decimals("jpy"), Some(0));
+        assert_eq!(policy.currency_decimals("BHD"), Some(3));
+        let fixed = policy.with_currency_decimals(4);
+        assert_eq!(fixed.currency_decimals("JPY"), Some(4));
+        assert_eq!(PrecisionPolicy::new().currency_decimals("USD"), None);
+    }
+}
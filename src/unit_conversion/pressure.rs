@@ -0,0 +1,422 @@
+//! # Pressure Unit Conversions
+//!
+//! This module provides type-safe pressure conversions between pascals, bar, psi, and
+//! the other units found on gauges and datasheets.
+//! All pressures are internally stored in pascals (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Pascal (Pa)**: The base unit, SI unit of pressure
+//! - **Kilopascal (kPa)**: 1000 pascals
+//! - **Bar (bar)**: 100,000 pascals
+//! - **Pound per square inch (psi)**: About 6894.76 pascals
+//! - **Standard atmosphere (atm)**: 101,325 pascals
+//! - **Millimeter of mercury (mmHg)**: About 133.322 pascals
+//!
+//! ## Gauge and Absolute Pressure
+//!
+//! A [`Pressure`] is always absolute, measured from a perfect vacuum. Tire gauges and
+//! most process instruments instead read [`GaugePressure`], the difference from the
+//! ambient pressure around them (the "g" in psig and barg). The two are related by an
+//! offset that depends on the ambient reference, so converting between them always
+//! takes that reference explicitly, either as a measured [`Pressure`] or as
+//! [`standard_atmosphere`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::pressure::{GaugePressure, Pressure, Psi, Kilopascal, standard_atmosphere};
+//!
+//! // A tire inflated to 32 psig at sea level
+//! let tire = GaugePressure::from_unit::<Psi>(32.0);
+//! let absolute = tire.to_absolute(standard_atmosphere());
+//! assert!((absolute.to_unit::<Psi>() - 46.696).abs() < 1e-3);
+//!
+//! // The same absolute pressure reads lower on a gauge at altitude
+//! let mountain = Pressure::from_unit::<Kilopascal>(80.0);
+//! assert!(absolute.to_gauge(mountain).to_unit::<Psi>() > 32.0);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for pressure conversions
+const PASCALS_PER_KILOPASCAL: f64 = 1000.0;
+const PASCALS_PER_BAR: f64 = 100_000.0; // Exact definition
+const PASCALS_PER_PSI: f64 = 6894.757293168361; // 1 lbf / in², from exact lb, g0 and in
+const PASCALS_PER_ATMOSPHERE: f64 = 101_325.0; // Exact definition
+const PASCALS_PER_MILLIMETER_OF_MERCURY: f64 = 133.322387415; // Conventional value
+
+/// Marker type for the pressure dimension.
+///
+/// This prevents accidental conversions between pressure and other dimensions
+/// like energy or power.
+pub enum PressureDimension {}
+
+impl Dimension for PressureDimension {
+    const NAME: &'static str = "pressure";
+    const ID: u8 = 9;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Pascal>(&["pascal", "pascals"]),
+        UnitInfo::of::<Kilopascal>(&["kilopascal", "kilopascals"]),
+        UnitInfo::of::<Bar>(&["bars", "bara"]),
+        UnitInfo::of::<Psi>(&["psia", "lbf/in²", "lbf/in2"]),
+        UnitInfo::of::<Atmosphere>(&["atmosphere", "atmospheres"]),
+        UnitInfo::of::<MillimeterOfMercury>(&["mm Hg"]),
+    ];
+}
+
+/// An absolute pressure quantity that stores values in pascals internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Bar, Kilopascal};
+///
+/// let cylinder = Pressure::from_unit::<Bar>(200.0);
+/// let kpa = cylinder.to_unit::<Kilopascal>(); // 20000.0
+/// ```
+pub type Pressure = Quantity<Pascal>;
+
+/// Pascal pressure unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Pascal};
+///
+/// let pressure = Pressure::from_unit::<Pascal>(101_325.0);
+/// assert_eq!(pressure.to_unit::<Pascal>(), 101_325.0);
+/// ```
+pub struct Pascal;
+
+impl UnitConversion for Pascal {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Pascal is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Pascal is the base unit
+    }
+
+    const SYMBOL: &'static str = "Pa";
+    const NAME: &'static str = "pascal";
+    const PLURAL: &'static str = "pascals";
+}
+
+/// Kilopascal pressure unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Kilopascal, Pascal};
+///
+/// let pressure = Pressure::from_unit::<Kilopascal>(101.325);
+/// assert!((pressure.to_unit::<Pascal>() - 101_325.0).abs() < 1e-9);
+/// ```
+pub struct Kilopascal;
+
+impl UnitConversion for Kilopascal {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * PASCALS_PER_KILOPASCAL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / PASCALS_PER_KILOPASCAL
+    }
+
+    const SYMBOL: &'static str = "kPa";
+    const NAME: &'static str = "kilopascal";
+    const PLURAL: &'static str = "kilopascals";
+}
+
+/// Bar pressure unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Bar, Kilopascal};
+///
+/// let pressure = Pressure::from_unit::<Bar>(1.0);
+/// assert_eq!(pressure.to_unit::<Kilopascal>(), 100.0);
+/// ```
+pub struct Bar;
+
+impl UnitConversion for Bar {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * PASCALS_PER_BAR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / PASCALS_PER_BAR
+    }
+
+    const SYMBOL: &'static str = "bar";
+    const NAME: &'static str = "bar";
+    const PLURAL: &'static str = "bars";
+}
+
+/// Pound-force per square inch pressure unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Psi, Kilopascal};
+///
+/// let pressure = Pressure::from_unit::<Psi>(1.0);
+/// assert!((pressure.to_unit::<Kilopascal>() - 6.894757).abs() < 1e-6);
+/// ```
+pub struct Psi;
+
+impl UnitConversion for Psi {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * PASCALS_PER_PSI
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / PASCALS_PER_PSI
+    }
+
+    const SYMBOL: &'static str = "psi";
+    const NAME: &'static str = "pound per square inch";
+    const PLURAL: &'static str = "pounds per square inch";
+}
+
+/// Standard atmosphere pressure unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, Atmosphere, Bar};
+///
+/// let pressure = Pressure::from_unit::<Atmosphere>(1.0);
+/// assert!((pressure.to_unit::<Bar>() - 1.01325).abs() < 1e-12);
+/// ```
+pub struct Atmosphere;
+
+impl UnitConversion for Atmosphere {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * PASCALS_PER_ATMOSPHERE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / PASCALS_PER_ATMOSPHERE
+    }
+
+    const SYMBOL: &'static str = "atm";
+    const NAME: &'static str = "atmosphere";
+    const PLURAL: &'static str = "atmospheres";
+}
+
+/// Millimeter of mercury pressure unit, as used for blood pressure and vacuum gauges.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Pressure, MillimeterOfMercury, Atmosphere};
+///
+/// let pressure = Pressure::from_unit::<Atmosphere>(1.0);
+/// assert!((pressure.to_unit::<MillimeterOfMercury>() - 760.0).abs() < 1e-3);
+/// ```
+pub struct MillimeterOfMercury;
+
+impl UnitConversion for MillimeterOfMercury {
+    type Dimension = PressureDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * PASCALS_PER_MILLIMETER_OF_MERCURY
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / PASCALS_PER_MILLIMETER_OF_MERCURY
+    }
+
+    const SYMBOL: &'static str = "mmHg";
+    const NAME: &'static str = "millimeter of mercury";
+    const PLURAL: &'static str = "millimeters of mercury";
+}
+
+/// Returns the standard atmosphere, the conventional ambient reference of 101.325 kPa.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Atmosphere, standard_atmosphere};
+///
+/// assert_eq!(standard_atmosphere().to_unit::<Atmosphere>(), 1.0);
+/// ```
+pub fn standard_atmosphere() -> Pressure {
+    Pressure::from_unit::<Atmosphere>(1.0)
+}
+
+/// A pressure measured relative to the ambient pressure, as read by most gauges.
+///
+/// Gauge pressure is stored as the difference from ambient in pascals. It is a separate
+/// type from [`Pressure`] so that a psig reading cannot be passed where an absolute
+/// pressure is expected; use [`GaugePressure::to_absolute`] and [`Pressure::to_gauge`]
+/// to move between the two. Negative values denote partial vacuum.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{GaugePressure, Bar, Psi};
+///
+/// let line = GaugePressure::from_unit::<Bar>(6.0);
+/// assert!((line.to_unit::<Psi>() - 87.0226).abs() < 1e-4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct GaugePressure {
+    /// Pressure above ambient, in pascals
+    above_ambient: f64,
+}
+
+impl GaugePressure {
+    /// Creates a gauge pressure from a reading in any pressure unit.
+    ///
+    /// Every pressure unit is a plain scale factor, so a reading of 32 in [`Psi`] is
+    /// 32 psig.
+    pub fn from_unit<V>(value: f64) -> Self
+    where
+        V: UnitConversion<Dimension = PressureDimension>,
+    {
+        Self {
+            above_ambient: V::convert_to(value),
+        }
+    }
+
+    /// Returns the reading in the specified pressure unit.
+    pub fn to_unit<V>(self) -> f64
+    where
+        V: UnitConversion<Dimension = PressureDimension>,
+    {
+        V::convert_from(self.above_ambient)
+    }
+
+    /// Returns the absolute pressure for this reading taken at the given ambient pressure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::pressure::{GaugePressure, Pressure, Bar, Kilopascal};
+    ///
+    /// let ambient = Pressure::from_unit::<Kilopascal>(100.0);
+    /// let absolute = GaugePressure::from_unit::<Bar>(2.0).to_absolute(ambient);
+    /// assert!((absolute.to_unit::<Bar>() - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn to_absolute(self, ambient: Pressure) -> Pressure {
+        Pressure::from_base(ambient.in_base() + self.above_ambient)
+    }
+}
+
+impl Quantity<Pascal> {
+    /// Returns the gauge reading for this absolute pressure at the given ambient pressure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::pressure::{Pressure, Kilopascal, standard_atmosphere};
+    ///
+    /// // A perfect vacuum reads about -101 kPa on a gauge at sea level
+    /// let vacuum = Pressure::from_unit::<Kilopascal>(0.0);
+    /// let reading = vacuum.to_gauge(standard_atmosphere());
+    /// assert!((reading.to_unit::<Kilopascal>() + 101.325).abs() < 1e-9);
+    /// ```
+    pub fn to_gauge(self, ambient: Pressure) -> GaugePressure {
+        GaugePressure {
+            above_ambient: self.in_base() - ambient.in_base(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn bar_and_psi_conversions() {
+        let pressure = Pressure::from_unit::<Bar>(1.0);
+        assert!(approx(pressure.to_unit::<Psi>(), 14.503774, 1e-6));
+        assert!(approx(pressure.to_unit::<Pascal>(), 100_000.0, 1e-9));
+
+        let pressure = Pressure::from_unit::<Psi>(14.695949);
+        assert!(approx(pressure.to_unit::<Atmosphere>(), 1.0, 1e-6));
+    }
+
+    #[test]
+    fn mercury_column() {
+        let pressure = Pressure::from_unit::<MillimeterOfMercury>(120.0);
+        assert!(approx(pressure.to_unit::<Kilopascal>(), 15.998686, 1e-6));
+    }
+
+    #[test]
+    fn gauge_round_trip() {
+        let ambient = Pressure::from_unit::<Kilopascal>(95.0);
+        let reading = GaugePressure::from_unit::<Psi>(35.0);
+        let absolute = reading.to_absolute(ambient);
+        assert!(approx(
+            absolute.to_unit::<Kilopascal>(),
+            95.0 + 35.0 * 6.894757293168361,
+            1e-9
+        ));
+        assert!(approx(
+            absolute.to_gauge(ambient).to_unit::<Psi>(),
+            35.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn gauge_depends_on_ambient() {
+        let absolute = Pressure::from_unit::<Bar>(3.0);
+        let sea_level = absolute.to_gauge(standard_atmosphere());
+        let altitude = absolute.to_gauge(Pressure::from_unit::<Bar>(0.8));
+        assert!(approx(sea_level.to_unit::<Bar>(), 1.98675, 1e-12));
+        assert!(approx(altitude.to_unit::<Bar>(), 2.2, 1e-12));
+        assert!(altitude > sea_level);
+    }
+
+    #[test]
+    fn ambient_reads_zero_gauge() {
+        let ambient = standard_atmosphere();
+        assert_eq!(ambient.to_gauge(ambient).to_unit::<Psi>(), 0.0);
+        assert_eq!(
+            GaugePressure::from_unit::<Bar>(0.0).to_absolute(ambient),
+            ambient
+        );
+    }
+
+    #[test]
+    fn pressure_symbols() {
+        assert_eq!(Pascal::SYMBOL, "Pa");
+        assert_eq!(Kilopascal::SYMBOL, "kPa");
+        assert_eq!(Bar::SYMBOL, "bar");
+        assert_eq!(Psi::SYMBOL, "psi");
+        assert_eq!(Atmosphere::SYMBOL, "atm");
+        assert_eq!(MillimeterOfMercury::SYMBOL, "mmHg");
+    }
+}
@@ -0,0 +1,292 @@
+//! # Dense Quantity Storage
+//!
+//! A `Vec<Quantity<U>>` already has the layout of a `Vec<f64>`, but code that wants to
+//! hand the raw numbers to a vectorized routine or a numeric library has to copy them
+//! out first. [`QuantityVec`] stores the base values in a plain `Vec<f64>` and keeps the
+//! unit in the type, so the numbers are available as a slice while pushes, reads and
+//! conversions stay typed.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Foot, Length, Meter};
+//! use uom::unit_conversion::quantity_vec::QuantityVec;
+//!
+//! let mut depths = QuantityVec::<Meter>::from_unit::<Foot>(&[10.0, 20.0]);
+//! depths.push(Length::from_unit::<Meter>(3.0));
+//!
+//! assert_eq!(depths.len(), 3);
+//! assert_eq!(depths.get(2).unwrap().to_unit::<Meter>(), 3.0);
+//! assert_eq!(depths.as_base_slice()[0], 3.048);
+//!
+//! let feet = depths.to_unit::<Foot>();
+//! assert!((feet[2] - 9.8425).abs() < 1e-4);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::unit_conversion::{Quantity, UnitConversion};
+
+/// A growable list of quantities of unit `U`, stored as contiguous base values.
+///
+/// Every element is a value in the dimension's base unit, just as inside a
+/// [`Quantity`]; [`as_base_slice`](Self::as_base_slice) exposes them without copying.
+pub struct QuantityVec<U: UnitConversion> {
+    base: Vec<f64>,
+    _u: PhantomData<U>,
+}
+
+impl<U: UnitConversion> Clone for QuantityVec<U> {
+    fn clone(&self) -> Self {
+        Self::from_base_vec(self.base.clone())
+    }
+}
+
+impl<U: UnitConversion> std::fmt::Debug for QuantityVec<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuantityVec")
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> Default for QuantityVec<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: UnitConversion> PartialEq for QuantityVec<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<U: UnitConversion> QuantityVec<U> {
+    /// Creates an empty list.
+    pub const fn new() -> Self {
+        Self::from_base_vec(Vec::new())
+    }
+
+    /// Creates an empty list with room for `capacity` quantities.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_base_vec(Vec::with_capacity(capacity))
+    }
+
+    /// Wraps values that are already in the dimension's base unit.
+    pub const fn from_base_vec(base: Vec<f64>) -> Self {
+        Self {
+            base,
+            _u: PhantomData,
+        }
+    }
+
+    /// Converts `values`, written in unit `V`, into a new list.
+    pub fn from_unit<V>(values: &[f64]) -> Self
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Self::from_base_vec(values.iter().map(|&v| V::convert_to(v)).collect())
+    }
+
+    /// Returns the number of quantities.
+    pub fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// Returns true if the list holds no quantities.
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+
+    /// Appends a quantity.
+    pub fn push(&mut self, quantity: Quantity<U>) {
+        self.base.push(quantity.in_base());
+    }
+
+    /// Removes and returns the last quantity.
+    pub fn pop(&mut self) -> Option<Quantity<U>> {
+        self.base.pop().map(Quantity::from_base)
+    }
+
+    /// Returns the quantity at `index`.
+    pub fn get(&self, index: usize) -> Option<Quantity<U>> {
+        self.base.get(index).copied().map(Quantity::from_base)
+    }
+
+    /// Replaces the quantity at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, quantity: Quantity<U>) {
+        self.base[index] = quantity.in_base();
+    }
+
+    /// Removes every quantity, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.base.clear();
+    }
+
+    /// Iterates over the quantities.
+    pub fn iter(&self) -> Iter<'_, U> {
+        Iter {
+            inner: self.base.iter(),
+            _u: PhantomData,
+        }
+    }
+
+    /// Returns every quantity converted to unit `V`.
+    pub fn to_unit<V>(&self) -> Vec<f64>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        self.base
+            .iter()
+            .map(|&base| V::convert_from(base))
+            .collect()
+    }
+
+    /// Returns the base values as a slice.
+    pub fn as_base_slice(&self) -> &[f64] {
+        &self.base
+    }
+
+    /// Returns the base values as a mutable slice, for routines that transform them in
+    /// place. Every value written must be in the dimension's base unit.
+    pub fn as_base_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.base
+    }
+
+    /// Unwraps the base values.
+    pub fn into_base_vec(self) -> Vec<f64> {
+        self.base
+    }
+}
+
+/// Iterator over the quantities of a [`QuantityVec`], created by
+/// [`QuantityVec::iter`].
+pub struct Iter<'a, U: UnitConversion> {
+    inner: std::slice::Iter<'a, f64>,
+    _u: PhantomData<U>,
+}
+
+impl<U: UnitConversion> Clone for Iter<'_, U> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _u: PhantomData,
+        }
+    }
+}
+
+impl<U: UnitConversion> Iterator for Iter<'_, U> {
+    type Item = Quantity<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().copied().map(Quantity::from_base)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<U: UnitConversion> DoubleEndedIterator for Iter<'_, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().copied().map(Quantity::from_base)
+    }
+}
+
+impl<U: UnitConversion> ExactSizeIterator for Iter<'_, U> {}
+
+impl<'a, U: UnitConversion> IntoIterator for &'a QuantityVec<U> {
+    type Item = Quantity<U>;
+    type IntoIter = Iter<'a, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<U: UnitConversion> FromIterator<Quantity<U>> for QuantityVec<U> {
+    fn from_iter<I: IntoIterator<Item = Quantity<U>>>(iter: I) -> Self {
+        Self::from_base_vec(iter.into_iter().map(|q| q.in_base()).collect())
+    }
+}
+
+impl<U: UnitConversion> Extend<Quantity<U>> for QuantityVec<U> {
+    fn extend<I: IntoIterator<Item = Quantity<U>>>(&mut self, iter: I) {
+        self.base.extend(iter.into_iter().map(|q| q.in_base()));
+    }
+}
+
+impl<U: UnitConversion> From<Vec<Quantity<U>>> for QuantityVec<U> {
+    fn from(quantities: Vec<Quantity<U>>) -> Self {
+        quantities.into_iter().collect()
+    }
+}
+
+#[cfg(all(test, feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn stores_base_values() {
+        let mut temps = QuantityVec::<Kelvin>::from_unit::<Celsius>(&[0.0, 100.0]);
+        assert_eq!(temps.as_base_slice(), [273.15, 373.15]);
+
+        temps.push(Temperature::from_unit::<Fahrenheit>(32.0));
+        assert!(approx(
+            temps.get(2).unwrap().to_unit::<Celsius>(),
+            0.0,
+            1e-9
+        ));
+        assert!(temps.get(3).is_none());
+
+        temps.set(0, Temperature::from_unit::<Kelvin>(0.0));
+        assert_eq!(temps.pop().map(|t| t.to_unit::<Kelvin>()), Some(273.15));
+        assert_eq!(temps.to_unit::<Kelvin>(), [0.0, 373.15]);
+    }
+
+    #[test]
+    fn converts_and_iterates() {
+        let temps: QuantityVec<Kelvin> = [-40.0, 20.0]
+            .into_iter()
+            .map(Temperature::from_unit::<Celsius>)
+            .collect();
+        let fahrenheit = temps.to_unit::<Fahrenheit>();
+        assert!(approx(fahrenheit[0], -40.0, 1e-9));
+        assert!(approx(fahrenheit[1], 68.0, 1e-9));
+
+        let celsius: Vec<f64> = temps.iter().rev().map(|t| t.to_unit::<Celsius>()).collect();
+        assert!(approx(celsius[0], 20.0, 1e-9));
+        assert_eq!(temps.iter().len(), 2);
+
+        let mut copy = QuantityVec::from(temps.iter().collect::<Vec<_>>());
+        assert_eq!(copy, temps);
+        for base in copy.as_base_mut_slice() {
+            *base += 10.0;
+        }
+        copy.extend(temps.iter());
+        assert_eq!(copy.len(), 4);
+        assert!(approx(
+            copy.get(0).unwrap().to_unit::<Celsius>(),
+            -30.0,
+            1e-9
+        ));
+
+        copy.clear();
+        assert!(copy.is_empty());
+        assert_eq!(
+            QuantityVec::<Kelvin>::default().into_base_vec(),
+            Vec::<f64>::new()
+        );
+    }
+}
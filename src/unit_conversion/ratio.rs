@@ -0,0 +1,597 @@
+//! # Ratio Unit Conversions
+//!
+//! This module provides a dimensionless ratio quantity with conversions between plain
+//! fractions, percent, per mille, basis points, parts per million and decibels, plus helpers that
+//! compare two quantities of the same dimension and convert between interest rates.
+//! All ratios are internally stored as plain fractions (the base unit) and converted on
+//! demand.
+//!
+//! ## Supported Units
+//!
+//! - **Unity (1)**: The base unit, a plain fraction where 1 is the whole
+//! - **Percent (%)**: 0.01
+//! - **Per mille (‰)**: 0.001
+//! - **Basis point (bp)**: 0.0001, a hundredth of a percent; `bps` is not accepted as an
+//!   alias, since it names bits per second
+//! - **Parts per million (ppm)**: 10⁻⁶
+//! - **Decibel (dB)**: A power ratio on a logarithmic scale, `10 log10(r)` dB, so 0 dB
+//!   is 1 and every 10 dB is a factor of ten
+//!
+//! ## Comparing Quantities
+//!
+//! [`relative_change`] and [`Quantity::percent_diff`] work on base-unit values. Every
+//! dimension stores an absolute base unit, so comparisons involving affine units such as
+//! degrees Celsius are physically meaningful: warming from 20 °C to 25 °C is a 1.7%
+//! change in absolute temperature, not 25%.
+//!
+//! ## Interest Rates
+//!
+//! A nominal annual rate (APR) compounded `n` times a year charges `APR / n` each period;
+//! [`periodic_rate`] and [`annual_rate`] convert between the two. Compounding makes the
+//! effective annual rate higher than the APR: [`effective_annual_rate`] and
+//! [`apr_from_effective`] convert between those.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::ratio::{Percent, Ratio, Unity, relative_change};
+//!
+//! let before = Length::from_unit::<Meter>(80.0);
+//! let after = Length::from_unit::<Meter>(100.0);
+//! let change = relative_change(before, after).unwrap();
+//! assert_eq!(change.to_unit::<Percent>(), 25.0);
+//!
+//! let tolerance = Ratio::from_unit::<Percent>(0.5);
+//! assert_eq!(tolerance.to_unit::<Unity>(), 0.005);
+//! ```
+
+use crate::unit_conversion::logarithmic::LogarithmicUnit;
+use crate::unit_conversion::{
+    Dimension, Quantity, UnitConversion, UnitError, UnitInfo, check_finite,
+};
+
+/// Constants for ratio conversions
+const FRACTION_PER_PERCENT: f64 = 0.01;
+const FRACTION_PER_PERMILLE: f64 = 0.001;
+const FRACTION_PER_BASIS_POINT: f64 = 1e-4;
+const FRACTION_PER_PART_PER_MILLION: f64 = 1e-6;
+const DECIBELS_PER_DECADE: f64 = 10.0;
+
+/// Marker type for the dimensionless ratio dimension.
+///
+/// Ratios are a separate dimension so that a plain fraction cannot be passed where a
+/// length or mass is expected.
+pub enum RatioDimension {}
+
+impl Dimension for RatioDimension {
+    const NAME: &'static str = "ratio";
+    const ID: u8 = 14;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Unity>(&["unity", "fraction"]),
+        UnitInfo::of::<Percent>(&["percent", "pct"]),
+        UnitInfo::of::<Permille>(&["permille", "per mille"]),
+        UnitInfo::of::<BasisPoint>(&["‱", "basis point", "basis points"]),
+        UnitInfo::of::<PartsPerMillion>(&["parts per million"]),
+        UnitInfo::of::<Decibel>(&["decibel", "decibels"]),
+    ];
+}
+
+/// A dimensionless ratio that stores values as plain fractions internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Ratio, Unity};
+///
+/// let efficiency = Ratio::from_unit::<Unity>(0.92);
+/// let percent = efficiency.to_unit::<Percent>(); // 92.0
+/// ```
+pub type Ratio = Quantity<Unity>;
+
+/// Unity ratio unit (base unit): a plain fraction, where 1 is the whole.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Ratio, Unity};
+///
+/// let half = Ratio::from_unit::<Unity>(0.5);
+/// assert_eq!(half.to_unit::<Unity>(), 0.5);
+/// ```
+pub struct Unity;
+
+impl UnitConversion for Unity {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Unity is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Unity is the base unit
+    }
+
+    const SYMBOL: &'static str = "1";
+    const NAME: &'static str = "";
+    const PLURAL: &'static str = "";
+}
+
+/// Percent ratio unit: one hundredth.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Ratio, Unity};
+///
+/// let discount = Ratio::from_unit::<Percent>(15.0);
+/// assert_eq!(discount.to_unit::<Unity>(), 0.15);
+/// ```
+pub struct Percent;
+
+impl UnitConversion for Percent {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * FRACTION_PER_PERCENT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / FRACTION_PER_PERCENT
+    }
+
+    const SYMBOL: &'static str = "%";
+    const NAME: &'static str = "percent";
+    const PLURAL: &'static str = "percent";
+}
+
+/// Per mille ratio unit: one thousandth.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Permille, Ratio};
+///
+/// let salinity = Ratio::from_unit::<Permille>(35.0);
+/// assert!((salinity.to_unit::<Percent>() - 3.5).abs() < 1e-12);
+/// ```
+pub struct Permille;
+
+impl UnitConversion for Permille {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * FRACTION_PER_PERMILLE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / FRACTION_PER_PERMILLE
+    }
+
+    const SYMBOL: &'static str = "‰";
+    const NAME: &'static str = "per mille";
+    const PLURAL: &'static str = "per mille";
+}
+
+/// Basis point ratio unit: one hundredth of a percent.
+///
+/// Used for interest rates and spreads, where a move from 4.25% to 4.50% is 25 basis
+/// points.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{BasisPoint, Percent, Ratio};
+///
+/// let hike = Ratio::from_unit::<BasisPoint>(25.0);
+/// assert!((hike.to_unit::<Percent>() - 0.25).abs() < 1e-12);
+/// ```
+pub struct BasisPoint;
+
+impl UnitConversion for BasisPoint {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * FRACTION_PER_BASIS_POINT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / FRACTION_PER_BASIS_POINT
+    }
+
+    const SYMBOL: &'static str = "bp";
+    const NAME: &'static str = "basis point";
+    const PLURAL: &'static str = "basis points";
+}
+
+/// Parts per million ratio unit: one millionth.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{PartsPerMillion, Percent, Ratio};
+///
+/// let co2 = Ratio::from_unit::<PartsPerMillion>(420.0);
+/// assert!((co2.to_unit::<Percent>() - 0.042).abs() < 1e-12);
+/// ```
+pub struct PartsPerMillion;
+
+impl UnitConversion for PartsPerMillion {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * FRACTION_PER_PART_PER_MILLION
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / FRACTION_PER_PART_PER_MILLION
+    }
+
+    const SYMBOL: &'static str = "ppm";
+    const NAME: &'static str = "part per million";
+    const PLURAL: &'static str = "parts per million";
+}
+
+/// Decibel ratio unit, a power ratio on a logarithmic scale.
+///
+/// A ratio `r` is `10 log10(r)` dB, as for gains and losses of power. Field quantities
+/// such as voltage have their own decibel units with a factor of 20.
+///
+/// # Examples
+///
+/// ```
+/// use uom::smart;
+/// use uom::unit_conversion::ratio::{Decibel, Ratio, Unity};
+///
+/// let half_power = Ratio::from_unit::<Decibel>(-3.0);
+/// assert!((half_power.to_unit::<Unity>() - 0.501).abs() < 1e-3);
+/// assert_eq!(smart!(-3.0, 1).with_linear::<Decibel>().to_string(), "-3 dB (0.501×)");
+/// ```
+pub struct Decibel;
+
+impl UnitConversion for Decibel {
+    type Dimension = RatioDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        Self::to_linear(value)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        Self::from_linear(value)
+    }
+
+    const SYMBOL: &'static str = "dB";
+    const NAME: &'static str = "decibel";
+    const PLURAL: &'static str = "decibels";
+}
+
+impl LogarithmicUnit for Decibel {
+    const LOG_FACTOR: f64 = DECIBELS_PER_DECADE;
+    const REFERENCE: f64 = 1.0;
+    const LINEAR_SYMBOL: &'static str = "×";
+}
+
+/// Returns the relative change from `from` to `to`, `(to - from) / from`, as a ratio.
+///
+/// The result is negative for a decrease. Both quantities are compared in base units.
+///
+/// # Errors
+///
+/// Returns [`UnitError::NonFinite`] if `from` is zero or either value is not finite.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
+/// use uom::unit_conversion::ratio::{Percent, relative_change};
+/// use uom::unit_conversion::temperature::{Celsius, Temperature};
+///
+/// let morning = Temperature::from_unit::<Celsius>(20.0);
+/// let noon = Temperature::from_unit::<Celsius>(25.0);
+/// let change = relative_change(morning, noon).unwrap();
+/// assert!((change.to_unit::<Percent>() - 1.7056).abs() < 1e-4);
+/// ```
+pub fn relative_change<U: UnitConversion>(
+    from: Quantity<U>,
+    to: Quantity<U>,
+) -> Result<Ratio, UnitError> {
+    let (from, to) = (from.in_base(), to.in_base());
+    check_finite((to - from) / from).map(Ratio::from_base)
+}
+
+impl<U: UnitConversion> Quantity<U> {
+    /// Returns the symmetric percent difference between this quantity and `other`:
+    /// their absolute difference divided by their mean, as a ratio.
+    ///
+    /// Unlike [`relative_change`] the result does not depend on which value is taken as
+    /// the reference, which suits comparing two measurements of the same thing. Both
+    /// quantities are compared in base units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if the mean is zero or either value is not
+    /// finite.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter};
+    /// use uom::unit_conversion::ratio::Percent;
+    ///
+    /// let tape = Length::from_unit::<Meter>(9.0);
+    /// let laser = Length::from_unit::<Meter>(11.0);
+    /// let diff = tape.percent_diff(laser).unwrap();
+    /// assert_eq!(diff.to_unit::<Percent>(), 20.0);
+    /// assert_eq!(laser.percent_diff(tape), Ok(diff));
+    /// ```
+    pub fn percent_diff(self, other: Self) -> Result<Ratio, UnitError> {
+        let (a, b) = (self.in_base(), other.in_base());
+        check_finite((a - b).abs() / ((a + b) / 2.0)).map(Ratio::from_base)
+    }
+}
+
+/// Returns the rate charged each period under the nominal annual rate `apr`,
+/// compounded `periods_per_year` times a year.
+///
+/// # Errors
+///
+/// Returns [`UnitError::OutOfRange`] if `periods_per_year` is zero, or
+/// [`UnitError::NonFinite`] if `apr` is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Ratio, periodic_rate};
+///
+/// let card = Ratio::from_unit::<Percent>(24.0);
+/// let monthly = periodic_rate(card, 12).unwrap();
+/// assert!((monthly.to_unit::<Percent>() - 2.0).abs() < 1e-12);
+/// ```
+pub fn periodic_rate(apr: Ratio, periods_per_year: u32) -> Result<Ratio, UnitError> {
+    let n = periods(periods_per_year)?;
+    check_finite(apr.in_base() / n).map(Ratio::from_base)
+}
+
+/// Returns the nominal annual rate (APR) of a rate `periodic` charged
+/// `periods_per_year` times a year.
+///
+/// # Errors
+///
+/// Returns [`UnitError::OutOfRange`] if `periods_per_year` is zero, or
+/// [`UnitError::NonFinite`] if `periodic` is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{BasisPoint, Percent, Ratio, annual_rate};
+///
+/// let daily = Ratio::from_unit::<BasisPoint>(1.0);
+/// let apr = annual_rate(daily, 365).unwrap();
+/// assert!((apr.to_unit::<Percent>() - 3.65).abs() < 1e-12);
+/// ```
+pub fn annual_rate(periodic: Ratio, periods_per_year: u32) -> Result<Ratio, UnitError> {
+    let n = periods(periods_per_year)?;
+    check_finite(periodic.in_base() * n).map(Ratio::from_base)
+}
+
+/// Returns the effective annual rate of the nominal annual rate `apr` compounded
+/// `periods_per_year` times a year: `(1 + APR / n)ⁿ − 1`.
+///
+/// # Errors
+///
+/// Returns [`UnitError::OutOfRange`] if `periods_per_year` is zero, or
+/// [`UnitError::NonFinite`] if `apr` is not finite or the result overflows.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Ratio, effective_annual_rate};
+///
+/// let apr = Ratio::from_unit::<Percent>(12.0);
+/// let ear = effective_annual_rate(apr, 12).unwrap();
+/// assert!((ear.to_unit::<Percent>() - 12.6825).abs() < 1e-4);
+/// ```
+pub fn effective_annual_rate(apr: Ratio, periods_per_year: u32) -> Result<Ratio, UnitError> {
+    let n = periods(periods_per_year)?;
+    check_finite((apr.in_base() / n).ln_1p() * n)
+        .map(f64::exp_m1)
+        .and_then(check_finite)
+        .map(Ratio::from_base)
+}
+
+/// Returns the nominal annual rate (APR) that, compounded `periods_per_year` times a
+/// year, gives the effective annual rate `ear`: `n((1 + EAR)^(1/n) − 1)`.
+///
+/// # Errors
+///
+/// Returns [`UnitError::OutOfRange`] if `periods_per_year` is zero, or
+/// [`UnitError::NonFinite`] if `ear` is not finite or is a loss of 100% or more.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::ratio::{Percent, Ratio, apr_from_effective};
+///
+/// let ear = Ratio::from_unit::<Percent>(5.0);
+/// let apr = apr_from_effective(ear, 12).unwrap();
+/// assert!((apr.to_unit::<Percent>() - 4.8889).abs() < 1e-4);
+/// ```
+pub fn apr_from_effective(ear: Ratio, periods_per_year: u32) -> Result<Ratio, UnitError> {
+    let n = periods(periods_per_year)?;
+    check_finite(ear.in_base().ln_1p()).map(|growth| Ratio::from_base((growth / n).exp_m1() * n))
+}
+
+fn periods(periods_per_year: u32) -> Result<f64, UnitError> {
+    if periods_per_year == 0 {
+        return Err(UnitError::OutOfRange {
+            value: 0.0,
+            min: 1.0,
+            max: f64::from(u32::MAX),
+        });
+    }
+    Ok(f64::from(periods_per_year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn ratio(value: f64) -> Ratio {
+        Ratio::from_unit::<Unity>(value)
+    }
+
+    #[test]
+    fn unit_conversions() {
+        assert!(approx(ratio(0.25).to_unit::<Percent>(), 25.0, 1e-12));
+        assert!(approx(ratio(0.25).to_unit::<Permille>(), 250.0, 1e-12));
+        assert!(approx(
+            Ratio::from_unit::<PartsPerMillion>(5000.0).to_unit::<Percent>(),
+            0.5,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn relative_changes() {
+        assert!(approx(
+            relative_change(ratio(2.0), ratio(3.0))
+                .unwrap()
+                .to_unit::<Percent>(),
+            50.0,
+            1e-12
+        ));
+        assert!(approx(
+            relative_change(ratio(4.0), ratio(3.0))
+                .unwrap()
+                .to_unit::<Unity>(),
+            -0.25,
+            1e-12
+        ));
+        assert!(matches!(
+            relative_change(ratio(0.0), ratio(1.0)),
+            Err(UnitError::NonFinite { .. })
+        ));
+    }
+
+    #[test]
+    fn percent_diff_is_symmetric() {
+        let (a, b) = (ratio(90.0), ratio(110.0));
+        assert_eq!(a.percent_diff(b), b.percent_diff(a));
+        assert!(approx(
+            a.percent_diff(b).unwrap().to_unit::<Percent>(),
+            20.0,
+            1e-12
+        ));
+        assert!(ratio(1.0).percent_diff(ratio(-1.0)).is_err());
+        assert!(ratio(f64::NAN).percent_diff(ratio(1.0)).is_err());
+    }
+
+    #[test]
+    fn basis_points() {
+        let spread = Ratio::from_unit::<BasisPoint>(150.0);
+        assert!(approx(spread.to_unit::<Percent>(), 1.5, 1e-12));
+        assert!(approx(spread.to_unit::<Unity>(), 0.015, 1e-15));
+        assert_eq!(Ratio::parse("25 bp").unwrap().to_unit::<BasisPoint>(), 25.0);
+        assert!(RatioDimension::find_unit("bps").is_none());
+    }
+
+    #[test]
+    fn interest_rates() {
+        let apr = Ratio::from_unit::<Percent>(6.0);
+        let monthly = periodic_rate(apr, 12).unwrap();
+        assert!(approx(monthly.to_unit::<BasisPoint>(), 50.0, 1e-9));
+        assert!(approx(
+            annual_rate(monthly, 12).unwrap().to_unit::<Percent>(),
+            6.0,
+            1e-12
+        ));
+
+        let ear = effective_annual_rate(apr, 12).unwrap();
+        assert!(approx(ear.to_unit::<Percent>(), 6.167781, 1e-6));
+        assert!(approx(
+            apr_from_effective(ear, 12).unwrap().to_unit::<Percent>(),
+            6.0,
+            1e-9
+        ));
+
+        // Compounding once a year changes nothing
+        assert!(approx(
+            effective_annual_rate(apr, 1).unwrap().to_unit::<Percent>(),
+            6.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_rates() {
+        let apr = Ratio::from_unit::<Percent>(5.0);
+        assert!(matches!(
+            periodic_rate(apr, 0),
+            Err(UnitError::OutOfRange { .. })
+        ));
+        assert!(apr_from_effective(Ratio::from_unit::<Percent>(-100.0), 12).is_err());
+        assert!(effective_annual_rate(ratio(f64::NAN), 12).is_err());
+    }
+
+    #[test]
+    fn parses_percentages() {
+        assert_eq!(Ratio::parse("12.5%").unwrap().to_unit::<Unity>(), 0.125);
+        assert_eq!(
+            Ratio::parse("3 ppm").unwrap().to_unit::<PartsPerMillion>(),
+            3.0
+        );
+        assert_eq!(
+            Ratio::parse("40 percent").unwrap().to_unit::<Percent>(),
+            40.0
+        );
+    }
+
+    #[test]
+    fn ratio_symbols() {
+        assert_eq!(Unity::SYMBOL, "1");
+        assert_eq!(Percent::SYMBOL, "%");
+        assert_eq!(Permille::SYMBOL, "‰");
+        assert_eq!(BasisPoint::SYMBOL, "bp");
+        assert_eq!(PartsPerMillion::SYMBOL, "ppm");
+        assert_eq!(Decibel::SYMBOL, "dB");
+    }
+
+    #[test]
+    fn decibels_and_their_linear_equivalent() {
+        assert!(approx(
+            Ratio::from_unit::<Decibel>(20.0).to_unit::<Unity>(),
+            100.0,
+            1e-9
+        ));
+        assert!(approx(ratio(2.0).to_unit::<Decibel>(), 3.0103, 1e-4));
+        assert_eq!(ratio(0.0).to_unit::<Decibel>(), f64::NEG_INFINITY);
+        assert!(ratio(-1.0).to_unit::<Decibel>().is_nan());
+
+        let gain = crate::smart!(30.0, 1).with_linear::<Decibel>();
+        assert_eq!(gain.to_string(), "30 dB (1000×)");
+        let loss = crate::smart!(-6.02, 2).with_linear::<Decibel>();
+        assert_eq!(loss.with_figures(2).to_string(), "-6.02 dB (0.25×)");
+        let mut huge = crate::smart!(73.0, 0).with_linear::<Decibel>();
+        assert_eq!(huge.to_string(), "73 dB (2.00e7×)");
+        huge.figures = 0;
+        assert_eq!(huge.to_string(), "73 dB (2e7×)");
+    }
+}
@@ -0,0 +1,588 @@
+//! # Unit Registry
+//!
+//! Every built-in dimension lists its units in a [`Dimension::UNITS`] table. This
+//! module collects the tables of all enabled dimensions into [`DIMENSIONS`] so that
+//! units can be looked up and converted by name at runtime, when the unit is not known
+//! until a user or a request supplies it. Statically typed code should keep using
+//! [`Quantity`](crate::unit_conversion::Quantity) directly.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::registry;
+//!
+//! let conversion = registry::convert(5.0, "km", "m").unwrap();
+//! assert_eq!(conversion.dimension, "length");
+//! assert_eq!(conversion.to.symbol, "m");
+//! assert_eq!(conversion.value, 5000.0);
+//!
+//! assert!(registry::convert(5.0, "km", "°C").is_err());
+//! ```
+//!
+//! Applications can add units of their own with [`register_unit`]. Registered units
+//! are found, converted and listed by [`DimensionInfo::all_units`] alongside the
+//! built-in ones, but they have no type and cannot form a `Quantity`.
+
+use std::sync::RwLock;
+
+use crate::unit_conversion::parse::{NumberLocale, split_number};
+use crate::unit_conversion::{Dimension, UnitError, UnitInfo, check_finite};
+
+/// Runtime description of a dimension: its name and unit table.
+#[derive(Debug)]
+pub struct DimensionInfo {
+    /// The lowercase dimension name (the dimension's `Dimension::NAME`).
+    pub name: &'static str,
+    /// The dimension's stable binary identifier (the dimension's `Dimension::ID`).
+    pub id: u8,
+    /// The dimension's units, base unit first.
+    pub units: &'static [UnitInfo],
+    suggest: fn(&str) -> Vec<&'static str>,
+}
+
+impl DimensionInfo {
+    /// Creates the runtime description of dimension `D`.
+    pub const fn of<D: Dimension>() -> Self {
+        Self {
+            name: D::NAME,
+            id: D::ID,
+            units: D::UNITS,
+            suggest: D::suggest,
+        }
+    }
+
+    /// Finds the unit matching `text` among the built-in and registered units, as
+    /// [`Dimension::find_unit`] does: an exact match first, then one ignoring case.
+    pub fn find_unit(&self, text: &str) -> Option<&'static UnitInfo> {
+        self.find_unit_exactly(text)
+            .or_else(|| self.find_first(|unit| unit.matches(text)))
+    }
+
+    /// Finds the unit whose symbol or one of whose aliases is exactly `text`.
+    pub fn find_unit_exactly(&self, text: &str) -> Option<&'static UnitInfo> {
+        self.find_first(|unit| unit.matches_exactly(text))
+    }
+
+    /// Returns the first unit, in [`all_units`](Self::all_units) order, that `accept`
+    /// holds for, without collecting the registered units.
+    fn find_first(&self, accept: impl Fn(&UnitInfo) -> bool) -> Option<&'static UnitInfo> {
+        self.units.iter().find(|unit| accept(unit)).or_else(|| {
+            REGISTERED
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .iter()
+                .find(|(id, unit)| *id == self.id && accept(unit))
+                .map(|(_, unit)| *unit)
+        })
+    }
+
+    /// Returns every unit of this dimension: the built-in ones, base unit first, then
+    /// those added with [`register_unit`] in the order they were registered.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::registry;
+    ///
+    /// let length = registry::dimension("length").unwrap();
+    /// let symbols: Vec<&str> = length.all_units().map(|unit| unit.symbol).collect();
+    /// assert_eq!(symbols[0], "m");
+    /// assert!(symbols.contains(&"ft"));
+    /// ```
+    pub fn all_units(&self) -> impl Iterator<Item = &'static UnitInfo> + use<> {
+        self.units.iter().chain(self.registered_units())
+    }
+
+    /// Returns the units added to this dimension with [`register_unit`].
+    pub fn registered_units(&self) -> Vec<&'static UnitInfo> {
+        REGISTERED
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .filter(|(id, _)| *id == self.id)
+            .map(|(_, unit)| *unit)
+            .collect()
+    }
+
+    /// Suggests unit names that `text` may have been a misspelling of, as
+    /// [`Dimension::suggest`] does.
+    pub fn suggest(&self, text: &str) -> Vec<&'static str> {
+        (self.suggest)(text)
+    }
+}
+
+/// The dimensions enabled in this build.
+///
+/// Lookups that search every dimension try them in this order, which matters for the
+/// few names shared between dimensions, such as `m` for meters and minutes. An exact
+/// match in any dimension wins over a match ignoring case in an earlier one, so `mA`
+/// is milliamperes even though `Ma` (Mach) is a speed.
+pub const DIMENSIONS: &[DimensionInfo] = &[
+    #[cfg(feature = "length")]
+    DimensionInfo::of::<super::length::LengthDimension>(),
+    #[cfg(feature = "temperature")]
+    DimensionInfo::of::<super::temperature::TemperatureDimension>(),
+    #[cfg(feature = "mass")]
+    DimensionInfo::of::<super::mass::MassDimension>(),
+    #[cfg(feature = "volume")]
+    DimensionInfo::of::<super::volume::VolumeDimension>(),
+    #[cfg(feature = "time")]
+    DimensionInfo::of::<super::time::TimeDimension>(),
+    #[cfg(feature = "angle")]
+    DimensionInfo::of::<super::angle::AngleDimension>(),
+    #[cfg(feature = "energy")]
+    DimensionInfo::of::<super::energy::EnergyDimension>(),
+    #[cfg(feature = "power")]
+    DimensionInfo::of::<super::power::PowerDimension>(),
+    #[cfg(feature = "pressure")]
+    DimensionInfo::of::<super::pressure::PressureDimension>(),
+    #[cfg(feature = "area")]
+    DimensionInfo::of::<super::area::AreaDimension>(),
+    #[cfg(feature = "speed")]
+    DimensionInfo::of::<super::speed::SpeedDimension>(),
+    #[cfg(feature = "voltage")]
+    DimensionInfo::of::<super::voltage::VoltageDimension>(),
+    #[cfg(feature = "frequency")]
+    DimensionInfo::of::<super::frequency::FrequencyDimension>(),
+    #[cfg(feature = "ratio")]
+    DimensionInfo::of::<super::ratio::RatioDimension>(),
+    #[cfg(feature = "amount")]
+    DimensionInfo::of::<super::amount::AmountDimension>(),
+    #[cfg(feature = "force")]
+    DimensionInfo::of::<super::force::ForceDimension>(),
+    #[cfg(feature = "acceleration")]
+    DimensionInfo::of::<super::acceleration::AccelerationDimension>(),
+    #[cfg(feature = "data_size")]
+    DimensionInfo::of::<super::data_size::DataSizeDimension>(),
+    #[cfg(feature = "data_rate")]
+    DimensionInfo::of::<super::data_rate::DataRateDimension>(),
+    #[cfg(feature = "current")]
+    DimensionInfo::of::<super::current::CurrentDimension>(),
+    #[cfg(feature = "resistance")]
+    DimensionInfo::of::<super::resistance::ResistanceDimension>(),
+    #[cfg(feature = "charge")]
+    DimensionInfo::of::<super::charge::ChargeDimension>(),
+    #[cfg(feature = "emissions")]
+    DimensionInfo::of::<super::emissions::DistanceEmissionsDimension>(),
+    #[cfg(feature = "emissions")]
+    DimensionInfo::of::<super::emissions::EnergyEmissionsDimension>(),
+    #[cfg(feature = "concentration")]
+    DimensionInfo::of::<super::concentration::MassConcentrationDimension>(),
+    #[cfg(feature = "concentration")]
+    DimensionInfo::of::<super::concentration::MolarConcentrationDimension>(),
+    #[cfg(feature = "mass_rate")]
+    DimensionInfo::of::<super::mass_rate::MassRateDimension>(),
+];
+
+/// Units added at runtime, keyed by dimension ID.
+static REGISTERED: RwLock<Vec<(u8, &'static UnitInfo)>> = RwLock::new(Vec::new());
+
+/// Adds `unit` to the enabled dimension named `dimension`, for the lifetime of the
+/// program.
+///
+/// The unit's conversions are relative to the dimension's base unit, and it takes part
+/// in [`find_unit`], [`convert`], [`parse`] and
+/// [`Quantity::parse`](crate::unit_conversion::Quantity::parse) after the built-in
+/// units. Registered units are never freed.
+///
+/// # Errors
+///
+/// Returns [`UnitError::ParseError`] if there is no such dimension, or if the unit's
+/// symbol or one of its aliases already names a unit of that dimension.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::UnitInfo;
+/// use uom::unit_conversion::registry;
+///
+/// registry::register_unit("length", UnitInfo {
+///     symbol: "fur",
+///     name: "furlong",
+///     plural: "furlongs",
+///     aliases: &["furlong", "furlongs"],
+///     to_base: |furlongs| furlongs * 201.168,
+///     from_base: |meters| meters / 201.168,
+/// })
+/// .unwrap();
+///
+/// assert_eq!(registry::convert(1.0, "furlong", "m").unwrap().value, 201.168);
+/// let length = registry::dimension("length").unwrap();
+/// assert_eq!(length.all_units().last().unwrap().symbol, "fur");
+/// ```
+pub fn register_unit(dimension: &str, unit: UnitInfo) -> Result<&'static UnitInfo, UnitError> {
+    let info = self::dimension(dimension).ok_or_else(|| UnitError::ParseError {
+        input: dimension.to_string(),
+        reason: "unknown dimension",
+    })?;
+    let mut registered = REGISTERED
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let taken = std::iter::once(&unit.symbol)
+        .chain(unit.aliases)
+        .find(|name| {
+            info.units
+                .iter()
+                .chain(
+                    registered
+                        .iter()
+                        .filter(|(id, _)| *id == info.id)
+                        .map(|(_, unit)| *unit),
+                )
+                .any(|existing| existing.matches_exactly(name))
+        });
+    if let Some(name) = taken {
+        return Err(UnitError::ParseError {
+            input: name.to_string(),
+            reason: "a unit with this name already exists",
+        });
+    }
+    let unit: &'static UnitInfo = Box::leak(Box::new(unit));
+    registered.push((info.id, unit));
+    Ok(unit)
+}
+
+/// Returns the enabled dimension with the given name.
+pub fn dimension(name: &str) -> Option<&'static DimensionInfo> {
+    DIMENSIONS.iter().find(|d| d.name == name)
+}
+
+/// Finds the unit of dimension `D` matching `text`, among its built-in units and any
+/// registered with [`register_unit`].
+pub(crate) fn find_unit_of<D: Dimension>(text: &str) -> Option<&'static UnitInfo> {
+    match dimension(D::NAME) {
+        Some(info) => info.find_unit(text),
+        None => D::find_unit(text),
+    }
+}
+
+/// Finds the first dimension with a unit matching `text`, and that unit.
+///
+/// Every dimension is searched for an exact match before any is searched ignoring
+/// case.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::registry;
+///
+/// let (dimension, unit) = registry::find_unit("KM").unwrap();
+/// assert_eq!((dimension.name, unit.symbol), ("length", "km"));
+/// ```
+pub fn find_unit(text: &str) -> Option<(&'static DimensionInfo, &'static UnitInfo)> {
+    DIMENSIONS
+        .iter()
+        .find_map(|d| d.find_unit_exactly(text).map(|unit| (d, unit)))
+        .or_else(|| {
+            DIMENSIONS
+                .iter()
+                .find_map(|d| d.find_unit(text).map(|unit| (d, unit)))
+        })
+}
+
+/// A number and a unit read from text whose dimension was not known in advance.
+#[derive(Copy, Clone, Debug)]
+pub struct Measurement {
+    /// The number as written, in `unit`.
+    pub value: f64,
+    /// The dimension of the unit.
+    pub dimension: &'static DimensionInfo,
+    /// The unit the number was written in.
+    pub unit: &'static UnitInfo,
+}
+
+/// Parses a number followed by a unit of any enabled dimension, such as `"100 km"`.
+///
+/// The number is read in the given [`NumberLocale`]. The unit is looked up as by
+/// [`find_unit`], so a unit that belongs to several dimensions resolves to the first
+/// one in [`DIMENSIONS`] where it matches exactly.
+///
+/// # Errors
+///
+/// Returns [`UnitError::ParseError`] if there is no number or unit, or the unit is not
+/// part of any enabled dimension.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::parse::NumberLocale;
+/// use uom::unit_conversion::registry;
+///
+/// let measurement = registry::parse("1,5 km", NumberLocale::EUROPEAN).unwrap();
+/// assert_eq!(measurement.value, 1.5);
+/// assert_eq!(measurement.dimension.name, "length");
+/// assert_eq!(measurement.unit.symbol, "km");
+/// ```
+pub fn parse(input: &str, locale: NumberLocale) -> Result<Measurement, UnitError> {
+    let fail = |reason| UnitError::ParseError {
+        input: input.to_string(),
+        reason,
+    };
+    let (value, rest) = split_number(input.trim(), locale).map_err(fail)?;
+    let unit = rest.trim();
+    if unit.is_empty() {
+        return Err(fail("expected a unit after the number"));
+    }
+    let (dimension, unit) = find_unit(unit).ok_or_else(|| fail("unknown unit"))?;
+    Ok(Measurement {
+        value,
+        dimension,
+        unit,
+    })
+}
+
+/// The result of converting a value between two units named at runtime.
+#[derive(Copy, Clone, Debug)]
+pub struct Conversion {
+    /// The name of the dimension both units belong to.
+    pub dimension: &'static str,
+    /// The unit converted from.
+    pub from: &'static UnitInfo,
+    /// The unit converted to.
+    pub to: &'static UnitInfo,
+    /// The converted value, in `to` units.
+    pub value: f64,
+}
+
+/// Converts `value` from the unit named `from` to the unit named `to`.
+///
+/// Both names are matched against symbols and aliases as when parsing. If a name
+/// belongs to several dimensions, the first dimension containing both units is used,
+/// so `convert(90.0, "m", "h")` converts minutes to hours. A dimension where both
+/// names match exactly is preferred over one where they only match ignoring case.
+///
+/// # Errors
+///
+/// - [`UnitError::ParseError`] if `from` is not a unit of any enabled dimension
+/// - [`UnitError::DimensionMismatch`] if `to` belongs to a different dimension
+/// - [`UnitError::UnknownUnit`] if `to` is not a known unit at all
+/// - [`UnitError::NonFinite`] if the result is infinite or NaN
+pub fn convert(value: f64, from: &str, to: &str) -> Result<Conversion, UnitError> {
+    let (dimension, from_unit, to_unit) = resolve(from, to)?;
+    let result = (to_unit.from_base)((from_unit.to_base)(value));
+    Ok(Conversion {
+        dimension: dimension.name,
+        from: from_unit,
+        to: to_unit,
+        value: check_finite(result)?,
+    })
+}
+
+/// Finds the units named `from` and `to` in the first dimension containing both,
+/// preferring the one where most of the names match exactly, with the errors
+/// described for [`convert`].
+pub(crate) fn resolve(
+    from: &str,
+    to: &str,
+) -> Result<(&'static DimensionInfo, &'static UnitInfo, &'static UnitInfo), UnitError> {
+    let mut both: Option<(usize, &DimensionInfo, &UnitInfo, &UnitInfo)> = None;
+    for d in DIMENSIONS {
+        let (Some(from_unit), Some(to_unit)) = (d.find_unit(from), d.find_unit(to)) else {
+            continue;
+        };
+        let exact =
+            usize::from(from_unit.matches_exactly(from)) + usize::from(to_unit.matches_exactly(to));
+        if both.is_none_or(|(best, ..)| exact > best) {
+            both = Some((exact, d, from_unit, to_unit));
+        }
+    }
+    let Some((_, dimension, from_unit, to_unit)) = both else {
+        let (dimension, _) = find_unit(from).ok_or_else(|| UnitError::ParseError {
+            input: from.to_string(),
+            reason: "unknown unit",
+        })?;
+        return Err(match find_unit(to) {
+            Some((found, _)) => UnitError::DimensionMismatch {
+                expected: dimension.name,
+                found: found.name,
+            },
+            None => UnitError::UnknownUnit {
+                unit: to.to_string(),
+                dimension: dimension.name,
+                expected: dimension.all_units().map(|unit| unit.symbol).collect(),
+                suggestions: dimension.suggest(to),
+            },
+        });
+    };
+    Ok((dimension, from_unit, to_unit))
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn converts_by_name() {
+        let conversion = convert(100.0, "celsius", "°F").unwrap();
+        assert_eq!(conversion.dimension, "temperature");
+        assert_eq!(conversion.from.symbol, "°C");
+        assert!(approx(conversion.value, 212.0, 1e-9));
+
+        let conversion = convert(2.0, "ft", "inches").unwrap();
+        assert!(approx(conversion.value, 24.0, 1e-12));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn shared_names_resolve_within_one_dimension() {
+        assert!(approx(convert(90.0, "m", "h").unwrap().value, 1.5, 1e-12));
+        assert!(approx(
+            convert(1.0, "km", "m").unwrap().value,
+            1000.0,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn reports_why_a_conversion_failed() {
+        assert_eq!(
+            convert(1.0, "km", "K").unwrap_err(),
+            UnitError::DimensionMismatch {
+                expected: "length",
+                found: "temperature",
+            }
+        );
+        assert!(matches!(
+            convert(1.0, "furlongs", "m"),
+            Err(UnitError::ParseError { .. })
+        ));
+        match convert(1.0, "m", "meterz") {
+            Err(UnitError::UnknownUnit {
+                dimension,
+                suggestions,
+                ..
+            }) => {
+                assert_eq!(dimension, "length");
+                assert!(!suggestions.is_empty());
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_any_dimension() {
+        let measurement = parse(" 100 km ", NumberLocale::PLAIN).unwrap();
+        assert_eq!(measurement.value, 100.0);
+        assert_eq!(measurement.dimension.name, "length");
+        assert_eq!(measurement.unit.symbol, "km");
+
+        let measurement = parse("-40°F", NumberLocale::PLAIN).unwrap();
+        assert_eq!(measurement.dimension.name, "temperature");
+
+        let reason = |input| match parse(input, NumberLocale::PLAIN) {
+            Err(UnitError::ParseError { reason, .. }) => reason,
+            other => panic!("unexpected result: {other:?}"),
+        };
+        assert_eq!(reason("km"), "expected a number");
+        assert_eq!(reason("12"), "expected a unit after the number");
+        assert_eq!(reason("12 furlongs"), "unknown unit");
+    }
+
+    #[test]
+    fn lists_enabled_dimensions() {
+        assert_eq!(dimension("length").unwrap().units[0].symbol, "m");
+        assert!(dimension("length").unwrap().find_unit("ft").is_some());
+        assert!(dimension("flavor").is_none());
+        let (found, unit) = find_unit("kelvin").unwrap();
+        assert_eq!((found.name, unit.symbol), ("temperature", "K"));
+    }
+
+    #[test]
+    fn registers_units_at_runtime() {
+        let temperature = dimension("temperature").unwrap();
+        let built_in = temperature.units.len();
+        let unit = register_unit(
+            "temperature",
+            UnitInfo {
+                symbol: "°Rtest",
+                name: "test degree",
+                plural: "test degrees",
+                aliases: &["testdeg"],
+                to_base: |value| value * 2.0,
+                from_base: |kelvin| kelvin / 2.0,
+            },
+        )
+        .unwrap();
+        assert_eq!(unit.plural, "test degrees");
+
+        let all: Vec<_> = temperature.all_units().collect();
+        assert_eq!(all.len(), built_in + temperature.registered_units().len());
+        assert_eq!(all[0].symbol, "K");
+        assert!(all.iter().any(|unit| unit.symbol == "°Rtest"));
+        assert!(
+            dimension("length")
+                .unwrap()
+                .registered_units()
+                .iter()
+                .all(|u| u.symbol != "°Rtest")
+        );
+
+        assert_eq!(find_unit("TESTDEG").unwrap().1.symbol, "°Rtest");
+        assert!(approx(
+            convert(10.0, "testdeg", "K").unwrap().value,
+            20.0,
+            1e-12
+        ));
+        assert_eq!(
+            parse("3 testdeg", NumberLocale::PLAIN).unwrap().unit.symbol,
+            "°Rtest"
+        );
+
+        let duplicate = |symbol, aliases| UnitInfo {
+            symbol,
+            name: "",
+            plural: "",
+            aliases,
+            to_base: |value| value,
+            from_base: |value| value,
+        };
+        assert!(register_unit("temperature", duplicate("K", &[])).is_err());
+        assert!(register_unit("temperature", duplicate("°X", &["testdeg"])).is_err());
+        assert!(register_unit("flavor", duplicate("mint", &[])).is_err());
+    }
+
+    #[cfg(all(
+        feature = "acceleration",
+        feature = "angle",
+        feature = "area",
+        feature = "current",
+        feature = "force",
+        feature = "power",
+        feature = "speed",
+    ))]
+    #[test]
+    fn exact_matches_win_over_case_insensitive_ones() {
+        let colliding = [
+            ("A", "current"),
+            ("a", "area"),
+            ("mA", "current"),
+            ("Ma", "speed"),
+            ("kN", "force"),
+            ("kn", "speed"),
+            ("TR", "power"),
+            ("tr", "angle"),
+            ("Gal", "acceleration"),
+        ];
+        for (symbol, name) in colliding {
+            let (found, unit) = find_unit(symbol).unwrap();
+            assert_eq!((found.name, unit.symbol), (name, symbol));
+            let measurement = parse(&format!("20 {symbol}"), NumberLocale::PLAIN).unwrap();
+            assert_eq!(measurement.unit.symbol, symbol);
+            assert_eq!(convert(1.0, symbol, symbol).unwrap().dimension, name);
+        }
+        assert_eq!(resolve("mA", "A").unwrap().0.name, "current");
+        assert_eq!(resolve("kn", "km/h").unwrap().0.name, "speed");
+    }
+}
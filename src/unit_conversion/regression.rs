@@ -0,0 +1,375 @@
+//! # Linear Regression
+//!
+//! Calibration runs and trend analysis fit a straight line through measured pairs: a
+//! sensor's output against a reference, a tank's temperature against time. A
+//! [`LinearFit`] is the least-squares line through pairs of quantities with a unit on
+//! each axis, so its slope reads directly as °C per hour or millivolts per kelvin and
+//! its predictions come back as typed quantities.
+//!
+//! The fit works on base values, which gives the same line as fitting in any units
+//! related to the base by a scale and an offset. The slope is a ratio of differences,
+//! so it does not depend on offsets such as the one between °C and K.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//! use uom::unit_conversion::regression::LinearFit;
+//! use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+//!
+//! // Temperature down a borehole rises about 3 °C per 100 m
+//! let survey = [(0.0, 12.1), (100.0, 14.9), (200.0, 18.2), (300.0, 20.9)];
+//! let points: Vec<(Length, Temperature)> = survey
+//!     .iter()
+//!     .map(|&(m, c)| (Length::from_unit::<Meter>(m), Temperature::from_unit::<Celsius>(c)))
+//!     .collect();
+//! let fit = LinearFit::<Meter, Celsius>::new(&points).unwrap();
+//!
+//! assert!((fit.slope_in::<Celsius, Meter>() * 100.0 - 2.97).abs() < 1e-9);
+//! assert!((fit.intercept().to_unit::<Celsius>() - 12.07).abs() < 1e-9);
+//! let at_500 = fit.predict(Length::from_unit::<Meter>(500.0));
+//! assert!((at_500.to_unit::<Fahrenheit>() - 80.456).abs() < 1e-9);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, check_finite, unit_span};
+
+/// The least-squares straight line through pairs of quantities in units `X` and `Y`.
+///
+/// The fitted parameters are read in any units of the two dimensions: the slope as a
+/// plain number of `Y` units per `X` unit, the intercept and predictions as
+/// quantities.
+pub struct LinearFit<X: UnitConversion, Y: UnitConversion> {
+    /// Base units of `Y` per base unit of `X`.
+    slope: f64,
+    /// The fitted value at a base input of zero, in base units of `Y`.
+    offset: f64,
+    r_squared: f64,
+    points: usize,
+    _units: PhantomData<(X, Y)>,
+}
+
+impl<X: UnitConversion, Y: UnitConversion> Clone for LinearFit<X, Y> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<X: UnitConversion, Y: UnitConversion> Copy for LinearFit<X, Y> {}
+
+impl<X: UnitConversion, Y: UnitConversion> std::fmt::Debug for LinearFit<X, Y> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinearFit")
+            .field("slope", &self.slope)
+            .field("offset", &self.offset)
+            .field("r_squared", &self.r_squared)
+            .field("points", &self.points)
+            .finish()
+    }
+}
+
+impl<X: UnitConversion, Y: UnitConversion> LinearFit<X, Y> {
+    /// Fits a line through `(input, output)` points.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnitError::OutOfRange`] if there are fewer than two distinct inputs, with
+    ///   the number of distinct inputs as the value
+    /// - [`UnitError::NonFinite`] if any value is infinite or NaN
+    pub fn new<A, B>(points: &[(Quantity<A>, Quantity<B>)]) -> Result<Self, UnitError>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        let mut xs = Vec::with_capacity(points.len());
+        let mut ys = Vec::with_capacity(points.len());
+        for (x, y) in points {
+            xs.push(check_finite(x.in_base())?);
+            ys.push(check_finite(y.in_base())?);
+        }
+        Self::from_base(&xs, &ys)
+    }
+
+    /// Fits a line through points given as two slices of equal length, the inputs
+    /// and the outputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if the slices differ in length, with the
+    /// number of outputs as the value, and otherwise as for [`new`](Self::new).
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::regression::LinearFit;
+    /// use uom::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+    ///
+    /// // A thermometer checked against a reference bath
+    /// let reference = [0.0, 50.0, 100.0].map(Temperature::from_unit::<Celsius>);
+    /// let reading = [0.4, 50.9, 101.4].map(Temperature::from_unit::<Celsius>);
+    /// let fit = LinearFit::<Celsius, Celsius>::from_slices(&reference, &reading).unwrap();
+    /// assert!((fit.slope_in::<Celsius, Kelvin>() - 1.01).abs() < 1e-9);
+    /// assert!((fit.intercept().to_unit::<Celsius>() - 0.4).abs() < 1e-9);
+    /// assert!((fit.r_squared() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_slices<A, B>(xs: &[Quantity<A>], ys: &[Quantity<B>]) -> Result<Self, UnitError>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        if xs.len() != ys.len() {
+            return Err(UnitError::OutOfRange {
+                value: ys.len() as f64,
+                min: xs.len() as f64,
+                max: xs.len() as f64,
+            });
+        }
+        let points: Vec<_> = xs.iter().copied().zip(ys.iter().copied()).collect();
+        Self::new(&points)
+    }
+
+    fn from_base(xs: &[f64], ys: &[f64]) -> Result<Self, UnitError> {
+        let n = xs.len() as f64;
+        let mean_x = xs.iter().sum::<f64>() / n;
+        let mean_y = ys.iter().sum::<f64>() / n;
+        let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+        for (&x, &y) in xs.iter().zip(ys) {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            sxx += dx * dx;
+            sxy += dx * dy;
+            syy += dy * dy;
+        }
+        // Also catches an empty or single-point input
+        if sxx == 0.0 {
+            return Err(UnitError::OutOfRange {
+                value: xs.len().min(1) as f64,
+                min: 2.0,
+                max: f64::INFINITY,
+            });
+        }
+        let slope = sxy / sxx;
+        // A horizontal line through constant outputs explains them perfectly
+        let r_squared = if syy > 0.0 {
+            sxy * sxy / (sxx * syy)
+        } else {
+            1.0
+        };
+        Ok(Self {
+            slope,
+            offset: mean_y - slope * mean_x,
+            r_squared,
+            points: xs.len(),
+            _units: PhantomData,
+        })
+    }
+
+    /// Returns the slope as a number of units `B` per unit `A`, such as °C per hour.
+    ///
+    /// Both are taken as differences, so a slope of 1 °C/h is also 1.8 °F/h.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Centimeter, Kilometer, Length, Meter};
+    /// use uom::unit_conversion::regression::LinearFit;
+    ///
+    /// // A road profile climbing 1 m in every 50 m
+    /// let profile = [(0.0, 10.0), (100.0, 12.0), (200.0, 14.0)]
+    ///     .map(|(x, z)| (Length::from_unit::<Meter>(x), Length::from_unit::<Meter>(z)));
+    /// let grade = LinearFit::<Meter, Meter>::new(&profile).unwrap();
+    /// assert!((grade.slope_in::<Meter, Meter>() - 0.02).abs() < 1e-12);
+    /// assert!((grade.slope_in::<Centimeter, Kilometer>() - 2000.0).abs() < 1e-9);
+    /// ```
+    pub fn slope_in<B, A>(&self) -> f64
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        self.slope * unit_span::<A>() / unit_span::<B>()
+    }
+
+    /// Returns the fitted output at an input of zero in `X`, such as at 0 h or 0 °C.
+    pub fn intercept(&self) -> Quantity<Y> {
+        self.predict(Quantity::<X>::from_unit::<X>(0.0))
+    }
+
+    /// Returns the fitted output at `x`, extrapolating beyond the fitted inputs.
+    pub fn predict<A>(&self, x: Quantity<A>) -> Quantity<Y>
+    where
+        A: UnitConversion<Dimension = X::Dimension>,
+    {
+        Quantity::from_base(self.offset + self.slope * x.in_base())
+    }
+
+    /// Returns the input at which the fitted line reaches `y`, such as when a trend
+    /// will cross an alarm threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if the line is horizontal and so never
+    /// reaches `y`, or reaches it everywhere.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(
+        not(all(feature = "length", feature = "temperature")),
+        doc = "```ignore"
+    )]
+    #[cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter};
+    /// use uom::unit_conversion::regression::LinearFit;
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let points = [(0.0, 12.0), (100.0, 15.0)]
+    ///     .map(|(m, c)| (Length::from_unit::<Meter>(m), Temperature::from_unit::<Celsius>(c)));
+    /// let fit = LinearFit::<Meter, Celsius>::new(&points).unwrap();
+    /// let depth = fit.inverse(Temperature::from_unit::<Celsius>(30.0)).unwrap();
+    /// assert!((depth.to_unit::<Meter>() - 600.0).abs() < 1e-9);
+    /// ```
+    pub fn inverse<B>(&self, y: Quantity<B>) -> Result<Quantity<X>, UnitError>
+    where
+        B: UnitConversion<Dimension = Y::Dimension>,
+    {
+        if self.slope == 0.0 {
+            return Err(UnitError::OutOfRange {
+                value: y.to_unit::<Y>(),
+                min: Y::convert_from(self.offset),
+                max: Y::convert_from(self.offset),
+            });
+        }
+        Ok(Quantity::from_base(
+            (y.in_base() - self.offset) / self.slope,
+        ))
+    }
+
+    /// Returns the coefficient of determination, from 0 for no linear relationship to
+    /// 1 for points exactly on the line.
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Returns the number of points the line was fitted to.
+    pub fn points(&self) -> usize {
+        self.points
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature", feature = "time"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+    use crate::unit_conversion::time::{Hour, Minute, Second, Time};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn cooling(points: &[(f64, f64)]) -> Vec<(Time, Temperature)> {
+        points
+            .iter()
+            .map(|&(min, c)| {
+                (
+                    Time::from_unit::<Minute>(min),
+                    Temperature::from_unit::<Celsius>(c),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn slope_reads_in_any_units() {
+        // A tank cooling 1.5 °C every 30 minutes, sampled in minutes
+        let points = cooling(&[(0.0, 80.0), (30.0, 78.5), (60.0, 77.0), (90.0, 75.5)]);
+        let fit = LinearFit::<Second, Kelvin>::new(&points).unwrap();
+        assert!(approx(fit.slope_in::<Celsius, Hour>(), -3.0, 1e-9));
+        assert!(approx(fit.slope_in::<Fahrenheit, Hour>(), -5.4, 1e-9));
+        assert!(approx(
+            fit.slope_in::<Kelvin, Second>(),
+            -3.0 / 3600.0,
+            1e-15
+        ));
+        assert!(approx(fit.intercept().to_unit::<Celsius>(), 80.0, 1e-9));
+        assert!(approx(fit.r_squared(), 1.0, 1e-12));
+        assert_eq!(fit.points(), 4);
+
+        let later = fit.predict(Time::from_unit::<Hour>(10.0));
+        assert!(approx(later.to_unit::<Celsius>(), 50.0, 1e-9));
+        let threshold = fit
+            .inverse(Temperature::from_unit::<Celsius>(71.0))
+            .unwrap();
+        assert!(approx(threshold.to_unit::<Hour>(), 3.0, 1e-9));
+    }
+
+    #[test]
+    fn fits_noisy_data_and_offset_units() {
+        let points = [(1.0, 2.1), (2.0, 3.9), (3.0, 6.2), (4.0, 7.8), (5.0, 10.1)];
+        let pairs: Vec<(Length, Length)> = points
+            .iter()
+            .map(|&(x, y)| (Length::from_unit::<Meter>(x), Length::from_unit::<Foot>(y)))
+            .collect();
+        let fit = LinearFit::<Meter, Foot>::new(&pairs).unwrap();
+        // Least squares by hand: slope 1.99, intercept 0.05, r² ≈ 0.9978
+        assert!(approx(fit.slope_in::<Foot, Meter>(), 1.99, 1e-9));
+        assert!(approx(fit.intercept().to_unit::<Foot>(), 0.05, 1e-9));
+        assert!(approx(fit.r_squared(), 0.997_305, 1e-6));
+
+        // Fitting against °F gives the same line as against K
+        let temps: Vec<(Temperature, Length)> = points
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    Temperature::from_unit::<Fahrenheit>(x),
+                    Length::from_unit::<Meter>(y),
+                )
+            })
+            .collect();
+        let in_f = LinearFit::<Fahrenheit, Meter>::new(&temps).unwrap();
+        let in_k = LinearFit::<Kelvin, Meter>::new(&temps).unwrap();
+        assert!(approx(in_f.slope_in::<Meter, Fahrenheit>(), 1.99, 1e-9));
+        assert!(approx(in_k.slope_in::<Meter, Kelvin>(), 1.99 * 1.8, 1e-9));
+        assert!(approx(in_f.intercept().to_unit::<Meter>(), 0.05, 1e-9));
+    }
+
+    #[test]
+    fn rejects_degenerate_inputs() {
+        let none: [(Time, Temperature); 0] = [];
+        assert_eq!(
+            LinearFit::<Second, Celsius>::new(&none).unwrap_err(),
+            UnitError::OutOfRange {
+                value: 0.0,
+                min: 2.0,
+                max: f64::INFINITY
+            }
+        );
+        let same_time = cooling(&[(5.0, 20.0), (5.0, 21.0)]);
+        assert!(matches!(
+            LinearFit::<Second, Celsius>::new(&same_time),
+            Err(UnitError::OutOfRange { value, .. }) if value == 1.0
+        ));
+        let nan = cooling(&[(0.0, 20.0), (1.0, f64::NAN)]);
+        assert!(matches!(
+            LinearFit::<Second, Celsius>::new(&nan),
+            Err(UnitError::NonFinite { .. })
+        ));
+
+        let times = [0.0, 1.0].map(Time::from_unit::<Minute>);
+        let temps = [20.0].map(Temperature::from_unit::<Celsius>);
+        assert!(LinearFit::<Second, Celsius>::from_slices(&times, &temps).is_err());
+
+        let flat =
+            LinearFit::<Minute, Celsius>::new(&cooling(&[(0.0, 20.0), (1.0, 20.0)])).unwrap();
+        assert_eq!(flat.r_squared(), 1.0);
+        assert!(
+            flat.inverse(Temperature::from_unit::<Celsius>(25.0))
+                .is_err()
+        );
+    }
+}
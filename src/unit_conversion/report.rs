@@ -0,0 +1,134 @@
+//! # Dimension Reports
+//!
+//! A [`Report`] shows one quantity in every unit of its dimension as a table, with the
+//! numbers aligned on their decimal point and formatted by [`SmartF64`] so that
+//! trailing zeros are dropped. It is meant for `describe`-style command-line output and
+//! for debugging conversions.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//! use uom::unit_conversion::report::Report;
+//!
+//! let report = Report::new(Length::from_unit::<Kilometer>(1.5)).precision(2);
+//! print!("{report}");
+//! // length
+//! //      1500    m
+//! //         1.5  km
+//! //    150000    cm
+//! //   1500000    mm
+//! //      4921.26 ft
+//! //     59055.12 in
+//! //         0.93 mi
+//! assert!(report.to_string().contains("\n     4921.26 ft\n"));
+//! ```
+
+use std::fmt;
+
+use crate::smart;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+#[cfg(doc)]
+use crate::util::smart::SmartF64;
+
+/// The number of decimal places shown unless [`Report::precision`] says otherwise.
+const DEFAULT_PRECISION: usize = 4;
+
+/// A quantity rendered in every unit of its dimension.
+#[derive(Clone, Debug)]
+pub struct Report {
+    dimension: &'static str,
+    rows: Vec<(&'static str, f64)>,
+    precision: usize,
+}
+
+impl Report {
+    /// Builds the report for `quantity`, listing units in the dimension's table order
+    /// (base unit first).
+    pub fn new<U>(quantity: Quantity<U>) -> Self
+    where
+        U: UnitConversion,
+        U::Dimension: Dimension,
+    {
+        let base = quantity.in_base();
+        Self {
+            dimension: <U::Dimension as Dimension>::NAME,
+            rows: <U::Dimension as Dimension>::UNITS
+                .iter()
+                .map(|unit| (unit.symbol, (unit.from_base)(base)))
+                .collect(),
+            precision: DEFAULT_PRECISION,
+        }
+    }
+
+    /// Sets the maximum number of decimal places shown.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the name of the quantity's dimension.
+    pub fn dimension(&self) -> &'static str {
+        self.dimension
+    }
+
+    /// Returns each unit symbol with the quantity's value in that unit, unrounded.
+    pub fn rows(&self) -> &[(&'static str, f64)] {
+        &self.rows
+    }
+}
+
+/// Writes the dimension name, then one indented line per unit with the values aligned
+/// on their decimal point.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<(String, &str)> = self
+            .rows
+            .iter()
+            .map(|&(symbol, value)| (smart!(value, self.precision).to_string(), symbol))
+            .collect();
+        let split = |number: &str| number.find('.').unwrap_or(number.len());
+        let whole_width = cells.iter().map(|(n, _)| split(n)).max().unwrap_or(0);
+        let number_width = cells
+            .iter()
+            .map(|(n, _)| whole_width + n.len() - split(n))
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f, "{}", self.dimension)?;
+        for (number, symbol) in &cells {
+            let padded = format!(
+                "{:>whole_width$}{}",
+                &number[..split(number)],
+                &number[split(number)..]
+            );
+            writeln!(f, "  {padded:<number_width$} {symbol}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::temperature::{Celsius, Temperature};
+
+    #[test]
+    fn lists_every_unit() {
+        let report = Report::new(Temperature::from_unit::<Celsius>(20.0));
+        assert_eq!(report.dimension(), "temperature");
+        let symbols: Vec<&str> = report.rows().iter().map(|(symbol, _)| *symbol).collect();
+        assert_eq!(symbols, ["K", "°C", "°F", "°De", "°N", "°Rø"]);
+        assert!((report.rows()[2].1 - 68.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn aligns_on_the_decimal_point() {
+        let report = Report::new(Temperature::from_unit::<Celsius>(-40.0)).precision(1);
+        assert_eq!(
+            report.to_string(),
+            "temperature\n  233.2 K\n  -40   °C\n  -40   °F\n  210   °De\n  -13.2 °N\n  -13.5 °Rø\n"
+        );
+    }
+}
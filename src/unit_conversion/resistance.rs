@@ -0,0 +1,239 @@
+//! # Electrical Resistance Unit Conversions
+//!
+//! This module provides type-safe resistance conversions between the ohm and its metric
+//! multiples. All resistances are internally stored in ohms (the base unit) and converted
+//! on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Ohm (Ω)**: The base unit, SI unit of electrical resistance
+//! - **Milliohm (mΩ)**: 10⁻³ ohms
+//! - **Kiloohm (kΩ)**: 1000 ohms
+//! - **Megaohm (MΩ)**: 10⁶ ohms
+//!
+//! Symbols use the Greek capital omega; the ohm sign (U+2126) and `ohm`, as in `kohm`,
+//! are accepted in its place, and `mohm` and `Mohm` are told apart by case.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::resistance::{Kiloohm, Ohm, Resistance};
+//!
+//! let pull_down = Resistance::from_unit::<Kiloohm>(4.7);
+//! assert_eq!(pull_down.to_unit::<Ohm>(), 4700.0);
+//! ```
+
+use crate::unit_conversion::{Dimension, NonNegative, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for resistance conversions
+const OHMS_PER_MILLIOHM: f64 = 1e-3;
+const OHMS_PER_KILOOHM: f64 = 1e3;
+const OHMS_PER_MEGAOHM: f64 = 1e6;
+
+/// Marker type for the electrical resistance dimension.
+///
+/// This prevents accidental conversions between a resistance and the voltage or
+/// current of the same circuit.
+pub enum ResistanceDimension {}
+
+impl Dimension for ResistanceDimension {
+    const NAME: &'static str = "resistance";
+    const ID: u8 = 21;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Ohm>(&["ohm", "ohms", "\u{2126}"]),
+        UnitInfo::of::<Milliohm>(&["mohm", "m\u{2126}", "milliohm", "milliohms"]),
+        UnitInfo::of::<Kiloohm>(&[
+            "kohm",
+            "k\u{2126}",
+            "kiloohm",
+            "kiloohms",
+            "kilohm",
+            "kilohms",
+        ]),
+        UnitInfo::of::<Megaohm>(&[
+            "Mohm",
+            "M\u{2126}",
+            "megaohm",
+            "megaohms",
+            "megohm",
+            "megohms",
+        ]),
+    ];
+}
+
+impl NonNegative for ResistanceDimension {}
+
+/// An electrical resistance that stores values in ohms internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::resistance::{Kiloohm, Megaohm, Resistance};
+///
+/// let probe = Resistance::from_unit::<Megaohm>(10.0);
+/// let kiloohms = probe.to_unit::<Kiloohm>(); // 10 000.0
+/// ```
+pub type Resistance = Quantity<Ohm>;
+
+/// Ohm resistance unit (SI derived unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::resistance::{Ohm, Resistance};
+///
+/// let pull_up = Resistance::from_unit::<Ohm>(4700.0);
+/// assert_eq!(pull_up.to_unit::<Ohm>(), 4700.0);
+/// ```
+pub struct Ohm;
+
+impl UnitConversion for Ohm {
+    type Dimension = ResistanceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Ohm is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Ohm is the base unit
+    }
+
+    const SYMBOL: &'static str = "Ω";
+    const NAME: &'static str = "ohm";
+    const PLURAL: &'static str = "ohms";
+    const PHYSICAL_RANGE: (f64, f64) = (0.0, f64::INFINITY);
+}
+
+/// Milliohm resistance unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::resistance::{Milliohm, Ohm, Resistance};
+///
+/// let shunt = Resistance::from_unit::<Milliohm>(10.0);
+/// assert!((shunt.to_unit::<Ohm>() - 0.01).abs() < 1e-15);
+/// ```
+pub struct Milliohm;
+
+impl UnitConversion for Milliohm {
+    type Dimension = ResistanceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * OHMS_PER_MILLIOHM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / OHMS_PER_MILLIOHM
+    }
+
+    const SYMBOL: &'static str = "mΩ";
+    const NAME: &'static str = "milliohm";
+    const PLURAL: &'static str = "milliohms";
+}
+
+/// Kiloohm resistance unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::resistance::{Kiloohm, Ohm, Resistance};
+///
+/// let divider = Resistance::from_unit::<Kiloohm>(10.0);
+/// assert_eq!(divider.to_unit::<Ohm>(), 10_000.0);
+/// ```
+pub struct Kiloohm;
+
+impl UnitConversion for Kiloohm {
+    type Dimension = ResistanceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * OHMS_PER_KILOOHM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / OHMS_PER_KILOOHM
+    }
+
+    const SYMBOL: &'static str = "kΩ";
+    const NAME: &'static str = "kiloohm";
+    const PLURAL: &'static str = "kiloohms";
+}
+
+/// Megaohm resistance unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::resistance::{Kiloohm, Megaohm, Resistance};
+///
+/// let insulation = Resistance::from_unit::<Megaohm>(2.0);
+/// assert_eq!(insulation.to_unit::<Kiloohm>(), 2000.0);
+/// ```
+pub struct Megaohm;
+
+impl UnitConversion for Megaohm {
+    type Dimension = ResistanceDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * OHMS_PER_MEGAOHM
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / OHMS_PER_MEGAOHM
+    }
+
+    const SYMBOL: &'static str = "MΩ";
+    const NAME: &'static str = "megaohm";
+    const PLURAL: &'static str = "megaohms";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_prefixes() {
+        let resistance = Resistance::from_unit::<Kiloohm>(2.2);
+        assert!(approx(resistance.to_unit::<Ohm>(), 2200.0, 1e-9));
+        assert!(approx(resistance.to_unit::<Megaohm>(), 0.0022, 1e-15));
+        assert!(approx(resistance.to_unit::<Milliohm>(), 2.2e6, 1e-6));
+    }
+
+    #[test]
+    fn ascii_spellings_keep_case() {
+        for (alias, symbol) in [
+            ("ohms", "Ω"),
+            ("mohm", "mΩ"),
+            ("Mohm", "MΩ"),
+            ("kohm", "kΩ"),
+            ("megohm", "MΩ"),
+            ("k\u{2126}", "kΩ"),
+        ] {
+            assert_eq!(
+                ResistanceDimension::find_unit(alias).unwrap().symbol,
+                symbol
+            );
+        }
+    }
+
+    #[test]
+    fn resistance_symbols() {
+        assert_eq!(Ohm::SYMBOL, "Ω");
+        assert_eq!(Milliohm::SYMBOL, "mΩ");
+        assert_eq!(Kiloohm::SYMBOL, "kΩ");
+        assert_eq!(Megaohm::SYMBOL, "MΩ");
+    }
+}
@@ -0,0 +1,350 @@
+//! # Rolling Window Statistics
+//!
+//! Monitoring agents summarize live telemetry over the last few samples: the mean
+//! supply temperature over the last minute, the peak vibration amplitude over the last
+//! hundred readings. [`RollingStats`] keeps that window for a stream of typed
+//! quantities and answers mean, minimum, maximum and standard deviation queries in
+//! constant time, with each new sample evicting the oldest once the window is full.
+//!
+//! Samples are kept in the base unit, so a window fed in mixed units agrees with one
+//! fed in a single unit. The mean is updated incrementally and recomputed from the
+//! window once per window length of evictions, which bounds the rounding drift of long
+//! streams while keeping updates amortized O(1). The minimum and maximum use monotonic
+//! queues, also amortized O(1).
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::rolling::RollingStats;
+//! use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+//!
+//! let mut supply = RollingStats::<Celsius>::new(3);
+//! for reading in [20.0, 22.0, 24.0, 30.0] {
+//!     supply.push(Temperature::from_unit::<Celsius>(reading)).unwrap();
+//! }
+//! // The window now holds 22, 24 and 30 °C
+//! assert!((supply.mean().unwrap().to_unit::<Celsius>() - 25.333).abs() < 1e-3);
+//! assert_eq!(supply.min().unwrap().to_unit::<Celsius>(), 22.0);
+//! assert!((supply.max().unwrap().to_unit::<Fahrenheit>() - 86.0).abs() < 1e-9);
+//! assert!((supply.std_dev_in::<Celsius>().unwrap() - 3.399).abs() < 1e-3);
+//! ```
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, check_finite, unit_span};
+
+/// Windowed statistics over the last `window` quantities pushed.
+pub struct RollingStats<U: UnitConversion> {
+    window: usize,
+    samples: VecDeque<f64>,
+    /// Sequence number of the next sample, which keys the extremum queues.
+    next: u64,
+    /// Candidates for the minimum, increasing in value.
+    minima: VecDeque<(u64, f64)>,
+    /// Candidates for the maximum, decreasing in value.
+    maxima: VecDeque<(u64, f64)>,
+    mean: f64,
+    /// Sum of squared deviations from the mean.
+    m2: f64,
+    /// Evictions since the mean and `m2` were last recomputed from the window.
+    evictions: usize,
+    _u: PhantomData<U>,
+}
+
+impl<U: UnitConversion> Clone for RollingStats<U> {
+    fn clone(&self) -> Self {
+        Self {
+            window: self.window,
+            samples: self.samples.clone(),
+            next: self.next,
+            minima: self.minima.clone(),
+            maxima: self.maxima.clone(),
+            mean: self.mean,
+            m2: self.m2,
+            evictions: self.evictions,
+            _u: PhantomData,
+        }
+    }
+}
+
+impl<U: UnitConversion> std::fmt::Debug for RollingStats<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RollingStats")
+            .field("window", &self.window)
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> RollingStats<U> {
+    /// Creates empty statistics over the last `window` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "rolling window must hold at least one sample");
+        Self {
+            window,
+            samples: VecDeque::with_capacity(window),
+            next: 0,
+            minima: VecDeque::new(),
+            maxima: VecDeque::new(),
+            mean: 0.0,
+            m2: 0.0,
+            evictions: 0,
+            _u: PhantomData,
+        }
+    }
+
+    /// Adds a sample, evicting the oldest one if the window is full, and returns the
+    /// evicted sample.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] for a NaN or infinite sample, which is not
+    /// added.
+    pub fn push<V>(&mut self, quantity: Quantity<V>) -> Result<Option<Quantity<U>>, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let value = check_finite(quantity.in_base())?;
+        let evicted = if self.samples.len() == self.window {
+            self.samples.pop_front()
+        } else {
+            None
+        };
+        self.samples.push_back(value);
+        self.update_extrema(value);
+        match evicted {
+            Some(old) => {
+                self.evictions += 1;
+                if self.evictions >= self.window {
+                    self.recompute();
+                } else {
+                    let mean = self.mean + (value - old) / self.window as f64;
+                    self.m2 += (value - old) * (value - mean + old - self.mean);
+                    self.mean = mean;
+                    // Cancellation can leave a tiny negative sum for a constant window
+                    self.m2 = self.m2.max(0.0);
+                }
+            }
+            None => {
+                let delta = value - self.mean;
+                self.mean += delta / self.samples.len() as f64;
+                self.m2 += delta * (value - self.mean);
+            }
+        }
+        Ok(evicted.map(Quantity::from_base))
+    }
+
+    /// Returns the number of samples in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true if no sample has been pushed since creation or the last
+    /// [`clear`](Self::clear).
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns true once the window holds `window` samples.
+    pub fn is_full(&self) -> bool {
+        self.samples.len() == self.window
+    }
+
+    /// Returns the number of samples the window holds when full.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Removes every sample.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.window);
+    }
+
+    /// Returns the mean of the samples in the window.
+    pub fn mean(&self) -> Option<Quantity<U>> {
+        (!self.is_empty()).then(|| Quantity::from_base(self.mean))
+    }
+
+    /// Returns the smallest sample in the window.
+    pub fn min(&self) -> Option<Quantity<U>> {
+        self.minima
+            .front()
+            .map(|&(_, value)| Quantity::from_base(value))
+    }
+
+    /// Returns the largest sample in the window.
+    pub fn max(&self) -> Option<Quantity<U>> {
+        self.maxima
+            .front()
+            .map(|&(_, value)| Quantity::from_base(value))
+    }
+
+    /// Returns the population standard deviation of the samples in the window, in
+    /// unit `V`.
+    ///
+    /// The deviation is a difference, so it is returned as a plain number: a spread of
+    /// one kelvin is 1 in degrees Celsius and 1.8 in degrees Fahrenheit.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::rolling::RollingStats;
+    /// use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+    ///
+    /// let mut stats = RollingStats::<Kelvin>::new(10);
+    /// for celsius in [19.0, 21.0] {
+    ///     stats.push(Temperature::from_unit::<Celsius>(celsius)).unwrap();
+    /// }
+    /// assert!((stats.std_dev_in::<Celsius>().unwrap() - 1.0).abs() < 1e-9);
+    /// assert!((stats.std_dev_in::<Fahrenheit>().unwrap() - 1.8).abs() < 1e-9);
+    /// ```
+    pub fn std_dev_in<V>(&self) -> Option<f64>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        (!self.is_empty()).then(|| {
+            let std_dev = (self.m2 / self.samples.len() as f64).sqrt();
+            std_dev / unit_span::<V>()
+        })
+    }
+
+    fn update_extrema(&mut self, value: f64) {
+        let index = self.next;
+        self.next += 1;
+        let oldest = index.saturating_sub(self.window as u64 - 1);
+        while self.minima.back().is_some_and(|&(_, min)| min >= value) {
+            self.minima.pop_back();
+        }
+        self.minima.push_back((index, value));
+        while self.minima.front().is_some_and(|&(i, _)| i < oldest) {
+            self.minima.pop_front();
+        }
+        while self.maxima.back().is_some_and(|&(_, max)| max <= value) {
+            self.maxima.pop_back();
+        }
+        self.maxima.push_back((index, value));
+        while self.maxima.front().is_some_and(|&(i, _)| i < oldest) {
+            self.maxima.pop_front();
+        }
+    }
+
+    fn recompute(&mut self) {
+        let n = self.samples.len() as f64;
+        self.mean = self.samples.iter().sum::<f64>() / n;
+        self.m2 = self
+            .samples
+            .iter()
+            .map(|value| (value - self.mean).powi(2))
+            .sum();
+        self.evictions = 0;
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    /// Statistics of `values` computed directly, for comparison.
+    fn reference(values: &[f64]) -> (f64, f64, f64, f64) {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (mean, min, max, variance.sqrt())
+    }
+
+    #[test]
+    fn matches_direct_computation_over_a_long_stream() {
+        let window = 7;
+        let mut stats = RollingStats::<Meter>::new(window);
+        let mut values = Vec::new();
+        for i in 0..1000 {
+            // A drifting sawtooth with a large offset, to exercise rounding drift
+            let value = 1e6 + (i % 13) as f64 * 0.37 - (i % 5) as f64 + i as f64 * 1e-3;
+            values.push(value);
+            stats.push(Length::from_unit::<Meter>(value)).unwrap();
+
+            let start = values.len().saturating_sub(window);
+            let (mean, min, max, std_dev) = reference(&values[start..]);
+            assert!(approx(stats.mean().unwrap().to_unit::<Meter>(), mean, 1e-6));
+            assert_eq!(stats.min().unwrap().to_unit::<Meter>(), min);
+            assert_eq!(stats.max().unwrap().to_unit::<Meter>(), max);
+            assert!(approx(stats.std_dev_in::<Meter>().unwrap(), std_dev, 1e-6));
+        }
+        assert!(stats.is_full());
+        assert_eq!(stats.len(), window);
+    }
+
+    #[test]
+    fn evicts_oldest_samples_in_any_unit() {
+        let mut stats = RollingStats::<Meter>::new(2);
+        assert!(stats.mean().is_none() && stats.min().is_none());
+        assert!(stats.std_dev_in::<Meter>().is_none());
+
+        assert!(
+            stats
+                .push(Length::from_unit::<Foot>(10.0))
+                .unwrap()
+                .is_none()
+        );
+        stats.push(Length::from_unit::<Meter>(1.0)).unwrap();
+        let evicted = stats
+            .push(Length::from_unit::<Meter>(2.0))
+            .unwrap()
+            .unwrap();
+        assert!(approx(evicted.to_unit::<Foot>(), 10.0, 1e-12));
+        assert_eq!(stats.max().unwrap().to_unit::<Meter>(), 2.0);
+        assert!(approx(stats.std_dev_in::<Meter>().unwrap(), 0.5, 1e-12));
+
+        stats.clear();
+        assert!(stats.is_empty());
+        assert_eq!(stats.window(), 2);
+    }
+
+    #[test]
+    fn constant_streams_have_no_spread() {
+        let mut stats = RollingStats::<Kelvin>::new(3);
+        for _ in 0..10 {
+            stats.push(Temperature::from_unit::<Celsius>(36.6)).unwrap();
+            assert_eq!(stats.std_dev_in::<Celsius>().unwrap(), 0.0);
+        }
+        assert!(approx(
+            stats.mean().unwrap().to_unit::<Celsius>(),
+            36.6,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn rejects_non_finite_samples() {
+        let mut stats = RollingStats::<Meter>::new(3);
+        stats.push(Length::from_unit::<Meter>(1.0)).unwrap();
+        assert_eq!(
+            stats.push(Length::from_unit::<Meter>(f64::INFINITY)),
+            Err(UnitError::NonFinite {
+                value: f64::INFINITY
+            })
+        );
+        assert_eq!(stats.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one sample")]
+    fn rejects_empty_windows() {
+        let _ = RollingStats::<Meter>::new(0);
+    }
+}
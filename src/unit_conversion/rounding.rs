@@ -0,0 +1,237 @@
+//! # Integer Output
+//!
+//! Controllers, fieldbus registers and wire protocols often carry integers: a PLC
+//! setpoint in tenths of a degree, a packet field in whole milliseconds. This module
+//! converts quantities to integers with an explicit [`RoundingMode`], either failing
+//! when the value does not fit ([`Quantity::to_unit_rounded`]) or clamping it to the
+//! target type's range ([`Quantity::to_unit_saturating`]).
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Length, Millimeter};
+//! use uom::unit_conversion::rounding::RoundingMode;
+//!
+//! let gap = Length::from_unit::<Millimeter>(12.5);
+//! assert_eq!(gap.to_unit_rounded::<Millimeter>(RoundingMode::HalfEven), Ok(12));
+//! assert_eq!(gap.to_unit_rounded::<Millimeter>(RoundingMode::HalfAwayFromZero), Ok(13));
+//!
+//! let huge = Length::from_unit::<Millimeter>(1e6);
+//! assert_eq!(huge.to_unit_saturating::<Millimeter, i16>(RoundingMode::Floor), Ok(i16::MAX));
+//! ```
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError};
+
+/// How a fractional value is rounded to an integer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with halves rounded away from zero (2.5 → 3,
+    /// -2.5 → -3), like [`f64::round`].
+    HalfAwayFromZero,
+    /// Round to the nearest integer, with halves rounded to the even neighbor (2.5 → 2,
+    /// 3.5 → 4). This avoids a systematic bias when many values are rounded.
+    HalfEven,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, discarding the fractional part.
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// Rounds `value` to an integral `f64` using this mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::rounding::RoundingMode;
+    ///
+    /// assert_eq!(RoundingMode::HalfEven.round(-2.5), -2.0);
+    /// assert_eq!(RoundingMode::TowardZero.round(-2.7), -2.0);
+    /// ```
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            RoundingMode::HalfAwayFromZero => value.round(),
+            RoundingMode::HalfEven => value.round_ties_even(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::TowardZero => value.trunc(),
+        }
+    }
+}
+
+/// A primitive integer type that rounded quantities can be clamped into.
+pub trait SaturatingInt: Copy {
+    /// The smallest value of the type, as an `f64`.
+    const MIN_F64: f64;
+    /// The largest value of the type, as an `f64`.
+    const MAX_F64: f64;
+
+    /// Converts an integral, non-NaN `f64`, clamping it to the type's range.
+    fn saturating_from_f64(value: f64) -> Self;
+}
+
+macro_rules! saturating_int_impl {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingInt for $t {
+                const MIN_F64: f64 = <$t>::MIN as f64;
+                const MAX_F64: f64 = <$t>::MAX as f64;
+
+                #[inline]
+                fn saturating_from_f64(value: f64) -> Self {
+                    // Float-to-integer `as` casts saturate at the type's bounds
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+saturating_int_impl!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<U: UnitConversion> Quantity<U> {
+    /// Converts to unit `V` and rounds to an `i64` using `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if the converted value is NaN or infinite, and
+    /// [`UnitError::OutOfRange`] if the rounded value does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    /// use uom::unit_conversion::rounding::RoundingMode;
+    ///
+    /// let t = Temperature::from_unit::<Celsius>(-3.7);
+    /// assert_eq!(t.to_unit_rounded::<Celsius>(RoundingMode::Floor), Ok(-4));
+    /// assert_eq!(t.to_unit_rounded::<Celsius>(RoundingMode::TowardZero), Ok(-3));
+    /// ```
+    pub fn to_unit_rounded<V>(self, mode: RoundingMode) -> Result<i64, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let rounded = mode.round(self.try_to_unit::<V>()?);
+        // `i64::MAX as f64` rounds up to 2^63, which does not fit, so the range is
+        // half-open
+        if !(i64::MIN_F64..i64::MAX_F64).contains(&rounded) {
+            return Err(UnitError::OutOfRange {
+                value: rounded,
+                min: i64::MIN_F64,
+                max: i64::MAX_F64,
+            });
+        }
+        Ok(rounded as i64)
+    }
+
+    /// Converts to unit `V`, rounds using `mode`, and clamps the result to the range of
+    /// the integer type `T`.
+    ///
+    /// Infinite values clamp to the nearest bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NonFinite`] if the converted value is NaN, since it has no
+    /// meaningful integer value.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    /// use uom::unit_conversion::rounding::RoundingMode;
+    ///
+    /// // An unsigned byte register cannot hold temperatures below freezing
+    /// let t = Temperature::from_unit::<Celsius>(-5.0);
+    /// let register = t.to_unit_saturating::<Celsius, u8>(RoundingMode::HalfEven);
+    /// assert_eq!(register, Ok(0));
+    /// ```
+    pub fn to_unit_saturating<V, T>(self, mode: RoundingMode) -> Result<T, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+        T: SaturatingInt,
+    {
+        let value = self.to_unit::<V>();
+        if value.is_nan() {
+            return Err(UnitError::NonFinite { value });
+        }
+        Ok(T::saturating_from_f64(mode.round(value)))
+    }
+}
+
+#[cfg(all(test, feature = "length"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Length, Meter, Millimeter};
+
+    fn meters(value: f64) -> Length {
+        Length::from_unit::<Meter>(value)
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let cases = [
+            (RoundingMode::HalfAwayFromZero, [3, -3, 3, -2]),
+            (RoundingMode::HalfEven, [2, -2, 3, -2]),
+            (RoundingMode::Floor, [2, -3, 2, -3]),
+            (RoundingMode::Ceil, [3, -2, 3, -2]),
+            (RoundingMode::TowardZero, [2, -2, 2, -2]),
+        ];
+        for (mode, expected) in cases {
+            let got = [2.5, -2.5, 2.6, -2.4].map(|v| meters(v).to_unit_rounded::<Meter>(mode));
+            assert_eq!(got, expected.map(Ok), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn rounds_in_the_requested_unit() {
+        let length = meters(0.0125);
+        assert_eq!(
+            length.to_unit_rounded::<Millimeter>(RoundingMode::HalfEven),
+            Ok(12)
+        );
+        assert_eq!(length.to_unit_rounded::<Meter>(RoundingMode::Ceil), Ok(1));
+    }
+
+    #[test]
+    fn rounded_rejects_unrepresentable_values() {
+        let mode = RoundingMode::HalfEven;
+        assert!(matches!(
+            meters(f64::NAN).to_unit_rounded::<Meter>(mode),
+            Err(UnitError::NonFinite { .. })
+        ));
+        assert!(matches!(
+            meters(1e19).to_unit_rounded::<Meter>(mode),
+            Err(UnitError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            meters(i64::MIN as f64).to_unit_rounded::<Meter>(mode),
+            Ok(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn saturating_clamps_to_the_target_type() {
+        let mode = RoundingMode::HalfAwayFromZero;
+        assert_eq!(meters(300.0).to_unit_saturating::<Meter, u8>(mode), Ok(255));
+        assert_eq!(meters(-1.0).to_unit_saturating::<Meter, u16>(mode), Ok(0));
+        assert_eq!(
+            meters(-40_000.4).to_unit_saturating::<Meter, i16>(mode),
+            Ok(i16::MIN)
+        );
+        assert_eq!(
+            meters(f64::INFINITY).to_unit_saturating::<Meter, i32>(mode),
+            Ok(i32::MAX)
+        );
+        assert_eq!(meters(41.5).to_unit_saturating::<Meter, i32>(mode), Ok(42));
+        assert!(
+            meters(f64::NAN)
+                .to_unit_saturating::<Meter, i64>(mode)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! # Earthquake Magnitude Helpers
+//!
+//! Moment magnitude is logarithmic: each whole step corresponds to about 31.6 times more
+//! radiated energy, and two steps to 1000 times. This module converts between moment
+//! magnitude (Mw) and the typed [`Energy`] radiated as seismic waves, using the
+//! Gutenberg–Richter energy relation `log10(E) = 1.5 Mw + 4.8` with `E` in joules.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::energy::{Energy, Joule};
+//! use uom::unit_conversion::seismology::{moment_magnitude, radiated_energy};
+//!
+//! let small = radiated_energy(5.0);
+//! let large = radiated_energy(7.0);
+//! assert!((large.to_unit::<Joule>() / small.to_unit::<Joule>() - 1000.0).abs() < 1e-6);
+//!
+//! let magnitude = moment_magnitude(Energy::from_unit::<Joule>(2.0e15)).unwrap();
+//! assert!((magnitude - 7.0).abs() < 1e-3);
+//! ```
+
+use crate::unit_conversion::energy::{Energy, Joule};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// Slope of log10 radiated energy against magnitude.
+const ENERGY_SLOPE: f64 = 1.5;
+
+/// Log10 of the energy in joules radiated by a magnitude 0 event.
+const ENERGY_OFFSET: f64 = 4.8;
+
+/// Returns the energy radiated as seismic waves by an earthquake of the given moment
+/// magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Joule, KilowattHour};
+/// use uom::unit_conversion::seismology::radiated_energy;
+///
+/// let energy = radiated_energy(6.0);
+/// assert!((energy.to_unit::<Joule>() - 6.309573e13).abs() < 1e8);
+/// assert!((energy.to_unit::<KilowattHour>() - 1.752659e7).abs() < 10.0);
+/// ```
+pub fn radiated_energy(magnitude: f64) -> Energy {
+    Energy::from_unit::<Joule>(10f64.powf(ENERGY_SLOPE * magnitude + ENERGY_OFFSET))
+}
+
+/// Returns the moment magnitude of an earthquake that radiates the given energy.
+///
+/// Returns [`UnitError::OutOfRange`] if the energy is not positive and finite, since
+/// the logarithm is undefined there.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::energy::{Energy, Joule};
+/// use uom::unit_conversion::seismology::moment_magnitude;
+///
+/// let magnitude = moment_magnitude(Energy::from_unit::<Joule>(1e12)).unwrap();
+/// assert!((magnitude - 4.8).abs() < 1e-12);
+/// assert!(moment_magnitude(Energy::from_unit::<Joule>(0.0)).is_err());
+/// ```
+pub fn moment_magnitude(energy: Energy) -> Result<f64, UnitError> {
+    let joules = check_positive(energy.to_unit::<Joule>())?;
+    Ok((joules.log10() - ENERGY_OFFSET) / ENERGY_SLOPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn one_step_is_about_32_times_the_energy() {
+        let ratio =
+            radiated_energy(6.5).to_unit::<Joule>() / radiated_energy(5.5).to_unit::<Joule>();
+        assert!(approx(ratio, 31.622777, 1e-6));
+    }
+
+    #[test]
+    fn round_trips() {
+        for magnitude in [-1.0, 0.0, 2.5, 6.0, 9.1] {
+            let energy = radiated_energy(magnitude);
+            assert!(approx(moment_magnitude(energy).unwrap(), magnitude, 1e-12));
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        assert!(approx(
+            radiated_energy(0.0).to_unit::<Joule>(),
+            63_095.734448,
+            1e-6
+        ));
+        // Magnitude 9 radiates on the order of 10^18 J
+        let energy = radiated_energy(9.0).to_unit::<Joule>();
+        assert!(approx(energy.log10(), 18.3, 1e-12));
+    }
+
+    #[test]
+    fn rejects_non_positive_energy() {
+        assert!(moment_magnitude(Energy::from_unit::<Joule>(-5.0)).is_err());
+        assert!(moment_magnitude(Energy::from_unit::<Joule>(f64::NAN)).is_err());
+        assert!(moment_magnitude(Energy::from_unit::<Joule>(f64::INFINITY)).is_err());
+    }
+}
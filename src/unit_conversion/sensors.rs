@@ -0,0 +1,571 @@
+//! # Temperature Sensors
+//!
+//! Thermocouples and platinum resistance thermometers (RTDs) do not read out a
+//! temperature: a thermocouple produces a small voltage that depends on the temperature
+//! difference between its two junctions, and an RTD changes resistance. This module
+//! converts both to a typed [`Temperature`] using the standard reference functions:
+//!
+//! - [`Thermocouple`] types K, J and T use the NIST ITS-90 polynomials, which agree
+//!   with the reference tables to within 0.06 °C over each type's range.
+//! - [`Rtd`] uses the IEC 60751 Callendar–Van Dusen equation for PT100 and PT1000
+//!   elements (α = 0.00385).
+//!
+//! Thermocouple voltages are relative to a reference (cold) junction at 0 °C. A real
+//! measurement has the cold junction at the terminal temperature instead, which
+//! [`Thermocouple::temperature_compensated`] corrects for.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::sensors::{Rtd, Thermocouple};
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//! use uom::unit_conversion::voltage::{Millivolt, Voltage};
+//!
+//! let t = Thermocouple::K.temperature(Voltage::from_unit::<Millivolt>(4.096)).unwrap();
+//! assert!((t.to_unit::<Celsius>() - 100.0).abs() < 0.05);
+//!
+//! let t = Rtd::PT100.temperature(138.5055).unwrap();
+//! assert!((t.to_unit::<Celsius>() - 100.0).abs() < 1e-3);
+//! ```
+
+use crate::unit_conversion::temperature::{Celsius, Temperature};
+use crate::unit_conversion::voltage::{Millivolt, Voltage};
+use crate::unit_conversion::{UnitError, check_finite, check_positive};
+
+/// A polynomial valid over `from..=to`, lowest order coefficient first.
+struct Segment {
+    from: f64,
+    to: f64,
+    coefficients: &'static [f64],
+}
+
+impl Segment {
+    fn eval(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &c| acc * x + c)
+    }
+}
+
+/// Finds the segment containing `x` and evaluates it.
+fn eval(segments: &[Segment], x: f64) -> Result<f64, UnitError> {
+    segments
+        .iter()
+        .find(|segment| (segment.from..=segment.to).contains(&x))
+        .map(|segment| segment.eval(x))
+        .ok_or(UnitError::OutOfRange {
+            value: x,
+            min: segments[0].from,
+            max: segments[segments.len() - 1].to,
+        })
+}
+
+// NIST ITS-90 thermocouple reference functions: temperature in °C to EMF in mV, and
+// the inverse approximations from EMF in mV to temperature in °C.
+
+const K_EMF: &[Segment] = &[
+    Segment {
+        from: -270.0,
+        to: 0.0,
+        coefficients: &[
+            0.0,
+            3.945_012_802_5e-2,
+            2.362_237_359_8e-5,
+            -3.285_890_678_4e-7,
+            -4.990_482_877_7e-9,
+            -6.750_905_917_3e-11,
+            -5.741_032_742_8e-13,
+            -3.108_887_289_4e-15,
+            -1.045_160_936_5e-17,
+            -1.988_926_687_8e-20,
+            -1.632_269_748_6e-23,
+        ],
+    },
+    Segment {
+        from: 0.0,
+        to: 1372.0,
+        coefficients: &[
+            -1.760_041_368_6e-2,
+            3.892_120_497_5e-2,
+            1.855_877_003_2e-5,
+            -9.945_759_287_4e-8,
+            3.184_094_571_9e-10,
+            -5.607_284_488_9e-13,
+            5.607_505_905_9e-16,
+            -3.202_072_000_3e-19,
+            9.715_114_715_2e-23,
+            -1.210_472_127_5e-26,
+        ],
+    },
+];
+const K_TEMPERATURE: &[Segment] = &[
+    Segment {
+        from: -5.891,
+        to: 0.0,
+        coefficients: &[
+            0.0,
+            2.517_346_2e1,
+            -1.166_287_8,
+            -1.083_363_8,
+            -8.977_354_0e-1,
+            -3.734_237_7e-1,
+            -8.663_264_3e-2,
+            -1.045_059_8e-2,
+            -5.192_057_7e-4,
+        ],
+    },
+    Segment {
+        from: 0.0,
+        to: 20.644,
+        coefficients: &[
+            0.0,
+            2.508_355e1,
+            7.860_106e-2,
+            -2.503_131e-1,
+            8.315_270e-2,
+            -1.228_034e-2,
+            9.804_036e-4,
+            -4.413_030e-5,
+            1.057_734e-6,
+            -1.052_755e-8,
+        ],
+    },
+    Segment {
+        from: 20.644,
+        to: 54.886,
+        coefficients: &[
+            -1.318_058e2,
+            4.830_222e1,
+            -1.646_031,
+            5.464_731e-2,
+            -9.650_715e-4,
+            8.802_193e-6,
+            -3.110_810e-8,
+        ],
+    },
+];
+
+const J_EMF: &[Segment] = &[Segment {
+    from: -210.0,
+    to: 760.0,
+    coefficients: &[
+        0.0,
+        5.038_118_781_5e-2,
+        3.047_583_693_0e-5,
+        -8.568_106_572_0e-8,
+        1.322_819_529_5e-10,
+        -1.705_295_833_7e-13,
+        2.094_809_069_7e-16,
+        -1.253_839_533_6e-19,
+        1.563_172_569_7e-23,
+    ],
+}];
+const J_TEMPERATURE: &[Segment] = &[
+    Segment {
+        from: -8.095,
+        to: 0.0,
+        coefficients: &[
+            0.0,
+            1.952_826_8e1,
+            -1.228_618_5,
+            -1.075_217_8,
+            -5.908_693_3e-1,
+            -1.725_671_3e-1,
+            -2.813_151_3e-2,
+            -2.396_337_0e-3,
+            -8.382_332_1e-5,
+        ],
+    },
+    Segment {
+        from: 0.0,
+        to: 42.919,
+        coefficients: &[
+            0.0,
+            1.978_425e1,
+            -2.001_204e-1,
+            1.036_969e-2,
+            -2.549_687e-4,
+            3.585_153e-6,
+            -5.344_285e-8,
+            5.099_890e-10,
+        ],
+    },
+];
+
+const T_EMF: &[Segment] = &[
+    Segment {
+        from: -270.0,
+        to: 0.0,
+        coefficients: &[
+            0.0,
+            3.874_810_636_4e-2,
+            4.419_443_434_7e-5,
+            1.184_432_310_5e-7,
+            2.003_297_355_4e-8,
+            9.013_801_955_9e-10,
+            2.265_115_659_3e-11,
+            3.607_115_420_5e-13,
+            3.849_393_988_3e-15,
+            2.821_352_192_5e-17,
+            1.425_159_477_9e-19,
+            4.876_866_228_6e-22,
+            1.079_553_927_0e-24,
+            1.394_502_706_2e-27,
+            7.979_515_392_7e-31,
+        ],
+    },
+    Segment {
+        from: 0.0,
+        to: 400.0,
+        coefficients: &[
+            0.0,
+            3.874_810_636_4e-2,
+            3.329_222_788_0e-5,
+            2.061_824_340_4e-7,
+            -2.188_225_684_6e-9,
+            1.099_688_092_8e-11,
+            -3.081_575_877_2e-14,
+            4.547_913_529_0e-17,
+            -2.751_290_167_3e-20,
+        ],
+    },
+];
+const T_TEMPERATURE: &[Segment] = &[
+    Segment {
+        from: -5.603,
+        to: 0.0,
+        coefficients: &[
+            0.0,
+            2.594_919_2e1,
+            -2.131_696_7e-1,
+            7.901_869_2e-1,
+            4.252_777_7e-1,
+            1.330_447_3e-1,
+            2.024_144_6e-2,
+            1.266_817_1e-3,
+        ],
+    },
+    Segment {
+        from: 0.0,
+        to: 20.872,
+        coefficients: &[
+            0.0,
+            2.592_800e1,
+            -7.602_961e-1,
+            4.637_791e-2,
+            -2.165_394e-3,
+            6.048_144e-5,
+            -7.293_422e-7,
+        ],
+    },
+];
+
+/// A thermocouple type, identified by its letter code.
+///
+/// The supported ranges are those of the NIST inverse functions: K from −200 °C to
+/// 1372 °C, J from −210 °C to 760 °C and T from −200 °C to 400 °C.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Thermocouple {
+    /// Chromel–alumel, the general-purpose type.
+    K,
+    /// Iron–constantan.
+    J,
+    /// Copper–constantan, accurate at low temperatures.
+    T,
+}
+
+impl Thermocouple {
+    fn emf_curve(self) -> &'static [Segment] {
+        match self {
+            Self::K => K_EMF,
+            Self::J => J_EMF,
+            Self::T => T_EMF,
+        }
+    }
+
+    fn temperature_curve(self) -> &'static [Segment] {
+        match self {
+            Self::K => K_TEMPERATURE,
+            Self::J => J_TEMPERATURE,
+            Self::T => T_TEMPERATURE,
+        }
+    }
+
+    /// Returns the temperature at which the thermocouple produces `emf` with its
+    /// reference junction at 0 °C.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`], in millivolts, if `emf` is outside the type's
+    /// range.
+    pub fn temperature(self, emf: Voltage) -> Result<Temperature, UnitError> {
+        let millivolts = check_finite(emf.to_unit::<Millivolt>())?;
+        let celsius = eval(self.temperature_curve(), millivolts)?;
+        Ok(Temperature::from_unit::<Celsius>(celsius))
+    }
+
+    /// Returns the temperature at the measuring junction when the reference junction
+    /// is at `cold_junction` rather than 0 °C.
+    ///
+    /// The voltage the reference junction would produce against 0 °C is added to
+    /// `emf` before converting.
+    ///
+    /// # Errors
+    ///
+    /// As for [`emf`](Self::emf) and [`temperature`](Self::temperature).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::sensors::Thermocouple;
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    /// use uom::unit_conversion::voltage::{Millivolt, Voltage};
+    ///
+    /// // 3.096 mV measured with the terminals at 25 °C
+    /// let t = Thermocouple::K
+    ///     .temperature_compensated(
+    ///         Voltage::from_unit::<Millivolt>(3.096),
+    ///         Temperature::from_unit::<Celsius>(25.0),
+    ///     )
+    ///     .unwrap();
+    /// assert!((t.to_unit::<Celsius>() - 100.0).abs() < 0.05);
+    /// ```
+    pub fn temperature_compensated(
+        self,
+        emf: Voltage,
+        cold_junction: Temperature,
+    ) -> Result<Temperature, UnitError> {
+        self.temperature(Voltage::from_base(
+            emf.in_base() + self.emf(cold_junction)?.in_base(),
+        ))
+    }
+
+    /// Returns the voltage the thermocouple produces at `temperature` with its
+    /// reference junction at 0 °C.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`], in degrees Celsius, if `temperature` is
+    /// outside the type's range.
+    pub fn emf(self, temperature: Temperature) -> Result<Voltage, UnitError> {
+        let celsius = check_finite(temperature.to_unit::<Celsius>())?;
+        let mut millivolts = eval(self.emf_curve(), celsius)?;
+        if self == Self::K && celsius > 0.0 {
+            // Type K adds an exponential term above 0 °C
+            millivolts += 1.185_976e-1 * (-1.183_432e-4 * (celsius - 126.968_6).powi(2)).exp();
+        }
+        Ok(Voltage::from_unit::<Millivolt>(millivolts))
+    }
+}
+
+/// IEC 60751 Callendar–Van Dusen coefficients.
+const A: f64 = 3.9083e-3;
+const B: f64 = -5.775e-7;
+const C: f64 = -4.183e-12;
+const RTD_MIN_CELSIUS: f64 = -200.0;
+const RTD_MAX_CELSIUS: f64 = 850.0;
+
+/// A platinum resistance thermometer with the IEC 60751 temperature coefficient.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rtd {
+    /// The resistance at 0 °C, in ohms.
+    r0: f64,
+}
+
+impl Rtd {
+    /// A PT100 element, 100 Ω at 0 °C.
+    pub const PT100: Rtd = Rtd { r0: 100.0 };
+    /// A PT1000 element, 1000 Ω at 0 °C.
+    pub const PT1000: Rtd = Rtd { r0: 1000.0 };
+
+    /// Creates an element with resistance `r0` ohms at 0 °C.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] or [`UnitError::NonFinite`] unless `r0` is
+    /// positive and finite.
+    pub fn new(r0: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            r0: check_positive(r0)?,
+        })
+    }
+
+    /// Returns the resistance at 0 °C, in ohms.
+    pub fn r0(&self) -> f64 {
+        self.r0
+    }
+
+    /// Returns the element's resistance in ohms at `temperature`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`], in degrees Celsius, outside −200 °C to
+    /// 850 °C.
+    pub fn resistance(&self, temperature: Temperature) -> Result<f64, UnitError> {
+        let t = check_finite(temperature.to_unit::<Celsius>())?;
+        if !(RTD_MIN_CELSIUS..=RTD_MAX_CELSIUS).contains(&t) {
+            return Err(UnitError::OutOfRange {
+                value: t,
+                min: RTD_MIN_CELSIUS,
+                max: RTD_MAX_CELSIUS,
+            });
+        }
+        Ok(self.resistance_at(t))
+    }
+
+    fn resistance_at(&self, t: f64) -> f64 {
+        let low = if t < 0.0 {
+            C * (t - 100.0) * t.powi(3)
+        } else {
+            0.0
+        };
+        self.r0 * (1.0 + A * t + B * t * t + low)
+    }
+
+    /// Returns the temperature at which the element has resistance `ohms`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`], in ohms, if `ohms` lies outside the
+    /// resistance range for −200 °C to 850 °C.
+    pub fn temperature(&self, ohms: f64) -> Result<Temperature, UnitError> {
+        let ohms = check_finite(ohms)?;
+        let (min, max) = (
+            self.resistance_at(RTD_MIN_CELSIUS),
+            self.resistance_at(RTD_MAX_CELSIUS),
+        );
+        if !(min..=max).contains(&ohms) {
+            return Err(UnitError::OutOfRange {
+                value: ohms,
+                min,
+                max,
+            });
+        }
+        // Above 0 °C the equation is quadratic; below, the quadratic root starts a
+        // Newton iteration on the full quartic.
+        let ratio = ohms / self.r0;
+        let mut t = (-A + (A * A - 4.0 * B * (1.0 - ratio)).sqrt()) / (2.0 * B);
+        if ratio < 1.0 {
+            for _ in 0..20 {
+                let error = self.resistance_at(t) - ohms;
+                let slope = self.r0 * (A + 2.0 * B * t + C * (4.0 * t.powi(3) - 300.0 * t.powi(2)));
+                let step = error / slope;
+                t -= step;
+                if step.abs() < 1e-12 {
+                    break;
+                }
+            }
+        }
+        Ok(Temperature::from_unit::<Celsius>(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::from_unit::<Celsius>(value)
+    }
+
+    fn millivolts(value: f64) -> Voltage {
+        Voltage::from_unit::<Millivolt>(value)
+    }
+
+    /// Values from the NIST ITS-90 reference tables, in °C and mV.
+    const REFERENCE: &[(Thermocouple, f64, f64)] = &[
+        (Thermocouple::K, -100.0, -3.554),
+        (Thermocouple::K, 100.0, 4.096),
+        (Thermocouple::K, 500.0, 20.644),
+        (Thermocouple::K, 1000.0, 41.276),
+        (Thermocouple::J, -100.0, -4.633),
+        (Thermocouple::J, 100.0, 5.269),
+        (Thermocouple::J, 500.0, 27.393),
+        (Thermocouple::T, -100.0, -3.379),
+        (Thermocouple::T, 100.0, 4.279),
+        (Thermocouple::T, 400.0, 20.872),
+    ];
+
+    #[test]
+    fn matches_reference_tables() {
+        for &(kind, t, mv) in REFERENCE {
+            let emf = kind.emf(celsius(t)).unwrap().to_unit::<Millivolt>();
+            assert!(approx(emf, mv, 5e-4), "{kind:?} at {t} °C: {emf} mV");
+            let back = kind
+                .temperature(millivolts(mv))
+                .unwrap()
+                .to_unit::<Celsius>();
+            assert!(approx(back, t, 0.06), "{kind:?} at {mv} mV: {back} °C");
+        }
+    }
+
+    #[test]
+    fn round_trips_across_the_range() {
+        for (kind, from, to) in [
+            (Thermocouple::K, -200.0, 1372.0),
+            (Thermocouple::J, -210.0, 760.0),
+            (Thermocouple::T, -200.0, 400.0),
+        ] {
+            let steps = 100;
+            for i in 1..steps {
+                let t = from + (to - from) * i as f64 / steps as f64;
+                let emf = kind.emf(celsius(t)).unwrap();
+                let back = kind.temperature(emf).unwrap().to_unit::<Celsius>();
+                assert!(approx(back, t, 0.07), "{kind:?} at {t} °C: {back}");
+            }
+        }
+    }
+
+    #[test]
+    fn compensates_for_the_cold_junction() {
+        let hot = Thermocouple::J.emf(celsius(300.0)).unwrap();
+        let cold = Thermocouple::J.emf(celsius(22.0)).unwrap();
+        let measured = Voltage::from_base(hot.in_base() - cold.in_base());
+        let t = Thermocouple::J
+            .temperature_compensated(measured, celsius(22.0))
+            .unwrap();
+        assert!(approx(t.to_unit::<Celsius>(), 300.0, 0.05));
+    }
+
+    #[test]
+    fn rejects_values_outside_the_range() {
+        assert_eq!(
+            Thermocouple::T.temperature(millivolts(25.0)).unwrap_err(),
+            UnitError::OutOfRange {
+                value: 25.0,
+                min: -5.603,
+                max: 20.872
+            }
+        );
+        assert!(Thermocouple::J.emf(celsius(900.0)).is_err());
+        assert!(Thermocouple::K.temperature(millivolts(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn converts_rtd_resistance() {
+        for (t, ohms) in [
+            (-100.0, 60.2558),
+            (0.0, 100.0),
+            (100.0, 138.5055),
+            (800.0, 375.704),
+        ] {
+            let r = Rtd::PT100.resistance(celsius(t)).unwrap();
+            assert!(approx(r, ohms, 1e-4), "{t} °C: {r} Ω");
+            let back = Rtd::PT100.temperature(ohms).unwrap().to_unit::<Celsius>();
+            assert!(approx(back, t, 1e-3), "{ohms} Ω: {back} °C");
+        }
+        let r = Rtd::PT1000.resistance(celsius(-150.0)).unwrap();
+        let back = Rtd::PT1000.temperature(r).unwrap().to_unit::<Celsius>();
+        assert!(approx(back, -150.0, 1e-9));
+
+        assert!(Rtd::PT100.temperature(10.0).is_err());
+        assert!(Rtd::PT100.resistance(celsius(900.0)).is_err());
+        assert!(Rtd::new(0.0).is_err());
+        assert_eq!(Rtd::new(500.0).unwrap().r0(), 500.0);
+    }
+}
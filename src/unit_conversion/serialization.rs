@@ -0,0 +1,137 @@
+//! # Serde Support
+//!
+//! [`Quantity`] implements `Serialize` and `Deserialize`, choosing its representation
+//! from [`Serializer::is_human_readable`]:
+//!
+//! - Human-readable formats (JSON, YAML, TOML) get a `{ "value": .., "unit": .. }`
+//!   map. Serialization writes the value in the quantity's own unit; deserialization
+//!   accepts any unit of the dimension by symbol or alias, so config files can say
+//!   `{ value = 5, unit = "km" }` for a `Length`.
+//! - Compact formats (postcard, bincode) get the base-unit value as a bare `f64`, a
+//!   fixed eight bytes on the wire with no unit string to transmit or look up.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Length, Meter};
+//!
+//! let length: Length = serde_json::from_str(r#"{ "value": 2.5, "unit": "km" }"#).unwrap();
+//! assert_eq!(length.to_unit::<Meter>(), 2500.0);
+//! assert_eq!(
+//!     serde_json::to_string(&length).unwrap(),
+//!     r#"{"value":2500.0,"unit":"m"}"#
+//! );
+//! ```
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::unit_conversion::parse::unknown_unit;
+use crate::unit_conversion::registry::find_unit_of;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+
+/// The human-readable form of a quantity.
+#[derive(serde::Deserialize)]
+#[serde(rename = "Quantity", deny_unknown_fields)]
+struct Readable {
+    value: f64,
+    unit: String,
+}
+
+impl<U: UnitConversion> Serialize for Quantity<U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Quantity", 2)?;
+            state.serialize_field("value", &U::convert_from(self.in_base()))?;
+            state.serialize_field("unit", U::SYMBOL)?;
+            state.end()
+        } else {
+            serializer.serialize_f64(self.in_base())
+        }
+    }
+}
+
+impl<'de, U: UnitConversion> Deserialize<'de> for Quantity<U>
+where
+    U::Dimension: Dimension,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let Readable { value, unit } = Readable::deserialize(deserializer)?;
+            let info = find_unit_of::<U::Dimension>(&unit)
+                .ok_or_else(|| de::Error::custom(unknown_unit::<U::Dimension>(&unit)))?;
+            Self::try_from_base((info.to_base)(value)).map_err(de::Error::custom)
+        } else {
+            Self::try_from_base(f64::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn human_readable_uses_value_and_unit() {
+        let temp = Temperature::from_unit::<Celsius>(25.0);
+        let json = serde_json::to_value(temp).unwrap();
+        assert!(approx(json["value"].as_f64().unwrap(), 298.15, 1e-12));
+        assert_eq!(json["unit"], "K");
+
+        let back: Temperature = serde_json::from_value(json).unwrap();
+        assert!(approx(back.to_unit::<Celsius>(), 25.0, 1e-12));
+    }
+
+    #[test]
+    fn human_readable_accepts_any_unit_of_the_dimension() {
+        let temp: Temperature =
+            serde_json::from_str(r#"{ "unit": "fahrenheit", "value": 32 }"#).unwrap();
+        assert!(approx(temp.to_unit::<Kelvin>(), 273.15, 1e-12));
+
+        let length: Length = serde_json::from_str(r#"{ "value": 10, "unit": "ft" }"#).unwrap();
+        assert!(approx(length.to_unit::<Foot>(), 10.0, 1e-12));
+    }
+
+    #[test]
+    fn human_readable_rejects_bad_units() {
+        let err = serde_json::from_str::<Length>(r#"{ "value": 1, "unit": "°C" }"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected a length unit but found a temperature unit")
+        );
+
+        let err =
+            serde_json::from_str::<Length>(r#"{ "value": 1, "unit": "meterz" }"#).unwrap_err();
+        assert!(err.to_string().contains("unknown length unit 'meterz'"));
+
+        assert!(serde_json::from_str::<Length>(r#"{ "value": 1 }"#).is_err());
+        assert!(serde_json::from_str::<Length>("1.0").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "strict_physics")]
+    fn rejects_physically_impossible_values() {
+        let err =
+            serde_json::from_str::<Temperature>(r#"{ "value": -5, "unit": "K" }"#).unwrap_err();
+        assert!(err.to_string().contains("outside the valid range"), "{err}");
+
+        let bytes = postcard::to_allocvec(&-5.0f64).unwrap();
+        assert!(postcard::from_bytes::<Temperature>(&bytes).is_err());
+    }
+
+    #[test]
+    fn compact_uses_the_base_value() {
+        let length = Length::from_unit::<Foot>(1.0);
+        let bytes = postcard::to_allocvec(&length).unwrap();
+        assert_eq!(bytes, 0.3048f64.to_le_bytes());
+
+        let back: Length = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(back.to_unit::<Meter>(), 0.3048);
+    }
+}
@@ -0,0 +1,399 @@
+//! # Significant Figures
+//!
+//! [`SigFig`] pairs a quantity with the precision it was measured to and carries that
+//! precision through arithmetic by the rules taught in introductory lab courses:
+//!
+//! - **Addition and subtraction** keep the least precise decimal place of the
+//!   operands: `12.11 m + 0.3 m = 12.4 m`.
+//! - **Multiplication and division** keep the fewest significant figures of the
+//!   operands: `2.5 m × 3.42 s⁻¹` has two. Products of typed quantities are formed
+//!   with [`SigFig::combine`], and exact factors such as counts with `*` and `/`.
+//!
+//! Precision is tracked in the unit `U` of the wrapper, so a `SigFig<Foot>` counts
+//! decimal places of feet. Rounding happens only when formatting; the value itself
+//! keeps full precision so that intermediate results are not rounded twice.
+//!
+//! Parsing counts the figures of the number as written. Trailing zeros of a whole
+//! number without a decimal point are taken as not significant, so `1200` has two
+//! figures while `1200.` and `1.200e3` have four.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Meter, Millimeter};
+//! use uom::unit_conversion::sigfig::SigFig;
+//!
+//! let rod = SigFig::<Meter>::parse("12.11 m").unwrap();
+//! let shim = SigFig::<Meter>::parse("0.3 m").unwrap();
+//! assert_eq!((rod + shim).to_string(), "12.4 m");
+//!
+//! let plate = SigFig::<Meter>::parse("2.50 mm").unwrap();
+//! assert_eq!(plate.figures(), 3);
+//! assert_eq!(plate.convert::<Millimeter>().to_string(), "2.50 mm");
+//! assert_eq!((plate * 4.0).to_string(), "0.0100 m");
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::unit_conversion::parse::{NumberLocale, split_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError, check_finite};
+
+/// The most significant figures an `f64` can hold.
+const MAX_FIGURES: u32 = 17;
+
+/// A quantity measured to a known number of significant figures in unit `U`.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Meter};
+/// use uom::unit_conversion::sigfig::SigFig;
+///
+/// let width = SigFig::new(Length::from_unit::<Meter>(1.23456), 3).unwrap();
+/// assert_eq!(width.to_string(), "1.23 m");
+/// assert!(SigFig::new(Length::from_unit::<Meter>(1.0), 0).is_err());
+/// ```
+pub struct SigFig<U: UnitConversion> {
+    quantity: Quantity<U>,
+    /// Decimal exponent of the last significant digit, in `U`.
+    last_digit: i32,
+}
+
+impl<U: UnitConversion> Copy for SigFig<U> {}
+
+impl<U: UnitConversion> Clone for SigFig<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> fmt::Debug for SigFig<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigFig")
+            .field("quantity", &self.quantity)
+            .field("figures", &self.figures())
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> SigFig<U> {
+    /// Wraps a quantity measured to `figures` significant figures in `U`.
+    ///
+    /// Returns [`UnitError::OutOfRange`] unless `figures` is between 1 and 17, and
+    /// [`UnitError::NonFinite`] if the quantity is infinite or NaN.
+    pub fn new(quantity: Quantity<U>, figures: u32) -> Result<Self, UnitError> {
+        if !(1..=MAX_FIGURES).contains(&figures) {
+            return Err(UnitError::OutOfRange {
+                value: figures.into(),
+                min: 1.0,
+                max: MAX_FIGURES.into(),
+            });
+        }
+        check_finite(quantity.to_unit::<U>())?;
+        Ok(Self::with_figures(quantity, figures))
+    }
+
+    fn with_figures(quantity: Quantity<U>, figures: u32) -> Self {
+        let value = quantity.to_unit::<U>();
+        let mut last_digit = -(figures as i32 - 1);
+        if value != 0.0 {
+            last_digit += exponent(value);
+            // Rounding 9.96 to two figures carries into a new leading digit, "10."
+            let scale = 10f64.powi(last_digit);
+            let rounded = (value / scale).round() * scale;
+            if exponent(rounded) > exponent(value) {
+                last_digit += 1;
+            }
+        }
+        Self {
+            quantity,
+            last_digit,
+        }
+    }
+
+    /// Returns the quantity at full precision.
+    pub fn quantity(&self) -> Quantity<U> {
+        self.quantity
+    }
+
+    /// Returns the value in `U` at full precision.
+    pub fn value(&self) -> f64 {
+        self.quantity.to_unit::<U>()
+    }
+
+    /// Returns the number of significant figures.
+    ///
+    /// A value that has rounded away entirely, such as the difference of two equal
+    /// measurements, reports one figure.
+    pub fn figures(&self) -> u32 {
+        let rounded = self.rounded();
+        let leading = if rounded == 0.0 { 0 } else { exponent(rounded) };
+        (leading - self.last_digit + 1).clamp(1, MAX_FIGURES as i32) as u32
+    }
+
+    /// Returns the value in `U` rounded to its last significant digit.
+    fn rounded(&self) -> f64 {
+        let scale = 10f64.powi(self.last_digit);
+        (self.value() / scale).round() * scale
+    }
+
+    /// Re-expresses the measurement in unit `V`, keeping its significant figures.
+    pub fn convert<V>(self) -> SigFig<V>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let quantity = Quantity::<V>::from_unit::<U>(self.value());
+        SigFig::with_figures(quantity, self.figures())
+    }
+
+    /// Combines two measurements by a product or quotient formula, keeping the fewer
+    /// significant figures of the two.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter, Millimeter};
+    /// use uom::unit_conversion::sigfig::SigFig;
+    ///
+    /// // A distance measured on a map whose scale is 25 m of ground per millimeter
+    /// let on_map = SigFig::<Millimeter>::parse("42.5 mm").unwrap();
+    /// let per_mm = SigFig::<Meter>::parse("25 m").unwrap();
+    /// let ground = on_map.combine(per_mm, |d, s| {
+    ///     Length::from_unit::<Meter>(d.to_unit::<Millimeter>() * s.to_unit::<Meter>())
+    /// });
+    /// assert_eq!(ground.to_string(), "1.1e3 m");
+    /// ```
+    pub fn combine<V, W, F>(self, other: SigFig<V>, formula: F) -> SigFig<W>
+    where
+        V: UnitConversion,
+        W: UnitConversion,
+        F: FnOnce(Quantity<U>, Quantity<V>) -> Quantity<W>,
+    {
+        let figures = self.figures().min(other.figures());
+        SigFig::with_figures(formula(self.quantity, other.quantity), figures)
+    }
+}
+
+impl<U: UnitConversion> SigFig<U>
+where
+    U::Dimension: Dimension,
+{
+    /// Parses a number followed by a unit, counting the significant figures of the
+    /// number as written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Quantity::parse`].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::Meter;
+    /// use uom::unit_conversion::sigfig::SigFig;
+    ///
+    /// assert_eq!(SigFig::<Meter>::parse("0.00340 m").unwrap().figures(), 3);
+    /// assert_eq!(SigFig::<Meter>::parse("1200 m").unwrap().figures(), 2);
+    /// assert_eq!(SigFig::<Meter>::parse("1.200e3 m").unwrap().figures(), 4);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, UnitError> {
+        let quantity = Quantity::<U>::parse(input)?;
+        let trimmed = input.trim();
+        let number = match split_number(trimmed, NumberLocale::PLAIN) {
+            Ok((_, rest)) => &trimmed[..trimmed.len() - rest.len()],
+            Err(_) => trimmed,
+        };
+        Ok(Self::with_figures(quantity, count_figures(number)))
+    }
+}
+
+/// Adds two measurements, keeping the less precise decimal place.
+impl<U: UnitConversion> Add for SigFig<U> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            quantity: Quantity::from_unit::<U>(self.value() + other.value()),
+            last_digit: self.last_digit.max(other.last_digit),
+        }
+    }
+}
+
+/// Subtracts two measurements, keeping the less precise decimal place.
+impl<U: UnitConversion> Sub for SigFig<U> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            quantity: Quantity::from_unit::<U>(self.value() - other.value()),
+            last_digit: self.last_digit.max(other.last_digit),
+        }
+    }
+}
+
+/// Scales a measurement by an exact factor, keeping its significant figures.
+impl<U: UnitConversion> Mul<f64> for SigFig<U> {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self::with_figures(
+            Quantity::from_unit::<U>(self.value() * factor),
+            self.figures(),
+        )
+    }
+}
+
+/// Divides a measurement by an exact divisor, keeping its significant figures.
+impl<U: UnitConversion> Div<f64> for SigFig<U> {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self::with_figures(
+            Quantity::from_unit::<U>(self.value() / divisor),
+            self.figures(),
+        )
+    }
+}
+
+/// Formats the value rounded to its significant figures, followed by the unit symbol.
+///
+/// Whole numbers whose last significant digit is left of the ones place are written
+/// in scientific notation (`1.2e3 m`) so that no trailing zero is misread as
+/// significant, and a whole number significant to the ones place ends in a point
+/// (`10. m/s`) when its trailing zero would otherwise be ambiguous.
+impl<U: UnitConversion> fmt::Display for SigFig<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value();
+        if self.last_digit <= 0 {
+            let decimals = (-self.last_digit) as usize;
+            write!(f, "{value:.decimals$}")?;
+            if decimals == 0 && format!("{value:.0}").ends_with('0') {
+                f.write_str(".")?;
+            }
+        } else {
+            let rounded = self.rounded();
+            if rounded == 0.0 {
+                f.write_str("0")?;
+            } else {
+                let decimals = (exponent(rounded) - self.last_digit) as usize;
+                write!(f, "{rounded:.decimals$e}")?;
+            }
+        }
+        write!(f, " {}", U::SYMBOL)
+    }
+}
+
+/// Returns the decimal exponent of the leading digit of a nonzero finite value.
+fn exponent(value: f64) -> i32 {
+    let value = value.abs();
+    let mut exponent = value.log10().floor() as i32;
+    // Correct for log10 rounding near exact powers of ten
+    if 10f64.powi(exponent) > value {
+        exponent -= 1;
+    } else if 10f64.powi(exponent + 1) <= value {
+        exponent += 1;
+    }
+    exponent
+}
+
+/// Counts the significant figures of a number as written.
+fn count_figures(number: &str) -> u32 {
+    let mantissa = number
+        .trim_start_matches(['+', '-'])
+        .split(['e', 'E'])
+        .next()
+        .unwrap_or_default();
+    let has_point = mantissa.contains('.');
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let significant = digits.trim_start_matches('0');
+    let figures = if significant.is_empty() {
+        // A measured zero is significant to its last written decimal place
+        mantissa.split('.').nth(1).map_or(1, str::len)
+    } else if has_point {
+        significant.len()
+    } else {
+        significant.trim_end_matches('0').len()
+    };
+    (figures as u32).clamp(1, MAX_FIGURES)
+}
+
+#[cfg(all(test, feature = "length"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Inch, Length, Meter};
+
+    fn meters(input: &str) -> SigFig<Meter> {
+        SigFig::parse(input).unwrap()
+    }
+
+    #[test]
+    fn counts_figures_as_written() {
+        for (input, figures) in [
+            ("123", 3),
+            ("1.230", 4),
+            ("0.0045", 2),
+            ("1200", 2),
+            ("1200.", 4),
+            ("1.20e3", 3),
+            ("-0.500", 3),
+            ("0.00", 2),
+            ("0", 1),
+        ] {
+            assert_eq!(count_figures(input), figures, "{input}");
+        }
+    }
+
+    #[test]
+    fn addition_keeps_the_coarser_decimal_place() {
+        assert_eq!((meters("1.234 m") + meters("10.1 m")).to_string(), "11.3 m");
+        assert_eq!((meters("100 m") + meters("1.5 m")).to_string(), "1e2 m");
+        assert_eq!((meters("100. m") + meters("1.5 m")).to_string(), "102 m");
+        let difference = meters("5.27 m") - meters("5.25 m");
+        assert_eq!(difference.figures(), 1);
+        assert_eq!(difference.to_string(), "0.02 m");
+        assert_eq!((meters("4.0 m") - meters("4.0 m")).to_string(), "0.0 m");
+    }
+
+    #[test]
+    fn products_keep_the_fewest_figures() {
+        let a = meters("2.5 m");
+        let b = meters("3.42 m");
+        let product = a.combine(b, |x, y| {
+            Length::from_unit::<Meter>(x.to_unit::<Meter>() * y.to_unit::<Meter>())
+        });
+        assert_eq!(product.figures(), 2);
+        assert_eq!(product.to_string(), "8.6 m");
+
+        // Exact factors do not limit precision
+        assert_eq!((b * 3.0).to_string(), "10.3 m");
+        assert_eq!((b / 2.0).to_string(), "1.71 m");
+    }
+
+    #[test]
+    fn precision_is_counted_in_the_wrappers_unit() {
+        let board = SigFig::<Foot>::parse("36.0 in").unwrap();
+        assert_eq!(board.figures(), 3);
+        assert_eq!(board.to_string(), "3.00 ft");
+        assert_eq!(board.convert::<Inch>().to_string(), "36.0 in");
+    }
+
+    #[test]
+    fn formats_rounding_carries() {
+        let value = SigFig::new(Length::from_unit::<Meter>(9.96), 2).unwrap();
+        assert_eq!(value.to_string(), "10. m");
+        let value = SigFig::new(Length::from_unit::<Meter>(123_456.0), 3).unwrap();
+        assert_eq!(value.to_string(), "1.23e5 m");
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let one = Length::from_unit::<Meter>(1.0);
+        assert!(SigFig::new(one, 18).is_err());
+        assert!(SigFig::new(Length::from_unit::<Meter>(f64::NAN), 3).is_err());
+        assert!(SigFig::<Meter>::parse("m").is_err());
+    }
+}
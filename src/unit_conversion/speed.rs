@@ -0,0 +1,478 @@
+//! # Speed Unit Conversions
+//!
+//! This module provides type-safe speed conversions between meters per second, kilometers
+//! per hour, miles per hour, knots, feet per second, Mach numbers, and fractions of the
+//! speed of light.
+//! All speeds are internally stored in meters per second (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Meter per second (m/s)**: The base unit, SI unit of speed
+//! - **Kilometer per hour (km/h)**: 1/3.6 meters per second
+//! - **Mile per hour (mph)**: Exactly 0.44704 meters per second
+//! - **Knot (kn)**: One nautical mile (1852 m) per hour
+//! - **Foot per second (ft/s)**: Exactly 0.3048 meters per second
+//! - **Mach (Ma)**: Multiples of the speed of sound at sea level in the International
+//!   Standard Atmosphere, 340.294 meters per second
+//! - **Speed of light fraction (c)**: Multiples of [`SPEED_OF_LIGHT`], exactly
+//!   299,792,458 meters per second
+//!
+//! The speed of sound depends on the air temperature, so the [`Mach`] unit only fits
+//! standard sea-level conditions. [`Speed::from_mach`] and [`Speed::to_mach`] take the
+//! ambient temperature instead.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::speed::{Speed, KilometerPerHour, MeterPerSecond, MilePerHour};
+//!
+//! let limit = Speed::from_unit::<MilePerHour>(60.0);
+//! assert!((limit.to_unit::<KilometerPerHour>() - 96.56064).abs() < 1e-9);
+//!
+//! let sprint = Speed::from_unit::<MeterPerSecond>(10.0);
+//! assert!((sprint.to_unit::<KilometerPerHour>() - 36.0).abs() < 1e-12);
+//! ```
+
+#[cfg(feature = "temperature")]
+use crate::unit_conversion::temperature::Temperature;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for speed conversions
+const METERS_PER_SECOND_PER_KILOMETER_PER_HOUR: f64 = 1000.0 / 3600.0;
+const METERS_PER_SECOND_PER_MILE_PER_HOUR: f64 = 0.44704; // Exact definition
+const METERS_PER_SECOND_PER_KNOT: f64 = 1852.0 / 3600.0; // Exact definition
+const METERS_PER_SECOND_PER_FOOT_PER_SECOND: f64 = 0.3048; // Exact definition
+const METERS_PER_SECOND_OF_LIGHT: f64 = 299_792_458.0; // Exact definition
+/// Heat capacity ratio of dry air.
+#[cfg(feature = "temperature")]
+const AIR_HEAT_CAPACITY_RATIO: f64 = 1.4;
+/// Specific gas constant of dry air in J/(kg·K), as used by the standard atmosphere.
+#[cfg(feature = "temperature")]
+const AIR_GAS_CONSTANT: f64 = 287.05287;
+
+/// The speed of light in vacuum, exactly 299 792 458 m/s by the SI definition of the
+/// meter.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{KilometerPerHour, SPEED_OF_LIGHT};
+///
+/// assert!((SPEED_OF_LIGHT.to_unit::<KilometerPerHour>() - 1.0792528488e9).abs() < 1.0);
+/// ```
+pub const SPEED_OF_LIGHT: Speed = Speed::from_base(METERS_PER_SECOND_OF_LIGHT);
+
+/// The speed of sound in dry air at sea level in the International Standard Atmosphere
+/// (15 °C), 340.294 m/s. This is the reference speed of the [`Mach`] unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{KilometerPerHour, SPEED_OF_SOUND};
+///
+/// assert!((SPEED_OF_SOUND.to_unit::<KilometerPerHour>() - 1225.06).abs() < 0.01);
+/// ```
+pub const SPEED_OF_SOUND: Speed = Speed::from_base(340.294);
+
+/// Marker type for the speed dimension.
+///
+/// This prevents accidental conversions between speed and other dimensions
+/// like length or time.
+pub enum SpeedDimension {}
+
+impl Dimension for SpeedDimension {
+    const NAME: &'static str = "speed";
+    const ID: u8 = 11;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<MeterPerSecond>(&["mps", "meter per second", "meters per second"]),
+        UnitInfo::of::<KilometerPerHour>(&["kph", "kmh", "km/hr", "kilometers per hour"]),
+        UnitInfo::of::<MilePerHour>(&["mi/h", "mile per hour", "miles per hour"]),
+        UnitInfo::of::<Knot>(&["kt", "kts", "knot", "knots"]),
+        UnitInfo::of::<FootPerSecond>(&["fps", "foot per second", "feet per second"]),
+        UnitInfo::of::<Mach>(&["mach"]),
+        UnitInfo::of::<SpeedOfLightFraction>(&["speed of light", "speeds of light"]),
+    ];
+}
+
+/// A speed quantity that stores values in meters per second internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, Knot, KilometerPerHour};
+///
+/// let cruise = Speed::from_unit::<Knot>(450.0);
+/// let kmh = cruise.to_unit::<KilometerPerHour>(); // 833.4
+/// ```
+pub type Speed = Quantity<MeterPerSecond>;
+
+/// Meter per second speed unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, MeterPerSecond};
+///
+/// let walk = Speed::from_unit::<MeterPerSecond>(1.4);
+/// assert_eq!(walk.to_unit::<MeterPerSecond>(), 1.4);
+/// ```
+pub struct MeterPerSecond;
+
+impl UnitConversion for MeterPerSecond {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // MeterPerSecond is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // MeterPerSecond is the base unit
+    }
+
+    const SYMBOL: &'static str = "m/s";
+    const NAME: &'static str = "meter per second";
+    const PLURAL: &'static str = "meters per second";
+    const PHYSICAL_RANGE: (f64, f64) = (-METERS_PER_SECOND_OF_LIGHT, METERS_PER_SECOND_OF_LIGHT);
+}
+
+/// Kilometer per hour speed unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, KilometerPerHour, MeterPerSecond};
+///
+/// let car = Speed::from_unit::<KilometerPerHour>(90.0);
+/// assert!((car.to_unit::<MeterPerSecond>() - 25.0).abs() < 1e-12);
+/// ```
+pub struct KilometerPerHour;
+
+impl UnitConversion for KilometerPerHour {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_SECOND_PER_KILOMETER_PER_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_SECOND_PER_KILOMETER_PER_HOUR
+    }
+
+    const SYMBOL: &'static str = "km/h";
+    const NAME: &'static str = "kilometer per hour";
+    const PLURAL: &'static str = "kilometers per hour";
+}
+
+/// Mile per hour speed unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, MilePerHour, MeterPerSecond};
+///
+/// let limit = Speed::from_unit::<MilePerHour>(30.0);
+/// assert!((limit.to_unit::<MeterPerSecond>() - 13.4112).abs() < 1e-12);
+/// ```
+pub struct MilePerHour;
+
+impl UnitConversion for MilePerHour {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_SECOND_PER_MILE_PER_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_SECOND_PER_MILE_PER_HOUR
+    }
+
+    const SYMBOL: &'static str = "mph";
+    const NAME: &'static str = "mile per hour";
+    const PLURAL: &'static str = "miles per hour";
+}
+
+/// Knot speed unit, one nautical mile per hour, used at sea and in aviation.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, Knot, KilometerPerHour};
+///
+/// let wind = Speed::from_unit::<Knot>(10.0);
+/// assert!((wind.to_unit::<KilometerPerHour>() - 18.52).abs() < 1e-12);
+/// ```
+pub struct Knot;
+
+impl UnitConversion for Knot {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_SECOND_PER_KNOT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_SECOND_PER_KNOT
+    }
+
+    const SYMBOL: &'static str = "kn";
+    const NAME: &'static str = "knot";
+    const PLURAL: &'static str = "knots";
+}
+
+/// Foot per second speed unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{Speed, FootPerSecond, MilePerHour};
+///
+/// let speed = Speed::from_unit::<FootPerSecond>(88.0);
+/// assert!((speed.to_unit::<MilePerHour>() - 60.0).abs() < 1e-9);
+/// ```
+pub struct FootPerSecond;
+
+impl UnitConversion for FootPerSecond {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * METERS_PER_SECOND_PER_FOOT_PER_SECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / METERS_PER_SECOND_PER_FOOT_PER_SECOND
+    }
+
+    const SYMBOL: &'static str = "ft/s";
+    const NAME: &'static str = "foot per second";
+    const PLURAL: &'static str = "feet per second";
+}
+
+/// Mach speed unit.
+///
+/// Multiples of [`SPEED_OF_SOUND`], the speed of sound at sea level in the standard
+/// atmosphere. Use [`Speed::from_mach`] for a Mach number measured at another
+/// temperature, such as at cruise altitude.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{KilometerPerHour, Mach, Speed};
+///
+/// let jet = Speed::from_unit::<Mach>(2.0);
+/// assert!((jet.to_unit::<KilometerPerHour>() - 2450.1168).abs() < 1e-6);
+/// ```
+pub struct Mach;
+
+impl UnitConversion for Mach {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SPEED_OF_SOUND.in_base()
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SPEED_OF_SOUND.in_base()
+    }
+
+    const SYMBOL: &'static str = "Ma";
+    const NAME: &'static str = "Mach";
+    const PLURAL: &'static str = "Mach";
+}
+
+/// Speed unit expressed as a fraction of the speed of light.
+///
+/// A value of 0.5 is half of [`SPEED_OF_LIGHT`]. The factor is read from that constant, so
+/// the two always agree.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::speed::{KilometerPerHour, Speed, SpeedOfLightFraction};
+///
+/// let probe = Speed::from_unit::<KilometerPerHour>(690_000.0);
+/// assert!((probe.to_unit::<SpeedOfLightFraction>() - 6.393e-4).abs() < 1e-6);
+/// ```
+pub struct SpeedOfLightFraction;
+
+impl UnitConversion for SpeedOfLightFraction {
+    type Dimension = SpeedDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SPEED_OF_LIGHT.in_base()
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SPEED_OF_LIGHT.in_base()
+    }
+
+    const SYMBOL: &'static str = "c";
+    const NAME: &'static str = "speed of light";
+    const PLURAL: &'static str = "speeds of light";
+}
+
+/// Returns the speed of sound in dry air at the temperature `ambient`.
+///
+/// Temperatures at or below absolute zero give NaN.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
+/// use uom::unit_conversion::speed::{speed_of_sound, MeterPerSecond};
+/// use uom::unit_conversion::temperature::{Celsius, Temperature};
+///
+/// // Cruise altitude in the standard atmosphere
+/// let cold = speed_of_sound(Temperature::from_unit::<Celsius>(-56.5));
+/// assert!((cold.to_unit::<MeterPerSecond>() - 295.07).abs() < 0.01);
+/// ```
+#[cfg(feature = "temperature")]
+pub fn speed_of_sound(ambient: Temperature) -> Speed {
+    let kelvin = ambient.in_base();
+    Speed::from_base((AIR_HEAT_CAPACITY_RATIO * AIR_GAS_CONSTANT * kelvin).sqrt())
+}
+
+#[cfg(feature = "temperature")]
+impl Speed {
+    /// Creates the speed of Mach number `mach` in air at the temperature `ambient`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::speed::{Mach, Speed};
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let stratosphere = Temperature::from_unit::<Celsius>(-56.5);
+    /// let cruise = Speed::from_mach(0.85, stratosphere);
+    /// // Slower than Mach 0.85 at sea level
+    /// assert!(cruise.to_unit::<Mach>() < 0.75);
+    /// ```
+    pub fn from_mach(mach: f64, ambient: Temperature) -> Self {
+        Self::from_base(mach * speed_of_sound(ambient).in_base())
+    }
+
+    /// Returns the Mach number of this speed in air at the temperature `ambient`.
+    pub fn to_mach(&self, ambient: Temperature) -> f64 {
+        self.in_base() / speed_of_sound(ambient).in_base()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn metric_conversions() {
+        let speed = Speed::from_unit::<MeterPerSecond>(1.0);
+        assert!(approx(speed.to_unit::<KilometerPerHour>(), 3.6, 1e-12));
+
+        let speed = Speed::from_unit::<KilometerPerHour>(100.0);
+        assert!(approx(speed.to_unit::<MeterPerSecond>(), 27.777778, 1e-6));
+    }
+
+    #[test]
+    fn imperial_and_nautical_conversions() {
+        let speed = Speed::from_unit::<MilePerHour>(1.0);
+        assert!(approx(speed.to_unit::<KilometerPerHour>(), 1.609344, 1e-12));
+        assert!(approx(speed.to_unit::<FootPerSecond>(), 22.0 / 15.0, 1e-12));
+
+        let speed = Speed::from_unit::<Knot>(1.0);
+        assert!(approx(speed.to_unit::<KilometerPerHour>(), 1.852, 1e-12));
+        assert!(approx(speed.to_unit::<MilePerHour>(), 1.150779, 1e-6));
+    }
+
+    #[test]
+    fn mach_conversions() {
+        let speed = Speed::from_unit::<Mach>(1.0);
+        assert!(approx(speed.to_unit::<MeterPerSecond>(), 340.294, 1e-12));
+        assert!(approx(speed.to_unit::<Knot>(), 661.479, 1e-3));
+        assert_eq!(SpeedDimension::find_unit("mach").unwrap().symbol, "Ma");
+    }
+
+    #[test]
+    fn fraction_of_light_speed() {
+        let speed = Speed::from_unit::<SpeedOfLightFraction>(1.0);
+        assert_eq!(speed, SPEED_OF_LIGHT);
+        assert_eq!(speed.to_unit::<MeterPerSecond>(), 299_792_458.0);
+
+        let speed = Speed::from_unit::<MeterPerSecond>(29_979_245.8);
+        assert!(approx(speed.to_unit::<SpeedOfLightFraction>(), 0.1, 1e-15));
+        assert_eq!(
+            SpeedDimension::find_unit("speed of light").unwrap().symbol,
+            "c"
+        );
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict_physics", debug_assertions),
+        ignore = "builds physically impossible quantities"
+    )]
+    fn mach_depends_on_temperature() {
+        use crate::unit_conversion::temperature::{Celsius, Kelvin};
+
+        let sea_level = Temperature::from_unit::<Celsius>(15.0);
+        assert!(approx(
+            speed_of_sound(sea_level).to_unit::<MeterPerSecond>(),
+            340.294,
+            1e-3
+        ));
+
+        let cold = Temperature::from_unit::<Celsius>(-56.5);
+        let cruise = Speed::from_mach(0.8, cold);
+        assert!(approx(cruise.to_unit::<MeterPerSecond>(), 236.06, 1e-2));
+        assert!(approx(cruise.to_mach(cold), 0.8, 1e-12));
+        assert!(approx(cruise.to_mach(sea_level), 0.6937, 1e-4));
+
+        assert!(
+            Speed::from_mach(1.0, Temperature::from_unit::<Kelvin>(-1.0))
+                .to_unit::<MeterPerSecond>()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn speed_symbols() {
+        assert_eq!(MeterPerSecond::SYMBOL, "m/s");
+        assert_eq!(KilometerPerHour::SYMBOL, "km/h");
+        assert_eq!(MilePerHour::SYMBOL, "mph");
+        assert_eq!(Knot::SYMBOL, "kn");
+        assert_eq!(FootPerSecond::SYMBOL, "ft/s");
+        assert_eq!(Mach::SYMBOL, "Ma");
+        assert_eq!(SpeedOfLightFraction::SYMBOL, "c");
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict_physics", debug_assertions))]
+    #[should_panic(expected = "PHYSICAL_RANGE")]
+    fn strict_physics_rejects_faster_than_light() {
+        let _ = Speed::from_unit::<SpeedOfLightFraction>(-1.01);
+    }
+
+    #[test]
+    fn light_speed_is_physical() {
+        assert_eq!(
+            Speed::from_unit::<SpeedOfLightFraction>(1.0).in_base(),
+            SPEED_OF_LIGHT.in_base()
+        );
+    }
+}
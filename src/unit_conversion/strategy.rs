@@ -0,0 +1,204 @@
+//! # Property-Testing Strategies
+//!
+//! With the `proptest` feature enabled, every built-in quantity type implements
+//! [`proptest::arbitrary::Arbitrary`], so downstream crates can property-test
+//! unit-handling code with `any::<Length>()` instead of writing a generator per
+//! dimension. Generated values are drawn uniformly from a physically meaningful range
+//! for each dimension (see [`PhysicalRange`]); use [`quantity_in`] to narrow it.
+//!
+//! ## Examples
+//!
+#![cfg_attr(
+    not(all(feature = "length", feature = "temperature")),
+    doc = "```ignore"
+)]
+#![cfg_attr(all(feature = "length", feature = "temperature"), doc = "```")]
+//! use proptest::prelude::*;
+//! use uom::unit_conversion::length::{Foot, Length, Meter};
+//! use uom::unit_conversion::strategy::quantity_in;
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//!
+//! proptest!(|(length in any::<Length>())| {
+//!     let back = Length::from_unit::<Foot>(length.to_unit::<Foot>());
+//!     prop_assert!((back.to_unit::<Meter>() - length.to_unit::<Meter>()).abs() < 1e-6);
+//! });
+//!
+//! proptest!(|(room in quantity_in::<Temperature, Celsius>(15.0..=30.0))| {
+//!     prop_assert!(room.to_unit::<Celsius>() >= 15.0 - 1e-9);
+//! });
+//! ```
+
+use std::ops::RangeInclusive;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+
+/// A dimension with a range of values that property tests should cover by default.
+///
+/// The bounds are in the dimension's base unit. They span everyday and engineering
+/// magnitudes without reaching values where `f64` rounding dominates.
+pub trait PhysicalRange: Dimension {
+    /// The smallest generated value, in base units.
+    const MIN: f64;
+    /// The largest generated value, in base units.
+    const MAX: f64;
+}
+
+macro_rules! physical_range {
+    ($($feature:literal => $dimension:path, $min:expr, $max:expr;)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl PhysicalRange for $dimension {
+                const MIN: f64 = $min;
+                const MAX: f64 = $max;
+            }
+        )*
+    };
+}
+
+physical_range! {
+    // Up to 10 g either way
+    "acceleration" => super::acceleration::AccelerationDimension, -100.0, 100.0;
+    // Up to 1000 mol
+    "amount" => super::amount::AmountDimension, 0.0, 1e3;
+    // Signed, up to several full turns in either direction
+    "angle" => super::angle::AngleDimension, -4.0 * std::f64::consts::PI, 4.0 * std::f64::consts::PI;
+    // Up to 1 km²
+    "area" => super::area::AreaDimension, 0.0, 1e6;
+    // Up to 100 g/L, beyond blood's total protein
+    "concentration" => super::concentration::MassConcentrationDimension, 0.0, 100.0;
+    // Up to 1000 mmol/L
+    "concentration" => super::concentration::MolarConcentrationDimension, 0.0, 1e3;
+    // Up to 1000 Ah either way
+    "charge" => super::charge::ChargeDimension, -3.6e6, 3.6e6;
+    // Up to 1 kA either way
+    "current" => super::current::CurrentDimension, -1e3, 1e3;
+    // Up to 10 Tbit/s
+    "data_rate" => super::data_rate::DataRateDimension, 0.0, 1e13;
+    // Up to 1 PB
+    "data_size" => super::data_size::DataSizeDimension, 0.0, 8e15;
+    // Up to 10 kg/km
+    "emissions" => super::emissions::DistanceEmissionsDimension, 0.0, 1e4;
+    // Up to about 280 MWh
+    "energy" => super::energy::EnergyDimension, 0.0, 1e12;
+    // Up to 2 kg/kWh, beyond the dirtiest power stations
+    "emissions" => super::emissions::EnergyEmissionsDimension, 0.0, 2.0;
+    // Up to 1 MN either way
+    "force" => super::force::ForceDimension, -1e6, 1e6;
+    // Up to microwave frequencies
+    "frequency" => super::frequency::FrequencyDimension, 0.0, 1e11;
+    // Up to 10 000 km
+    "length" => super::length::LengthDimension, 0.0, 1e7;
+    // Up to 1000 t
+    "mass" => super::mass::MassDimension, 0.0, 1e6;
+    // Up to 1 t/s
+    "mass_rate" => super::mass_rate::MassRateDimension, 0.0, 1e3;
+    // Up to 1 GW
+    "power" => super::power::PowerDimension, 0.0, 1e9;
+    // Up to 1000 bar
+    "pressure" => super::pressure::PressureDimension, 0.0, 1e8;
+    // Up to 1000%
+    "ratio" => super::ratio::RatioDimension, 0.0, 10.0;
+    // Up to 1 GΩ
+    "resistance" => super::resistance::ResistanceDimension, 0.0, 1e9;
+    // Up to about Mach 3
+    "speed" => super::speed::SpeedDimension, 0.0, 1e3;
+    // From absolute zero to beyond the surface of the Sun
+    "temperature" => super::temperature::TemperatureDimension, 0.0, 1e4;
+    // Up to about 30 years
+    "time" => super::time::TimeDimension, 0.0, 1e9;
+    // Up to high-voltage transmission levels
+    "voltage" => super::voltage::VoltageDimension, 0.0, 1e6;
+    // Up to 1000 m³
+    "volume" => super::volume::VolumeDimension, 0.0, 1e3;
+}
+
+impl<U> Arbitrary for Quantity<U>
+where
+    U: UnitConversion + 'static,
+    U::Dimension: PhysicalRange,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (U::Dimension::MIN..=U::Dimension::MAX)
+            .prop_map(Self::from_base)
+            .boxed()
+    }
+}
+
+/// Returns a strategy for quantities whose value in unit `V` lies in `range`.
+///
+/// Values are drawn uniformly in `V`, so for an affine unit such as degrees Celsius
+/// the range is in that unit's scale.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use proptest::prelude::*;
+/// use uom::unit_conversion::length::{Kilometer, Length};
+/// use uom::unit_conversion::strategy::quantity_in;
+///
+/// proptest!(|(commute in quantity_in::<Length, Kilometer>(1.0..=50.0))| {
+///     prop_assert!(commute.to_unit::<Kilometer>() <= 50.0 + 1e-9);
+/// });
+/// ```
+pub fn quantity_in<Q, V>(range: RangeInclusive<f64>) -> BoxedStrategy<Q>
+where
+    Q: QuantityOf<V>,
+    V: UnitConversion + 'static,
+{
+    range.prop_map(Q::from_value).boxed()
+}
+
+/// Connects a quantity type to the units it can be built from, so [`quantity_in`] can
+/// name the quantity (`Temperature`) rather than its base unit (`Kelvin`).
+pub trait QuantityOf<V>: Sized + std::fmt::Debug + 'static {
+    /// Creates the quantity from a value in unit `V`.
+    fn from_value(value: f64) -> Self;
+}
+
+impl<U, V> QuantityOf<V> for Quantity<U>
+where
+    U: UnitConversion + 'static,
+    V: UnitConversion<Dimension = U::Dimension>,
+{
+    fn from_value(value: f64) -> Self {
+        Self::from_unit::<V>(value)
+    }
+}
+
+#[cfg(all(test, feature = "temperature", feature = "length"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Inch, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arbitrary_values_stay_in_range(t in any::<Temperature>(), l in any::<Length>()) {
+            prop_assert!((0.0..=1e4).contains(&t.to_unit::<Kelvin>()));
+            prop_assert!((0.0..=1e7).contains(&l.to_unit::<Meter>()));
+        }
+
+        #[test]
+        fn temperatures_round_trip(t in any::<Temperature>()) {
+            let back = Temperature::from_unit::<Fahrenheit>(t.to_unit::<Fahrenheit>());
+            prop_assert!((back.to_unit::<Kelvin>() - t.to_unit::<Kelvin>()).abs() < 1e-9);
+        }
+
+        #[test]
+        fn ranges_are_in_the_given_unit(
+            t in quantity_in::<Temperature, Celsius>(-40.0..=40.0),
+            l in quantity_in::<Length, Inch>(1.0..=12.0),
+        ) {
+            prop_assert!((233.0..=314.0).contains(&t.to_unit::<Kelvin>()));
+            prop_assert!((0.0254..=0.3048 + 1e-12).contains(&l.to_unit::<Meter>()));
+        }
+    }
+}
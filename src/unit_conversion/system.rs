@@ -0,0 +1,314 @@
+//! # Unit Systems
+//!
+//! Applications that let users switch between "metric" and "imperial" need to know
+//! which unit each system conventionally uses for a dimension: kilometers or miles for
+//! a distance, liters or gallons for a volume. A [`UnitSystem`] lists those units per
+//! dimension, and [`Quantity::in_system`] converts a quantity to the first of them.
+//!
+//! Dimensions whose units are the same everywhere, such as time, angle and frequency,
+//! are shared by every system. A system has no unit for a dimension it does not cover,
+//! such as speed in CGS.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Kilometer, Length};
+//! use uom::unit_conversion::system::UnitSystem;
+//!
+//! let distance = Length::from_unit::<Kilometer>(100.0);
+//! let shown = distance.in_system(UnitSystem::Imperial).unwrap();
+//! assert_eq!(shown.unit.symbol, "mi");
+//! assert_eq!(format!("{shown:.1}"), "62.1 mi");
+//!
+//! assert_eq!(UnitSystem::Si.unit_for("temperature"), Some("°C"));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError, UnitInfo};
+
+/// A system of units, such as SI or US customary.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnitSystem {
+    /// The International System of Units, with the metric units in everyday use
+    /// alongside it (kilometers, liters, degrees Celsius).
+    Si,
+    /// United States customary units; volumes use US gallons and pints.
+    UsCustomary,
+    /// British imperial units; volumes use imperial gallons and pints.
+    Imperial,
+    /// The centimetre–gram–second system.
+    Cgs,
+}
+
+/// Conventional units per dimension, the default first.
+type Table = &'static [(&'static str, &'static [&'static str])];
+
+const SI: Table = &[
+    ("length", &["km", "m", "cm", "mm"]),
+    ("temperature", &["°C", "K"]),
+    ("mass", &["kg", "g"]),
+    ("volume", &["L", "mL", "m³"]),
+    ("energy", &["kJ", "J", "kWh"]),
+    ("power", &["kW", "W"]),
+    ("pressure", &["kPa", "Pa", "bar"]),
+    ("area", &["m²", "cm²", "mm²"]),
+    ("speed", &["km/h", "m/s"]),
+    ("force", &["N", "kN"]),
+    ("acceleration", &["m/s²"]),
+    ("distance emissions", &["g/km"]),
+];
+const US_CUSTOMARY: Table = &[
+    ("length", &["mi", "ft", "in"]),
+    ("temperature", &["°F"]),
+    ("mass", &["lb"]),
+    (
+        "volume",
+        &["US gal", "US pt", "US fl oz", "cup", "tbsp", "tsp"],
+    ),
+    ("energy", &["BTU", "thm"]),
+    ("power", &["BTU/h", "TR"]),
+    ("pressure", &["psi"]),
+    ("area", &["ft²", "in²"]),
+    ("speed", &["mph", "ft/s"]),
+    ("force", &["lbf"]),
+    ("acceleration", &["ft/s²"]),
+    ("distance emissions", &["g/mi"]),
+];
+const IMPERIAL: Table = &[
+    ("length", &["mi", "ft", "in"]),
+    ("temperature", &["°F"]),
+    ("mass", &["lb"]),
+    ("volume", &["imp gal", "imp pt", "imp fl oz"]),
+    ("energy", &["BTU", "thm"]),
+    ("power", &["BTU/h"]),
+    ("pressure", &["psi"]),
+    ("area", &["ft²", "in²"]),
+    ("speed", &["mph", "ft/s"]),
+    ("force", &["lbf"]),
+    ("acceleration", &["ft/s²"]),
+    ("distance emissions", &["g/km"]),
+];
+const CGS: Table = &[
+    ("length", &["cm", "mm"]),
+    ("temperature", &["K", "°C"]),
+    ("mass", &["g"]),
+    ("volume", &["mL"]),
+    ("area", &["cm²", "mm²"]),
+    ("force", &["dyn"]),
+    ("acceleration", &["Gal"]),
+];
+/// Dimensions measured the same way in every system.
+const SHARED: Table = &[
+    ("time", &["s", "min", "h"]),
+    ("angle", &["°", "rad"]),
+    ("voltage", &["V", "mV", "kV"]),
+    ("current", &["A", "mA", "µA"]),
+    ("resistance", &["Ω", "kΩ", "MΩ"]),
+    ("charge", &["mAh", "Ah", "C"]),
+    ("energy emissions", &["g/kWh", "kg/kWh"]),
+    ("frequency", &["Hz", "kHz", "MHz", "GHz"]),
+    ("ratio", &["%", "1"]),
+    ("amount", &["mol"]),
+    ("data size", &["GB", "MB", "kB", "B"]),
+    ("data rate", &["Mbit/s", "kbit/s", "bit/s"]),
+];
+
+impl UnitSystem {
+    /// Every system, in declaration order.
+    pub const ALL: [UnitSystem; 4] = [Self::Si, Self::UsCustomary, Self::Imperial, Self::Cgs];
+
+    /// Returns the system's display name, such as "US customary".
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Si => "SI",
+            Self::UsCustomary => "US customary",
+            Self::Imperial => "imperial",
+            Self::Cgs => "CGS",
+        }
+    }
+
+    fn table(self) -> Table {
+        match self {
+            Self::Si => SI,
+            Self::UsCustomary => US_CUSTOMARY,
+            Self::Imperial => IMPERIAL,
+            Self::Cgs => CGS,
+        }
+    }
+
+    /// Returns the symbols of the units this system uses for `dimension`, the
+    /// conventional one first, or an empty slice if the system has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::system::UnitSystem;
+    ///
+    /// assert_eq!(UnitSystem::UsCustomary.units("length"), ["mi", "ft", "in"]);
+    /// assert_eq!(UnitSystem::Cgs.units("time"), ["s", "min", "h"]);
+    /// assert!(UnitSystem::Cgs.units("speed").is_empty());
+    /// ```
+    pub fn units(self, dimension: &str) -> &'static [&'static str] {
+        self.table()
+            .iter()
+            .chain(SHARED)
+            .find(|(name, _)| *name == dimension)
+            .map_or(&[], |(_, units)| units)
+    }
+
+    /// Returns the symbol of the conventional unit for `dimension` in this system.
+    pub fn unit_for(self, dimension: &str) -> Option<&'static str> {
+        self.units(dimension).first().copied()
+    }
+
+    /// Returns true if the unit `symbol` of `dimension` belongs to this system.
+    pub fn contains(self, dimension: &str, symbol: &str) -> bool {
+        self.units(dimension).contains(&symbol)
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a system name, ignoring ASCII case: `"si"` or `"metric"`, `"us"` or
+/// `"us customary"`, `"imperial"` or `"uk"`, and `"cgs"`.
+impl FromStr for UnitSystem {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "si" | "metric" => Ok(Self::Si),
+            "us" | "us customary" | "us_customary" | "customary" => Ok(Self::UsCustomary),
+            "imperial" | "uk" => Ok(Self::Imperial),
+            "cgs" => Ok(Self::Cgs),
+            _ => Err(UnitError::ParseError {
+                input: s.to_string(),
+                reason: "unknown unit system",
+            }),
+        }
+    }
+}
+
+/// A value expressed in a unit chosen at runtime, as returned by
+/// [`Quantity::in_system`].
+///
+/// Displays as the value followed by the unit symbol; the formatter's precision, or
+/// else the installed [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies
+/// to the value.
+#[derive(Copy, Clone, Debug)]
+pub struct SystemValue {
+    /// The value, in `unit`.
+    pub value: f64,
+    /// The unit the value is expressed in.
+    pub unit: &'static UnitInfo,
+    /// The name of the value's dimension.
+    pub dimension: &'static str,
+}
+
+impl fmt::Display for SystemValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subject = Subject::Dimension(self.dimension);
+        f.write_str(&format_number(self.value, f.precision(), subject))?;
+        if !self.unit.symbol.is_empty() {
+            write!(f, " {}", self.unit.symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Converts the quantity to the conventional unit of `system` for its dimension.
+    ///
+    /// Returns `None` if the system has no unit for the dimension.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::system::UnitSystem;
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let t = Temperature::from_unit::<Celsius>(100.0);
+    /// let f = t.in_system(UnitSystem::UsCustomary).unwrap();
+    /// assert_eq!((f.value, f.unit.symbol), (212.0, "°F"));
+    /// ```
+    pub fn in_system(&self, system: UnitSystem) -> Option<SystemValue> {
+        let symbol = system.unit_for(<U::Dimension as Dimension>::NAME)?;
+        let unit = <U::Dimension as Dimension>::find_unit(symbol)?;
+        Some(SystemValue {
+            value: (unit.from_base)(self.in_base()),
+            unit,
+            dimension: <U::Dimension as Dimension>::NAME,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Length, Meter};
+    use crate::unit_conversion::registry;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn picks_the_conventional_unit() {
+        let length = Length::from_unit::<Foot>(5280.0);
+        let si = length.in_system(UnitSystem::Si).unwrap();
+        assert_eq!(si.unit.symbol, "km");
+        assert!(approx(si.value, 1.609344, 1e-12));
+        let us = length.in_system(UnitSystem::UsCustomary).unwrap();
+        assert!(approx(us.value, 1.0, 1e-12));
+        let cgs = length.in_system(UnitSystem::Cgs).unwrap();
+        assert_eq!(cgs.unit.symbol, "cm");
+        assert_eq!(format!("{cgs:.0}"), "160934 cm");
+    }
+
+    #[test]
+    fn every_listed_unit_exists() {
+        for system in UnitSystem::ALL {
+            for dimension in registry::DIMENSIONS {
+                for symbol in system.units(dimension.name) {
+                    assert!(
+                        dimension.find_unit(symbol).is_some(),
+                        "{system}: no {} unit '{symbol}'",
+                        dimension.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_system_names() {
+        assert_eq!("Metric".parse::<UnitSystem>().unwrap(), UnitSystem::Si);
+        assert_eq!("US".parse::<UnitSystem>().unwrap(), UnitSystem::UsCustomary);
+        assert_eq!("cgs".parse::<UnitSystem>().unwrap(), UnitSystem::Cgs);
+        assert!("martian".parse::<UnitSystem>().is_err());
+        for system in UnitSystem::ALL {
+            assert_eq!(system.name().parse::<UnitSystem>().unwrap(), system);
+        }
+        assert!(UnitSystem::Imperial.contains("length", "ft"));
+        assert!(!UnitSystem::Imperial.contains("length", "m"));
+        assert_eq!(
+            Length::from_unit::<Meter>(1.0)
+                .in_system(UnitSystem::Cgs)
+                .unwrap()
+                .value,
+            100.0
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! # Provenance-Preserving Quantities
+//!
+//! Converting user input to base units straight away loses what the user typed: a
+//! distance entered as 0.9 miles comes back as 0.9000000000000001 miles after a round
+//! trip through meters, and a report can no longer say which unit the figure was given in.
+//! A [`TaggedQuantity`] keeps the raw value and the unit it was entered in alongside the
+//! converted [`Quantity`], so the input can be echoed back exactly while calculations
+//! use the quantity as usual.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Kilometer, Meter, Mile};
+//! use uom::unit_conversion::tagged::TaggedQuantity;
+//!
+//! let trip = TaggedQuantity::<Meter>::parse("5.0 mi").unwrap();
+//! let report = format!(
+//!     "entered as {trip:.1} ({:.3} km)",
+//!     trip.quantity().to_unit::<Kilometer>()
+//! );
+//! assert_eq!(report, "entered as 5.0 mi (8.047 km)");
+//! assert_eq!(trip.to_unit::<Mile>(), 5.0);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::unit_conversion::parse::{NumberLocale, split_number};
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::registry::find_unit_of;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError, UnitInfo};
+
+/// A quantity that remembers the value and unit it was entered in.
+///
+/// Displays as the entered value followed by the entered unit's symbol; the
+/// formatter's precision, or else the installed
+/// [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies to the value.
+pub struct TaggedQuantity<U: UnitConversion> {
+    quantity: Quantity<U>,
+    value: f64,
+    unit: &'static UnitInfo,
+}
+
+impl<U: UnitConversion> Clone for TaggedQuantity<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> Copy for TaggedQuantity<U> {}
+
+impl<U: UnitConversion> fmt::Debug for TaggedQuantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedQuantity")
+            .field("value", &self.value)
+            .field("unit", &self.unit.symbol)
+            .field("base", &self.quantity.in_base())
+            .finish()
+    }
+}
+
+/// Tagged quantities are equal when they were entered as the same value in the same
+/// unit; compare [`quantity`](TaggedQuantity::quantity) to ignore the provenance.
+impl<U: UnitConversion> PartialEq for TaggedQuantity<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.unit.symbol == other.unit.symbol
+    }
+}
+
+impl<U: UnitConversion> TaggedQuantity<U> {
+    /// Creates a quantity entered as `value` in unit `V`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Foot, Meter};
+    /// use uom::unit_conversion::tagged::TaggedQuantity;
+    ///
+    /// let height = TaggedQuantity::<Meter>::new::<Foot>(6.0);
+    /// assert_eq!(height.unit().symbol, "ft");
+    /// assert!((height.quantity().to_unit::<Meter>() - 1.8288).abs() < 1e-12);
+    /// ```
+    pub fn new<V>(value: f64) -> Self
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Self {
+            quantity: Quantity::from_unit::<V>(value),
+            value,
+            unit: const { &UnitInfo::of::<V>(&[]) },
+        }
+    }
+
+    /// Returns the converted quantity.
+    pub fn quantity(&self) -> Quantity<U> {
+        self.quantity
+    }
+
+    /// Returns the value as entered, in [`unit`](Self::unit).
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit the value was entered in.
+    pub fn unit(&self) -> &'static UnitInfo {
+        self.unit
+    }
+
+    /// Returns the value in unit `V`.
+    ///
+    /// If `V` is the unit the value was entered in, the entered value is returned
+    /// exactly rather than converted there and back.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter, Mile};
+    /// use uom::unit_conversion::tagged::TaggedQuantity;
+    ///
+    /// let entered = TaggedQuantity::<Meter>::new::<Mile>(0.9);
+    /// assert_eq!(entered.to_unit::<Mile>(), 0.9);
+    /// assert_eq!(Length::from_unit::<Mile>(0.9).to_unit::<Mile>(), 0.9000000000000001);
+    /// ```
+    pub fn to_unit<V>(&self) -> f64
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        if V::SYMBOL == self.unit.symbol {
+            self.value
+        } else {
+            self.quantity.to_unit::<V>()
+        }
+    }
+}
+
+impl<U: UnitConversion> TaggedQuantity<U>
+where
+    U::Dimension: Dimension,
+{
+    /// Parses a number followed by a unit of this dimension, remembering both.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] as described for [`Quantity::parse`]. Compound input such
+    /// as `5'11"` has no single input unit and is rejected with
+    /// [`UnitError::ParseError`].
+    pub fn parse(input: &str) -> Result<Self, UnitError> {
+        Self::parse_with(input, NumberLocale::PLAIN)
+    }
+
+    /// Parses a quantity whose number is written in the given [`NumberLocale`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] as described for [`TaggedQuantity::parse`].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::Meter;
+    /// use uom::unit_conversion::parse::NumberLocale;
+    /// use uom::unit_conversion::tagged::TaggedQuantity;
+    ///
+    /// let depth = TaggedQuantity::<Meter>::parse_with("2,5 km", NumberLocale::EUROPEAN).unwrap();
+    /// assert_eq!((depth.value(), depth.unit().symbol), (2.5, "km"));
+    /// ```
+    pub fn parse_with(input: &str, locale: NumberLocale) -> Result<Self, UnitError> {
+        // Validates the input and reports the same errors as untagged parsing
+        let quantity = Quantity::parse_with(input, locale)?;
+        let input = input.trim();
+        let single = split_number(input, locale)
+            .ok()
+            .and_then(|(value, unit)| Some((value, find_unit_of::<U::Dimension>(unit.trim())?)));
+        match single {
+            Some((value, unit)) => Ok(Self {
+                quantity,
+                value,
+                unit,
+            }),
+            None => Err(UnitError::ParseError {
+                input: input.to_string(),
+                reason: "expected a single number and unit",
+            }),
+        }
+    }
+}
+
+impl<U> fmt::Display for TaggedQuantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subject = Subject::Dimension(<U::Dimension as Dimension>::NAME);
+        f.write_str(&format_number(self.value, f.precision(), subject))?;
+        if !self.unit.symbol.is_empty() {
+            write!(f, " {}", self.unit.symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl<U: UnitConversion> FromStr for TaggedQuantity<U>
+where
+    U::Dimension: Dimension,
+{
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<U: UnitConversion> From<TaggedQuantity<U>> for Quantity<U> {
+    fn from(tagged: TaggedQuantity<U>) -> Self {
+        tagged.quantity
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Inch, Kilometer, Length, Meter, Mile};
+    use crate::unit_conversion::temperature::{Fahrenheit, Kelvin};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn keeps_the_entered_unit() {
+        let t: TaggedQuantity<Kelvin> = "98.6 °F".parse().unwrap();
+        assert_eq!(t.value(), 98.6);
+        assert_eq!(t.unit().symbol, "°F");
+        assert_eq!(t.to_unit::<Fahrenheit>(), 98.6);
+        assert!(approx(t.quantity().to_unit::<Kelvin>(), 310.15, 1e-9));
+        assert_eq!(format!("{t:.2}"), "98.60 °F");
+
+        let aliased: TaggedQuantity<Meter> = "3 miles".parse().unwrap();
+        assert_eq!(aliased.unit().symbol, "mi");
+        assert_eq!(aliased.to_string(), "3 mi");
+    }
+
+    #[test]
+    fn round_trips_without_drift() {
+        for value in [0.1, 0.9, 5.0, 26.2, 1e-7] {
+            let tagged = TaggedQuantity::<Meter>::new::<Mile>(value);
+            assert_eq!(tagged.to_unit::<Mile>(), value);
+            assert_eq!(
+                tagged.to_string().parse::<TaggedQuantity<Meter>>(),
+                Ok(tagged)
+            );
+        }
+        let tagged = TaggedQuantity::<Meter>::new::<Kilometer>(1.5);
+        assert_eq!(tagged.to_unit::<Meter>(), 1500.0);
+        let length: Length = tagged.into();
+        assert_eq!(length.to_unit::<Kilometer>(), 1.5);
+    }
+
+    #[test]
+    fn equality_includes_provenance() {
+        let feet = TaggedQuantity::<Meter>::new::<Foot>(1.0);
+        let inches = TaggedQuantity::<Meter>::new::<Inch>(12.0);
+        assert_ne!(feet, inches);
+        assert!(approx(
+            feet.quantity().in_base(),
+            inches.quantity().in_base(),
+            1e-15
+        ));
+    }
+
+    #[test]
+    fn rejects_what_parse_rejects() {
+        assert!(TaggedQuantity::<Meter>::parse("5 kg").is_err());
+        assert!(TaggedQuantity::<Meter>::parse("five mi").is_err());
+        assert_eq!(
+            TaggedQuantity::<Meter>::parse("5 ft 11 in"),
+            Err(UnitError::ParseError {
+                input: "5 ft 11 in".to_string(),
+                reason: "expected a single number and unit",
+            })
+        );
+        assert!(Length::parse("5 ft 11 in").is_ok());
+    }
+}
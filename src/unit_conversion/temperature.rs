@@ -8,6 +8,8 @@
 //! - **Kelvin (K)**: The base unit, absolute temperature scale
 //! - **Celsius (°C)**: Water freezes at 0°C, boils at 100°C at standard pressure
 //! - **Fahrenheit (°F)**: Water freezes at 32°F, boils at 212°F at standard pressure
+//! - **Delisle (°De)**, **Newton (°N)**, **Rømer (°Rø)**: Historic 18th-century scales,
+//!   found in early instrument records
 //!
 //! ## Examples
 //!
@@ -23,14 +25,28 @@
 //! let temp = Temperature::from_unit::<Fahrenheit>(68.0); // Room temperature
 //! assert_eq!(temp.to_unit::<Celsius>(), 20.0);
 //! ```
+//!
+//! ## Color Temperature
+//!
+//! The color temperature of light is often given in mired (micro reciprocal degrees),
+//! `10⁶ / K`. Mired is reciprocal rather than a scale with an offset, so a difference
+//! in mired is not a difference in kelvin; it is reached through
+//! [`Temperature::to_mired`] and [`Temperature::from_mired`] instead of being a unit of
+//! the dimension.
 
-use crate::unit_conversion::{Quantity, UnitConversion};
+use crate::unit_conversion::{Dimension, NonNegative, Quantity, UnitConversion, UnitInfo};
 
 /// Constants for temperature conversions
 const CELSIUS_TO_KELVIN_OFFSET: f64 = 273.15;
 const FAHRENHEIT_FREEZING_POINT: f64 = 32.0;
 const FAHRENHEIT_DEGREE_RATIO: f64 = 9.0 / 5.0;
 const CELSIUS_DEGREE_RATIO: f64 = 5.0 / 9.0;
+const MIRED_KELVIN_PRODUCT: f64 = 1e6;
+const WATER_BOILING_POINT_KELVIN: f64 = 373.15;
+const DELISLE_DEGREE_RATIO: f64 = 2.0 / 3.0;
+const NEWTON_DEGREE_RATIO: f64 = 100.0 / 33.0;
+const ROMER_FREEZING_POINT: f64 = 7.5;
+const ROMER_DEGREE_RATIO: f64 = 40.0 / 21.0;
 
 /// Marker type for the temperature dimension.
 ///
@@ -38,6 +54,21 @@ const CELSIUS_DEGREE_RATIO: f64 = 5.0 / 9.0;
 /// like length or mass.
 pub enum TemperatureDimension {}
 
+impl Dimension for TemperatureDimension {
+    const NAME: &'static str = "temperature";
+    const ID: u8 = 2;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Kelvin>(&["kelvin", "kelvins"]),
+        UnitInfo::of::<Celsius>(&["C", "degC", "celsius", "centigrade"]),
+        UnitInfo::of::<Fahrenheit>(&["F", "degF", "fahrenheit"]),
+        UnitInfo::of::<Delisle>(&["De", "degDe", "delisle"]),
+        UnitInfo::of::<NewtonDegree>(&["degN"]),
+        UnitInfo::of::<Romer>(&["Ro", "Rø", "degRo", "romer", "rømer"]),
+    ];
+}
+
+impl NonNegative for TemperatureDimension {}
+
 /// A temperature quantity that stores values in Kelvin internally.
 ///
 /// This is the main type for working with temperatures. It provides type-safe
@@ -82,6 +113,9 @@ impl UnitConversion for Kelvin {
     }
 
     const SYMBOL: &'static str = "K";
+    const NAME: &'static str = "kelvin";
+    const PLURAL: &'static str = "kelvins";
+    const PHYSICAL_RANGE: (f64, f64) = (0.0, f64::INFINITY);
 }
 
 /// Celsius temperature unit.
@@ -111,6 +145,8 @@ impl UnitConversion for Celsius {
     }
 
     const SYMBOL: &'static str = "°C";
+    const NAME: &'static str = "degree Celsius";
+    const PLURAL: &'static str = "degrees Celsius";
 }
 
 /// Fahrenheit temperature unit.
@@ -140,6 +176,156 @@ impl UnitConversion for Fahrenheit {
     }
 
     const SYMBOL: &'static str = "°F";
+    const NAME: &'static str = "degree Fahrenheit";
+    const PLURAL: &'static str = "degrees Fahrenheit";
+}
+
+/// Delisle temperature unit.
+///
+/// An inverted scale: water boils at 0 °De and freezes at 150 °De, and the number
+/// grows as it gets colder. Each degree is two thirds of a kelvin.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::temperature::{Celsius, Delisle, Temperature};
+///
+/// let freezing = Temperature::from_unit::<Delisle>(150.0);
+/// assert!((freezing.to_unit::<Celsius>() - 0.0).abs() < 1e-12);
+/// ```
+pub struct Delisle;
+
+impl UnitConversion for Delisle {
+    type Dimension = TemperatureDimension;
+
+    fn convert_to(value: f64) -> f64 {
+        WATER_BOILING_POINT_KELVIN - value * DELISLE_DEGREE_RATIO
+    }
+
+    fn convert_from(value: f64) -> f64 {
+        (WATER_BOILING_POINT_KELVIN - value) / DELISLE_DEGREE_RATIO
+    }
+
+    const SYMBOL: &'static str = "°De";
+    const NAME: &'static str = "degree Delisle";
+    const PLURAL: &'static str = "degrees Delisle";
+}
+
+/// Newton temperature unit.
+///
+/// Water freezes at 0 °N and boils at 33 °N. Named `NewtonDegree` to keep it apart
+/// from the newton, the unit of force.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::temperature::{Celsius, NewtonDegree, Temperature};
+///
+/// let boiling = Temperature::from_unit::<NewtonDegree>(33.0);
+/// assert!((boiling.to_unit::<Celsius>() - 100.0).abs() < 1e-12);
+/// ```
+pub struct NewtonDegree;
+
+impl UnitConversion for NewtonDegree {
+    type Dimension = TemperatureDimension;
+
+    fn convert_to(value: f64) -> f64 {
+        value * NEWTON_DEGREE_RATIO + CELSIUS_TO_KELVIN_OFFSET
+    }
+
+    fn convert_from(value: f64) -> f64 {
+        (value - CELSIUS_TO_KELVIN_OFFSET) / NEWTON_DEGREE_RATIO
+    }
+
+    const SYMBOL: &'static str = "°N";
+    const NAME: &'static str = "degree Newton";
+    const PLURAL: &'static str = "degrees Newton";
+}
+
+/// Rømer temperature unit.
+///
+/// Water freezes at 7.5 °Rø and boils at 60 °Rø.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::temperature::{Celsius, Romer, Temperature};
+///
+/// let freezing = Temperature::from_unit::<Romer>(7.5);
+/// assert!((freezing.to_unit::<Celsius>() - 0.0).abs() < 1e-12);
+/// ```
+pub struct Romer;
+
+impl UnitConversion for Romer {
+    type Dimension = TemperatureDimension;
+
+    fn convert_to(value: f64) -> f64 {
+        (value - ROMER_FREEZING_POINT) * ROMER_DEGREE_RATIO + CELSIUS_TO_KELVIN_OFFSET
+    }
+
+    fn convert_from(value: f64) -> f64 {
+        (value - CELSIUS_TO_KELVIN_OFFSET) / ROMER_DEGREE_RATIO + ROMER_FREEZING_POINT
+    }
+
+    const SYMBOL: &'static str = "°Rø";
+    const NAME: &'static str = "degree Rømer";
+    const PLURAL: &'static str = "degrees Rømer";
+}
+
+impl Quantity<Kelvin> {
+    /// Creates a color temperature from a value in mired (micro reciprocal degrees).
+    ///
+    /// A color temperature of `T` kelvin is `10⁶ / T` mired. Equal steps in mired are
+    /// roughly equal steps in perceived color, so lighting gels and camera filters are
+    /// rated by the mired shift they produce. The scale is reciprocal: 0 mired is an
+    /// infinite color temperature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::temperature::{Temperature, Kelvin};
+    ///
+    /// let tungsten = Temperature::from_mired(312.5);
+    /// assert!((tungsten.to_unit::<Kelvin>() - 3200.0).abs() < 1e-9);
+    /// ```
+    pub fn from_mired(mired: f64) -> Self {
+        Self::from_unit::<Kelvin>(MIRED_KELVIN_PRODUCT / mired)
+    }
+
+    /// Returns this color temperature in mired, `10⁶ / K`; 0 K is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::temperature::{Temperature, Kelvin};
+    ///
+    /// let daylight = Temperature::from_unit::<Kelvin>(5500.0);
+    /// assert!((daylight.to_mired() - 181.818).abs() < 1e-3);
+    /// ```
+    pub fn to_mired(self) -> f64 {
+        MIRED_KELVIN_PRODUCT / self.to_unit::<Kelvin>()
+    }
+
+    /// Returns the mired shift needed to turn light of this color temperature into
+    /// light of the `target` color temperature.
+    ///
+    /// A positive shift warms the light (an amber or CTO filter) and a negative shift
+    /// cools it (a blue or CTB filter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uom::unit_conversion::temperature::{Temperature, Kelvin};
+    ///
+    /// // Balancing daylight to tungsten film needs an amber filter of about +131 mired
+    /// let daylight = Temperature::from_unit::<Kelvin>(5500.0);
+    /// let tungsten = Temperature::from_unit::<Kelvin>(3200.0);
+    /// assert!((daylight.mired_shift_to(tungsten) - 130.68).abs() < 0.01);
+    /// assert!((tungsten.mired_shift_to(daylight) + 130.68).abs() < 0.01);
+    /// ```
+    pub fn mired_shift_to(self, target: Temperature) -> f64 {
+        target.to_mired() - self.to_mired()
+    }
 }
 
 #[cfg(test)]
@@ -222,10 +408,124 @@ mod tests {
         assert!(approx(temp.to_unit::<Fahrenheit>(), -459.67, 1e-8));
     }
 
+    #[test]
+    fn mired_conversions() {
+        let temp = Temperature::from_unit::<Kelvin>(4000.0);
+        assert!(approx(temp.to_mired(), 250.0, 1e-12));
+
+        let temp = Temperature::from_mired(100.0);
+        assert!(approx(temp.to_unit::<Kelvin>(), 10_000.0, 1e-9));
+
+        let temp = Temperature::from_unit::<Kelvin>(0.0);
+        assert_eq!(temp.to_mired(), f64::INFINITY);
+
+        // Not a unit of the dimension, so span-based helpers never see it
+        assert!(TemperatureDimension::find_unit("mired").is_none());
+    }
+
+    #[test]
+    fn mired_shift_is_antisymmetric() {
+        let warm = Temperature::from_unit::<Kelvin>(2700.0);
+        let cool = Temperature::from_unit::<Kelvin>(6500.0);
+        assert!(approx(warm.mired_shift_to(cool), -216.524, 1e-3));
+        assert!(approx(cool.mired_shift_to(warm), 216.524, 1e-3));
+        assert_eq!(warm.mired_shift_to(warm), 0.0);
+    }
+
+    #[test]
+    fn historic_scales() {
+        // (°C, °De, °N, °Rø)
+        for (c, de, n, ro) in [
+            (-273.15, 559.725, -90.1395, -135.90375),
+            (0.0, 150.0, 0.0, 7.5),
+            (37.0, 94.5, 12.21, 26.925),
+            (100.0, 0.0, 33.0, 60.0),
+        ] {
+            let t = Temperature::from_unit::<Celsius>(c);
+            assert!(approx(t.to_unit::<Delisle>(), de, 1e-9));
+            assert!(approx(t.to_unit::<NewtonDegree>(), n, 1e-9));
+            assert!(approx(t.to_unit::<Romer>(), ro, 1e-9));
+            assert!(approx(
+                Temperature::from_unit::<Delisle>(de).to_unit::<Celsius>(),
+                c,
+                1e-9
+            ));
+            assert!(approx(
+                Temperature::from_unit::<NewtonDegree>(n).to_unit::<Celsius>(),
+                c,
+                1e-9
+            ));
+            assert!(approx(
+                Temperature::from_unit::<Romer>(ro).to_unit::<Celsius>(),
+                c,
+                1e-9
+            ));
+        }
+        // Delisle runs backwards: hotter is a smaller number
+        let warm = Temperature::from_unit::<Celsius>(30.0).to_unit::<Delisle>();
+        let cold = Temperature::from_unit::<Celsius>(10.0).to_unit::<Delisle>();
+        assert!(warm < cold);
+        assert_eq!(
+            TemperatureDimension::find_unit("rømer").unwrap().symbol,
+            "°Rø"
+        );
+    }
+
     #[test]
     fn temperature_symbols() {
         assert_eq!(Kelvin::SYMBOL, "K");
         assert_eq!(Celsius::SYMBOL, "°C");
         assert_eq!(Fahrenheit::SYMBOL, "°F");
+        assert_eq!(Delisle::SYMBOL, "°De");
+        assert_eq!(NewtonDegree::SYMBOL, "°N");
+        assert_eq!(Romer::SYMBOL, "°Rø");
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict_physics", debug_assertions))]
+    #[should_panic(expected = "PHYSICAL_RANGE")]
+    fn strict_physics_rejects_negative_kelvin() {
+        let _ = Temperature::from_unit::<Celsius>(-300.0);
+    }
+
+    #[test]
+    fn differences_may_go_negative() {
+        let warm = Temperature::from_unit::<Celsius>(20.0);
+        let cold = Temperature::from_unit::<Celsius>(5.0);
+        let drop = cold - warm;
+        assert!(approx(drop.to_unit::<Kelvin>(), -15.0, 1e-9));
+        assert!(approx((drop / 2.0).to_unit::<Kelvin>(), -7.5, 1e-9));
+        assert!(approx((-drop * 2.0).to_unit::<Kelvin>(), 30.0, 1e-9));
+        let mut cooled = warm;
+        cooled -= drop * -1.0;
+        assert!(approx(cooled.to_unit::<Celsius>(), 5.0, 1e-9));
+    }
+
+    #[test]
+    #[cfg(feature = "strict_physics")]
+    fn strict_physics_rejects_negative_kelvin_without_panicking_when_fallible() {
+        use crate::unit_conversion::UnitError;
+        use crate::unit_conversion::parse::NumberLocale;
+
+        let below_zero = UnitError::OutOfRange {
+            value: -5.0,
+            min: 0.0,
+            max: f64::INFINITY,
+        };
+        assert_eq!(Temperature::parse("-5 K"), Err(below_zero.clone()));
+        assert_eq!(Temperature::try_from_unit::<Kelvin>(-5.0), Err(below_zero));
+        assert!(matches!(
+            Temperature::parse_with("-300,5 °C", NumberLocale::EUROPEAN),
+            Err(UnitError::OutOfRange { value, .. }) if approx(value, -27.35, 1e-9)
+        ));
+        assert!(Temperature::parse("0 K").is_ok());
+        assert!(Temperature::try_from_unit::<Fahrenheit>(-459.67).is_ok());
+    }
+
+    #[test]
+    fn strict_physics_tolerates_rounding_at_absolute_zero() {
+        let zero = Temperature::from_unit::<Fahrenheit>(-459.67);
+        assert!(approx(zero.to_unit::<Kelvin>(), 0.0, 1e-12));
+        let _ = Temperature::from_unit::<Romer>(-135.90375);
     }
 }
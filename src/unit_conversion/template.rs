@@ -0,0 +1,266 @@
+//! # Template Formatting
+//!
+//! [`format_qty`] and [`Quantity::format_in`] fill a pattern string with parts of a
+//! quantity, so UI code can lay out a value once for every dimension instead of
+//! assembling strings by hand:
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Foot, Length};
+//! use uom::unit_conversion::template::format_qty;
+//!
+//! let height = Length::from_unit::<Foot>(6.0);
+//! let text = height.format_in::<Foot>("{value:.1} {symbol} ({base:.2} {base_symbol})");
+//! assert_eq!(text.unwrap(), "6.0 ft (1.83 m)");
+//!
+//! // `format_qty` uses the quantity's own unit, the base unit for `Length`
+//! assert_eq!(format_qty("{value:.3} {name}", height).unwrap(), "1.829 meters");
+//! ```
+//!
+//! ## Placeholders
+//!
+//! | Placeholder     | Replaced with                                              |
+//! |-----------------|------------------------------------------------------------|
+//! | `{value}`       | The value in the display unit                              |
+//! | `{symbol}`      | The display unit's symbol                                  |
+//! | `{name}`        | The unit name, singular or plural to agree with `{value}`  |
+//! | `{base}`        | The value in the dimension's base unit                     |
+//! | `{base_symbol}` | The base unit's symbol                                     |
+//! | `{dimension}`   | The dimension name, such as `length`                       |
+//!
+//! Each placeholder accepts a subset of the standard format spec after a colon:
+//! an optional alignment (`<`, `>` or `^`), an optional width and, for the numeric
+//! placeholders, an optional `.precision`. `{{` and `}}` write literal braces.
+//!
+//! `{name}` agrees with the most recent `{value}` as it was written, or with the
+//! unformatted value if the template has no `{value}` before it.
+
+use crate::unit_conversion::names::unit_name;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError};
+
+/// Formats `quantity` according to `template`, with `{value}` and `{symbol}` in the
+/// quantity's own unit `U`.
+///
+/// For the dimension aliases such as `Length`, `U` is the base unit; use
+/// [`Quantity::format_in`] to show another unit. See the
+/// [module documentation](self) for the placeholders and format specs.
+///
+/// # Errors
+///
+/// Returns [`UnitError::ParseError`] if the template has an unknown placeholder, an
+/// unclosed or unmatched brace, or a format spec that cannot be read.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
+/// use uom::unit_conversion::temperature::{Celsius, Temperature};
+/// use uom::unit_conversion::template::format_qty;
+///
+/// let t = Temperature::from_unit::<Celsius>(1.0);
+/// assert_eq!(format_qty("{value:.2} {name}", t).unwrap(), "274.15 kelvins");
+/// assert_eq!(format_qty("[{symbol:>3}]", t).unwrap(), "[  K]");
+/// assert!(format_qty("{kelvin}", t).is_err());
+/// ```
+pub fn format_qty<U>(template: &str, quantity: Quantity<U>) -> Result<String, UnitError>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    quantity.format_in::<U>(template)
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Formats this quantity according to `template`, with `{value}`, `{symbol}` and
+    /// `{name}` in unit `V`.
+    ///
+    /// # Errors
+    ///
+    /// Fails on the same malformed templates as [`format_qty`].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+    #[cfg_attr(feature = "temperature", doc = "```")]
+    /// use uom::unit_conversion::temperature::{Celsius, Temperature};
+    ///
+    /// let t = Temperature::from_unit::<Celsius>(1.0);
+    /// assert_eq!(t.format_in::<Celsius>("{value} {name}").unwrap(), "1 degree Celsius");
+    /// ```
+    pub fn format_in<V>(&self, template: &str) -> Result<String, UnitError>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        render::<V, U::Dimension>(template, self.to_unit::<V>(), self.in_base())
+    }
+}
+
+fn render<V, D>(template: &str, value: f64, base: f64) -> Result<String, UnitError>
+where
+    V: UnitConversion,
+    D: Dimension,
+{
+    let fail = |reason| UnitError::ParseError {
+        input: template.to_string(),
+        reason,
+    };
+    let base_symbol = D::UNITS.first().map_or("", |unit| unit.symbol);
+    let mut value_text = value.to_string();
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(at) = rest.find(['{', '}']) {
+        out.push_str(&rest[..at]);
+        let brace = rest.as_bytes()[at];
+        rest = &rest[at + 1..];
+        if brace == b'}' {
+            rest = rest
+                .strip_prefix('}')
+                .ok_or_else(|| fail("unmatched '}'"))?;
+            out.push('}');
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix('{') {
+            rest = after;
+            out.push('{');
+            continue;
+        }
+        let end = rest.find('}').ok_or_else(|| fail("unclosed placeholder"))?;
+        let (key, spec) = match rest[..end].split_once(':') {
+            Some((key, spec)) => (
+                key,
+                Spec::parse(spec).ok_or_else(|| fail("invalid format spec"))?,
+            ),
+            None => (&rest[..end], Spec::default()),
+        };
+        rest = &rest[end + 1..];
+
+        let text = match key {
+            "value" => {
+                value_text = spec.number(value);
+                value_text.clone()
+            }
+            "base" => spec.number(base),
+            "symbol" => V::SYMBOL.to_string(),
+            "name" => unit_name::<V>(&value_text).to_string(),
+            "base_symbol" => base_symbol.to_string(),
+            "dimension" => D::NAME.to_string(),
+            _ => return Err(fail("unknown placeholder")),
+        };
+        spec.pad(&text, &mut out);
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// The parsed `[align][width][.precision]` part of a placeholder.
+#[derive(Default)]
+struct Spec {
+    align: Option<char>,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    fn parse(spec: &str) -> Option<Self> {
+        let mut parsed = Self::default();
+        let mut rest = spec;
+        if let Some(align @ ('<' | '>' | '^')) = rest.chars().next() {
+            parsed.align = Some(align);
+            rest = &rest[1..];
+        }
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (rest, None),
+        };
+        if !width.is_empty() {
+            parsed.width = width.parse().ok()?;
+        }
+        if let Some(precision) = precision {
+            parsed.precision = Some(precision.parse().ok()?);
+        }
+        Some(parsed)
+    }
+
+    fn number(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        }
+    }
+
+    /// Appends `text` to `out`, padded to the width. Numbers and text both align left
+    /// unless the spec says otherwise.
+    fn pad(&self, text: &str, out: &mut String) {
+        let fill = self.width.saturating_sub(text.chars().count());
+        let (before, after) = match self.align {
+            Some('>') => (fill, 0),
+            Some('^') => (fill / 2, fill - fill / 2),
+            _ => (0, fill),
+        };
+        out.extend(std::iter::repeat_n(' ', before));
+        out.push_str(text);
+        out.extend(std::iter::repeat_n(' ', after));
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Foot, Kilometer, Length};
+    use crate::unit_conversion::temperature::{Fahrenheit, Temperature};
+
+    #[test]
+    fn fills_every_placeholder() {
+        let q = Length::from_unit::<Kilometer>(1.5);
+        let text = q
+            .format_in::<Kilometer>("{value} {symbol} {name} = {base} {base_symbol} ({dimension})");
+        assert_eq!(text.unwrap(), "1.5 km kilometers = 1500 m (length)");
+    }
+
+    #[test]
+    fn applies_precision_and_width() {
+        let t = Temperature::from_unit::<Fahrenheit>(98.6);
+        assert_eq!(
+            t.format_in::<Fahrenheit>("{value:.0}{symbol}").unwrap(),
+            "99°F"
+        );
+        assert_eq!(format_qty("|{base:>8.2}|", t).unwrap(), "|  310.15|");
+        assert_eq!(format_qty("|{symbol:^4}|", t).unwrap(), "| K  |");
+        assert_eq!(format_qty("|{symbol:3}|", t).unwrap(), "|K  |");
+    }
+
+    #[test]
+    fn name_agrees_with_written_value() {
+        let q = Length::from_unit::<Foot>(1.04);
+        assert_eq!(q.format_in::<Foot>("{value:.0} {name}").unwrap(), "1 foot");
+        assert_eq!(
+            q.format_in::<Foot>("{value:.2} {name}").unwrap(),
+            "1.04 feet"
+        );
+        assert_eq!(q.format_in::<Foot>("{name}").unwrap(), "feet");
+    }
+
+    #[test]
+    fn escapes_braces() {
+        let q = Length::from_unit::<Foot>(2.0);
+        assert_eq!(q.format_in::<Foot>("{{{value}}}").unwrap(), "{2}");
+    }
+
+    #[test]
+    fn rejects_bad_templates() {
+        let q = Length::from_unit::<Foot>(2.0);
+        let reason = |template| match format_qty(template, q) {
+            Err(UnitError::ParseError { reason, .. }) => reason,
+            other => panic!("unexpected result: {other:?}"),
+        };
+        assert_eq!(reason("{volts}"), "unknown placeholder");
+        assert_eq!(reason("{value"), "unclosed placeholder");
+        assert_eq!(reason("value}"), "unmatched '}'");
+        assert_eq!(reason("{value:.x}"), "invalid format spec");
+    }
+}
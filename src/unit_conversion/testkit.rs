@@ -0,0 +1,400 @@
+//! # Mock Sensor Data
+//!
+//! Systems that consume this crate's types need realistic input for integration
+//! tests: a thermostat that should ride out sensor noise, a fleet tracker that should
+//! cope with GPS jitter. The generators here produce such streams as typed quantities,
+//! so a test exercises the same types production code receives:
+//!
+//! - [`TemperatureRamp`] moves linearly between two temperatures with Gaussian noise.
+//! - [`GpsTrack`] follows a vehicle wandering from a starting point, reporting noisy
+//!   positions with its speed and heading.
+//!
+//! Both are iterators and deterministic: the same seed gives the same stream on every
+//! run and platform, so a failing test can be replayed. Use `with_seed` to vary it.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::geo::{Latitude, Longitude};
+//! use uom::unit_conversion::speed::{KilometerPerHour, Speed};
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//! use uom::unit_conversion::testkit::{GpsTrack, TemperatureRamp};
+//!
+//! let warm_up = TemperatureRamp::new(
+//!     Temperature::from_unit::<Celsius>(18.0),
+//!     Temperature::from_unit::<Celsius>(22.0),
+//!     60,
+//! )
+//! .with_noise(0.2);
+//! let readings: Vec<Temperature> = warm_up.collect();
+//! assert_eq!(readings.len(), 60);
+//!
+//! let drive = GpsTrack::new(
+//!     Latitude::from_degrees(52.52).unwrap(),
+//!     Longitude::from_degrees(13.405).unwrap(),
+//! )
+//! .with_speed(Speed::from_unit::<KilometerPerHour>(50.0))
+//! .with_seed(7);
+//! for fix in drive.take(100) {
+//!     assert!((fix.latitude.degrees() - 52.52).abs() < 0.1);
+//! }
+//! ```
+
+use std::f64::consts::{PI, TAU};
+
+use crate::unit_conversion::Quantity;
+use crate::unit_conversion::angle::{Angle, Degree};
+use crate::unit_conversion::geo::{Latitude, Longitude};
+use crate::unit_conversion::length::Length;
+use crate::unit_conversion::speed::Speed;
+use crate::unit_conversion::temperature::Temperature;
+use crate::unit_conversion::time::Time;
+
+/// The seed used when none is given.
+const DEFAULT_SEED: u64 = 0x5EED;
+
+/// Mean radius of the Earth in meters, for moving a position over short distances.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// The latitude a track turns back at, keeping clear of the poles where longitude
+/// steps diverge.
+const MAX_LATITUDE: f64 = 85.0;
+
+/// SplitMix64, a small generator whose output depends only on the seed.
+#[derive(Clone, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform value in `[0, 1)`.
+    fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a normally distributed value with the given standard deviation, by the
+    /// Box-Muller transform.
+    fn gaussian(&mut self, std_dev: f64) -> f64 {
+        if std_dev == 0.0 {
+            return 0.0;
+        }
+        let u = 1.0 - self.uniform();
+        let v = self.uniform();
+        std_dev * (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+    }
+}
+
+/// A stream of temperatures moving linearly from a start to an end value, with
+/// Gaussian sensor noise.
+///
+/// Yields exactly the requested number of samples, the first at the start temperature
+/// and the last at the end temperature, before noise. Noisy values never fall below
+/// absolute zero.
+#[derive(Clone, Debug)]
+pub struct TemperatureRamp {
+    rng: Rng,
+    start: f64,
+    end: f64,
+    noise: f64,
+    samples: usize,
+    index: usize,
+}
+
+impl TemperatureRamp {
+    /// Creates a noise-free ramp of `samples` temperatures from `start` to `end`.
+    pub fn new(start: Temperature, end: Temperature, samples: usize) -> Self {
+        Self {
+            rng: Rng(DEFAULT_SEED),
+            start: start.in_base(),
+            end: end.in_base(),
+            noise: 0.0,
+            samples,
+            index: 0,
+        }
+    }
+
+    /// Adds Gaussian noise with the given standard deviation in kelvins, which is the
+    /// same size as degrees Celsius.
+    pub fn with_noise(mut self, std_dev: f64) -> Self {
+        self.noise = std_dev.abs();
+        self
+    }
+
+    /// Sets the seed of the noise.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Rng(seed);
+        self
+    }
+}
+
+impl Iterator for TemperatureRamp {
+    type Item = Temperature;
+
+    fn next(&mut self) -> Option<Temperature> {
+        if self.index >= self.samples {
+            return None;
+        }
+        let fraction = match self.samples {
+            1 => 0.0,
+            n => self.index as f64 / (n - 1) as f64,
+        };
+        self.index += 1;
+        let ideal = self.start + (self.end - self.start) * fraction;
+        let noisy = ideal + self.rng.gaussian(self.noise);
+        Some(Quantity::from_base(noisy.max(0.0)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.samples - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for TemperatureRamp {}
+
+/// One report of a [`GpsTrack`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GpsFix {
+    /// Time since the first fix.
+    pub elapsed: Time,
+    /// The reported latitude, including position noise.
+    pub latitude: Latitude,
+    /// The reported longitude, including position noise.
+    pub longitude: Longitude,
+    /// Speed over ground.
+    pub speed: Speed,
+    /// Course over ground, clockwise from true north in `[0°, 360°)`.
+    pub heading: Angle,
+}
+
+/// An endless stream of GPS fixes from a vehicle wandering away from a starting point.
+///
+/// The vehicle's speed drifts around a cruising speed and its heading turns a little at
+/// each fix; the reported position adds Gaussian noise to the true one. Positions are
+/// advanced on a spherical Earth, which is accurate to well within GPS noise over the
+/// short intervals between fixes. Use [`Iterator::take`] to bound the stream.
+#[derive(Clone, Debug)]
+pub struct GpsTrack {
+    rng: Rng,
+    latitude: f64,
+    longitude: f64,
+    heading: f64,
+    speed: f64,
+    cruise: f64,
+    interval: f64,
+    position_noise: f64,
+    elapsed: f64,
+}
+
+impl GpsTrack {
+    /// Creates a track starting at the given position, heading north at 10 m/s with a
+    /// fix every second and 3 m of position noise.
+    pub fn new(latitude: Latitude, longitude: Longitude) -> Self {
+        Self {
+            rng: Rng(DEFAULT_SEED),
+            latitude: latitude.degrees(),
+            longitude: longitude.degrees(),
+            heading: 0.0,
+            speed: 10.0,
+            cruise: 10.0,
+            interval: 1.0,
+            position_noise: 3.0,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Sets the speed the vehicle starts at and drifts around.
+    pub fn with_speed(mut self, cruise: Speed) -> Self {
+        self.cruise = cruise.in_base().abs();
+        self.speed = self.cruise;
+        self
+    }
+
+    /// Sets the initial heading, clockwise from true north.
+    pub fn with_heading(mut self, heading: Angle) -> Self {
+        self.heading = heading.in_base().rem_euclid(TAU);
+        self
+    }
+
+    /// Sets the time between fixes.
+    pub fn with_interval(mut self, interval: Time) -> Self {
+        self.interval = interval.in_base().abs();
+        self
+    }
+
+    /// Sets the standard deviation of the reported position around the true one.
+    pub fn with_position_noise(mut self, std_dev: Length) -> Self {
+        self.position_noise = std_dev.in_base().abs();
+        self
+    }
+
+    /// Sets the seed of the drift and noise.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Rng(seed);
+        self
+    }
+
+    /// Returns the position `north` and `east` meters from the given one, in degrees.
+    fn offset(latitude: f64, longitude: f64, north: f64, east: f64) -> (f64, f64) {
+        let latitude = latitude + (north / EARTH_RADIUS).to_degrees();
+        let longitude =
+            longitude + (east / (EARTH_RADIUS * latitude.to_radians().cos())).to_degrees();
+        (latitude, (longitude + 180.0).rem_euclid(360.0) - 180.0)
+    }
+
+    /// Moves the true position one interval along the current heading, then lets the
+    /// speed and heading drift.
+    fn advance(&mut self) {
+        let distance = self.speed * self.interval;
+        let (latitude, longitude) = Self::offset(
+            self.latitude,
+            self.longitude,
+            distance * self.heading.cos(),
+            distance * self.heading.sin(),
+        );
+        self.longitude = longitude;
+        self.latitude = latitude;
+        if self.latitude.abs() > MAX_LATITUDE {
+            self.latitude = MAX_LATITUDE.copysign(self.latitude);
+            self.heading = PI - self.heading;
+        }
+        let drift = self.interval.sqrt();
+        self.speed = (self.speed + self.rng.gaussian(0.05 * self.cruise * drift)
+            - 0.1 * (self.speed - self.cruise))
+            .max(0.0);
+        self.heading = (self.heading + self.rng.gaussian(0.05 * drift)).rem_euclid(TAU);
+    }
+}
+
+impl Iterator for GpsTrack {
+    type Item = GpsFix;
+
+    fn next(&mut self) -> Option<GpsFix> {
+        let (latitude, longitude) = Self::offset(
+            self.latitude,
+            self.longitude,
+            self.rng.gaussian(self.position_noise),
+            self.rng.gaussian(self.position_noise),
+        );
+        let fix = GpsFix {
+            elapsed: Quantity::from_base(self.elapsed),
+            latitude: Latitude::from_degrees(latitude.clamp(-90.0, 90.0))
+                .expect("latitude is clamped to its range"),
+            longitude: Longitude::from_degrees(longitude)
+                .expect("longitude is wrapped to its range"),
+            speed: Quantity::from_base(self.speed),
+            heading: Angle::from_unit::<Degree>(self.heading.to_degrees()),
+        };
+        self.advance();
+        self.elapsed += self.interval;
+        Some(fix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::Meter;
+    use crate::unit_conversion::speed::MeterPerSecond;
+    use crate::unit_conversion::temperature::Celsius;
+    use crate::unit_conversion::time::Second;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::from_unit::<Celsius>(value)
+    }
+
+    fn track() -> GpsTrack {
+        GpsTrack::new(
+            Latitude::from_degrees(48.8566).unwrap(),
+            Longitude::from_degrees(2.3522).unwrap(),
+        )
+    }
+
+    #[test]
+    fn ramps_between_the_endpoints() {
+        let ramp = TemperatureRamp::new(celsius(10.0), celsius(20.0), 11);
+        assert_eq!(ramp.len(), 11);
+        let values: Vec<f64> = ramp.map(|t| t.to_unit::<Celsius>()).collect();
+        assert!(approx(values[0], 10.0, 1e-9));
+        assert!(approx(values[5], 15.0, 1e-9));
+        assert!(approx(values[10], 20.0, 1e-9));
+        assert_eq!(
+            TemperatureRamp::new(celsius(5.0), celsius(9.0), 0).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn noise_has_the_requested_spread() {
+        let n = 10_000;
+        let noise: Vec<f64> = TemperatureRamp::new(celsius(20.0), celsius(20.0), n)
+            .with_noise(0.5)
+            .map(|t| t.to_unit::<Celsius>() - 20.0)
+            .collect();
+        let mean = noise.iter().sum::<f64>() / n as f64;
+        let std_dev = (noise.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64).sqrt();
+        assert!(approx(mean, 0.0, 0.02));
+        assert!(approx(std_dev, 0.5, 0.02));
+
+        let mut cold = TemperatureRamp::new(
+            Temperature::from_base(0.5),
+            Temperature::from_base(0.5),
+            100,
+        )
+        .with_noise(5.0);
+        assert!(cold.all(|t| t.in_base() >= 0.0));
+    }
+
+    #[test]
+    fn seeds_make_streams_reproducible() {
+        let ramp = TemperatureRamp::new(celsius(0.0), celsius(1.0), 20).with_noise(0.1);
+        assert!(ramp.clone().eq(ramp.clone()));
+        assert!(!ramp.clone().eq(ramp.with_seed(1)));
+        assert!(track().take(50).eq(track().take(50)));
+        assert!(!track().take(50).eq(track().with_seed(1).take(50)));
+    }
+
+    #[test]
+    fn tracks_move_at_the_reported_speed() {
+        let fixes: Vec<GpsFix> = track()
+            .with_speed(Speed::from_unit::<MeterPerSecond>(20.0))
+            .with_interval(Time::from_unit::<Second>(2.0))
+            .with_position_noise(Length::from_unit::<Meter>(0.0))
+            .take(2)
+            .collect();
+        assert_eq!(fixes[1].elapsed.to_unit::<Second>(), 2.0);
+        assert_eq!(fixes[0].speed.to_unit::<MeterPerSecond>(), 20.0);
+        // Heading north, so the first leg is 40 m of latitude
+        let north =
+            (fixes[1].latitude.degrees() - fixes[0].latitude.degrees()).to_radians() * EARTH_RADIUS;
+        assert!(approx(north, 40.0, 1e-6));
+        assert_eq!(fixes[0].longitude, fixes[1].longitude);
+    }
+
+    #[test]
+    fn stays_within_coordinate_ranges() {
+        let polar = GpsTrack::new(
+            Latitude::from_degrees(84.99).unwrap(),
+            Longitude::from_degrees(179.99).unwrap(),
+        )
+        .with_speed(Speed::from_unit::<MeterPerSecond>(300.0))
+        .with_heading(Angle::from_unit::<Degree>(45.0));
+        for fix in polar.take(5_000) {
+            assert!(fix.latitude.degrees().abs() <= 90.0);
+            assert!(fix.longitude.degrees().abs() <= 180.0);
+            assert!(fix.speed.in_base() >= 0.0);
+            let heading = fix.heading.to_unit::<Degree>();
+            assert!((0.0..360.0).contains(&heading));
+        }
+    }
+}
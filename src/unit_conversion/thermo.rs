@@ -0,0 +1,284 @@
+//! # Ideal Gas Law Helpers
+//!
+//! The ideal gas law `PV = nRT` relates the pressure, volume, amount and absolute
+//! temperature of a gas through the molar gas constant `R`. This module solves it for
+//! any one of the four from typed values of the other three, so the units of the inputs
+//! never need to match each other: litres, bar and degrees Celsius combine directly.
+//!
+//! Every input must be positive. Pressures are absolute (see
+//! [`GaugePressure`](crate::unit_conversion::pressure::GaugePressure) for readings
+//! relative to the atmosphere), and temperatures are converted to kelvin, so 0 °C is a
+//! valid input while −300 °C is rejected.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::amount::{Amount, Mole};
+//! use uom::unit_conversion::pressure::{Atmosphere, Pressure};
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//! use uom::unit_conversion::thermo::volume;
+//! use uom::unit_conversion::volume::Liter;
+//!
+//! // One mole at standard temperature and pressure fills about 22.4 L
+//! let v = volume(
+//!     Pressure::from_unit::<Atmosphere>(1.0),
+//!     Amount::from_unit::<Mole>(1.0),
+//!     Temperature::from_unit::<Celsius>(0.0),
+//! )
+//! .unwrap();
+//! assert!((v.to_unit::<Liter>() - 22.414).abs() < 1e-3);
+//! ```
+
+use crate::unit_conversion::amount::{Amount, Mole};
+use crate::unit_conversion::pressure::{Pascal, Pressure};
+use crate::unit_conversion::temperature::{Kelvin, Temperature};
+use crate::unit_conversion::volume::{CubicMeter, Volume};
+use crate::unit_conversion::{UnitConversion, UnitError, check_positive};
+
+/// The molar gas constant `R`.
+///
+/// Its value is exact in the SI, the product of the Avogadro and Boltzmann constants.
+/// Textbooks quote it in several unit combinations; [`GasConstant::in_units`] gives it
+/// for any pair of pressure and volume units, per mole per kelvin.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::pressure::{Atmosphere, Pascal};
+/// use uom::unit_conversion::thermo::GAS_CONSTANT;
+/// use uom::unit_conversion::volume::{CubicMeter, Liter};
+///
+/// // 8.314 J/(mol·K), since a pascal cubic meter is a joule
+/// assert!((GAS_CONSTANT.in_units::<Pascal, CubicMeter>() - 8.314).abs() < 1e-3);
+/// // 0.08206 L·atm/(mol·K)
+/// assert!((GAS_CONSTANT.in_units::<Atmosphere, Liter>() - 0.08206).abs() < 1e-5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GasConstant {
+    /// The value in pascal cubic meters (joules) per mole per kelvin.
+    joules_per_mole_kelvin: f64,
+}
+
+/// The molar gas constant, 8.314 462 618… J/(mol·K).
+pub const GAS_CONSTANT: GasConstant = GasConstant {
+    joules_per_mole_kelvin: 8.314_462_618_153_24,
+};
+
+impl GasConstant {
+    /// Returns the constant in joules per mole per kelvin.
+    pub const fn joules_per_mole_kelvin(self) -> f64 {
+        self.joules_per_mole_kelvin
+    }
+
+    /// Returns the constant in units of pressure `P` times volume `V` per mole per
+    /// kelvin.
+    pub fn in_units<P, V>(self) -> f64
+    where
+        P: UnitConversion<Dimension = <Pascal as UnitConversion>::Dimension>,
+        V: UnitConversion<Dimension = <CubicMeter as UnitConversion>::Dimension>,
+    {
+        self.joules_per_mole_kelvin / P::convert_to(1.0) / V::convert_to(1.0)
+    }
+}
+
+/// Returns the pressure of `amount` of an ideal gas held in `volume` at `temperature`.
+///
+/// Returns [`UnitError::OutOfRange`] if any input is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::{Amount, Mole};
+/// use uom::unit_conversion::pressure::Kilopascal;
+/// use uom::unit_conversion::temperature::{Kelvin, Temperature};
+/// use uom::unit_conversion::thermo::pressure;
+/// use uom::unit_conversion::volume::{Liter, Volume};
+///
+/// let p = pressure(
+///     Volume::from_unit::<Liter>(10.0),
+///     Amount::from_unit::<Mole>(0.5),
+///     Temperature::from_unit::<Kelvin>(300.0),
+/// )
+/// .unwrap();
+/// assert!((p.to_unit::<Kilopascal>() - 124.717).abs() < 1e-3);
+/// ```
+pub fn pressure(
+    volume: Volume,
+    amount: Amount,
+    temperature: Temperature,
+) -> Result<Pressure, UnitError> {
+    let nrt = moles(amount)? * GAS_CONSTANT.joules_per_mole_kelvin * kelvin(temperature)?;
+    Ok(Pressure::from_unit::<Pascal>(nrt / cubic_meters(volume)?))
+}
+
+/// Returns the volume occupied by `amount` of an ideal gas at `pressure` and
+/// `temperature`.
+///
+/// Returns [`UnitError::OutOfRange`] if any input is not positive and finite.
+pub fn volume(
+    pressure: Pressure,
+    amount: Amount,
+    temperature: Temperature,
+) -> Result<Volume, UnitError> {
+    let nrt = moles(amount)? * GAS_CONSTANT.joules_per_mole_kelvin * kelvin(temperature)?;
+    Ok(Volume::from_unit::<CubicMeter>(nrt / pascals(pressure)?))
+}
+
+/// Returns the amount of an ideal gas that fills `volume` at `pressure` and
+/// `temperature`.
+///
+/// Returns [`UnitError::OutOfRange`] if any input is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::amount::Mole;
+/// use uom::unit_conversion::pressure::{Bar, Pressure};
+/// use uom::unit_conversion::temperature::{Celsius, Temperature};
+/// use uom::unit_conversion::thermo::amount;
+/// use uom::unit_conversion::volume::{Liter, Volume};
+///
+/// // A 50 L cylinder filled to 200 bar at room temperature
+/// let n = amount(
+///     Pressure::from_unit::<Bar>(200.0),
+///     Volume::from_unit::<Liter>(50.0),
+///     Temperature::from_unit::<Celsius>(20.0),
+/// )
+/// .unwrap();
+/// assert!((n.to_unit::<Mole>() - 410.3).abs() < 0.1);
+/// ```
+pub fn amount(
+    pressure: Pressure,
+    volume: Volume,
+    temperature: Temperature,
+) -> Result<Amount, UnitError> {
+    let pv = pascals(pressure)? * cubic_meters(volume)?;
+    Ok(Amount::from_unit::<Mole>(
+        pv / (GAS_CONSTANT.joules_per_mole_kelvin * kelvin(temperature)?),
+    ))
+}
+
+/// Returns the temperature at which `amount` of an ideal gas held in `volume` reaches
+/// `pressure`.
+///
+/// Returns [`UnitError::OutOfRange`] if any input is not positive and finite.
+pub fn temperature(
+    pressure: Pressure,
+    volume: Volume,
+    amount: Amount,
+) -> Result<Temperature, UnitError> {
+    let pv = pascals(pressure)? * cubic_meters(volume)?;
+    Ok(Temperature::from_unit::<Kelvin>(
+        pv / (moles(amount)? * GAS_CONSTANT.joules_per_mole_kelvin),
+    ))
+}
+
+fn pascals(pressure: Pressure) -> Result<f64, UnitError> {
+    check_positive(pressure.to_unit::<Pascal>())
+}
+
+fn cubic_meters(volume: Volume) -> Result<f64, UnitError> {
+    check_positive(volume.to_unit::<CubicMeter>())
+}
+
+fn moles(amount: Amount) -> Result<f64, UnitError> {
+    check_positive(amount.to_unit::<Mole>())
+}
+
+fn kelvin(temperature: Temperature) -> Result<f64, UnitError> {
+    check_positive(temperature.to_unit::<Kelvin>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::pressure::{Atmosphere, Bar, Kilopascal};
+    use crate::unit_conversion::temperature::Celsius;
+    use crate::unit_conversion::volume::Liter;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn gas_constant_in_textbook_units() {
+        assert!(approx(
+            GAS_CONSTANT.in_units::<Pascal, CubicMeter>(),
+            8.314462618,
+            1e-9
+        ));
+        assert!(approx(
+            GAS_CONSTANT.in_units::<Kilopascal, Liter>(),
+            8.314462618,
+            1e-9
+        ));
+        assert!(approx(
+            GAS_CONSTANT.in_units::<Bar, Liter>(),
+            0.08314462618,
+            1e-11
+        ));
+        assert!(approx(
+            GAS_CONSTANT.in_units::<Atmosphere, Liter>(),
+            0.082057366,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn solvers_agree() {
+        let p = Pressure::from_unit::<Bar>(3.0);
+        let v = Volume::from_unit::<Liter>(12.0);
+        let n = Amount::from_unit::<Mole>(1.5);
+        let t = temperature(p, v, n).unwrap();
+
+        assert!(approx(t.to_unit::<Kelvin>(), 288.6537, 1e-4));
+        assert!(approx(
+            pressure(v, n, t).unwrap().to_unit::<Bar>(),
+            3.0,
+            1e-12
+        ));
+        assert!(approx(
+            volume(p, n, t).unwrap().to_unit::<Liter>(),
+            12.0,
+            1e-12
+        ));
+        assert!(approx(
+            amount(p, v, t).unwrap().to_unit::<Mole>(),
+            1.5,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn molar_volume_at_stp() {
+        let v = volume(
+            Pressure::from_unit::<Kilopascal>(100.0),
+            Amount::from_unit::<Mole>(1.0),
+            Temperature::from_unit::<Celsius>(0.0),
+        )
+        .unwrap();
+        // IUPAC STP (0 °C, 100 kPa)
+        assert!(approx(v.to_unit::<Liter>(), 22.71095, 1e-5));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict_physics", debug_assertions),
+        ignore = "builds physically impossible quantities"
+    )]
+    fn rejects_non_physical_inputs() {
+        let p = Pressure::from_unit::<Atmosphere>(1.0);
+        let v = Volume::from_unit::<Liter>(1.0);
+        let n = Amount::from_unit::<Mole>(1.0);
+        let t = Temperature::from_unit::<Celsius>(25.0);
+
+        assert!(matches!(
+            pressure(v, n, Temperature::from_unit::<Celsius>(-300.0)),
+            Err(UnitError::OutOfRange { .. })
+        ));
+        assert!(pressure(Volume::from_unit::<Liter>(0.0), n, t).is_err());
+        assert!(volume(Pressure::from_unit::<Pascal>(-1.0), n, t).is_err());
+        assert!(amount(p, v, Temperature::from_unit::<Kelvin>(0.0)).is_err());
+        assert!(temperature(p, v, Amount::from_unit::<Mole>(f64::NAN)).is_err());
+    }
+}
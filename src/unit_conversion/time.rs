@@ -0,0 +1,1151 @@
+//! # Time Unit Conversions
+//!
+//! This module provides type-safe time conversions between sub-second units, seconds,
+//! minutes, hours, days, weeks, and calendar months and years. All durations are internally stored in seconds (the base
+//! unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Second (s)**: The base unit, SI unit of time
+//! - **Millisecond (ms)**, **Microsecond (µs)**, **Nanosecond (ns)**: 10⁻³, 10⁻⁶ and 10⁻⁹
+//!   seconds
+//! - **Minute (min)**: 60 seconds
+//! - **Hour (h)**: 3600 seconds
+//! - **Day (d)**, **Week (wk)**, **Fortnight (ftn)**: 24 hours, 7 days and 14 days
+//! - **Julian year (a_j)** and **Julian month (mo_j)**: 365.25 days and a twelfth of it
+//! - **Gregorian year (a_g)** and **Gregorian month (mo_g)**: 365.2425 days and a twelfth
+//!   of it
+//! - **Thirty-day month (mo_30)**: 30 days
+//!
+//! Months and years have no single length, so there is no plain `Year` or `Month` unit.
+//! Callers pick the convention that matches their domain, and parsing rejects bare
+//! "year" or "month" rather than guessing.
+//!
+//! Clock ticks have no fixed length either: a [`Tick`] holds the rate of a particular
+//! clock, such as a CPU's cycle counter, and converts tick counts to and from [`Time`].
+//!
+//! With the `business_days` feature, [`BusinessDays`] counts and adds working days on
+//! `chrono` dates, skipping weekends and the days of a pluggable [`HolidayCalendar`],
+//! and converts between those spans and [`Time`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::time::{Time, Second, Minute, Hour};
+//!
+//! let meeting = Time::from_unit::<Minute>(90.0);
+//! assert_eq!(meeting.to_unit::<Hour>(), 1.5);
+//! assert_eq!(meeting.to_unit::<Second>(), 5400.0);
+//! ```
+
+#[cfg(feature = "business_days")]
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::unit_conversion::{
+    Dimension, Quantity, UnitConversion, UnitError, UnitInfo, check_positive,
+};
+
+/// Constants for time conversions
+const SECONDS_PER_MILLISECOND: f64 = 1e-3;
+const SECONDS_PER_MICROSECOND: f64 = 1e-6;
+const SECONDS_PER_NANOSECOND: f64 = 1e-9;
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 3600.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const SECONDS_PER_WEEK: f64 = 7.0 * SECONDS_PER_DAY;
+const SECONDS_PER_FORTNIGHT: f64 = 14.0 * SECONDS_PER_DAY;
+const SECONDS_PER_JULIAN_YEAR: f64 = 365.25 * SECONDS_PER_DAY;
+const SECONDS_PER_GREGORIAN_YEAR: f64 = 365.2425 * SECONDS_PER_DAY;
+const SECONDS_PER_JULIAN_MONTH: f64 = SECONDS_PER_JULIAN_YEAR / 12.0;
+const SECONDS_PER_GREGORIAN_MONTH: f64 = SECONDS_PER_GREGORIAN_YEAR / 12.0;
+const SECONDS_PER_THIRTY_DAY_MONTH: f64 = 30.0 * SECONDS_PER_DAY;
+
+/// Marker type for the time dimension.
+///
+/// This prevents accidental conversions between time and other dimensions
+/// like length or energy.
+pub enum TimeDimension {}
+
+impl Dimension for TimeDimension {
+    const NAME: &'static str = "time";
+    const ID: u8 = 5;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Second>(&["sec", "secs", "second", "seconds"]),
+        UnitInfo::of::<Millisecond>(&["msec", "millisecond", "milliseconds"]),
+        UnitInfo::of::<Microsecond>(&["us", "μs", "usec", "microsecond", "microseconds"]),
+        UnitInfo::of::<Nanosecond>(&["nsec", "nanosecond", "nanoseconds"]),
+        UnitInfo::of::<Minute>(&["m", "mins", "minute", "minutes"]),
+        UnitInfo::of::<Hour>(&["hr", "hrs", "hour", "hours"]),
+        UnitInfo::of::<Day>(&["day", "days"]),
+        UnitInfo::of::<Week>(&["week", "weeks"]),
+        UnitInfo::of::<Fortnight>(&["fortnight", "fortnights"]),
+        UnitInfo::of::<JulianYear>(&["julian year", "julian years"]),
+        UnitInfo::of::<GregorianYear>(&["gregorian year", "gregorian years"]),
+        UnitInfo::of::<JulianMonth>(&["julian month", "julian months"]),
+        UnitInfo::of::<GregorianMonth>(&["gregorian month", "gregorian months"]),
+        UnitInfo::of::<ThirtyDayMonth>(&["thirty-day month", "thirty-day months"]),
+    ];
+}
+
+/// A time quantity (duration) that stores values in seconds internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Time, Hour, Minute};
+///
+/// let shift = Time::from_unit::<Hour>(8.0);
+/// let minutes = shift.to_unit::<Minute>(); // 480.0
+/// ```
+pub type Time = Quantity<Second>;
+
+/// Second time unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Time, Second};
+///
+/// let timeout = Time::from_unit::<Second>(30.0);
+/// assert_eq!(timeout.to_unit::<Second>(), 30.0);
+/// ```
+pub struct Second;
+
+impl UnitConversion for Second {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Second is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Second is the base unit
+    }
+
+    const SYMBOL: &'static str = "s";
+    const NAME: &'static str = "second";
+    const PLURAL: &'static str = "seconds";
+}
+
+/// Millisecond time unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Millisecond, Second, Time};
+///
+/// let frame = Time::from_unit::<Millisecond>(16.0);
+/// assert!((frame.to_unit::<Second>() - 0.016).abs() < 1e-15);
+/// ```
+pub struct Millisecond;
+
+impl UnitConversion for Millisecond {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_MILLISECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_MILLISECOND
+    }
+
+    const SYMBOL: &'static str = "ms";
+    const NAME: &'static str = "millisecond";
+    const PLURAL: &'static str = "milliseconds";
+}
+
+/// Microsecond time unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Microsecond, Millisecond, Time};
+///
+/// let latency = Time::from_unit::<Microsecond>(250.0);
+/// assert!((latency.to_unit::<Millisecond>() - 0.25).abs() < 1e-12);
+/// ```
+pub struct Microsecond;
+
+impl UnitConversion for Microsecond {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_MICROSECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_MICROSECOND
+    }
+
+    const SYMBOL: &'static str = "µs";
+    const NAME: &'static str = "microsecond";
+    const PLURAL: &'static str = "microseconds";
+}
+
+/// Nanosecond time unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Microsecond, Nanosecond, Time};
+///
+/// let access = Time::from_unit::<Nanosecond>(1500.0);
+/// assert!((access.to_unit::<Microsecond>() - 1.5).abs() < 1e-12);
+/// ```
+pub struct Nanosecond;
+
+impl UnitConversion for Nanosecond {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_NANOSECOND
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_NANOSECOND
+    }
+
+    const SYMBOL: &'static str = "ns";
+    const NAME: &'static str = "nanosecond";
+    const PLURAL: &'static str = "nanoseconds";
+}
+
+/// Minute time unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Time, Minute, Second};
+///
+/// let break_time = Time::from_unit::<Minute>(15.0);
+/// assert_eq!(break_time.to_unit::<Second>(), 900.0);
+/// ```
+pub struct Minute;
+
+impl UnitConversion for Minute {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_MINUTE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_MINUTE
+    }
+
+    const SYMBOL: &'static str = "min";
+    const NAME: &'static str = "minute";
+    const PLURAL: &'static str = "minutes";
+}
+
+/// Hour time unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Time, Hour, Second};
+///
+/// let flight = Time::from_unit::<Hour>(2.5);
+/// assert_eq!(flight.to_unit::<Second>(), 9000.0);
+/// ```
+pub struct Hour;
+
+impl UnitConversion for Hour {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_HOUR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_HOUR
+    }
+
+    const SYMBOL: &'static str = "h";
+    const NAME: &'static str = "hour";
+    const PLURAL: &'static str = "hours";
+}
+
+/// Day time unit: 24 hours.
+///
+/// Days of civil time are occasionally a leap second longer; this unit ignores them,
+/// as calendar arithmetic does.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Day, Hour, Time};
+///
+/// let shipping = Time::from_unit::<Day>(3.0);
+/// assert_eq!(shipping.to_unit::<Hour>(), 72.0);
+/// ```
+pub struct Day;
+
+impl UnitConversion for Day {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_DAY
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_DAY
+    }
+
+    const SYMBOL: &'static str = "d";
+    const NAME: &'static str = "day";
+    const PLURAL: &'static str = "days";
+}
+
+/// Week time unit: 7 days.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Day, Time, Week};
+///
+/// let sprint = Time::from_unit::<Week>(2.0);
+/// assert_eq!(sprint.to_unit::<Day>(), 14.0);
+/// ```
+pub struct Week;
+
+impl UnitConversion for Week {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_WEEK
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_WEEK
+    }
+
+    const SYMBOL: &'static str = "wk";
+    const NAME: &'static str = "week";
+    const PLURAL: &'static str = "weeks";
+}
+
+/// Fortnight time unit: 14 days.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Fortnight, Time, Week};
+///
+/// let pay_period = Time::from_unit::<Fortnight>(1.0);
+/// assert_eq!(pay_period.to_unit::<Week>(), 2.0);
+/// ```
+pub struct Fortnight;
+
+impl UnitConversion for Fortnight {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_FORTNIGHT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_FORTNIGHT
+    }
+
+    const SYMBOL: &'static str = "ftn";
+    const NAME: &'static str = "fortnight";
+    const PLURAL: &'static str = "fortnights";
+}
+
+/// Julian year unit: exactly 365.25 days.
+///
+/// This is the year used in astronomy, for example to define the light-year.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{JulianYear, Second, Time};
+///
+/// let year = Time::from_unit::<JulianYear>(1.0);
+/// assert_eq!(year.to_unit::<Second>(), 31_557_600.0);
+/// ```
+pub struct JulianYear;
+
+impl UnitConversion for JulianYear {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_JULIAN_YEAR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_JULIAN_YEAR
+    }
+
+    const SYMBOL: &'static str = "a_j";
+    const NAME: &'static str = "Julian year";
+    const PLURAL: &'static str = "Julian years";
+}
+
+/// Gregorian year unit: the mean length of a year over the 400-year Gregorian cycle,
+/// 365.2425 days.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{GregorianYear, JulianYear, Time};
+///
+/// // 400 Gregorian years are three leap days shorter than 400 Julian years
+/// let cycle = Time::from_unit::<GregorianYear>(400.0);
+/// assert!((cycle.to_unit::<JulianYear>() * 365.25 - (400.0 * 365.25 - 3.0)).abs() < 1e-9);
+/// ```
+pub struct GregorianYear;
+
+impl UnitConversion for GregorianYear {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_GREGORIAN_YEAR
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_GREGORIAN_YEAR
+    }
+
+    const SYMBOL: &'static str = "a_g";
+    const NAME: &'static str = "Gregorian year";
+    const PLURAL: &'static str = "Gregorian years";
+}
+
+/// Julian month unit: one twelfth of a Julian year, 30.4375 days.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{JulianMonth, JulianYear, Time};
+///
+/// let months = Time::from_unit::<JulianMonth>(18.0);
+/// assert_eq!(months.to_unit::<JulianYear>(), 1.5);
+/// ```
+pub struct JulianMonth;
+
+impl UnitConversion for JulianMonth {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_JULIAN_MONTH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_JULIAN_MONTH
+    }
+
+    const SYMBOL: &'static str = "mo_j";
+    const NAME: &'static str = "Julian month";
+    const PLURAL: &'static str = "Julian months";
+}
+
+/// Gregorian month unit: one twelfth of a Gregorian year, 30.436875 days.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{GregorianMonth, GregorianYear, Time};
+///
+/// let months = Time::from_unit::<GregorianMonth>(6.0);
+/// assert_eq!(months.to_unit::<GregorianYear>(), 0.5);
+/// ```
+pub struct GregorianMonth;
+
+impl UnitConversion for GregorianMonth {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_GREGORIAN_MONTH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_GREGORIAN_MONTH
+    }
+
+    const SYMBOL: &'static str = "mo_g";
+    const NAME: &'static str = "Gregorian month";
+    const PLURAL: &'static str = "Gregorian months";
+}
+
+/// Thirty-day month unit, the convention used by many billing and interest
+/// calculations.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Hour, ThirtyDayMonth, Time};
+///
+/// let billing_period = Time::from_unit::<ThirtyDayMonth>(1.0);
+/// assert_eq!(billing_period.to_unit::<Hour>(), 720.0);
+/// ```
+pub struct ThirtyDayMonth;
+
+impl UnitConversion for ThirtyDayMonth {
+    type Dimension = TimeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * SECONDS_PER_THIRTY_DAY_MONTH
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / SECONDS_PER_THIRTY_DAY_MONTH
+    }
+
+    const SYMBOL: &'static str = "mo_30";
+    const NAME: &'static str = "30-day month";
+    const PLURAL: &'static str = "30-day months";
+}
+
+/// The tick of a clock running at a fixed rate, for converting tick or cycle counts
+/// into time.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::time::{Microsecond, Tick, Time};
+///
+/// // A 3 GHz cycle counter
+/// let cycles = Tick::new(3e9).unwrap();
+/// let elapsed = Time::from_ticks(4_500.0, &cycles);
+/// assert!((elapsed.to_unit::<Microsecond>() - 1.5).abs() < 1e-12);
+/// assert!((elapsed.to_ticks(&cycles) - 4_500.0).abs() < 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tick {
+    hertz: f64,
+}
+
+impl Tick {
+    /// Creates the tick of a clock that ticks `hertz` times per second.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `hertz` is not positive and finite.
+    pub fn new(hertz: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            hertz: check_positive(hertz)?,
+        })
+    }
+
+    /// Creates the tick of a clock whose ticks are `period` apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::OutOfRange`] if `period` is not positive and finite.
+    pub fn from_period(period: Time) -> Result<Self, UnitError> {
+        Self::new(1.0 / check_positive(period.in_base())?)
+    }
+
+    /// Returns the clock rate in ticks per second.
+    pub fn rate(&self) -> f64 {
+        self.hertz
+    }
+
+    /// Returns the time between two ticks.
+    pub fn period(&self) -> Time {
+        Time::from_base(1.0 / self.hertz)
+    }
+}
+
+impl Time {
+    /// Creates the time taken by `ticks` ticks of the clock `tick`.
+    pub fn from_ticks(ticks: f64, tick: &Tick) -> Self {
+        Self::from_base(ticks / tick.hertz)
+    }
+
+    /// Returns the number of ticks of the clock `tick` in this time. The result is not
+    /// rounded.
+    pub fn to_ticks(&self, tick: &Tick) -> f64 {
+        self.in_base() * tick.hertz
+    }
+}
+
+/// A source of non-working days for [`BusinessDays`].
+///
+/// Implemented for slices and vectors of dates, for sets of dates, and for closures,
+/// so a calendar can be a fixed list or a rule such as "the first Monday of May".
+#[cfg(feature = "business_days")]
+pub trait HolidayCalendar {
+    /// Returns true if no work happens on `date`.
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Returns the holidays from `start` up to but excluding `end`, in any order.
+    ///
+    /// The default asks [`is_holiday`](Self::is_holiday) about every day in between;
+    /// calendars that list their dates answer without visiting each day.
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|&date| date < end)
+            .filter(|&date| self.is_holiday(date))
+            .collect()
+    }
+}
+
+/// A calendar without holidays.
+#[cfg(feature = "business_days")]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct NoHolidays;
+
+#[cfg(feature = "business_days")]
+impl HolidayCalendar for NoHolidays {
+    fn is_holiday(&self, _: NaiveDate) -> bool {
+        false
+    }
+
+    fn holidays_between(&self, _: NaiveDate, _: NaiveDate) -> Vec<NaiveDate> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl HolidayCalendar for &[NaiveDate] {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let span = start..end;
+        self.iter()
+            .copied()
+            .filter(|date| span.contains(date))
+            .collect()
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl HolidayCalendar for Vec<NaiveDate> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let span = start..end;
+        self.iter()
+            .copied()
+            .filter(|date| span.contains(date))
+            .collect()
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl HolidayCalendar for std::collections::BTreeSet<NaiveDate> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        if end <= start {
+            return Vec::new();
+        }
+        self.range(start..end).copied().collect()
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl<F: Fn(NaiveDate) -> bool> HolidayCalendar for F {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self(date)
+    }
+}
+
+/// Working-day arithmetic on calendar dates: every day is a business day except the
+/// weekend days, Saturday and Sunday unless changed, and the days of the holiday
+/// calendar.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use uom::unit_conversion::time::{BusinessDays, Day, Time};
+///
+/// let date = |d| NaiveDate::from_ymd_opt(2025, 12, d).unwrap();
+/// let office = BusinessDays::new().with_holidays(vec![date(25), date(26)]);
+///
+/// // Friday the 19th plus five business days skips a weekend and two holidays
+/// assert_eq!(office.add(date(19), 5), date(30));
+/// assert_eq!(office.count(date(22), date(29)), 3);
+///
+/// let lead_time = Time::from_unit::<Day>(2.0);
+/// assert_eq!(office.add_time(date(24), lead_time).unwrap(), date(30));
+/// ```
+#[cfg(feature = "business_days")]
+#[derive(Clone, Debug)]
+pub struct BusinessDays<C = NoHolidays> {
+    /// Weekend days as a bit set indexed by `Weekday::num_days_from_monday`.
+    weekend: u8,
+    holidays: C,
+}
+
+#[cfg(feature = "business_days")]
+impl BusinessDays {
+    /// Creates a calendar with Saturday and Sunday weekends and no holidays.
+    pub fn new() -> Self {
+        Self {
+            weekend: weekend_mask(&[Weekday::Sat, Weekday::Sun]),
+            holidays: NoHolidays,
+        }
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl Default for BusinessDays {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "business_days")]
+impl<C: HolidayCalendar> BusinessDays<C> {
+    /// Returns the calendar with `holidays` as its holiday calendar.
+    pub fn with_holidays<H: HolidayCalendar>(self, holidays: H) -> BusinessDays<H> {
+        BusinessDays {
+            weekend: self.weekend,
+            holidays,
+        }
+    }
+
+    /// Returns the calendar with `days` as its weekend, such as Friday and Saturday.
+    pub fn with_weekend(mut self, days: &[Weekday]) -> Self {
+        self.weekend = weekend_mask(days);
+        self
+    }
+
+    /// Returns true if `date` is neither a weekend day nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.holidays.is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend & (1 << date.weekday().num_days_from_monday()) != 0
+    }
+
+    /// Returns the number of working weekdays in seven consecutive days.
+    fn per_week(&self) -> u64 {
+        u64::from(7 - self.weekend.count_ones())
+    }
+
+    /// Returns the number of holidays from `start` up to but excluding `end` that
+    /// fall outside the weekend, counting each date once.
+    fn weekday_holidays(&self, start: NaiveDate, end: NaiveDate) -> u64 {
+        let mut holidays = self.holidays.holidays_between(start, end);
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+            .iter()
+            .filter(|&&date| !self.is_weekend(date))
+            .count() as u64
+    }
+
+    /// Returns the date `days` business days after `start`, or before it for a negative
+    /// count, or `None` if that date is outside the range of `NaiveDate`.
+    fn checked_add(&self, start: NaiveDate, days: i64) -> Option<NaiveDate> {
+        assert!(self.weekend != 0x7F, "every weekday is a weekend day");
+        let forward = days >= 0;
+        let mut remaining = days.unsigned_abs();
+        let mut date = start;
+        while remaining > 0 {
+            // Any seven days hold the same number of weekdays, so whole weeks are
+            // skipped at once, leaving at least one business day to walk to
+            let weeks = (remaining - 1) / self.per_week();
+            if weeks > 0 {
+                let span = Days::new(weeks.checked_mul(7)?);
+                let (first, end) = if forward {
+                    let first = date.succ_opt()?;
+                    (first, first.checked_add_days(span)?)
+                } else {
+                    (date.checked_sub_days(span)?, date)
+                };
+                remaining -= weeks * self.per_week() - self.weekday_holidays(first, end);
+                date = if forward { end.pred_opt()? } else { first };
+            } else {
+                date = if forward {
+                    date.succ_opt()?
+                } else {
+                    date.pred_opt()?
+                };
+                if self.is_business_day(date) {
+                    remaining -= 1;
+                }
+            }
+        }
+        Some(date)
+    }
+
+    /// Returns the date `days` business days after `start`, or before it for a negative
+    /// count. Zero days gives `start` itself, business day or not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every weekday is a weekend day, or the result is outside the range of
+    /// `NaiveDate`.
+    pub fn add(&self, start: NaiveDate, days: i64) -> NaiveDate {
+        self.checked_add(start, days).expect("date out of range")
+    }
+
+    /// Returns the date `duration`, counted in business days, after `start`, as
+    /// [`add`](Self::add) does for a count.
+    ///
+    /// A partial day rounds up to a whole one, since work that takes part of a day
+    /// still occupies it.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnitError::OutOfRange`] if `duration` is negative, or so long that the result
+    ///   is outside the range of `NaiveDate`, with the business days left before the
+    ///   end of that range as the maximum
+    /// - [`UnitError::NonFinite`] if `duration` is not finite
+    ///
+    /// # Panics
+    ///
+    /// Panics if every weekday is a weekend day.
+    pub fn add_time(&self, start: NaiveDate, duration: Time) -> Result<NaiveDate, UnitError> {
+        let days = duration.to_unit::<Day>();
+        if !days.is_finite() {
+            return Err(UnitError::NonFinite { value: days });
+        }
+        if days < 0.0 {
+            return Err(UnitError::OutOfRange {
+                value: days,
+                min: 0.0,
+                max: f64::INFINITY,
+            });
+        }
+        // A count too large for an i64 saturates and so still fails below
+        self.checked_add(start, days.ceil() as i64)
+            .ok_or_else(|| UnitError::OutOfRange {
+                value: days,
+                min: 0.0,
+                max: (self.count(start, NaiveDate::MAX) - i64::from(self.is_business_day(start))
+                    + i64::from(self.is_business_day(NaiveDate::MAX))) as f64,
+            })
+    }
+
+    /// Returns the number of business days from `start` up to but excluding `end`, or
+    /// the negated count from `end` to `start` if `end` comes first.
+    pub fn count(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end < start {
+            return -self.count(end, start);
+        }
+        let days = (end - start).num_days() as u64;
+        let weeks = days / 7;
+        let rest = (start + Days::new(weeks * 7))
+            .iter_days()
+            .take_while(|&date| date < end)
+            .filter(|&date| !self.is_weekend(date))
+            .count() as u64;
+        (weeks * self.per_week() + rest - self.weekday_holidays(start, end)) as i64
+    }
+
+    /// Returns the business days from `start` up to but excluding `end` as a [`Time`]
+    /// of whole [`Day`]s.
+    pub fn between(&self, start: NaiveDate, end: NaiveDate) -> Time {
+        Time::from_unit::<Day>(self.count(start, end) as f64)
+    }
+}
+
+#[cfg(feature = "business_days")]
+fn weekend_mask(days: &[Weekday]) -> u8 {
+    days.iter()
+        .fold(0, |mask, day| mask | 1 << day.num_days_from_monday())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn minute_to_second() {
+        let time = Time::from_unit::<Minute>(1.0);
+        assert!(approx(time.to_unit::<Second>(), 60.0, 1e-12));
+
+        let time = Time::from_unit::<Second>(90.0);
+        assert!(approx(time.to_unit::<Minute>(), 1.5, 1e-12));
+    }
+
+    #[test]
+    fn hour_to_minute() {
+        let time = Time::from_unit::<Hour>(1.0);
+        assert!(approx(time.to_unit::<Minute>(), 60.0, 1e-12));
+        assert!(approx(time.to_unit::<Second>(), 3600.0, 1e-12));
+    }
+
+    #[test]
+    fn sub_second_units() {
+        let time = Time::from_unit::<Second>(1.0);
+        assert!(approx(time.to_unit::<Millisecond>(), 1e3, 1e-9));
+        assert!(approx(time.to_unit::<Microsecond>(), 1e6, 1e-6));
+        assert!(approx(time.to_unit::<Nanosecond>(), 1e9, 1e-3));
+
+        let time = Time::from_unit::<Microsecond>(1.0);
+        assert!(approx(time.to_unit::<Nanosecond>(), 1000.0, 1e-9));
+        for alias in ["µs", "μs", "us"] {
+            assert_eq!(TimeDimension::find_unit(alias).unwrap().symbol, "µs");
+        }
+    }
+
+    #[test]
+    fn clock_ticks() {
+        let timer = Tick::new(1000.0).unwrap();
+        assert!(approx(
+            Time::from_ticks(250.0, &timer).to_unit::<Second>(),
+            0.25,
+            1e-12
+        ));
+        assert!(approx(
+            Time::from_unit::<Minute>(1.0).to_ticks(&timer),
+            60_000.0,
+            1e-9
+        ));
+        assert!(approx(timer.period().to_unit::<Millisecond>(), 1.0, 1e-12));
+
+        let clock = Tick::from_period(Time::from_unit::<Nanosecond>(100.0)).unwrap();
+        assert!(approx(clock.rate(), 1e7, 1e-3));
+
+        assert!(Tick::new(0.0).is_err());
+        assert!(Tick::new(f64::INFINITY).is_err());
+        assert!(Tick::from_period(Time::from_unit::<Second>(-1.0)).is_err());
+    }
+
+    #[test]
+    fn time_symbols() {
+        assert_eq!(Second::SYMBOL, "s");
+        assert_eq!(Minute::SYMBOL, "min");
+        assert_eq!(Hour::SYMBOL, "h");
+        assert_eq!(Millisecond::SYMBOL, "ms");
+        assert_eq!(Microsecond::SYMBOL, "µs");
+        assert_eq!(Nanosecond::SYMBOL, "ns");
+    }
+
+    #[test]
+    fn calendar_policies() {
+        let year = Time::from_unit::<JulianYear>(1.0);
+        assert!(approx(year.to_unit::<Hour>(), 8766.0, 1e-9));
+        assert!(approx(year.to_unit::<JulianMonth>(), 12.0, 1e-12));
+
+        let year = Time::from_unit::<GregorianYear>(1.0);
+        assert!(approx(year.to_unit::<Second>(), 31_556_952.0, 1e-6));
+        assert!(approx(year.to_unit::<GregorianMonth>(), 12.0, 1e-12));
+        assert!(approx(year.to_unit::<ThirtyDayMonth>(), 12.17475, 1e-12));
+    }
+
+    #[test]
+    fn policies_disagree_over_long_spans() {
+        // A century differs by three quarters of a day between the two year lengths
+        let julian = Time::from_unit::<JulianYear>(100.0).to_unit::<Hour>();
+        let gregorian = Time::from_unit::<GregorianYear>(100.0).to_unit::<Hour>();
+        assert!(approx(julian - gregorian, 18.0, 1e-6));
+    }
+
+    #[test]
+    fn days_and_weeks() {
+        let fortnight = Time::from_unit::<Fortnight>(1.0);
+        assert_eq!(fortnight.to_unit::<Week>(), 2.0);
+        assert_eq!(fortnight.to_unit::<Day>(), 14.0);
+        assert!(approx(
+            Time::from_unit::<Day>(1.0).to_unit::<Hour>(),
+            24.0,
+            1e-12
+        ));
+        let parsed = Time::parse("3 weeks").unwrap();
+        assert!(approx(parsed.to_unit::<Day>(), 21.0, 1e-12));
+        assert_eq!(
+            TimeDimension::find_unit("fortnights").unwrap().symbol,
+            "ftn"
+        );
+        assert_eq!(TimeDimension::find_unit("d").unwrap().symbol, "d");
+    }
+
+    #[cfg(feature = "business_days")]
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[cfg(feature = "business_days")]
+    #[test]
+    fn adds_business_days_across_weekends() {
+        let week = BusinessDays::new();
+        // 2026-10-16 is a Friday
+        assert_eq!(week.add(date(10, 16), 1), date(10, 19));
+        assert_eq!(week.add(date(10, 19), -1), date(10, 16));
+        assert_eq!(week.add(date(10, 17), 0), date(10, 17));
+        assert_eq!(week.add(date(10, 17), 1), date(10, 19));
+        assert_eq!(week.add(date(10, 16), 10), date(10, 30));
+        assert!(week.is_business_day(date(10, 16)));
+        assert!(!week.is_business_day(date(10, 18)));
+
+        let gulf = BusinessDays::new().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+        assert_eq!(gulf.add(date(10, 15), 1), date(10, 18));
+    }
+
+    #[cfg(feature = "business_days")]
+    #[test]
+    fn holiday_calendars_are_pluggable() {
+        let fixed = [date(12, 25), date(12, 28)];
+        let listed = BusinessDays::new().with_holidays(&fixed[..]);
+        assert_eq!(listed.add(date(12, 24), 1), date(12, 29));
+        assert_eq!(listed.count(date(12, 21), date(12, 31)), 6);
+
+        let set: std::collections::BTreeSet<_> = fixed.into_iter().collect();
+        assert_eq!(
+            BusinessDays::new().with_holidays(set).add(date(12, 24), 1),
+            date(12, 29)
+        );
+
+        // Thanksgiving: the fourth Thursday of November
+        let rule = |d: NaiveDate| {
+            d.month() == 11 && d.weekday() == Weekday::Thu && (22..=28).contains(&d.day())
+        };
+        let us = BusinessDays::new().with_holidays(rule);
+        assert!(!us.is_business_day(date(11, 26)));
+        assert!(us.is_business_day(date(11, 19)));
+    }
+
+    #[cfg(feature = "business_days")]
+    #[test]
+    fn converts_between_spans_and_time() {
+        let week = BusinessDays::new();
+        assert_eq!(week.count(date(10, 12), date(10, 26)), 10);
+        assert_eq!(week.count(date(10, 26), date(10, 12)), -10);
+        let span = week.between(date(10, 12), date(10, 26));
+        assert!(approx(span.to_unit::<Day>(), 10.0, 1e-12));
+
+        let half = Time::from_unit::<Hour>(12.0);
+        assert_eq!(week.add_time(date(10, 16), half), Ok(date(10, 19)));
+        assert_eq!(
+            week.add_time(date(10, 16), Time::from_unit::<Fortnight>(1.0)),
+            Ok(date(11, 5))
+        );
+        assert!(
+            week.add_time(date(10, 16), Time::from_unit::<Day>(-1.0))
+                .is_err()
+        );
+        assert!(
+            week.add_time(date(10, 16), Time::from_unit::<Day>(f64::NAN))
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "business_days")]
+    #[test]
+    fn skipping_whole_weeks_matches_walking_day_by_day() {
+        fn walk<C: HolidayCalendar>(days: &BusinessDays<C>, start: NaiveDate, n: i64) -> NaiveDate {
+            let mut date = start;
+            for _ in 0..n.unsigned_abs() {
+                date = if n < 0 {
+                    date.pred_opt()
+                } else {
+                    date.succ_opt()
+                }
+                .unwrap();
+                while !days.is_business_day(date) {
+                    date = if n < 0 {
+                        date.pred_opt()
+                    } else {
+                        date.succ_opt()
+                    }
+                    .unwrap();
+                }
+            }
+            date
+        }
+        let fixed = vec![
+            date(10, 21),
+            date(10, 24),
+            date(11, 2),
+            date(11, 2),
+            date(12, 25),
+        ];
+        let listed = BusinessDays::new().with_holidays(fixed);
+        let gulf = BusinessDays::new()
+            .with_weekend(&[Weekday::Fri, Weekday::Sat])
+            .with_holidays(|d: NaiveDate| d.day() == 1);
+        for start in date(10, 10).iter_days().take(10) {
+            for n in -60..=60 {
+                assert_eq!(listed.add(start, n), walk(&listed, start, n));
+                assert_eq!(gulf.add(start, n), walk(&gulf, start, n));
+            }
+            let end = start + Days::new(80);
+            let brute = start
+                .iter_days()
+                .take_while(|&d| d < end)
+                .filter(|&d| gulf.is_business_day(d))
+                .count() as i64;
+            assert_eq!(gulf.count(start, end), brute);
+        }
+
+        // A thousand years of weekdays, without visiting each one
+        let week = BusinessDays::new();
+        assert_eq!(
+            week.add(date(10, 19), 5 * 52_000),
+            date(10, 19) + Days::new(7 * 52_000)
+        );
+    }
+
+    #[cfg(feature = "business_days")]
+    #[test]
+    fn adding_past_the_last_date_is_an_error() {
+        let week = BusinessDays::new();
+        let forever = Time::from_unit::<Day>(1e12);
+        assert!(matches!(
+            week.add_time(date(10, 16), forever),
+            Err(UnitError::OutOfRange { value, max, .. }) if value == 1e12 && max < 1e12
+        ));
+        let beyond = Time::from_unit::<Day>(1e30);
+        assert!(week.add_time(date(10, 16), beyond).is_err());
+        assert_eq!(
+            week.add_time(NaiveDate::MAX - Days::new(10), Time::from_unit::<Day>(7.0)),
+            Err(UnitError::OutOfRange {
+                value: 7.0,
+                min: 0.0,
+                max: week.count(NaiveDate::MAX - Days::new(9), NaiveDate::MAX) as f64
+                    + f64::from(u8::from(week.is_business_day(NaiveDate::MAX))),
+            })
+        );
+    }
+
+    #[test]
+    fn ambiguous_names_do_not_parse() {
+        assert!(Time::parse("2 years").is_err());
+        assert!(Time::parse("3 months").is_err());
+        let months = Time::parse("3 mo_30").unwrap();
+        assert!(approx(months.to_unit::<Hour>(), 2160.0, 1e-9));
+        let years = Time::parse("1.5 julian years").unwrap();
+        assert!(approx(years.to_unit::<JulianMonth>(), 18.0, 1e-9));
+    }
+}
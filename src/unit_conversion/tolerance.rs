@@ -0,0 +1,428 @@
+//! # Tolerance Stacking
+//!
+//! A [`Toleranced`] value is a nominal dimension with the deviations allowed either
+//! side of it, such as a 25 mm spacer toleranced +0.10/−0.05 mm. A [`Stack`] chains
+//! toleranced parts into one dimension, typically the gap or overall length of an
+//! assembly, and predicts its variation two ways:
+//!
+//! - [`Stack::worst_case`] adds every tolerance at its limit. The result is guaranteed,
+//!   but pessimistic for long chains.
+//! - [`Stack::rss`] (root sum square) combines tolerances statistically, assuming the
+//!   parts vary independently and are centred in their tolerance bands. Asymmetric
+//!   tolerances are first re-centred on the middle of their band.
+//!
+//! Each [`StackResult`] breaks the variation down by part, so the tolerances worth
+//! tightening are easy to spot. Stacks are meant for linear dimensions such as lengths
+//! and angles, where a sum of values is meaningful.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::Millimeter;
+//! use uom::unit_conversion::tolerance::{Stack, Toleranced};
+//!
+//! // The gap left in a 100 mm housing by three 30 mm blocks
+//! let housing = Toleranced::<Millimeter>::symmetric(100.0, 0.2).unwrap();
+//! let block = Toleranced::<Millimeter>::symmetric(30.0, 0.1).unwrap();
+//! let stack = Stack::new()
+//!     .add("housing", housing)
+//!     .subtract("block A", block)
+//!     .subtract("block B", block)
+//!     .subtract("block C", block);
+//!
+//! let worst = stack.worst_case();
+//! assert!((worst.nominal().to_unit::<Millimeter>() - 10.0).abs() < 1e-9);
+//! assert!((worst.minus_in::<Millimeter>() - 0.5).abs() < 1e-9);
+//!
+//! let rss = stack.rss();
+//! assert!((rss.plus_in::<Millimeter>() - 0.2646).abs() < 1e-4);
+//! assert_eq!(rss.contributions()[0].name, "housing");
+//! ```
+
+use crate::unit_conversion::{Quantity, UnitConversion, UnitError, unit_span};
+
+/// A nominal value with the deviations allowed below and above it.
+///
+/// Deviations are kept as differences in the base unit, so a tolerance of ±1 °C is
+/// one kelvin wide rather than an absolute temperature.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, Millimeter};
+/// use uom::unit_conversion::tolerance::Toleranced;
+///
+/// let pin = Toleranced::<Millimeter>::bilateral(6.0, 0.0, 0.05).unwrap();
+/// assert!((pin.max().to_unit::<Millimeter>() - 6.0).abs() < 1e-12);
+/// assert!((pin.min().to_unit::<Millimeter>() - 5.95).abs() < 1e-12);
+/// assert!(pin.contains(Length::from_unit::<Millimeter>(5.98)));
+/// ```
+pub struct Toleranced<U: UnitConversion> {
+    nominal: Quantity<U>,
+    /// Allowed deviation below nominal, in base units.
+    minus: f64,
+    /// Allowed deviation above nominal, in base units.
+    plus: f64,
+}
+
+impl<U: UnitConversion> Copy for Toleranced<U> {}
+
+impl<U: UnitConversion> Clone for Toleranced<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: UnitConversion> std::fmt::Debug for Toleranced<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Toleranced")
+            .field("nominal", &self.nominal)
+            .field("minus", &self.minus)
+            .field("plus", &self.plus)
+            .finish()
+    }
+}
+
+impl<U: UnitConversion> Toleranced<U> {
+    /// Creates a value toleranced by the same amount either side, all in unit `U`.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if the tolerance is negative or not finite.
+    pub fn symmetric(nominal: f64, tolerance: f64) -> Result<Self, UnitError> {
+        Self::bilateral(nominal, tolerance, tolerance)
+    }
+
+    /// Creates a value with separate deviations below (`minus`) and above (`plus`)
+    /// nominal, all in unit `U`. Both deviations are given as magnitudes.
+    ///
+    /// Returns [`UnitError::OutOfRange`] if either deviation is negative or not finite.
+    pub fn bilateral(nominal: f64, plus: f64, minus: f64) -> Result<Self, UnitError> {
+        Ok(Self {
+            nominal: Quantity::from_unit::<U>(nominal),
+            minus: deviation::<U>(minus)?,
+            plus: deviation::<U>(plus)?,
+        })
+    }
+
+    /// Returns the nominal value.
+    pub fn nominal(&self) -> Quantity<U> {
+        self.nominal
+    }
+
+    /// Returns the smallest allowed value.
+    pub fn min(&self) -> Quantity<U> {
+        Quantity::from_base(self.nominal.in_base() - self.minus)
+    }
+
+    /// Returns the largest allowed value.
+    pub fn max(&self) -> Quantity<U> {
+        Quantity::from_base(self.nominal.in_base() + self.plus)
+    }
+
+    /// Returns true if `value` lies within the tolerance band, limits included.
+    pub fn contains<V>(&self, value: Quantity<V>) -> bool
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        let value = value.in_base();
+        self.min().in_base() <= value && value <= self.max().in_base()
+    }
+
+    /// Re-expresses the value in unit `V` so it can join a stack kept in `V`.
+    pub fn convert<V>(self) -> Toleranced<V>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Toleranced {
+            nominal: Quantity::from_base(self.nominal.in_base()),
+            minus: self.minus,
+            plus: self.plus,
+        }
+    }
+
+    /// Returns the middle of the tolerance band and its half-width, in base units.
+    fn centred(&self) -> (f64, f64) {
+        (
+            self.nominal.in_base() + (self.plus - self.minus) / 2.0,
+            (self.plus + self.minus) / 2.0,
+        )
+    }
+}
+
+/// Converts a deviation magnitude in `U` to base units.
+fn deviation<U: UnitConversion>(value: f64) -> Result<f64, UnitError> {
+    if !(value.is_finite() && value >= 0.0) {
+        return Err(UnitError::OutOfRange {
+            value,
+            min: 0.0,
+            max: f64::INFINITY,
+        });
+    }
+    Ok(value * unit_span::<U>())
+}
+
+/// A chain of toleranced dimensions that add up to one resulting dimension.
+pub struct Stack<U: UnitConversion> {
+    links: Vec<Link<U>>,
+}
+
+struct Link<U: UnitConversion> {
+    name: String,
+    /// +1.0 for a dimension that adds to the result, −1.0 for one that subtracts.
+    direction: f64,
+    value: Toleranced<U>,
+}
+
+impl<U: UnitConversion> Default for Stack<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: UnitConversion> Stack<U> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Self { links: Vec::new() }
+    }
+
+    /// Adds a dimension that increases the result as it grows.
+    pub fn add(mut self, name: impl Into<String>, value: Toleranced<U>) -> Self {
+        self.links.push(Link {
+            name: name.into(),
+            direction: 1.0,
+            value,
+        });
+        self
+    }
+
+    /// Adds a dimension that decreases the result as it grows, such as a part
+    /// taking up space in a gap.
+    pub fn subtract(mut self, name: impl Into<String>, value: Toleranced<U>) -> Self {
+        self.links.push(Link {
+            name: name.into(),
+            direction: -1.0,
+            value,
+        });
+        self
+    }
+
+    /// Returns the nominal result with the variation reached when every dimension
+    /// sits at the limit that pushes the result furthest.
+    ///
+    /// Each contribution is the dimension's share of the total tolerance width.
+    pub fn worst_case(&self) -> StackResult<U> {
+        let mut nominal = 0.0;
+        let (mut minus, mut plus) = (0.0, 0.0);
+        for link in &self.links {
+            nominal += link.direction * link.value.nominal.in_base();
+            // A subtracted dimension at its maximum gives the result's minimum
+            let (low, high) = if link.direction > 0.0 {
+                (link.value.minus, link.value.plus)
+            } else {
+                (link.value.plus, link.value.minus)
+            };
+            minus += low;
+            plus += high;
+        }
+        let total = minus + plus;
+        let contributions = self.contributions(|value| (value.minus + value.plus) / total);
+        StackResult {
+            nominal: Quantity::from_base(nominal),
+            minus,
+            plus,
+            contributions,
+        }
+    }
+
+    /// Returns the result of a root-sum-square stack: the centred nominal with a
+    /// symmetric variation equal to the root sum square of the half-widths.
+    ///
+    /// Each contribution is the dimension's share of the total variance.
+    pub fn rss(&self) -> StackResult<U> {
+        let mut nominal = 0.0;
+        let mut variance = 0.0;
+        for link in &self.links {
+            let (centre, half_width) = link.value.centred();
+            nominal += link.direction * centre;
+            variance += half_width * half_width;
+        }
+        let contributions = self.contributions(|value| {
+            let (_, half_width) = value.centred();
+            half_width * half_width / variance
+        });
+        let spread = variance.sqrt();
+        StackResult {
+            nominal: Quantity::from_base(nominal),
+            minus: spread,
+            plus: spread,
+            contributions,
+        }
+    }
+
+    /// Lists each dimension's share, largest first. A stack with no tolerance at all
+    /// gives every dimension a share of zero.
+    fn contributions(&self, share: impl Fn(&Toleranced<U>) -> f64) -> Vec<Contribution> {
+        let mut contributions: Vec<Contribution> = self
+            .links
+            .iter()
+            .map(|link| {
+                let share = share(&link.value);
+                Contribution {
+                    name: link.name.clone(),
+                    share: if share.is_nan() { 0.0 } else { share },
+                }
+            })
+            .collect();
+        contributions.sort_by(|a, b| b.share.total_cmp(&a.share));
+        contributions
+    }
+}
+
+/// The predicted variation of a stacked dimension.
+pub struct StackResult<U: UnitConversion> {
+    nominal: Quantity<U>,
+    minus: f64,
+    plus: f64,
+    contributions: Vec<Contribution>,
+}
+
+/// One dimension's part in a [`StackResult`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contribution {
+    /// The name the dimension was added to the stack under.
+    pub name: String,
+    /// The fraction of the result's variation due to this dimension, from 0 to 1.
+    pub share: f64,
+}
+
+impl<U: UnitConversion> StackResult<U> {
+    /// Returns the nominal (for RSS, the centred) result.
+    pub fn nominal(&self) -> Quantity<U> {
+        self.nominal
+    }
+
+    /// Returns the smallest predicted result.
+    pub fn min(&self) -> Quantity<U> {
+        Quantity::from_base(self.nominal.in_base() - self.minus)
+    }
+
+    /// Returns the largest predicted result.
+    pub fn max(&self) -> Quantity<U> {
+        Quantity::from_base(self.nominal.in_base() + self.plus)
+    }
+
+    /// Returns the predicted deviation below nominal, in unit `V`.
+    pub fn minus_in<V>(&self) -> f64
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        self.minus / unit_span::<V>()
+    }
+
+    /// Returns the predicted deviation above nominal, in unit `V`.
+    pub fn plus_in<V>(&self) -> f64
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        self.plus / unit_span::<V>()
+    }
+
+    /// Returns each dimension's share of the variation, largest first.
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+}
+
+#[cfg(all(test, feature = "length"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::length::{Inch, Millimeter};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn mm(nominal: f64, plus: f64, minus: f64) -> Toleranced<Millimeter> {
+        Toleranced::bilateral(nominal, plus, minus).unwrap()
+    }
+
+    #[test]
+    fn worst_case_flips_subtracted_limits() {
+        let result = Stack::new()
+            .add("bore", mm(50.0, 0.1, 0.0))
+            .subtract("shaft", mm(49.9, 0.0, 0.05))
+            .worst_case();
+        assert!(approx(result.nominal().to_unit::<Millimeter>(), 0.1, 1e-9));
+        // Smallest clearance: smallest bore, largest shaft
+        assert!(approx(result.min().to_unit::<Millimeter>(), 0.1, 1e-9));
+        assert!(approx(result.max().to_unit::<Millimeter>(), 0.25, 1e-9));
+        assert!(approx(result.contributions()[0].share, 2.0 / 3.0, 1e-12));
+        assert_eq!(result.contributions()[0].name, "bore");
+    }
+
+    #[test]
+    fn rss_centres_asymmetric_tolerances() {
+        let result = Stack::new()
+            .add("a", mm(10.0, 0.2, 0.0))
+            .add("b", mm(20.0, 0.1, 0.1))
+            .rss();
+        assert!(approx(result.nominal().to_unit::<Millimeter>(), 30.1, 1e-9));
+        assert!(approx(result.plus_in::<Millimeter>(), 0.02f64.sqrt(), 1e-9));
+        assert!(approx(
+            result.minus_in::<Millimeter>(),
+            0.02f64.sqrt(),
+            1e-9
+        ));
+        let shares: f64 = result.contributions().iter().map(|c| c.share).sum();
+        assert!(approx(shares, 1.0, 1e-12));
+    }
+
+    #[test]
+    fn rss_is_tighter_than_worst_case() {
+        let part = mm(5.0, 0.05, 0.05);
+        let stack = (0..9).fold(Stack::new(), |stack, i| {
+            stack.add(format!("part {i}"), part)
+        });
+        assert!(approx(
+            stack.worst_case().plus_in::<Millimeter>(),
+            0.45,
+            1e-9
+        ));
+        assert!(approx(stack.rss().plus_in::<Millimeter>(), 0.15, 1e-9));
+    }
+
+    #[test]
+    fn units_can_be_mixed() {
+        let result = Stack::new()
+            .add(
+                "plate",
+                Toleranced::<Inch>::symmetric(1.0, 0.005)
+                    .unwrap()
+                    .convert::<Millimeter>(),
+            )
+            .add("washer", mm(1.6, 0.1, 0.1))
+            .worst_case();
+        assert!(approx(result.nominal().to_unit::<Millimeter>(), 27.0, 1e-9));
+        assert!(approx(result.plus_in::<Millimeter>(), 0.227, 1e-9));
+        assert!(result.max().to_unit::<Millimeter>() > 27.2);
+    }
+
+    #[test]
+    fn empty_or_exact_stacks() {
+        let result = Stack::<Millimeter>::new().rss();
+        assert_eq!(result.nominal().to_unit::<Millimeter>(), 0.0);
+        assert!(result.contributions().is_empty());
+
+        let result = Stack::new()
+            .add("gauge block", mm(10.0, 0.0, 0.0))
+            .worst_case();
+        assert_eq!(result.contributions()[0].share, 0.0);
+    }
+
+    #[test]
+    fn rejects_negative_tolerances() {
+        assert!(Toleranced::<Millimeter>::symmetric(1.0, -0.1).is_err());
+        assert!(Toleranced::<Millimeter>::bilateral(1.0, 0.1, f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+//! # Tracing Fields
+//!
+//! Logging a quantity as a bare `f64` loses its unit, and the reader of a trace has to
+//! guess whether `temperature=293.15` is in kelvins or degrees Fahrenheit. The
+//! [`Field`] view carries the value together with its unit symbol and dimension.
+//!
+//! `tracing::Value` is sealed, so no type outside `tracing` can implement it directly.
+//! [`Quantity::field`] and [`Quantity::field_in`] instead return the view wrapped in
+//! `tracing`'s display adapter, which is a `Value`, so a quantity is recorded without a
+//! `%` sigil and reads `293.15 K`. For subscribers that aggregate numbers, such as a
+//! JSON or metrics layer, record the parts as separate typed fields with
+//! [`Field::value`], [`Field::unit`] and [`Field::dimension`].
+//!
+//! A [`Field`] is also `Display` on its own, for the `log` crate and other
+//! format-string based loggers: [`Quantity::log_field`] returns it unwrapped.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::unit_conversion::temperature::{Celsius, Temperature};
+//!
+//! let t = Temperature::from_unit::<Celsius>(21.5);
+//! tracing::info!(temperature = t.field_in::<Celsius>(), "room reading");
+//!
+//! let f = t.log_field_in::<Celsius>();
+//! tracing::info!(
+//!     temperature.value = f.value(),
+//!     temperature.unit = f.unit(),
+//!     "room reading"
+//! );
+//! assert_eq!(f.to_string(), "21.5 °C");
+//! ```
+
+use std::fmt;
+
+use tracing::field::{DisplayValue, display};
+
+use crate::unit_conversion::precision::{Subject, format_number};
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion};
+
+/// A quantity's value with its unit symbol and dimension, for recording in a trace or
+/// log.
+///
+/// Displays as the value followed by the unit symbol; the formatter's precision, or
+/// else the installed [`PrecisionPolicy`](super::precision::PrecisionPolicy), applies
+/// to the value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Field {
+    value: f64,
+    unit: &'static str,
+    dimension: &'static str,
+}
+
+impl Field {
+    /// Returns the numeric value in [`unit`](Self::unit).
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the symbol of the unit the value is expressed in.
+    pub fn unit(&self) -> &'static str {
+        self.unit
+    }
+
+    /// Returns the name of the quantity's dimension, such as "temperature".
+    pub fn dimension(&self) -> &'static str {
+        self.dimension
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subject = Subject::Dimension(self.dimension);
+        f.write_str(&format_number(self.value, f.precision(), subject))?;
+        if !self.unit.is_empty() {
+            write!(f, " {}", self.unit)?;
+        }
+        Ok(())
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: UnitConversion,
+    U::Dimension: Dimension,
+{
+    /// Returns the quantity in its own unit, the base unit of the dimension for the
+    /// dimension type aliases, as a `tracing` field value.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Kilometer, Length};
+    ///
+    /// let leg = Length::from_unit::<Kilometer>(12.4);
+    /// tracing::debug!(distance = leg.field(), "leg complete");
+    /// ```
+    pub fn field(&self) -> DisplayValue<Field> {
+        display(self.log_field())
+    }
+
+    /// Returns the quantity in unit `V` as a `tracing` field value.
+    pub fn field_in<V>(&self) -> DisplayValue<Field>
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        display(self.log_field_in::<V>())
+    }
+
+    /// Returns the quantity in its own unit as a [`Field`].
+    pub fn log_field(&self) -> Field {
+        self.log_field_in::<U>()
+    }
+
+    /// Returns the quantity in unit `V` as a [`Field`].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "length"), doc = "```ignore")]
+    #[cfg_attr(feature = "length", doc = "```")]
+    /// use uom::unit_conversion::length::{Length, Meter, Mile};
+    ///
+    /// let f = Length::from_unit::<Mile>(1.0).log_field_in::<Meter>();
+    /// assert_eq!((f.value(), f.unit(), f.dimension()), (1609.344, "m", "length"));
+    /// assert_eq!(format!("{f:.0}"), "1609 m");
+    /// ```
+    pub fn log_field_in<V>(&self) -> Field
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        Field {
+            value: self.to_unit::<V>(),
+            unit: V::SYMBOL,
+            dimension: <U::Dimension as Dimension>::NAME,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field as TracingField, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::unit_conversion::length::{Kilometer, Length, Meter};
+    use crate::unit_conversion::temperature::{Celsius, Kelvin, Temperature};
+
+    /// Collects the fields of every event as `name=value` strings.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Recorder {
+        fn record_f64(&mut self, field: &TracingField, value: f64) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{field}={value} (f64)"));
+        }
+
+        fn record_str(&mut self, field: &TracingField, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{field}={value} (str)"));
+        }
+
+        fn record_debug(&mut self, field: &TracingField, value: &dyn std::fmt::Debug) {
+            self.0.lock().unwrap().push(format!("{field}={value:?}"));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn recorded(log: impl FnOnce()) -> Vec<String> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), log);
+        recorder.0.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn records_value_with_unit() {
+        let t = Temperature::from_unit::<Celsius>(-4.5);
+        let fields = recorded(|| {
+            tracing::info!(outside = t.field_in::<Celsius>(), inside = t.field());
+        });
+        assert_eq!(fields, ["outside=-4.5 °C", "inside=268.65 K"]);
+    }
+
+    #[test]
+    fn records_typed_parts() {
+        let f = Length::from_unit::<Kilometer>(2.5).log_field_in::<Meter>();
+        let fields = recorded(|| {
+            tracing::info!(
+                d.value = f.value(),
+                d.unit = f.unit(),
+                d.dim = f.dimension()
+            );
+        });
+        assert_eq!(
+            fields,
+            ["d.value=2500 (f64)", "d.unit=m (str)", "d.dim=length (str)"]
+        );
+    }
+
+    #[test]
+    fn displays_for_log() {
+        let f = Temperature::from_unit::<Kelvin>(300.0).log_field();
+        assert_eq!(format!("{f}"), "300 K");
+        assert_eq!(format!("{f:.1}"), "300.0 K");
+        assert_eq!(f.dimension(), "temperature");
+    }
+}
@@ -0,0 +1,332 @@
+//! # Versioned Records
+//!
+//! The [`Serialize`](serde::Serialize) impl of [`Quantity`] suits messages read by the
+//! same build that wrote them. Stores that outlive a release, such as a database column
+//! or an archived telemetry log, need a record that says what it holds and in which
+//! layout, so a later version of this crate can still read it after units were added,
+//! renamed or retired. A [`VersionedQuantity`] carries:
+//!
+//! | Field | Content |
+//! |-------|---------|
+//! | `version` | Record layout, currently [`FORMAT_VERSION`] |
+//! | `dimension` | Dimension identifier ([`Dimension::ID`], see [`binary`](super::binary)) |
+//! | `unit` | Symbol of the unit the value is in |
+//! | `value` | The value in that unit |
+//!
+//! Keeping the value in its own unit rather than the base unit means the record reads
+//! the same as the figure that was stored, and decoding a record in the unit it was
+//! written in is exact.
+//!
+//! When this crate renames a unit it keeps the old symbol as an alias, so records
+//! written by earlier versions decode without further work. A [`Migrator`] handles the
+//! cases the crate cannot know about: units an application registered and later
+//! renamed, or retired units whose records must be rescaled into a surviving unit.
+//! Records of a later version than [`FORMAT_VERSION`] are rejected rather than misread.
+//!
+//! ## Examples
+//!
+#![cfg_attr(not(feature = "length"), doc = "```ignore")]
+#![cfg_attr(feature = "length", doc = "```")]
+//! use uom::unit_conversion::length::{Kilometer, Length, Meter};
+//! use uom::unit_conversion::versioned::VersionedQuantity;
+//!
+//! let leg = Length::from_unit::<Kilometer>(42.195);
+//! let json = serde_json::to_string(&leg.to_versioned_in::<Kilometer>()).unwrap();
+//! assert_eq!(json, r#"{"version":1,"dimension":1,"unit":"km","value":42.195}"#);
+//!
+//! let record: VersionedQuantity = serde_json::from_str(&json).unwrap();
+//! assert_eq!(Length::from_versioned(&record).unwrap().to_unit::<Meter>(), 42195.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::unit_conversion::parse::unknown_unit;
+use crate::unit_conversion::registry::DIMENSIONS;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitError};
+
+/// The record layout written by [`Quantity::to_versioned`].
+pub const FORMAT_VERSION: u16 = 1;
+
+/// A serialized quantity that names its layout version, dimension and unit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VersionedQuantity {
+    /// The record layout version.
+    pub version: u16,
+    /// The [`Dimension::ID`] of the quantity's dimension.
+    pub dimension: u8,
+    /// The symbol of the unit `value` is in.
+    pub unit: String,
+    /// The value, in `unit`.
+    pub value: f64,
+}
+
+impl<U: UnitConversion> Quantity<U>
+where
+    U::Dimension: Dimension,
+{
+    /// Returns the quantity as a record in its own unit, the base unit of the dimension
+    /// for the dimension type aliases.
+    pub fn to_versioned(&self) -> VersionedQuantity {
+        self.to_versioned_in::<U>()
+    }
+
+    /// Returns the quantity as a record in unit `V`.
+    pub fn to_versioned_in<V>(&self) -> VersionedQuantity
+    where
+        V: UnitConversion<Dimension = U::Dimension>,
+    {
+        VersionedQuantity {
+            version: FORMAT_VERSION,
+            dimension: <U::Dimension as Dimension>::ID,
+            unit: V::SYMBOL.to_string(),
+            value: self.to_unit::<V>(),
+        }
+    }
+
+    /// Decodes a record, without application-specific migrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Migrator::decode`].
+    pub fn from_versioned(record: &VersionedQuantity) -> Result<Self, UnitError> {
+        Migrator::new().decode(record)
+    }
+}
+
+/// One rewrite of a unit in stored records.
+#[derive(Clone, Debug)]
+struct Rule {
+    dimension: u8,
+    from: String,
+    to: String,
+    convert: fn(f64) -> f64,
+}
+
+/// Rewrites records whose units have since been renamed or retired.
+///
+/// Rules apply to records of their dimension whose unit symbol matches exactly, and
+/// chain, so a unit renamed twice needs one rule per rename.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::unit_conversion::length::{Length, LengthDimension, Meter};
+/// use uom::unit_conversion::versioned::{Migrator, VersionedQuantity};
+///
+/// // An older release of the application stored hectometers under a symbol of its own
+/// let record = VersionedQuantity {
+///     version: 1,
+///     dimension: 1,
+///     unit: "hm_legacy".to_string(),
+///     value: 3.0,
+/// };
+/// let migrator =
+///     Migrator::new().replace::<LengthDimension>("hm_legacy", "m", |hm| hm * 100.0);
+/// let length: Length = migrator.decode(&record).unwrap();
+/// assert_eq!(length.to_unit::<Meter>(), 300.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Migrator {
+    rules: Vec<Rule>,
+}
+
+impl Migrator {
+    /// Creates a migrator with no rules, which accepts current records unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule reading unit `from` of dimension `D` as unit `to`, with the value
+    /// unchanged.
+    pub fn rename<D: Dimension>(self, from: &str, to: &str) -> Self {
+        self.replace::<D>(from, to, |value| value)
+    }
+
+    /// Adds a rule reading unit `from` of dimension `D` as unit `to`, with the value
+    /// converted by `convert`.
+    pub fn replace<D: Dimension>(mut self, from: &str, to: &str, convert: fn(f64) -> f64) -> Self {
+        self.rules.push(Rule {
+            dimension: D::ID,
+            from: from.to_string(),
+            to: to.to_string(),
+            convert,
+        });
+        self
+    }
+
+    /// Returns `record` rewritten to the current layout with its unit rules applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] if the record's version is not one this build
+    /// can read.
+    pub fn migrate(&self, record: &VersionedQuantity) -> Result<VersionedQuantity, UnitError> {
+        if record.version == 0 || record.version > FORMAT_VERSION {
+            return Err(UnitError::ParseError {
+                input: format!("{record:?}"),
+                reason: "unsupported format version",
+            });
+        }
+        let mut record = record.clone();
+        record.version = FORMAT_VERSION;
+        // Each rule applies at most once, so a cycle of renames cannot loop forever
+        let mut applied = vec![false; self.rules.len()];
+        while let Some(index) = (0..self.rules.len()).find(|&i| {
+            let rule = &self.rules[i];
+            !applied[i] && rule.dimension == record.dimension && rule.from == record.unit
+        }) {
+            let rule = &self.rules[index];
+            applied[index] = true;
+            record.unit.clone_from(&rule.to);
+            record.value = (rule.convert)(record.value);
+        }
+        Ok(record)
+    }
+
+    /// Migrates `record` and decodes it as a quantity of type `U`.
+    ///
+    /// The unit is looked up among the units of the dimension, including those added
+    /// with [`register_unit`](super::registry::register_unit).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::ParseError`] for an unsupported version or an unknown
+    /// dimension id, [`UnitError::DimensionMismatch`] if the record holds another
+    /// dimension, and [`UnitError::UnknownUnit`] if its unit is not known.
+    pub fn decode<U>(&self, record: &VersionedQuantity) -> Result<Quantity<U>, UnitError>
+    where
+        U: UnitConversion,
+        U::Dimension: Dimension,
+    {
+        let record = self.migrate(record)?;
+        let Some(dimension) = DIMENSIONS.iter().find(|info| info.id == record.dimension) else {
+            return Err(UnitError::ParseError {
+                input: format!("{record:?}"),
+                reason: "unknown dimension id",
+            });
+        };
+        if dimension.id != <U::Dimension as Dimension>::ID {
+            return Err(UnitError::DimensionMismatch {
+                expected: <U::Dimension as Dimension>::NAME,
+                found: dimension.name,
+            });
+        }
+        let unit = dimension
+            .find_unit(&record.unit)
+            .ok_or_else(|| unknown_unit::<U::Dimension>(&record.unit))?;
+        Ok(Quantity::from_base((unit.to_base)(record.value)))
+    }
+}
+
+#[cfg(all(test, feature = "length", feature = "temperature"))]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::UnitInfo;
+    use crate::unit_conversion::length::{Foot, Length, LengthDimension, Meter, Mile};
+    use crate::unit_conversion::registry::register_unit;
+    use crate::unit_conversion::temperature::{Celsius, Temperature, TemperatureDimension};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    fn record(dimension: u8, unit: &str, value: f64) -> VersionedQuantity {
+        VersionedQuantity {
+            version: FORMAT_VERSION,
+            dimension,
+            unit: unit.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_formats() {
+        let t = Temperature::from_unit::<Celsius>(21.5);
+        let stored = t.to_versioned_in::<Celsius>();
+        assert_eq!(stored, record(2, "°C", 21.5));
+
+        let json = serde_json::to_string(&stored).unwrap();
+        let back: VersionedQuantity = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, stored);
+        let bytes = postcard::to_allocvec(&stored).unwrap();
+        let back: VersionedQuantity = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(back, stored);
+
+        let decoded = Temperature::from_versioned(&back).unwrap();
+        assert!(approx(decoded.to_unit::<Celsius>(), 21.5, 1e-12));
+        assert_eq!(Length::from_unit::<Mile>(1.0).to_versioned().unit, "m");
+    }
+
+    #[test]
+    fn reads_aliases_and_registered_units() {
+        let feet = Length::from_versioned(&record(1, "feet", 10.0)).unwrap();
+        assert!(approx(feet.to_unit::<Foot>(), 10.0, 1e-12));
+
+        register_unit(
+            "length",
+            UnitInfo {
+                symbol: "lea_test",
+                name: "test league",
+                plural: "test leagues",
+                aliases: &[],
+                to_base: |leagues| leagues * 4828.032,
+                from_base: |meters| meters / 4828.032,
+            },
+        )
+        .unwrap();
+        let league = Length::from_versioned(&record(1, "lea_test", 1.0)).unwrap();
+        assert_eq!(league.to_unit::<Meter>(), 4828.032);
+    }
+
+    #[test]
+    fn migrations_rename_and_rescale() {
+        let migrator = Migrator::new()
+            .rename::<LengthDimension>("metre_old", "metre_mid")
+            .rename::<LengthDimension>("metre_mid", "m")
+            .replace::<TemperatureDimension>("dK", "K", |decikelvin| decikelvin / 10.0);
+        let chained = migrator.migrate(&record(1, "metre_old", 2.0)).unwrap();
+        assert_eq!(chained, record(1, "m", 2.0));
+
+        let t: Temperature = migrator.decode(&record(2, "dK", 2931.5)).unwrap();
+        assert!(approx(t.in_base(), 293.15, 1e-12));
+        // Rules are scoped to their dimension
+        assert!(migrator.decode::<Meter>(&record(1, "dK", 1.0)).is_err());
+
+        let looping = Migrator::new()
+            .rename::<LengthDimension>("a", "b")
+            .rename::<LengthDimension>("b", "a");
+        assert_eq!(looping.migrate(&record(1, "a", 1.0)).unwrap().unit, "a");
+    }
+
+    #[test]
+    fn rejects_unreadable_records() {
+        let mut future = record(1, "m", 1.0);
+        future.version = FORMAT_VERSION + 1;
+        assert!(matches!(
+            Length::from_versioned(&future),
+            Err(UnitError::ParseError {
+                reason: "unsupported format version",
+                ..
+            })
+        ));
+        assert_eq!(
+            Length::from_versioned(&record(2, "K", 1.0)),
+            Err(UnitError::DimensionMismatch {
+                expected: "length",
+                found: "temperature",
+            })
+        );
+        assert!(matches!(
+            Length::from_versioned(&record(0, "m", 1.0)),
+            Err(UnitError::ParseError {
+                reason: "unknown dimension id",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Length::from_versioned(&record(1, "parsec_x", 1.0)),
+            Err(UnitError::UnknownUnit { .. })
+        ));
+    }
+}
@@ -0,0 +1,279 @@
+//! # Voltage Unit Conversions
+//!
+//! This module provides type-safe voltage conversions between volts, millivolts, kilovolts,
+//! and the decibel-referenced levels used in audio and instrumentation.
+//! All voltages are internally stored in volts (the base unit) and converted on demand.
+//!
+//! ## Supported Units
+//!
+//! - **Volt (V)**: The base unit, SI unit of electric potential
+//! - **Millivolt (mV)**: 0.001 volts
+//! - **Kilovolt (kV)**: 1000 volts
+//! - **Decibel-volt (dBV)**: Level relative to 1 V RMS, `20 log10(V / 1 V)`
+//! - **Decibel-unloaded (dBu)**: Level relative to √0.6 V ≈ 0.775 V RMS, the voltage that
+//!   dissipates 1 mW in 600 Ω
+//!
+//! Decibel levels describe RMS magnitudes, so a voltage of zero is −∞ dB and negative
+//! voltages have no decibel value (converting one yields NaN).
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::voltage::{Voltage, Dbu, Dbv, Volt};
+//!
+//! // Professional line level
+//! let line = Voltage::from_unit::<Dbu>(4.0);
+//! assert!((line.to_unit::<Volt>() - 1.2277).abs() < 1e-4);
+//! assert!((line.to_unit::<Dbv>() - 1.7815).abs() < 1e-4);
+//! ```
+
+use crate::unit_conversion::logarithmic::LogarithmicUnit;
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for voltage conversions
+const VOLTS_PER_MILLIVOLT: f64 = 0.001;
+const VOLTS_PER_KILOVOLT: f64 = 1000.0;
+const DBV_REFERENCE_VOLTS: f64 = 1.0;
+const DBU_REFERENCE_VOLTS: f64 = 0.7745966692414834; // √0.6, 1 mW into 600 Ω
+
+/// Marker type for the voltage dimension.
+///
+/// This prevents accidental conversions between voltage and other dimensions
+/// like power or energy.
+pub enum VoltageDimension {}
+
+impl Dimension for VoltageDimension {
+    const NAME: &'static str = "voltage";
+    const ID: u8 = 12;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<Volt>(&["volt", "volts"]),
+        UnitInfo::of::<Millivolt>(&["millivolt", "millivolts"]),
+        UnitInfo::of::<Kilovolt>(&["kilovolt", "kilovolts"]),
+        UnitInfo::of::<Dbv>(&["dB(V)"]),
+        UnitInfo::of::<Dbu>(&["dB(u)"]),
+    ];
+}
+
+/// A voltage quantity that stores values in volts internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Volt, Millivolt};
+///
+/// let cell = Voltage::from_unit::<Volt>(3.7);
+/// let millivolts = cell.to_unit::<Millivolt>(); // 3700.0
+/// ```
+pub type Voltage = Quantity<Volt>;
+
+/// Volt voltage unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Volt};
+///
+/// let mains = Voltage::from_unit::<Volt>(230.0);
+/// assert_eq!(mains.to_unit::<Volt>(), 230.0);
+/// ```
+pub struct Volt;
+
+impl UnitConversion for Volt {
+    type Dimension = VoltageDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Volt is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Volt is the base unit
+    }
+
+    const SYMBOL: &'static str = "V";
+    const NAME: &'static str = "volt";
+    const PLURAL: &'static str = "volts";
+}
+
+/// Millivolt voltage unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Millivolt, Volt};
+///
+/// let thermocouple = Voltage::from_unit::<Millivolt>(4.1);
+/// assert!((thermocouple.to_unit::<Volt>() - 0.0041).abs() < 1e-15);
+/// ```
+pub struct Millivolt;
+
+impl UnitConversion for Millivolt {
+    type Dimension = VoltageDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * VOLTS_PER_MILLIVOLT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / VOLTS_PER_MILLIVOLT
+    }
+
+    const SYMBOL: &'static str = "mV";
+    const NAME: &'static str = "millivolt";
+    const PLURAL: &'static str = "millivolts";
+}
+
+/// Kilovolt voltage unit.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Kilovolt, Volt};
+///
+/// let line = Voltage::from_unit::<Kilovolt>(11.0);
+/// assert_eq!(line.to_unit::<Volt>(), 11_000.0);
+/// ```
+pub struct Kilovolt;
+
+impl UnitConversion for Kilovolt {
+    type Dimension = VoltageDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * VOLTS_PER_KILOVOLT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / VOLTS_PER_KILOVOLT
+    }
+
+    const SYMBOL: &'static str = "kV";
+    const NAME: &'static str = "kilovolt";
+    const PLURAL: &'static str = "kilovolts";
+}
+
+/// Decibel-volt voltage level unit.
+///
+/// A voltage of `V` volts RMS is `20 log10(V / 1 V)` dBV. Consumer audio line level is
+/// −10 dBV.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Dbv, Volt};
+///
+/// let consumer_line = Voltage::from_unit::<Dbv>(-10.0);
+/// assert!((consumer_line.to_unit::<Volt>() - 0.316228).abs() < 1e-6);
+/// ```
+pub struct Dbv;
+
+impl UnitConversion for Dbv {
+    type Dimension = VoltageDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        DBV_REFERENCE_VOLTS * 10f64.powf(value / 20.0)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        20.0 * (value / DBV_REFERENCE_VOLTS).log10()
+    }
+
+    const SYMBOL: &'static str = "dBV";
+    const NAME: &'static str = "decibel-volt";
+    const PLURAL: &'static str = "decibel-volts";
+}
+
+impl LogarithmicUnit for Dbv {
+    const LOG_FACTOR: f64 = 20.0;
+    const REFERENCE: f64 = DBV_REFERENCE_VOLTS;
+    const LINEAR_SYMBOL: &'static str = "V";
+}
+
+/// Decibel-unloaded voltage level unit.
+///
+/// A voltage of `V` volts RMS is `20 log10(V / 0.7746 V)` dBu. The reference is the
+/// voltage that dissipates 1 mW in a 600 Ω load, so 0 dBu matches 0 dBm on a 600 Ω line.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::voltage::{Voltage, Dbu, Millivolt};
+///
+/// let reference = Voltage::from_unit::<Dbu>(0.0);
+/// assert!((reference.to_unit::<Millivolt>() - 774.597).abs() < 1e-3);
+/// ```
+pub struct Dbu;
+
+impl UnitConversion for Dbu {
+    type Dimension = VoltageDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        DBU_REFERENCE_VOLTS * 10f64.powf(value / 20.0)
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        20.0 * (value / DBU_REFERENCE_VOLTS).log10()
+    }
+
+    const SYMBOL: &'static str = "dBu";
+    const NAME: &'static str = "decibel-unloaded";
+    const PLURAL: &'static str = "decibels-unloaded";
+}
+
+impl LogarithmicUnit for Dbu {
+    const LOG_FACTOR: f64 = 20.0;
+    const REFERENCE: f64 = DBU_REFERENCE_VOLTS;
+    const LINEAR_SYMBOL: &'static str = "V";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn linear_conversions() {
+        let voltage = Voltage::from_unit::<Kilovolt>(1.5);
+        assert!(approx(voltage.to_unit::<Volt>(), 1500.0, 1e-9));
+        assert!(approx(voltage.to_unit::<Millivolt>(), 1.5e6, 1e-6));
+    }
+
+    #[test]
+    fn decibel_conversions() {
+        let voltage = Voltage::from_unit::<Volt>(10.0);
+        assert!(approx(voltage.to_unit::<Dbv>(), 20.0, 1e-12));
+
+        // dBu and dBV differ by a fixed 2.2185 dB
+        for level in [-20.0, 0.0, 4.0] {
+            let voltage = Voltage::from_unit::<Dbv>(level);
+            assert!(approx(voltage.to_unit::<Dbu>() - level, 2.218487, 1e-6));
+        }
+    }
+
+    #[test]
+    fn decibels_of_zero_and_negative_voltage() {
+        let voltage = Voltage::from_unit::<Volt>(0.0);
+        assert_eq!(voltage.to_unit::<Dbv>(), f64::NEG_INFINITY);
+        assert!(Voltage::from_unit::<Volt>(-1.0).to_unit::<Dbu>().is_nan());
+    }
+
+    #[test]
+    fn voltage_symbols() {
+        assert_eq!(Volt::SYMBOL, "V");
+        assert_eq!(Millivolt::SYMBOL, "mV");
+        assert_eq!(Kilovolt::SYMBOL, "kV");
+        assert_eq!(Dbv::SYMBOL, "dBV");
+        assert_eq!(Dbu::SYMBOL, "dBu");
+    }
+}
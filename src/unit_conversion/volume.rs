@@ -0,0 +1,822 @@
+//! # Volume Unit Conversions
+//!
+//! This module provides type-safe volume conversions between metric units, the US
+//! customary and British imperial liquid measures, kitchen measures, and the bulk units
+//! used in the energy and agriculture industries. All volumes are internally stored in
+//! cubic meters (the base unit) and converted on demand.
+//!
+//! The US and imperial systems share unit names but not sizes: an imperial gallon is about
+//! 20% larger than a US gallon, and an imperial fluid ounce is about 4% smaller than a US
+//! one. Each is therefore a distinct type, so the two systems can never be mixed up silently.
+//! For the same reason a bare `gallon`, `pint` or `fluid ounce`, or a bare symbol such as
+//! `gal`, is not parsed; the error suggests the US and imperial names instead.
+//!
+//! ## Supported Units
+//!
+//! - **Cubic meter (m³)**: The base unit, SI unit of volume
+//! - **Liter (L)**: 0.001 cubic meters
+//! - **Milliliter (mL)**: 0.001 liters
+//! - **US gallon (US gal)**: 231 cubic inches, exactly 3.785411784 liters
+//! - **US pint (US pt)**: 1/8 US gallon
+//! - **US fluid ounce (US fl oz)**: 1/128 US gallon
+//! - **Imperial gallon (imp gal)**: Exactly 4.54609 liters
+//! - **Imperial pint (imp pt)**: 1/8 imperial gallon
+//! - **Imperial fluid ounce (imp fl oz)**: 1/160 imperial gallon
+//! - **Cup (cup)**: US customary cup, 1/16 US gallon, exactly 236.5882365 milliliters
+//! - **Metric cup (metric cup)**: Exactly 250 milliliters
+//! - **Tablespoon (tbsp)**: US tablespoon, 1/16 cup, exactly 14.78676478125 milliliters
+//! - **Teaspoon (tsp)**: US teaspoon, 1/3 tablespoon, exactly 4.92892159375 milliliters
+//! - **Oil barrel (bbl)**: 42 US gallons, exactly 158.987294928 liters
+//! - **US bushel (bu)**: 2150.42 cubic inches, exactly 35.23907016688 liters
+//! - **Acre-foot (ac⋅ft)**: 43,560 cubic feet, exactly 1233.48183754752 cubic meters
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::volume::{Volume, CubicMeter, Liter, Milliliter};
+//!
+//! let bottle = Volume::from_unit::<Liter>(1.5);
+//! assert!((bottle.to_unit::<Milliliter>() - 1500.0).abs() < 1e-9);
+//! assert!((bottle.to_unit::<CubicMeter>() - 0.0015).abs() < 1e-12);
+//!
+//! // US and imperial measures are distinct units
+//! use uom::unit_conversion::volume::{ImperialGallon, UsGallon};
+//! let tank = Volume::from_unit::<ImperialGallon>(1.0);
+//! assert!((tank.to_unit::<UsGallon>() - 1.200949925).abs() < 1e-9);
+//! ```
+
+use crate::unit_conversion::{Dimension, Quantity, UnitConversion, UnitInfo};
+
+/// Constants for volume conversions
+const CUBIC_METERS_PER_LITER: f64 = 1e-3;
+const CUBIC_METERS_PER_MILLILITER: f64 = 1e-6;
+const CUBIC_METERS_PER_US_GALLON: f64 = 3.785411784e-3; // Exact definition
+const CUBIC_METERS_PER_US_PINT: f64 = CUBIC_METERS_PER_US_GALLON / 8.0;
+const CUBIC_METERS_PER_US_FLUID_OUNCE: f64 = CUBIC_METERS_PER_US_GALLON / 128.0;
+const CUBIC_METERS_PER_IMPERIAL_GALLON: f64 = 4.54609e-3; // Exact definition
+const CUBIC_METERS_PER_IMPERIAL_PINT: f64 = CUBIC_METERS_PER_IMPERIAL_GALLON / 8.0;
+const CUBIC_METERS_PER_IMPERIAL_FLUID_OUNCE: f64 = CUBIC_METERS_PER_IMPERIAL_GALLON / 160.0;
+const CUBIC_METERS_PER_CUP: f64 = CUBIC_METERS_PER_US_GALLON / 16.0;
+const CUBIC_METERS_PER_TABLESPOON: f64 = CUBIC_METERS_PER_CUP / 16.0;
+const CUBIC_METERS_PER_TEASPOON: f64 = CUBIC_METERS_PER_TABLESPOON / 3.0;
+const CUBIC_METERS_PER_METRIC_CUP: f64 = 250e-6; // Exact definition
+const CUBIC_METERS_PER_OIL_BARREL: f64 = 42.0 * CUBIC_METERS_PER_US_GALLON;
+const CUBIC_METERS_PER_US_BUSHEL: f64 = 35.23907016688e-3; // Exact definition
+const CUBIC_METERS_PER_ACRE_FOOT: f64 = 1233.48183754752; // Exact definition
+
+/// Marker type for the volume dimension.
+///
+/// This prevents accidental conversions between volume and other dimensions
+/// like length or mass.
+pub enum VolumeDimension {}
+
+impl Dimension for VolumeDimension {
+    const NAME: &'static str = "volume";
+    const ID: u8 = 4;
+    const UNITS: &'static [UnitInfo] = &[
+        UnitInfo::of::<CubicMeter>(&[
+            "m3",
+            "cubic meter",
+            "cubic meters",
+            "cubic metre",
+            "cubic metres",
+        ]),
+        UnitInfo::of::<Liter>(&["liter", "liters", "litre", "litres"]),
+        UnitInfo::of::<Milliliter>(&["milliliter", "milliliters", "millilitre", "millilitres"]),
+        UnitInfo::of::<UsGallon>(&["us gallon", "us gallons"]),
+        UnitInfo::of::<UsPint>(&["us pint", "us pints"]),
+        UnitInfo::of::<UsFluidOunce>(&["us fluid ounce", "us fluid ounces"]),
+        UnitInfo::of::<ImperialGallon>(&["imperial gallon", "imperial gallons"]),
+        UnitInfo::of::<ImperialPint>(&["imperial pint", "imperial pints"]),
+        UnitInfo::of::<ImperialFluidOunce>(&[
+            "imp floz",
+            "imperial fluid ounce",
+            "imperial fluid ounces",
+        ]),
+        UnitInfo::of::<Cup>(&["cups", "us cup", "us cups"]),
+        UnitInfo::of::<MetricCup>(&["metric cups"]),
+        UnitInfo::of::<Tablespoon>(&["tablespoon", "tablespoons"]),
+        UnitInfo::of::<Teaspoon>(&["teaspoon", "teaspoons"]),
+        UnitInfo::of::<OilBarrel>(&["barrel", "barrels", "oil barrel", "oil barrels"]),
+        UnitInfo::of::<UsBushel>(&["bushel", "bushels", "us bushel", "us bushels"]),
+        UnitInfo::of::<AcreFoot>(&[
+            "acre-ft",
+            "acre-foot",
+            "acre-feet",
+            "acre foot",
+            "acre feet",
+        ]),
+    ];
+    const AMBIGUOUS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("gal", &["US gal", "imp gal"]),
+        ("gallon", &["us gallon", "imperial gallon"]),
+        ("gallons", &["us gallons", "imperial gallons"]),
+        ("pt", &["US pt", "imp pt"]),
+        ("pint", &["us pint", "imperial pint"]),
+        ("pints", &["us pints", "imperial pints"]),
+        ("fluid ounce", &["us fluid ounce", "imperial fluid ounce"]),
+        (
+            "fluid ounces",
+            &["us fluid ounces", "imperial fluid ounces"],
+        ),
+        ("fl oz", &["US fl oz", "imp fl oz"]),
+        ("floz", &["US fl oz", "imp floz"]),
+    ];
+}
+
+/// A volume quantity that stores values in cubic meters internally.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, Liter, Milliliter};
+///
+/// let glass = Volume::from_unit::<Milliliter>(250.0);
+/// let liters = glass.to_unit::<Liter>(); // 0.25
+/// ```
+pub type Volume = Quantity<CubicMeter>;
+
+/// Cubic meter volume unit (SI base unit).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, CubicMeter, Liter};
+///
+/// let tank = Volume::from_unit::<CubicMeter>(2.0);
+/// assert_eq!(tank.to_unit::<Liter>(), 2000.0);
+/// ```
+pub struct CubicMeter;
+
+impl UnitConversion for CubicMeter {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value // Cubic meter is the base unit
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value // Cubic meter is the base unit
+    }
+
+    const SYMBOL: &'static str = "m³";
+    const NAME: &'static str = "cubic meter";
+    const PLURAL: &'static str = "cubic meters";
+}
+
+/// Liter volume unit.
+///
+/// A liter is exactly one cubic decimeter (0.001 m³).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, Liter, Milliliter};
+///
+/// let jug = Volume::from_unit::<Liter>(2.0);
+/// assert!((jug.to_unit::<Milliliter>() - 2000.0).abs() < 1e-9);
+/// ```
+pub struct Liter;
+
+impl UnitConversion for Liter {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_LITER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_LITER
+    }
+
+    const SYMBOL: &'static str = "L";
+    const NAME: &'static str = "liter";
+    const PLURAL: &'static str = "liters";
+}
+
+/// Milliliter volume unit.
+///
+/// A milliliter is exactly one cubic centimeter (10⁻⁶ m³).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, Milliliter, Liter};
+///
+/// let dose = Volume::from_unit::<Milliliter>(500.0);
+/// assert_eq!(dose.to_unit::<Liter>(), 0.5);
+/// ```
+pub struct Milliliter;
+
+impl UnitConversion for Milliliter {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_MILLILITER
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_MILLILITER
+    }
+
+    const SYMBOL: &'static str = "mL";
+    const NAME: &'static str = "milliliter";
+    const PLURAL: &'static str = "milliliters";
+}
+
+/// US liquid gallon.
+///
+/// Defined as exactly 231 cubic inches (3.785411784 liters). Not to be confused
+/// with the larger [`ImperialGallon`].
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, UsGallon, Liter};
+///
+/// let fuel = Volume::from_unit::<UsGallon>(1.0);
+/// assert!((fuel.to_unit::<Liter>() - 3.785411784).abs() < 1e-12);
+/// ```
+pub struct UsGallon;
+
+impl UnitConversion for UsGallon {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_US_GALLON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_US_GALLON
+    }
+
+    const SYMBOL: &'static str = "US gal";
+    const NAME: &'static str = "US gallon";
+    const PLURAL: &'static str = "US gallons";
+}
+
+/// US liquid pint.
+///
+/// One eighth of a US gallon (473.176473 milliliters).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, UsPint, UsGallon};
+///
+/// let milk = Volume::from_unit::<UsPint>(8.0);
+/// assert!((milk.to_unit::<UsGallon>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct UsPint;
+
+impl UnitConversion for UsPint {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_US_PINT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_US_PINT
+    }
+
+    const SYMBOL: &'static str = "US pt";
+    const NAME: &'static str = "US pint";
+    const PLURAL: &'static str = "US pints";
+}
+
+/// US customary fluid ounce.
+///
+/// One 128th of a US gallon (29.5735295625 milliliters).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, UsFluidOunce, UsPint};
+///
+/// let glass = Volume::from_unit::<UsFluidOunce>(16.0);
+/// assert!((glass.to_unit::<UsPint>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct UsFluidOunce;
+
+impl UnitConversion for UsFluidOunce {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_US_FLUID_OUNCE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_US_FLUID_OUNCE
+    }
+
+    const SYMBOL: &'static str = "US fl oz";
+    const NAME: &'static str = "US fluid ounce";
+    const PLURAL: &'static str = "US fluid ounces";
+}
+
+/// Imperial gallon.
+///
+/// Defined as exactly 4.54609 liters. Used in the United Kingdom and several
+/// Commonwealth countries.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, ImperialGallon, Liter};
+///
+/// let fuel = Volume::from_unit::<ImperialGallon>(1.0);
+/// assert!((fuel.to_unit::<Liter>() - 4.54609).abs() < 1e-12);
+/// ```
+pub struct ImperialGallon;
+
+impl UnitConversion for ImperialGallon {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_IMPERIAL_GALLON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_IMPERIAL_GALLON
+    }
+
+    const SYMBOL: &'static str = "imp gal";
+    const NAME: &'static str = "imperial gallon";
+    const PLURAL: &'static str = "imperial gallons";
+}
+
+/// Imperial pint.
+///
+/// One eighth of an imperial gallon (568.26125 milliliters).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, ImperialPint, Milliliter};
+///
+/// let beer = Volume::from_unit::<ImperialPint>(1.0);
+/// assert!((beer.to_unit::<Milliliter>() - 568.26125).abs() < 1e-9);
+/// ```
+pub struct ImperialPint;
+
+impl UnitConversion for ImperialPint {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_IMPERIAL_PINT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_IMPERIAL_PINT
+    }
+
+    const SYMBOL: &'static str = "imp pt";
+    const NAME: &'static str = "imperial pint";
+    const PLURAL: &'static str = "imperial pints";
+}
+
+/// Imperial fluid ounce.
+///
+/// One 160th of an imperial gallon (28.4130625 milliliters).
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Volume, ImperialFluidOunce, ImperialPint};
+///
+/// let glass = Volume::from_unit::<ImperialFluidOunce>(20.0);
+/// assert!((glass.to_unit::<ImperialPint>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct ImperialFluidOunce;
+
+impl UnitConversion for ImperialFluidOunce {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_IMPERIAL_FLUID_OUNCE
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_IMPERIAL_FLUID_OUNCE
+    }
+
+    const SYMBOL: &'static str = "imp fl oz";
+    const NAME: &'static str = "imperial fluid ounce";
+    const PLURAL: &'static str = "imperial fluid ounces";
+}
+
+/// US customary cup.
+///
+/// Half a US pint. US nutrition labels use a rounded 240 mL cup instead.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Cup, Milliliter, Volume};
+///
+/// let milk = Volume::from_unit::<Cup>(1.0);
+/// assert!((milk.to_unit::<Milliliter>() - 236.5882365).abs() < 1e-9);
+/// ```
+pub struct Cup;
+
+impl UnitConversion for Cup {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_CUP
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_CUP
+    }
+
+    const SYMBOL: &'static str = "cup";
+    const NAME: &'static str = "cup";
+    const PLURAL: &'static str = "cups";
+}
+
+/// Metric cup.
+///
+/// Exactly 250 milliliters, as used in recipes from Australia, New Zealand, Canada and
+/// South Africa.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Liter, MetricCup, Volume};
+///
+/// let stock = Volume::from_unit::<MetricCup>(4.0);
+/// assert!((stock.to_unit::<Liter>() - 1.0).abs() < 1e-12);
+/// ```
+pub struct MetricCup;
+
+impl UnitConversion for MetricCup {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_METRIC_CUP
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_METRIC_CUP
+    }
+
+    const SYMBOL: &'static str = "metric cup";
+    const NAME: &'static str = "metric cup";
+    const PLURAL: &'static str = "metric cups";
+}
+
+/// US customary tablespoon.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Tablespoon, Teaspoon, Volume};
+///
+/// let oil = Volume::from_unit::<Tablespoon>(1.0);
+/// assert!((oil.to_unit::<Teaspoon>() - 3.0).abs() < 1e-12);
+/// ```
+pub struct Tablespoon;
+
+impl UnitConversion for Tablespoon {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_TABLESPOON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_TABLESPOON
+    }
+
+    const SYMBOL: &'static str = "tbsp";
+    const NAME: &'static str = "tablespoon";
+    const PLURAL: &'static str = "tablespoons";
+}
+
+/// US customary teaspoon.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Milliliter, Teaspoon, Volume};
+///
+/// let vanilla = Volume::from_unit::<Teaspoon>(1.0);
+/// assert!((vanilla.to_unit::<Milliliter>() - 4.92892159375).abs() < 1e-9);
+/// ```
+pub struct Teaspoon;
+
+impl UnitConversion for Teaspoon {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_TEASPOON
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_TEASPOON
+    }
+
+    const SYMBOL: &'static str = "tsp";
+    const NAME: &'static str = "teaspoon";
+    const PLURAL: &'static str = "teaspoons";
+}
+
+/// Oil barrel volume unit.
+///
+/// The petroleum barrel of exactly 42 US gallons, used to quote crude oil production and
+/// prices.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Liter, OilBarrel, Volume};
+///
+/// let crude = Volume::from_unit::<OilBarrel>(1.0);
+/// assert!((crude.to_unit::<Liter>() - 158.987294928).abs() < 1e-9);
+/// ```
+pub struct OilBarrel;
+
+impl UnitConversion for OilBarrel {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_OIL_BARREL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_OIL_BARREL
+    }
+
+    const SYMBOL: &'static str = "bbl";
+    const NAME: &'static str = "barrel";
+    const PLURAL: &'static str = "barrels";
+}
+
+/// US bushel volume unit.
+///
+/// The Winchester bushel of exactly 2150.42 cubic inches, used for grain and produce.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{Liter, UsBushel, Volume};
+///
+/// let grain = Volume::from_unit::<UsBushel>(1.0);
+/// assert!((grain.to_unit::<Liter>() - 35.23907016688).abs() < 1e-9);
+/// ```
+pub struct UsBushel;
+
+impl UnitConversion for UsBushel {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_US_BUSHEL
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_US_BUSHEL
+    }
+
+    const SYMBOL: &'static str = "bu";
+    const NAME: &'static str = "bushel";
+    const PLURAL: &'static str = "bushels";
+}
+
+/// Acre-foot volume unit.
+///
+/// The volume covering one acre to a depth of one foot, exactly 43,560 cubic feet, used
+/// for water rights and reservoir capacity.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::volume::{AcreFoot, CubicMeter, Volume};
+///
+/// let reservoir = Volume::from_unit::<AcreFoot>(1.0);
+/// assert!((reservoir.to_unit::<CubicMeter>() - 1233.48183754752).abs() < 1e-9);
+/// ```
+pub struct AcreFoot;
+
+impl UnitConversion for AcreFoot {
+    type Dimension = VolumeDimension;
+
+    #[inline]
+    fn convert_to(value: f64) -> f64 {
+        value * CUBIC_METERS_PER_ACRE_FOOT
+    }
+
+    #[inline]
+    fn convert_from(value: f64) -> f64 {
+        value / CUBIC_METERS_PER_ACRE_FOOT
+    }
+
+    const SYMBOL: &'static str = "ac⋅ft";
+    const NAME: &'static str = "acre-foot";
+    const PLURAL: &'static str = "acre-feet";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::UnitError;
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn liter_to_cubic_meter() {
+        let volume = Volume::from_unit::<Liter>(1000.0);
+        assert!(approx(volume.to_unit::<CubicMeter>(), 1.0, 1e-12));
+
+        let volume = Volume::from_unit::<Liter>(1.0);
+        assert!(approx(volume.to_unit::<CubicMeter>(), 0.001, 1e-12));
+    }
+
+    #[test]
+    fn milliliter_to_liter() {
+        let volume = Volume::from_unit::<Milliliter>(1000.0);
+        assert!(approx(volume.to_unit::<Liter>(), 1.0, 1e-12));
+
+        let volume = Volume::from_unit::<Milliliter>(250.0);
+        assert!(approx(volume.to_unit::<Liter>(), 0.25, 1e-12));
+    }
+
+    #[test]
+    fn us_liquid_measures() {
+        let volume = Volume::from_unit::<UsGallon>(1.0);
+        assert!(approx(volume.to_unit::<Liter>(), 3.785411784, 1e-12));
+        assert!(approx(volume.to_unit::<UsPint>(), 8.0, 1e-12));
+        assert!(approx(volume.to_unit::<UsFluidOunce>(), 128.0, 1e-9));
+
+        let volume = Volume::from_unit::<UsFluidOunce>(1.0);
+        assert!(approx(volume.to_unit::<Milliliter>(), 29.5735295625, 1e-9));
+    }
+
+    #[test]
+    fn imperial_liquid_measures() {
+        let volume = Volume::from_unit::<ImperialGallon>(1.0);
+        assert!(approx(volume.to_unit::<Liter>(), 4.54609, 1e-12));
+        assert!(approx(volume.to_unit::<ImperialPint>(), 8.0, 1e-12));
+        assert!(approx(volume.to_unit::<ImperialFluidOunce>(), 160.0, 1e-9));
+
+        let volume = Volume::from_unit::<ImperialFluidOunce>(1.0);
+        assert!(approx(volume.to_unit::<Milliliter>(), 28.4130625, 1e-9));
+    }
+
+    #[test]
+    fn us_and_imperial_differ() {
+        let volume = Volume::from_unit::<ImperialGallon>(1.0);
+        assert!(approx(volume.to_unit::<UsGallon>(), 1.200949925, 1e-9));
+
+        let volume = Volume::from_unit::<ImperialPint>(1.0);
+        assert!(approx(volume.to_unit::<UsPint>(), 1.200949925, 1e-9));
+
+        let volume = Volume::from_unit::<UsFluidOunce>(1.0);
+        assert!(approx(
+            volume.to_unit::<ImperialFluidOunce>(),
+            1.040842731,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn kitchen_measures() {
+        let cup = Volume::from_unit::<Cup>(1.0);
+        assert!(approx(cup.to_unit::<Milliliter>(), 236.5882365, 1e-9));
+        assert!(approx(cup.to_unit::<UsFluidOunce>(), 8.0, 1e-12));
+        assert!(approx(cup.to_unit::<Tablespoon>(), 16.0, 1e-12));
+
+        let spoon = Volume::from_unit::<Tablespoon>(2.0);
+        assert!(approx(spoon.to_unit::<UsFluidOunce>(), 1.0, 1e-12));
+        assert!(approx(spoon.to_unit::<Teaspoon>(), 6.0, 1e-12));
+
+        let metric = Volume::from_unit::<MetricCup>(1.0);
+        assert!(approx(metric.to_unit::<Milliliter>(), 250.0, 1e-9));
+        assert!(approx(metric.to_unit::<ImperialFluidOunce>(), 8.7988, 1e-4));
+        assert_eq!(
+            VolumeDimension::find_unit("metric cups").unwrap().symbol,
+            "metric cup"
+        );
+    }
+
+    #[test]
+    fn industrial_measures() {
+        let barrel = Volume::from_unit::<OilBarrel>(1.0);
+        assert!(approx(barrel.to_unit::<UsGallon>(), 42.0, 1e-12));
+        assert!(approx(
+            barrel.to_unit::<CubicMeter>(),
+            0.158987294928,
+            1e-15
+        ));
+
+        // 2150.42 cubic inches of 0.0254 m each
+        let bushel = Volume::from_unit::<UsBushel>(1.0);
+        assert!(approx(
+            bushel.to_unit::<CubicMeter>(),
+            2150.42 * 0.0254f64.powi(3),
+            1e-15
+        ));
+
+        // 43,560 square feet by one foot, at 0.3048 m per foot
+        let acre_foot = Volume::from_unit::<AcreFoot>(1.0);
+        assert!(approx(
+            acre_foot.to_unit::<CubicMeter>(),
+            43_560.0 * 0.3048f64.powi(3),
+            1e-9
+        ));
+        assert!(approx(acre_foot.to_unit::<UsGallon>(), 325_851.43, 1e-2));
+
+        for (alias, symbol) in [("bbl", "bbl"), ("bushels", "bu"), ("acre-feet", "ac⋅ft")] {
+            assert_eq!(VolumeDimension::find_unit(alias).unwrap().symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn ambiguous_names_suggest_both_systems() {
+        for &(name, qualified) in VolumeDimension::AMBIGUOUS {
+            assert!(VolumeDimension::find_unit(name).is_none(), "{name}");
+            for unit in qualified {
+                assert!(VolumeDimension::find_unit(unit).is_some(), "{unit}");
+            }
+        }
+        assert_eq!(
+            Volume::parse("2 gallons").unwrap_err().to_string(),
+            "unknown volume unit 'gallons' (did you mean 'us gallons', 'imperial gallons'?)"
+        );
+        assert!(approx(
+            Volume::parse("2 imperial gallons")
+                .unwrap()
+                .to_unit::<Liter>(),
+            9.09218,
+            1e-9
+        ));
+
+        // Bare symbols are as ambiguous as bare names, in any case
+        for (text, us, imperial) in [
+            ("2 gal", "US gal", "imp gal"),
+            ("2 GAL", "US gal", "imp gal"),
+            ("1 pt", "US pt", "imp pt"),
+            ("1 fl oz", "US fl oz", "imp fl oz"),
+        ] {
+            match Volume::parse(text) {
+                Err(UnitError::UnknownUnit { suggestions, .. }) => {
+                    assert_eq!(suggestions, vec![us, imperial], "{text}");
+                }
+                other => panic!("{text}: unexpected result: {other:?}"),
+            }
+        }
+        assert!(approx(
+            Volume::parse("2 US gal").unwrap().to_unit::<Liter>(),
+            7.570823568,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn volume_symbols() {
+        assert_eq!(CubicMeter::SYMBOL, "m³");
+        assert_eq!(Liter::SYMBOL, "L");
+        assert_eq!(Milliliter::SYMBOL, "mL");
+        assert_eq!(UsGallon::SYMBOL, "US gal");
+        assert_eq!(UsPint::SYMBOL, "US pt");
+        assert_eq!(UsFluidOunce::SYMBOL, "US fl oz");
+        assert_eq!(ImperialGallon::SYMBOL, "imp gal");
+        assert_eq!(ImperialPint::SYMBOL, "imp pt");
+        assert_eq!(ImperialFluidOunce::SYMBOL, "imp fl oz");
+        assert_eq!(Cup::SYMBOL, "cup");
+        assert_eq!(MetricCup::SYMBOL, "metric cup");
+    }
+}
@@ -0,0 +1,217 @@
+//! # Wire Gauge Helpers
+//!
+//! American Wire Gauge (AWG) numbers are not a unit: each step down the scale multiplies
+//! the conductor diameter by a fixed ratio, so that 36 AWG is 0.005 in across and 0000
+//! AWG is 0.46 in across, with 39 geometric steps in between. This module converts gauge
+//! numbers to the typed [`Length`] diameter and [`Area`] cross-section of a solid round
+//! conductor, and back.
+//!
+//! Gauges larger than 0 AWG are written 00, 000 and 0000 (or 2/0, 3/0 and 4/0) and are
+//! passed here as -1, -2 and -3, which is where the formula puts them.
+//!
+//! ## Examples
+//!
+//! ```
+//! use uom::unit_conversion::area::SquareMillimeter;
+//! use uom::unit_conversion::length::Inch;
+//! use uom::unit_conversion::wire::{awg_area, awg_diameter, awg_from_area};
+//!
+//! // 12 AWG, common in household branch circuits
+//! let diameter = awg_diameter(12).unwrap();
+//! assert!((diameter.to_unit::<Inch>() - 0.0808).abs() < 1e-4);
+//!
+//! let area = awg_area(12).unwrap();
+//! assert!((area.to_unit::<SquareMillimeter>() - 3.31).abs() < 1e-2);
+//! assert!((awg_from_area(area).unwrap() - 12.0).abs() < 1e-9);
+//! ```
+
+use std::f64::consts::PI;
+
+use crate::unit_conversion::area::{Area, SquareMeter};
+use crate::unit_conversion::length::{Inch, Length, Meter};
+use crate::unit_conversion::{UnitError, check_positive};
+
+/// The smallest gauge number accepted, 0000 (4/0) AWG.
+pub const MIN_AWG: i32 = -3;
+
+/// The largest gauge number accepted, 40 AWG.
+pub const MAX_AWG: i32 = 40;
+
+/// Diameter of 36 AWG, the reference point of the gauge, in inches.
+const REFERENCE_DIAMETER_INCHES: f64 = 0.005;
+
+/// Gauge number of the reference diameter.
+const REFERENCE_GAUGE: f64 = 36.0;
+
+/// Diameter ratio between 0000 and 36 AWG, spread over 39 gauge steps.
+const DIAMETER_RATIO: f64 = 92.0;
+const GAUGE_STEPS: f64 = 39.0;
+
+/// Returns the diameter of a solid round conductor of the given AWG number.
+///
+/// Returns [`UnitError::OutOfRange`] if `gauge` is outside
+/// [`MIN_AWG`]..=[`MAX_AWG`].
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::Inch;
+/// use uom::unit_conversion::wire::awg_diameter;
+///
+/// let four_aught = awg_diameter(-3).unwrap();
+/// assert!((four_aught.to_unit::<Inch>() - 0.46).abs() < 1e-12);
+/// assert!(awg_diameter(41).is_err());
+/// ```
+pub fn awg_diameter(gauge: i32) -> Result<Length, UnitError> {
+    if !(MIN_AWG..=MAX_AWG).contains(&gauge) {
+        return Err(UnitError::OutOfRange {
+            value: gauge.into(),
+            min: MIN_AWG.into(),
+            max: MAX_AWG.into(),
+        });
+    }
+    let exponent = (REFERENCE_GAUGE - f64::from(gauge)) / GAUGE_STEPS;
+    Ok(Length::from_unit::<Inch>(
+        REFERENCE_DIAMETER_INCHES * DIAMETER_RATIO.powf(exponent),
+    ))
+}
+
+/// Returns the cross-sectional area of a solid round conductor of the given AWG number.
+///
+/// Returns [`UnitError::OutOfRange`] if `gauge` is outside
+/// [`MIN_AWG`]..=[`MAX_AWG`].
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::CircularMil;
+/// use uom::unit_conversion::wire::awg_area;
+///
+/// // 10 AWG is close to 10,000 circular mils
+/// let area = awg_area(10).unwrap();
+/// assert!((area.to_unit::<CircularMil>() - 10_380.0).abs() < 5.0);
+/// ```
+pub fn awg_area(gauge: i32) -> Result<Area, UnitError> {
+    let radius = awg_diameter(gauge)?.to_unit::<Meter>() / 2.0;
+    Ok(Area::from_unit::<SquareMeter>(PI * radius * radius))
+}
+
+/// Returns the AWG number of a solid round conductor with the given diameter.
+///
+/// The result is fractional for diameters between standard gauges; round it to get
+/// the nearest gauge. Returns [`UnitError::OutOfRange`] if the diameter is not
+/// positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::length::{Length, Meter};
+/// use uom::unit_conversion::wire::awg_from_diameter;
+///
+/// // A 1 mm conductor falls between 18 and 17 AWG
+/// let gauge = awg_from_diameter(Length::from_unit::<Meter>(0.001)).unwrap();
+/// assert_eq!(gauge.round(), 18.0);
+/// ```
+pub fn awg_from_diameter(diameter: Length) -> Result<f64, UnitError> {
+    let inches = check_positive(diameter.to_unit::<Inch>())?;
+    let steps = (inches / REFERENCE_DIAMETER_INCHES).ln() / DIAMETER_RATIO.ln();
+    Ok(REFERENCE_GAUGE - GAUGE_STEPS * steps)
+}
+
+/// Returns the AWG number of a solid round conductor with the given cross-sectional area.
+///
+/// The result is fractional for areas between standard gauges. Returns
+/// [`UnitError::OutOfRange`] if the area is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use uom::unit_conversion::area::{Area, SquareMillimeter};
+/// use uom::unit_conversion::wire::awg_from_area;
+///
+/// // Metric 2.5 mm² cable is slightly smaller than 13 AWG
+/// let gauge = awg_from_area(Area::from_unit::<SquareMillimeter>(2.5)).unwrap();
+/// assert_eq!(gauge.round(), 13.0);
+/// ```
+pub fn awg_from_area(area: Area) -> Result<f64, UnitError> {
+    let square_meters = check_positive(area.to_unit::<SquareMeter>())?;
+    let diameter = 2.0 * (square_meters / PI).sqrt();
+    awg_from_diameter(Length::from_unit::<Meter>(diameter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_conversion::area::{CircularMil, SquareMillimeter};
+
+    fn approx(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn reference_gauges() {
+        assert!(approx(
+            awg_diameter(36).unwrap().to_unit::<Inch>(),
+            0.005,
+            1e-15
+        ));
+        assert!(approx(
+            awg_diameter(-3).unwrap().to_unit::<Inch>(),
+            0.46,
+            1e-12
+        ));
+        assert!(approx(
+            awg_diameter(0).unwrap().to_unit::<Inch>(),
+            0.3249,
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn table_values() {
+        // Diameters in mm and areas in mm² from standard AWG tables
+        for (gauge, mm, mm2) in [(4, 5.189, 21.15), (14, 1.628, 2.081), (24, 0.511, 0.205)] {
+            let diameter = awg_diameter(gauge).unwrap().to_unit::<Meter>() * 1000.0;
+            assert!(approx(diameter, mm, 1e-3), "{gauge} AWG: {diameter} mm");
+            let area = awg_area(gauge).unwrap().to_unit::<SquareMillimeter>();
+            assert!(approx(area, mm2, 1e-2), "{gauge} AWG: {area} mm²");
+        }
+    }
+
+    #[test]
+    fn area_in_circular_mils_is_diameter_in_mils_squared() {
+        let mils = awg_diameter(20).unwrap().to_unit::<Inch>() * 1000.0;
+        let cmil = awg_area(20).unwrap().to_unit::<CircularMil>();
+        assert!(approx(cmil, mils * mils, 1e-6));
+    }
+
+    #[test]
+    fn round_trips() {
+        for gauge in MIN_AWG..=MAX_AWG {
+            let diameter = awg_diameter(gauge).unwrap();
+            assert!(approx(
+                awg_from_diameter(diameter).unwrap(),
+                gauge.into(),
+                1e-9
+            ));
+            let area = awg_area(gauge).unwrap();
+            assert!(approx(awg_from_area(area).unwrap(), gauge.into(), 1e-9));
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_input() {
+        assert!(matches!(
+            awg_diameter(-4),
+            Err(UnitError::OutOfRange {
+                min: -3.0,
+                max: 40.0,
+                ..
+            })
+        ));
+        assert!(awg_area(41).is_err());
+        assert!(awg_from_diameter(Length::from_unit::<Meter>(0.0)).is_err());
+        assert!(awg_from_area(Area::from_unit::<SquareMeter>(-1.0)).is_err());
+        assert!(awg_from_area(Area::from_unit::<SquareMeter>(f64::NAN)).is_err());
+    }
+}
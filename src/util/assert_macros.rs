@@ -0,0 +1,198 @@
+//! Assertion macros that compare quantities within a tolerance in a chosen unit.
+//!
+//! Both values are converted to the tolerance's unit before comparing, and a failure
+//! prints them in that unit, so tests read like the requirement they check:
+//!
+#![cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#![cfg_attr(feature = "temperature", doc = "```")]
+//! use uom::assert_qty_eq;
+//! use uom::unit_conversion::temperature::{Celsius, Fahrenheit, Temperature};
+//!
+//! let body = Temperature::from_unit::<Fahrenheit>(98.6);
+//! assert_qty_eq!(body, Temperature::from_unit::<Celsius>(37.0), tolerance = 0.01 Celsius);
+//! ```
+//!
+//! The tolerance is a difference in that unit, so `0.01 Celsius` means a hundredth of
+//! a degree, not a temperature near absolute zero.
+
+use std::fmt;
+
+/// Asserts that two quantities are equal to within a tolerance in the given unit.
+///
+/// The tolerance is a literal, a variable, or a parenthesized expression, followed by
+/// the unit type. An optional format string and arguments add context to the panic
+/// message, as with [`assert_eq!`].
+///
+/// # Panics
+///
+/// Panics if the quantities differ by more than the tolerance, or if either is NaN.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "length"), doc = "```ignore")]
+#[cfg_attr(feature = "length", doc = "```")]
+/// use uom::assert_qty_eq;
+/// use uom::unit_conversion::length::{Foot, Length, Meter};
+///
+/// let tolerance = 0.5;
+/// let mast = Length::from_unit::<Foot>(30.0);
+/// assert_qty_eq!(mast, Length::from_unit::<Meter>(9.144), tolerance = 0.001 Meter);
+/// let drawing = Length::from_unit::<Foot>(30.2);
+/// assert_qty_eq!(mast, drawing, tolerance = tolerance Foot, "mast height");
+/// ```
+#[macro_export]
+macro_rules! assert_qty_eq {
+    ($left:expr, $right:expr, tolerance = $tolerance:tt $unit:path $(,)?) => {
+        $crate::assert_qty_eq!($left, $right, tolerance = $tolerance $unit, "")
+    };
+    ($left:expr, $right:expr, tolerance = $tolerance:tt $unit:path, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let left = left.to_unit::<$unit>();
+                let right = right.to_unit::<$unit>();
+                #[allow(unused_parens)]
+                let tolerance: f64 = $tolerance;
+                if !$crate::util::assert_macros::within(left, right, tolerance) {
+                    $crate::util::assert_macros::assert_failed(
+                        "≈",
+                        left,
+                        right,
+                        tolerance,
+                        <$unit as $crate::unit_conversion::UnitConversion>::SYMBOL,
+                        format_args!($($arg)+),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two quantities differ by more than a tolerance in the given unit.
+///
+/// Takes the same arguments as [`assert_qty_eq!`].
+///
+/// # Panics
+///
+/// Panics if the quantities are within the tolerance of each other.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "temperature"), doc = "```ignore")]
+#[cfg_attr(feature = "temperature", doc = "```")]
+/// use uom::assert_qty_ne;
+/// use uom::unit_conversion::temperature::{Celsius, Temperature};
+///
+/// let setpoint = Temperature::from_unit::<Celsius>(21.0);
+/// let reading = Temperature::from_unit::<Celsius>(23.5);
+/// assert_qty_ne!(setpoint, reading, tolerance = 1.0 Celsius);
+/// ```
+#[macro_export]
+macro_rules! assert_qty_ne {
+    ($left:expr, $right:expr, tolerance = $tolerance:tt $unit:path $(,)?) => {
+        $crate::assert_qty_ne!($left, $right, tolerance = $tolerance $unit, "")
+    };
+    ($left:expr, $right:expr, tolerance = $tolerance:tt $unit:path, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let left = left.to_unit::<$unit>();
+                let right = right.to_unit::<$unit>();
+                #[allow(unused_parens)]
+                let tolerance: f64 = $tolerance;
+                if $crate::util::assert_macros::within(left, right, tolerance) {
+                    $crate::util::assert_macros::assert_failed(
+                        "≉",
+                        left,
+                        right,
+                        tolerance,
+                        <$unit as $crate::unit_conversion::UnitConversion>::SYMBOL,
+                        format_args!($($arg)+),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Returns true if `left` and `right` are within `tolerance` of each other.
+///
+/// Equal infinities count as within any tolerance; NaN is never within one.
+#[doc(hidden)]
+pub fn within(left: f64, right: f64, tolerance: f64) -> bool {
+    left == right || (left - right).abs() <= tolerance
+}
+
+/// Panics with the message shared by the quantity assertion macros.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_failed(
+    op: &str,
+    left: f64,
+    right: f64,
+    tolerance: f64,
+    symbol: &str,
+    message: fmt::Arguments<'_>,
+) -> ! {
+    let separator = if message.as_str() == Some("") {
+        ""
+    } else {
+        ": "
+    };
+    panic!(
+        "assertion `left {op} right` failed{separator}{message}\n  \
+         left: {left} {symbol}\n \
+         right: {right} {symbol}\n \
+         tolerance: {tolerance} {symbol}"
+    )
+}
+
+#[cfg(all(test, feature = "temperature"))]
+mod tests {
+    use crate::unit_conversion::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::from_unit::<Celsius>(value)
+    }
+
+    #[test]
+    fn passes_within_tolerance() {
+        let boiling = Temperature::from_unit::<Fahrenheit>(212.0);
+        assert_qty_eq!(celsius(100.0), boiling, tolerance = 1e-9 Celsius);
+        assert_qty_eq!(celsius(20.0), celsius(20.004), tolerance = 0.005 Kelvin,);
+        assert_qty_ne!(celsius(20.0), celsius(20.1), tolerance = (0.01 * 5.0) Celsius);
+        assert_qty_eq!(
+            Temperature::from_unit::<Kelvin>(f64::INFINITY),
+            Temperature::from_unit::<Kelvin>(f64::INFINITY),
+            tolerance = 0.0 Kelvin
+        );
+    }
+
+    #[test]
+    fn tolerance_is_a_difference_in_the_unit() {
+        // 1 °F is 5/9 of a kelvin, so 0.5 K apart is within 1 °F but not within 0.5 °F
+        let (a, b) = (celsius(10.0), celsius(10.5));
+        assert_qty_eq!(a, b, tolerance = 1.0 Fahrenheit);
+        assert_qty_ne!(a, b, tolerance = 0.5 Fahrenheit);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion `left ≈ right` failed: probe 3\n  left: 20 °C\n right: 21 °C\n tolerance: 0.5 °C"
+    )]
+    fn reports_values_in_the_tolerance_unit() {
+        let probe = 3;
+        assert_qty_eq!(celsius(20.0), celsius(21.0), tolerance = 0.5 Celsius, "probe {probe}");
+    }
+
+    #[test]
+    #[should_panic(expected = "left: NaN K")]
+    fn nan_never_matches() {
+        let nan = Temperature::from_unit::<Kelvin>(f64::NAN);
+        assert_qty_eq!(nan, nan, tolerance = 1.0 Kelvin);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left ≉ right` failed\n")]
+    fn ne_fails_within_tolerance() {
+        assert_qty_ne!(celsius(5.0), celsius(5.0), tolerance = 0.1 Celsius);
+    }
+}
@@ -1,2 +1,3 @@
+pub mod assert_macros;
 pub mod smart;
-pub mod smart_macros;
\ No newline at end of file
+pub mod smart_macros;
@@ -1,4 +1,10 @@
 use std::fmt;
+use std::marker::PhantomData;
+
+use crate::unit_conversion::logarithmic::LogarithmicUnit;
+
+/// Significant figures of the linear equivalent written by [`SmartLevel`].
+const LINEAR_FIGURES: usize = 3;
 
 pub enum Mode {
     Round,
@@ -11,13 +17,29 @@ pub struct SmartF64 {
     pub mode: Mode,
 }
 
-impl fmt::Display for SmartF64 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl SmartF64 {
+    /// Treats the value as a level in the logarithmic unit `U` and writes it with its
+    /// linear equivalent in parentheses, as in "-3 dB (0.501×)".
+    pub fn with_linear<U: LogarithmicUnit>(self) -> SmartLevel<U> {
+        SmartLevel {
+            level: self,
+            figures: LINEAR_FIGURES,
+            _u: PhantomData,
+        }
+    }
+
+    fn rounded(&self) -> f64 {
         let factor = 10f64.powi(self.precision as i32);
-        let v = match self.mode {
+        match self.mode {
             Mode::Round => (self.value * factor).round() / factor,
             Mode::Trunc => (self.value * factor).trunc() / factor,
-        };
+        }
+    }
+}
+
+impl fmt::Display for SmartF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = self.rounded();
 
         // format with max precision, then trim
         let mut s = format!("{:.1$}", v, self.precision);
@@ -36,3 +58,61 @@ impl fmt::Display for SmartF64 {
         f.write_str(&s)
     }
 }
+
+/// A level in the logarithmic unit `U` followed by its linear equivalent, as returned by
+/// [`SmartF64::with_linear`].
+///
+/// The level is written like its [`SmartF64`], then the unit symbol; the linear value
+/// is rounded to three significant figures unless [`with_figures`](Self::with_figures)
+/// says otherwise, in scientific notation when it is below 10⁻⁴ or from 10⁶ up.
+pub struct SmartLevel<U: LogarithmicUnit> {
+    pub level: SmartF64,
+    pub figures: usize,
+    _u: PhantomData<U>,
+}
+
+impl<U: LogarithmicUnit> SmartLevel<U> {
+    /// Writes the linear equivalent with `figures` significant figures, at least one.
+    pub fn with_figures(mut self, figures: usize) -> Self {
+        self.figures = figures.max(1);
+        self
+    }
+}
+
+impl<U: LogarithmicUnit> fmt::Display for SmartLevel<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let linear = significant(U::to_linear(self.level.value), self.figures);
+        let separator = if U::LINEAR_SYMBOL == "×" { "" } else { " " };
+        write!(
+            f,
+            "{} {} ({linear}{separator}{})",
+            self.level,
+            U::SYMBOL,
+            U::LINEAR_SYMBOL
+        )
+    }
+}
+
+/// Writes `value` rounded to `figures` significant figures, at least one.
+fn significant(value: f64, figures: usize) -> String {
+    // `figures` is a public field, so it may be zero despite `with_figures`
+    let figures = figures.max(1);
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+    let exponent = value.abs().log10().floor() as i32;
+    if !(-4..6).contains(&exponent) {
+        return format!("{:.*e}", figures - 1, value);
+    }
+    let decimals = (figures as i32 - 1 - exponent).max(0) as usize;
+    format!("{value:.decimals$}")
+}
+
+// The host-side decoder prints the shortest representation, which already drops
+// trailing zeros.
+#[cfg(feature = "defmt")]
+impl defmt::Format for SmartF64 {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=f64}", self.rounded())
+    }
+}